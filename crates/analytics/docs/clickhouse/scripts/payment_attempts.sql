@@ -48,6 +48,8 @@ CREATE TABLE payment_attempt_queue (
     `signature_network` Nullable(String),
     `is_issuer_regulated` Nullable(Bool),
     `processor_merchant_id` Nullable(String),
+    `retry_hop_reason` LowCardinality(Nullable(String)),
+    `retry_hop` Nullable(String),
     `sign_flag` Int8
 ) ENGINE = Kafka SETTINGS kafka_broker_list = 'kafka0:29092',
 kafka_topic_list = 'hyperswitch-payment-attempt-events',
@@ -106,6 +108,8 @@ CREATE TABLE payment_attempts (
     `signature_network` Nullable(String),
     `is_issuer_regulated` Nullable(Bool),
     `processor_merchant_id` Nullable(String),
+    `retry_hop_reason` LowCardinality(Nullable(String)),
+    `retry_hop` Nullable(String),
     `sign_flag` Int8,
     INDEX connectorIndex connector TYPE bloom_filter GRANULARITY 1,
     INDEX paymentMethodIndex payment_method TYPE bloom_filter GRANULARITY 1,
@@ -167,6 +171,8 @@ CREATE MATERIALIZED VIEW payment_attempt_mv TO payment_attempts (
     `signature_network` Nullable(String),
     `is_issuer_regulated` Nullable(Bool),
     `processor_merchant_id` Nullable(String),
+    `retry_hop_reason` LowCardinality(Nullable(String)),
+    `retry_hop` Nullable(String),
     `sign_flag` Int8
 ) AS
 SELECT
@@ -220,6 +226,8 @@ SELECT
     signature_network,
     is_issuer_regulated,
     processor_merchant_id,
+    retry_hop_reason,
+    retry_hop,
     sign_flag
 FROM
     payment_attempt_queue
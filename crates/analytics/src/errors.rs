@@ -16,6 +16,12 @@ pub enum AnalyticsError {
     ForexFetchFailed,
     #[error("Missing email")]
     MissingEmail,
+    #[error("Report job not found")]
+    ReportJobNotFound,
+    #[error("Report is not available yet")]
+    ReportNotAvailable,
+    #[error("Too many reports are being generated")]
+    ReportJobLimitReached,
 }
 
 impl ErrorSwitch<ApiErrorResponse> for AnalyticsError {
@@ -48,6 +54,24 @@ impl ErrorSwitch<ApiErrorResponse> for AnalyticsError {
                 "Missing or invalid merchant email address.",
                 None,
             )),
+            Self::ReportJobNotFound => ApiErrorResponse::NotFound(ApiError::new(
+                "IR",
+                7,
+                "Report job does not exist in our records",
+                None,
+            )),
+            Self::ReportNotAvailable => ApiErrorResponse::BadRequest(ApiError::new(
+                "IR",
+                8,
+                "Report has not been generated yet",
+                None,
+            )),
+            Self::ReportJobLimitReached => ApiErrorResponse::Conflict(ApiError::new(
+                "IR",
+                9,
+                "Too many reports are being generated, retry once one of them completes",
+                None,
+            )),
         }
     }
 }
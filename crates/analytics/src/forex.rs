@@ -0,0 +1,244 @@
+use api_models::analytics::{Granularity, TimeRange};
+use bigdecimal::{BigDecimal, ToPrimitive};
+use common_enums::Currency;
+use currency_conversion::{conversion::convert_minor, types::ExchangeRates};
+use router_env::logger;
+use time::PrimitiveDateTime;
+
+use crate::query::{PostProcessingError, SeriesBucket};
+
+/// The exchange rates used to report amounts in a single currency. Amounts are grouped by the hour
+/// in which they were created, and each group is converted using the snapshot of rates that was
+/// valid at the time of its earliest record. The latest rates are used when no snapshot precedes
+/// the group.
+#[derive(Clone, Debug)]
+pub struct ReportingExchangeRates {
+    reporting_currency: Currency,
+    latest: ExchangeRates,
+    /// Snapshots of rates, sorted by the time from which they were valid
+    snapshots: Vec<(PrimitiveDateTime, ExchangeRates)>,
+}
+
+impl ReportingExchangeRates {
+    pub fn new(
+        reporting_currency: Currency,
+        latest: ExchangeRates,
+        mut snapshots: Vec<(PrimitiveDateTime, ExchangeRates)>,
+    ) -> Self {
+        snapshots.sort_by_key(|(valid_from, _)| *valid_from);
+        Self {
+            reporting_currency,
+            latest,
+            snapshots,
+        }
+    }
+
+    pub fn get_reporting_currency(&self) -> Currency {
+        self.reporting_currency
+    }
+
+    /// Returns the rates that were valid at the given time
+    pub fn get_rates_as_of(&self, time: PrimitiveDateTime) -> &ExchangeRates {
+        let valid_snapshots = self
+            .snapshots
+            .partition_point(|(valid_from, _)| *valid_from <= time);
+
+        valid_snapshots
+            .checked_sub(1)
+            .and_then(|index| self.snapshots.get(index))
+            .map(|(_, rates)| rates)
+            .unwrap_or(&self.latest)
+    }
+
+    /// Converts an amount in the minor unit of `currency` to the minor unit of the reporting
+    /// currency, using the rates that were valid at the given time
+    pub fn convert_to_reporting_currency(
+        &self,
+        amount: u64,
+        currency: Currency,
+        time: PrimitiveDateTime,
+    ) -> Option<u64> {
+        let amount = i64::try_from(amount)
+            .inspect_err(|e| logger::error!("Amount conversion error: {:?}", e))
+            .ok()?;
+
+        convert_minor(
+            self.get_rates_as_of(time),
+            currency,
+            self.reporting_currency,
+            amount,
+        )
+        .inspect_err(|e| logger::error!("Currency conversion error: {:?}", e))
+        .ok()
+        .and_then(|amount| u64::try_from(amount).ok())
+    }
+
+    /// Converts the total of a metric row to the reporting currency, using the rates that were
+    /// valid at the time of the earliest record aggregated into the row
+    pub fn convert_metric_total(
+        &self,
+        total: Option<&BigDecimal>,
+        currency: Option<Currency>,
+        start_bucket: PrimitiveDateTime,
+    ) -> Option<u64> {
+        let total = total.and_then(ToPrimitive::to_u64)?;
+        self.convert_to_reporting_currency(total, currency?, start_bucket)
+    }
+}
+
+/// Returns the granularity at which the amounts of forex metrics are queried, which is never
+/// coarser than an hour so that amounts are converted close to the time they were created at
+pub(crate) fn get_conversion_granularity(granularity: Option<Granularity>) -> Granularity {
+    match granularity {
+        None | Some(Granularity::OneDay) => Granularity::OneHour,
+        Some(granularity) => granularity,
+    }
+}
+
+/// Returns the time bucket of the requested granularity which contains a time bucket of the
+/// conversion granularity
+pub(crate) fn get_requested_time_bucket(
+    conversion_time_bucket: TimeRange,
+    granularity: Option<Granularity>,
+    time_range: &TimeRange,
+) -> error_stack::Result<TimeRange, PostProcessingError> {
+    match granularity {
+        Some(granularity) => Ok(TimeRange {
+            start_time: granularity.clip_to_start(conversion_time_bucket.start_time)?,
+            end_time: conversion_time_bucket
+                .end_time
+                .map(|end_time| granularity.clip_to_end(end_time))
+                .transpose()?,
+        }),
+        None => Ok(*time_range),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use std::collections::HashMap;
+
+    use currency_conversion::types::CurrencyFactors;
+    use rust_decimal::Decimal;
+    use time::{Date, Month, Time};
+
+    use super::*;
+
+    fn january(day: u8, hour: u8, minute: u8, second: u8) -> PrimitiveDateTime {
+        PrimitiveDateTime::new(
+            Date::from_calendar_date(2024, Month::January, day).unwrap(),
+            Time::from_hms(hour, minute, second).unwrap(),
+        )
+    }
+
+    fn exchange_rates(eur_per_usd: i64) -> ExchangeRates {
+        ExchangeRates::new(
+            Currency::USD,
+            HashMap::from([
+                (
+                    Currency::USD,
+                    CurrencyFactors::new(Decimal::ONE, Decimal::ONE),
+                ),
+                (
+                    Currency::EUR,
+                    CurrencyFactors::new(
+                        Decimal::from(eur_per_usd),
+                        Decimal::ONE / Decimal::from(eur_per_usd),
+                    ),
+                ),
+            ]),
+        )
+    }
+
+    fn reporting_exchange_rates() -> ReportingExchangeRates {
+        ReportingExchangeRates::new(
+            Currency::EUR,
+            exchange_rates(4),
+            vec![
+                (january(3, 0, 0, 0), exchange_rates(3)),
+                (january(1, 0, 0, 0), exchange_rates(2)),
+            ],
+        )
+    }
+
+    #[test]
+    fn test_get_rates_as_of() {
+        let rates = reporting_exchange_rates();
+
+        let eur_factor = |time| {
+            rates
+                .get_rates_as_of(time)
+                .conversion
+                .get(&Currency::EUR)
+                .unwrap()
+                .to_factor
+        };
+
+        // The latest rates are used before the first snapshot
+        assert_eq!(
+            eur_factor(january(1, 0, 0, 0) - time::Duration::MINUTE),
+            Decimal::from(4)
+        );
+        assert_eq!(eur_factor(january(1, 0, 0, 0)), Decimal::from(2));
+        assert_eq!(eur_factor(january(2, 12, 0, 0)), Decimal::from(2));
+        assert_eq!(eur_factor(january(3, 0, 0, 0)), Decimal::from(3));
+        assert_eq!(eur_factor(january(31, 23, 59, 59)), Decimal::from(3));
+    }
+
+    #[test]
+    fn test_convert_to_reporting_currency() {
+        let rates = reporting_exchange_rates();
+
+        assert_eq!(
+            rates.convert_to_reporting_currency(1000, Currency::USD, january(2, 0, 0, 0)),
+            Some(2000)
+        );
+        assert_eq!(
+            rates.convert_metric_total(
+                Some(&BigDecimal::from(1000)),
+                Some(Currency::USD),
+                january(3, 1, 0, 0)
+            ),
+            Some(3000)
+        );
+        assert_eq!(
+            rates.convert_metric_total(Some(&BigDecimal::from(1000)), None, january(3, 1, 0, 0)),
+            None
+        );
+    }
+
+    #[test]
+    fn test_get_requested_time_bucket() {
+        let time_range = TimeRange {
+            start_time: january(1, 0, 0, 0),
+            end_time: Some(january(31, 0, 0, 0)),
+        };
+        let conversion_time_bucket = TimeRange {
+            start_time: january(2, 13, 0, 0),
+            end_time: Some(january(2, 13, 59, 59)),
+        };
+
+        assert!(matches!(
+            get_conversion_granularity(Some(Granularity::OneDay)),
+            Granularity::OneHour
+        ));
+        assert!(matches!(
+            get_conversion_granularity(Some(Granularity::FifteenMin)),
+            Granularity::FifteenMin
+        ));
+
+        let time_bucket =
+            get_requested_time_bucket(conversion_time_bucket, None, &time_range).unwrap();
+        assert_eq!(time_bucket.start_time, time_range.start_time);
+        assert_eq!(time_bucket.end_time, time_range.end_time);
+
+        let time_bucket = get_requested_time_bucket(
+            conversion_time_bucket,
+            Some(Granularity::OneDay),
+            &time_range,
+        )
+        .unwrap();
+        assert_eq!(time_bucket.start_time, january(2, 0, 0, 0));
+    }
+}
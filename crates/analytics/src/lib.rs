@@ -7,6 +7,7 @@ pub mod core;
 pub mod disputes;
 pub mod enums;
 pub mod errors;
+pub mod forex;
 pub mod frm;
 pub mod health_check;
 pub mod metrics;
@@ -16,6 +17,7 @@ pub mod payment_intents;
 pub mod payments;
 mod query;
 pub mod refunds;
+pub mod reports;
 pub mod routing_events;
 pub mod sdk_events;
 pub mod search;
@@ -1120,6 +1122,47 @@ pub struct ReportConfig {
     pub authentication_function: String,
     pub payout_function: String,
     pub region: String,
+    #[serde(default)]
+    pub backend: ReportBackend,
+    #[serde(default)]
+    pub in_process: InProcessReportConfig,
+}
+
+/// Where reports are generated
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ReportBackend {
+    /// Invoke the configured AWS Lambda functions
+    #[default]
+    Lambda,
+    /// Query the analytics provider from the application and store the report in file storage
+    InProcess,
+}
+
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+#[serde(default)]
+pub struct InProcessReportConfig {
+    pub format: api_models::analytics::ReportFormat,
+    /// Number of rows read from the analytics provider in a single query
+    pub batch_size: u64,
+    /// Time for which the status of a report job and the generated report are retained
+    pub job_ttl_in_seconds: i64,
+    /// Time after which a report job which has not completed is considered failed
+    pub job_timeout_in_seconds: i64,
+    /// Number of reports which can be generated at the same time for a merchant
+    pub max_concurrent_jobs_per_merchant: usize,
+}
+
+impl Default for InProcessReportConfig {
+    fn default() -> Self {
+        Self {
+            format: api_models::analytics::ReportFormat::default(),
+            batch_size: 10_000,
+            job_ttl_in_seconds: 7 * 24 * 60 * 60,
+            job_timeout_in_seconds: 60 * 60,
+            max_concurrent_jobs_per_merchant: 2,
+        }
+    }
 }
 
 /// Analytics Flow routes Enums
@@ -1149,6 +1192,8 @@ pub enum AnalyticsFlow {
     GenerateRefundReport,
     GenerateAuthenticationReport,
     GeneratePayoutReport,
+    GetReportJob,
+    DownloadReport,
     GetApiEventMetrics,
     GetApiEventFilters,
     GetConnectorEvents,
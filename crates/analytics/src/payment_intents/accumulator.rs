@@ -45,6 +45,8 @@ pub trait PaymentIntentMetricAccumulator {
 pub struct SmartRetriedAmountAccumulator {
     pub amount: Option<i64>,
     pub amount_without_retries: Option<i64>,
+    pub amount_in_reporting_currency: Option<u64>,
+    pub amount_without_retries_in_reporting_currency: Option<u64>,
 }
 
 #[derive(Debug, Default)]
@@ -60,6 +62,8 @@ pub struct ProcessedAmountAccumulator {
     pub total_with_retries: Option<i64>,
     pub count_without_retries: Option<i64>,
     pub total_without_retries: Option<i64>,
+    pub total_with_retries_in_reporting_currency: Option<u64>,
+    pub total_without_retries_in_reporting_currency: Option<u64>,
 }
 
 #[derive(Debug, Default)]
@@ -117,7 +121,32 @@ impl PaymentIntentMetricAccumulator for SmartRetriedAmountAccumulator {
             .amount_without_retries
             .and_then(|i| u64::try_from(i).ok())
             .or(Some(0));
-        (with_retries, without_retries, Some(0), Some(0))
+        (
+            with_retries,
+            without_retries,
+            self.amount_in_reporting_currency,
+            self.amount_without_retries_in_reporting_currency,
+        )
+    }
+}
+
+impl SmartRetriedAmountAccumulator {
+    /// Adds the total of a metric row, converted to the reporting currency
+    pub fn add_reporting_amount(&mut self, metrics: &PaymentIntentMetricRow, total: Option<u64>) {
+        self.amount_in_reporting_currency = match (self.amount_in_reporting_currency, total) {
+            (None, None) => None,
+            (None, i @ Some(_)) | (i @ Some(_), None) => i,
+            (Some(a), Some(b)) => Some(a + b),
+        };
+
+        if metrics.first_attempt.unwrap_or(0) == 1 {
+            self.amount_without_retries_in_reporting_currency =
+                match (self.amount_without_retries_in_reporting_currency, total) {
+                    (None, None) => None,
+                    (None, i @ Some(_)) | (i @ Some(_), None) => i,
+                    (Some(a), Some(b)) => Some(a + b),
+                };
+        }
     }
 }
 
@@ -242,12 +271,33 @@ impl PaymentIntentMetricAccumulator for ProcessedAmountAccumulator {
             count_with_retries,
             total_without_retries,
             count_without_retries,
-            Some(0),
-            Some(0),
+            self.total_with_retries_in_reporting_currency,
+            self.total_without_retries_in_reporting_currency,
         )
     }
 }
 
+impl ProcessedAmountAccumulator {
+    /// Adds the total of a metric row, converted to the reporting currency
+    pub fn add_reporting_amount(&mut self, metrics: &PaymentIntentMetricRow, total: Option<u64>) {
+        self.total_with_retries_in_reporting_currency =
+            match (self.total_with_retries_in_reporting_currency, total) {
+                (None, None) => None,
+                (None, i @ Some(_)) | (i @ Some(_), None) => i,
+                (Some(a), Some(b)) => Some(a + b),
+            };
+
+        if metrics.first_attempt.unwrap_or(0) == 1 {
+            self.total_without_retries_in_reporting_currency =
+                match (self.total_without_retries_in_reporting_currency, total) {
+                    (None, None) => None,
+                    (None, i @ Some(_)) | (i @ Some(_), None) => i,
+                    (Some(a), Some(b)) => Some(a + b),
+                };
+        }
+    }
+}
+
 impl PaymentIntentMetricAccumulator for PaymentsDistributionAccumulator {
     type MetricOutput = (Option<f64>, Option<f64>);
 
@@ -355,6 +405,7 @@ impl PaymentIntentMetricsAccumulator {
             payments_failure_rate_distribution_without_smart_retries,
             payment_processed_amount_in_usd,
             payment_processed_amount_without_smart_retries_in_usd,
+            reporting_currency: None,
         }
     }
 }
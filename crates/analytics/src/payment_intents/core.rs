@@ -6,13 +6,11 @@ use api_models::analytics::{
         MetricsBucketResponse, PaymentIntentDimensions, PaymentIntentMetrics,
         PaymentIntentMetricsBucketIdentifier,
     },
-    GetPaymentIntentFiltersRequest, GetPaymentIntentMetricRequest, PaymentIntentFilterValue,
-    PaymentIntentFiltersResponse, PaymentIntentsAnalyticsMetadata, PaymentIntentsMetricsResponse,
+    ForexMetric, GetPaymentIntentFiltersRequest, GetPaymentIntentMetricRequest,
+    PaymentIntentFilterValue, PaymentIntentFiltersResponse, PaymentIntentsAnalyticsMetadata,
+    PaymentIntentsMetricsResponse,
 };
-use bigdecimal::ToPrimitive;
-use common_enums::Currency;
 use common_utils::{errors::CustomResult, types::TimeRange};
-use currency_conversion::{conversion::convert, types::ExchangeRates};
 use error_stack::ResultExt;
 use router_env::{
     instrument, logger,
@@ -28,6 +26,7 @@ use super::{
 use crate::{
     enums::AuthInfo,
     errors::{AnalyticsError, AnalyticsResult},
+    forex::{self, ReportingExchangeRates},
     metrics,
     payment_intents::PaymentIntentMetricAccumulator,
     AnalyticsProvider,
@@ -68,7 +67,7 @@ pub async fn get_sankey(
 #[instrument(skip_all)]
 pub async fn get_metrics(
     pool: &AnalyticsProvider,
-    ex_rates: &Option<ExchangeRates>,
+    ex_rates: &Option<ReportingExchangeRates>,
     auth: &AuthInfo,
     req: GetPaymentIntentMetricRequest,
 ) -> AnalyticsResult<PaymentIntentsMetricsResponse<MetricsBucketResponse>> {
@@ -77,10 +76,17 @@ pub async fn get_metrics(
         PaymentIntentMetricsAccumulator,
     > = HashMap::new();
 
+    let granularity = req.time_series.map(|t| t.granularity);
     let mut set = tokio::task::JoinSet::new();
     for metric_type in req.metrics.iter().cloned() {
         let req = req.clone();
         let pool = pool.clone();
+        // Amounts are grouped finer than requested to be converted close to their creation time
+        let query_granularity = if ex_rates.is_some() && metric_type.is_forex_metric() {
+            Some(forex::get_conversion_granularity(granularity))
+        } else {
+            granularity
+        };
         let task_span = tracing::debug_span!(
             "analytics_payment_intents_metrics_query",
             payment_metric = metric_type.as_ref()
@@ -97,7 +103,7 @@ pub async fn get_metrics(
                         &req.group_by_names.clone(),
                         &auth_scoped,
                         &req.filters,
-                        req.time_series.map(|t| t.granularity),
+                        query_granularity,
                         &req.time_range,
                     )
                     .await
@@ -128,8 +134,24 @@ pub async fn get_metrics(
                     logger::debug!("Attributes: {:?}, Buckets fetched: {}", attributes, val);
                 }
 
-                for (id, value) in data {
+                let forex_rates = ex_rates.as_ref().filter(|_| metric.is_forex_metric());
+                for (mut id, value) in data {
                     logger::debug!(bucket_id=?id, bucket_value=?value, "Bucket row for metric {metric}");
+                    let mut reporting_amount = None;
+                    if let Some(ex_rates) = forex_rates {
+                        reporting_amount = ex_rates.convert_metric_total(
+                            value.total.as_ref(),
+                            id.currency,
+                            value.start_bucket.unwrap_or(id.start_time),
+                        );
+                        id.time_bucket = forex::get_requested_time_bucket(
+                            id.time_bucket,
+                            granularity,
+                            &req.time_range,
+                        )
+                        .change_context(AnalyticsError::UnknownError)?;
+                        id.start_time = id.time_bucket.start_time;
+                    }
                     let metrics_builder = metrics_accumulator.entry(id).or_default();
                     match metric {
                         PaymentIntentMetrics::SuccessfulSmartRetries
@@ -143,9 +165,14 @@ pub async fn get_metrics(
                             .total_smart_retries
                             .add_metrics_bucket(&value),
                         PaymentIntentMetrics::SmartRetriedAmount
-                        | PaymentIntentMetrics::SessionizedSmartRetriedAmount => metrics_builder
-                            .smart_retried_amount
-                            .add_metrics_bucket(&value),
+                        | PaymentIntentMetrics::SessionizedSmartRetriedAmount => {
+                            metrics_builder
+                                .smart_retried_amount
+                                .add_metrics_bucket(&value);
+                            metrics_builder
+                                .smart_retried_amount
+                                .add_reporting_amount(&value, reporting_amount);
+                        }
                         PaymentIntentMetrics::PaymentIntentCount
                         | PaymentIntentMetrics::SessionizedPaymentIntentCount => metrics_builder
                             .payment_intent_count
@@ -155,9 +182,14 @@ pub async fn get_metrics(
                             .payments_success_rate
                             .add_metrics_bucket(&value),
                         PaymentIntentMetrics::SessionizedPaymentProcessedAmount
-                        | PaymentIntentMetrics::PaymentProcessedAmount => metrics_builder
-                            .payment_processed_amount
-                            .add_metrics_bucket(&value),
+                        | PaymentIntentMetrics::PaymentProcessedAmount => {
+                            metrics_builder
+                                .payment_processed_amount
+                                .add_metrics_bucket(&value);
+                            metrics_builder
+                                .payment_processed_amount
+                                .add_reporting_amount(&value, reporting_amount);
+                        }
                         PaymentIntentMetrics::SessionizedPaymentsDistribution => metrics_builder
                             .payments_distribution
                             .add_metrics_bucket(&value),
@@ -190,6 +222,9 @@ pub async fn get_metrics(
         .into_iter()
         .map(|(id, val)| {
             let mut collected_values = val.collect();
+            collected_values.reporting_currency = ex_rates
+                .as_ref()
+                .map(ReportingExchangeRates::get_reporting_currency);
             if let Some(success_count) = collected_values.successful_payments {
                 success += success_count;
             }
@@ -201,107 +236,31 @@ pub async fn get_metrics(
                 total += total_count;
             }
             if let Some(retried_amount) = collected_values.smart_retried_amount {
-                let amount_in_usd = if let Some(ex_rates) = ex_rates {
-                    id.currency
-                        .and_then(|currency| {
-                            i64::try_from(retried_amount)
-                                .inspect_err(|e| logger::error!("Amount conversion error: {:?}", e))
-                                .ok()
-                                .and_then(|amount_i64| {
-                                    convert(ex_rates, currency, Currency::USD, amount_i64)
-                                        .inspect_err(|e| {
-                                            logger::error!("Currency conversion error: {:?}", e)
-                                        })
-                                        .ok()
-                                })
-                        })
-                        .map(|amount| (amount * rust_decimal::Decimal::new(100, 0)).to_u64())
-                        .unwrap_or_default()
-                } else {
-                    None
-                };
-                collected_values.smart_retried_amount_in_usd = amount_in_usd;
                 total_smart_retried_amount += retried_amount;
-                total_smart_retried_amount_in_usd += amount_in_usd.unwrap_or(0);
+                total_smart_retried_amount_in_usd +=
+                    collected_values.smart_retried_amount_in_usd.unwrap_or(0);
             }
             if let Some(retried_amount) =
                 collected_values.smart_retried_amount_without_smart_retries
             {
-                let amount_in_usd = if let Some(ex_rates) = ex_rates {
-                    id.currency
-                        .and_then(|currency| {
-                            i64::try_from(retried_amount)
-                                .inspect_err(|e| logger::error!("Amount conversion error: {:?}", e))
-                                .ok()
-                                .and_then(|amount_i64| {
-                                    convert(ex_rates, currency, Currency::USD, amount_i64)
-                                        .inspect_err(|e| {
-                                            logger::error!("Currency conversion error: {:?}", e)
-                                        })
-                                        .ok()
-                                })
-                        })
-                        .map(|amount| (amount * rust_decimal::Decimal::new(100, 0)).to_u64())
-                        .unwrap_or_default()
-                } else {
-                    None
-                };
-                collected_values.smart_retried_amount_without_smart_retries_in_usd = amount_in_usd;
                 total_smart_retried_amount_without_smart_retries += retried_amount;
-                total_smart_retried_amount_without_smart_retries_in_usd +=
-                    amount_in_usd.unwrap_or(0);
+                total_smart_retried_amount_without_smart_retries_in_usd += collected_values
+                    .smart_retried_amount_without_smart_retries_in_usd
+                    .unwrap_or(0);
             }
             if let Some(amount) = collected_values.payment_processed_amount {
-                let amount_in_usd = if let Some(ex_rates) = ex_rates {
-                    id.currency
-                        .and_then(|currency| {
-                            i64::try_from(amount)
-                                .inspect_err(|e| logger::error!("Amount conversion error: {:?}", e))
-                                .ok()
-                                .and_then(|amount_i64| {
-                                    convert(ex_rates, currency, Currency::USD, amount_i64)
-                                        .inspect_err(|e| {
-                                            logger::error!("Currency conversion error: {:?}", e)
-                                        })
-                                        .ok()
-                                })
-                        })
-                        .map(|amount| (amount * rust_decimal::Decimal::new(100, 0)).to_u64())
-                        .unwrap_or_default()
-                } else {
-                    None
-                };
-                collected_values.payment_processed_amount_in_usd = amount_in_usd;
-                total_payment_processed_amount_in_usd += amount_in_usd.unwrap_or(0);
+                total_payment_processed_amount_in_usd += collected_values
+                    .payment_processed_amount_in_usd
+                    .unwrap_or(0);
                 total_payment_processed_amount += amount;
             }
             if let Some(count) = collected_values.payment_processed_count {
                 total_payment_processed_count += count;
             }
             if let Some(amount) = collected_values.payment_processed_amount_without_smart_retries {
-                let amount_in_usd = if let Some(ex_rates) = ex_rates {
-                    id.currency
-                        .and_then(|currency| {
-                            i64::try_from(amount)
-                                .inspect_err(|e| logger::error!("Amount conversion error: {:?}", e))
-                                .ok()
-                                .and_then(|amount_i64| {
-                                    convert(ex_rates, currency, Currency::USD, amount_i64)
-                                        .inspect_err(|e| {
-                                            logger::error!("Currency conversion error: {:?}", e)
-                                        })
-                                        .ok()
-                                })
-                        })
-                        .map(|amount| (amount * rust_decimal::Decimal::new(100, 0)).to_u64())
-                        .unwrap_or_default()
-                } else {
-                    None
-                };
-                collected_values.payment_processed_amount_without_smart_retries_in_usd =
-                    amount_in_usd;
-                total_payment_processed_amount_without_smart_retries_in_usd +=
-                    amount_in_usd.unwrap_or(0);
+                total_payment_processed_amount_without_smart_retries_in_usd += collected_values
+                    .payment_processed_amount_without_smart_retries_in_usd
+                    .unwrap_or(0);
                 total_payment_processed_amount_without_smart_retries += amount;
             }
             if let Some(count) = collected_values.payment_processed_count_without_smart_retries {
@@ -358,6 +317,9 @@ pub async fn get_metrics(
             total_payment_processed_count_without_smart_retries: Some(
                 total_payment_processed_count_without_smart_retries,
             ),
+            reporting_currency: ex_rates
+                .as_ref()
+                .map(ReportingExchangeRates::get_reporting_currency),
         }],
     })
 }
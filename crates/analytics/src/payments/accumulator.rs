@@ -57,12 +57,15 @@ pub struct ProcessedAmountAccumulator {
     pub total_with_retries: Option<i64>,
     pub count_without_retries: Option<i64>,
     pub total_without_retries: Option<i64>,
+    pub total_with_retries_in_reporting_currency: Option<u64>,
+    pub total_without_retries_in_reporting_currency: Option<u64>,
 }
 
 #[derive(Debug, Default)]
 pub struct DebitRoutingAccumulator {
     pub transaction_count: u64,
     pub savings_amount: u64,
+    pub savings_amount_in_reporting_currency: Option<u64>,
 }
 
 #[derive(Debug, Default)]
@@ -206,11 +209,23 @@ impl PaymentMetricAccumulator for DebitRoutingAccumulator {
         (
             Some(self.transaction_count),
             Some(self.savings_amount),
-            Some(0),
+            self.savings_amount_in_reporting_currency,
         )
     }
 }
 
+impl DebitRoutingAccumulator {
+    /// Adds the savings of a metric row, converted to the reporting currency
+    pub fn add_reporting_amount(&mut self, savings_amount: Option<u64>) {
+        self.savings_amount_in_reporting_currency =
+            match (self.savings_amount_in_reporting_currency, savings_amount) {
+                (None, None) => None,
+                (None, i @ Some(_)) | (i @ Some(_), None) => i,
+                (Some(a), Some(b)) => Some(a + b),
+            };
+    }
+}
+
 impl PaymentMetricAccumulator for PaymentsDistributionAccumulator {
     type MetricOutput = (
         Option<f64>,
@@ -397,12 +412,33 @@ impl PaymentMetricAccumulator for ProcessedAmountAccumulator {
             count_with_retries,
             total_without_retries,
             count_without_retries,
-            Some(0),
-            Some(0),
+            self.total_with_retries_in_reporting_currency,
+            self.total_without_retries_in_reporting_currency,
         )
     }
 }
 
+impl ProcessedAmountAccumulator {
+    /// Adds the total of a metric row, converted to the reporting currency
+    pub fn add_reporting_amount(&mut self, metrics: &PaymentMetricRow, total: Option<u64>) {
+        self.total_with_retries_in_reporting_currency =
+            match (self.total_with_retries_in_reporting_currency, total) {
+                (None, None) => None,
+                (None, i @ Some(_)) | (i @ Some(_), None) => i,
+                (Some(a), Some(b)) => Some(a + b),
+            };
+
+        if metrics.first_attempt.unwrap_or(false) {
+            self.total_without_retries_in_reporting_currency =
+                match (self.total_without_retries_in_reporting_currency, total) {
+                    (None, None) => None,
+                    (None, i @ Some(_)) | (i @ Some(_), None) => i,
+                    (Some(a), Some(b)) => Some(a + b),
+                };
+        }
+    }
+}
+
 impl PaymentMetricAccumulator for RetriesAmountAccumulator {
     type MetricOutput = Option<u64>;
     fn add_metrics_bucket(&mut self, metrics: &PaymentMetricRow) {
@@ -497,6 +533,7 @@ impl PaymentMetricsAccumulator {
             debit_routed_transaction_count,
             debit_routing_savings,
             debit_routing_savings_in_usd,
+            reporting_currency: None,
         }
     }
 }
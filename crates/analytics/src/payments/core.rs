@@ -6,13 +6,10 @@ use api_models::analytics::{
         MetricsBucketResponse, PaymentDimensions, PaymentDistributions, PaymentMetrics,
         PaymentMetricsBucketIdentifier,
     },
-    FilterValue, GetPaymentFiltersRequest, GetPaymentMetricRequest, PaymentFiltersResponse,
-    PaymentsAnalyticsMetadata, PaymentsMetricsResponse,
+    FilterValue, ForexMetric, GetPaymentFiltersRequest, GetPaymentMetricRequest,
+    PaymentFiltersResponse, PaymentsAnalyticsMetadata, PaymentsMetricsResponse,
 };
-use bigdecimal::ToPrimitive;
-use common_enums::Currency;
 use common_utils::errors::CustomResult;
-use currency_conversion::{conversion::convert, types::ExchangeRates};
 use error_stack::ResultExt;
 use router_env::{
     instrument, logger,
@@ -28,6 +25,7 @@ use super::{
 use crate::{
     enums::AuthInfo,
     errors::{AnalyticsError, AnalyticsResult},
+    forex::{self, ReportingExchangeRates},
     metrics,
     payments::{PaymentDistributionAccumulator, PaymentMetricAccumulator},
     AnalyticsProvider,
@@ -48,7 +46,7 @@ pub enum TaskType {
 #[instrument(skip_all)]
 pub async fn get_metrics(
     pool: &AnalyticsProvider,
-    ex_rates: &Option<ExchangeRates>,
+    ex_rates: &Option<ReportingExchangeRates>,
     auth: &AuthInfo,
     req: GetPaymentMetricRequest,
 ) -> AnalyticsResult<PaymentsMetricsResponse<MetricsBucketResponse>> {
@@ -57,10 +55,17 @@ pub async fn get_metrics(
         PaymentMetricsAccumulator,
     > = HashMap::new();
 
+    let granularity = req.time_series.map(|t| t.granularity);
     let mut set = tokio::task::JoinSet::new();
     for metric_type in req.metrics.iter().cloned() {
         let req = req.clone();
         let pool = pool.clone();
+        // Amounts are grouped finer than requested to be converted close to their creation time
+        let query_granularity = if ex_rates.is_some() && metric_type.is_forex_metric() {
+            Some(forex::get_conversion_granularity(granularity))
+        } else {
+            granularity
+        };
         let task_span = tracing::debug_span!(
             "analytics_payments_metrics_query",
             payment_metric = metric_type.as_ref()
@@ -77,7 +82,7 @@ pub async fn get_metrics(
                         &req.group_by_names.clone(),
                         &auth_scoped,
                         &req.filters,
-                        req.time_series.map(|t| t.granularity),
+                        query_granularity,
                         &req.time_range,
                     )
                     .await
@@ -136,8 +141,24 @@ pub async fn get_metrics(
                     logger::debug!("Attributes: {:?}, Buckets fetched: {}", attributes, val);
                 }
 
-                for (id, value) in data {
+                let forex_rates = ex_rates.as_ref().filter(|_| metric.is_forex_metric());
+                for (mut id, value) in data {
                     logger::debug!(bucket_id=?id, bucket_value=?value, "Bucket row for metric {metric}");
+                    let mut reporting_amount = None;
+                    if let Some(ex_rates) = forex_rates {
+                        reporting_amount = ex_rates.convert_metric_total(
+                            value.total.as_ref(),
+                            id.currency,
+                            value.start_bucket.unwrap_or(id.start_time),
+                        );
+                        id.time_bucket = forex::get_requested_time_bucket(
+                            id.time_bucket,
+                            granularity,
+                            &req.time_range,
+                        )
+                        .change_context(AnalyticsError::UnknownError)?;
+                        id.start_time = id.time_bucket.start_time;
+                    }
                     let metrics_builder = metrics_accumulator.entry(id).or_default();
                     match metric {
                         PaymentMetrics::PaymentSuccessRate
@@ -153,7 +174,10 @@ pub async fn get_metrics(
                         }
                         PaymentMetrics::PaymentProcessedAmount
                         | PaymentMetrics::SessionizedPaymentProcessedAmount => {
-                            metrics_builder.processed_amount.add_metrics_bucket(&value)
+                            metrics_builder.processed_amount.add_metrics_bucket(&value);
+                            metrics_builder
+                                .processed_amount
+                                .add_reporting_amount(&value, reporting_amount);
                         }
                         PaymentMetrics::AvgTicketSize
                         | PaymentMetrics::SessionizedAvgTicketSize => {
@@ -173,6 +197,9 @@ pub async fn get_metrics(
                         }
                         PaymentMetrics::DebitRouting | PaymentMetrics::SessionizedDebitRouting => {
                             metrics_builder.debit_routing.add_metrics_bucket(&value);
+                            metrics_builder
+                                .debit_routing
+                                .add_reporting_amount(reporting_amount);
                         }
                         PaymentMetrics::PaymentsDistribution => {
                             metrics_builder
@@ -236,57 +263,23 @@ pub async fn get_metrics(
         .into_iter()
         .map(|(id, val)| {
             let mut collected_values = val.collect();
+            collected_values.reporting_currency = ex_rates
+                .as_ref()
+                .map(ReportingExchangeRates::get_reporting_currency);
             if let Some(amount) = collected_values.payment_processed_amount {
-                let amount_in_usd = if let Some(ex_rates) = ex_rates {
-                    id.currency
-                        .and_then(|currency| {
-                            i64::try_from(amount)
-                                .inspect_err(|e| logger::error!("Amount conversion error: {:?}", e))
-                                .ok()
-                                .and_then(|amount_i64| {
-                                    convert(ex_rates, currency, Currency::USD, amount_i64)
-                                        .inspect_err(|e| {
-                                            logger::error!("Currency conversion error: {:?}", e)
-                                        })
-                                        .ok()
-                                })
-                        })
-                        .map(|amount| (amount * rust_decimal::Decimal::new(100, 0)).to_u64())
-                        .unwrap_or_default()
-                } else {
-                    None
-                };
-                collected_values.payment_processed_amount_in_usd = amount_in_usd;
                 total_payment_processed_amount += amount;
-                total_payment_processed_amount_in_usd += amount_in_usd.unwrap_or(0);
+                total_payment_processed_amount_in_usd += collected_values
+                    .payment_processed_amount_in_usd
+                    .unwrap_or(0);
             }
             if let Some(count) = collected_values.payment_processed_count {
                 total_payment_processed_count += count;
             }
             if let Some(amount) = collected_values.payment_processed_amount_without_smart_retries {
-                let amount_in_usd = if let Some(ex_rates) = ex_rates {
-                    id.currency
-                        .and_then(|currency| {
-                            i64::try_from(amount)
-                                .inspect_err(|e| logger::error!("Amount conversion error: {:?}", e))
-                                .ok()
-                                .and_then(|amount_i64| {
-                                    convert(ex_rates, currency, Currency::USD, amount_i64)
-                                        .inspect_err(|e| {
-                                            logger::error!("Currency conversion error: {:?}", e)
-                                        })
-                                        .ok()
-                                })
-                        })
-                        .map(|amount| (amount * rust_decimal::Decimal::new(100, 0)).to_u64())
-                        .unwrap_or_default()
-                } else {
-                    None
-                };
-                collected_values.payment_processed_amount_without_smart_retries_usd = amount_in_usd;
                 total_payment_processed_amount_without_smart_retries += amount;
-                total_payment_processed_amount_without_smart_retries_usd +=
-                    amount_in_usd.unwrap_or(0);
+                total_payment_processed_amount_without_smart_retries_usd += collected_values
+                    .payment_processed_amount_without_smart_retries_usd
+                    .unwrap_or(0);
             }
             if let Some(count) = collected_values.payment_processed_count_without_smart_retries {
                 total_payment_processed_count_without_smart_retries += count;
@@ -297,33 +290,6 @@ pub async fn get_metrics(
             if let Some(count) = collected_values.failure_reason_count_without_smart_retries {
                 total_failure_reasons_count_without_smart_retries += count;
             }
-            if let Some(savings) = collected_values.debit_routing_savings {
-                let savings_in_usd = if let Some(ex_rates) = ex_rates {
-                    id.currency
-                        .and_then(|currency| {
-                            i64::try_from(savings)
-                                .inspect_err(|e| {
-                                    logger::error!(
-                                        "Debit Routing savings conversion error: {:?}",
-                                        e
-                                    )
-                                })
-                                .ok()
-                                .and_then(|savings_i64| {
-                                    convert(ex_rates, currency, Currency::USD, savings_i64)
-                                        .inspect_err(|e| {
-                                            logger::error!("Currency conversion error: {:?}", e)
-                                        })
-                                        .ok()
-                                })
-                        })
-                        .map(|savings| (savings * rust_decimal::Decimal::new(100, 0)).to_u64())
-                        .unwrap_or_default()
-                } else {
-                    None
-                };
-                collected_values.debit_routing_savings_in_usd = savings_in_usd;
-            }
             MetricsBucketResponse {
                 values: collected_values,
                 dimensions: id,
@@ -355,6 +321,9 @@ pub async fn get_metrics(
             total_failure_reasons_count_without_smart_retries: Some(
                 total_failure_reasons_count_without_smart_retries,
             ),
+            reporting_currency: ex_rates
+                .as_ref()
+                .map(ReportingExchangeRates::get_reporting_currency),
         }],
     })
 }
@@ -61,6 +61,7 @@ pub struct CountAccumulator {
 pub struct RefundProcessedAmountAccumulator {
     pub count: Option<i64>,
     pub total: Option<i64>,
+    pub total_in_reporting_currency: Option<u64>,
 }
 
 pub trait RefundMetricAccumulator {
@@ -198,7 +199,18 @@ impl RefundMetricAccumulator for RefundProcessedAmountAccumulator {
         let total = u64::try_from(self.total.unwrap_or_default()).ok();
         let count = self.count.and_then(|i| u64::try_from(i).ok());
 
-        (total, count, Some(0))
+        (total, count, self.total_in_reporting_currency)
+    }
+}
+
+impl RefundProcessedAmountAccumulator {
+    /// Adds the total of a metric row, converted to the reporting currency
+    pub fn add_reporting_amount(&mut self, total: Option<u64>) {
+        self.total_in_reporting_currency = match (self.total_in_reporting_currency, total) {
+            (None, None) => None,
+            (None, i @ Some(_)) | (i @ Some(_), None) => i,
+            (Some(a), Some(b)) => Some(a + b),
+        };
     }
 }
 
@@ -271,6 +283,7 @@ impl RefundMetricsAccumulator {
             refund_error_message_distribution: self.refund_error_message_distribution.collect(),
             refund_reason_count: self.refund_reason.collect(),
             refund_error_message_count: self.refund_error_message.collect(),
+            reporting_currency: None,
         }
     }
 }
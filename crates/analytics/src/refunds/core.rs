@@ -6,13 +6,10 @@ use api_models::analytics::{
         RefundDimensions, RefundDistributions, RefundMetrics, RefundMetricsBucketIdentifier,
         RefundMetricsBucketResponse,
     },
-    GetRefundFilterRequest, GetRefundMetricRequest, RefundFilterValue, RefundFiltersResponse,
-    RefundsAnalyticsMetadata, RefundsMetricsResponse,
+    ForexMetric, GetRefundFilterRequest, GetRefundMetricRequest, RefundFilterValue,
+    RefundFiltersResponse, RefundsAnalyticsMetadata, RefundsMetricsResponse,
 };
-use bigdecimal::ToPrimitive;
-use common_enums::Currency;
 use common_utils::errors::CustomResult;
-use currency_conversion::{conversion::convert, types::ExchangeRates};
 use error_stack::ResultExt;
 use router_env::{
     logger,
@@ -28,6 +25,7 @@ use super::{
 use crate::{
     enums::AuthInfo,
     errors::{AnalyticsError, AnalyticsResult},
+    forex::{self, ReportingExchangeRates},
     metrics,
     refunds::{accumulator::RefundDistributionAccumulator, RefundMetricAccumulator},
     AnalyticsProvider,
@@ -47,16 +45,23 @@ pub enum TaskType {
 
 pub async fn get_metrics(
     pool: &AnalyticsProvider,
-    ex_rates: &Option<ExchangeRates>,
+    ex_rates: &Option<ReportingExchangeRates>,
     auth: &AuthInfo,
     req: GetRefundMetricRequest,
 ) -> AnalyticsResult<RefundsMetricsResponse<RefundMetricsBucketResponse>> {
     let mut metrics_accumulator: HashMap<RefundMetricsBucketIdentifier, RefundMetricsAccumulator> =
         HashMap::new();
+    let granularity = req.time_series.map(|t| t.granularity);
     let mut set = tokio::task::JoinSet::new();
     for metric_type in req.metrics.iter().cloned() {
         let req = req.clone();
         let pool = pool.clone();
+        // Amounts are grouped finer than requested to be converted close to their creation time
+        let query_granularity = if ex_rates.is_some() && metric_type.is_forex_metric() {
+            Some(forex::get_conversion_granularity(granularity))
+        } else {
+            granularity
+        };
         let task_span = tracing::debug_span!(
             "analytics_refund_query",
             refund_metric = metric_type.as_ref()
@@ -72,7 +77,7 @@ pub async fn get_metrics(
                         &req.group_by_names.clone(),
                         &auth_scoped,
                         &req.filters,
-                        req.time_series.map(|t| t.granularity),
+                        query_granularity,
                         &req.time_range,
                     )
                     .await
@@ -131,8 +136,24 @@ pub async fn get_metrics(
                     logger::debug!("Attributes: {:?}, Buckets fetched: {}", attributes, val);
                 }
 
-                for (id, value) in data {
+                let forex_rates = ex_rates.as_ref().filter(|_| metric.is_forex_metric());
+                for (mut id, value) in data {
                     logger::debug!(bucket_id=?id, bucket_value=?value, "Bucket row for metric {metric}");
+                    let mut reporting_amount = None;
+                    if let Some(ex_rates) = forex_rates {
+                        reporting_amount = ex_rates.convert_metric_total(
+                            value.total.as_ref(),
+                            id.currency,
+                            value.start_bucket.unwrap_or(id.start_time),
+                        );
+                        id.time_bucket = forex::get_requested_time_bucket(
+                            id.time_bucket,
+                            granularity,
+                            &req.time_range,
+                        )
+                        .change_context(AnalyticsError::UnknownError)?;
+                        id.start_time = id.time_bucket.start_time;
+                    }
                     let metrics_builder = metrics_accumulator.entry(id).or_default();
                     match metric {
                         RefundMetrics::RefundSuccessRate
@@ -148,7 +169,10 @@ pub async fn get_metrics(
                         }
                         RefundMetrics::RefundProcessedAmount
                         | RefundMetrics::SessionizedRefundProcessedAmount => {
-                            metrics_builder.processed_amount.add_metrics_bucket(&value)
+                            metrics_builder.processed_amount.add_metrics_bucket(&value);
+                            metrics_builder
+                                .processed_amount
+                                .add_reporting_amount(reporting_amount);
                         }
                         RefundMetrics::SessionizedRefundReason => {
                             metrics_builder.refund_reason.add_metrics_bucket(&value)
@@ -210,6 +234,9 @@ pub async fn get_metrics(
         .into_iter()
         .map(|(id, val)| {
             let mut collected_values = val.collect();
+            collected_values.reporting_currency = ex_rates
+                .as_ref()
+                .map(ReportingExchangeRates::get_reporting_currency);
             if let Some(success_count) = collected_values.successful_refunds {
                 success += success_count;
             }
@@ -217,28 +244,9 @@ pub async fn get_metrics(
                 total += total_count;
             }
             if let Some(amount) = collected_values.refund_processed_amount {
-                let amount_in_usd = if let Some(ex_rates) = ex_rates {
-                    id.currency
-                        .and_then(|currency| {
-                            i64::try_from(amount)
-                                .inspect_err(|e| logger::error!("Amount conversion error: {:?}", e))
-                                .ok()
-                                .and_then(|amount_i64| {
-                                    convert(ex_rates, currency, Currency::USD, amount_i64)
-                                        .inspect_err(|e| {
-                                            logger::error!("Currency conversion error: {:?}", e)
-                                        })
-                                        .ok()
-                                })
-                        })
-                        .map(|amount| (amount * rust_decimal::Decimal::new(100, 0)).to_u64())
-                        .unwrap_or_default()
-                } else {
-                    None
-                };
-                collected_values.refund_processed_amount_in_usd = amount_in_usd;
                 total_refund_processed_amount += amount;
-                total_refund_processed_amount_in_usd += amount_in_usd.unwrap_or(0);
+                total_refund_processed_amount_in_usd +=
+                    collected_values.refund_processed_amount_in_usd.unwrap_or(0);
             }
             if let Some(count) = collected_values.refund_processed_count {
                 total_refund_processed_count += count;
@@ -272,6 +280,9 @@ pub async fn get_metrics(
             total_refund_processed_count: Some(total_refund_processed_count),
             total_refund_reason_count: Some(total_refund_reason_count),
             total_refund_error_message_count: Some(total_refund_error_message_count),
+            reporting_currency: ex_rates
+                .as_ref()
+                .map(ReportingExchangeRates::get_reporting_currency),
         }],
     })
 }
@@ -0,0 +1,600 @@
+//! In-process generation of payment, refund, dispute and authentication reports, as an
+//! alternative to invoking the report Lambda functions
+
+use std::{collections::HashMap, io::Write, sync::Arc};
+
+use api_models::analytics::{Granularity, ReportDomain, ReportFormat, TimeRange};
+use common_utils::errors::ParsingError;
+use error_stack::{report, ResultExt};
+use parquet::{
+    basic::{Compression, LogicalType, Repetition, Type as PhysicalType},
+    data_type::{ByteArray, ByteArrayType},
+    file::{properties::WriterProperties, writer::SerializedFileWriter},
+    schema::types::Type,
+};
+use time::PrimitiveDateTime;
+
+use crate::{
+    clickhouse::ClickhouseClient,
+    enums::AuthInfo,
+    errors::{AnalyticsError, AnalyticsResult},
+    query::{
+        Aggregate, FilterTypes, GroupByClause, Order, QueryBuilder, QueryFilter, ToSql, Window,
+    },
+    sqlx::SqlxClient,
+    types::{AnalyticsCollection, AnalyticsDataSource, LoadRow, TableEngine},
+    AnalyticsProvider,
+};
+
+pub trait ReportAnalytics: LoadRow<ReportRow> {}
+
+/// A report row, holding the value of every report column as text
+#[derive(Debug, serde::Deserialize)]
+#[serde(transparent)]
+pub struct ReportRow(pub(crate) HashMap<String, Option<String>>);
+
+/// A column selected as text, so that every column of a report can be read the same way
+/// irrespective of its type
+pub struct TextColumn(&'static str);
+
+impl ToSql<SqlxClient> for TextColumn {
+    fn to_sql(&self, _table_engine: &TableEngine) -> error_stack::Result<String, ParsingError> {
+        Ok(format!("{0}::text AS {0}", self.0))
+    }
+}
+
+impl ToSql<ClickhouseClient> for TextColumn {
+    fn to_sql(&self, _table_engine: &TableEngine) -> error_stack::Result<String, ParsingError> {
+        Ok(format!("toString({0}) AS {0}", self.0))
+    }
+}
+
+/// The columns rows are paged by, compared as text, the same way the rows are sorted
+struct CursorColumns(&'static str);
+
+impl ToSql<SqlxClient> for CursorColumns {
+    fn to_sql(&self, _table_engine: &TableEngine) -> error_stack::Result<String, ParsingError> {
+        Ok(format!("(created_at::text, {}::text)", self.0))
+    }
+}
+
+impl ToSql<ClickhouseClient> for CursorColumns {
+    fn to_sql(&self, _table_engine: &TableEngine) -> error_stack::Result<String, ParsingError> {
+        Ok(format!("(toString(created_at), toString({}))", self.0))
+    }
+}
+
+/// The `created_at` and ID of the last row read, the next batch starts after it
+struct ReportCursor {
+    created_at: String,
+    id: String,
+}
+
+impl ReportCursor {
+    fn from_row(row: &ReportRow, id_column: &str) -> AnalyticsResult<Self> {
+        let get_value = |column: &str| {
+            row.0
+                .get(column)
+                .cloned()
+                .flatten()
+                .ok_or(report!(AnalyticsError::UnknownError))
+                .attach_printable_lazy(|| format!("Report row is missing its {column}"))
+        };
+        Ok(Self {
+            created_at: get_value("created_at")?,
+            id: get_value(id_column)?,
+        })
+    }
+}
+
+impl ToSql<SqlxClient> for ReportCursor {
+    fn to_sql(&self, _table_engine: &TableEngine) -> error_stack::Result<String, ParsingError> {
+        let escape = |value: &str| value.replace('\'', "''");
+        Ok(format!(
+            "('{}', '{}')",
+            escape(&self.created_at),
+            escape(&self.id)
+        ))
+    }
+}
+
+impl ToSql<ClickhouseClient> for ReportCursor {
+    fn to_sql(&self, _table_engine: &TableEngine) -> error_stack::Result<String, ParsingError> {
+        let escape = |value: &str| value.replace('\\', "\\\\").replace('\'', "\\'");
+        Ok(format!(
+            "('{}', '{}')",
+            escape(&self.created_at),
+            escape(&self.id)
+        ))
+    }
+}
+
+/// A generated report file
+#[derive(Debug)]
+pub struct GeneratedReport<W> {
+    pub output: W,
+    pub row_count: usize,
+}
+
+struct ReportDefinition {
+    collection: AnalyticsCollection,
+    /// Column identifying a row, used along with `created_at` to page through the rows in a
+    /// stable order
+    id_column: &'static str,
+    columns: &'static [&'static str],
+}
+
+fn get_report_definition(domain: ReportDomain) -> AnalyticsResult<ReportDefinition> {
+    match domain {
+        ReportDomain::Payments => Ok(ReportDefinition {
+            collection: AnalyticsCollection::Payment,
+            id_column: "attempt_id",
+            columns: &[
+                "payment_id",
+                "attempt_id",
+                "merchant_id",
+                "profile_id",
+                "status",
+                "amount",
+                "currency",
+                "connector",
+                "merchant_connector_id",
+                "payment_method",
+                "payment_method_type",
+                "connector_transaction_id",
+                "capture_method",
+                "authentication_type",
+                "error_code",
+                "error_message",
+                "created_at",
+                "modified_at",
+            ],
+        }),
+        ReportDomain::Refunds => Ok(ReportDefinition {
+            collection: AnalyticsCollection::Refund,
+            id_column: "refund_id",
+            columns: &[
+                "refund_id",
+                "payment_id",
+                "merchant_id",
+                "profile_id",
+                "connector",
+                "connector_refund_id",
+                "refund_type",
+                "total_amount",
+                "refund_amount",
+                "currency",
+                "refund_status",
+                "refund_reason",
+                "refund_error_code",
+                "refund_error_message",
+                "created_at",
+                "modified_at",
+            ],
+        }),
+        ReportDomain::Disputes => Ok(ReportDefinition {
+            collection: AnalyticsCollection::Dispute,
+            id_column: "dispute_id",
+            columns: &[
+                "dispute_id",
+                "payment_id",
+                "attempt_id",
+                "merchant_id",
+                "profile_id",
+                "connector",
+                "dispute_amount",
+                "currency",
+                "dispute_stage",
+                "dispute_status",
+                "connector_status",
+                "connector_dispute_id",
+                "connector_reason",
+                "connector_reason_code",
+                "challenge_required_by",
+                "created_at",
+                "modified_at",
+            ],
+        }),
+        ReportDomain::Authentications => Ok(ReportDefinition {
+            collection: AnalyticsCollection::Authentications,
+            id_column: "authentication_id",
+            columns: &[
+                "authentication_id",
+                "payment_id",
+                "merchant_id",
+                "profile_id",
+                "authentication_connector",
+                "authentication_type",
+                "authentication_status",
+                "authentication_lifecycle_status",
+                "trans_status",
+                "eci",
+                "message_version",
+                "error_code",
+                "error_message",
+                "created_at",
+                "modified_at",
+            ],
+        }),
+        ReportDomain::Payouts => Err(report!(AnalyticsError::NotImplemented(
+            "In-process payout reports"
+        ))),
+    }
+}
+
+/// Generates the report by reading the rows in batches of `batch_size` from the analytics
+/// provider and writing them to `output` as they are read, so that only a single batch is held in
+/// memory at a time
+pub async fn generate_report<W: Write + Send>(
+    pool: &AnalyticsProvider,
+    domain: ReportDomain,
+    auth: &AuthInfo,
+    time_range: &TimeRange,
+    format: ReportFormat,
+    batch_size: u64,
+    output: W,
+) -> AnalyticsResult<GeneratedReport<W>> {
+    let definition = get_report_definition(domain)?;
+    let mut writer = ReportWriter::new(format, definition.columns, output)?;
+
+    let row_count = match pool {
+        AnalyticsProvider::Sqlx(pool) | AnalyticsProvider::CombinedSqlx(pool, _) => {
+            write_report_rows(pool, &definition, auth, time_range, batch_size, &mut writer).await
+        }
+        AnalyticsProvider::Clickhouse(pool) | AnalyticsProvider::CombinedCkh(_, pool) => {
+            write_report_rows(pool, &definition, auth, time_range, batch_size, &mut writer).await
+        }
+    }?;
+
+    Ok(GeneratedReport {
+        output: writer.finish()?,
+        row_count,
+    })
+}
+
+/// Reads the rows a batch at a time, starting each batch after the last row of the previous one
+async fn write_report_rows<T, W: Write + Send>(
+    pool: &T,
+    definition: &ReportDefinition,
+    auth: &AuthInfo,
+    time_range: &TimeRange,
+    batch_size: u64,
+    writer: &mut ReportWriter<W>,
+) -> AnalyticsResult<usize>
+where
+    T: AnalyticsDataSource + ReportAnalytics,
+    PrimitiveDateTime: ToSql<T>,
+    AnalyticsCollection: ToSql<T>,
+    Granularity: GroupByClause<T>,
+    Aggregate<&'static str>: ToSql<T>,
+    Window<&'static str>: ToSql<T>,
+    TextColumn: ToSql<T>,
+    CursorColumns: ToSql<T>,
+    ReportCursor: ToSql<T>,
+{
+    let mut row_count = 0;
+    let mut cursor = None;
+    loop {
+        let query = build_report_query::<T>(definition, auth, time_range, cursor.take())?;
+        let rows: Vec<ReportRow> = pool
+            .load_results(&format!("{query} LIMIT {batch_size}"))
+            .await
+            .change_context(AnalyticsError::UnknownError)
+            .attach_printable("Failed to load report rows")?;
+        let is_last_batch = u64::try_from(rows.len()).map_or(true, |count| count < batch_size);
+        cursor = rows
+            .last()
+            .map(|row| ReportCursor::from_row(row, definition.id_column))
+            .transpose()?;
+
+        row_count += rows.len();
+        writer.write_rows(rows)?;
+
+        if is_last_batch {
+            break;
+        }
+    }
+
+    Ok(row_count)
+}
+
+fn build_report_query<T>(
+    definition: &ReportDefinition,
+    auth: &AuthInfo,
+    time_range: &TimeRange,
+    cursor: Option<ReportCursor>,
+) -> AnalyticsResult<String>
+where
+    T: AnalyticsDataSource,
+    PrimitiveDateTime: ToSql<T>,
+    AnalyticsCollection: ToSql<T>,
+    Granularity: GroupByClause<T>,
+    Aggregate<&'static str>: ToSql<T>,
+    Window<&'static str>: ToSql<T>,
+    TextColumn: ToSql<T>,
+    CursorColumns: ToSql<T>,
+    ReportCursor: ToSql<T>,
+{
+    let mut query_builder: QueryBuilder<T> = QueryBuilder::new(definition.collection);
+
+    for column in definition.columns {
+        query_builder
+            .add_select_column(TextColumn(*column))
+            .change_context(AnalyticsError::UnknownError)?;
+    }
+    time_range
+        .set_filter_clause(&mut query_builder)
+        .attach_printable("Error filtering time range")
+        .change_context(AnalyticsError::UnknownError)?;
+    auth.set_filter_clause(&mut query_builder)
+        .change_context(AnalyticsError::UnknownError)?;
+    if let Some(cursor) = cursor {
+        query_builder
+            .add_custom_filter_clause(CursorColumns(definition.id_column), cursor, FilterTypes::Gt)
+            .change_context(AnalyticsError::UnknownError)?;
+    }
+
+    // Grouping by every column keeps only the latest version of rows in collapsing tables
+    if let TableEngine::CollapsingMergeTree { .. } = T::get_table_engine(definition.collection) {
+        for column in definition.columns {
+            query_builder
+                .add_group_by_clause(*column)
+                .change_context(AnalyticsError::UnknownError)?;
+        }
+    }
+    query_builder
+        .add_order_by_clause("created_at", Order::Ascending)
+        .change_context(AnalyticsError::UnknownError)?;
+    query_builder
+        .add_order_by_clause(definition.id_column, Order::Ascending)
+        .change_context(AnalyticsError::UnknownError)?;
+
+    query_builder
+        .build_query()
+        .change_context(AnalyticsError::UnknownError)
+        .attach_printable("Failed to build report query")
+}
+
+enum ReportWriter<W: Write + Send> {
+    Csv(csv::Writer<W>, &'static [&'static str]),
+    Parquet(SerializedFileWriter<W>, &'static [&'static str]),
+}
+
+impl<W: Write + Send> ReportWriter<W> {
+    fn new(
+        format: ReportFormat,
+        columns: &'static [&'static str],
+        output: W,
+    ) -> AnalyticsResult<Self> {
+        match format {
+            ReportFormat::Csv => {
+                let mut writer = csv::Writer::from_writer(output);
+                writer
+                    .write_record(columns)
+                    .change_context(AnalyticsError::UnknownError)
+                    .attach_printable("Failed to write report header")?;
+                Ok(Self::Csv(writer, columns))
+            }
+            ReportFormat::Parquet => {
+                let fields = columns
+                    .iter()
+                    .map(|column| {
+                        Type::primitive_type_builder(column, PhysicalType::BYTE_ARRAY)
+                            .with_repetition(Repetition::OPTIONAL)
+                            .with_logical_type(Some(LogicalType::String))
+                            .build()
+                            .map(Arc::new)
+                    })
+                    .collect::<Result<Vec<_>, _>>()
+                    .change_context(AnalyticsError::UnknownError)
+                    .attach_printable("Failed to build report schema")?;
+                let schema = Type::group_type_builder("report")
+                    .with_fields(fields)
+                    .build()
+                    .change_context(AnalyticsError::UnknownError)
+                    .attach_printable("Failed to build report schema")?;
+                let properties = WriterProperties::builder()
+                    .set_compression(Compression::SNAPPY)
+                    .build();
+                let writer =
+                    SerializedFileWriter::new(output, Arc::new(schema), Arc::new(properties))
+                        .change_context(AnalyticsError::UnknownError)
+                        .attach_printable("Failed to create report writer")?;
+                Ok(Self::Parquet(writer, columns))
+            }
+        }
+    }
+
+    fn write_rows(&mut self, rows: Vec<ReportRow>) -> AnalyticsResult<()> {
+        match self {
+            Self::Csv(writer, columns) => rows.into_iter().try_for_each(|ReportRow(mut row)| {
+                let record = columns
+                    .iter()
+                    .map(|column| row.remove(*column).flatten().unwrap_or_default());
+                writer
+                    .write_record(record)
+                    .change_context(AnalyticsError::UnknownError)
+                    .attach_printable("Failed to write report row")
+            }),
+            // Every batch is written as a row group of its own, so that rows are encoded as they are read
+            Self::Parquet(writer, columns) => {
+                if rows.is_empty() {
+                    return Ok(());
+                }
+                let row_count = rows.len();
+                let mut rows = rows
+                    .into_iter()
+                    .map(|ReportRow(row)| row)
+                    .collect::<Vec<_>>();
+                let mut row_group = writer
+                    .next_row_group()
+                    .change_context(AnalyticsError::UnknownError)?;
+                for column in columns.iter() {
+                    let (values, definition_levels) = rows.iter_mut().fold(
+                        (Vec::new(), Vec::with_capacity(row_count)),
+                        |(mut values, mut definition_levels), row| {
+                            match row.remove(*column).flatten() {
+                                Some(value) => {
+                                    values.push(ByteArray::from(value.into_bytes()));
+                                    definition_levels.push(1);
+                                }
+                                None => definition_levels.push(0),
+                            }
+                            (values, definition_levels)
+                        },
+                    );
+                    let mut column_writer = row_group
+                        .next_column()
+                        .change_context(AnalyticsError::UnknownError)?
+                        .ok_or(AnalyticsError::UnknownError)
+                        .attach_printable("Report schema has fewer columns than the report")?;
+                    column_writer
+                        .typed::<ByteArrayType>()
+                        .write_batch(&values, Some(&definition_levels), None)
+                        .change_context(AnalyticsError::UnknownError)
+                        .attach_printable("Failed to write report column")?;
+                    column_writer
+                        .close()
+                        .change_context(AnalyticsError::UnknownError)?;
+                }
+                row_group
+                    .close()
+                    .map(|_| ())
+                    .change_context(AnalyticsError::UnknownError)
+            }
+        }
+    }
+
+    fn finish(self) -> AnalyticsResult<W> {
+        match self {
+            Self::Csv(writer, _) => writer
+                .into_inner()
+                .map_err(|error| report!(error.into_error()))
+                .change_context(AnalyticsError::UnknownError)
+                .attach_printable("Failed to flush report"),
+            Self::Parquet(writer, _) => writer
+                .into_inner()
+                .change_context(AnalyticsError::UnknownError)
+                .attach_printable("Failed to write report footer"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use parquet::{
+        file::reader::{FileReader, SerializedFileReader},
+        record::Field,
+    };
+
+    use super::*;
+
+    const COLUMNS: &[&str] = &["payment_id", "amount", "error_message"];
+
+    fn get_rows() -> Vec<ReportRow> {
+        vec![
+            ReportRow(HashMap::from([
+                ("payment_id".to_string(), Some("pay_1".to_string())),
+                ("amount".to_string(), Some("100".to_string())),
+                ("error_message".to_string(), None),
+            ])),
+            ReportRow(HashMap::from([
+                ("payment_id".to_string(), Some("pay_2".to_string())),
+                ("amount".to_string(), Some("200".to_string())),
+                (
+                    "error_message".to_string(),
+                    Some("Card declined, \"do not honor\"".to_string()),
+                ),
+            ])),
+        ]
+    }
+
+    #[test]
+    fn test_csv_report_writer() {
+        let mut writer = ReportWriter::new(ReportFormat::Csv, COLUMNS, Vec::new()).unwrap();
+        let mut rows = get_rows();
+        let last_row = rows.pop().unwrap();
+        writer.write_rows(rows).unwrap();
+        writer.write_rows(Vec::new()).unwrap();
+        writer.write_rows(vec![last_row]).unwrap();
+
+        let output = String::from_utf8(writer.finish().unwrap()).unwrap();
+        assert_eq!(
+            output,
+            "payment_id,amount,error_message\n\
+             pay_1,100,\n\
+             pay_2,200,\"Card declined, \"\"do not honor\"\"\"\n"
+        );
+    }
+
+    #[test]
+    fn test_parquet_report_writer() {
+        let path = std::env::temp_dir().join(format!(
+            "test_parquet_report_writer_{}.parquet",
+            std::process::id()
+        ));
+        let mut writer = ReportWriter::new(
+            ReportFormat::Parquet,
+            COLUMNS,
+            std::fs::File::create(&path).unwrap(),
+        )
+        .unwrap();
+        let mut rows = get_rows();
+        let last_row = rows.pop().unwrap();
+        writer.write_rows(rows).unwrap();
+        writer.write_rows(Vec::new()).unwrap();
+        writer.write_rows(vec![last_row]).unwrap();
+        writer.finish().unwrap();
+
+        let reader = SerializedFileReader::new(std::fs::File::open(&path).unwrap()).unwrap();
+        assert_eq!(reader.metadata().num_row_groups(), 2);
+        let rows = reader
+            .get_row_iter(None)
+            .unwrap()
+            .map(|row| {
+                row.unwrap()
+                    .get_column_iter()
+                    .map(|(name, field)| (name.clone(), field.clone()))
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(
+            rows,
+            vec![
+                vec![
+                    ("payment_id".to_string(), Field::Str("pay_1".to_string())),
+                    ("amount".to_string(), Field::Str("100".to_string())),
+                    ("error_message".to_string(), Field::Null),
+                ],
+                vec![
+                    ("payment_id".to_string(), Field::Str("pay_2".to_string())),
+                    ("amount".to_string(), Field::Str("200".to_string())),
+                    (
+                        "error_message".to_string(),
+                        Field::Str("Card declined, \"do not honor\"".to_string()),
+                    ),
+                ],
+            ]
+        );
+    }
+
+    #[test]
+    fn test_report_cursor_escaping() {
+        let cursor = ReportCursor {
+            created_at: "2024-01-01 10:00:00".to_string(),
+            id: "pay_'1\\".to_string(),
+        };
+        assert_eq!(
+            ToSql::<SqlxClient>::to_sql(&cursor, &TableEngine::BasicTree).unwrap(),
+            "('2024-01-01 10:00:00', 'pay_''1\\')"
+        );
+        assert_eq!(
+            ToSql::<ClickhouseClient>::to_sql(&cursor, &TableEngine::BasicTree).unwrap(),
+            "('2024-01-01 10:00:00', 'pay_\\'1\\\\')"
+        );
+    }
+}
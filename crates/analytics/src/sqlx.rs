@@ -18,7 +18,7 @@ use diesel_models::enums::{
 use error_stack::ResultExt;
 use sqlx::{
     postgres::{PgArgumentBuffer, PgPoolOptions, PgRow, PgTypeInfo, PgValueRef},
-    Decode, Encode,
+    Column, Decode, Encode,
     Error::ColumnNotFound,
     FromRow, Pool, Postgres, Row,
 };
@@ -169,6 +169,7 @@ impl super::frm::metrics::FrmMetricAnalytics for SqlxClient {}
 impl super::frm::filters::FrmFilterAnalytics for SqlxClient {}
 impl super::auth_events::metrics::AuthEventMetricAnalytics for SqlxClient {}
 impl super::auth_events::filters::AuthEventFilterAnalytics for SqlxClient {}
+impl super::reports::ReportAnalytics for SqlxClient {}
 
 #[async_trait::async_trait]
 impl AnalyticsDataSource for SqlxClient {
@@ -1434,6 +1435,19 @@ impl<'a> FromRow<'a, PgRow> for super::disputes::metrics::DisputeMetricRow {
     }
 }
 
+impl<'a> FromRow<'a, PgRow> for super::reports::ReportRow {
+    fn from_row(row: &'a PgRow) -> sqlx::Result<Self> {
+        row.columns()
+            .iter()
+            .map(|column| {
+                row.try_get::<Option<String>, _>(column.ordinal())
+                    .map(|value| (column.name().to_owned(), value))
+            })
+            .collect::<sqlx::Result<_>>()
+            .map(Self)
+    }
+}
+
 impl ToSql<SqlxClient> for PrimitiveDateTime {
     fn to_sql(&self, _table_engine: &TableEngine) -> error_stack::Result<String, ParsingError> {
         Ok(self.to_string())
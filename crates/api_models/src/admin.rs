@@ -19,7 +19,10 @@ use utoipa::ToSchema;
 
 use super::payments::AddressDetails;
 use crate::{
-    consts::{MAX_ORDER_FULFILLMENT_EXPIRY, MIN_ORDER_FULFILLMENT_EXPIRY},
+    consts::{
+        MAX_ORDER_FULFILLMENT_EXPIRY, MAX_VELOCITY_RULE_WINDOW_IN_MINS,
+        MIN_ORDER_FULFILLMENT_EXPIRY,
+    },
     enums as api_enums, payment_methods,
 };
 #[cfg(feature = "v1")]
@@ -267,6 +270,63 @@ pub struct CardTestingGuardConfig {
     pub customer_id_blocking_threshold: i32,
     /// Determines Redis Expiry for Card Testing Guard for profile
     pub card_testing_guard_expiry: i32,
+    /// Velocity rules evaluated for card payments of the profile, in addition to the blocking
+    /// checks above
+    #[serde(default)]
+    pub velocity_rules: Vec<VelocityRule>,
+}
+
+impl CardTestingGuardConfig {
+    pub fn validate(&self) -> Result<(), String> {
+        let mut rule_names = HashSet::new();
+        for velocity_rule in &self.velocity_rules {
+            if velocity_rule.name.trim().is_empty() {
+                return Err("`name` of a velocity rule cannot be empty".to_string());
+            }
+            if !rule_names.insert(velocity_rule.name.as_str()) {
+                return Err(format!(
+                    "Duplicate velocity rule name provided: {}",
+                    velocity_rule.name
+                ));
+            }
+            if velocity_rule.max_attempts == 0 {
+                return Err(format!(
+                    "`max_attempts` of velocity rule {} must be greater than zero",
+                    velocity_rule.name
+                ));
+            }
+            if velocity_rule.window_in_mins == 0
+                || velocity_rule.window_in_mins > MAX_VELOCITY_RULE_WINDOW_IN_MINS
+            {
+                return Err(format!(
+                    "`window_in_mins` of velocity rule {} must be between 1 and {MAX_VELOCITY_RULE_WINDOW_IN_MINS}",
+                    velocity_rule.name
+                ));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// A rule limiting the number of card payment attempts within a sliding window, for the attempts
+/// grouped by an attribute of the payment
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema)]
+pub struct VelocityRule {
+    /// Name of the rule, unique within the profile, which is reported when the rule is hit
+    #[schema(example = "max_5_attempts_per_ip_in_10_mins")]
+    pub name: String,
+    /// The attribute of the payment by which attempts are grouped
+    #[schema(value_type = VelocityRuleGroupBy)]
+    pub group_by: common_enums::VelocityRuleGroupBy,
+    /// The maximum number of attempts allowed within the window
+    #[schema(example = 5)]
+    pub max_attempts: u32,
+    /// The duration of the sliding window in minutes
+    #[schema(example = 10)]
+    pub window_in_mins: u32,
+    /// The action taken when the number of attempts within the window exceeds `max_attempts`
+    #[schema(value_type = VelocityRuleAction)]
+    pub action: common_enums::VelocityRuleAction,
 }
 
 #[derive(Debug, Clone, serde::Deserialize, serde::Serialize, ToSchema)]
@@ -729,9 +789,97 @@ pub struct WebhookDetails {
     #[cfg(feature = "payouts")]
     #[schema(value_type = Option<Vec<PayoutStatus>>, example = json!(["success", "failed"]))]
     pub payout_statuses_enabled: Option<Vec<api_enums::PayoutStatus>>,
+
+    /// Additional webhook endpoints to which webhooks are delivered, alongside `webhook_url`
+    pub multiple_webhooks_list: Option<Vec<MultipleWebhookDetail>>,
+}
+
+#[derive(Clone, Debug, Deserialize, ToSchema, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct MultipleWebhookDetail {
+    /// The identifier for the webhook endpoint. A new identifier is generated if not provided.
+    #[schema(value_type = Option<String>, max_length = 64, example = "whe_NJ4wsHn3cwN3bm2ZfRDg")]
+    pub webhook_endpoint_id: Option<id_type::WebhookEndpointId>,
+
+    /// The url for the webhook endpoint
+    #[schema(value_type = String, example = "www.ekart.com/webhooks/refunds")]
+    pub webhook_url: Secret<String>,
+
+    /// The key used to sign webhooks delivered to this endpoint. The `payment_response_hash_key`
+    /// of the profile is used if not provided. The secret is returned in full only when the
+    /// profile is created, and masked in all other responses.
+    #[schema(value_type = Option<String>, max_length = 255)]
+    pub webhook_secret: Option<Secret<String>>,
+
+    /// The event types delivered to this endpoint. All event types are delivered if empty.
+    #[schema(value_type = Vec<EventType>, example = json!(["refund_succeeded", "refund_failed"]))]
+    #[serde(default)]
+    pub events: HashSet<api_enums::EventType>,
+
+    /// The status of the webhook endpoint. Webhooks are delivered only to `active` endpoints.
+    #[schema(value_type = Option<OutgoingWebhookEndpointStatus>, example = "active")]
+    pub status: Option<api_enums::OutgoingWebhookEndpointStatus>,
+}
+
+impl MultipleWebhookDetail {
+    /// Retains the webhook secret of existing endpoints which are updated without a secret, or
+    /// with the masked secret returned when retrieving the profile
+    fn merge_list(existing: Vec<Self>, updated: Vec<Self>) -> Vec<Self> {
+        updated
+            .into_iter()
+            .map(|endpoint| {
+                let existing_secret = endpoint
+                    .webhook_endpoint_id
+                    .as_ref()
+                    .and_then(|webhook_endpoint_id| {
+                        existing.iter().find(|existing_endpoint| {
+                            existing_endpoint.webhook_endpoint_id.as_ref()
+                                == Some(webhook_endpoint_id)
+                        })
+                    })
+                    .and_then(|existing_endpoint| existing_endpoint.webhook_secret.clone());
+
+                let updated_secret = endpoint.webhook_secret.filter(|updated_secret| {
+                    existing_secret.as_ref().is_none_or(|existing_secret| {
+                        MaskedHeaders::mask_value(existing_secret.peek()) != *updated_secret.peek()
+                    })
+                });
+
+                Self {
+                    webhook_secret: updated_secret.or(existing_secret),
+                    ..endpoint
+                }
+            })
+            .collect()
+    }
+
+    /// Masks the webhook secret, such that it is returned in full only when the endpoint is
+    /// created
+    fn mask_webhook_secret(self) -> Self {
+        Self {
+            webhook_secret: self
+                .webhook_secret
+                .map(|secret| Secret::new(MaskedHeaders::mask_value(secret.peek()))),
+            ..self
+        }
+    }
 }
 
 impl WebhookDetails {
+    /// Masks the secrets of the webhook endpoints, for responses other than the one returned when
+    /// the endpoints are created
+    pub fn mask_webhook_secrets(self) -> Self {
+        Self {
+            multiple_webhooks_list: self.multiple_webhooks_list.map(|webhook_endpoints| {
+                webhook_endpoints
+                    .into_iter()
+                    .map(MultipleWebhookDetail::mask_webhook_secret)
+                    .collect()
+            }),
+            ..self
+        }
+    }
+
     pub fn merge(self, other: Self) -> Self {
         Self {
             webhook_version: other.webhook_version.or(self.webhook_version),
@@ -755,6 +903,15 @@ impl WebhookDetails {
             payout_statuses_enabled: other
                 .payout_statuses_enabled
                 .or(self.payout_statuses_enabled),
+            multiple_webhooks_list: match (
+                self.multiple_webhooks_list,
+                other.multiple_webhooks_list,
+            ) {
+                (Some(existing), Some(updated)) => {
+                    Some(MultipleWebhookDetail::merge_list(existing, updated))
+                }
+                (existing, updated) => updated.or(existing),
+            },
         }
     }
 
@@ -791,10 +948,84 @@ impl WebhookDetails {
             }
         }
 
+        if let Some(webhook_endpoints) = &self.multiple_webhooks_list {
+            let mut webhook_endpoint_ids = HashSet::new();
+            for webhook_endpoint in webhook_endpoints {
+                if webhook_endpoint.webhook_url.peek().trim().is_empty() {
+                    return Err("`webhook_url` of a webhook endpoint cannot be empty".to_string());
+                }
+                if let Some(webhook_endpoint_id) = &webhook_endpoint.webhook_endpoint_id {
+                    if !webhook_endpoint_ids.insert(webhook_endpoint_id) {
+                        return Err(format!(
+                            "Duplicate webhook endpoint provided: {}",
+                            webhook_endpoint_id.get_string_repr()
+                        ));
+                    }
+                }
+            }
+        }
+
         Ok(())
     }
 }
 
+/// The request body for rotating the key used to sign outgoing webhooks for a profile
+#[derive(Clone, Debug, Default, Deserialize, ToSchema, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct WebhookSigningKeyRotateRequest {
+    /// The duration in seconds for which the current signing key continues to sign webhooks
+    /// alongside the new key. Defaults to 24 hours.
+    #[schema(example = 86400, maximum = 2592000)]
+    pub overlap_window_in_secs: Option<u32>,
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct WebhookSigningKeyRotateRequestInternal {
+    pub profile_id: id_type::ProfileId,
+    pub request: WebhookSigningKeyRotateRequest,
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct WebhookSigningKeyRetireRequest {
+    pub profile_id: id_type::ProfileId,
+    pub key_id: String,
+}
+
+/// The response body for a key used to sign outgoing webhooks
+#[derive(Clone, Debug, Serialize, ToSchema)]
+pub struct WebhookSigningKeyResponse {
+    /// The identifier for the signing key
+    #[schema(max_length = 64, example = "whsk_2R3GquQgKCSNFUJZVWg2")]
+    pub key_id: String,
+
+    /// The identifier for the profile
+    #[schema(value_type = String, max_length = 64, example = "pro_abcdefghijklmnopqrstuvwxyz")]
+    pub profile_id: id_type::ProfileId,
+
+    /// The version of the signing key, incremented on every rotation
+    #[schema(example = 2)]
+    pub version: i32,
+
+    /// The status of the signing key
+    #[schema(value_type = WebhookSigningKeyStatus)]
+    pub status: api_enums::WebhookSigningKeyStatus,
+
+    /// The secret of the signing key. This is only returned when the key is created.
+    #[schema(value_type = Option<String>)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub secret: Option<Secret<String>>,
+
+    /// The time at which the signing key was created
+    #[schema(example = "2024-01-01T00:00:00Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: time::PrimitiveDateTime,
+
+    /// The time after which the signing key is no longer used to sign webhooks
+    #[schema(example = "2024-01-02T00:00:00Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub expires_at: Option<time::PrimitiveDateTime>,
+}
+
 #[derive(Debug, Serialize, ToSchema)]
 pub struct MerchantAccountDeleteResponse {
     /// The identifier for the Merchant Account
@@ -2014,6 +2245,53 @@ pub struct TransferKeyResponse {
     #[schema(example = 32)]
     pub total_transferred: usize,
 }
+
+/// Stage of a key rotation, the records of each stage are re-encrypted in batches
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum KeyRotationStage {
+    /// Merchant key stores are being re-encrypted with the current master key
+    KeyStores,
+    /// Customers are being re-encrypted with the new merchant key
+    Customers,
+    /// Addresses are being re-encrypted with the new merchant key
+    Addresses,
+    /// Payment methods are being re-encrypted with the new merchant key
+    PaymentMethods,
+    /// All records have been re-encrypted
+    Completed,
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum KeyRotationStatus {
+    InProgress,
+    Completed,
+    /// The rotation stopped after repeated failures, starting it again resumes from its progress
+    Failed,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct KeyRotationResponse {
+    /// The identifier of the background task re-encrypting the records
+    pub rotation_id: String,
+    /// The merchant whose data encryption key is rotated, absent for a master key rotation
+    #[schema(value_type = Option<String>)]
+    pub merchant_id: Option<id_type::MerchantId>,
+    /// Version of the merchant data encryption key the records are re-encrypted with
+    #[schema(example = 2)]
+    pub key_version: Option<i32>,
+    pub status: KeyRotationStatus,
+    pub stage: KeyRotationStage,
+    /// Number of merchant key stores re-encrypted so far
+    pub key_stores_re_encrypted: u64,
+    /// Number of customers re-encrypted so far
+    pub customers_re_encrypted: u64,
+    /// Number of addresses re-encrypted so far
+    pub addresses_re_encrypted: u64,
+    /// Number of payment methods re-encrypted so far
+    pub payment_methods_re_encrypted: u64,
+}
 #[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct ToggleKVRequest {
     #[serde(skip_deserializing)]
@@ -3578,3 +3856,83 @@ impl std::ops::Deref for TtlForExtendedCardInfo {
         &self.0
     }
 }
+
+#[cfg(test)]
+mod card_testing_guard_config_tests {
+    use super::*;
+
+    fn velocity_rule(name: &str, max_attempts: u32, window_in_mins: u32) -> VelocityRule {
+        VelocityRule {
+            name: name.to_string(),
+            group_by: common_enums::VelocityRuleGroupBy::Ip,
+            max_attempts,
+            window_in_mins,
+            action: common_enums::VelocityRuleAction::Block,
+        }
+    }
+
+    fn card_testing_guard_config(velocity_rules: Vec<VelocityRule>) -> CardTestingGuardConfig {
+        CardTestingGuardConfig {
+            card_ip_blocking_status: CardTestingGuardStatus::Disabled,
+            card_ip_blocking_threshold: 3,
+            guest_user_card_blocking_status: CardTestingGuardStatus::Disabled,
+            guest_user_card_blocking_threshold: 10,
+            customer_id_blocking_status: CardTestingGuardStatus::Disabled,
+            customer_id_blocking_threshold: 5,
+            card_testing_guard_expiry: 3600,
+            velocity_rules,
+        }
+    }
+
+    #[test]
+    fn test_validate_accepts_valid_rules() {
+        let config = card_testing_guard_config(vec![
+            velocity_rule("per_ip", 5, 10),
+            velocity_rule("per_ip_daily", 20, MAX_VELOCITY_RULE_WINDOW_IN_MINS),
+        ]);
+
+        assert!(config.validate().is_ok());
+    }
+
+    #[test]
+    fn test_validate_rejects_zero_window() {
+        let config = card_testing_guard_config(vec![velocity_rule("per_ip", 5, 0)]);
+
+        assert!(config.validate().is_err());
+    }
+
+    #[test]
+    fn test_validate_rejects_window_above_maximum() {
+        let config = card_testing_guard_config(vec![velocity_rule(
+            "per_ip",
+            5,
+            MAX_VELOCITY_RULE_WINDOW_IN_MINS + 1,
+        )]);
+
+        assert!(config.validate().is_err());
+    }
+
+    #[test]
+    fn test_validate_rejects_zero_attempts() {
+        let config = card_testing_guard_config(vec![velocity_rule("per_ip", 0, 10)]);
+
+        assert!(config.validate().is_err());
+    }
+
+    #[test]
+    fn test_validate_rejects_duplicate_names() {
+        let config = card_testing_guard_config(vec![
+            velocity_rule("per_ip", 5, 10),
+            velocity_rule("per_ip", 10, 60),
+        ]);
+
+        assert!(config.validate().is_err());
+    }
+
+    #[test]
+    fn test_validate_rejects_empty_name() {
+        let config = card_testing_guard_config(vec![velocity_rule(" ", 5, 10)]);
+
+        assert!(config.validate().is_err());
+    }
+}
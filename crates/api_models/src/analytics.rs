@@ -97,6 +97,29 @@ impl AnalyticsRequest {
                 .map(|req| req.metrics.iter().any(|metric| metric.is_forex_metric()))
                 .unwrap_or_default()
     }
+
+    /// Returns the reporting currency requested for the metrics, defaulting to USD
+    pub fn get_reporting_currency(&self) -> common_enums::Currency {
+        self.payment_attempt
+            .as_ref()
+            .and_then(|req| req.reporting_currency)
+            .or_else(|| {
+                self.payment_intent
+                    .as_ref()
+                    .and_then(|req| req.reporting_currency)
+            })
+            .or_else(|| self.refund.as_ref().and_then(|req| req.reporting_currency))
+            .unwrap_or(common_enums::Currency::USD)
+    }
+
+    /// Returns the time range of the metrics, for which historical rates are required
+    pub fn get_time_range(&self) -> Option<TimeRange> {
+        self.payment_attempt
+            .as_ref()
+            .map(|req| req.time_range)
+            .or_else(|| self.payment_intent.as_ref().map(|req| req.time_range))
+            .or_else(|| self.refund.as_ref().map(|req| req.time_range))
+    }
 }
 #[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
 #[serde(rename_all = "camelCase")]
@@ -111,6 +134,9 @@ pub struct GetPaymentMetricRequest {
     pub distribution: Option<PaymentDistributionBody>,
     #[serde(default)]
     pub delta: bool,
+    /// The currency in which amounts are reported, converted using the rates valid within the hour
+    /// each amount was created in. Defaults to USD
+    pub reporting_currency: Option<common_enums::Currency>,
 }
 
 #[derive(Clone, Copy, Debug, serde::Deserialize, serde::Serialize)]
@@ -164,6 +190,71 @@ pub struct GenerateReportRequest {
     pub email: Email,
 }
 
+#[derive(
+    Clone, Copy, Debug, PartialEq, Eq, serde::Deserialize, serde::Serialize, strum::Display,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum ReportDomain {
+    Payments,
+    Refunds,
+    Disputes,
+    Authentications,
+    Payouts,
+}
+
+#[derive(
+    Clone, Copy, Debug, Default, PartialEq, Eq, serde::Deserialize, serde::Serialize, strum::Display,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum ReportFormat {
+    #[default]
+    Csv,
+    Parquet,
+}
+
+impl ReportFormat {
+    pub fn get_file_extension(&self) -> &'static str {
+        match self {
+            Self::Csv => "csv",
+            Self::Parquet => "parquet",
+        }
+    }
+}
+
+#[derive(
+    Clone, Copy, Debug, PartialEq, Eq, serde::Deserialize, serde::Serialize, strum::Display,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum ReportJobStatus {
+    Pending,
+    Processing,
+    Completed,
+    Failed,
+}
+
+/// Status of a report generated in process, returned when the report is requested and when it
+/// is polled for
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReportJobResponse {
+    pub job_id: String,
+    pub report: ReportDomain,
+    pub status: ReportJobStatus,
+    pub format: ReportFormat,
+    /// Number of rows in the report, once it has been generated
+    pub row_count: Option<usize>,
+    pub error_message: Option<String>,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: time::PrimitiveDateTime,
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub completed_at: Option<time::PrimitiveDateTime>,
+}
+
+impl ApiEventMetric for ReportJobResponse {}
+
 #[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct GetPaymentIntentMetricRequest {
@@ -176,6 +267,9 @@ pub struct GetPaymentIntentMetricRequest {
     pub metrics: HashSet<PaymentIntentMetrics>,
     #[serde(default)]
     pub delta: bool,
+    /// The currency in which amounts are reported, converted using the rates valid within the hour
+    /// each amount was created in. Defaults to USD
+    pub reporting_currency: Option<common_enums::Currency>,
 }
 
 #[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
@@ -191,6 +285,9 @@ pub struct GetRefundMetricRequest {
     pub distribution: Option<RefundDistributionBody>,
     #[serde(default)]
     pub delta: bool,
+    /// The currency in which amounts are reported, converted using the rates valid within the hour
+    /// each amount was created in. Defaults to USD
+    pub reporting_currency: Option<common_enums::Currency>,
 }
 
 #[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
@@ -261,6 +358,9 @@ pub struct PaymentsAnalyticsMetadata {
     pub total_payment_processed_count_without_smart_retries: Option<u64>,
     pub total_failure_reasons_count: Option<u64>,
     pub total_failure_reasons_count_without_smart_retries: Option<u64>,
+    /// The currency in which the `*_in_usd` amounts are reported, USD unless a reporting currency
+    /// was requested
+    pub reporting_currency: Option<common_enums::Currency>,
 }
 
 #[derive(Debug, serde::Serialize)]
@@ -277,6 +377,9 @@ pub struct PaymentIntentsAnalyticsMetadata {
     pub total_payment_processed_amount_without_smart_retries_in_usd: Option<u64>,
     pub total_payment_processed_count: Option<u64>,
     pub total_payment_processed_count_without_smart_retries: Option<u64>,
+    /// The currency in which the `*_in_usd` amounts are reported, USD unless a reporting currency
+    /// was requested
+    pub reporting_currency: Option<common_enums::Currency>,
 }
 
 #[derive(Debug, serde::Serialize)]
@@ -287,6 +390,9 @@ pub struct RefundsAnalyticsMetadata {
     pub total_refund_processed_count: Option<u64>,
     pub total_refund_reason_count: Option<u64>,
     pub total_refund_error_message_count: Option<u64>,
+    /// The currency in which the `*_in_usd` amounts are reported, USD unless a reporting currency
+    /// was requested
+    pub reporting_currency: Option<common_enums::Currency>,
 }
 #[derive(Debug, serde::Deserialize, serde::Serialize)]
 #[serde(rename_all = "camelCase")]
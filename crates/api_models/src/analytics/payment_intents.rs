@@ -258,6 +258,9 @@ pub struct PaymentIntentMetricsBucketValue {
     pub payment_processed_count_without_smart_retries: Option<u64>,
     pub payments_success_rate_distribution_without_smart_retries: Option<f64>,
     pub payments_failure_rate_distribution_without_smart_retries: Option<f64>,
+    /// The currency in which the `*_in_usd` amounts are reported, USD unless a reporting currency
+    /// was requested
+    pub reporting_currency: Option<Currency>,
 }
 
 #[derive(Debug, serde::Serialize)]
@@ -340,6 +340,9 @@ pub struct PaymentMetricsBucketValue {
     pub debit_routed_transaction_count: Option<u64>,
     pub debit_routing_savings: Option<u64>,
     pub debit_routing_savings_in_usd: Option<u64>,
+    /// The currency in which the `*_in_usd` amounts are reported, USD unless a reporting currency
+    /// was requested
+    pub reporting_currency: Option<Currency>,
 }
 
 #[derive(Debug, serde::Serialize)]
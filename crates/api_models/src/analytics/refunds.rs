@@ -246,6 +246,9 @@ pub struct RefundMetricsBucketValue {
     pub refund_error_message_distribution: Option<Vec<ErrorMessagesResult>>,
     pub refund_reason_count: Option<u64>,
     pub refund_error_message_count: Option<u64>,
+    /// The currency in which the `*_in_usd` amounts are reported, USD unless a reporting currency
+    /// was requested
+    pub reporting_currency: Option<Currency>,
 }
 #[derive(Debug, serde::Serialize)]
 pub struct RefundMetricsBucketResponse {
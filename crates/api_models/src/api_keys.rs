@@ -23,6 +23,18 @@ pub struct CreateApiKeyRequest {
     /// rotating your keys once every 6 months.
     #[schema(example = "2022-09-10T10:11:12Z")]
     pub expiration: ApiKeyExpiration,
+
+    /// Restricts the API Key to the profile, which is used for all requests made with the key.
+    #[schema(max_length = 64, example = "pro_abcdefghijklmnop", value_type = Option<String>)]
+    pub profile_id: Option<common_utils::id_type::ProfileId>,
+
+    /// Restricts the API Key to the resources listed, with the level of access granted to each of
+    /// them. Requests made to any other resource with the API Key are rejected.
+    pub scopes: Option<Vec<ApiKeyScope>>,
+
+    /// Restricts the API Key to requests made from the IP addresses or CIDR ranges listed.
+    #[schema(value_type = Option<Vec<String>>, example = json!(["203.0.113.7", "198.51.100.0/24"]))]
+    pub ip_allowlist: Option<Vec<String>>,
 }
 
 /// The response body for creating an API Key.
@@ -60,6 +72,18 @@ pub struct CreateApiKeyResponse {
     /// The expiration date for the API Key.
     #[schema(example = "2022-09-10T10:11:12Z")]
     pub expiration: ApiKeyExpiration,
+
+    /// The profile which the API Key is restricted to.
+    #[schema(max_length = 64, example = "pro_abcdefghijklmnop", value_type = Option<String>)]
+    pub profile_id: Option<common_utils::id_type::ProfileId>,
+
+    /// The resources which the API Key is restricted to, with the level of access granted to each
+    /// of them.
+    pub scopes: Option<Vec<ApiKeyScope>>,
+
+    /// The IP addresses or CIDR ranges which the API Key is restricted to.
+    #[schema(value_type = Option<Vec<String>>, example = json!(["203.0.113.7", "198.51.100.0/24"]))]
+    pub ip_allowlist: Option<Vec<String>>,
     /*
     /// The date and time indicating when the API Key was last used.
     #[schema(example = "2022-09-10T10:11:12Z")]
@@ -102,6 +126,18 @@ pub struct RetrieveApiKeyResponse {
     /// The expiration date for the API Key.
     #[schema(example = "2022-09-10T10:11:12Z")]
     pub expiration: ApiKeyExpiration,
+
+    /// The profile which the API Key is restricted to.
+    #[schema(max_length = 64, example = "pro_abcdefghijklmnop", value_type = Option<String>)]
+    pub profile_id: Option<common_utils::id_type::ProfileId>,
+
+    /// The resources which the API Key is restricted to, with the level of access granted to each
+    /// of them.
+    pub scopes: Option<Vec<ApiKeyScope>>,
+
+    /// The IP addresses or CIDR ranges which the API Key is restricted to.
+    #[schema(value_type = Option<Vec<String>>, example = json!(["203.0.113.7", "198.51.100.0/24"]))]
+    pub ip_allowlist: Option<Vec<String>>,
     /*
     /// The date and time indicating when the API Key was last used.
     #[schema(example = "2022-09-10T10:11:12Z")]
@@ -110,6 +146,19 @@ pub struct RetrieveApiKeyResponse {
     */
 }
 
+/// The access granted by a restricted API Key to a resource.
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct ApiKeyScope {
+    /// The resource which the API Key can access.
+    #[schema(value_type = ApiKeyResource, example = "refunds")]
+    pub resource: common_enums::ApiKeyResource,
+
+    /// The level of access granted to the resource. Write access includes read access.
+    #[schema(value_type = ApiKeyAccess, example = "read")]
+    pub access: common_enums::ApiKeyAccess,
+}
+
 /// The request body for updating an API Key.
 #[derive(Debug, Deserialize, ToSchema, Serialize)]
 #[serde(deny_unknown_fields)]
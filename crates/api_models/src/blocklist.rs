@@ -9,6 +9,14 @@ pub enum BlocklistRequest {
     CardBin(String),
     Fingerprint(String),
     ExtendedCardBin(String),
+    /// The email of the customer, matched against the customer and billing email of the payment
+    Email(String),
+    /// The IP address of the client, matched against the `browser_info` of the payment
+    IpAddress(String),
+    /// The customer id, matched against the customer of the payment
+    CustomerId(String),
+    /// The device fingerprint, matched against the `browser_info` of the payment
+    DeviceFingerprint(String),
 }
 
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
@@ -20,9 +28,22 @@ pub struct GenerateFingerprintRequest {
 pub struct Card {
     pub card_number: StrongSecret<String>,
 }
-pub type AddToBlocklistRequest = BlocklistRequest;
 pub type DeleteFromBlocklistRequest = BlocklistRequest;
 
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+pub struct AddToBlocklistRequest {
+    #[serde(flatten)]
+    pub entry: BlocklistRequest,
+    /// The time after which the entry no longer blocks payments. Cannot be provided along with
+    /// `ttl_in_secs`. The entry does not expire if neither is provided.
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    #[schema(value_type = Option<PrimitiveDateTime>, example = "2022-09-10T10:11:12Z")]
+    pub expires_at: Option<time::PrimitiveDateTime>,
+    /// The duration in seconds after which the entry no longer blocks payments. Cannot be
+    /// provided along with `expires_at`.
+    pub ttl_in_secs: Option<u32>,
+}
+
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
 pub struct BlocklistResponse {
     pub fingerprint_id: String,
@@ -30,6 +51,9 @@ pub struct BlocklistResponse {
     pub data_kind: enums::BlocklistDataKind,
     #[serde(with = "common_utils::custom_serde::iso8601")]
     pub created_at: time::PrimitiveDateTime,
+    /// The time after which the entry no longer blocks payments
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub expires_at: Option<time::PrimitiveDateTime>,
 }
 
 #[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
@@ -66,7 +90,95 @@ pub struct ToggleBlocklistQuery {
     pub status: bool,
 }
 
+/// A row of a blocklist CSV file, used for both importing and exporting blocklist entries.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct BlocklistRecord {
+    pub data_kind: enums::BlocklistDataKind,
+    /// The card BIN, extended card BIN, payment method fingerprint, email, IP address, customer id
+    /// or device fingerprint to be blocked
+    pub data: String,
+    /// The duration in seconds after which the entry expires, used only when importing entries
+    #[serde(default, skip_serializing)]
+    pub ttl_in_secs: Option<u32>,
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub expires_at: Option<time::PrimitiveDateTime>,
+    #[serde(default, skip_serializing)]
+    pub line_number: Option<i64>,
+}
+
+impl BlocklistRecord {
+    pub fn get_blocklist_request(&self) -> BlocklistRequest {
+        match self.data_kind {
+            enums::BlocklistDataKind::CardBin => BlocklistRequest::CardBin(self.data.clone()),
+            enums::BlocklistDataKind::ExtendedCardBin => {
+                BlocklistRequest::ExtendedCardBin(self.data.clone())
+            }
+            enums::BlocklistDataKind::PaymentMethod => {
+                BlocklistRequest::Fingerprint(self.data.clone())
+            }
+            enums::BlocklistDataKind::Email => BlocklistRequest::Email(self.data.clone()),
+            enums::BlocklistDataKind::IpAddress => BlocklistRequest::IpAddress(self.data.clone()),
+            enums::BlocklistDataKind::CustomerId => BlocklistRequest::CustomerId(self.data.clone()),
+            enums::BlocklistDataKind::DeviceFingerprint => {
+                BlocklistRequest::DeviceFingerprint(self.data.clone())
+            }
+        }
+    }
+}
+
+#[derive(Debug, Default, serde::Serialize)]
+pub enum BlocklistImportStatus {
+    Success,
+    #[default]
+    Failed,
+}
+
+#[derive(Debug, serde::Serialize)]
+pub struct BlocklistImportResponse {
+    pub line_number: Option<i64>,
+    pub data_kind: enums::BlocklistDataKind,
+    pub data: String,
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub expires_at: Option<time::PrimitiveDateTime>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub import_error: Option<String>,
+    pub import_status: BlocklistImportStatus,
+}
+
+type BlocklistImportResponseType = (Result<BlocklistResponse, String>, BlocklistRecord);
+
+impl From<BlocklistImportResponseType> for BlocklistImportResponse {
+    fn from((response, record): BlocklistImportResponseType) -> Self {
+        match response {
+            Ok(res) => Self {
+                line_number: record.line_number,
+                data_kind: res.data_kind,
+                data: res.fingerprint_id,
+                expires_at: res.expires_at,
+                import_error: None,
+                import_status: BlocklistImportStatus::Success,
+            },
+            Err(e) => Self {
+                line_number: record.line_number,
+                data_kind: record.data_kind,
+                data: record.data,
+                expires_at: None,
+                import_error: Some(e),
+                import_status: BlocklistImportStatus::Failed,
+            },
+        }
+    }
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+pub struct ExportBlocklistQuery {
+    /// Export only the entries of the specified kind, all entries are exported if not specified
+    #[schema(value_type = Option<BlocklistDataKind>)]
+    pub data_kind: Option<enums::BlocklistDataKind>,
+}
+
 impl ApiEventMetric for BlocklistRequest {}
+impl ApiEventMetric for AddToBlocklistRequest {}
 impl ApiEventMetric for BlocklistResponse {}
 impl ApiEventMetric for ToggleBlocklistResponse {}
 impl ApiEventMetric for ListBlocklistQuery {}
@@ -74,3 +186,6 @@ impl ApiEventMetric for GenerateFingerprintRequest {}
 impl ApiEventMetric for ToggleBlocklistQuery {}
 impl ApiEventMetric for GenerateFingerprintResponsePayload {}
 impl ApiEventMetric for Card {}
+impl ApiEventMetric for BlocklistRecord {}
+impl ApiEventMetric for BlocklistImportResponse {}
+impl ApiEventMetric for ExportBlocklistQuery {}
@@ -3,3 +3,6 @@ pub const MAX_ORDER_FULFILLMENT_EXPIRY: i64 = 1800;
 
 /// Min payment intent fulfillment expiry
 pub const MIN_ORDER_FULFILLMENT_EXPIRY: i64 = 60;
+
+/// Max sliding window of a card testing guard velocity rule, which is 7 days
+pub const MAX_VELOCITY_RULE_WINDOW_IN_MINS: u32 = 7 * 24 * 60;
@@ -1,4 +1,44 @@
+use std::collections::HashMap;
+
 use common_utils::{events::ApiEventMetric, types::MinorUnit};
+use rust_decimal::Decimal;
+use time::PrimitiveDateTime;
+
+/// The provider of the forex rates used for currency conversions
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    Hash,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum ForexProvider {
+    /// Rates fetched from openexchangerates.org
+    OpenExchangeRates,
+    /// Rates fetched from apilayer.net
+    ApiLayer,
+    /// Rates read from a JSON file configured in the forex settings
+    StaticFile,
+    /// Rates stored in the configs table
+    ConfigTable,
+    /// Rates uploaded by the merchant, with the markup percentage of the merchant applied
+    MerchantRateTable,
+}
+
+/// QueryParams to be send to retrieve the forex rates
+#[derive(Debug, Default, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub struct ForexRatesParams {
+    /// The provider of the rates, the configured providers are used if not provided
+    pub provider: Option<ForexProvider>,
+}
 
 /// QueryParams to be send to convert the amount -> from_currency -> to_currency
 #[derive(Debug, serde::Deserialize)]
@@ -7,6 +47,12 @@ pub struct CurrencyConversionParams {
     pub amount: MinorUnit,
     pub to_currency: String,
     pub from_currency: String,
+    /// The provider of the rates, the configured providers are used if not provided
+    pub provider: Option<ForexProvider>,
+    /// Converts the amount using the rates that were valid at this time, instead of the latest
+    /// rates
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub as_of: Option<PrimitiveDateTime>,
 }
 
 /// Response to be send for convert currency route
@@ -15,7 +61,41 @@ pub struct CurrencyConversionParams {
 pub struct CurrencyConversionResponse {
     pub converted_amount: String,
     pub currency: String,
+    /// The provider of the rates used for the conversion
+    pub provider: ForexProvider,
+    /// The identifier of the snapshot of rates used for the conversion
+    pub rate_snapshot_id: String,
+}
+
+/// Request to upload the forex rate table of a merchant
+#[derive(Debug, serde::Deserialize, serde::Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct ForexRateTableRequest {
+    /// The currency which the rates are quoted against
+    pub base_currency: common_enums::Currency,
+    /// The number of units of each currency per unit of the base currency
+    pub rates: HashMap<common_enums::Currency, Decimal>,
+    /// The markup percentage deducted from the amounts converted using the rate table
+    #[serde(default, with = "rust_decimal::serde::str")]
+    pub markup_percentage: Decimal,
+}
+
+/// The forex rate table uploaded by a merchant
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+pub struct ForexRateTableResponse {
+    pub merchant_id: common_utils::id_type::MerchantId,
+    /// The identifier of the snapshot of rates, which changes with every upload
+    pub snapshot_id: String,
+    pub base_currency: common_enums::Currency,
+    pub rates: HashMap<common_enums::Currency, Decimal>,
+    #[serde(with = "rust_decimal::serde::str")]
+    pub markup_percentage: Decimal,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub uploaded_at: PrimitiveDateTime,
 }
 
 impl ApiEventMetric for CurrencyConversionResponse {}
 impl ApiEventMetric for CurrencyConversionParams {}
+impl ApiEventMetric for ForexRatesParams {}
+impl ApiEventMetric for ForexRateTableRequest {}
+impl ApiEventMetric for ForexRateTableResponse {}
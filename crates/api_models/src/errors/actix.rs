@@ -14,6 +14,7 @@ impl actix_web::ResponseError for ApiErrorResponse {
             Self::InternalServerError(_) => StatusCode::INTERNAL_SERVER_ERROR,
             Self::NotImplemented(_) => StatusCode::NOT_IMPLEMENTED,
             Self::ConnectorError(_, code) => *code,
+            Self::TooManyRequests(_, _) => StatusCode::TOO_MANY_REQUESTS,
             Self::MethodNotAllowed(_) => StatusCode::METHOD_NOT_ALLOWED,
             Self::NotFound(_) => StatusCode::NOT_FOUND,
             Self::BadRequest(_) => StatusCode::BAD_REQUEST,
@@ -24,7 +25,17 @@ impl actix_web::ResponseError for ApiErrorResponse {
     fn error_response(&self) -> actix_web::HttpResponse {
         use actix_web::http::header;
 
-        actix_web::HttpResponseBuilder::new(self.status_code())
+        let mut response_builder = actix_web::HttpResponseBuilder::new(self.status_code());
+
+        if let Self::TooManyRequests(_, rate_limit_info) = self {
+            response_builder
+                .insert_header((header::RETRY_AFTER, rate_limit_info.retry_after_in_secs))
+                .insert_header(("RateLimit-Limit", rate_limit_info.limit))
+                .insert_header(("RateLimit-Remaining", 0))
+                .insert_header(("RateLimit-Reset", rate_limit_info.reset_in_secs));
+        }
+
+        response_builder
             .insert_header((header::CONTENT_TYPE, mime::APPLICATION_JSON))
             .body(self.to_string())
     }
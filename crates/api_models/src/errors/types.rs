@@ -70,6 +70,17 @@ impl From<&ApiErrorResponse> for ErrorResponse {
     }
 }
 
+/// The state of the rate limit which was exceeded by a request, returned in the response headers
+#[derive(Debug, Clone)]
+pub struct RateLimitInfo {
+    /// The maximum number of requests which can be made in a burst
+    pub limit: u32,
+    /// Seconds after which the limit is fully replenished
+    pub reset_in_secs: u64,
+    /// Seconds after which the request can be retried
+    pub retry_after_in_secs: u64,
+}
+
 #[derive(Debug, serde::Serialize, Default, Clone)]
 pub struct Extra {
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -98,6 +109,7 @@ pub enum ApiErrorResponse {
     InternalServerError(ApiError),
     NotImplemented(ApiError),
     ConnectorError(ApiError, #[serde(skip_serializing)] StatusCode),
+    TooManyRequests(ApiError, #[serde(skip_serializing)] RateLimitInfo),
     NotFound(ApiError),
     MethodNotAllowed(ApiError),
     BadRequest(ApiError),
@@ -131,7 +143,8 @@ impl ApiErrorResponse {
             | Self::MethodNotAllowed(i)
             | Self::BadRequest(i)
             | Self::DomainError(i)
-            | Self::ConnectorError(i, _) => i,
+            | Self::ConnectorError(i, _)
+            | Self::TooManyRequests(i, _) => i,
         }
     }
 
@@ -149,7 +162,8 @@ impl ApiErrorResponse {
             | Self::MethodNotAllowed(i)
             | Self::BadRequest(i)
             | Self::DomainError(i)
-            | Self::ConnectorError(i, _) => i,
+            | Self::ConnectorError(i, _)
+            | Self::TooManyRequests(i, _) => i,
         }
     }
 
@@ -164,7 +178,8 @@ impl ApiErrorResponse {
             | Self::NotImplemented(_)
             | Self::MethodNotAllowed(_)
             | Self::NotFound(_)
-            | Self::BadRequest(_) => "invalid_request",
+            | Self::BadRequest(_)
+            | Self::TooManyRequests(_, _) => "invalid_request",
             Self::InternalServerError(_) => "api",
             Self::DomainError(_) => "blocked",
             Self::ConnectorError(_, _) => "connector",
@@ -67,6 +67,30 @@ impl ApiEventMetric for PaymentIntentFiltersResponse {
     }
 }
 
+impl ApiEventMetric for WebhookSigningKeyRotateRequestInternal {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::BusinessProfile {
+            profile_id: self.profile_id.clone(),
+        })
+    }
+}
+
+impl ApiEventMetric for WebhookSigningKeyRetireRequest {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::BusinessProfile {
+            profile_id: self.profile_id.clone(),
+        })
+    }
+}
+
+impl ApiEventMetric for WebhookSigningKeyResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::BusinessProfile {
+            profile_id: self.profile_id.clone(),
+        })
+    }
+}
+
 impl_api_event_type!(
     Miscellaneous,
     (
@@ -161,6 +185,7 @@ impl_api_event_type!(
     (
         TransferKeyResponse,
         MerchantKeyTransferRequest,
+        KeyRotationResponse,
         UserKeyTransferRequest,
         UserTransferKeyResponse
     )
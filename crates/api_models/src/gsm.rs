@@ -57,6 +57,12 @@ pub struct GsmCreateRequest {
     /// This message provides guidance on what the user should do to
     /// resolve the issue.
     pub user_guidance_message: Option<String>,
+    /// How the `code` is matched against the error code received from the connector, defaults to `exact`
+    #[schema(value_type = Option<GsmMatchType>)]
+    pub code_match_type: Option<api_enums::GsmMatchType>,
+    /// How the `message` is matched against the error message received from the connector, defaults to `exact`
+    #[schema(value_type = Option<GsmMatchType>)]
+    pub message_match_type: Option<api_enums::GsmMatchType>,
 }
 
 #[derive(Debug, serde::Deserialize, serde::Serialize, ToSchema)]
@@ -128,6 +134,12 @@ pub struct GsmUpdateRequest {
     /// This message provides guidance on what the user should do to
     /// resolve the issue.
     pub user_guidance_message: Option<String>,
+    /// How the `code` is matched against the error code received from the connector
+    #[schema(value_type = Option<GsmMatchType>)]
+    pub code_match_type: Option<api_enums::GsmMatchType>,
+    /// How the `message` is matched against the error message received from the connector
+    #[schema(value_type = Option<GsmMatchType>)]
+    pub message_match_type: Option<api_enums::GsmMatchType>,
 }
 
 #[derive(Debug, serde::Deserialize, serde::Serialize, ToSchema)]
@@ -211,4 +223,129 @@ pub struct GsmResponse {
     /// This message provides guidance on what the user should do to
     /// resolve the issue.
     pub user_guidance_message: Option<String>,
+    /// How the `code` is matched against the error code received from the connector
+    #[schema(value_type = GsmMatchType)]
+    pub code_match_type: api_enums::GsmMatchType,
+    /// How the `message` is matched against the error message received from the connector
+    #[schema(value_type = GsmMatchType)]
+    pub message_match_type: api_enums::GsmMatchType,
+}
+
+/// A GSM rule in a flat format, used to import and export rules in bulk as CSV or JSON
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, ToSchema)]
+pub struct GsmRecord {
+    /// The connector through which payment has gone through
+    pub connector: String,
+    /// The flow in which the code and message occurred for a connector
+    pub flow: String,
+    /// The sub_flow in which the code and message occurred  for a connector
+    pub sub_flow: String,
+    /// code received from the connector
+    pub code: String,
+    /// How the `code` is matched against the error code received from the connector
+    #[serde(default)]
+    #[schema(value_type = GsmMatchType)]
+    pub code_match_type: api_enums::GsmMatchType,
+    /// message received from the connector
+    pub message: String,
+    /// How the `message` is matched against the error message received from the connector
+    #[serde(default)]
+    #[schema(value_type = GsmMatchType)]
+    pub message_match_type: api_enums::GsmMatchType,
+    /// status provided by the router
+    pub status: String,
+    /// optional error provided by the router
+    pub router_error: Option<String>,
+    /// decision to be taken for auto retries flow
+    #[schema(value_type = GsmDecision)]
+    pub decision: api_enums::GsmDecision,
+    /// indicates if step_up retry is possible
+    #[serde(default)]
+    pub step_up_possible: bool,
+    /// indicates if retry with pan is possible
+    #[serde(default)]
+    pub clear_pan_possible: bool,
+    /// indicates if retry through an alternate card network is possible
+    #[serde(default)]
+    pub alternate_network_possible: bool,
+    /// error code unified across the connectors
+    pub unified_code: Option<String>,
+    /// error message unified across the connectors
+    pub unified_message: Option<String>,
+    /// category in which error belongs to
+    #[schema(value_type = Option<ErrorCategory>)]
+    pub error_category: Option<api_enums::ErrorCategory>,
+    /// Code that identifies the specific cause for a failure within a broader error category
+    #[schema(value_type = Option<StandardisedCode>)]
+    pub standardised_code: Option<api_enums::StandardisedCode>,
+    /// A detailed description of the error intended for debugging, analytics, and support teams.
+    pub description: Option<String>,
+    /// A user-friendly message that can be safely displayed to the customer.
+    pub user_guidance_message: Option<String>,
+    /// The line of the CSV file or the position in the JSON array the rule was read from, used
+    /// only when importing rules
+    #[serde(default, skip_serializing)]
+    #[schema(value_type = Option<i64>)]
+    pub line_number: Option<i64>,
+}
+
+#[derive(Debug, Default, serde::Serialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum GsmImportStatus {
+    Created,
+    Updated,
+    #[default]
+    Failed,
+}
+
+#[derive(Debug, serde::Serialize, ToSchema)]
+pub struct GsmImportResponse {
+    pub line_number: Option<i64>,
+    pub connector: String,
+    pub flow: String,
+    pub sub_flow: String,
+    pub code: String,
+    pub message: String,
+    pub import_status: GsmImportStatus,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub import_error: Option<String>,
+}
+
+impl From<(Result<GsmImportStatus, String>, GsmRecord)> for GsmImportResponse {
+    fn from((result, record): (Result<GsmImportStatus, String>, GsmRecord)) -> Self {
+        let (import_status, import_error) = match result {
+            Ok(import_status) => (import_status, None),
+            Err(error) => (GsmImportStatus::Failed, Some(error)),
+        };
+        Self {
+            line_number: record.line_number,
+            connector: record.connector,
+            flow: record.flow,
+            sub_flow: record.sub_flow,
+            code: record.code,
+            message: record.message,
+            import_status,
+            import_error,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, serde::Deserialize, serde::Serialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum GsmExportFormat {
+    #[default]
+    Csv,
+    Json,
+}
+
+#[derive(Debug, Clone, serde::Deserialize, serde::Serialize, ToSchema)]
+pub struct GsmExportQuery {
+    /// Export only the rules of the specified connector, rules of all connectors are exported if
+    /// not specified
+    pub connector: Option<String>,
+    /// Export only the rules of the specified flow, rules of all flows are exported if not
+    /// specified
+    pub flow: Option<String>,
+    /// The format of the exported file, defaults to `csv`
+    pub format: Option<GsmExportFormat>,
 }
@@ -1987,6 +1987,10 @@ pub struct BrowserInformation {
     /// Identifier of the source that initiated the request.
     #[smithy(value_type = "Option<String>")]
     pub referer: Option<String>,
+
+    /// Fingerprint of the client device, as computed by the device fingerprinting solution of the merchant
+    #[smithy(value_type = "Option<String>")]
+    pub device_fingerprint: Option<String>,
 }
 
 impl RequestSurchargeDetails {
@@ -2112,6 +2116,10 @@ pub struct PaymentAttemptResponse {
     #[schema(value_type = Option<PaymentErrorDetails>)]
     #[smithy(value_type = "Option<PaymentErrorDetails>")]
     pub error_details: Option<PaymentErrorDetails>,
+    /// Details of the auto retry which created this attempt, used to trace the cascade path of the payment
+    #[schema(value_type = Option<PaymentRetryHop>)]
+    #[smithy(value_type = "Option<PaymentRetryHop>")]
+    pub retry_hop: Option<PaymentRetryHop>,
 }
 
 #[cfg(feature = "v2")]
@@ -8394,6 +8402,26 @@ pub struct PaymentErrorDetails {
     pub connector_details: Option<ApiConnectorErrorDetails>,
 }
 
+/// Details of the auto retry which moved the payment from a failed attempt to a new one
+#[cfg(feature = "v1")]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, PartialEq, Eq, ToSchema)]
+pub struct PaymentRetryHop {
+    /// Reason for which the payment was retried
+    #[schema(value_type = RetryHopReason, example = "gsm_retry")]
+    pub reason: api_enums::RetryHopReason,
+    /// Identifier of the failed attempt which was retried
+    pub previous_attempt_id: String,
+    /// Connector used for the failed attempt
+    pub previous_connector: Option<String>,
+    /// Error code returned by the connector for the failed attempt
+    pub error_code: Option<String>,
+    /// Category of the error as configured in the GSM rule
+    #[schema(value_type = Option<ErrorCategory>)]
+    pub error_category: Option<api_enums::ErrorCategory>,
+    /// Connectors skipped for this retry since they soft declined the same card recently
+    pub skipped_connectors: Vec<String>,
+}
+
 /// Unified error details standardized across all payment connectors
 #[cfg(feature = "v1")]
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize, PartialEq, Eq, ToSchema)]
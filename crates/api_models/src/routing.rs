@@ -1,4 +1,4 @@
-use std::{fmt::Debug, ops::Deref};
+use std::{fmt::Debug, ops::Deref, str::FromStr};
 
 use common_types::three_ds_decision_rule_engine::{ThreeDSDecision, ThreeDSDecisionRule};
 use common_utils::{
@@ -6,7 +6,12 @@ use common_utils::{
     ext_traits::ValueExt,
     fp_utils, pii,
 };
-use euclid::frontend::ast::Program;
+#[cfg(feature = "routing_text")]
+use euclid::frontend::ast::parser::{self, EuclidParsable};
+use euclid::frontend::ast::{
+    printer::{self, EuclidPrintable},
+    Program,
+};
 pub use euclid::{
     dssa::types::EuclidAnalysable,
     enums::RoutableConnectors,
@@ -15,6 +20,8 @@ pub use euclid::{
         dir::{DirKeyKind, EuclidDirFilter},
     },
 };
+#[cfg(feature = "routing_text")]
+use nom::{bytes::complete, character::complete as pchar, combinator, multi, sequence};
 use serde::{Deserialize, Serialize};
 use utoipa::ToSchema;
 
@@ -365,6 +372,13 @@ pub struct MerchantRoutingAlgorithm {
     /// - Other supported algorithm types
     pub algorithm: RoutingAlgorithmWrapper,
 
+    /// Textual form of the algorithm, present only for advanced routing algorithms.
+    ///
+    /// The same text can be supplied back while creating a routing configuration, with the
+    /// algorithm type set to `advanced_text`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub algorithm_text: Option<String>,
+
     /// Timestamp (in milliseconds since epoch) when the routing
     /// configuration was created.
     ///
@@ -429,9 +443,98 @@ impl EuclidDirFilter for ConnectorSelection {
         DirKeyKind::RealTimePaymentType,
         DirKeyKind::TransactionInitiator,
         DirKeyKind::NetworkTokenType,
+        DirKeyKind::TimeOfDay,
+        DirKeyKind::DayOfWeek,
+        DirKeyKind::Date,
+        DirKeyKind::BaseCurrencyAmount,
     ];
 }
 
+/// Parses a connector choice such as `"stripe"` or `"stripe:mca_123"`, as displayed by
+/// [`RoutableConnectorChoice`]
+#[cfg(feature = "routing_text")]
+fn routable_connector_choice(input: &str) -> parser::ParseResult<&str, RoutableConnectorChoice> {
+    combinator::map_res(parser::string_str, |choice: String| {
+        RoutableConnectorChoice::from_str(&choice)
+    })(input)
+}
+
+#[cfg(feature = "routing_text")]
+fn volume_split(input: &str) -> parser::ParseResult<&str, ConnectorVolumeSplit> {
+    let split = sequence::terminated(
+        combinator::map_res(pchar::digit1, u8::from_str),
+        complete::tag("%"),
+    );
+
+    combinator::map(
+        sequence::separated_pair(
+            parser::skip_ws(routable_connector_choice),
+            parser::skip_ws(complete::tag(":")),
+            parser::skip_ws(split),
+        ),
+        |(connector, split)| ConnectorVolumeSplit { connector, split },
+    )(input)
+}
+
+/// Parses a connector selection in the textual form of a program, either as a priority list
+/// `["stripe", "adyen:mca_123"]` or as a volume split `{"stripe": 60%, "adyen": 40%}`
+#[cfg(feature = "routing_text")]
+impl EuclidParsable for ConnectorSelection {
+    fn parse_output(input: &str) -> parser::ParseResult<&str, Self> {
+        let priority = combinator::map(
+            sequence::delimited(
+                parser::skip_ws(complete::tag("[")),
+                multi::separated_list1(
+                    parser::skip_ws(complete::tag(",")),
+                    parser::skip_ws(routable_connector_choice),
+                ),
+                parser::skip_ws(complete::tag("]")),
+            ),
+            Self::Priority,
+        );
+        let volume_split = combinator::map(
+            sequence::delimited(
+                parser::skip_ws(complete::tag("{")),
+                multi::separated_list1(parser::skip_ws(complete::tag(",")), volume_split),
+                parser::skip_ws(complete::tag("}")),
+            ),
+            Self::VolumeSplit,
+        );
+
+        nom::error::context(
+            "connector_selection",
+            nom::branch::alt((priority, volume_split)),
+        )(input)
+    }
+}
+
+impl EuclidPrintable for ConnectorSelection {
+    fn print_output(&self) -> String {
+        match self {
+            Self::Priority(choices) => format!(
+                "[{}]",
+                choices
+                    .iter()
+                    .map(|choice| printer::string_str(&choice.to_string()))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ),
+            Self::VolumeSplit(splits) => format!(
+                "{{{}}}",
+                splits
+                    .iter()
+                    .map(|split| format!(
+                        "{}: {}%",
+                        printer::string_str(&split.connector.to_string()),
+                        split.split
+                    ))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ),
+        }
+    }
+}
+
 impl EuclidAnalysable for ConnectorSelection {
     fn get_dir_value_for_analysis(
         &self,
@@ -510,6 +613,30 @@ impl From<RoutableConnectorChoice> for ast::ConnectorChoice {
     }
 }
 
+impl FromStr for RoutableConnectorChoice {
+    type Err = ParsingError;
+
+    fn from_str(choice: &str) -> Result<Self, Self::Err> {
+        let (connector, merchant_connector_id) = choice
+            .split_once(':')
+            .map_or((choice, None), |(connector, mca_id)| {
+                (connector, Some(mca_id))
+            });
+
+        Ok(Self {
+            choice_kind: RoutableChoiceKind::FullStruct,
+            connector: RoutableConnectors::from_str(connector)
+                .map_err(|_| ParsingError::EnumParseFailure("RoutableConnectors"))?,
+            merchant_connector_id: merchant_connector_id
+                .map(|mca_id| {
+                    common_utils::id_type::MerchantConnectorAccountId::wrap(mca_id.to_string())
+                })
+                .transpose()
+                .map_err(|_| ParsingError::StructParseFailure("MerchantConnectorAccountId"))?,
+        })
+    }
+}
+
 impl PartialEq for RoutableConnectorChoice {
     fn eq(&self, other: &Self) -> bool {
         self.connector.eq(&other.connector)
@@ -653,6 +780,9 @@ pub enum RoutingAlgorithmSerde {
     Priority(Vec<RoutableConnectorChoice>),
     VolumeSplit(Vec<ConnectorVolumeSplit>),
     Advanced(Program<ConnectorSelection>),
+    /// An advanced routing program in its textual form
+    #[cfg(feature = "routing_text")]
+    AdvancedText(String),
     ThreeDsDecisionRule(Program<ThreeDSDecisionRule>),
 }
 
@@ -678,11 +808,32 @@ impl TryFrom<RoutingAlgorithmSerde> for StaticRoutingAlgorithm {
             RoutingAlgorithmSerde::Priority(i) => Self::Priority(i),
             RoutingAlgorithmSerde::VolumeSplit(i) => Self::VolumeSplit(i),
             RoutingAlgorithmSerde::Advanced(i) => Self::Advanced(i),
+            #[cfg(feature = "routing_text")]
+            RoutingAlgorithmSerde::AdvancedText(i) => {
+                Self::Advanced(parser::parse_program::<ConnectorSelection>(&i).map_err(
+                    |error| ParsingError::RoutingProgramParseFailure {
+                        error: error.to_string(),
+                    },
+                )?)
+            }
             RoutingAlgorithmSerde::ThreeDsDecisionRule(i) => Self::ThreeDsDecisionRule(i),
         })
     }
 }
 
+impl StaticRoutingAlgorithm {
+    /// Formats the program of an advanced routing algorithm in its textual form
+    pub fn get_program_text(&self) -> Option<String> {
+        match self {
+            Self::Advanced(program) => Some(printer::program(program)),
+            Self::Single(_)
+            | Self::Priority(_)
+            | Self::VolumeSplit(_)
+            | Self::ThreeDsDecisionRule(_) => None,
+        }
+    }
+}
+
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema, PartialEq)]
 #[serde(
     tag = "type",
@@ -948,6 +1099,80 @@ pub struct RoutingLinkWrapper {
     pub algorithm_id: RoutingAlgorithmId,
 }
 
+/// Request for replaying the payments of a time range through a routing algorithm, and comparing
+/// the outcome with that of the algorithm active for the profile
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct RoutingSimulationRequest {
+    /// The time range within which the payments to be replayed were created
+    pub time_range: common_utils::types::TimeRange,
+    /// The maximum number of payments to be replayed, defaults to 1000
+    #[schema(example = 1000, maximum = 10000)]
+    pub limit: Option<u32>,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct RoutingSimulationWrapper {
+    pub algorithm_id: common_utils::id_type::RoutingId,
+    pub simulation_request: RoutingSimulationRequest,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+pub struct RoutingSimulationResponse {
+    /// The routing algorithm that was simulated
+    #[schema(value_type = String)]
+    pub algorithm_id: common_utils::id_type::RoutingId,
+    /// The routing algorithm active for the profile, which the simulated algorithm is compared with
+    #[schema(value_type = Option<String>)]
+    pub active_algorithm_id: Option<common_utils::id_type::RoutingId>,
+    /// The number of payments replayed through both the algorithms
+    pub total_payments: usize,
+    /// The number of payments for which the simulated algorithm selected a different connector
+    /// than the active algorithm
+    pub rerouted_payments: usize,
+    /// The change in volume and amount routed to each connector
+    pub connectors: Vec<ConnectorSimulationResult>,
+    /// The rules of the simulated algorithm that did not match any of the replayed payments
+    pub unmatched_rules: Vec<UnmatchedRoutingRule>,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+pub struct ConnectorSimulationResult {
+    pub connector: RoutableConnectors,
+    #[schema(value_type = Option<String>)]
+    pub merchant_connector_id: Option<common_utils::id_type::MerchantConnectorAccountId>,
+    /// The number of payments routed to the connector by the active algorithm
+    pub active_volume: u64,
+    /// The number of payments routed to the connector by the simulated algorithm
+    pub simulated_volume: u64,
+    /// The change in the number of payments routed to the connector
+    pub volume_delta: i64,
+    /// The change in the amount routed to the connector, for each currency
+    pub amounts: Vec<CurrencyAmountDelta>,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+pub struct CurrencyAmountDelta {
+    pub currency: common_enums::Currency,
+    /// The amount routed by the active algorithm, in the minor unit of the currency
+    #[schema(value_type = i64)]
+    pub active_amount: common_utils::types::MinorUnit,
+    /// The amount routed by the simulated algorithm, in the minor unit of the currency
+    #[schema(value_type = i64)]
+    pub simulated_amount: common_utils::types::MinorUnit,
+    /// The change in the amount routed, in the minor unit of the currency
+    #[schema(value_type = i64)]
+    pub amount_delta: common_utils::types::MinorUnit,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+pub struct UnmatchedRoutingRule {
+    pub rule_name: String,
+    /// Present when the conditions of the rule can never be satisfied by any payment, describing
+    /// the reason for it
+    pub unreachable_reason: Option<String>,
+}
+
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct DynamicAlgorithmWithTimestamp<T> {
     pub algorithm_id: Option<T>,
@@ -99,6 +99,11 @@ pub struct EventListItemResponse {
     #[schema(example = "2022-09-10T10:11:12Z")]
     #[serde(with = "common_utils::custom_serde::iso8601")]
     pub created: PrimitiveDateTime,
+
+    /// The identifier for the webhook endpoint the event was delivered to. This is absent for
+    /// events delivered to the primary webhook URL of the business profile.
+    #[schema(value_type = Option<String>, max_length = 64, example = "whe_NJ4wsHn3cwN3bm2ZfRDg")]
+    pub webhook_endpoint_id: Option<common_utils::id_type::WebhookEndpointId>,
 }
 
 /// The response body of list initial delivery attempts api call.
@@ -440,6 +440,37 @@ pub enum RecommendedAction {
 pub enum GsmFeature {
     Retry,
 }
+
+/// Specifies how the `code` or the `message` of a GSM rule is matched against the error received
+/// from a connector
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    strum::Display,
+    PartialEq,
+    Eq,
+    serde::Serialize,
+    serde::Deserialize,
+    strum::EnumString,
+    ToSchema,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+#[router_derive::diesel_enum(storage_type = "text")]
+pub enum GsmMatchType {
+    /// The value must be equal to the rule
+    #[default]
+    Exact,
+    /// The value must start with the rule
+    Prefix,
+    /// The rule is a pattern where `*` matches any sequence of characters and `?` matches a single
+    /// character, the whole value must match the pattern
+    Wildcard,
+    /// The rule is a regular expression, which matches anywhere in the value unless anchored
+    Regex,
+}
 #[derive(
     Clone,
     Copy,
@@ -672,6 +703,10 @@ pub enum BlocklistDataKind {
     PaymentMethod,
     CardBin,
     ExtendedCardBin,
+    Email,
+    IpAddress,
+    CustomerId,
+    DeviceFingerprint,
 }
 
 /// Specifies how the payment is captured.
@@ -1873,6 +1908,82 @@ pub enum OutgoingWebhookEndpointStatus {
     Deprecated,
 }
 
+/// The status of a key used to sign outgoing webhooks.
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+#[router_derive::diesel_enum(storage_type = "text")]
+pub enum WebhookSigningKeyStatus {
+    /// The key is the current signing key of the profile.
+    Active,
+    /// The key has been rotated out, and is used to sign webhooks until its overlap window ends.
+    Expiring,
+    /// The key is no longer used to sign webhooks.
+    Retired,
+}
+
+/// The attribute of a payment by which attempts are grouped when evaluating a velocity rule of
+/// the card testing guard.
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum VelocityRuleGroupBy {
+    /// The IP address of the client, from the browser information of the payment.
+    Ip,
+    /// The fingerprint of the card used for the payment.
+    Fingerprint,
+    /// The email of the customer.
+    Email,
+    /// The BIN of the card used for the payment.
+    Bin,
+}
+
+/// The action taken when a velocity rule of the card testing guard is hit.
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum VelocityRuleAction {
+    /// The payment is blocked.
+    Block,
+    /// The payment is allowed, but the customer is challenged with 3DS.
+    Challenge,
+    /// The payment is allowed, and the rule hit is only reported.
+    Flag,
+}
+
 // TODO: This decision about using KV mode or not,
 // should be taken at a top level rather than pushing it down to individual functions via an enum.
 #[derive(
@@ -9180,6 +9291,58 @@ pub enum PermissionScope {
     Write = 1,
 }
 
+/// The resources which the access of a restricted API key can be limited to.
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    Hash,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum ApiKeyResource {
+    Payments,
+    Refunds,
+    Customers,
+    PaymentMethods,
+    Mandates,
+    Disputes,
+    Payouts,
+    PaymentLinks,
+    Files,
+}
+
+/// The level of access granted by a restricted API key to a resource. Write access includes
+/// read access to the resource.
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    Hash,
+    Ord,
+    PartialEq,
+    PartialOrd,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum ApiKeyAccess {
+    Read,
+    Write,
+}
+
 /// Name of banks supported by Hyperswitch
 #[derive(
     Clone,
@@ -10253,6 +10416,9 @@ pub enum ProcessTrackerRunner {
     ProcessDisputeWorkflow,
     DisputeListWorkflow,
     InvoiceSyncflow,
+    BlocklistPurgeWorkflow,
+    KeyRotationWorkflow,
+    CustomerEmailWorkflow,
 }
 
 #[derive(
@@ -10666,6 +10832,32 @@ pub enum RetryType {
     AutoRetry,
 }
 
+/// Reason for which an auto retry moved the payment to a new attempt
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    Hash,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum RetryHopReason {
+    /// The GSM decision for the previous error was to retry on the next eligible connector
+    GsmRetry,
+    /// The previous network token attempt was retried with the card number
+    ClearPanRetry,
+    /// The previous frictionless attempt was retried with 3DS after the connector asked for a step up
+    StepUp,
+}
+
 #[derive(Debug, serde::Serialize, Clone, strum::EnumString, strum::Display)]
 #[serde(rename_all = "snake_case")]
 #[strum(ascii_case_insensitive)]
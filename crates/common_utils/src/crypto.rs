@@ -310,13 +310,38 @@ impl EncodeMessage for GcmAes256 {
     }
 }
 
+/// Length in bytes of a [`GcmAes256`] key
+pub const GCM_AES_256_KEY_LENGTH: usize = 32;
+
 impl DecodeMessage for GcmAes256 {
+    /// `secret` may also be a key ring, the current key followed by the keys it replaced, newest
+    /// first. The message is then decrypted with the first key of the ring which opens it, so that
+    /// data encrypted before a key rotation stays readable until it has been re-encrypted.
     fn decode_message(
         &self,
         secret: &[u8],
         msg: Secret<Vec<u8>, EncryptionStrategy>,
     ) -> CustomResult<Vec<u8>, errors::CryptoError> {
         let msg = msg.expose();
+        let is_key_ring =
+            secret.len() > GCM_AES_256_KEY_LENGTH && secret.len() % GCM_AES_256_KEY_LENGTH == 0;
+        let key_length = if is_key_ring {
+            GCM_AES_256_KEY_LENGTH
+        } else {
+            secret.len().max(1)
+        };
+
+        let mut keys = secret.chunks(key_length);
+        let current_key = keys.next().unwrap_or_default();
+        Self::open(current_key, msg.clone()).or_else(|error| {
+            keys.find_map(|previous_key| Self::open(previous_key, msg.clone()).ok())
+                .ok_or(error)
+        })
+    }
+}
+
+impl GcmAes256 {
+    fn open(secret: &[u8], msg: Vec<u8>) -> CustomResult<Vec<u8>, errors::CryptoError> {
         let key = UnboundKey::new(&aead::AES_256_GCM, secret)
             .change_context(errors::CryptoError::DecodingFailed)?;
 
@@ -960,6 +985,50 @@ mod crypto_tests {
         assert!(err_decoded.is_err());
     }
 
+    #[test]
+    fn test_gcm_aes_256_decode_message_with_key_ring() {
+        let message = r#"{"type":"PAYMENT"}"#.as_bytes();
+        let old_secret =
+            hex::decode("101112131415161718191a1b1c1d1e1f101112131415161718191a1b1c1d1e1f")
+                .expect("Secret decoding");
+        let new_secret =
+            hex::decode("202122232425262728292a2b2c2d2e2f202122232425262728292a2b2c2d2e2f")
+                .expect("Secret decoding");
+        let algorithm = super::GcmAes256;
+
+        let old_encoded_message = algorithm
+            .encode_message(&old_secret, message)
+            .expect("Encoded message and tag");
+        let new_encoded_message = algorithm
+            .encode_message(&new_secret, message)
+            .expect("Encoded message and tag");
+
+        assert!(algorithm
+            .decode_message(&new_secret, old_encoded_message.clone().into())
+            .is_err());
+
+        let key_ring = [new_secret.clone(), old_secret].concat();
+        assert_eq!(
+            algorithm
+                .decode_message(&key_ring, old_encoded_message.into())
+                .expect("Decode Failed"),
+            message
+        );
+        assert_eq!(
+            algorithm
+                .decode_message(&key_ring, new_encoded_message.into())
+                .expect("Decode Failed"),
+            message
+        );
+
+        let unrelated_message = algorithm
+            .encode_message(&[0; super::GCM_AES_256_KEY_LENGTH], message)
+            .expect("Encoded message and tag");
+        assert!(algorithm
+            .decode_message(&key_ring, unrelated_message.into())
+            .is_err());
+    }
+
     #[test]
     fn test_md5_digest() {
         let message = "abcdefghijklmnopqrstuvwxyz".as_bytes();
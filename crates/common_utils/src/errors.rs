@@ -54,6 +54,9 @@ pub enum ParsingError {
     /// Failed to parse String value to Decimal value conversion because `error`
     #[error("Failed to parse String value to Decimal value conversion because {error}")]
     StringToDecimalConversionFailure { error: String },
+    /// Failed to parse the textual form of a routing program because `error`
+    #[error("Failed to parse routing program: {error}")]
+    RoutingProgramParseFailure { error: String },
     /// Failed to convert the given integer because of integer overflow error
     #[error("Integer Overflow error")]
     IntegerOverflow,
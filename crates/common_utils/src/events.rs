@@ -221,6 +221,12 @@ impl<Q: ApiEventMetric, E> ApiEventMetric for Result<Q, E> {
     }
 }
 
+impl<T: ApiEventMetric> ApiEventMetric for Option<T> {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        self.as_ref().and_then(ApiEventMetric::get_api_event_type)
+    }
+}
+
 // TODO: Ideally all these types should be replaced by newtype responses
 impl<T> ApiEventMetric for Vec<T> {
     fn get_api_event_type(&self) -> Option<ApiEventsType> {
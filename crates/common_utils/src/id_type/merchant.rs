@@ -105,6 +105,19 @@ impl MerchantId {
         format!("max_auto_retries_enabled_{}", self.get_string_repr())
     }
 
+    /// get_auto_retry_policy_key
+    pub fn get_auto_retry_policy_key(&self) -> String {
+        format!("auto_retry_policy_{}", self.get_string_repr())
+    }
+
+    /// get_soft_decline_ban_key
+    pub fn get_soft_decline_ban_key(&self, card_fingerprint: &str, connector: &str) -> String {
+        format!(
+            "soft_decline_ban_{}_{card_fingerprint}_{connector}",
+            self.get_string_repr()
+        )
+    }
+
     /// get_requires_cvv_key
     pub fn get_requires_cvv_key(&self) -> String {
         format!("{}_requires_cvv", self.get_string_repr())
@@ -125,6 +138,16 @@ impl MerchantId {
         format!("guard_blocklist_for_{}", self.get_string_repr())
     }
 
+    /// get_rate_limit_config_key
+    pub fn get_rate_limit_config_key(&self) -> String {
+        format!("rate_limit_config_{}", self.get_string_repr())
+    }
+
+    /// get_forex_rate_table_key
+    pub fn get_forex_rate_table_key(&self) -> String {
+        format!("forex_rate_table_{}", self.get_string_repr())
+    }
+
     /// get_pre_routing_disabled_pm_pmt_key
     pub fn get_pre_routing_disabled_pm_pmt_key(&self) -> String {
         format!("pre_routing_disabled_pm_pmt_for_{}", self.get_string_repr())
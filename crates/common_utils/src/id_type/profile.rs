@@ -38,3 +38,10 @@ impl From<ProfileId> for router_env::opentelemetry::Value {
         Self::from(val.0 .0 .0)
     }
 }
+
+impl ProfileId {
+    /// get_customer_email_config_key
+    pub fn get_customer_email_config_key(&self) -> String {
+        format!("customer_email_config_{}", self.get_string_repr())
+    }
+}
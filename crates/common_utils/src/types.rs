@@ -1247,6 +1247,9 @@ pub struct BrowserInformation {
 
     /// Identifier of the source that initiated the request.
     pub referer: Option<String>,
+
+    /// Fingerprint of the client device, as computed by the device fingerprinting solution of the merchant
+    pub device_fingerprint: Option<String>,
 }
 
 #[cfg(feature = "v2")]
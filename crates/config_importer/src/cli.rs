@@ -3,7 +3,29 @@ use std::path::PathBuf;
 /// Utility to import a hyperswitch TOML configuration file, convert it into environment variable
 /// key-value pairs, and export it in the specified format.
 #[derive(clap::Parser, Debug)]
-#[command(arg_required_else_help = true)]
+#[command(
+    arg_required_else_help = true,
+    args_conflicts_with_subcommands = true,
+    subcommand_negates_reqs = true
+)]
+pub(crate) struct Cli {
+    #[command(subcommand)]
+    pub(crate) command: Option<Command>,
+
+    #[command(flatten)]
+    pub(crate) args: Option<Args>,
+}
+
+#[derive(clap::Subcommand, Debug)]
+pub(crate) enum Command {
+    /// Seal a TOML configuration file into a secrets file readable by the `local` secrets manager.
+    ///
+    /// The values of the configuration file are addressed by their dotted paths in the sealed
+    /// file, for example `secrets.master_enc_key`.
+    Seal(SealArgs),
+}
+
+#[derive(clap::Args, Debug)]
 pub(crate) struct Args {
     /// Input TOML configuration file.
     #[arg(short, long, value_name = "FILE")]
@@ -28,6 +50,26 @@ pub(crate) struct Args {
     pub(crate) prefix: String,
 }
 
+#[derive(clap::Args, Debug)]
+pub(crate) struct SealArgs {
+    /// Input TOML configuration file.
+    #[arg(short, long, value_name = "FILE")]
+    pub(crate) input_file: PathBuf,
+
+    /// File holding the hex encoded key encryption key.
+    #[arg(short, long, value_name = "FILE", conflicts_with = "key_env")]
+    pub(crate) key_file: Option<PathBuf>,
+
+    /// Environment variable holding the hex encoded key encryption key, used if no key file is
+    /// specified.
+    #[arg(long, value_name = "NAME", default_value = "HYPERSWITCH_SECRETS_KEY")]
+    pub(crate) key_env: String,
+
+    /// Output file. Output will be written to stdout if not specified.
+    #[arg(short, long, value_name = "FILE")]
+    pub(crate) output_file: Option<PathBuf>,
+}
+
 /// The output format to convert environment variables to.
 #[derive(clap::ValueEnum, Clone, Copy, Debug)]
 pub(crate) enum OutputFormat {
@@ -1,4 +1,5 @@
 mod cli;
+mod seal;
 
 use std::io::{BufWriter, Write};
 
@@ -14,14 +15,18 @@ type EnvironmentVariableMap = std::collections::HashMap<String, String>;
 type EnvironmentVariableMap = indexmap::IndexMap<String, String>;
 
 fn main() -> anyhow::Result<()> {
-    let args = <cli::Args as clap::Parser>::parse();
+    let cli = <cli::Cli as clap::Parser>::parse();
 
+    match (cli.command, cli.args) {
+        (Some(cli::Command::Seal(args)), _) => seal::seal_config(args),
+        (None, Some(args)) => export_environment_variables(args),
+        (None, None) => anyhow::bail!("Either an input file or a subcommand must be specified"),
+    }
+}
+
+fn export_environment_variables(args: cli::Args) -> anyhow::Result<()> {
     // Read input TOML file
-    let toml_contents =
-        std::fs::read_to_string(args.input_file).context("Failed to read input file")?;
-    let table = toml_contents
-        .parse::<toml::Table>()
-        .context("Failed to parse TOML file contents")?;
+    let table = read_toml_file(&args.input_file)?;
 
     // Parse TOML file contents to a `HashMap` of environment variable name and value pairs
     let env_vars = table
@@ -29,19 +34,7 @@ fn main() -> anyhow::Result<()> {
         .flat_map(|(key, value)| process_toml_value(&args.prefix, key, value))
         .collect::<EnvironmentVariableMap>();
 
-    let writer: BufWriter<Box<dyn Write>> = match args.output_file {
-        // Write to file if output file is specified
-        Some(file) => BufWriter::new(Box::new(
-            std::fs::OpenOptions::new()
-                .create(true)
-                .write(true)
-                .truncate(true)
-                .open(file)
-                .context("Failed to open output file")?,
-        )),
-        // Write to stdout otherwise
-        None => BufWriter::new(Box::new(std::io::stdout().lock())),
-    };
+    let writer = get_writer(args.output_file)?;
 
     // Write environment variables in specified format
     match args.output_format {
@@ -58,6 +51,31 @@ fn main() -> anyhow::Result<()> {
     Ok(())
 }
 
+pub(crate) fn read_toml_file(input_file: &std::path::Path) -> anyhow::Result<toml::Table> {
+    let toml_contents = std::fs::read_to_string(input_file).context("Failed to read input file")?;
+    toml_contents
+        .parse::<toml::Table>()
+        .context("Failed to parse TOML file contents")
+}
+
+pub(crate) fn get_writer(
+    output_file: Option<std::path::PathBuf>,
+) -> anyhow::Result<BufWriter<Box<dyn Write>>> {
+    Ok(match output_file {
+        // Write to file if output file is specified
+        Some(file) => BufWriter::new(Box::new(
+            std::fs::OpenOptions::new()
+                .create(true)
+                .write(true)
+                .truncate(true)
+                .open(file)
+                .context("Failed to open output file")?,
+        )),
+        // Write to stdout otherwise
+        None => BufWriter::new(Box::new(std::io::stdout().lock())),
+    })
+}
+
 fn process_toml_value(
     prefix: impl std::fmt::Display + Clone,
     key: impl std::fmt::Display + Clone,
@@ -0,0 +1,72 @@
+use std::io::Write;
+
+use anyhow::Context;
+use external_services::local_encryption::core::{LocalEncryption, LocalEncryptionConfig};
+
+use crate::{cli, EnvironmentVariableMap};
+
+/// The separator used in the paths of sealed values.
+const PATH_SEPARATOR: &str = ".";
+
+/// Seals the values of a TOML configuration file into a secrets file.
+///
+/// The configuration is flattened into a JSON object of dotted paths and values, which is sealed
+/// by the `local` secrets manager of `external_services`, so that the secrets file is always in
+/// the format it reads.
+pub(crate) fn seal_config(args: cli::SealArgs) -> anyhow::Result<()> {
+    let table = crate::read_toml_file(&args.input_file)?;
+    let secrets = table
+        .iter()
+        .flat_map(|(key, value)| flatten_toml_value(None, key, value))
+        .collect::<EnvironmentVariableMap>();
+    let secrets = serde_json::to_vec(&secrets).context("Failed to serialize configuration")?;
+
+    let client = LocalEncryption::new(&LocalEncryptionConfig {
+        key_file: args.key_file,
+        key_env: Some(args.key_env),
+        sealed_file: None,
+    })
+    .map_err(|error| anyhow::anyhow!("Failed to read key encryption key: {error:?}"))?;
+    let sealed_value = client
+        .encrypt(secrets)
+        .map_err(|error| anyhow::anyhow!("Failed to seal configuration: {error:?}"))?;
+
+    let mut writer = crate::get_writer(args.output_file)?;
+    writer
+        .write_all(sealed_value.as_bytes())
+        .context("Failed to write sealed configuration")?;
+
+    Ok(())
+}
+
+fn flatten_toml_value(
+    prefix: Option<&str>,
+    key: &str,
+    value: &toml::Value,
+) -> Vec<(String, String)> {
+    let path = match prefix {
+        Some(prefix) => format!("{prefix}{PATH_SEPARATOR}{key}"),
+        None => key.to_owned(),
+    };
+
+    match value {
+        toml::Value::String(s) => vec![(path, s.to_owned())],
+        toml::Value::Integer(i) => vec![(path, i.to_string())],
+        toml::Value::Float(f) => vec![(path, f.to_string())],
+        toml::Value::Boolean(b) => vec![(path, b.to_string())],
+        toml::Value::Datetime(dt) => vec![(path, dt.to_string())],
+        // Arrays are joined with commas, the same as for environment variables
+        toml::Value::Array(values) => {
+            let values = values
+                .iter()
+                .flat_map(|v| flatten_toml_value(None, key, v))
+                .map(|(_, value)| value)
+                .collect::<Vec<_>>();
+            vec![(path, values.join(","))]
+        }
+        toml::Value::Table(map) => map
+            .into_iter()
+            .flat_map(|(k, v)| flatten_toml_value(Some(&path), k, v))
+            .collect(),
+    }
+}
@@ -1,5 +1,5 @@
 use common_enums::Currency;
-use rust_decimal::Decimal;
+use rust_decimal::{prelude::ToPrimitive, Decimal};
 use rusty_money::Money;
 
 use crate::{
@@ -26,6 +26,26 @@ pub fn convert(
     }
 }
 
+/// Converts an amount in the minor unit of `from_currency` to the minor unit of `to_currency`,
+/// rounding the converted value to the nearest minor unit of `to_currency`
+pub fn convert_minor(
+    ex_rates: &ExchangeRates,
+    from_currency: Currency,
+    to_currency: Currency,
+    amount: i64,
+) -> Result<i64, CurrencyConversionError> {
+    if from_currency == to_currency {
+        return Ok(amount);
+    }
+    let converted_amount = convert(ex_rates, from_currency, to_currency, amount)?;
+    Decimal::from(10_i64.pow(u32::from(
+        to_currency.number_of_digits_after_decimal_point(),
+    )))
+    .checked_mul(converted_amount)
+    .and_then(|minor_amount| minor_amount.round().to_i64())
+    .ok_or(CurrencyConversionError::DecimalMultiplicationFailed)
+}
+
 #[cfg(test)]
 mod tests {
     use std::collections::HashMap;
@@ -88,4 +108,27 @@ mod tests {
             convert(&sample_rate, convert_from, convert_to, amount).expect("converted_currency");
         println!("The conversion from {amount} {convert_from} to {convert_to} is {res:?}");
     }
+
+    #[test]
+    fn currency_to_base_minor_unit_conversion() {
+        use super::*;
+        let mut conversion: HashMap<Currency, CurrencyFactors> = HashMap::new();
+        let jpy_conversion_rates = CurrencyFactors::new(Decimal::new(15000, 2), Decimal::new(1, 2));
+        conversion.insert(Currency::JPY, jpy_conversion_rates);
+        let sample_rate = ExchangeRates::new(Currency::USD, conversion);
+
+        let res = convert_minor(&sample_rate, Currency::JPY, Currency::USD, 15000)
+            .expect("converted_currency");
+        assert_eq!(res, 15000);
+
+        let res = convert_minor(&sample_rate, Currency::USD, Currency::JPY, 100)
+            .expect("converted_currency");
+        assert_eq!(res, 150);
+
+        let res = convert_minor(&sample_rate, Currency::EUR, Currency::EUR, 100)
+            .expect("converted_currency");
+        assert_eq!(res, 100);
+
+        assert!(convert_minor(&sample_rate, Currency::EUR, Currency::USD, 100).is_err());
+    }
 }
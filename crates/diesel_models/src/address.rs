@@ -111,3 +111,19 @@ impl AddressUpdateInternal {
         }
     }
 }
+
+/// Re-encrypted fields of an address, written while rotating the merchant data encryption key
+#[derive(Clone, Debug, Default, AsChangeset, router_derive::DebugAsDisplay)]
+#[diesel(table_name = address)]
+pub struct AddressEncryptionUpdateInternal {
+    pub line1: Option<Encryption>,
+    pub line2: Option<Encryption>,
+    pub line3: Option<Encryption>,
+    pub state: Option<Encryption>,
+    pub zip: Option<Encryption>,
+    pub first_name: Option<Encryption>,
+    pub last_name: Option<Encryption>,
+    pub phone_number: Option<Encryption>,
+    pub email: Option<Encryption>,
+    pub origin_zip: Option<Encryption>,
+}
@@ -18,6 +18,9 @@ pub struct ApiKey {
     pub created_at: PrimitiveDateTime,
     pub expires_at: Option<PrimitiveDateTime>,
     pub last_used: Option<PrimitiveDateTime>,
+    pub profile_id: Option<common_utils::id_type::ProfileId>,
+    pub scopes: Option<ApiKeyScopes>,
+    pub ip_allowlist: Option<ApiKeyIpAllowlist>,
 }
 
 #[derive(Debug, Insertable)]
@@ -32,6 +35,9 @@ pub struct ApiKeyNew {
     pub created_at: PrimitiveDateTime,
     pub expires_at: Option<PrimitiveDateTime>,
     pub last_used: Option<PrimitiveDateTime>,
+    pub profile_id: Option<common_utils::id_type::ProfileId>,
+    pub scopes: Option<ApiKeyScopes>,
+    pub ip_allowlist: Option<ApiKeyIpAllowlist>,
 }
 
 #[derive(Debug)]
@@ -80,6 +86,47 @@ impl From<ApiKeyUpdate> for ApiKeyUpdateInternal {
     }
 }
 
+impl ApiKey {
+    /// Whether the access of the API key is restricted to a profile, a set of resources or a set
+    /// of IP addresses
+    pub fn is_restricted(&self) -> bool {
+        self.profile_id.is_some() || self.scopes.is_some() || self.ip_allowlist.is_some()
+    }
+}
+
+/// The resources which a restricted API key can access, along with the level of access granted
+/// to each of them
+#[derive(Clone, Debug, Eq, PartialEq, serde::Deserialize, serde::Serialize, AsExpression)]
+#[diesel(sql_type = diesel::sql_types::Jsonb)]
+pub struct ApiKeyScopes(pub Vec<ApiKeyScope>);
+
+common_utils::impl_to_sql_from_sql_json!(ApiKeyScopes);
+
+impl ApiKeyScopes {
+    pub fn allows(
+        &self,
+        resource: common_enums::ApiKeyResource,
+        access: common_enums::ApiKeyAccess,
+    ) -> bool {
+        self.0
+            .iter()
+            .any(|scope| scope.resource == resource && scope.access >= access)
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, serde::Deserialize, serde::Serialize)]
+pub struct ApiKeyScope {
+    pub resource: common_enums::ApiKeyResource,
+    pub access: common_enums::ApiKeyAccess,
+}
+
+/// The IP addresses and CIDR ranges from which a restricted API key can be used
+#[derive(Clone, Debug, Eq, PartialEq, serde::Deserialize, serde::Serialize, AsExpression)]
+#[diesel(sql_type = diesel::sql_types::Jsonb)]
+pub struct ApiKeyIpAllowlist(pub Vec<String>);
+
+common_utils::impl_to_sql_from_sql_json!(ApiKeyIpAllowlist);
+
 #[derive(serde::Serialize, serde::Deserialize, Debug, Clone, AsExpression, PartialEq)]
 #[diesel(sql_type = diesel::sql_types::Text)]
 pub struct HashedApiKey(String);
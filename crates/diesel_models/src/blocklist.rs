@@ -11,6 +11,7 @@ pub struct BlocklistNew {
     pub data_kind: common_enums::BlocklistDataKind,
     pub metadata: Option<serde_json::Value>,
     pub created_at: time::PrimitiveDateTime,
+    pub expires_at: Option<time::PrimitiveDateTime>,
 }
 
 #[derive(
@@ -23,4 +24,12 @@ pub struct Blocklist {
     pub data_kind: common_enums::BlocklistDataKind,
     pub metadata: Option<serde_json::Value>,
     pub created_at: time::PrimitiveDateTime,
+    pub expires_at: Option<time::PrimitiveDateTime>,
+}
+
+impl Blocklist {
+    /// Whether the entry has expired at the specified time, and must no longer block payments.
+    pub fn is_expired_at(&self, time: time::PrimitiveDateTime) -> bool {
+        self.expires_at.is_some_and(|expires_at| expires_at <= time)
+    }
 }
@@ -789,6 +789,17 @@ pub struct CardTestingGuardConfig {
     pub is_customer_id_blocking_enabled: bool,
     pub customer_id_blocking_threshold: i32,
     pub card_testing_guard_expiry: i32,
+    #[serde(default)]
+    pub velocity_rules: Vec<VelocityRule>,
+}
+
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+pub struct VelocityRule {
+    pub name: String,
+    pub group_by: common_enums::VelocityRuleGroupBy,
+    pub max_attempts: u32,
+    pub window_in_mins: u32,
+    pub action: common_enums::VelocityRuleAction,
 }
 
 common_utils::impl_to_sql_from_sql_json!(CardTestingGuardConfig);
@@ -808,6 +819,7 @@ impl Default for CardTestingGuardConfig {
                 common_utils::consts::DEFAULT_CUSTOMER_ID_BLOCKING_THRESHOLD,
             card_testing_guard_expiry:
                 common_utils::consts::DEFAULT_CARD_TESTING_GUARD_EXPIRY_IN_SECS,
+            velocity_rules: Vec::new(),
         }
     }
 }
@@ -816,6 +828,7 @@ impl Default for CardTestingGuardConfig {
 pub struct MultipleWebhookDetail {
     pub webhook_endpoint_id: common_utils::id_type::WebhookEndpointId,
     pub webhook_url: Secret<String>,
+    pub webhook_secret: Option<Secret<String>>,
     pub events: HashSet<common_enums::EventType>,
     pub status: common_enums::OutgoingWebhookEndpointStatus,
 }
@@ -45,3 +45,33 @@ pub struct UpdateCardInfo {
     pub last_updated: Option<PrimitiveDateTime>,
     pub last_updated_provider: Option<String>,
 }
+
+impl UpdateCardInfo {
+    pub fn apply_changeset(self, source: CardInfo) -> CardInfo {
+        let Self {
+            card_issuer,
+            card_network,
+            card_type,
+            card_subtype,
+            card_issuing_country,
+            bank_code_id,
+            bank_code,
+            country_code,
+            last_updated,
+            last_updated_provider,
+        } = self;
+        CardInfo {
+            card_issuer: card_issuer.or(source.card_issuer),
+            card_network: card_network.or(source.card_network),
+            card_type: card_type.or(source.card_type),
+            card_subtype: card_subtype.or(source.card_subtype),
+            card_issuing_country: card_issuing_country.or(source.card_issuing_country),
+            bank_code_id: bank_code_id.or(source.bank_code_id),
+            bank_code: bank_code.or(source.bank_code),
+            country_code: country_code.or(source.country_code),
+            last_updated: last_updated.or(source.last_updated),
+            last_updated_provider: last_updated_provider.or(source.last_updated_provider),
+            ..source
+        }
+    }
+}
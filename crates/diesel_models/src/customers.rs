@@ -329,3 +329,15 @@ impl CustomerUpdateInternal {
         }
     }
 }
+
+/// Re-encrypted fields of a customer, written while rotating the merchant data encryption key
+#[cfg(feature = "v1")]
+#[derive(Clone, Debug, Default, AsChangeset, router_derive::DebugAsDisplay)]
+#[diesel(table_name = customers)]
+pub struct CustomerEncryptionUpdateInternal {
+    pub name: Option<Encryption>,
+    pub email: Option<Encryption>,
+    pub phone: Option<Encryption>,
+    pub tax_registration_id: Option<Encryption>,
+    pub document_details: Option<Encryption>,
+}
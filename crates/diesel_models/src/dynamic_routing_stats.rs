@@ -61,3 +61,36 @@ pub struct DynamicRoutingStatsUpdate {
     pub payment_method_type: Option<common_enums::PaymentMethodType>,
     pub global_success_based_connector: Option<String>,
 }
+
+impl DynamicRoutingStatsUpdate {
+    pub fn apply_changeset(self, source: DynamicRoutingStats) -> DynamicRoutingStats {
+        let Self {
+            amount,
+            success_based_routing_connector,
+            payment_connector,
+            currency,
+            payment_method,
+            capture_method,
+            authentication_type,
+            payment_status,
+            conclusive_classification,
+            payment_method_type,
+            global_success_based_connector,
+        } = self;
+        DynamicRoutingStats {
+            amount,
+            success_based_routing_connector,
+            payment_connector,
+            currency: currency.or(source.currency),
+            payment_method: payment_method.or(source.payment_method),
+            capture_method: capture_method.or(source.capture_method),
+            authentication_type: authentication_type.or(source.authentication_type),
+            payment_status,
+            conclusive_classification,
+            payment_method_type: payment_method_type.or(source.payment_method_type),
+            global_success_based_connector: global_success_based_connector
+                .or(source.global_success_based_connector),
+            ..source
+        }
+    }
+}
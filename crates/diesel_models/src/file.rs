@@ -80,3 +80,23 @@ impl From<FileMetadataUpdate> for FileMetadataUpdateInternal {
         }
     }
 }
+
+impl FileMetadataUpdate {
+    pub fn apply_changeset(self, source: FileMetadata) -> FileMetadata {
+        let FileMetadataUpdateInternal {
+            provider_file_id,
+            file_upload_provider,
+            available,
+            profile_id,
+            merchant_connector_id,
+        } = self.into();
+        FileMetadata {
+            provider_file_id: provider_file_id.or(source.provider_file_id),
+            file_upload_provider: file_upload_provider.or(source.file_upload_provider),
+            available,
+            profile_id: profile_id.or(source.profile_id),
+            merchant_connector_id: merchant_connector_id.or(source.merchant_connector_id),
+            ..source
+        }
+    }
+}
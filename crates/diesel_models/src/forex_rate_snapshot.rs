@@ -0,0 +1,21 @@
+use diesel::{Identifiable, Insertable, Queryable, Selectable};
+
+use crate::schema::forex_rate_snapshot;
+
+#[derive(Clone, Debug, Insertable, router_derive::DebugAsDisplay)]
+#[diesel(table_name = forex_rate_snapshot)]
+pub struct ForexRateSnapshotNew {
+    pub snapshot_id: String,
+    pub provider: String,
+    pub rates: serde_json::Value,
+    pub created_at: time::PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Identifiable, Queryable, Selectable)]
+#[diesel(table_name = forex_rate_snapshot, primary_key(snapshot_id), check_for_backend(diesel::pg::Pg))]
+pub struct ForexRateSnapshot {
+    pub snapshot_id: String,
+    pub provider: String,
+    pub rates: serde_json::Value,
+    pub created_at: time::PrimitiveDateTime,
+}
@@ -44,6 +44,8 @@ pub struct GatewayStatusMap {
     pub standardised_code: Option<common_enums::StandardisedCode>,
     pub description: Option<String>,
     pub user_guidance_message: Option<String>,
+    pub code_match_type: Option<common_enums::GsmMatchType>,
+    pub message_match_type: Option<common_enums::GsmMatchType>,
 }
 
 #[derive(Clone, Debug, Eq, PartialEq, Insertable)]
@@ -67,6 +69,8 @@ pub struct GatewayStatusMappingNew {
     pub standardised_code: Option<common_enums::StandardisedCode>,
     pub description: Option<String>,
     pub user_guidance_message: Option<String>,
+    pub code_match_type: Option<common_enums::GsmMatchType>,
+    pub message_match_type: Option<common_enums::GsmMatchType>,
 }
 
 #[derive(
@@ -93,6 +97,8 @@ pub struct GatewayStatusMapperUpdateInternal {
     pub standardised_code: Option<common_enums::StandardisedCode>,
     pub description: Option<String>,
     pub user_guidance_message: Option<String>,
+    pub code_match_type: Option<common_enums::GsmMatchType>,
+    pub message_match_type: Option<common_enums::GsmMatchType>,
 }
 
 #[derive(Debug)]
@@ -110,6 +116,8 @@ pub struct GatewayStatusMappingUpdate {
     pub standardised_code: Option<common_enums::StandardisedCode>,
     pub description: Option<String>,
     pub user_guidance_message: Option<String>,
+    pub code_match_type: Option<common_enums::GsmMatchType>,
+    pub message_match_type: Option<common_enums::GsmMatchType>,
 }
 
 impl From<GatewayStatusMappingUpdate> for GatewayStatusMapperUpdateInternal {
@@ -128,6 +136,8 @@ impl From<GatewayStatusMappingUpdate> for GatewayStatusMapperUpdateInternal {
             standardised_code,
             description,
             user_guidance_message,
+            code_match_type,
+            message_match_type,
         } = value;
         Self {
             status,
@@ -149,6 +159,55 @@ impl From<GatewayStatusMappingUpdate> for GatewayStatusMapperUpdateInternal {
             standardised_code,
             description,
             user_guidance_message,
+            code_match_type,
+            message_match_type,
+        }
+    }
+}
+
+impl GatewayStatusMappingUpdate {
+    pub fn apply_changeset(self, source: GatewayStatusMap) -> GatewayStatusMap {
+        let GatewayStatusMapperUpdateInternal {
+            connector: _,
+            flow: _,
+            sub_flow: _,
+            code: _,
+            message: _,
+            status,
+            router_error,
+            decision,
+            step_up_possible,
+            unified_code,
+            unified_message,
+            error_category,
+            last_modified,
+            clear_pan_possible,
+            feature_data,
+            feature,
+            standardised_code,
+            description,
+            user_guidance_message,
+            code_match_type,
+            message_match_type,
+        } = self.into();
+        GatewayStatusMap {
+            status: status.unwrap_or(source.status),
+            router_error: router_error.unwrap_or(source.router_error),
+            decision: decision.unwrap_or(source.decision),
+            step_up_possible: step_up_possible.unwrap_or(source.step_up_possible),
+            unified_code: unified_code.or(source.unified_code),
+            unified_message: unified_message.or(source.unified_message),
+            error_category: error_category.or(source.error_category),
+            last_modified,
+            clear_pan_possible: clear_pan_possible.unwrap_or(source.clear_pan_possible),
+            feature_data: feature_data.or(source.feature_data),
+            feature: feature.or(source.feature),
+            standardised_code: standardised_code.or(source.standardised_code),
+            description: description.or(source.description),
+            user_guidance_message: user_guidance_message.or(source.user_guidance_message),
+            code_match_type: code_match_type.or(source.code_match_type),
+            message_match_type: message_match_type.or(source.message_match_type),
+            ..source
         }
     }
 }
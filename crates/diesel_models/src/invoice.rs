@@ -125,3 +125,27 @@ impl InvoiceUpdate {
         }
     }
 }
+
+impl InvoiceUpdate {
+    pub fn apply_changeset(self, source: Invoice) -> Invoice {
+        let Self {
+            status,
+            payment_method_id,
+            connector_invoice_id,
+            modified_at,
+            payment_intent_id,
+            amount,
+            currency,
+        } = self;
+        Invoice {
+            status: status.unwrap_or(source.status),
+            payment_method_id: payment_method_id.or(source.payment_method_id),
+            connector_invoice_id: connector_invoice_id.or(source.connector_invoice_id),
+            modified_at,
+            payment_intent_id: payment_intent_id.or(source.payment_intent_id),
+            amount: amount.unwrap_or(source.amount),
+            currency: currency.unwrap_or(source.currency),
+            ..source
+        }
+    }
+}
@@ -1,18 +1,16 @@
 use error_stack::ResultExt;
 use serde::{Deserialize, Serialize};
 
-#[cfg(feature = "v2")]
-use crate::payment_attempt::PaymentAttemptUpdateInternal;
 #[cfg(feature = "v1")]
 use crate::payment_intent::PaymentIntentUpdate;
-#[cfg(feature = "v2")]
-use crate::payment_intent::PaymentIntentUpdateInternal;
 use crate::{
     address::{Address, AddressNew, AddressUpdateInternal},
     customers::{Customer, CustomerNew, CustomerUpdateInternal},
     errors,
-    payment_attempt::{PaymentAttempt, PaymentAttemptNew, PaymentAttemptUpdate},
-    payment_intent::PaymentIntentNew,
+    payment_attempt::{
+        PaymentAttempt, PaymentAttemptNew, PaymentAttemptUpdate, PaymentAttemptUpdateInternal,
+    },
+    payment_intent::{PaymentIntentNew, PaymentIntentUpdateInternal},
     payout_attempt::{PayoutAttempt, PayoutAttemptNew, PayoutAttemptUpdate},
     payouts::{Payouts, PayoutsNew, PayoutsUpdate},
     refund::{Refund, RefundNew, RefundUpdate},
@@ -183,6 +181,297 @@ impl DBOperation {
     }
 }
 
+/// Operations of consecutive stream entries, applied with fewer statements than executing each of
+/// them in order.
+///
+/// The inserts are applied first with one statement per table, since a row is always inserted
+/// before it's updated. Successive updates to the same payment attempt or payment intent are merged
+/// into a single update, and the other updates are applied in order after the inserts.
+#[derive(Debug, Default)]
+pub struct DBOperationBatch {
+    inserts: Vec<InsertableBatch>,
+    updates: Vec<BatchedUpdate>,
+}
+
+impl DBOperationBatch {
+    pub fn push(&mut self, operation: DBOperation) {
+        match operation {
+            DBOperation::Insert { insertable } => {
+                let mut insertable = *insertable;
+                for batch in self.inserts.iter_mut() {
+                    match batch.push(insertable) {
+                        Ok(()) => return,
+                        Err(other) => insertable = other,
+                    }
+                }
+                self.inserts.push(InsertableBatch::from(insertable));
+            }
+            DBOperation::Update { updatable } => {
+                let update = BatchedUpdate::from(*updatable);
+                match self
+                    .updates
+                    .iter()
+                    .position(|batched| batched.is_same_row(&update))
+                {
+                    Some(position) => match self.updates.remove(position).merge(update) {
+                        Ok(merged) => self.updates.insert(position, merged),
+                        Err((batched, update)) => {
+                            self.updates.insert(position, batched);
+                            self.updates.push(update);
+                        }
+                    },
+                    None => self.updates.push(update),
+                }
+            }
+        }
+    }
+
+    /// Number of statements executed to apply the batch
+    pub fn statement_count(&self) -> usize {
+        self.inserts.len() + self.updates.len()
+    }
+
+    pub async fn execute(self, conn: &PgPooledConn) -> crate::StorageResult<()> {
+        for batch in self.inserts {
+            batch.execute(conn).await?;
+        }
+        for update in self.updates {
+            update.execute(conn).await?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Maximum number of bind parameters of a PostgreSQL statement
+const MAX_BIND_PARAMETERS: usize = 65535;
+
+/// Maximum number of columns of a table, as supported by the `128-column-tables` feature of diesel
+const MAX_TABLE_COLUMNS: usize = 128;
+
+/// Maximum number of rows inserted with a single statement. Each row binds at most one parameter
+/// per column of the table, so a statement with this many rows stays under the bind parameter
+/// limit whatever the table.
+const MAX_ROWS_PER_INSERT: usize = MAX_BIND_PARAMETERS / MAX_TABLE_COLUMNS;
+
+/// Rows of a single table, inserted with a single statement of at most `MAX_ROWS_PER_INSERT` rows
+#[derive(Debug)]
+enum InsertableBatch {
+    PaymentIntent(Vec<PaymentIntentNew>),
+    PaymentAttempt(Vec<PaymentAttemptNew>),
+    Refund(Vec<RefundNew>),
+    Address(Vec<AddressNew>),
+    Customer(Vec<CustomerNew>),
+    ReverseLookUp(Vec<ReverseLookupNew>),
+    Payouts(Vec<PayoutsNew>),
+    PayoutAttempt(Vec<PayoutAttemptNew>),
+    PaymentMethod(Vec<PaymentMethodNew>),
+    Mandate(Vec<MandateNew>),
+}
+
+impl From<Insertable> for InsertableBatch {
+    fn from(insertable: Insertable) -> Self {
+        match insertable {
+            Insertable::PaymentIntent(row) => Self::PaymentIntent(vec![*row]),
+            Insertable::PaymentAttempt(row) => Self::PaymentAttempt(vec![*row]),
+            Insertable::Refund(row) => Self::Refund(vec![row]),
+            Insertable::Address(row) => Self::Address(vec![*row]),
+            Insertable::Customer(row) => Self::Customer(vec![row]),
+            Insertable::ReverseLookUp(row) => Self::ReverseLookUp(vec![row]),
+            Insertable::Payouts(row) => Self::Payouts(vec![row]),
+            Insertable::PayoutAttempt(row) => Self::PayoutAttempt(vec![row]),
+            Insertable::PaymentMethod(row) => Self::PaymentMethod(vec![*row]),
+            Insertable::Mandate(row) => Self::Mandate(vec![row]),
+        }
+    }
+}
+
+impl InsertableBatch {
+    fn len(&self) -> usize {
+        match self {
+            Self::PaymentIntent(rows) => rows.len(),
+            Self::PaymentAttempt(rows) => rows.len(),
+            Self::Refund(rows) => rows.len(),
+            Self::Address(rows) => rows.len(),
+            Self::Customer(rows) => rows.len(),
+            Self::ReverseLookUp(rows) => rows.len(),
+            Self::Payouts(rows) => rows.len(),
+            Self::PayoutAttempt(rows) => rows.len(),
+            Self::PaymentMethod(rows) => rows.len(),
+            Self::Mandate(rows) => rows.len(),
+        }
+    }
+
+    /// Adds the row to the batch, or returns it if it belongs to another table or the batch is
+    /// full.
+    fn push(&mut self, insertable: Insertable) -> Result<(), Insertable> {
+        if self.len() >= MAX_ROWS_PER_INSERT {
+            return Err(insertable);
+        }
+
+        match (self, insertable) {
+            (Self::PaymentIntent(rows), Insertable::PaymentIntent(row)) => rows.push(*row),
+            (Self::PaymentAttempt(rows), Insertable::PaymentAttempt(row)) => rows.push(*row),
+            (Self::Refund(rows), Insertable::Refund(row)) => rows.push(row),
+            (Self::Address(rows), Insertable::Address(row)) => rows.push(*row),
+            (Self::Customer(rows), Insertable::Customer(row)) => rows.push(row),
+            (Self::ReverseLookUp(rows), Insertable::ReverseLookUp(row)) => rows.push(row),
+            (Self::Payouts(rows), Insertable::Payouts(row)) => rows.push(row),
+            (Self::PayoutAttempt(rows), Insertable::PayoutAttempt(row)) => rows.push(row),
+            (Self::PaymentMethod(rows), Insertable::PaymentMethod(row)) => rows.push(*row),
+            (Self::Mandate(rows), Insertable::Mandate(row)) => rows.push(row),
+            (_, insertable) => return Err(insertable),
+        }
+
+        Ok(())
+    }
+
+    async fn execute(self, conn: &PgPooledConn) -> crate::StorageResult<()> {
+        match self {
+            Self::PaymentIntent(rows) => PaymentIntentNew::batch_insert(rows, conn).await,
+            Self::PaymentAttempt(rows) => PaymentAttemptNew::batch_insert(rows, conn).await,
+            Self::Refund(rows) => RefundNew::batch_insert(rows, conn).await,
+            Self::Address(rows) => AddressNew::batch_insert(rows, conn).await,
+            Self::Customer(rows) => CustomerNew::batch_insert(rows, conn).await,
+            Self::ReverseLookUp(rows) => ReverseLookupNew::batch_insert(rows, conn).await,
+            Self::Payouts(rows) => PayoutsNew::batch_insert(rows, conn).await,
+            Self::PayoutAttempt(rows) => PayoutAttemptNew::batch_insert(rows, conn).await,
+            Self::PaymentMethod(rows) => PaymentMethodNew::batch_insert(rows, conn).await,
+            Self::Mandate(rows) => MandateNew::batch_insert(rows, conn).await,
+        }
+    }
+}
+
+/// An update of a batch, possibly merged from successive updates to the same row
+#[derive(Debug)]
+enum BatchedUpdate {
+    #[cfg(feature = "v1")]
+    PaymentAttempt {
+        orig: Box<PaymentAttempt>,
+        update_data: Box<PaymentAttemptUpdateInternal>,
+    },
+    #[cfg(feature = "v1")]
+    PaymentIntent {
+        orig: Box<PaymentIntent>,
+        update_data: Box<PaymentIntentUpdateInternal>,
+    },
+    Single(Box<Updateable>),
+}
+
+impl From<Updateable> for BatchedUpdate {
+    #[cfg(feature = "v1")]
+    fn from(updatable: Updateable) -> Self {
+        match updatable {
+            Updateable::PaymentAttemptUpdate(update) => {
+                let PaymentAttemptUpdateMems { orig, update_data } = *update;
+                // The derived fields are computed from the row as it was before each update
+                let update_data =
+                    PaymentAttemptUpdateInternal::from(update_data).populate_derived_fields(&orig);
+                Self::PaymentAttempt {
+                    orig: Box::new(orig),
+                    update_data: Box::new(update_data),
+                }
+            }
+            Updateable::PaymentIntentUpdate(update) => {
+                let PaymentIntentUpdateMems { orig, update_data } = *update;
+                Self::PaymentIntent {
+                    orig: Box::new(orig),
+                    update_data: Box::new(PaymentIntentUpdateInternal::from(update_data)),
+                }
+            }
+            updatable => Self::Single(Box::new(updatable)),
+        }
+    }
+
+    #[cfg(feature = "v2")]
+    fn from(updatable: Updateable) -> Self {
+        Self::Single(Box::new(updatable))
+    }
+}
+
+impl BatchedUpdate {
+    #[cfg(feature = "v1")]
+    fn is_same_row(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::PaymentAttempt { orig, .. }, Self::PaymentAttempt { orig: other, .. }) => {
+                orig.attempt_id == other.attempt_id
+                    && orig.merchant_id == other.merchant_id
+                    && orig.processor_merchant_id == other.processor_merchant_id
+            }
+            (Self::PaymentIntent { orig, .. }, Self::PaymentIntent { orig: other, .. }) => {
+                orig.payment_id == other.payment_id
+                    && orig.merchant_id == other.merchant_id
+                    && orig.processor_merchant_id == other.processor_merchant_id
+            }
+            _ => false,
+        }
+    }
+
+    #[cfg(feature = "v2")]
+    fn is_same_row(&self, _other: &Self) -> bool {
+        false
+    }
+
+    /// Merges an update of the same row applied after this one, or returns both updates if they
+    /// can't be merged. The original row of the first update is kept, since the row is looked up
+    /// by its identifiers only.
+    #[cfg(feature = "v1")]
+    fn merge(self, later: Self) -> Result<Self, (Self, Self)> {
+        if !self.is_same_row(&later) {
+            return Err((self, later));
+        }
+
+        match (self, later) {
+            (
+                Self::PaymentAttempt { orig, update_data },
+                Self::PaymentAttempt {
+                    update_data: later_update_data,
+                    ..
+                },
+            ) => Ok(Self::PaymentAttempt {
+                orig,
+                update_data: Box::new(update_data.merge(*later_update_data)),
+            }),
+            (
+                Self::PaymentIntent { orig, update_data },
+                Self::PaymentIntent {
+                    update_data: later_update_data,
+                    ..
+                },
+            ) => Ok(Self::PaymentIntent {
+                orig,
+                update_data: Box::new(update_data.merge(*later_update_data)),
+            }),
+            (earlier, later) => Err((earlier, later)),
+        }
+    }
+
+    #[cfg(feature = "v2")]
+    fn merge(self, later: Self) -> Result<Self, (Self, Self)> {
+        Err((self, later))
+    }
+
+    async fn execute(self, conn: &PgPooledConn) -> crate::StorageResult<()> {
+        match self {
+            #[cfg(feature = "v1")]
+            Self::PaymentAttempt { orig, update_data } => {
+                (*orig)
+                    .update_with_attempt_id_and_changeset(conn, *update_data)
+                    .await?;
+            }
+            #[cfg(feature = "v1")]
+            Self::PaymentIntent { orig, update_data } => {
+                (*orig).update_with_changeset(conn, *update_data).await?;
+            }
+            Self::Single(updatable) => {
+                DBOperation::Update { updatable }.execute(conn).await?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
 impl TypedSql {
     pub fn to_field_value_pairs(
         &self,
@@ -301,3 +590,138 @@ pub struct MandateUpdateMems {
     pub orig: Mandate,
     pub update_data: MandateUpdateInternal,
 }
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use super::*;
+
+    fn get_reverse_lookup_insert(lookup_id: &str) -> DBOperation {
+        DBOperation::Insert {
+            insertable: Box::new(Insertable::ReverseLookUp(ReverseLookupNew {
+                lookup_id: lookup_id.to_owned(),
+                pk_id: "pk_1".to_owned(),
+                sk_id: "sk_1".to_owned(),
+                source: "payment_attempt".to_owned(),
+                updated_by: "redis_kv".to_owned(),
+            })),
+        }
+    }
+
+    #[cfg(feature = "v1")]
+    fn get_payment_attempt_update(attempt_id: &str, update: PaymentAttemptUpdate) -> DBOperation {
+        let orig = serde_json::from_value(serde_json::json!({
+            "payment_id": "pay_1",
+            "merchant_id": "merchant_1",
+            "attempt_id": attempt_id,
+            "status": "started",
+            "amount": 100,
+            "confirm": false,
+            "created_at": "2024-01-01T00:00:00.000Z",
+            "modified_at": "2024-01-01T00:00:00.000Z",
+            "amount_capturable": 0,
+            "updated_by": "redis_kv",
+            "profile_id": "pro_1",
+            "organization_id": "org_1",
+        }))
+        .unwrap();
+
+        DBOperation::Update {
+            updatable: Box::new(Updateable::PaymentAttemptUpdate(Box::new(
+                PaymentAttemptUpdateMems {
+                    orig,
+                    update_data: update,
+                },
+            ))),
+        }
+    }
+
+    #[test]
+    fn test_push_groups_inserts_by_table() {
+        let mut batch = DBOperationBatch::default();
+        batch.push(get_reverse_lookup_insert("lookup_1"));
+        batch.push(get_reverse_lookup_insert("lookup_2"));
+
+        assert_eq!(batch.statement_count(), 1);
+        assert!(matches!(
+            batch.inserts.as_slice(),
+            [InsertableBatch::ReverseLookUp(rows)]
+                if rows.iter().map(|row| row.lookup_id.as_str()).eq(["lookup_1", "lookup_2"])
+        ));
+    }
+
+    #[test]
+    fn test_push_splits_inserts_under_bind_parameter_limit() {
+        let mut batch = DBOperationBatch::default();
+        for index in 0..=MAX_ROWS_PER_INSERT {
+            batch.push(get_reverse_lookup_insert(&format!("lookup_{index}")));
+        }
+
+        assert_eq!(batch.statement_count(), 2);
+        assert_eq!(
+            batch
+                .inserts
+                .iter()
+                .map(InsertableBatch::len)
+                .collect::<Vec<_>>(),
+            [MAX_ROWS_PER_INSERT, 1]
+        );
+    }
+
+    #[cfg(feature = "v1")]
+    #[test]
+    fn test_push_merges_updates_of_a_row_in_order() {
+        use common_enums::AttemptStatus;
+
+        let mut batch = DBOperationBatch::default();
+        batch.push(get_payment_attempt_update(
+            "attempt_1",
+            PaymentAttemptUpdate::StatusUpdate {
+                status: AttemptStatus::Pending,
+                updated_by: "redis_kv".to_owned(),
+            },
+        ));
+        batch.push(get_payment_attempt_update(
+            "attempt_2",
+            PaymentAttemptUpdate::StatusUpdate {
+                status: AttemptStatus::Failure,
+                updated_by: "redis_kv".to_owned(),
+            },
+        ));
+        batch.push(get_payment_attempt_update(
+            "attempt_1",
+            PaymentAttemptUpdate::PaymentMethodDetailsUpdate {
+                payment_method_id: Some("pm_1".to_owned()),
+                updated_by: "redis_kv".to_owned(),
+            },
+        ));
+        batch.push(get_payment_attempt_update(
+            "attempt_1",
+            PaymentAttemptUpdate::StatusUpdate {
+                status: AttemptStatus::Charged,
+                updated_by: "redis_kv".to_owned(),
+            },
+        ));
+
+        // The updates of a row are merged into its first update, later updates taking precedence
+        assert_eq!(batch.statement_count(), 2);
+        assert!(matches!(
+            batch.updates.as_slice(),
+            [
+                BatchedUpdate::PaymentAttempt {
+                    orig: first_orig,
+                    update_data: first_update,
+                },
+                BatchedUpdate::PaymentAttempt {
+                    orig: second_orig,
+                    update_data: second_update,
+                },
+            ] if first_orig.attempt_id == "attempt_1"
+                && first_update.status == Some(AttemptStatus::Charged)
+                && first_update.payment_method_id.as_deref() == Some("pm_1")
+                && second_orig.attempt_id == "attempt_2"
+                && second_update.status == Some(AttemptStatus::Failure)
+        ));
+    }
+}
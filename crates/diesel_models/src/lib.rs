@@ -19,6 +19,7 @@ pub mod ephemeral_key;
 pub mod errors;
 pub mod events;
 pub mod file;
+pub mod forex_rate_snapshot;
 #[allow(unused)]
 pub mod fraud_check;
 pub mod generic_link;
@@ -62,6 +63,7 @@ pub mod user;
 pub mod user_authentication_method;
 pub mod user_key_store;
 pub mod user_role;
+pub mod webhook_signing_key;
 
 use diesel_impl::{DieselArray, OptionalDieselArray};
 #[cfg(feature = "v2")]
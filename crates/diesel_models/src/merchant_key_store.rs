@@ -20,6 +20,8 @@ pub struct MerchantKeyStore {
     pub key: Encryption,
     #[serde(with = "custom_serde::iso8601")]
     pub created_at: PrimitiveDateTime,
+    pub key_version: i32,
+    pub previous_keys: Option<Encryption>,
 }
 
 #[derive(
@@ -30,6 +32,8 @@ pub struct MerchantKeyStoreNew {
     pub merchant_id: common_utils::id_type::MerchantId,
     pub key: Encryption,
     pub created_at: PrimitiveDateTime,
+    pub key_version: i32,
+    pub previous_keys: Option<Encryption>,
 }
 
 #[derive(
@@ -37,6 +41,7 @@ pub struct MerchantKeyStoreNew {
 )]
 #[diesel(table_name = merchant_key_store)]
 pub struct MerchantKeyStoreUpdateInternal {
-    pub merchant_id: common_utils::id_type::MerchantId,
     pub key: Encryption,
+    pub key_version: i32,
+    pub previous_keys: Option<Encryption>,
 }
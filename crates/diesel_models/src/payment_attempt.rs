@@ -47,6 +47,23 @@ pub struct NetworkDetails {
     pub network_advice_code: Option<String>,
 }
 
+common_utils::impl_to_sql_from_sql_json!(RetryHop);
+/// Details of the auto retry hop which created a payment attempt
+#[derive(
+    Clone, Debug, serde::Deserialize, Eq, PartialEq, serde::Serialize, diesel::AsExpression,
+)]
+#[diesel(sql_type = diesel::sql_types::Jsonb)]
+pub struct RetryHop {
+    pub reason: storage_enums::RetryHopReason,
+    pub previous_attempt_id: String,
+    pub previous_connector: Option<String>,
+    pub error_code: Option<String>,
+    pub error_category: Option<storage_enums::ErrorCategory>,
+    /// Connectors skipped for this hop since they soft declined the same card recently
+    #[serde(default)]
+    pub skipped_connectors: Vec<String>,
+}
+
 // ErrorDetails nested structs for V1 payment_attempt
 common_utils::impl_to_sql_from_sql_json!(ErrorDetails);
 #[derive(
@@ -164,6 +181,8 @@ pub struct PaymentAttempt {
     pub error_details: Option<ErrorDetails>,
     pub retry_type: Option<storage_enums::RetryType>,
     pub installment_data: Option<common_types::payments::InstallmentData>,
+    /// Auto retry hop which created this payment attempt (None if not created by an auto retry)
+    pub retry_hop: Option<RetryHop>,
     #[diesel(deserialize_as = RequiredFromNullable<storage_enums::PaymentMethod>)]
     pub payment_method_type_v2: storage_enums::PaymentMethod,
     pub connector_payment_id: Option<ConnectorTransactionId>,
@@ -296,6 +315,8 @@ pub struct PaymentAttempt {
     /// Indicates the type of retry for this payment attempt (None for initial attempt)
     pub retry_type: Option<storage_enums::RetryType>,
     pub installment_data: Option<common_types::payments::InstallmentData>,
+    /// Auto retry hop which created this payment attempt (None if not created by an auto retry)
+    pub retry_hop: Option<RetryHop>,
 }
 
 #[cfg(feature = "v1")]
@@ -542,6 +563,8 @@ pub struct PaymentAttemptNew {
     /// Indicates the type of retry for this payment attempt (None for initial attempt)
     pub retry_type: Option<storage_enums::RetryType>,
     pub installment_data: Option<common_types::payments::InstallmentData>,
+    /// Auto retry hop which created this payment attempt (None if not created by an auto retry)
+    pub retry_hop: Option<RetryHop>,
 }
 
 #[cfg(feature = "v1")]
@@ -1259,6 +1282,167 @@ impl PaymentAttemptUpdateInternal {
             .map(|network| network.to_string());
         update_internal
     }
+
+    /// Combines the changeset with one applied after it, so that applying the result is the same as
+    /// applying both of them in order.
+    pub fn merge(self, later: Self) -> Self {
+        let Self {
+            amount,
+            net_amount,
+            currency,
+            status,
+            connector_transaction_id,
+            amount_to_capture,
+            connector,
+            authentication_type,
+            payment_method,
+            error_message,
+            payment_method_id,
+            cancellation_reason,
+            modified_at,
+            mandate_id,
+            browser_info,
+            payment_token,
+            error_code,
+            connector_metadata,
+            payment_method_data,
+            encrypted_payment_method_data,
+            payment_method_type,
+            payment_experience,
+            business_sub_label,
+            straight_through_algorithm,
+            preprocessing_step_id,
+            error_reason,
+            capture_method,
+            connector_response_reference_id,
+            multiple_capture_count,
+            surcharge_amount,
+            tax_amount,
+            amount_capturable,
+            updated_by,
+            merchant_connector_id,
+            authentication_data,
+            encoded_data,
+            unified_code,
+            unified_message,
+            external_three_ds_authentication_attempted,
+            authentication_connector,
+            authentication_id,
+            fingerprint_id,
+            payment_method_billing_address_id,
+            client_source,
+            client_version,
+            customer_acceptance,
+            card_network,
+            capture_before,
+            extended_authorization_applied,
+            extended_authorization_last_applied_at,
+            tokenization,
+            shipping_cost,
+            order_tax_amount,
+            installment_data,
+            connector_mandate_detail,
+            processor_transaction_data,
+            card_discovery,
+            charges,
+            issuer_error_code,
+            issuer_error_message,
+            setup_future_usage_applied,
+            routing_approach,
+            connector_request_reference_id,
+            network_transaction_id,
+            is_overcapture_enabled,
+            network_details,
+            is_stored_credential,
+            request_extended_authorization,
+            authorized_amount,
+            error_details,
+        } = later;
+
+        Self {
+            amount: amount.or(self.amount),
+            net_amount: net_amount.or(self.net_amount),
+            currency: currency.or(self.currency),
+            status: status.or(self.status),
+            connector_transaction_id: connector_transaction_id.or(self.connector_transaction_id),
+            amount_to_capture: amount_to_capture.or(self.amount_to_capture),
+            connector: connector.or(self.connector),
+            authentication_type: authentication_type.or(self.authentication_type),
+            payment_method: payment_method.or(self.payment_method),
+            error_message: error_message.or(self.error_message),
+            payment_method_id: payment_method_id.or(self.payment_method_id),
+            cancellation_reason: cancellation_reason.or(self.cancellation_reason),
+            modified_at,
+            mandate_id: mandate_id.or(self.mandate_id),
+            browser_info: browser_info.or(self.browser_info),
+            payment_token: payment_token.or(self.payment_token),
+            error_code: error_code.or(self.error_code),
+            connector_metadata: connector_metadata.or(self.connector_metadata),
+            payment_method_data: payment_method_data.or(self.payment_method_data),
+            encrypted_payment_method_data: encrypted_payment_method_data
+                .or(self.encrypted_payment_method_data),
+            payment_method_type: payment_method_type.or(self.payment_method_type),
+            payment_experience: payment_experience.or(self.payment_experience),
+            business_sub_label: business_sub_label.or(self.business_sub_label),
+            straight_through_algorithm: straight_through_algorithm
+                .or(self.straight_through_algorithm),
+            preprocessing_step_id: preprocessing_step_id.or(self.preprocessing_step_id),
+            error_reason: error_reason.or(self.error_reason),
+            capture_method: capture_method.or(self.capture_method),
+            connector_response_reference_id: connector_response_reference_id
+                .or(self.connector_response_reference_id),
+            multiple_capture_count: multiple_capture_count.or(self.multiple_capture_count),
+            surcharge_amount: surcharge_amount.or(self.surcharge_amount),
+            tax_amount: tax_amount.or(self.tax_amount),
+            amount_capturable: amount_capturable.or(self.amount_capturable),
+            updated_by,
+            merchant_connector_id: merchant_connector_id.or(self.merchant_connector_id),
+            authentication_data: authentication_data.or(self.authentication_data),
+            encoded_data: encoded_data.or(self.encoded_data),
+            unified_code: unified_code.or(self.unified_code),
+            unified_message: unified_message.or(self.unified_message),
+            external_three_ds_authentication_attempted: external_three_ds_authentication_attempted
+                .or(self.external_three_ds_authentication_attempted),
+            authentication_connector: authentication_connector.or(self.authentication_connector),
+            authentication_id: authentication_id.or(self.authentication_id),
+            fingerprint_id: fingerprint_id.or(self.fingerprint_id),
+            payment_method_billing_address_id: payment_method_billing_address_id
+                .or(self.payment_method_billing_address_id),
+            client_source: client_source.or(self.client_source),
+            client_version: client_version.or(self.client_version),
+            customer_acceptance: customer_acceptance.or(self.customer_acceptance),
+            card_network: card_network.or(self.card_network),
+            capture_before: capture_before.or(self.capture_before),
+            extended_authorization_applied: extended_authorization_applied
+                .or(self.extended_authorization_applied),
+            extended_authorization_last_applied_at: extended_authorization_last_applied_at
+                .or(self.extended_authorization_last_applied_at),
+            tokenization: tokenization.or(self.tokenization),
+            shipping_cost: shipping_cost.or(self.shipping_cost),
+            order_tax_amount: order_tax_amount.or(self.order_tax_amount),
+            installment_data: installment_data.or(self.installment_data),
+            connector_mandate_detail: connector_mandate_detail.or(self.connector_mandate_detail),
+            processor_transaction_data: processor_transaction_data
+                .or(self.processor_transaction_data),
+            card_discovery: card_discovery.or(self.card_discovery),
+            charges: charges.or(self.charges),
+            issuer_error_code: issuer_error_code.or(self.issuer_error_code),
+            issuer_error_message: issuer_error_message.or(self.issuer_error_message),
+            setup_future_usage_applied: setup_future_usage_applied
+                .or(self.setup_future_usage_applied),
+            routing_approach: routing_approach.or(self.routing_approach),
+            connector_request_reference_id: connector_request_reference_id
+                .or(self.connector_request_reference_id),
+            network_transaction_id: network_transaction_id.or(self.network_transaction_id),
+            is_overcapture_enabled: is_overcapture_enabled.or(self.is_overcapture_enabled),
+            network_details: network_details.or(self.network_details),
+            is_stored_credential: is_stored_credential.or(self.is_stored_credential),
+            request_extended_authorization: request_extended_authorization
+                .or(self.request_extended_authorization),
+            authorized_amount: authorized_amount.or(self.authorized_amount),
+            error_details: error_details.or(self.error_details),
+        }
+    }
 }
 
 #[cfg(feature = "v2")]
@@ -4637,6 +4821,52 @@ common_utils::impl_to_sql_from_sql_json!(PaymentAttemptFeatureMetadata);
 
 mod tests {
 
+    #[cfg(feature = "v1")]
+    #[test]
+    fn test_payment_attempt_update_internal_merge() {
+        use super::{PaymentAttemptUpdate, PaymentAttemptUpdateInternal};
+        use crate::enums::AttemptStatus;
+
+        let blocklist_update = || {
+            PaymentAttemptUpdateInternal::from(PaymentAttemptUpdate::BlocklistUpdate {
+                status: AttemptStatus::Failure,
+                error_code: Some(Some("BL_01".to_string())),
+                error_message: Some(Some("Blocked".to_string())),
+                updated_by: "blocklist".to_string(),
+            })
+        };
+        let status_update = || {
+            PaymentAttemptUpdateInternal::from(PaymentAttemptUpdate::StatusUpdate {
+                status: AttemptStatus::Charged,
+                updated_by: "status".to_string(),
+            })
+        };
+
+        // The fields left unset by the later changeset keep the values of the earlier one
+        let later = status_update();
+        let modified_at = later.modified_at;
+        let merged = blocklist_update().merge(later);
+        assert_eq!(merged.status, Some(AttemptStatus::Charged));
+        assert_eq!(merged.error_code, Some(Some("BL_01".to_string())));
+        assert_eq!(merged.error_message, Some(Some("Blocked".to_string())));
+        assert_eq!(merged.connector, Some(None));
+        assert_eq!(merged.updated_by, "status");
+        assert_eq!(merged.modified_at, modified_at);
+
+        // The fields set by the later changeset take precedence, including the ones it clears
+        let later = PaymentAttemptUpdateInternal::from(PaymentAttemptUpdate::BlocklistUpdate {
+            status: AttemptStatus::Failure,
+            error_code: Some(None),
+            error_message: None,
+            updated_by: "blocklist".to_string(),
+        });
+        let merged = blocklist_update().merge(status_update()).merge(later);
+        assert_eq!(merged.status, Some(AttemptStatus::Failure));
+        assert_eq!(merged.error_code, Some(None));
+        assert_eq!(merged.error_message, Some(Some("Blocked".to_string())));
+        assert_eq!(merged.updated_by, "blocklist");
+    }
+
     #[test]
     fn test_backwards_compatibility() {
         let serialized_payment_attempt = r#"{
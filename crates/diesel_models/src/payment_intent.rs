@@ -928,6 +928,128 @@ pub struct PaymentIntentUpdateInternal {
     pub installment_options: Option<common_types::payments::InstallmentOptions>,
 }
 
+#[cfg(feature = "v1")]
+impl PaymentIntentUpdateInternal {
+    /// Combines the changeset with one applied after it, so that applying the result is the same as
+    /// applying both of them in order.
+    pub fn merge(self, later: Self) -> Self {
+        let Self {
+            amount,
+            currency,
+            status,
+            amount_captured,
+            customer_id,
+            return_url,
+            setup_future_usage,
+            off_session,
+            metadata,
+            billing_address_id,
+            shipping_address_id,
+            modified_at,
+            active_attempt_id,
+            business_country,
+            business_label,
+            description,
+            statement_descriptor_name,
+            statement_descriptor_suffix,
+            order_details,
+            attempt_count,
+            merchant_decision,
+            payment_confirm_source,
+            updated_by,
+            surcharge_applicable,
+            incremental_authorization_allowed,
+            authorization_count,
+            session_expiry,
+            fingerprint_id,
+            request_external_three_ds_authentication,
+            frm_metadata,
+            customer_details,
+            billing_details,
+            merchant_order_reference_id,
+            shipping_details,
+            is_payment_processor_token_flow,
+            tax_details,
+            force_3ds_challenge,
+            is_iframe_redirection_enabled,
+            extended_return_url,
+            payment_channel,
+            feature_metadata,
+            tax_status,
+            discount_amount,
+            order_date,
+            shipping_amount_tax,
+            duty_amount,
+            enable_partial_authorization,
+            enable_overcapture,
+            shipping_cost,
+            state_metadata,
+            installment_options,
+        } = later;
+
+        Self {
+            amount: amount.or(self.amount),
+            currency: currency.or(self.currency),
+            status: status.or(self.status),
+            amount_captured: amount_captured.or(self.amount_captured),
+            customer_id: customer_id.or(self.customer_id),
+            return_url: return_url.or(self.return_url),
+            setup_future_usage: setup_future_usage.or(self.setup_future_usage),
+            off_session: off_session.or(self.off_session),
+            metadata: metadata.or(self.metadata),
+            billing_address_id: billing_address_id.or(self.billing_address_id),
+            shipping_address_id: shipping_address_id.or(self.shipping_address_id),
+            modified_at,
+            active_attempt_id: active_attempt_id.or(self.active_attempt_id),
+            business_country: business_country.or(self.business_country),
+            business_label: business_label.or(self.business_label),
+            description: description.or(self.description),
+            statement_descriptor_name: statement_descriptor_name.or(self.statement_descriptor_name),
+            statement_descriptor_suffix: statement_descriptor_suffix
+                .or(self.statement_descriptor_suffix),
+            order_details: order_details.or(self.order_details),
+            attempt_count: attempt_count.or(self.attempt_count),
+            merchant_decision: merchant_decision.or(self.merchant_decision),
+            payment_confirm_source: payment_confirm_source.or(self.payment_confirm_source),
+            updated_by,
+            surcharge_applicable: surcharge_applicable.or(self.surcharge_applicable),
+            incremental_authorization_allowed: incremental_authorization_allowed
+                .or(self.incremental_authorization_allowed),
+            authorization_count: authorization_count.or(self.authorization_count),
+            session_expiry: session_expiry.or(self.session_expiry),
+            fingerprint_id: fingerprint_id.or(self.fingerprint_id),
+            request_external_three_ds_authentication: request_external_three_ds_authentication
+                .or(self.request_external_three_ds_authentication),
+            frm_metadata: frm_metadata.or(self.frm_metadata),
+            customer_details: customer_details.or(self.customer_details),
+            billing_details: billing_details.or(self.billing_details),
+            merchant_order_reference_id: merchant_order_reference_id
+                .or(self.merchant_order_reference_id),
+            shipping_details: shipping_details.or(self.shipping_details),
+            is_payment_processor_token_flow: is_payment_processor_token_flow
+                .or(self.is_payment_processor_token_flow),
+            tax_details: tax_details.or(self.tax_details),
+            force_3ds_challenge: force_3ds_challenge.or(self.force_3ds_challenge),
+            is_iframe_redirection_enabled: is_iframe_redirection_enabled
+                .or(self.is_iframe_redirection_enabled),
+            extended_return_url: extended_return_url.or(self.extended_return_url),
+            payment_channel: payment_channel.or(self.payment_channel),
+            feature_metadata: feature_metadata.or(self.feature_metadata),
+            tax_status: tax_status.or(self.tax_status),
+            discount_amount: discount_amount.or(self.discount_amount),
+            order_date: order_date.or(self.order_date),
+            shipping_amount_tax: shipping_amount_tax.or(self.shipping_amount_tax),
+            duty_amount: duty_amount.or(self.duty_amount),
+            enable_partial_authorization: enable_partial_authorization
+                .or(self.enable_partial_authorization),
+            enable_overcapture: enable_overcapture.or(self.enable_overcapture),
+            shipping_cost: shipping_cost.or(self.shipping_cost),
+            state_metadata: state_metadata.or(self.state_metadata),
+            installment_options: installment_options.or(self.installment_options),
+        }
+    }
+}
+
 #[cfg(feature = "v1")]
 impl PaymentIntentUpdate {
     pub fn apply_changeset(self, source: PaymentIntent) -> PaymentIntent {
@@ -2077,4 +2199,33 @@ mod tests {
 
         assert!(deserialized_payment_intent.is_ok());
     }
+
+    #[cfg(feature = "v1")]
+    #[test]
+    fn test_merge_update_internal() {
+        use common_utils::types::MinorUnit;
+
+        use super::{storage_enums, PaymentIntentUpdate, PaymentIntentUpdateInternal};
+
+        let earlier = PaymentIntentUpdateInternal::from(PaymentIntentUpdate::ResponseUpdate {
+            status: storage_enums::IntentStatus::Succeeded,
+            amount_captured: Some(MinorUnit::new(100)),
+            fingerprint_id: None,
+            updated_by: "earlier".to_string(),
+            incremental_authorization_allowed: None,
+            feature_metadata: None,
+        });
+        let later = PaymentIntentUpdateInternal::from(PaymentIntentUpdate::MetadataUpdate {
+            metadata: Some(serde_json::json!({ "key": "value" })),
+            updated_by: "later".to_string(),
+            feature_metadata: None,
+        });
+
+        let merged = earlier.merge(later);
+
+        assert_eq!(merged.status, Some(storage_enums::IntentStatus::Succeeded));
+        assert_eq!(merged.amount_captured, Some(MinorUnit::new(100)));
+        assert_eq!(merged.metadata, Some(serde_json::json!({ "key": "value" })));
+        assert_eq!(merged.updated_by, "later");
+    }
 }
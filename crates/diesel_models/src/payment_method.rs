@@ -1389,3 +1389,14 @@ impl From<PaymentsMandateReference> for CommonMandateReference {
         }
     }
 }
+
+/// Re-encrypted fields of a payment method, written while rotating the merchant data encryption key
+#[cfg(feature = "v1")]
+#[derive(Clone, Debug, Default, AsChangeset, router_derive::DebugAsDisplay)]
+#[diesel(table_name = payment_methods)]
+pub struct PaymentMethodEncryptionUpdateInternal {
+    pub payment_method_data: Option<Encryption>,
+    pub payment_method_billing_address: Option<Encryption>,
+    pub network_token_payment_method_data: Option<Encryption>,
+    pub customer_details: Option<Encryption>,
+}
@@ -203,6 +203,30 @@ impl From<ProcessTrackerUpdate> for ProcessTrackerUpdateInternal {
     }
 }
 
+impl ProcessTrackerUpdateInternal {
+    pub fn apply_changeset(self, source: ProcessTracker) -> ProcessTracker {
+        let Self {
+            name,
+            retry_count,
+            schedule_time,
+            tracking_data,
+            business_status,
+            status,
+            updated_at,
+        } = self;
+        ProcessTracker {
+            name: name.or(source.name),
+            retry_count: retry_count.unwrap_or(source.retry_count),
+            schedule_time: schedule_time.or(source.schedule_time),
+            tracking_data: tracking_data.unwrap_or(source.tracking_data),
+            business_status: business_status.unwrap_or(source.business_status),
+            status: status.unwrap_or(source.status),
+            updated_at: updated_at.unwrap_or(source.updated_at),
+            ..source
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use common_utils::ext_traits::StringExt;
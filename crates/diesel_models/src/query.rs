@@ -17,6 +17,7 @@ pub mod dispute;
 pub mod dynamic_routing_stats;
 pub mod events;
 pub mod file;
+pub mod forex_rate_snapshot;
 pub mod fraud_check;
 pub mod generic_link;
 pub mod generics;
@@ -49,4 +50,5 @@ pub mod user;
 pub mod user_authentication_method;
 pub mod user_key_store;
 pub mod user_role;
+pub mod webhook_signing_key;
 mod utils;
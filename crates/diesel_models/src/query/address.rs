@@ -2,7 +2,7 @@ use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods};
 
 use super::generics;
 use crate::{
-    address::{Address, AddressNew, AddressUpdateInternal},
+    address::{Address, AddressEncryptionUpdateInternal, AddressNew, AddressUpdateInternal},
     errors,
     schema::address::dsl,
     PgPooledConn, StorageResult,
@@ -12,6 +12,11 @@ impl AddressNew {
     pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<Address> {
         generics::generic_insert(conn, self).await
     }
+
+    pub async fn batch_insert(addresses: Vec<Self>, conn: &PgPooledConn) -> StorageResult<()> {
+        generics::generic_insert::<_, _, Address>(conn, addresses).await?;
+        Ok(())
+    }
 }
 
 impl Address {
@@ -49,6 +54,43 @@ impl Address {
         }
     }
 
+    /// Lists the addresses of the merchant whose IDs sort after `cursor`, in ID order
+    pub async fn list_by_merchant_id_after_address_id(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        cursor: String,
+        limit: i64,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, Self>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::address_id.gt(cursor)),
+            Some(limit),
+            None,
+            Some(dsl::address_id.asc()),
+        )
+        .await
+    }
+
+    /// Updates the address only if it hasn't been modified since `modified_at`, returns the
+    /// number of updated rows
+    pub async fn update_encrypted_fields_by_address_id(
+        conn: &PgPooledConn,
+        address_id: String,
+        modified_at: time::PrimitiveDateTime,
+        address: AddressEncryptionUpdateInternal,
+    ) -> StorageResult<usize> {
+        generics::generic_update::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::address_id
+                .eq(address_id)
+                .and(dsl::modified_at.eq(modified_at)),
+            address,
+        )
+        .await
+    }
+
     pub async fn update(
         self,
         conn: &PgPooledConn,
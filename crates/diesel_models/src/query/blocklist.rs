@@ -74,4 +74,35 @@ impl Blocklist {
         )
         .await
     }
+
+    pub async fn find_next_expiring_by_merchant_id(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+    ) -> StorageResult<Option<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::expires_at.is_not_null()),
+            Some(1),
+            None,
+            Some(dsl::expires_at.asc()),
+        )
+        .await
+        .map(|entries| entries.into_iter().next())
+    }
+
+    pub async fn delete_expired_by_merchant_id(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        expired_before: time::PrimitiveDateTime,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_delete_with_results::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::expires_at.le(expired_before)),
+        )
+        .await
+    }
 }
@@ -15,6 +15,11 @@ impl CustomerNew {
     pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<Customer> {
         generics::generic_insert(conn, self).await
     }
+
+    pub async fn batch_insert(customers: Vec<Self>, conn: &PgPooledConn) -> StorageResult<()> {
+        generics::generic_insert::<_, _, Customer>(conn, customers).await?;
+        Ok(())
+    }
 }
 
 pub struct CustomerListConstraints {
@@ -283,6 +288,47 @@ impl Customer {
         }
     }
 
+    /// Lists the customers of the merchant whose IDs sort after `cursor`, in ID order
+    #[cfg(feature = "v1")]
+    pub async fn list_by_merchant_id_after_customer_id(
+        conn: &PgPooledConn,
+        merchant_id: &id_type::MerchantId,
+        cursor: String,
+        limit: i64,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, Self>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::customer_id.gt(cursor)),
+            Some(limit),
+            None,
+            Some(dsl::customer_id.asc()),
+        )
+        .await
+    }
+
+    /// Updates the customer only if it hasn't been modified since `modified_at`, returns the
+    /// number of updated rows
+    #[cfg(feature = "v1")]
+    pub async fn update_encrypted_fields_by_customer_id_merchant_id(
+        conn: &PgPooledConn,
+        customer_id: &id_type::CustomerId,
+        merchant_id: &id_type::MerchantId,
+        modified_at: time::PrimitiveDateTime,
+        customer: crate::customers::CustomerEncryptionUpdateInternal,
+    ) -> StorageResult<usize> {
+        generics::generic_update::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::customer_id
+                .eq(customer_id.to_owned())
+                .and(dsl::merchant_id.eq(merchant_id.to_owned()))
+                .and(dsl::modified_at.eq(modified_at)),
+            customer,
+        )
+        .await
+    }
+
     #[cfg(feature = "v1")]
     pub async fn delete_by_customer_id_merchant_id(
         conn: &PgPooledConn,
@@ -0,0 +1,59 @@
+use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods};
+
+use super::generics;
+use crate::{
+    forex_rate_snapshot::{ForexRateSnapshot, ForexRateSnapshotNew},
+    schema::forex_rate_snapshot::dsl,
+    PgPooledConn, StorageResult,
+};
+
+impl ForexRateSnapshotNew {
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<ForexRateSnapshot> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl ForexRateSnapshot {
+    pub async fn find_latest_as_of(
+        conn: &PgPooledConn,
+        provider: Option<&str>,
+        as_of: time::PrimitiveDateTime,
+    ) -> StorageResult<Option<Self>> {
+        Self::list_latest_as_of(conn, provider, as_of, 1)
+            .await
+            .map(|snapshots| snapshots.into_iter().next())
+    }
+
+    /// Lists the snapshots taken at or before the given time, latest first
+    pub async fn list_latest_as_of(
+        conn: &PgPooledConn,
+        provider: Option<&str>,
+        as_of: time::PrimitiveDateTime,
+        limit: i64,
+    ) -> StorageResult<Vec<Self>> {
+        match provider {
+            Some(provider) => {
+                generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+                    conn,
+                    dsl::provider
+                        .eq(provider.to_owned())
+                        .and(dsl::created_at.le(as_of)),
+                    Some(limit),
+                    None,
+                    Some(dsl::created_at.desc()),
+                )
+                .await
+            }
+            None => {
+                generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+                    conn,
+                    dsl::created_at.le(as_of),
+                    Some(limit),
+                    None,
+                    Some(dsl::created_at.desc()),
+                )
+                .await
+            }
+        }
+    }
+}
@@ -312,6 +312,31 @@ where
     })
 }
 
+pub async fn generic_delete_with_results<T, P, R>(
+    conn: &PgPooledConn,
+    predicate: P,
+) -> StorageResult<Vec<R>>
+where
+    T: FilterDsl<P> + HasTable<Table = T> + Table + 'static,
+    Filter<T, P>: IntoUpdateTarget,
+    DeleteStatement<
+        <Filter<T, P> as HasTable>::Table,
+        <Filter<T, P> as IntoUpdateTarget>::WhereClause,
+    >: AsQuery + LoadQuery<'static, PgConnection, R> + QueryFragment<Pg> + Send + 'static,
+    R: Send + 'static,
+{
+    let query = diesel::delete(<T as HasTable>::table().filter(predicate));
+    logger::debug!(query = %debug_query::<Pg, _>(&query).to_string());
+
+    track_database_call::<T, _, _>(
+        query.get_results_async(conn),
+        DatabaseOperation::DeleteWithResult,
+    )
+    .await
+    .change_context(errors::DatabaseError::Others)
+    .attach_printable("Error while deleting")
+}
+
 async fn generic_find_by_id_core<T, Pk, R>(conn: &PgPooledConn, id: Pk) -> StorageResult<R>
 where
     T: FindDsl<Pk> + HasTable<Table = T> + LimitDsl + Table + 'static,
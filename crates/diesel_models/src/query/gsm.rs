@@ -1,5 +1,9 @@
-use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods};
-use error_stack::report;
+use async_bb8_diesel::AsyncRunQueryDsl;
+use common_enums::GsmMatchType;
+use diesel::{
+    associations::HasTable, debug_query, pg::Pg, BoolExpressionMethods, ExpressionMethods, QueryDsl,
+};
+use error_stack::{report, ResultExt};
 
 use crate::{
     errors, gsm::*, query::generics, schema::gateway_status_map::dsl, PgPooledConn, StorageResult,
@@ -32,6 +36,73 @@ impl GatewayStatusMap {
         .await
     }
 
+    /// Lists the rules of the connector, flow and sub flow that match the code or the message
+    /// using a pattern, ordered by their code and message
+    pub async fn find_pattern_rules(
+        conn: &PgPooledConn,
+        connector: String,
+        flow: String,
+        sub_flow: String,
+    ) -> StorageResult<Vec<Self>> {
+        let query = <Self as HasTable>::table()
+            .filter(
+                dsl::connector
+                    .eq(connector)
+                    .and(dsl::flow.eq(flow))
+                    .and(dsl::sub_flow.eq(sub_flow))
+                    .and(
+                        dsl::code_match_type
+                            .ne(GsmMatchType::Exact)
+                            .or(dsl::message_match_type.ne(GsmMatchType::Exact)),
+                    ),
+            )
+            .order((dsl::code.asc(), dsl::message.asc()));
+
+        router_env::logger::debug!(query = %debug_query::<Pg, _>(&query).to_string());
+
+        generics::db_metrics::track_database_call::<Self, _, _>(
+            query.get_results_async(conn),
+            generics::db_metrics::DatabaseOperation::Filter,
+        )
+        .await
+        .change_context(errors::DatabaseError::Others)
+        .attach_printable("Error filtering gsm rules")
+    }
+
+    pub async fn list(
+        conn: &PgPooledConn,
+        connector: Option<String>,
+        flow: Option<String>,
+    ) -> StorageResult<Vec<Self>> {
+        let mut query = <Self as HasTable>::table().into_boxed();
+
+        if let Some(connector) = connector {
+            query = query.filter(dsl::connector.eq(connector));
+        }
+
+        if let Some(flow) = flow {
+            query = query.filter(dsl::flow.eq(flow));
+        }
+
+        let query = query.order((
+            dsl::connector.asc(),
+            dsl::flow.asc(),
+            dsl::sub_flow.asc(),
+            dsl::code.asc(),
+            dsl::message.asc(),
+        ));
+
+        router_env::logger::debug!(query = %debug_query::<Pg, _>(&query).to_string());
+
+        generics::db_metrics::track_database_call::<Self, _, _>(
+            query.get_results_async(conn),
+            generics::db_metrics::DatabaseOperation::Filter,
+        )
+        .await
+        .change_context(errors::DatabaseError::Others)
+        .attach_printable("Error filtering gsm rules")
+    }
+
     pub async fn retrieve_decision(
         conn: &PgPooledConn,
         connector: String,
@@ -8,6 +8,11 @@ impl MandateNew {
     pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<Mandate> {
         generics::generic_insert(conn, self).await
     }
+
+    pub async fn batch_insert(mandates: Vec<Self>, conn: &PgPooledConn) -> StorageResult<()> {
+        generics::generic_insert::<_, _, Mandate>(conn, mandates).await?;
+        Ok(())
+    }
 }
 
 impl Mandate {
@@ -2,7 +2,7 @@ use diesel::{associations::HasTable, ExpressionMethods};
 
 use super::generics;
 use crate::{
-    merchant_key_store::{MerchantKeyStore, MerchantKeyStoreNew},
+    merchant_key_store::{MerchantKeyStore, MerchantKeyStoreNew, MerchantKeyStoreUpdateInternal},
     schema::merchant_key_store::dsl,
     PgPooledConn, StorageResult,
 };
@@ -25,6 +25,24 @@ impl MerchantKeyStore {
         .await
     }
 
+    pub async fn update_by_merchant_id(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        merchant_key_store_update: MerchantKeyStoreUpdateInternal,
+    ) -> StorageResult<Self> {
+        generics::generic_update_with_unique_predicate_get_result::<
+            <Self as HasTable>::Table,
+            _,
+            _,
+            _,
+        >(
+            conn,
+            dsl::merchant_id.eq(merchant_id.to_owned()),
+            merchant_key_store_update,
+        )
+        .await
+    }
+
     pub async fn delete_by_merchant_id(
         conn: &PgPooledConn,
         merchant_id: &common_utils::id_type::MerchantId,
@@ -74,4 +92,20 @@ impl MerchantKeyStore {
         )
         .await
     }
+
+    /// Lists the key stores whose merchant IDs sort after `cursor`, in merchant ID order
+    pub async fn list_after_merchant_id(
+        conn: &PgPooledConn,
+        cursor: String,
+        limit: u32,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::merchant_id.gt(cursor),
+            Some(limit.into()),
+            None,
+            Some(dsl::merchant_id.asc()),
+        )
+        .await
+    }
 }
@@ -28,6 +28,14 @@ impl PaymentAttemptNew {
     pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<PaymentAttempt> {
         generics::generic_insert(conn, self).await
     }
+
+    pub async fn batch_insert(
+        payment_attempts: Vec<Self>,
+        conn: &PgPooledConn,
+    ) -> StorageResult<()> {
+        generics::generic_insert::<_, _, PaymentAttempt>(conn, payment_attempts).await?;
+        Ok(())
+    }
 }
 
 impl PaymentAttempt {
@@ -36,6 +44,18 @@ impl PaymentAttempt {
         self,
         conn: &PgPooledConn,
         payment_attempt: PaymentAttemptUpdate,
+    ) -> StorageResult<Self> {
+        let payment_attempt_update =
+            PaymentAttemptUpdateInternal::from(payment_attempt).populate_derived_fields(&self);
+        self.update_with_attempt_id_and_changeset(conn, payment_attempt_update)
+            .await
+    }
+
+    #[cfg(feature = "v1")]
+    pub async fn update_with_attempt_id_and_changeset(
+        self,
+        conn: &PgPooledConn,
+        payment_attempt_update: PaymentAttemptUpdateInternal,
     ) -> StorageResult<Self> {
         match generics::generic_update_with_unique_predicate_get_result::<
             <Self as HasTable>::Table,
@@ -47,7 +67,7 @@ impl PaymentAttempt {
             dsl::attempt_id
                 .eq(self.attempt_id.to_owned())
                 .and(dsl::processor_merchant_id.eq(self.processor_merchant_id.to_owned())),
-            PaymentAttemptUpdateInternal::from(payment_attempt).populate_derived_fields(&self),
+            payment_attempt_update,
         )
         .await
         {
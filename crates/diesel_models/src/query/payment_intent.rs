@@ -15,6 +15,14 @@ impl PaymentIntentNew {
     pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<PaymentIntent> {
         generics::generic_insert(conn, self).await
     }
+
+    pub async fn batch_insert(
+        payment_intents: Vec<Self>,
+        conn: &PgPooledConn,
+    ) -> StorageResult<()> {
+        generics::generic_insert::<_, _, PaymentIntent>(conn, payment_intents).await?;
+        Ok(())
+    }
 }
 
 impl PaymentIntent {
@@ -52,13 +60,26 @@ impl PaymentIntent {
         self,
         conn: &PgPooledConn,
         payment_intent: payment_intent::PaymentIntentUpdate,
+    ) -> StorageResult<Self> {
+        self.update_with_changeset(
+            conn,
+            payment_intent::PaymentIntentUpdateInternal::from(payment_intent),
+        )
+        .await
+    }
+
+    #[cfg(feature = "v1")]
+    pub async fn update_with_changeset(
+        self,
+        conn: &PgPooledConn,
+        payment_intent_update: payment_intent::PaymentIntentUpdateInternal,
     ) -> StorageResult<Self> {
         match generics::generic_update_with_results::<<Self as HasTable>::Table, _, _, _>(
             conn,
             dsl::payment_id
                 .eq(self.payment_id.to_owned())
                 .and(dsl::processor_merchant_id.eq(self.processor_merchant_id.to_owned())),
-            payment_intent::PaymentIntentUpdateInternal::from(payment_intent),
+            payment_intent_update,
         )
         .await
         {
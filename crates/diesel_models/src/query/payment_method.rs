@@ -21,6 +21,14 @@ impl PaymentMethodNew {
     pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<PaymentMethod> {
         generics::generic_insert(conn, self).await
     }
+
+    pub async fn batch_insert(
+        payment_methods: Vec<Self>,
+        conn: &PgPooledConn,
+    ) -> StorageResult<()> {
+        generics::generic_insert::<_, _, PaymentMethod>(conn, payment_methods).await?;
+        Ok(())
+    }
 }
 
 #[cfg(feature = "v1")]
@@ -221,6 +229,54 @@ impl PaymentMethod {
         }
     }
 
+    /// Lists the payment methods of the merchant whose IDs sort after `cursor`, in ID order
+    pub async fn list_by_merchant_id_after_payment_method_id(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        cursor: String,
+        limit: i64,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, Self>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::payment_method_id.gt(cursor)),
+            Some(limit),
+            None,
+            Some(dsl::payment_method_id.asc()),
+        )
+        .await
+    }
+
+    pub async fn find_optional_by_payment_method_id(
+        conn: &PgPooledConn,
+        payment_method_id: &str,
+    ) -> StorageResult<Option<Self>> {
+        generics::generic_find_one_optional::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::payment_method_id.eq(payment_method_id.to_owned()),
+        )
+        .await
+    }
+
+    /// Updates the payment method only if it hasn't been modified since `last_modified`, returns
+    /// the number of updated rows
+    pub async fn update_encrypted_fields_by_payment_method_id(
+        conn: &PgPooledConn,
+        payment_method_id: String,
+        last_modified: time::PrimitiveDateTime,
+        payment_method: payment_method::PaymentMethodEncryptionUpdateInternal,
+    ) -> StorageResult<usize> {
+        generics::generic_update::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::payment_method_id
+                .eq(payment_method_id)
+                .and(dsl::last_modified.eq(last_modified)),
+            payment_method,
+        )
+        .await
+    }
+
     pub async fn find_by_fingerprint_id(
         conn: &PgPooledConn,
         fingerprint_id: &str,
@@ -23,6 +23,14 @@ impl PayoutAttemptNew {
     pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<PayoutAttempt> {
         generics::generic_insert(conn, self).await
     }
+
+    pub async fn batch_insert(
+        payout_attempts: Vec<Self>,
+        conn: &PgPooledConn,
+    ) -> StorageResult<()> {
+        generics::generic_insert::<_, _, PayoutAttempt>(conn, payout_attempts).await?;
+        Ok(())
+    }
 }
 
 impl PayoutAttempt {
@@ -18,6 +18,11 @@ impl PayoutsNew {
     pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<Payouts> {
         generics::generic_insert(conn, self).await
     }
+
+    pub async fn batch_insert(payouts: Vec<Self>, conn: &PgPooledConn) -> StorageResult<()> {
+        generics::generic_insert::<_, _, Payouts>(conn, payouts).await?;
+        Ok(())
+    }
 }
 impl Payouts {
     pub async fn update(
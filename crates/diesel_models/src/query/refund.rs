@@ -15,6 +15,11 @@ impl RefundNew {
     pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<Refund> {
         generics::generic_insert(conn, self).await
     }
+
+    pub async fn batch_insert(refunds: Vec<Self>, conn: &PgPooledConn) -> StorageResult<()> {
+        generics::generic_insert::<_, _, Refund>(conn, refunds).await?;
+        Ok(())
+    }
 }
 
 #[cfg(feature = "v1")]
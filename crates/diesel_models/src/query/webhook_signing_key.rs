@@ -0,0 +1,84 @@
+use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods};
+
+use super::generics;
+use crate::{
+    schema::webhook_signing_key::dsl,
+    webhook_signing_key::{
+        WebhookSigningKey, WebhookSigningKeyNew, WebhookSigningKeyUpdate,
+        WebhookSigningKeyUpdateInternal,
+    },
+    PgPooledConn, StorageResult,
+};
+
+impl WebhookSigningKeyNew {
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<WebhookSigningKey> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl WebhookSigningKey {
+    pub async fn find_by_profile_id_key_id(
+        conn: &PgPooledConn,
+        profile_id: &common_utils::id_type::ProfileId,
+        key_id: &str,
+    ) -> StorageResult<Self> {
+        generics::generic_find_one::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::profile_id
+                .eq(profile_id.to_owned())
+                .and(dsl::key_id.eq(key_id.to_owned())),
+        )
+        .await
+    }
+
+    pub async fn list_by_profile_id(
+        conn: &PgPooledConn,
+        profile_id: &common_utils::id_type::ProfileId,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::profile_id.eq(profile_id.to_owned()),
+            None,
+            None,
+            Some(dsl::version.desc()),
+        )
+        .await
+    }
+
+    pub async fn update_by_profile_id_key_id(
+        conn: &PgPooledConn,
+        profile_id: &common_utils::id_type::ProfileId,
+        key_id: &str,
+        webhook_signing_key_update: WebhookSigningKeyUpdate,
+    ) -> StorageResult<Self> {
+        generics::generic_update_with_unique_predicate_get_result::<
+            <Self as HasTable>::Table,
+            _,
+            _,
+            _,
+        >(
+            conn,
+            dsl::profile_id
+                .eq(profile_id.to_owned())
+                .and(dsl::key_id.eq(key_id.to_owned())),
+            WebhookSigningKeyUpdateInternal::from(webhook_signing_key_update),
+        )
+        .await
+    }
+
+    pub async fn update_by_profile_id_status(
+        conn: &PgPooledConn,
+        profile_id: &common_utils::id_type::ProfileId,
+        status: common_enums::WebhookSigningKeyStatus,
+        webhook_signing_key_update: WebhookSigningKeyUpdate,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_update_with_results::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::profile_id
+                .eq(profile_id.to_owned())
+                .and(dsl::status.eq(status)),
+            WebhookSigningKeyUpdateInternal::from(webhook_signing_key_update),
+        )
+        .await
+    }
+}
@@ -75,3 +75,23 @@ pub struct RelayUpdateInternal {
     pub error_message: Option<String>,
     pub modified_at: PrimitiveDateTime,
 }
+
+impl RelayUpdateInternal {
+    pub fn apply_changeset(self, source: Relay) -> Relay {
+        let Self {
+            connector_reference_id,
+            status,
+            error_code,
+            error_message,
+            modified_at,
+        } = self;
+        Relay {
+            connector_reference_id: connector_reference_id.or(source.connector_reference_id),
+            status: status.unwrap_or(source.status),
+            error_code: error_code.or(source.error_code),
+            error_message: error_message.or(source.error_message),
+            modified_at,
+            ..source
+        }
+    }
+}
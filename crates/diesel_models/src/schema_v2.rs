@@ -55,6 +55,10 @@ diesel::table! {
         created_at -> Timestamp,
         expires_at -> Nullable<Timestamp>,
         last_used -> Nullable<Timestamp>,
+        #[max_length = 64]
+        profile_id -> Nullable<Varchar>,
+        scopes -> Nullable<Jsonb>,
+        ip_allowlist -> Nullable<Jsonb>,
     }
 }
 
@@ -196,6 +200,7 @@ diesel::table! {
         data_kind -> BlocklistDataKind,
         metadata -> Nullable<Jsonb>,
         created_at -> Timestamp,
+        expires_at -> Nullable<Timestamp>,
     }
 }
 
@@ -575,6 +580,8 @@ diesel::table! {
         delivery_attempt -> Nullable<WebhookDeliveryAttempt>,
         metadata -> Nullable<Jsonb>,
         is_overall_delivery_successful -> Nullable<Bool>,
+        #[max_length = 64]
+        webhook_endpoint_id -> Nullable<Varchar>,
     }
 }
 
@@ -607,6 +614,20 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    forex_rate_snapshot (snapshot_id) {
+        #[max_length = 64]
+        snapshot_id -> Varchar,
+        #[max_length = 64]
+        provider -> Varchar,
+        rates -> Jsonb,
+        created_at -> Timestamp,
+    }
+}
+
 diesel::table! {
     use diesel::sql_types::*;
     use crate::enums::diesel_exports::*;
@@ -680,6 +701,10 @@ diesel::table! {
         description -> Nullable<Varchar>,
         #[max_length = 1024]
         user_guidance_message -> Nullable<Varchar>,
+        #[max_length = 64]
+        code_match_type -> Nullable<Varchar>,
+        #[max_length = 64]
+        message_match_type -> Nullable<Varchar>,
     }
 }
 
@@ -979,6 +1004,8 @@ diesel::table! {
         merchant_id -> Varchar,
         key -> Bytea,
         created_at -> Timestamp,
+        key_version -> Int4,
+        previous_keys -> Nullable<Bytea>,
     }
 }
 
@@ -1095,6 +1122,7 @@ diesel::table! {
         #[max_length = 64]
         retry_type -> Nullable<Varchar>,
         installment_data -> Nullable<Jsonb>,
+        retry_hop -> Nullable<Jsonb>,
         payment_method_type_v2 -> Nullable<Varchar>,
         #[max_length = 128]
         connector_payment_id -> Nullable<Varchar>,
@@ -1813,6 +1841,27 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    webhook_signing_key (key_id) {
+        #[max_length = 64]
+        key_id -> Varchar,
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        #[max_length = 64]
+        profile_id -> Varchar,
+        version -> Int4,
+        secret -> Bytea,
+        #[max_length = 32]
+        status -> Varchar,
+        created_at -> Timestamp,
+        modified_at -> Timestamp,
+        expires_at -> Nullable<Timestamp>,
+    }
+}
+
 diesel::allow_tables_to_appear_in_same_query!(
     address,
     api_keys,
@@ -1831,6 +1880,7 @@ diesel::allow_tables_to_appear_in_same_query!(
     dynamic_routing_stats,
     events,
     file_metadata,
+    forex_rate_snapshot,
     fraud_check,
     gateway_status_map,
     generic_link,
@@ -1864,4 +1914,5 @@ diesel::allow_tables_to_appear_in_same_query!(
     user_key_store,
     user_roles,
     users,
+    webhook_signing_key,
 );
@@ -125,3 +125,26 @@ impl SubscriptionUpdate {
         }
     }
 }
+
+impl SubscriptionUpdate {
+    pub fn apply_changeset(self, source: Subscription) -> Subscription {
+        let Self {
+            connector_subscription_id,
+            payment_method_id,
+            status,
+            modified_at,
+            plan_id,
+            item_price_id,
+        } = self;
+        Subscription {
+            connector_subscription_id: connector_subscription_id
+                .or(source.connector_subscription_id),
+            payment_method_id: payment_method_id.or(source.payment_method_id),
+            status: status.unwrap_or(source.status),
+            modified_at,
+            plan_id: plan_id.or(source.plan_id),
+            item_price_id: item_price_id.or(source.item_price_id),
+            ..source
+        }
+    }
+}
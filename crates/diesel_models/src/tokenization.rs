@@ -45,3 +45,15 @@ pub struct TokenizationUpdateInternal {
     pub updated_at: PrimitiveDateTime,
     pub flag: Option<common_enums::enums::TokenizationFlag>,
 }
+
+#[cfg(all(feature = "v2", feature = "tokenization_v2"))]
+impl TokenizationUpdateInternal {
+    pub fn apply_changeset(self, source: Tokenization) -> Tokenization {
+        let Self { updated_at, flag } = self;
+        Tokenization {
+            updated_at,
+            flag: flag.unwrap_or(source.flag),
+            ..source
+        }
+    }
+}
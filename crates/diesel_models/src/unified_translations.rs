@@ -48,3 +48,17 @@ impl From<UnifiedTranslationsUpdate> for UnifiedTranslationsUpdateInternal {
         }
     }
 }
+
+impl UnifiedTranslationsUpdate {
+    pub fn apply_changeset(self, source: UnifiedTranslations) -> UnifiedTranslations {
+        let UnifiedTranslationsUpdateInternal {
+            translation,
+            last_modified_at,
+        } = self.into();
+        UnifiedTranslations {
+            translation: translation.unwrap_or(source.translation),
+            last_modified_at,
+            ..source
+        }
+    }
+}
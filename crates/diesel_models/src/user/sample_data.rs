@@ -18,7 +18,7 @@ use time::PrimitiveDateTime;
 use crate::{
     enums::{MandateDataType, MandateDetails},
     schema::payment_attempt,
-    ConnectorMandateReferenceId, NetworkDetails, PaymentAttemptNew,
+    ConnectorMandateReferenceId, NetworkDetails, PaymentAttempt, PaymentAttemptNew,
 };
 
 // #[cfg(feature = "v2")]
@@ -322,6 +322,107 @@ impl PaymentAttemptBatchNew {
             error_details: None,
             retry_type: None,
             installment_data: None,
+            retry_hop: None,
+        }
+    }
+}
+
+/// Sample attempts are inserted as is, without the fields set once a payment is processed, which
+/// is how the stores without a database build the inserted row.
+#[cfg(feature = "v1")]
+impl From<PaymentAttemptBatchNew> for PaymentAttempt {
+    fn from(batch: PaymentAttemptBatchNew) -> Self {
+        Self {
+            payment_id: batch.payment_id,
+            merchant_id: batch.merchant_id,
+            attempt_id: batch.attempt_id,
+            status: batch.status,
+            amount: batch.amount,
+            currency: batch.currency,
+            save_to_locker: batch.save_to_locker,
+            connector: batch.connector,
+            error_message: batch.error_message,
+            offer_amount: batch.offer_amount,
+            surcharge_amount: batch.surcharge_amount,
+            tax_amount: batch.tax_amount,
+            payment_method_id: batch.payment_method_id,
+            payment_method: batch.payment_method,
+            capture_method: batch.capture_method,
+            capture_on: batch.capture_on,
+            confirm: batch.confirm,
+            authentication_type: batch.authentication_type,
+            created_at: batch.created_at,
+            modified_at: batch.modified_at,
+            last_synced: batch.last_synced,
+            cancellation_reason: batch.cancellation_reason,
+            amount_to_capture: batch.amount_to_capture,
+            mandate_id: batch.mandate_id,
+            browser_info: batch.browser_info,
+            payment_token: batch.payment_token,
+            error_code: batch.error_code,
+            connector_metadata: batch.connector_metadata,
+            payment_experience: batch.payment_experience,
+            payment_method_type: batch.payment_method_type,
+            payment_method_data: batch.payment_method_data,
+            encrypted_payment_method_data: batch.encrypted_payment_method_data,
+            business_sub_label: batch.business_sub_label,
+            straight_through_algorithm: batch.straight_through_algorithm,
+            preprocessing_step_id: batch.preprocessing_step_id,
+            mandate_details: batch.mandate_details,
+            error_reason: batch.error_reason,
+            connector_response_reference_id: batch.connector_response_reference_id,
+            connector_transaction_id: batch.connector_transaction_id,
+            multiple_capture_count: batch.multiple_capture_count,
+            amount_capturable: batch.amount_capturable,
+            updated_by: batch.updated_by,
+            merchant_connector_id: batch.merchant_connector_id,
+            authentication_data: batch.authentication_data,
+            encoded_data: batch.encoded_data,
+            unified_code: batch.unified_code,
+            unified_message: batch.unified_message,
+            net_amount: batch.net_amount,
+            external_three_ds_authentication_attempted: batch
+                .external_three_ds_authentication_attempted,
+            authentication_connector: batch.authentication_connector,
+            authentication_id: batch.authentication_id,
+            mandate_data: batch.mandate_data,
+            payment_method_billing_address_id: batch.payment_method_billing_address_id,
+            fingerprint_id: batch.fingerprint_id,
+            charge_id: batch.charge_id,
+            client_source: batch.client_source,
+            client_version: batch.client_version,
+            customer_acceptance: batch.customer_acceptance,
+            profile_id: batch.profile_id,
+            organization_id: batch.organization_id,
+            shipping_cost: batch.shipping_cost,
+            order_tax_amount: batch.order_tax_amount,
+            processor_transaction_data: batch.processor_transaction_data,
+            connector_mandate_detail: batch.connector_mandate_detail,
+            request_extended_authorization: batch.request_extended_authorization,
+            extended_authorization_applied: batch.extended_authorization_applied,
+            tokenization: batch.tokenization,
+            extended_authorization_last_applied_at: batch.extended_authorization_last_applied_at,
+            capture_before: batch.capture_before,
+            card_discovery: batch.card_discovery,
+            processor_merchant_id: batch.processor_merchant_id,
+            created_by: batch.created_by,
+            setup_future_usage_applied: batch.setup_future_usage_applied,
+            routing_approach: batch.routing_approach,
+            connector_request_reference_id: batch.connector_request_reference_id,
+            network_transaction_id: batch.network_transaction_id,
+            network_details: batch.network_details,
+            is_stored_credential: batch.is_stored_credential,
+            authorized_amount: batch.authorized_amount,
+            connector_transaction_data: None,
+            charges: None,
+            issuer_error_code: None,
+            issuer_error_message: None,
+            card_network: None,
+            is_overcapture_enabled: None,
+            error_details: None,
+            installment_data: None,
+            retry_type: None,
+            retry_hop: None,
         }
     }
 }
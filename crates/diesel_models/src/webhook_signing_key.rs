@@ -0,0 +1,91 @@
+use common_utils::encryption::Encryption;
+use diesel::{AsChangeset, Identifiable, Insertable, Queryable, Selectable};
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+
+use crate::schema::webhook_signing_key;
+
+#[derive(Clone, Debug, Deserialize, Serialize, Identifiable, Queryable, Selectable)]
+#[diesel(table_name = webhook_signing_key, primary_key(key_id), check_for_backend(diesel::pg::Pg))]
+pub struct WebhookSigningKey {
+    pub key_id: String,
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub profile_id: common_utils::id_type::ProfileId,
+    pub version: i32,
+    pub secret: Encryption,
+    pub status: common_enums::WebhookSigningKeyStatus,
+    pub created_at: PrimitiveDateTime,
+    pub modified_at: PrimitiveDateTime,
+    pub expires_at: Option<PrimitiveDateTime>,
+}
+
+impl WebhookSigningKey {
+    /// Whether the key must be used to sign webhooks sent at the specified time.
+    pub fn is_valid_at(&self, time: PrimitiveDateTime) -> bool {
+        match self.status {
+            common_enums::WebhookSigningKeyStatus::Active => true,
+            common_enums::WebhookSigningKeyStatus::Expiring => {
+                self.expires_at.is_some_and(|expires_at| expires_at > time)
+            }
+            common_enums::WebhookSigningKeyStatus::Retired => false,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Insertable, router_derive::DebugAsDisplay)]
+#[diesel(table_name = webhook_signing_key)]
+pub struct WebhookSigningKeyNew {
+    pub key_id: String,
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub profile_id: common_utils::id_type::ProfileId,
+    pub version: i32,
+    pub secret: Encryption,
+    pub status: common_enums::WebhookSigningKeyStatus,
+    pub created_at: PrimitiveDateTime,
+    pub modified_at: PrimitiveDateTime,
+    pub expires_at: Option<PrimitiveDateTime>,
+}
+
+#[derive(Clone, Debug)]
+pub enum WebhookSigningKeyUpdate {
+    StatusUpdate {
+        status: common_enums::WebhookSigningKeyStatus,
+        expires_at: Option<PrimitiveDateTime>,
+    },
+}
+
+#[derive(Clone, Debug, AsChangeset, router_derive::DebugAsDisplay)]
+#[diesel(table_name = webhook_signing_key)]
+pub struct WebhookSigningKeyUpdateInternal {
+    pub status: Option<common_enums::WebhookSigningKeyStatus>,
+    pub expires_at: Option<PrimitiveDateTime>,
+    pub modified_at: PrimitiveDateTime,
+}
+
+impl From<WebhookSigningKeyUpdate> for WebhookSigningKeyUpdateInternal {
+    fn from(webhook_signing_key_update: WebhookSigningKeyUpdate) -> Self {
+        match webhook_signing_key_update {
+            WebhookSigningKeyUpdate::StatusUpdate { status, expires_at } => Self {
+                status: Some(status),
+                expires_at,
+                modified_at: common_utils::date_time::now(),
+            },
+        }
+    }
+}
+
+impl WebhookSigningKeyUpdate {
+    pub fn apply_changeset(self, source: WebhookSigningKey) -> WebhookSigningKey {
+        let WebhookSigningKeyUpdateInternal {
+            status,
+            expires_at,
+            modified_at,
+        } = self.into();
+        WebhookSigningKey {
+            status: status.unwrap_or(source.status),
+            expires_at: expires_at.or(source.expires_at),
+            modified_at,
+            ..source
+        }
+    }
+}
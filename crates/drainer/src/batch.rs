@@ -0,0 +1,98 @@
+use std::{collections::HashSet, sync::Arc};
+
+use async_bb8_diesel::AsyncConnection;
+use diesel_models::errors::DatabaseError;
+use router_env::{instrument, tracing};
+
+use crate::{
+    kv, logger, metrics, pg_connection, query, services::Store, stream::StreamEntries, StreamData,
+};
+
+#[derive(Debug, thiserror::Error)]
+enum BatchError {
+    #[error("Failed to execute the statements of the batch: {0:?}")]
+    Query(error_stack::Report<DatabaseError>),
+    #[error("Failed to run the transaction of the batch: {0}")]
+    Transaction(#[from] diesel::result::Error),
+}
+
+/// Applies the entries at the head of the stream in a single transaction, up to the first entry
+/// that can't be parsed or whose key is held, and returns the number of entries applied.
+///
+/// If the transaction fails nothing is applied, and the entries are left to be drained one at a
+/// time, so that the failing entry is found and handled as in the unbatched mode.
+#[instrument(skip_all)]
+pub(crate) async fn drain_batch(
+    store: &Arc<Store>,
+    stream_name: &str,
+    entries: &StreamEntries,
+    held_keys: &HashSet<String>,
+) -> usize {
+    let mut batch = kv::DBOperationBatch::default();
+    let mut drained_entries = Vec::new();
+    for (_, entry) in entries {
+        let Ok(data) = StreamData::from_hashmap(entry.clone()) else {
+            break;
+        };
+        if held_keys.contains(&data.global_id) {
+            break;
+        }
+        drained_entries.push((
+            data.pushed_at,
+            data.typed_sql.operation(),
+            data.typed_sql.table(),
+        ));
+        batch.push(data.typed_sql);
+    }
+
+    let entry_count = drained_entries.len();
+    if entry_count == 0 {
+        return 0;
+    }
+    let statement_count = batch.statement_count();
+
+    let conn = pg_connection(&store.master_pool).await;
+    let (result, execution_time) = Box::pin(common_utils::date_time::time_it(|| {
+        conn.transaction_async(|conn| {
+            Box::pin(async move { batch.execute(&conn).await.map_err(BatchError::Query) })
+        })
+    }))
+    .await;
+
+    let tags =
+        router_env::metric_attributes!(("stream", stream_name.to_owned()), ("mode", "batched"));
+    metrics::BATCH_EXECUTION_TIME.record(execution_time, tags);
+
+    match result {
+        Ok(()) => {
+            logger::info!(entry_count, statement_count, "Applied batch of entries");
+            for (pushed_at, operation, table) in drained_entries {
+                query::push_drainer_delay(
+                    pushed_at,
+                    operation,
+                    table,
+                    router_env::metric_attributes!(("operation", operation), ("table", table)),
+                );
+            }
+            metrics::ENTRIES_DRAINED_PER_STREAM
+                .add(u64::try_from(entry_count).unwrap_or(u64::MAX), tags);
+            metrics::STATEMENTS_EXECUTED_PER_STREAM
+                .add(u64::try_from(statement_count).unwrap_or(u64::MAX), tags);
+
+            entry_count
+        }
+        Err(error) => {
+            logger::error!(
+                ?error,
+                entry_count,
+                "Failed to apply batch of entries, draining them one at a time"
+            );
+            metrics::BATCH_EXECUTION_FAILED.add(
+                1,
+                router_env::metric_attributes!(("stream", stream_name.to_owned())),
+            );
+
+            0
+        }
+    }
+}
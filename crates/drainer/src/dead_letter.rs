@@ -0,0 +1,913 @@
+//! Handling of the entries that repeatedly fail to be drained
+//!
+//! An entry that fails to be drained is attempted again in a later session, with a delay that
+//! doubles after every failure. Only failures that would happen again, such as a query that can't
+//! be applied, are counted; failures to connect to the database are attempted again without being
+//! counted. Once the entry at the head of a partition has failed `max_attempts` times it's moved to
+//! the dead letter stream along with the error, so that it no longer blocks the entries behind it.
+//!
+//! The key of a dead lettered entry is held: the entries of the same key read after it are moved
+//! to the dead letter stream too, so that the updates of a key are never applied out of order.
+//! Dead lettered entries can be inspected, replayed or discarded through the admin endpoints or the
+//! `dead-letter` subcommand. The entries of a key are replayed oldest first, and the key is
+//! released once none of its entries are left in the dead letter stream.
+
+use std::{
+    collections::{HashMap, HashSet},
+    sync::Arc,
+};
+
+use actix_web::{web, HttpRequest, Scope};
+use common_utils::{fp_utils::when, id_type};
+use error_stack::{report, ResultExt};
+use masking::{PeekInterface, Secret, StrongSecret};
+use redis_interface as redis;
+use router_env::{instrument, logger, tracing};
+
+use crate::{
+    errors::{self, DeadLetterApiError},
+    metrics, pg_connection,
+    services::Store,
+    settings::DeadLetterCommand,
+    Settings, StreamData,
+};
+
+/// Header carrying the API key of the admin endpoints
+const API_KEY_HEADER: &str = "api-key";
+
+/// Number of entries listed if no count is provided
+const DEFAULT_LIST_COUNT: u64 = 20;
+
+/// Number of entries read at a time when searching the dead letter stream for the entries of a key
+const KEY_SEARCH_COUNT: u64 = 100;
+
+/// Expiry of the failure count of an entry, in seconds, so that the count of an entry removed
+/// from the stream by other means doesn't linger
+const ENTRY_FAILURE_EXPIRY: i64 = 86400;
+
+/// Delay before an entry is attempted again after its first failure, in seconds
+const ENTRY_RETRY_BASE_DELAY: i64 = 1;
+
+/// Maximum delay before an entry is attempted again, in seconds
+const ENTRY_RETRY_MAX_DELAY: i64 = 60;
+
+/// Field of a stream entry holding the key of the entry, shared by all the updates of a resource
+pub(crate) const GLOBAL_ID_FIELD: &str = "global_id";
+
+/// Error of the entries moved to the dead letter stream because an earlier entry of the same key
+/// was dead lettered
+const HELD_ENTRY_ERROR: &str = "Held behind an earlier dead lettered entry of the same key";
+
+// Fields added to a dead lettered entry, along with the fields of the original entry
+const ERROR_FIELD: &str = "dead_letter_error";
+const ATTEMPTS_FIELD: &str = "dead_letter_attempts";
+const SOURCE_STREAM_FIELD: &str = "dead_letter_source_stream";
+const SOURCE_ENTRY_ID_FIELD: &str = "dead_letter_source_entry_id";
+const FAILED_AT_FIELD: &str = "dead_letter_failed_at";
+
+/// Number of failed attempts of the entry at the head of a partition
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+struct EntryFailure {
+    entry_id: String,
+    attempts: u32,
+    /// Unix timestamp before which the entry isn't attempted again
+    #[serde(default)]
+    retry_at: i64,
+}
+
+/// Returns the delay before an entry that has failed `attempts` times is attempted again, in
+/// seconds.
+fn get_retry_delay(attempts: u32) -> i64 {
+    ENTRY_RETRY_BASE_DELAY
+        .saturating_mul(2_i64.saturating_pow(attempts.saturating_sub(1)))
+        .min(ENTRY_RETRY_MAX_DELAY)
+}
+
+#[derive(Debug, serde::Serialize)]
+pub struct DeadLetterEntry {
+    pub id: String,
+    pub source_stream: Option<String>,
+    pub source_entry_id: Option<String>,
+    pub error: Option<String>,
+    pub attempts: Option<u32>,
+    /// Unix timestamp of the last failed attempt
+    pub failed_at: Option<i64>,
+    /// Fields of the original entry
+    pub fields: HashMap<String, String>,
+}
+
+impl DeadLetterEntry {
+    fn new(id: String, mut fields: HashMap<String, String>) -> Self {
+        Self {
+            id,
+            source_stream: fields.remove(SOURCE_STREAM_FIELD),
+            source_entry_id: fields.remove(SOURCE_ENTRY_ID_FIELD),
+            error: fields.remove(ERROR_FIELD),
+            attempts: fields
+                .remove(ATTEMPTS_FIELD)
+                .and_then(|attempts| attempts.parse().ok()),
+            failed_at: fields
+                .remove(FAILED_AT_FIELD)
+                .and_then(|failed_at| failed_at.parse().ok()),
+            fields,
+        }
+    }
+
+    fn get_global_id(&self) -> Option<&str> {
+        self.fields.get(GLOBAL_ID_FIELD).map(String::as_str)
+    }
+
+    fn get_source_stream(&self) -> errors::DrainerResult<&str> {
+        self.source_stream.as_deref().ok_or_else(|| {
+            report!(errors::DrainerError::UnexpectedError(format!(
+                "Dead letter entry {} has no source stream",
+                self.id
+            )))
+        })
+    }
+}
+
+#[derive(Debug, serde::Serialize)]
+pub struct DeadLetterEntries {
+    /// Number of entries in the dead letter stream
+    pub total_count: usize,
+    pub entries: Vec<DeadLetterEntry>,
+}
+
+impl Store {
+    #[inline(always)]
+    fn get_entry_failure_key(&self, stream_name: &str) -> String {
+        format!("{stream_name}_failed_entry")
+    }
+
+    #[inline(always)]
+    fn get_held_keys_key(&self, stream_name: &str) -> String {
+        format!("{stream_name}_held_keys")
+    }
+
+    async fn get_entry_failure(
+        &self,
+        stream_name: &str,
+    ) -> errors::DrainerResult<Option<EntryFailure>> {
+        match self
+            .redis_conn
+            .get_and_deserialize_key::<EntryFailure>(
+                &self.get_entry_failure_key(stream_name).as_str().into(),
+                "EntryFailure",
+            )
+            .await
+        {
+            Ok(failure) => Ok(Some(failure)),
+            Err(error)
+                if matches!(error.current_context(), redis::errors::RedisError::NotFound) =>
+            {
+                Ok(None)
+            }
+            Err(error) => Err(errors::DrainerError::from(error).into()),
+        }
+    }
+
+    /// Returns whether the entry at the head of the stream can be attempted, which isn't the case
+    /// until the retry delay of its last failure has passed.
+    pub(crate) async fn is_entry_retry_due(
+        &self,
+        stream_name: &str,
+        entry_id: &str,
+    ) -> errors::DrainerResult<bool> {
+        let now = common_utils::date_time::now_unix_timestamp();
+
+        Ok(self
+            .get_entry_failure(stream_name)
+            .await?
+            .filter(|failure| failure.entry_id == entry_id)
+            .is_none_or(|failure| failure.retry_at <= now))
+    }
+
+    /// Returns the keys of the stream with dead lettered entries, whose entries are held.
+    pub(crate) async fn get_held_keys(
+        &self,
+        stream_name: &str,
+    ) -> errors::DrainerResult<HashSet<String>> {
+        let held_keys = self
+            .redis_conn
+            .get_hash_fields::<HashMap<String, String>>(
+                &self.get_held_keys_key(stream_name).as_str().into(),
+            )
+            .await
+            .map_err(errors::DrainerError::from)?;
+
+        Ok(held_keys.into_keys().collect())
+    }
+
+    /// Holds the entries of a key, recording the ID of its last dead lettered entry.
+    async fn hold_key(
+        &self,
+        stream_name: &str,
+        global_id: &str,
+        entry_id: &str,
+    ) -> errors::DrainerResult<()> {
+        self.redis_conn
+            .set_hash_fields_without_modifying_ttl(
+                &self.get_held_keys_key(stream_name).as_str().into(),
+                (global_id, entry_id),
+            )
+            .await
+            .map_err(errors::DrainerError::from)?;
+
+        Ok(())
+    }
+
+    /// Records a failed attempt to drain the entry at the head of the stream, and moves the entry
+    /// to the dead letter stream once it has failed `max_attempts` times.
+    ///
+    /// Returns whether the entry was moved, in which case it must be removed from the stream and
+    /// the entries of its key held.
+    #[instrument(skip(self, entry, error))]
+    pub(crate) async fn record_entry_failure(
+        &self,
+        stream_name: &str,
+        entry_id: &str,
+        entry: HashMap<String, String>,
+        error: String,
+    ) -> errors::DrainerResult<bool> {
+        let failure_key = self.get_entry_failure_key(stream_name);
+
+        // Only the entry at the head of the stream can fail, so the count of any other entry is
+        // stale
+        let attempts = self
+            .get_entry_failure(stream_name)
+            .await?
+            .filter(|failure| failure.entry_id == entry_id)
+            .map_or(0, |failure| failure.attempts)
+            .saturating_add(1);
+
+        if attempts < self.config.max_attempts {
+            let retry_delay = get_retry_delay(attempts);
+            logger::warn!(
+                %attempts,
+                %retry_delay,
+                %error,
+                "Failed to drain entry, it will be attempted again in a later session"
+            );
+            self.redis_conn
+                .serialize_and_set_key_with_expiry(
+                    &failure_key.as_str().into(),
+                    EntryFailure {
+                        entry_id: entry_id.to_owned(),
+                        attempts,
+                        retry_at: common_utils::date_time::now_unix_timestamp()
+                            .saturating_add(retry_delay),
+                    },
+                    ENTRY_FAILURE_EXPIRY,
+                )
+                .await
+                .map_err(errors::DrainerError::from)?;
+
+            return Ok(false);
+        }
+
+        logger::error!(%attempts, %error, "Moving entry to the dead letter stream");
+        self.append_to_dead_letter_stream(stream_name, entry_id, entry, error, attempts)
+            .await?;
+
+        // The count expires anyway, so failing to delete it isn't an error
+        let _ = self
+            .redis_conn
+            .delete_key(&failure_key.as_str().into())
+            .await
+            .map_err(|err| logger::error!(operation = "delete_entry_failure", err=?err));
+
+        Ok(true)
+    }
+
+    /// Moves an entry whose key is held to the dead letter stream, behind the earlier entries of
+    /// its key.
+    #[instrument(skip(self, entry))]
+    pub(crate) async fn hold_entry(
+        &self,
+        stream_name: &str,
+        entry_id: &str,
+        entry: HashMap<String, String>,
+    ) -> errors::DrainerResult<()> {
+        logger::warn!("Moving entry of a held key to the dead letter stream");
+        self.append_to_dead_letter_stream(stream_name, entry_id, entry, HELD_ENTRY_ERROR.into(), 0)
+            .await
+    }
+
+    async fn append_to_dead_letter_stream(
+        &self,
+        stream_name: &str,
+        entry_id: &str,
+        entry: HashMap<String, String>,
+        error: String,
+        attempts: u32,
+    ) -> errors::DrainerResult<()> {
+        let global_id = entry.get(GLOBAL_ID_FIELD).cloned();
+        let failed_at = common_utils::date_time::now_unix_timestamp();
+        let mut fields = entry.into_iter().collect::<Vec<_>>();
+        fields.extend([
+            (ERROR_FIELD.to_owned(), error),
+            (ATTEMPTS_FIELD.to_owned(), attempts.to_string()),
+            (SOURCE_STREAM_FIELD.to_owned(), stream_name.to_owned()),
+            (SOURCE_ENTRY_ID_FIELD.to_owned(), entry_id.to_owned()),
+            (FAILED_AT_FIELD.to_owned(), failed_at.to_string()),
+        ]);
+        self.redis_conn
+            .stream_append_entry(
+                &self.config.dead_letter_stream_name.as_str().into(),
+                &redis::RedisEntryId::AutoGeneratedID,
+                fields,
+            )
+            .await
+            .map_err(errors::DrainerError::from)?;
+
+        // The key is held after the entry is appended, so that releasing the key never misses an
+        // entry of the key
+        if let Some(global_id) = global_id {
+            self.hold_key(stream_name, &global_id, entry_id).await?;
+        }
+
+        metrics::ENTRIES_DEAD_LETTERED.add(
+            1,
+            router_env::metric_attributes!(("stream", stream_name.to_owned())),
+        );
+        let _ = self.record_dead_letter_stream_depth().await;
+
+        Ok(())
+    }
+
+    /// Records the number of entries in the dead letter stream, and returns it.
+    pub(crate) async fn record_dead_letter_stream_depth(&self) -> errors::DrainerResult<usize> {
+        let depth = self
+            .redis_conn
+            .stream_get_length(&self.config.dead_letter_stream_name.as_str().into())
+            .await
+            .map_err(errors::DrainerError::from)
+            .inspect_err(|err| logger::error!(operation = "dead_letter_stream_depth", err=?err))?;
+
+        metrics::DEAD_LETTER_STREAM_DEPTH.record(
+            u64::try_from(depth).unwrap_or(u64::MAX),
+            router_env::metric_attributes!((
+                "stream",
+                self.redis_conn
+                    .add_prefix(&self.config.dead_letter_stream_name)
+            )),
+        );
+
+        Ok(depth)
+    }
+
+    /// Lists at most `count` dead lettered entries, oldest first, starting after the entry with
+    /// the ID `after` if provided.
+    pub async fn list_dead_letter_entries(
+        &self,
+        count: u64,
+        after: Option<&str>,
+    ) -> errors::DrainerResult<DeadLetterEntries> {
+        // An ID prefixed with "(" is exclusive
+        let start = after.map_or_else(|| "-".to_owned(), |entry_id| format!("({entry_id}"));
+        let entries = self
+            .redis_conn
+            .stream_read_range(
+                &self.config.dead_letter_stream_name.as_str().into(),
+                &start,
+                "+",
+                Some(count),
+            )
+            .await
+            .map_err(errors::DrainerError::from)?;
+        let total_count = self.record_dead_letter_stream_depth().await?;
+
+        Ok(DeadLetterEntries {
+            total_count,
+            entries: entries
+                .into_iter()
+                .map(|(entry_id, fields)| DeadLetterEntry::new(entry_id, fields))
+                .collect(),
+        })
+    }
+
+    pub async fn find_dead_letter_entry(
+        &self,
+        entry_id: &str,
+    ) -> errors::DrainerResult<DeadLetterEntry> {
+        self.redis_conn
+            .stream_read_range(
+                &self.config.dead_letter_stream_name.as_str().into(),
+                entry_id,
+                entry_id,
+                Some(1),
+            )
+            .await
+            .map_err(errors::DrainerError::from)?
+            .into_iter()
+            .next()
+            .map(|(entry_id, fields)| DeadLetterEntry::new(entry_id, fields))
+            .ok_or_else(|| {
+                report!(errors::DrainerError::DeadLetterEntryNotFound(
+                    entry_id.to_owned()
+                ))
+            })
+    }
+
+    /// Finds the oldest dead lettered entry of the key read from the stream, with an ID between
+    /// `start` and `end` as accepted by `XRANGE`.
+    async fn find_first_dead_letter_entry_of_key(
+        &self,
+        source_stream: &str,
+        global_id: &str,
+        start: &str,
+        end: &str,
+    ) -> errors::DrainerResult<Option<DeadLetterEntry>> {
+        let mut start = start.to_owned();
+        loop {
+            let entries = self
+                .redis_conn
+                .stream_read_range(
+                    &self.config.dead_letter_stream_name.as_str().into(),
+                    &start,
+                    end,
+                    Some(KEY_SEARCH_COUNT),
+                )
+                .await
+                .map_err(errors::DrainerError::from)?;
+            let Some((last_entry_id, _)) = entries.last() else {
+                return Ok(None);
+            };
+            start = format!("({last_entry_id}");
+
+            let entry_of_key = entries
+                .into_iter()
+                .map(|(entry_id, fields)| DeadLetterEntry::new(entry_id, fields))
+                .find(|entry| {
+                    entry.source_stream.as_deref() == Some(source_stream)
+                        && entry.get_global_id() == Some(global_id)
+                });
+            if entry_of_key.is_some() {
+                return Ok(entry_of_key);
+            }
+        }
+    }
+
+    /// Applies the query of a dead lettered entry and removes it from the dead letter stream.
+    ///
+    /// The entries of a key are replayed oldest first, so the entry is rejected if an earlier
+    /// entry of its key is still dead lettered. Since the later entries of the key are held until
+    /// all of them have been replayed or discarded, the updates of the key are applied in order.
+    pub async fn replay_dead_letter_entry(
+        &self,
+        entry_id: &str,
+    ) -> errors::DrainerResult<DeadLetterEntry> {
+        let entry = self.find_dead_letter_entry(entry_id).await?;
+        let source_stream = entry.get_source_stream()?;
+
+        if let Some(global_id) = entry.get_global_id() {
+            if let Some(earlier_entry) = self
+                .find_first_dead_letter_entry_of_key(
+                    source_stream,
+                    global_id,
+                    "-",
+                    &format!("({entry_id}"),
+                )
+                .await?
+            {
+                return Err(report!(errors::DrainerError::DeadLetterEntryOutOfOrder {
+                    entry_id: entry_id.to_owned(),
+                    earlier_entry_id: earlier_entry.id,
+                }));
+            }
+        }
+
+        let data = StreamData::from_hashmap(entry.fields.clone())
+            .map_err(|error| report!(errors::DrainerError::ParsingError(error)))
+            .attach_printable("Dead letter entry can't be parsed, it can only be discarded")?;
+        let conn = pg_connection(&self.master_pool).await;
+        match data.typed_sql.execute(&conn).await {
+            Ok(_) => {}
+            // The entry was applied before, as in the drainer
+            Err(error)
+                if matches!(
+                    error.current_context(),
+                    diesel_models::errors::DatabaseError::UniqueViolation
+                ) => {}
+            Err(error) => {
+                return Err(
+                    error.change_context(errors::DrainerError::UnexpectedError(format!(
+                        "Failed to apply dead letter entry {entry_id}"
+                    ))),
+                )
+            }
+        }
+
+        self.delete_dead_letter_entry(&entry).await?;
+
+        metrics::DEAD_LETTER_ENTRIES_REPLAYED.add(1, &[]);
+
+        Ok(entry)
+    }
+
+    /// Removes a dead lettered entry without draining it.
+    pub async fn discard_dead_letter_entry(
+        &self,
+        entry_id: &str,
+    ) -> errors::DrainerResult<DeadLetterEntry> {
+        let entry = self.find_dead_letter_entry(entry_id).await?;
+        self.delete_dead_letter_entry(&entry).await?;
+
+        metrics::DEAD_LETTER_ENTRIES_DISCARDED.add(1, &[]);
+
+        Ok(entry)
+    }
+
+    /// Removes an entry from the dead letter stream, and releases its key if none of the entries
+    /// of the key are left.
+    async fn delete_dead_letter_entry(&self, entry: &DeadLetterEntry) -> errors::DrainerResult<()> {
+        self.redis_conn
+            .stream_delete_entries(
+                &self.config.dead_letter_stream_name.as_str().into(),
+                entry.id.as_str(),
+            )
+            .await
+            .map_err(errors::DrainerError::from)?;
+        let _ = self.record_dead_letter_stream_depth().await;
+
+        if let (Some(source_stream), Some(global_id)) =
+            (entry.source_stream.as_deref(), entry.get_global_id())
+        {
+            self.release_key(source_stream, global_id).await?;
+        }
+
+        Ok(())
+    }
+
+    async fn release_key(&self, source_stream: &str, global_id: &str) -> errors::DrainerResult<()> {
+        if self
+            .find_first_dead_letter_entry_of_key(source_stream, global_id, "-", "+")
+            .await?
+            .is_some()
+        {
+            return Ok(());
+        }
+
+        self.redis_conn
+            .delete_hash_fields(
+                &self.get_held_keys_key(source_stream).as_str().into(),
+                global_id,
+            )
+            .await
+            .map_err(errors::DrainerError::from)?;
+
+        // An entry of the key may have been dead lettered in the meantime, in which case the key is
+        // held again
+        if let Some(entry) = self
+            .find_first_dead_letter_entry_of_key(source_stream, global_id, "-", "+")
+            .await?
+        {
+            let entry_id = entry.source_entry_id.as_deref().unwrap_or(&entry.id);
+            self.hold_key(source_stream, global_id, entry_id).await?;
+        }
+
+        Ok(())
+    }
+}
+
+fn get_store(
+    stores: &HashMap<id_type::TenantId, Arc<Store>>,
+    tenant_id: &str,
+) -> Option<Arc<Store>> {
+    id_type::TenantId::try_from_string(tenant_id.to_owned())
+        .ok()
+        .and_then(|tenant_id| stores.get(&tenant_id))
+        .cloned()
+}
+
+/// Runs a `dead-letter` subcommand against the dead letter stream of the tenant, printing the
+/// affected entries as JSON.
+pub async fn run_command(
+    stores: &HashMap<id_type::TenantId, Arc<Store>>,
+    tenant_id: &str,
+    command: DeadLetterCommand,
+) -> errors::DrainerResult<()> {
+    let store = get_store(stores, tenant_id).ok_or_else(|| {
+        report!(errors::DrainerError::UnexpectedError(format!(
+            "Tenant {tenant_id} not found"
+        )))
+    })?;
+
+    let output = match command {
+        DeadLetterCommand::List { count, after } => serde_json::to_string_pretty(
+            &store
+                .list_dead_letter_entries(count, after.as_deref())
+                .await?,
+        ),
+        DeadLetterCommand::Show { entry_id } => {
+            serde_json::to_string_pretty(&store.find_dead_letter_entry(&entry_id).await?)
+        }
+        DeadLetterCommand::Replay { entry_id } => {
+            serde_json::to_string_pretty(&store.replay_dead_letter_entry(&entry_id).await?)
+        }
+        DeadLetterCommand::Discard { entry_id } => {
+            serde_json::to_string_pretty(&store.discard_dead_letter_entry(&entry_id).await?)
+        }
+    }
+    .change_context(errors::DrainerError::UnexpectedError(
+        "Failed to serialize dead letter entries".into(),
+    ))?;
+
+    #[allow(clippy::print_stdout)]
+    {
+        println!("{output}");
+    }
+
+    Ok(())
+}
+
+pub struct DeadLetter;
+
+impl DeadLetter {
+    pub fn server(conf: Settings, stores: HashMap<id_type::TenantId, Arc<Store>>) -> Scope {
+        web::scope("dead_letter/{tenant_id}/entries")
+            .app_data(web::Data::new(conf))
+            .app_data(web::Data::new(stores))
+            .service(web::resource("").route(web::get().to(list_entries)))
+            .service(
+                web::resource("/{entry_id}")
+                    .route(web::get().to(get_entry))
+                    .route(web::delete().to(discard_entry)),
+            )
+            .service(web::resource("/{entry_id}/replay").route(web::post().to(replay_entry)))
+    }
+}
+
+/// Checks the API key of the request, and returns the store of the tenant.
+fn authenticate(
+    req: &HttpRequest,
+    conf: &Settings,
+    stores: &HashMap<id_type::TenantId, Arc<Store>>,
+    tenant_id: &str,
+) -> Result<Arc<Store>, DeadLetterApiError> {
+    let provided_api_key = req
+        .headers()
+        .get(API_KEY_HEADER)
+        .and_then(|api_key| api_key.to_str().ok());
+    let is_authenticated = conf
+        .drainer
+        .dead_letter
+        .admin_api_key
+        .as_ref()
+        .filter(|admin_api_key| !admin_api_key.peek().is_empty())
+        .zip(provided_api_key)
+        .is_some_and(|(admin_api_key, provided_api_key)| {
+            is_admin_api_key(admin_api_key, provided_api_key)
+        });
+    when(!is_authenticated, || Err(DeadLetterApiError::Unauthorized))?;
+
+    get_store(stores, tenant_id)
+        .ok_or_else(|| DeadLetterApiError::TenantNotFound(tenant_id.to_owned()))
+}
+
+/// Checks the provided API key against the admin API key. The keys are compared as strong secrets,
+/// in a time that doesn't depend on where they differ, so that the API key can't be guessed from
+/// the response times.
+fn is_admin_api_key(admin_api_key: &Secret<String>, provided_api_key: &str) -> bool {
+    StrongSecret::<String>::new(admin_api_key.peek().clone())
+        == StrongSecret::new(provided_api_key.to_owned())
+}
+
+fn to_api_error(error: error_stack::Report<errors::DrainerError>) -> DeadLetterApiError {
+    logger::error!(?error);
+    match error.current_context() {
+        errors::DrainerError::DeadLetterEntryNotFound(entry_id) => {
+            DeadLetterApiError::EntryNotFound(entry_id.clone())
+        }
+        errors::DrainerError::DeadLetterEntryOutOfOrder { .. } => {
+            DeadLetterApiError::EntryOutOfOrder {
+                message: error.current_context().to_string(),
+            }
+        }
+        _ => DeadLetterApiError::InternalError {
+            message: error.to_string(),
+        },
+    }
+}
+
+#[derive(Debug, serde::Deserialize)]
+pub struct ListEntriesQuery {
+    count: Option<u64>,
+    after: Option<String>,
+}
+
+#[instrument(skip_all)]
+pub async fn list_entries(
+    req: HttpRequest,
+    conf: web::Data<Settings>,
+    stores: web::Data<HashMap<id_type::TenantId, Arc<Store>>>,
+    path: web::Path<String>,
+    query: web::Query<ListEntriesQuery>,
+) -> Result<web::Json<DeadLetterEntries>, DeadLetterApiError> {
+    let store = authenticate(&req, &conf, &stores, &path.into_inner())?;
+    let query = query.into_inner();
+
+    store
+        .list_dead_letter_entries(
+            query.count.unwrap_or(DEFAULT_LIST_COUNT),
+            query.after.as_deref(),
+        )
+        .await
+        .map(web::Json)
+        .map_err(to_api_error)
+}
+
+#[instrument(skip_all)]
+pub async fn get_entry(
+    req: HttpRequest,
+    conf: web::Data<Settings>,
+    stores: web::Data<HashMap<id_type::TenantId, Arc<Store>>>,
+    path: web::Path<(String, String)>,
+) -> Result<web::Json<DeadLetterEntry>, DeadLetterApiError> {
+    let (tenant_id, entry_id) = path.into_inner();
+    let store = authenticate(&req, &conf, &stores, &tenant_id)?;
+
+    store
+        .find_dead_letter_entry(&entry_id)
+        .await
+        .map(web::Json)
+        .map_err(to_api_error)
+}
+
+#[instrument(skip_all)]
+pub async fn replay_entry(
+    req: HttpRequest,
+    conf: web::Data<Settings>,
+    stores: web::Data<HashMap<id_type::TenantId, Arc<Store>>>,
+    path: web::Path<(String, String)>,
+) -> Result<web::Json<DeadLetterEntry>, DeadLetterApiError> {
+    let (tenant_id, entry_id) = path.into_inner();
+    let store = authenticate(&req, &conf, &stores, &tenant_id)?;
+    logger::info!(%entry_id, "Replaying dead letter entry");
+
+    store
+        .replay_dead_letter_entry(&entry_id)
+        .await
+        .map(web::Json)
+        .map_err(to_api_error)
+}
+
+#[instrument(skip_all)]
+pub async fn discard_entry(
+    req: HttpRequest,
+    conf: web::Data<Settings>,
+    stores: web::Data<HashMap<id_type::TenantId, Arc<Store>>>,
+    path: web::Path<(String, String)>,
+) -> Result<web::Json<DeadLetterEntry>, DeadLetterApiError> {
+    let (tenant_id, entry_id) = path.into_inner();
+    let store = authenticate(&req, &conf, &stores, &tenant_id)?;
+    logger::info!(%entry_id, "Discarding dead letter entry");
+
+    store
+        .discard_dead_letter_entry(&entry_id)
+        .await
+        .map(web::Json)
+        .map_err(to_api_error)
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use super::*;
+    use crate::services::StoreConfig;
+
+    async fn get_test_store(dead_letter_stream_name: &str, max_attempts: u32) -> Store {
+        let redis_conn = redis::RedisConnectionPool::new(&redis::RedisSettings {
+            in_memory: true,
+            ..Default::default()
+        })
+        .await
+        .unwrap();
+
+        Store {
+            master_pool: bb8::Pool::builder().build_unchecked(
+                async_bb8_diesel::ConnectionManager::new("postgres://localhost/drainer_test"),
+            ),
+            redis_conn: Arc::new(redis_conn),
+            config: StoreConfig {
+                drainer_stream_name: "DRAINER_STREAM".into(),
+                drainer_num_partitions: 1,
+                use_legacy_version: false,
+                batch_writes: false,
+                dead_letter_stream_name: dead_letter_stream_name.into(),
+                max_attempts,
+            },
+            request_id: None,
+        }
+    }
+
+    fn get_entry(global_id: &str) -> HashMap<String, String> {
+        HashMap::from([
+            (GLOBAL_ID_FIELD.to_owned(), global_id.to_owned()),
+            ("request_id".to_owned(), "req_1".to_owned()),
+        ])
+    }
+
+    #[test]
+    fn test_is_admin_api_key() {
+        let admin_api_key = Secret::new("admin_key".to_owned());
+
+        assert!(is_admin_api_key(&admin_api_key, "admin_key"));
+        assert!(!is_admin_api_key(&admin_api_key, "admin_kez"));
+        assert!(!is_admin_api_key(&admin_api_key, "admin"));
+        assert!(!is_admin_api_key(&admin_api_key, ""));
+    }
+
+    #[test]
+    fn test_get_retry_delay() {
+        assert_eq!(get_retry_delay(1), 1);
+        assert_eq!(get_retry_delay(2), 2);
+        assert_eq!(get_retry_delay(4), 8);
+        assert_eq!(get_retry_delay(7), ENTRY_RETRY_MAX_DELAY);
+        assert_eq!(get_retry_delay(u32::MAX), ENTRY_RETRY_MAX_DELAY);
+    }
+
+    #[tokio::test]
+    async fn test_entry_is_dead_lettered_after_max_attempts() {
+        let store = get_test_store("test_max_attempts_dead_letter_stream", 2).await;
+        let stream_name = "test_max_attempts_stream";
+
+        let is_dead_lettered = store
+            .record_entry_failure(stream_name, "1-0", get_entry("g1"), "failed".into())
+            .await
+            .unwrap();
+        assert!(!is_dead_lettered);
+        assert!(!store.is_entry_retry_due(stream_name, "1-0").await.unwrap());
+        assert!(store.is_entry_retry_due(stream_name, "2-0").await.unwrap());
+        assert!(store.get_held_keys(stream_name).await.unwrap().is_empty());
+
+        let is_dead_lettered = store
+            .record_entry_failure(stream_name, "1-0", get_entry("g1"), "failed".into())
+            .await
+            .unwrap();
+        assert!(is_dead_lettered);
+        assert!(store.is_entry_retry_due(stream_name, "1-0").await.unwrap());
+        assert_eq!(
+            store.get_held_keys(stream_name).await.unwrap(),
+            HashSet::from(["g1".to_owned()])
+        );
+
+        let entries = store.list_dead_letter_entries(10, None).await.unwrap();
+        assert_eq!(entries.total_count, 1);
+        let entry = entries.entries.first().unwrap();
+        assert_eq!(entry.source_stream.as_deref(), Some(stream_name));
+        assert_eq!(entry.source_entry_id.as_deref(), Some("1-0"));
+        assert_eq!(entry.error.as_deref(), Some("failed"));
+        assert_eq!(entry.attempts, Some(2));
+        assert_eq!(entry.fields, get_entry("g1"));
+    }
+
+    #[tokio::test]
+    async fn test_entries_of_a_key_are_replayed_in_order() {
+        let store = get_test_store("test_replay_order_dead_letter_stream", 1).await;
+        let stream_name = "test_replay_order_stream";
+
+        store
+            .record_entry_failure(stream_name, "1-0", get_entry("g1"), "failed".into())
+            .await
+            .unwrap();
+        store
+            .hold_entry(stream_name, "2-0", get_entry("g1"))
+            .await
+            .unwrap();
+        let entries = store.list_dead_letter_entries(10, None).await.unwrap();
+        assert_eq!(entries.total_count, 2);
+        let first_entry = entries.entries.first().unwrap();
+        let held_entry = entries.entries.get(1).unwrap();
+        assert_eq!(held_entry.error.as_deref(), Some(HELD_ENTRY_ERROR));
+
+        let error = store
+            .replay_dead_letter_entry(&held_entry.id)
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            error.current_context(),
+            errors::DrainerError::DeadLetterEntryOutOfOrder { earlier_entry_id, .. }
+                if *earlier_entry_id == first_entry.id
+        ));
+
+        // The key is held as long as any of its entries are dead lettered
+        store
+            .discard_dead_letter_entry(&first_entry.id)
+            .await
+            .unwrap();
+        assert!(store
+            .get_held_keys(stream_name)
+            .await
+            .unwrap()
+            .contains("g1"));
+
+        store
+            .discard_dead_letter_entry(&held_entry.id)
+            .await
+            .unwrap();
+        assert!(store.get_held_keys(stream_name).await.unwrap().is_empty());
+    }
+}
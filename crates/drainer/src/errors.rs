@@ -17,6 +17,15 @@ pub enum DrainerError {
     UnexpectedError(String),
     #[error("I/O: {0}")]
     IoError(std::io::Error),
+    #[error("Dead letter entry {0} not found")]
+    DeadLetterEntryNotFound(String),
+    #[error(
+        "Dead letter entry {earlier_entry_id} of the same key must be replayed or discarded before {entry_id}"
+    )]
+    DeadLetterEntryOutOfOrder {
+        entry_id: String,
+        earlier_entry_id: String,
+    },
 }
 
 #[derive(Debug, Error, Clone, serde::Serialize)]
@@ -33,6 +42,20 @@ impl From<std::io::Error> for DrainerError {
     }
 }
 
+#[derive(Debug, Error, Clone, serde::Serialize)]
+pub enum DeadLetterApiError {
+    #[error("Invalid or missing API key")]
+    Unauthorized,
+    #[error("Tenant {0} not found")]
+    TenantNotFound(String),
+    #[error("Dead letter entry {0} not found")]
+    EntryNotFound(String),
+    #[error("{message}")]
+    EntryOutOfOrder { message: String },
+    #[error("Failed to process dead letter entries: {message}")]
+    InternalError { message: String },
+}
+
 pub type DrainerResult<T> = error_stack::Result<T, DrainerError>;
 
 impl From<config::ConfigError> for DrainerError {
@@ -56,3 +79,26 @@ impl actix_web::ResponseError for HealthCheckError {
         }
     }
 }
+
+impl actix_web::ResponseError for DeadLetterApiError {
+    fn status_code(&self) -> reqwest::StatusCode {
+        use reqwest::StatusCode;
+
+        match self {
+            Self::Unauthorized => StatusCode::UNAUTHORIZED,
+            Self::TenantNotFound(_) | Self::EntryNotFound(_) => StatusCode::NOT_FOUND,
+            Self::EntryOutOfOrder { .. } => StatusCode::CONFLICT,
+            Self::InternalError { .. } => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+
+    fn error_response(&self) -> actix_web::HttpResponse {
+        let body = serde_json::json!({
+            "message": self.to_string()
+        })
+        .to_string();
+        actix_web::HttpResponse::build(self.status_code())
+            .content_type(mime::APPLICATION_JSON)
+            .body(body)
+    }
+}
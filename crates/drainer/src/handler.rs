@@ -11,8 +11,8 @@ use tokio::{
 };
 
 use crate::{
-    errors, instrument, logger, metrics, query::ExecuteQuery, tracing, utils, DrainerSettings,
-    Store, StreamData,
+    batch, dead_letter, errors, instrument, logger, metrics, query::ExecuteQuery, tracing, utils,
+    DrainerSettings, Store, StreamData,
 };
 
 /// Handler handles the spawning and closing of drainer
@@ -75,6 +75,15 @@ impl Handler {
 
         while self.running.load(atomic::Ordering::SeqCst) {
             metrics::DRAINER_HEALTH.add(1, &[]);
+            if stream_index == 0 {
+                for store in self.stores.values() {
+                    let store = store.clone();
+                    let _task_handle = tokio::spawn(
+                        async move { store.record_dead_letter_stream_depth().await }
+                            .in_current_span(),
+                    );
+                }
+            }
             for store in self.stores.values() {
                 if store.is_stream_available(stream_index).await {
                     let _task_handle = tokio::spawn(
@@ -243,11 +252,79 @@ async fn drainer(
     let session_id = common_utils::generate_id_with_default_len("drainer_session");
     tracing::Span::current().record("session_id", &session_id);
 
+    // An entry that failed recently isn't attempted until its retry delay has passed
+    if let Some((entry_id, _)) = entries.first() {
+        if !store.is_entry_retry_due(stream_name, entry_id).await? {
+            logger::info!(%entry_id, "Skipping session until the failed entry is due for retry");
+            return Ok(());
+        }
+    }
+    let mut held_keys = store.get_held_keys(stream_name).await?;
+
     let mut last_processed_id = String::new();
 
-    for (entry_id, entry) in entries.clone() {
-        let data = match StreamData::from_hashmap(entry) {
-            Ok(data) => data,
+    let batched_count = if store.batch_writes() {
+        batch::drain_batch(&store, stream_name, entries, &held_keys).await
+    } else {
+        0
+    };
+    if let Some((entry_id, _)) = batched_count
+        .checked_sub(1)
+        .and_then(|index| entries.get(index))
+    {
+        last_processed_id = entry_id.clone();
+    }
+    if store.use_legacy_version() {
+        for (entry_id, _) in entries.iter().take(batched_count) {
+            store.delete_from_stream(stream_name, entry_id).await?;
+        }
+    }
+
+    // The entries that weren't applied in a batch are drained one at a time
+    for (entry_id, entry) in entries.iter().skip(batched_count).cloned() {
+        let global_id = entry.get(dead_letter::GLOBAL_ID_FIELD).cloned();
+
+        // The entries of a key with a dead lettered entry are held behind it, so that they're
+        // applied in order once it's replayed
+        if global_id
+            .as_ref()
+            .is_some_and(|global_id| held_keys.contains(global_id))
+        {
+            if let Err(err) = store.hold_entry(stream_name, &entry_id, entry).await {
+                logger::error!(operation = "hold_entry", err=?err);
+                break;
+            }
+            last_processed_id = entry_id;
+
+            if store.use_legacy_version() {
+                store
+                    .delete_from_stream(stream_name, &last_processed_id)
+                    .await?;
+            }
+            continue;
+        }
+
+        let result = match StreamData::from_hashmap(entry.clone()) {
+            Ok(data) => {
+                tracing::Span::current().record("request_id", data.request_id);
+                tracing::Span::current().record("global_id", data.global_id);
+
+                match data.typed_sql.execute_query(&store, data.pushed_at).await {
+                    Ok(_) => Ok(()),
+                    Err(err) => match err.current_context() {
+                        // In case of Uniqueviolation we can't really do anything to fix it so
+                        // just clear it from the stream
+                        diesel_models::errors::DatabaseError::UniqueViolation => Ok(()),
+                        // The entry isn't at fault if the database can't be reached, so the
+                        // failure doesn't count towards dead lettering it
+                        diesel_models::errors::DatabaseError::DatabaseConnectionError => {
+                            logger::error!(?err, "Failed to connect to the database");
+                            break;
+                        }
+                        _ => Err(format!("{err:?}")),
+                    },
+                }
+            }
             Err(err) => {
                 logger::error!(operation = "deserialization", err=?err);
                 metrics::STREAM_PARSE_FAIL.add(
@@ -255,28 +332,36 @@ async fn drainer(
                     router_env::metric_attributes!(("operation", "deserialization")),
                 );
 
-                // break from the loop in case of a deser error
-                break;
+                Err(format!("{err:?}"))
             }
         };
 
-        tracing::Span::current().record("request_id", data.request_id);
-        tracing::Span::current().record("global_id", data.global_id);
-
-        match data.typed_sql.execute_query(&store, data.pushed_at).await {
-            Ok(_) => {
-                last_processed_id = entry_id;
+        match result {
+            Ok(()) => {
+                let tags = router_env::metric_attributes!(
+                    ("stream", stream_name.to_owned()),
+                    ("mode", "individual")
+                );
+                metrics::ENTRIES_DRAINED_PER_STREAM.add(1, tags);
+                metrics::STATEMENTS_EXECUTED_PER_STREAM.add(1, tags);
             }
-            Err(err) => match err.current_context() {
-                // In case of Uniqueviolation we can't really do anything to fix it so just clear
-                // it from the stream
-                diesel_models::errors::DatabaseError::UniqueViolation => {
-                    last_processed_id = entry_id;
+            Err(error) => {
+                // break from the loop unless the entry has failed too many times and was moved
+                // to the dead letter stream, as it blocks the entries behind it
+                let is_dead_lettered = store
+                    .record_entry_failure(stream_name, &entry_id, entry, error)
+                    .await
+                    .unwrap_or_else(|err| {
+                        logger::error!(operation = "record_entry_failure", err=?err);
+                        false
+                    });
+                if !is_dead_lettered {
+                    break;
                 }
-                // break from the loop in case of an error in query
-                _ => break,
-            },
+                held_keys.extend(global_id);
+            }
         }
+        last_processed_id = entry_id;
 
         if store.use_legacy_version() {
             store
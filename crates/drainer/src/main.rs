@@ -1,6 +1,8 @@
 use std::collections::HashMap;
 
-use drainer::{errors::DrainerResult, logger, services, settings, start_drainer, start_web_server};
+use drainer::{
+    dead_letter, errors::DrainerResult, logger, services, settings, start_drainer, start_web_server,
+};
 use router_env::tracing::Instrument;
 
 #[tokio::main]
@@ -23,6 +25,10 @@ async fn main() -> DrainerResult<()> {
         stores.insert(tenant_name.clone(), store);
     }
 
+    if let Some(settings::Command::DeadLetter { tenant, command }) = cmd_line.command {
+        return dead_letter::run_command(&stores, &tenant, command).await;
+    }
+
     #[allow(clippy::print_stdout)] // The logger has not yet been initialized
     #[cfg(feature = "vergen")]
     {
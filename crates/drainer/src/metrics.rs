@@ -1,4 +1,6 @@
-use router_env::{counter_metric, global_meter, histogram_metric_f64, histogram_metric_u64};
+use router_env::{
+    counter_metric, gauge_metric, global_meter, histogram_metric_f64, histogram_metric_u64,
+};
 
 global_meter!(DRAINER_METER, "DRAINER");
 
@@ -12,6 +14,14 @@ counter_metric!(SUCCESSFUL_SHUTDOWN, DRAINER_METER);
 counter_metric!(STREAM_EMPTY, DRAINER_METER);
 counter_metric!(STREAM_PARSE_FAIL, DRAINER_METER);
 counter_metric!(DRAINER_HEALTH, DRAINER_METER);
+counter_metric!(ENTRIES_DRAINED_PER_STREAM, DRAINER_METER);
+counter_metric!(STATEMENTS_EXECUTED_PER_STREAM, DRAINER_METER);
+counter_metric!(BATCH_EXECUTION_FAILED, DRAINER_METER);
+counter_metric!(ENTRIES_DEAD_LETTERED, DRAINER_METER);
+counter_metric!(DEAD_LETTER_ENTRIES_REPLAYED, DRAINER_METER);
+counter_metric!(DEAD_LETTER_ENTRIES_DISCARDED, DRAINER_METER);
+
+gauge_metric!(DEAD_LETTER_STREAM_DEPTH, DRAINER_METER);
 
 histogram_metric_f64!(QUERY_EXECUTION_TIME, DRAINER_METER); // Time in (ms) milliseconds
 histogram_metric_f64!(REDIS_STREAM_READ_TIME, DRAINER_METER); // Time in (ms) milliseconds
@@ -19,3 +29,4 @@ histogram_metric_f64!(REDIS_STREAM_TRIM_TIME, DRAINER_METER); // Time in (ms) mi
 histogram_metric_f64!(CLEANUP_TIME, DRAINER_METER); // Time in (ms) milliseconds
 histogram_metric_u64!(DRAINER_DELAY_SECONDS, DRAINER_METER); // Time in (s) seconds
 histogram_metric_f64!(REDIS_STREAM_DEL_TIME, DRAINER_METER); // Time in (ms) milliseconds
+histogram_metric_f64!(BATCH_EXECUTION_TIME, DRAINER_METER); // Time in (ms) milliseconds
@@ -49,7 +49,7 @@ impl ExecuteQuery for kv::DBOperation {
 }
 
 #[inline(always)]
-fn push_drainer_delay(
+pub(crate) fn push_drainer_delay(
     pushed_at: i64,
     operation: &str,
     table: &str,
@@ -5,7 +5,7 @@ use hyperswitch_interfaces::secrets_interface::{
     SecretManagementInterface, SecretsManagementError,
 };
 
-use crate::settings::{Database, Settings};
+use crate::settings::{Database, DeadLetterSettings, DrainerSettings, Settings};
 
 #[async_trait::async_trait]
 impl SecretsHandler for Database {
@@ -37,12 +37,29 @@ pub async fn fetch_raw_secrets(
         .await
         .expect("Failed to decrypt database password");
 
+    #[allow(clippy::expect_used)]
+    let dead_letter_admin_api_key = match conf.drainer.dead_letter.admin_api_key.clone() {
+        Some(admin_api_key) => Some(
+            secret_management_client
+                .get_secret(admin_api_key)
+                .await
+                .expect("Failed to decrypt dead letter admin API key"),
+        ),
+        None => None,
+    };
+
     Settings {
         server: conf.server,
         master_database: database,
         redis: conf.redis,
         log: conf.log,
-        drainer: conf.drainer,
+        drainer: DrainerSettings {
+            dead_letter: DeadLetterSettings {
+                admin_api_key: dead_letter_admin_api_key,
+                ..conf.drainer.dead_letter
+            },
+            ..conf.drainer
+        },
         encryption_management: conf.encryption_management,
         secrets_management: conf.secrets_management,
         multitenancy: conf.multitenancy,
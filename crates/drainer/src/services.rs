@@ -23,6 +23,9 @@ pub struct StoreConfig {
     pub drainer_stream_name: String,
     pub drainer_num_partitions: u8,
     pub use_legacy_version: bool,
+    pub batch_writes: bool,
+    pub dead_letter_stream_name: String,
+    pub max_attempts: u32,
 }
 
 impl Store {
@@ -47,6 +50,9 @@ impl Store {
                 drainer_stream_name: config.drainer.stream_name.clone(),
                 drainer_num_partitions: config.drainer.num_partitions,
                 use_legacy_version: config.redis.use_legacy_version,
+                batch_writes: config.drainer.batch_writes,
+                dead_letter_stream_name: config.drainer.dead_letter.stream_name.clone(),
+                max_attempts: config.drainer.dead_letter.max_attempts,
             },
             request_id: None,
         }
@@ -55,6 +61,10 @@ impl Store {
     pub fn use_legacy_version(&self) -> bool {
         self.config.use_legacy_version
     }
+
+    pub fn batch_writes(&self) -> bool {
+        self.config.batch_writes
+    }
 }
 
 pub fn log_and_return_error_response<T>(error: Report<T>) -> HttpResponse
@@ -26,6 +26,43 @@ pub struct CmdLineConf {
     /// Application will look for "config/config.toml" if this option isn't specified.
     #[arg(short = 'f', long, value_name = "FILE")]
     pub config_path: Option<PathBuf>,
+
+    #[command(subcommand)]
+    pub command: Option<Command>,
+}
+
+#[derive(clap::Subcommand)]
+pub enum Command {
+    /// Inspect, replay or discard the entries of the dead letter stream, instead of running the
+    /// drainer.
+    DeadLetter {
+        /// Tenant whose dead letter stream is operated on.
+        #[arg(short, long, value_name = "TENANT_ID")]
+        tenant: String,
+
+        #[command(subcommand)]
+        command: DeadLetterCommand,
+    },
+}
+
+#[derive(clap::Subcommand)]
+pub enum DeadLetterCommand {
+    /// List the dead lettered entries, oldest first.
+    List {
+        /// Maximum number of entries to list.
+        #[arg(short, long, default_value_t = 20)]
+        count: u64,
+
+        /// List the entries after this entry ID.
+        #[arg(short, long, value_name = "ENTRY_ID")]
+        after: Option<String>,
+    },
+    /// Show a dead lettered entry.
+    Show { entry_id: String },
+    /// Apply the query of a dead lettered entry, once the earlier entries of its key are handled.
+    Replay { entry_id: String },
+    /// Delete a dead lettered entry without draining it.
+    Discard { entry_id: String },
 }
 
 #[derive(Clone)]
@@ -114,6 +151,20 @@ pub struct DrainerSettings {
     pub max_read_count: u64,
     pub shutdown_interval: u32, // in milliseconds
     pub loop_interval: u32,     // in milliseconds
+    pub batch_writes: bool,
+    pub dead_letter: DeadLetterSettings,
+}
+
+/// Configuration of the handling of entries that repeatedly fail to be drained.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct DeadLetterSettings {
+    /// Name of the stream that entries are moved to once they have failed `max_attempts` times
+    pub stream_name: String,
+    /// Number of times an entry is attempted before it's moved to the dead letter stream
+    pub max_attempts: u32,
+    /// API key required by the dead letter admin endpoints, which are disabled if it's not set
+    pub admin_api_key: Option<Secret<String>>,
 }
 
 #[derive(Debug, Deserialize, Clone, Default)]
@@ -224,6 +275,18 @@ impl Default for DrainerSettings {
             max_read_count: 100,
             shutdown_interval: 1000, // in milliseconds
             loop_interval: 100,      // in milliseconds
+            batch_writes: false,
+            dead_letter: DeadLetterSettings::default(),
+        }
+    }
+}
+
+impl Default for DeadLetterSettings {
+    fn default() -> Self {
+        Self {
+            stream_name: "DRAINER_DEAD_LETTER_STREAM".into(),
+            max_attempts: 5,
+            admin_api_key: None,
         }
     }
 }
@@ -274,6 +337,26 @@ impl DrainerSettings {
             Err(errors::DrainerError::ConfigParsingError(
                 "drainer stream name must not be empty".into(),
             ))
+        })?;
+
+        self.dead_letter.validate()
+    }
+}
+
+impl DeadLetterSettings {
+    fn validate(&self) -> Result<(), errors::DrainerError> {
+        use common_utils::fp_utils::when;
+
+        when(self.stream_name.is_default_or_empty(), || {
+            Err(errors::DrainerError::ConfigParsingError(
+                "dead letter stream name must not be empty".into(),
+            ))
+        })?;
+
+        when(self.max_attempts == 0, || {
+            Err(errors::DrainerError::ConfigParsingError(
+                "dead letter max attempts must be greater than zero".into(),
+            ))
         })
     }
 }
@@ -63,6 +63,8 @@ fn get_program_data() -> (ast::Program<DummyOutput>, inputs::BackendInput) {
         issuer_data: None,
         acquirer_data: None,
         customer_device_data: None,
+        temporal: None,
+        exchange_rates: None,
     };
 
     let (_, program) = parser::program(code1).expect("Parser");
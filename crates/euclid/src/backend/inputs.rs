@@ -1,10 +1,16 @@
+use std::sync::Arc;
+
+use chrono::{Datelike, Timelike};
+use common_utils::types::MinorUnit;
+use currency_conversion::types::ExchangeRates;
 use rustc_hash::FxHashMap;
 use serde::{Deserialize, Serialize};
 
 use crate::{
     enums,
     frontend::dir::enums::{
-        CustomerDeviceDisplaySize, CustomerDevicePlatform, CustomerDeviceType, TransactionInitiator,
+        CustomerDeviceDisplaySize, CustomerDevicePlatform, CustomerDeviceType, DayOfWeek,
+        TransactionInitiator,
     },
 };
 
@@ -56,6 +62,39 @@ pub struct IssuerDataInput {
     pub country: Option<enums::Country>,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TemporalInput {
+    /// The instant at which the payment was made, in UTC
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub timestamp: time::PrimitiveDateTime,
+}
+
+/// The temporal values of a payment as observed in the timezone of the program
+#[derive(Debug, Clone)]
+pub struct LocalTemporalValues {
+    /// Local time of day in the HHMM format
+    pub time_of_day: MinorUnit,
+    pub day_of_week: DayOfWeek,
+    /// Local date in the YYYYMMDD format
+    pub date: MinorUnit,
+}
+
+impl TemporalInput {
+    pub fn localize(&self, timezone: chrono_tz::Tz) -> Option<LocalTemporalValues> {
+        let local =
+            chrono::DateTime::from_timestamp(self.timestamp.assume_utc().unix_timestamp(), 0)?
+                .with_timezone(&timezone);
+
+        Some(LocalTemporalValues {
+            time_of_day: MinorUnit::new(i64::from(local.hour() * 100 + local.minute())),
+            day_of_week: local.weekday().into(),
+            date: MinorUnit::new(
+                i64::from(local.year()) * 10000 + i64::from(local.month() * 100 + local.day()),
+            ),
+        })
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct BackendInput {
     pub metadata: Option<FxHashMap<String, String>>,
@@ -65,4 +104,33 @@ pub struct BackendInput {
     pub customer_device_data: Option<CustomerDeviceDataInput>,
     pub issuer_data: Option<IssuerDataInput>,
     pub mandate: MandateData,
+    #[serde(default)]
+    pub temporal: Option<TemporalInput>,
+    /// Exchange rates used to evaluate the `base_currency_amount` key
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub exchange_rates: Option<Arc<ExchangeRates>>,
+}
+
+impl BackendInput {
+    /// Converts the amount of the payment to the minor unit of `base_currency`.
+    ///
+    /// Returns `None` when the payment is in a different currency and either no exchange rates
+    /// were supplied or a rate for one of the currencies is missing, in which case the
+    /// `base_currency_amount` key is treated as absent during evaluation.
+    pub fn amount_in_base_currency(&self, base_currency: enums::Currency) -> Option<MinorUnit> {
+        if self.payment.currency == base_currency {
+            return Some(self.payment.amount);
+        }
+
+        self.exchange_rates.as_ref().and_then(|exchange_rates| {
+            currency_conversion::conversion::convert_minor(
+                exchange_rates,
+                self.payment.currency,
+                base_currency,
+                self.payment.amount.get_amount_as_i64(),
+            )
+            .ok()
+            .map(MinorUnit::new)
+        })
+    }
 }
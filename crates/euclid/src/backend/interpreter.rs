@@ -5,10 +5,12 @@ use common_utils::types::MinorUnit;
 use crate::{
     backend::{self, inputs, EuclidBackend},
     frontend::ast,
+    types::parse_timezone,
 };
 
 pub struct InterpreterBackend<O> {
     program: ast::Program<O>,
+    timezone: chrono_tz::Tz,
 }
 
 impl<O> InterpreterBackend<O>
@@ -172,11 +174,23 @@ where
     type Error = types::InterpreterError;
 
     fn with_program(program: ast::Program<O>) -> Result<Self, Self::Error> {
-        Ok(Self { program })
+        let timezone = program
+            .timezone
+            .as_deref()
+            .map(|timezone| {
+                parse_timezone(timezone).ok_or_else(|| types::InterpreterError {
+                    error_type: types::InterpreterErrorType::InvalidTimezone(timezone.to_string()),
+                    metadata: program.metadata.clone(),
+                })
+            })
+            .transpose()?
+            .unwrap_or(chrono_tz::UTC);
+
+        Ok(Self { program, timezone })
     }
 
     fn execute(&self, input: inputs::BackendInput) -> Result<super::BackendOutput<O>, Self::Error> {
-        let ctx: types::Context = input.into();
+        let ctx = types::Context::from_input(input, self.timezone, self.program.base_currency);
         Self::eval_program(&self.program, &ctx)
     }
 }
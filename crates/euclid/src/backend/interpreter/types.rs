@@ -2,7 +2,7 @@ use std::{collections::HashMap, fmt, ops::Deref, string::ToString};
 
 use serde::Serialize;
 
-use crate::{backend::inputs, frontend::ast::ValueType, types::EuclidKey};
+use crate::{backend::inputs, enums, frontend::ast::ValueType, types::EuclidKey};
 
 #[derive(Debug, Clone, Serialize, thiserror::Error)]
 #[serde(tag = "type", content = "data", rename_all = "snake_case")]
@@ -11,6 +11,8 @@ pub enum InterpreterErrorType {
     InvalidKey(String),
     #[error("Invalid Comparison")]
     InvalidComparison,
+    #[error("Invalid timezone '{0}' received for the program")]
+    InvalidTimezone(String),
 }
 
 #[derive(Debug, Clone, Serialize, thiserror::Error)]
@@ -35,8 +37,19 @@ impl Deref for Context {
     }
 }
 
-impl From<inputs::BackendInput> for Context {
-    fn from(input: inputs::BackendInput) -> Self {
+impl Context {
+    pub fn from_input(
+        input: inputs::BackendInput,
+        timezone: chrono_tz::Tz,
+        base_currency: Option<enums::Currency>,
+    ) -> Self {
+        let local_temporal_values = input
+            .temporal
+            .as_ref()
+            .and_then(|temporal| temporal.localize(timezone));
+        let base_currency_amount =
+            base_currency.and_then(|currency| input.amount_in_base_currency(currency));
+
         let ctx = HashMap::<String, Option<ValueType>>::from_iter([
             (
                 EuclidKey::PaymentMethod.to_string(),
@@ -74,6 +87,28 @@ impl From<inputs::BackendInput> for Context {
                 EuclidKey::PaymentCurrency.to_string(),
                 Some(ValueType::EnumVariant(input.payment.currency.to_string())),
             ),
+            (
+                EuclidKey::TimeOfDay.to_string(),
+                local_temporal_values
+                    .as_ref()
+                    .map(|values| ValueType::Number(values.time_of_day)),
+            ),
+            (
+                EuclidKey::DayOfWeek.to_string(),
+                local_temporal_values
+                    .as_ref()
+                    .map(|values| ValueType::EnumVariant(values.day_of_week.to_string())),
+            ),
+            (
+                EuclidKey::Date.to_string(),
+                local_temporal_values
+                    .as_ref()
+                    .map(|values| ValueType::Number(values.date)),
+            ),
+            (
+                EuclidKey::BaseCurrencyAmount.to_string(),
+                base_currency_amount.map(ValueType::Number),
+            ),
         ]);
 
         Self(ctx)
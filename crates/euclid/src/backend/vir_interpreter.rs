@@ -6,11 +6,13 @@ use serde::{Deserialize, Serialize};
 
 use crate::{
     backend::{self, inputs, EuclidBackend},
+    enums,
     frontend::{
         ast,
         dir::{self, EuclidDirFilter},
         vir,
     },
+    types::parse_timezone,
 };
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -78,6 +80,13 @@ where
     }
 }
 
+impl<O> VirInterpreterBackend<O> {
+    /// The currency in which the `base_currency_amount` key of the program is evaluated
+    pub fn get_base_currency(&self) -> Option<enums::Currency> {
+        self.program.base_currency
+    }
+}
+
 impl<O> EuclidBackend<O> for VirInterpreterBackend<O>
 where
     O: Clone + EuclidDirFilter,
@@ -100,7 +109,19 @@ where
         &self,
         input: inputs::BackendInput,
     ) -> Result<backend::BackendOutput<O>, Self::Error> {
-        let ctx = types::Context::from_input(input);
+        let timezone = self
+            .program
+            .timezone
+            .as_deref()
+            .map(|timezone| {
+                parse_timezone(timezone).ok_or_else(|| {
+                    types::VirInterpreterError::InvalidTimezone(timezone.to_string())
+                })
+            })
+            .transpose()?
+            .unwrap_or(chrono_tz::UTC);
+
+        let ctx = types::Context::from_input(input, timezone, self.program.base_currency);
         Ok(Self::eval_program(&self.program, &ctx))
     }
 }
@@ -157,6 +178,8 @@ mod test {
             acquirer_data: None,
             customer_device_data: None,
             issuer_data: None,
+            temporal: None,
+            exchange_rates: None,
         };
 
         let backend = VirInterpreterBackend::<DummyOutput>::with_program(program).expect("Program");
@@ -202,6 +225,8 @@ mod test {
             acquirer_data: None,
             customer_device_data: None,
             issuer_data: None,
+            temporal: None,
+            exchange_rates: None,
         };
 
         let backend = VirInterpreterBackend::<DummyOutput>::with_program(program).expect("Program");
@@ -248,6 +273,8 @@ mod test {
             acquirer_data: None,
             customer_device_data: None,
             issuer_data: None,
+            temporal: None,
+            exchange_rates: None,
         };
 
         let backend = VirInterpreterBackend::<DummyOutput>::with_program(program).expect("Program");
@@ -294,6 +321,8 @@ mod test {
             acquirer_data: None,
             customer_device_data: None,
             issuer_data: None,
+            temporal: None,
+            exchange_rates: None,
         };
 
         let backend = VirInterpreterBackend::<DummyOutput>::with_program(program).expect("Program");
@@ -340,6 +369,8 @@ mod test {
             acquirer_data: None,
             customer_device_data: None,
             issuer_data: None,
+            temporal: None,
+            exchange_rates: None,
         };
 
         let backend = VirInterpreterBackend::<DummyOutput>::with_program(program).expect("Program");
@@ -386,6 +417,8 @@ mod test {
             acquirer_data: None,
             customer_device_data: None,
             issuer_data: None,
+            temporal: None,
+            exchange_rates: None,
         };
 
         let backend = VirInterpreterBackend::<DummyOutput>::with_program(program).expect("Program");
@@ -432,6 +465,8 @@ mod test {
             acquirer_data: None,
             customer_device_data: None,
             issuer_data: None,
+            temporal: None,
+            exchange_rates: None,
         };
 
         let backend = VirInterpreterBackend::<DummyOutput>::with_program(program).expect("Program");
@@ -478,6 +513,8 @@ mod test {
             acquirer_data: None,
             customer_device_data: None,
             issuer_data: None,
+            temporal: None,
+            exchange_rates: None,
         };
 
         let backend = VirInterpreterBackend::<DummyOutput>::with_program(program).expect("Program");
@@ -524,6 +561,8 @@ mod test {
             acquirer_data: None,
             customer_device_data: None,
             issuer_data: None,
+            temporal: None,
+            exchange_rates: None,
         };
 
         let backend = VirInterpreterBackend::<DummyOutput>::with_program(program).expect("Program");
@@ -572,6 +611,8 @@ mod test {
             acquirer_data: None,
             customer_device_data: None,
             issuer_data: None,
+            temporal: None,
+            exchange_rates: None,
         };
 
         let backend = VirInterpreterBackend::<DummyOutput>::with_program(program).expect("Program");
@@ -618,6 +659,8 @@ mod test {
             acquirer_data: None,
             customer_device_data: None,
             issuer_data: None,
+            temporal: None,
+            exchange_rates: None,
         };
         let mut inp_equal = inp_greater.clone();
         inp_equal.payment.amount = MinorUnit::new(123);
@@ -673,6 +716,8 @@ mod test {
             acquirer_data: None,
             customer_device_data: None,
             issuer_data: None,
+            temporal: None,
+            exchange_rates: None,
         };
         let mut inp_equal = inp_lower.clone();
         inp_equal.payment.amount = MinorUnit::new(123);
@@ -688,4 +733,184 @@ mod test {
             "rule_1"
         );
     }
+
+    #[test]
+    fn test_temporal_keys_in_program_timezone() {
+        let program_str = r#"
+        timezone: "Asia/Kolkata"
+        default: ["stripe", "adyen"]
+
+        maintenance_window: ["adyen"]
+        {
+           time_of_day >= 100 & time_of_day < 300
+        }
+
+        weekend: ["stripe"]
+        {
+           day_of_week = (saturday, sunday) & date >= 20241201
+        }
+        "#;
+        let (_, program) = ast::parser::program::<DummyOutput>(program_str).expect("Program");
+        let inp = inputs::BackendInput {
+            metadata: None,
+            payment: inputs::PaymentInput {
+                amount: MinorUnit::new(150),
+                transaction_initiator: None,
+                card_bin: None,
+                extended_card_bin: None,
+                currency: enums::Currency::USD,
+                authentication_type: Some(enums::AuthenticationType::NoThreeDs),
+                capture_method: Some(enums::CaptureMethod::Automatic),
+                business_country: Some(enums::Country::UnitedStatesOfAmerica),
+                billing_country: Some(enums::Country::France),
+                business_label: None,
+                setup_future_usage: None,
+            },
+            payment_method: inputs::PaymentMethodInput {
+                payment_method: Some(enums::PaymentMethod::PayLater),
+                payment_method_type: Some(enums::PaymentMethodType::Affirm),
+                card_network: None,
+            },
+            mandate: inputs::MandateData {
+                mandate_acceptance_type: None,
+                mandate_type: None,
+                payment_type: None,
+            },
+            acquirer_data: None,
+            customer_device_data: None,
+            issuer_data: None,
+            // Friday, 2024-12-27 20:00 UTC is Saturday, 2024-12-28 01:30 IST
+            temporal: Some(inputs::TemporalInput {
+                timestamp: time::macros::datetime!(2024-12-27 20:00),
+            }),
+            exchange_rates: None,
+        };
+        let mut inp_weekend = inp.clone();
+        // Saturday, 2024-12-28 10:00 UTC is Saturday, 2024-12-28 15:30 IST
+        inp_weekend.temporal = Some(inputs::TemporalInput {
+            timestamp: time::macros::datetime!(2024-12-28 10:00),
+        });
+        let mut inp_without_time = inp.clone();
+        inp_without_time.temporal = None;
+
+        let backend = VirInterpreterBackend::<DummyOutput>::with_program(program).expect("Program");
+        let result = backend.execute(inp).expect("Execution");
+        let result_weekend = backend.execute(inp_weekend).expect("Execution");
+        let result_without_time = backend.execute(inp_without_time).expect("Execution");
+        assert_eq!(
+            result.rule_name.expect("Rule Name").as_str(),
+            "maintenance_window"
+        );
+        assert_eq!(
+            result_weekend.rule_name.expect("Rule Name").as_str(),
+            "weekend"
+        );
+        assert!(result_without_time.rule_name.is_none());
+    }
+
+    #[test]
+    fn test_base_currency_amount() {
+        let program_str = r#"
+        base_currency: "USD"
+        default: ["stripe"]
+
+        high_value: ["adyen"]
+        {
+           base_currency_amount >= 10000
+        }
+        "#;
+        let (_, program) = ast::parser::program::<DummyOutput>(program_str).expect("Program");
+        let exchange_rates: currency_conversion::types::ExchangeRates =
+            serde_json::from_value(serde_json::json!({
+                "base_currency": "USD",
+                "conversion": {
+                    "JPY": { "to_factor": "150", "from_factor": "0.01" }
+                }
+            }))
+            .expect("Exchange Rates");
+        let inp = inputs::BackendInput {
+            metadata: None,
+            payment: inputs::PaymentInput {
+                amount: MinorUnit::new(15000),
+                transaction_initiator: None,
+                card_bin: None,
+                extended_card_bin: None,
+                currency: enums::Currency::JPY,
+                authentication_type: Some(enums::AuthenticationType::NoThreeDs),
+                capture_method: Some(enums::CaptureMethod::Automatic),
+                business_country: Some(enums::Country::Japan),
+                billing_country: Some(enums::Country::Japan),
+                business_label: None,
+                setup_future_usage: None,
+            },
+            payment_method: inputs::PaymentMethodInput {
+                payment_method: Some(enums::PaymentMethod::Card),
+                payment_method_type: Some(enums::PaymentMethodType::Credit),
+                card_network: None,
+            },
+            mandate: inputs::MandateData {
+                mandate_acceptance_type: None,
+                mandate_type: None,
+                payment_type: None,
+            },
+            acquirer_data: None,
+            customer_device_data: None,
+            issuer_data: None,
+            temporal: None,
+            exchange_rates: Some(std::sync::Arc::new(exchange_rates)),
+        };
+        let mut inp_low_value = inp.clone();
+        inp_low_value.payment.amount = MinorUnit::new(1000);
+        let mut inp_without_rate = inp.clone();
+        inp_without_rate.payment.currency = enums::Currency::EUR;
+        let mut inp_without_rates = inp.clone();
+        inp_without_rates.exchange_rates = None;
+        let mut inp_in_base_currency = inp_without_rates.clone();
+        inp_in_base_currency.payment.currency = enums::Currency::USD;
+        inp_in_base_currency.payment.amount = MinorUnit::new(20000);
+
+        let backend = VirInterpreterBackend::<DummyOutput>::with_program(program).expect("Program");
+        let result = backend.execute(inp).expect("Execution");
+        let result_low_value = backend.execute(inp_low_value).expect("Execution");
+        let result_without_rate = backend.execute(inp_without_rate).expect("Execution");
+        let result_without_rates = backend.execute(inp_without_rates).expect("Execution");
+        let result_in_base_currency = backend.execute(inp_in_base_currency).expect("Execution");
+        assert_eq!(result.rule_name.expect("Rule Name").as_str(), "high_value");
+        assert!(result_low_value.rule_name.is_none());
+        assert!(result_without_rate.rule_name.is_none());
+        assert!(result_without_rates.rule_name.is_none());
+        assert_eq!(
+            result_in_base_currency
+                .rule_name
+                .expect("Rule Name")
+                .as_str(),
+            "high_value"
+        );
+    }
+
+    #[test]
+    fn test_base_currency_amount_without_base_currency() {
+        let program_str = r#"
+        default: ["stripe"]
+
+        high_value: ["adyen"]
+        {
+           payment_method = card {
+               base_currency_amount >= 10000
+           }
+        }
+        "#;
+        let (_, program) = ast::parser::program::<DummyOutput>(program_str).expect("Program");
+
+        let backend = VirInterpreterBackend::<DummyOutput>::with_program(program);
+        assert!(matches!(
+            backend,
+            Err(types::VirInterpreterError::LoweringError(
+                crate::dssa::types::AnalysisError {
+                    error_type: crate::dssa::types::AnalysisErrorType::MissingBaseCurrency(_),
+                    ..
+                }
+            ))
+        ));
+    }
 }
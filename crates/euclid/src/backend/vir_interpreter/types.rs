@@ -2,7 +2,7 @@ use rustc_hash::{FxHashMap, FxHashSet};
 
 use crate::{
     backend::inputs::BackendInput,
-    dssa,
+    dssa, enums,
     types::{self, EuclidKey, EuclidValue, MetadataValue, NumValueRefinement, StrValue},
 };
 
@@ -10,6 +10,8 @@ use crate::{
 pub enum VirInterpreterError {
     #[error("Error when lowering the program: {0:?}")]
     LoweringError(dssa::types::AnalysisError),
+    #[error("Invalid timezone '{0}' received for the program")]
+    InvalidTimezone(String),
 }
 
 pub struct Context {
@@ -50,7 +52,13 @@ impl Context {
         }
     }
 
-    pub fn from_input(input: BackendInput) -> Self {
+    pub fn from_input(
+        input: BackendInput,
+        timezone: chrono_tz::Tz,
+        base_currency: Option<enums::Currency>,
+    ) -> Self {
+        let base_currency_amount =
+            base_currency.and_then(|currency| input.amount_in_base_currency(currency));
         let payment = input.payment;
         let payment_method = input.payment_method;
         let meta_data = input.metadata;
@@ -58,6 +66,10 @@ impl Context {
         let customer_device_data = input.customer_device_data;
         let issuer_data = input.issuer_data;
         let payment_mandate = input.mandate;
+        let local_temporal_values = input
+            .temporal
+            .as_ref()
+            .and_then(|temporal| temporal.localize(timezone));
 
         let mut enum_values: FxHashSet<EuclidValue> =
             FxHashSet::from_iter([EuclidValue::PaymentCurrency(payment.currency)]);
@@ -152,7 +164,7 @@ impl Context {
             }
         }
 
-        let numeric_values: FxHashMap<EuclidKey, EuclidValue> = FxHashMap::from_iter([(
+        let mut numeric_values: FxHashMap<EuclidKey, EuclidValue> = FxHashMap::from_iter([(
             EuclidKey::PaymentAmount,
             EuclidValue::PaymentAmount(types::NumValue {
                 number: payment.amount,
@@ -160,6 +172,37 @@ impl Context {
             }),
         )]);
 
+        // Handle temporal data, localized to the timezone of the program
+        if let Some(local_values) = local_temporal_values {
+            enum_values.insert(EuclidValue::DayOfWeek(local_values.day_of_week));
+            numeric_values.insert(
+                EuclidKey::TimeOfDay,
+                EuclidValue::TimeOfDay(types::NumValue {
+                    number: local_values.time_of_day,
+                    refinement: None,
+                }),
+            );
+            numeric_values.insert(
+                EuclidKey::Date,
+                EuclidValue::Date(types::NumValue {
+                    number: local_values.date,
+                    refinement: None,
+                }),
+            );
+        }
+
+        // Handle the amount converted to the base currency of the program, which is left out
+        // when it cannot be converted
+        if let Some(amount) = base_currency_amount {
+            numeric_values.insert(
+                EuclidKey::BaseCurrencyAmount,
+                EuclidValue::BaseCurrencyAmount(types::NumValue {
+                    number: amount,
+                    refinement: None,
+                }),
+            );
+        }
+
         Self {
             atomic_values: enum_values,
             numeric_values,
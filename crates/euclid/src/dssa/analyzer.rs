@@ -18,7 +18,7 @@ use crate::{
         dir::{self, EuclidDirFilter},
         vir,
     },
-    types::{DataType, Metadata},
+    types::{DataType, Metadata, NumValueRefinement},
 };
 
 /// Analyses conflicting assertions on the same key in a conjunctive context.
@@ -101,6 +101,69 @@ pub fn analyze_exhaustive_negations(
     Ok(())
 }
 
+/// Analyses numeric assertions on the same key in a conjunctive context whose
+/// bounds cannot be satisfied together.
+///
+/// For example,
+/// ```notrust
+/// time_of_day > 2200 && ... && time_of_day < 0600
+/// ```notrust
+/// This is a condition that will never evaluate to `true` since no single value
+/// lies within both the bounds. Ranges wrapping around midnight need to be expressed
+/// as separate statements instead.
+pub fn analyze_unsatisfiable_ranges(
+    keywise_number_assertions: &FxHashMap<dir::DirKey, Vec<(&dir::DirValue, &Metadata)>>,
+) -> Result<(), types::AnalysisError> {
+    for (key, assertions) in keywise_number_assertions {
+        let mut lower_bound: Option<i64> = None;
+        let mut upper_bound: Option<i64> = None;
+
+        for (value, _) in assertions {
+            let Some(num_value) = value.get_num_value() else {
+                continue;
+            };
+            let number = num_value.number.get_amount_as_i64();
+
+            let (lower, upper) = match num_value.refinement {
+                None => (Some(number), Some(number)),
+                Some(NumValueRefinement::GreaterThan) => (Some(number.saturating_add(1)), None),
+                Some(NumValueRefinement::GreaterThanEqual) => (Some(number), None),
+                Some(NumValueRefinement::LessThan) => (None, Some(number.saturating_sub(1))),
+                Some(NumValueRefinement::LessThanEqual) => (None, Some(number)),
+                Some(NumValueRefinement::NotEqual) => (None, None),
+            };
+
+            lower_bound = lower_bound.max(lower);
+            upper_bound = match (upper_bound, upper) {
+                (Some(current), Some(new)) => Some(current.min(new)),
+                (current, new) => current.or(new),
+            };
+        }
+
+        if lower_bound
+            .zip(upper_bound)
+            .is_some_and(|(lower, upper)| lower > upper)
+        {
+            let error_type = types::AnalysisErrorType::UnsatisfiableRange {
+                key: key.clone(),
+                values: assertions
+                    .iter()
+                    .map(|(value, metadata)| types::ValueData {
+                        value: (*value).clone(),
+                        metadata: (*metadata).clone(),
+                    })
+                    .collect(),
+            };
+
+            Err(types::AnalysisError {
+                error_type,
+                metadata: Default::default(),
+            })?;
+        }
+    }
+    Ok(())
+}
+
 fn analyze_negated_assertions(
     keywise_assertions: &FxHashMap<dir::DirKey, FxHashSet<&dir::DirValue>>,
     assertion_metadata: &FxHashMap<&dir::DirValue, &Metadata>,
@@ -151,6 +214,8 @@ fn perform_condition_analyses(
         FxHashMap::default();
     let mut keywise_negations: FxHashMap<dir::DirKey, FxHashSet<&dir::DirValue>> =
         FxHashMap::default();
+    let mut keywise_number_assertions: FxHashMap<dir::DirKey, Vec<(&dir::DirValue, &Metadata)>> =
+        FxHashMap::default();
 
     for ctx_val in context {
         let key = if let Some(k) = ctx_val.value.get_key() {
@@ -163,6 +228,15 @@ fn perform_condition_analyses(
             continue;
         }
 
+        if let (DataType::Number, types::CtxValueKind::Assertion(val)) =
+            (key.kind.get_type(), &ctx_val.value)
+        {
+            keywise_number_assertions
+                .entry(key.clone())
+                .or_default()
+                .push((*val, ctx_val.metadata));
+        }
+
         if !matches!(key.kind.get_type(), DataType::EnumVariant) {
             continue;
         }
@@ -201,6 +275,7 @@ fn perform_condition_analyses(
         &keywise_negations,
         &negation_metadata,
     )?;
+    analyze_unsatisfiable_ranges(&keywise_number_assertions)?;
 
     Ok(())
 }
@@ -238,6 +313,226 @@ pub fn analyze<O: EuclidAnalysable + EuclidDirFilter>(
     dir::lowering::lower_program(dir_program)
 }
 
+type AnalysedContexts<'a> = Vec<(
+    types::ConjunctiveContext<'a>,
+    Result<(), types::AnalysisError>,
+)>;
+
+/// Collects every conjunctive context of the rule along with the result of analysing it
+fn analyze_rule_contexts<'a, O>(
+    rule: &'a dir::DirRule<O>,
+    connector_selection_data: &'a [(dir::DirValue, Metadata)],
+    knowledge_graph: &ConstraintGraph<dir::DirValue>,
+) -> Result<AnalysedContexts<'a>, types::AnalysisError> {
+    let mut ctx_manager = state_machine::RuleContextManager::new(rule, connector_selection_data);
+    let mut contexts = Vec::new();
+
+    while let Some(ctx) = ctx_manager.advance().map_err(|err| types::AnalysisError {
+        metadata: Default::default(),
+        error_type: types::AnalysisErrorType::StateMachine(err),
+    })? {
+        contexts.push((ctx.clone(), perform_context_analyses(ctx, knowledge_graph)));
+    }
+
+    Ok(contexts)
+}
+
+/// Returns the error from the first context of the rule when none of its contexts are
+/// satisfiable
+fn get_unreachable_reason(contexts: &AnalysedContexts<'_>) -> Option<types::AnalysisError> {
+    if contexts.iter().any(|(_, result)| result.is_ok()) {
+        return None;
+    }
+
+    contexts
+        .iter()
+        .find_map(|(_, result)| result.as_ref().err().cloned())
+}
+
+/// Checks whether every input satisfying the `context` also satisfies the `value`
+fn is_implied_by(value: &types::CtxValueKind<'_>, context: &[types::ContextValue<'_>]) -> bool {
+    match value {
+        types::CtxValueKind::Assertion(asserted) => match asserted.get_num_value() {
+            Some(asserted_num) => context.iter().any(|ctx_val| {
+                ctx_val.value.get_assertion().is_some_and(|other| {
+                    other.get_key() == asserted.get_key()
+                        && other
+                            .get_num_value()
+                            .is_some_and(|other_num| asserted_num.fits(&other_num))
+                })
+            }),
+            None => context
+                .iter()
+                .any(|ctx_val| ctx_val.value.get_assertion() == Some(*asserted)),
+        },
+
+        types::CtxValueKind::Negation(negated) => {
+            let Some(key) = negated.first().map(dir::DirValue::get_key) else {
+                return true;
+            };
+
+            context.iter().any(|ctx_val| match &ctx_val.value {
+                // A key takes a single value, hence asserting a value outside of the negated ones
+                // implies the negation
+                types::CtxValueKind::Assertion(other) => {
+                    other.get_key() == key
+                        && other.get_num_value().is_none()
+                        && !negated.contains(other)
+                }
+                types::CtxValueKind::Negation(other) => {
+                    negated.iter().all(|value| other.contains(value))
+                }
+            })
+        }
+    }
+}
+
+/// Checks whether every input satisfying the `conditions` also satisfies the `covering`
+/// conditions
+fn is_covered_by(
+    conditions: &[types::ContextValue<'_>],
+    covering: &[types::ContextValue<'_>],
+) -> bool {
+    covering
+        .iter()
+        .all(|ctx_val| is_implied_by(&ctx_val.value, conditions))
+}
+
+/// Strips the values of the connector selection, which precede the conditions of the rule in
+/// each of its contexts
+fn get_conditions<'b, 'a>(
+    context: &'b types::ConjunctiveContext<'a>,
+    connector_selection_data: &[(dir::DirValue, Metadata)],
+) -> &'b [types::ContextValue<'a>] {
+    context
+        .get(connector_selection_data.len()..)
+        .unwrap_or_default()
+}
+
+/// Analyses each rule of the program individually, reporting
+///
+/// - rules that can never match, since none of their conditions can be satisfied,
+/// - conditions within a rule that can never be satisfied, while the rest of the rule can,
+/// - rules that are shadowed by the rules preceding them, such that every input the rule would
+///   match is already matched by an earlier rule.
+///
+/// The analyses performed on each condition are the same as those in [`analyze`], checked against
+/// the given knowledge graph, such as the one constructed from the merchant connector accounts. A
+/// condition is shadowed only when it is covered by a single condition of an earlier rule, so a
+/// condition covered by the union of several earlier conditions is not reported.
+pub fn analyze_rules<O: EuclidAnalysable>(
+    program: &dir::DirProgram<O>,
+    knowledge_graph: Option<&ConstraintGraph<dir::DirValue>>,
+) -> Result<Vec<types::RuleAnalysis>, types::AnalysisError> {
+    let knowledge_graph = knowledge_graph.unwrap_or(&truth::ANALYSIS_GRAPH);
+    let selection_data = state_machine::make_connector_selection_data(program);
+    let mut preceding_rules: Vec<(&str, &[(dir::DirValue, Metadata)], AnalysedContexts<'_>)> =
+        Vec::new();
+    let mut findings = Vec::new();
+
+    for (rule, rule_selection_data) in program.rules.iter().zip(selection_data.iter()) {
+        // A rule without any statements never matches, nor does it shadow other rules
+        if rule.statements.is_empty() {
+            continue;
+        }
+
+        let contexts = analyze_rule_contexts(rule, rule_selection_data, knowledge_graph)?;
+
+        if let Some(reason) = get_unreachable_reason(&contexts) {
+            findings.push(types::RuleAnalysis {
+                rule_name: rule.name.clone(),
+                finding: types::RuleFinding::Unreachable { reason },
+            });
+            preceding_rules.push((rule.name.as_str(), rule_selection_data, contexts));
+            continue;
+        }
+
+        let mut shadowed_by: Vec<String> = Vec::new();
+        let mut is_shadowed = true;
+
+        for (context, _) in &contexts {
+            let conditions = get_conditions(context, rule_selection_data);
+            let covering_rule = preceding_rules.iter().find_map(
+                |(preceding_rule, preceding_selection_data, preceding_contexts)| {
+                    preceding_contexts
+                        .iter()
+                        .any(|(covering, _)| {
+                            is_covered_by(
+                                conditions,
+                                get_conditions(covering, preceding_selection_data),
+                            )
+                        })
+                        .then_some(*preceding_rule)
+                },
+            );
+
+            match covering_rule {
+                Some(covering_rule) => {
+                    if !shadowed_by.iter().any(|name| name == covering_rule) {
+                        shadowed_by.push(covering_rule.to_string());
+                    }
+                }
+                None => {
+                    is_shadowed = false;
+                    break;
+                }
+            }
+        }
+
+        if is_shadowed {
+            findings.push(types::RuleAnalysis {
+                rule_name: rule.name.clone(),
+                finding: types::RuleFinding::Shadowed { shadowed_by },
+            });
+        }
+
+        if let Some(reason) = contexts
+            .iter()
+            .find_map(|(_, result)| result.as_ref().err().cloned())
+        {
+            findings.push(types::RuleAnalysis {
+                rule_name: rule.name.clone(),
+                finding: types::RuleFinding::DeadCondition { reason },
+            });
+        }
+
+        preceding_rules.push((rule.name.as_str(), rule_selection_data, contexts));
+    }
+
+    Ok(findings)
+}
+
+/// Analyses the rules of the program that are absent from `matched_rules`, typically the rules
+/// that did not fire while replaying some traffic through the program.
+///
+/// A rule is reported as unreachable when every conjunctive context of the rule fails the
+/// analyses performed by [`analyze`], in which case the error from its first context is
+/// retained. Otherwise the rule could have matched, just not for the traffic at hand.
+pub fn analyze_unmatched_rules<O: EuclidAnalysable>(
+    program: &dir::DirProgram<O>,
+    matched_rules: &FxHashSet<&str>,
+    knowledge_graph: Option<&ConstraintGraph<dir::DirValue>>,
+) -> Result<Vec<types::UnmatchedRule>, types::AnalysisError> {
+    let knowledge_graph = knowledge_graph.unwrap_or(&truth::ANALYSIS_GRAPH);
+    let selection_data = state_machine::make_connector_selection_data(program);
+    let mut unmatched_rules = Vec::new();
+
+    for (rule, rule_selection_data) in program.rules.iter().zip(selection_data.iter()) {
+        if matched_rules.contains(rule.name.as_str()) {
+            continue;
+        }
+
+        let contexts = analyze_rule_contexts(rule, rule_selection_data, knowledge_graph)?;
+
+        unmatched_rules.push(types::UnmatchedRule {
+            rule_name: rule.name.clone(),
+            unreachable: get_unreachable_reason(&contexts),
+        });
+    }
+
+    Ok(unmatched_rules)
+}
+
 #[cfg(all(test, feature = "ast_parser"))]
 mod tests {
     use std::{ops::Deref, sync::Weak};
@@ -374,6 +669,193 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_unsatisfiable_range_detection() {
+        let program_str = r#"
+            default: ["stripe"]
+
+            night_window: ["adyen"]
+            {
+                payment_method = card & time_of_day >= 2200 & time_of_day < 600
+            }
+        "#;
+
+        let (_, program) = ast::parser::program::<DummyOutput>(program_str).expect("Program");
+        let analysis_result = analyze(program, None);
+
+        if let Err(types::AnalysisError {
+            error_type: types::AnalysisErrorType::UnsatisfiableRange { key, values },
+            ..
+        }) = analysis_result
+        {
+            assert!(
+                matches!(key.kind, dir::DirKeyKind::TimeOfDay),
+                "Key should be time_of_day"
+            );
+            assert_eq!(values.len(), 2, "There should be 2 range bounds");
+        } else {
+            panic!("Did not receive unsatisfiable range error");
+        }
+    }
+
+    #[test]
+    fn test_unmatched_rule_analysis() {
+        let program_str = r#"
+            default: ["stripe"]
+
+            card_rule: ["stripe"]
+            {
+                payment_method = card
+            }
+
+            wallet_rule: ["adyen"]
+            {
+                payment_method = wallet
+            }
+
+            night_window: ["adyen"]
+            {
+                time_of_day >= 2200 & time_of_day < 600
+            }
+        "#;
+
+        let (_, program) = ast::parser::program::<DummyOutput>(program_str).expect("Program");
+        let dir_program = ast::lowering::lower_program(program).expect("Lowering");
+        let matched_rules = FxHashSet::from_iter(["card_rule"]);
+        let unmatched_rules =
+            analyze_unmatched_rules(&dir_program, &matched_rules, None).expect("Analysis");
+
+        let mut unmatched_rules = unmatched_rules.into_iter();
+        let wallet_rule = unmatched_rules.next().expect("Unmatched wallet rule");
+        let night_window = unmatched_rules.next().expect("Unmatched night window rule");
+
+        assert!(
+            unmatched_rules.next().is_none(),
+            "Only 2 rules should be unmatched"
+        );
+        assert_eq!(wallet_rule.rule_name, "wallet_rule");
+        assert!(
+            wallet_rule.unreachable.is_none(),
+            "Wallet rule should be reachable"
+        );
+        assert_eq!(night_window.rule_name, "night_window");
+        assert!(
+            matches!(
+                night_window.unreachable,
+                Some(types::AnalysisError {
+                    error_type: types::AnalysisErrorType::UnsatisfiableRange { .. },
+                    ..
+                })
+            ),
+            "Night window rule should be unreachable"
+        );
+    }
+
+    #[test]
+    fn test_rule_analysis() {
+        let program_str = r#"
+            default: ["stripe"]
+
+            card_rule: ["stripe"]
+            {
+                payment_method = card
+            }
+
+            visa_rule: ["adyen"]
+            {
+                payment_method = card & card_network = visa
+            }
+
+            high_amount: ["stripe"]
+            {
+                amount > 1000
+            }
+
+            higher_amount: ["adyen"]
+            {
+                amount > 5000 & currency = USD
+                payment_method = wallet
+            }
+
+            mixed_rule: ["adyen"]
+            {
+                payment_method = (card, wallet) & amount > 100
+            }
+
+            night_window: ["adyen"]
+            {
+                payment_method = bank_debit & time_of_day >= 2200 & time_of_day < 600
+                payment_method = pay_later & currency = EUR
+            }
+
+            never_rule: ["adyen"]
+            {
+                payment_method = bank_redirect & payment_method = crypto
+            }
+        "#;
+
+        let (_, program) = ast::parser::program::<DummyOutput>(program_str).expect("Program");
+        let dir_program = ast::lowering::lower_program(program).expect("Lowering");
+        let findings = analyze_rules(&dir_program, None).expect("Analysis");
+        let mut findings = findings.into_iter();
+
+        let visa_rule = findings.next().expect("Visa rule finding");
+        assert_eq!(visa_rule.rule_name, "visa_rule");
+        assert!(
+            matches!(
+                &visa_rule.finding,
+                types::RuleFinding::Shadowed { shadowed_by } if shadowed_by == &["card_rule"]
+            ),
+            "Visa rule should be shadowed by the card rule"
+        );
+
+        let mixed_rule = findings.next().expect("Mixed rule finding");
+        assert_eq!(mixed_rule.rule_name, "mixed_rule");
+        assert!(
+            matches!(
+                &mixed_rule.finding,
+                types::RuleFinding::Shadowed { shadowed_by }
+                    if shadowed_by == &["card_rule", "higher_amount"]
+            ),
+            "Mixed rule should be shadowed by the card and higher amount rules"
+        );
+
+        let night_window = findings.next().expect("Night window finding");
+        assert_eq!(night_window.rule_name, "night_window");
+        assert!(
+            matches!(
+                &night_window.finding,
+                types::RuleFinding::DeadCondition {
+                    reason: types::AnalysisError {
+                        error_type: types::AnalysisErrorType::UnsatisfiableRange { .. },
+                        ..
+                    }
+                }
+            ),
+            "Night window rule should have a dead condition"
+        );
+
+        let never_rule = findings.next().expect("Never rule finding");
+        assert_eq!(never_rule.rule_name, "never_rule");
+        assert!(
+            matches!(
+                &never_rule.finding,
+                types::RuleFinding::Unreachable {
+                    reason: types::AnalysisError {
+                        error_type: types::AnalysisErrorType::ConflictingAssertions { .. },
+                        ..
+                    }
+                }
+            ),
+            "Never rule should be unreachable"
+        );
+
+        assert!(
+            findings.next().is_none(),
+            "No other rule should be reported"
+        );
+    }
+
     #[test]
     fn test_negation_graph_analysis() {
         let graph = knowledge! {
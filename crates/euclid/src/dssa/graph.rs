@@ -85,6 +85,10 @@ impl cgraph::NodeViz for dir::DirValue {
             Self::AcquirerFraudRate(acquirer_fraud_rate) => acquirer_fraud_rate.number.to_string(),
             Self::TransactionInitiator(transaction_initiator) => transaction_initiator.to_string(),
             Self::NetworkTokenType(ntt) => ntt.to_string(),
+            Self::TimeOfDay(time_of_day) => time_of_day.number.to_string(),
+            Self::DayOfWeek(day_of_week) => day_of_week.to_string(),
+            Self::Date(date) => date.number.to_string(),
+            Self::BaseCurrencyAmount(amount) => amount.number.to_string(),
         }
     }
 }
@@ -85,6 +85,33 @@ impl fmt::Display for AnalysisError {
         self.error_type.fmt(f)
     }
 }
+/// A finding from analysing a rule of a program
+#[derive(Debug, Clone, Serialize)]
+pub struct RuleAnalysis {
+    pub rule_name: String,
+    #[serde(flatten)]
+    pub finding: RuleFinding,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "finding", rename_all = "snake_case")]
+pub enum RuleFinding {
+    /// None of the conditions of the rule can be satisfied, hence the rule never matches
+    Unreachable { reason: AnalysisError },
+    /// Some of the conditions of the rule can never be satisfied, while the rest can
+    DeadCondition { reason: AnalysisError },
+    /// Every input matched by the rule is already matched by one of the preceding rules
+    Shadowed { shadowed_by: Vec<String> },
+}
+
+/// A rule of a program that did not match any of the inputs the program was evaluated against
+#[derive(Debug, Clone, Serialize)]
+pub struct UnmatchedRule {
+    pub rule_name: String,
+    /// Present when the conditions of the rule can never be satisfied by any input
+    pub unreachable: Option<AnalysisError>,
+}
+
 #[derive(Debug, Clone, Serialize)]
 pub struct ValueData {
     pub value: dir::DirValue,
@@ -133,6 +160,11 @@ pub enum AnalysisErrorType {
         key: dir::DirKey,
         metadata: Vec<Metadata>,
     },
+    #[error("Key '{}' is constrained to an empty range of values", .key.kind)]
+    UnsatisfiableRange {
+        key: dir::DirKey,
+        values: Vec<ValueData>,
+    },
     #[error("The condition '{value}' was asserted and negated in the same condition")]
     NegatedAssertion {
         value: dir::DirValue,
@@ -152,6 +184,10 @@ pub enum AnalysisErrorType {
     NotImplemented,
     #[error("The payment method type is not supported under the payment method")]
     NotSupported,
+    #[error("Invalid timezone '{0}' received for the program")]
+    InvalidTimezone(String),
+    #[error("The key '{}' requires the program to specify a base currency", .0)]
+    MissingBaseCurrency(dir::DirKeyKind),
 }
 
 #[derive(Debug, Clone)]
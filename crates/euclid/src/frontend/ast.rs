@@ -1,13 +1,14 @@
 pub mod lowering;
 #[cfg(feature = "ast_parser")]
 pub mod parser;
+pub mod printer;
 
 use common_utils::types::MinorUnit;
 use serde::{Deserialize, Serialize};
 use utoipa::ToSchema;
 
 use crate::{
-    enums::RoutableConnectors,
+    enums::{self, RoutableConnectors},
     types::{DataType, Metadata},
 };
 
@@ -158,6 +159,16 @@ pub struct Program<O> {
     pub rules: Vec<Rule<O>>,
     #[schema(value_type=HashMap<String, serde_json::Value>)]
     pub metadata: Metadata,
+    /// IANA timezone in which the temporal keys (`time_of_day`, `day_of_week`, `date`) of
+    /// the rules are evaluated. Defaults to UTC when not provided.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schema(example = "Asia/Kolkata")]
+    pub timezone: Option<String>,
+    /// Currency in which the `base_currency_amount` key of the rules is evaluated. The amount
+    /// of the payment is converted to this currency using the current exchange rates.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schema(example = "USD")]
+    pub base_currency: Option<enums::Currency>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
@@ -5,6 +5,8 @@
 
 use std::str::FromStr;
 
+use common_utils::types::MinorUnit;
+
 use crate::{
     dssa::types::{AnalysisError, AnalysisErrorType},
     enums::CollectVariants,
@@ -67,19 +69,23 @@ macro_rules! lower_enum {
 /// payment_amount = 17052001
 /// ```notrust
 /// This is for the cases in which there are numerical values involved and they are lowered
-/// accordingly on basis of the supplied key, currently payment_amount is the only key having this
-/// use case
+/// accordingly on basis of the supplied key. An optional validation closure can be supplied
+/// which is run against every number present in the value.
 macro_rules! lower_number {
-    ($key:ident, $value:ident, $comp:ident) => {
+    ($key:ident, $value:ident, $comp:ident $(, $validation_closure:expr)?) => {
         match $value {
-            ast::ValueType::Number(num) => Ok(vec![dir::DirValue::$key(types::NumValue {
-                number: num,
-                refinement: $comp.into(),
-            })]),
+            ast::ValueType::Number(num) => {
+                $($validation_closure(num)?;)?
+                Ok(vec![dir::DirValue::$key(types::NumValue {
+                    number: num,
+                    refinement: $comp.into(),
+                })])
+            }
 
             ast::ValueType::NumberArray(na) => na
                 .into_iter()
                 .map(|num| {
+                    $($validation_closure(num)?;)?
                     Ok(dir::DirValue::$key(types::NumValue {
                         number: num,
                         refinement: $comp.clone().into(),
@@ -90,6 +96,7 @@ macro_rules! lower_number {
             ast::ValueType::NumberComparisonArray(nca) => nca
                 .into_iter()
                 .map(|nc| {
+                    $($validation_closure(nc.number)?;)?
                     Ok(dir::DirValue::$key(types::NumValue {
                         number: nc.number,
                         refinement: nc.comparison_type.into(),
@@ -291,6 +298,47 @@ fn lower_comparison_inner<O: EuclidDirFilter>(
         dir::DirKeyKind::AcquirerFraudRate => lower_number!(AcquirerFraudRate, value, comparison),
         dir::DirKeyKind::TransactionInitiator => lower_enum!(TransactionInitiator, value),
         dir::DirKeyKind::NetworkTokenType => lower_enum!(NetworkTokenType, value),
+        dir::DirKeyKind::TimeOfDay => {
+            let validation_closure = |num: MinorUnit| -> Result<(), AnalysisErrorType> {
+                let hhmm = num.get_amount_as_i64();
+                if (0..2400).contains(&hhmm) && hhmm % 100 < 60 {
+                    Ok(())
+                } else {
+                    Err(AnalysisErrorType::InvalidValue {
+                        key: dir::DirKeyKind::TimeOfDay,
+                        value: hhmm.to_string(),
+                        message: Some("Expected a time of day in the HHMM format".to_string()),
+                    })
+                }
+            };
+            lower_number!(TimeOfDay, value, comparison, validation_closure)
+        }
+        dir::DirKeyKind::DayOfWeek => lower_enum!(DayOfWeek, value),
+        dir::DirKeyKind::Date => {
+            let validation_closure = |num: MinorUnit| -> Result<(), AnalysisErrorType> {
+                let yyyymmdd = num.get_amount_as_i64();
+                let is_valid_date = i32::try_from(yyyymmdd / 10000)
+                    .ok()
+                    .zip(u32::try_from(yyyymmdd / 100 % 100).ok())
+                    .zip(u32::try_from(yyyymmdd % 100).ok())
+                    .and_then(|((year, month), day)| {
+                        chrono::NaiveDate::from_ymd_opt(year, month, day)
+                    })
+                    .is_some();
+
+                if is_valid_date {
+                    Ok(())
+                } else {
+                    Err(AnalysisErrorType::InvalidValue {
+                        key: dir::DirKeyKind::Date,
+                        value: yyyymmdd.to_string(),
+                        message: Some("Expected a date in the YYYYMMDD format".to_string()),
+                    })
+                }
+            };
+            lower_number!(Date, value, comparison, validation_closure)
+        }
+        dir::DirKeyKind::BaseCurrencyAmount => lower_number!(BaseCurrencyAmount, value, comparison),
     }
 }
 
@@ -360,13 +408,51 @@ pub fn lower_rule<O: EuclidDirFilter>(
 pub fn lower_program<O: EuclidDirFilter>(
     program: ast::Program<O>,
 ) -> Result<dir::DirProgram<O>, AnalysisError> {
+    if let Some(timezone) = program.timezone.as_ref() {
+        types::parse_timezone(timezone).ok_or_else(|| AnalysisError {
+            error_type: AnalysisErrorType::InvalidTimezone(timezone.clone()),
+            metadata: program.metadata.clone(),
+        })?;
+    }
+
+    let rules: Vec<dir::DirRule<O>> = program
+        .rules
+        .into_iter()
+        .map(lower_rule)
+        .collect::<Result<_, _>>()?;
+
+    if program.base_currency.is_none()
+        && rules
+            .iter()
+            .any(|rule| uses_key(&rule.statements, &dir::DirKeyKind::BaseCurrencyAmount))
+    {
+        return Err(AnalysisError {
+            error_type: AnalysisErrorType::MissingBaseCurrency(dir::DirKeyKind::BaseCurrencyAmount),
+            metadata: program.metadata,
+        });
+    }
+
     Ok(dir::DirProgram {
         default_selection: program.default_selection,
-        rules: program
-            .rules
-            .into_iter()
-            .map(lower_rule)
-            .collect::<Result<_, _>>()?,
+        rules,
         metadata: program.metadata,
+        timezone: program.timezone,
+        base_currency: program.base_currency,
+    })
+}
+
+/// checks whether any comparison within the statements, including the nested ones, is made
+/// against the supplied key
+fn uses_key(statements: &[dir::DirIfStatement], key: &dir::DirKeyKind) -> bool {
+    statements.iter().any(|statement| {
+        statement.condition.iter().any(|comparison| {
+            comparison
+                .values
+                .iter()
+                .any(|value| value.get_key().kind == *key)
+        }) || statement
+            .nested
+            .as_deref()
+            .is_some_and(|nested| uses_key(nested, key))
     })
 }
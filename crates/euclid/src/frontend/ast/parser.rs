@@ -1,9 +1,11 @@
+use std::str::FromStr;
+
 use common_utils::types::MinorUnit;
 use nom::{
     branch, bytes::complete, character::complete as pchar, combinator, error, multi, sequence,
 };
 
-use crate::{frontend::ast, types::DummyOutput};
+use crate::{enums, frontend::ast, types::DummyOutput};
 pub type ParseResult<T, U> = nom::IResult<T, U, error::VerboseError<T>>;
 
 pub enum EuclidError {
@@ -62,7 +64,10 @@ pub fn num_i64(input: &str) -> ParseResult<&str, i64> {
     error::context(
         "num_i32",
         combinator::map_res(
-            complete::take_while1(|c: char| c.is_ascii_digit()),
+            combinator::recognize(sequence::pair(
+                combinator::opt(pchar::char('-')),
+                complete::take_while1(|c: char| c.is_ascii_digit()),
+            )),
             |o: &str| {
                 o.parse::<i64>()
                     .map_err(|_| EuclidError::InvalidNumber(o.to_string()))
@@ -71,16 +76,29 @@ pub fn num_i64(input: &str) -> ParseResult<&str, i64> {
     )(input)
 }
 
+/// Parses a double quoted string, in which `"` and `\` are escaped with a `\`
 pub fn string_str(input: &str) -> ParseResult<&str, String> {
+    let escaped_char = sequence::preceded(
+        pchar::char('\\'),
+        branch::alt((
+            combinator::value("\\", pchar::char('\\')),
+            combinator::value("\"", pchar::char('"')),
+        )),
+    );
+
     error::context(
         "String",
-        combinator::map(
-            sequence::delimited(
-                complete::tag("\""),
-                complete::take_while1(|c: char| c != '"'),
-                complete::tag("\""),
+        sequence::delimited(
+            complete::tag("\""),
+            multi::fold_many0(
+                branch::alt((complete::is_not("\"\\"), escaped_char)),
+                String::new,
+                |mut acc: String, val: &str| {
+                    acc.push_str(val);
+                    acc
+                },
             ),
-            |val: &str| val.to_string(),
+            complete::tag("\""),
         ),
     )(input)
 }
@@ -199,12 +217,16 @@ pub fn enum_variant_array_value(input: &str) -> ParseResult<&str, ast::ValueType
 pub fn number_comparison(input: &str) -> ParseResult<&str, ast::NumberComparison> {
     let operator = combinator::map_res(
         branch::alt((
+            complete::tag("/="),
             complete::tag(">="),
             complete::tag("<="),
+            complete::tag("="),
             complete::tag(">"),
             complete::tag("<"),
         )),
         |s: &str| match s {
+            "/=" => Ok(ast::ComparisonType::NotEqual),
+            "=" => Ok(ast::ComparisonType::Equal),
             ">=" => Ok(ast::ComparisonType::GreaterThanEqual),
             "<=" => Ok(ast::ComparisonType::LessThanEqual),
             ">" => Ok(ast::ComparisonType::GreaterThan),
@@ -335,7 +357,10 @@ pub fn arbitrary_comparison(input: &str) -> ParseResult<&str, ast::Comparison> {
 pub fn comparison_array(input: &str) -> ParseResult<&str, Vec<ast::Comparison>> {
     let many_with_ampersand = error::context(
         "many_with_amp",
-        multi::many0(sequence::preceded(skip_ws(complete::tag("&")), comparison)),
+        multi::many0(sequence::preceded(
+            skip_ws(complete::tag("&")),
+            skip_ws(branch::alt((comparison, arbitrary_comparison))),
+        )),
     );
 
     let full_sequence = sequence::pair(
@@ -387,26 +412,23 @@ pub fn rule_conditions_array(input: &str) -> ParseResult<&str, Vec<ast::IfStatem
 }
 
 pub fn rule<O: EuclidParsable>(input: &str) -> ParseResult<&str, ast::Rule<O>> {
-    let rule_name = error::context(
-        "rule_name",
-        combinator::map(
-            skip_ws(sequence::pair(
-                complete::take_while1(|c: char| c.is_ascii_alphabetic() || c == '_'),
-                complete::take_while(|c: char| c.is_ascii_alphanumeric() || c == '_'),
-            )),
-            |out: (&str, &str)| out.0.to_string() + out.1,
-        ),
-    );
+    let rule_name = error::context("rule_name", skip_ws(branch::alt((identifier, string_str))));
 
     let connector_selection = error::context(
         "parse_output",
         sequence::preceded(skip_ws(complete::tag(":")), output),
     );
 
+    // Once the name of the rule is parsed, any error in the rest of the rule is reported as is
+    // instead of backtracking, for the error to point to where it actually occurred
     error::context(
         "rule",
         combinator::map(
-            sequence::tuple((rule_name, connector_selection, rule_conditions_array)),
+            sequence::tuple((
+                rule_name,
+                combinator::cut(connector_selection),
+                combinator::cut(rule_conditions_array),
+            )),
             |tup: (String, O, Vec<ast::IfStatement>)| ast::Rule {
                 name: tup.0,
                 connector_selection: tup.1,
@@ -430,16 +452,142 @@ pub fn default_output<O: EuclidParsable + 'static>(input: &str) -> ParseResult<&
     )(input)
 }
 
+pub fn timezone(input: &str) -> ParseResult<&str, String> {
+    error::context(
+        "timezone",
+        sequence::preceded(
+            sequence::pair(
+                skip_ws(complete::tag("timezone")),
+                skip_ws(pchar::char(':')),
+            ),
+            skip_ws(string_str),
+        ),
+    )(input)
+}
+
+pub fn base_currency(input: &str) -> ParseResult<&str, enums::Currency> {
+    error::context(
+        "base_currency",
+        sequence::preceded(
+            sequence::pair(
+                skip_ws(complete::tag("base_currency")),
+                skip_ws(pchar::char(':')),
+            ),
+            combinator::map_res(skip_ws(string_str), |currency: String| {
+                enums::Currency::from_str(&currency)
+            }),
+        ),
+    )(input)
+}
+
 pub fn program<O: EuclidParsable + 'static>(input: &str) -> ParseResult<&str, ast::Program<O>> {
+    program_with_rules(input, 1)
+}
+
+/// Parses a program having at least `min_rules` rules
+fn program_with_rules<O: EuclidParsable + 'static>(
+    input: &str,
+    min_rules: usize,
+) -> ParseResult<&str, ast::Program<O>> {
     error::context(
         "program",
         combinator::map(
-            sequence::pair(default_output, multi::many1(skip_ws(rule::<O>))),
-            |tup: (O, Vec<ast::Rule<O>>)| ast::Program {
-                default_selection: tup.0,
-                rules: tup.1,
+            sequence::tuple((
+                combinator::opt(timezone),
+                combinator::opt(base_currency),
+                default_output,
+                multi::many_m_n(min_rules, usize::MAX, skip_ws(rule::<O>)),
+            )),
+            |tup: (
+                Option<String>,
+                Option<enums::Currency>,
+                O,
+                Vec<ast::Rule<O>>,
+            )| ast::Program {
+                default_selection: tup.2,
+                rules: tup.3,
                 metadata: std::collections::HashMap::new(),
+                timezone: tup.0,
+                base_currency: tup.1,
             },
         ),
     )(input)
 }
+
+/// An error encountered while parsing a program, along with the position at which it occurred
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, thiserror::Error)]
+#[error("{message} at line {line}, column {column}")]
+pub struct ParseError {
+    /// 1-based line number of the position at which parsing failed
+    pub line: usize,
+    /// 1-based column number of the position at which parsing failed
+    pub column: usize,
+    pub message: String,
+}
+
+impl ParseError {
+    fn new(input: &str, error: error::VerboseError<&str>) -> Self {
+        let (remaining, kind) = error
+            .errors
+            .first()
+            .map(|(remaining, kind)| (*remaining, kind.clone()))
+            .unwrap_or((input, error::VerboseErrorKind::Nom(error::ErrorKind::Fail)));
+
+        // The outermost context that failed at the same position as the innermost error
+        let context = error
+            .errors
+            .iter()
+            .filter(|(position, _)| position.len() == remaining.len())
+            .filter_map(|(_, kind)| match kind {
+                error::VerboseErrorKind::Context(context) => Some(*context),
+                error::VerboseErrorKind::Char(_) | error::VerboseErrorKind::Nom(_) => None,
+            })
+            .last();
+        let message = match (kind, context) {
+            (error::VerboseErrorKind::Char(c), _) => format!("expected '{c}'"),
+            (error::VerboseErrorKind::Nom(error::ErrorKind::Eof), _) => {
+                "unexpected trailing input".to_string()
+            }
+            (_, Some(context)) => format!("invalid {context}"),
+            (_, None) => "unexpected input".to_string(),
+        };
+
+        let consumed = input
+            .get(..input.len().saturating_sub(remaining.len()))
+            .unwrap_or_default();
+        let line = consumed.matches('\n').count() + 1;
+        let column = consumed
+            .rsplit('\n')
+            .next()
+            .map_or(0, |current_line| current_line.chars().count())
+            + 1;
+
+        Self {
+            line,
+            column,
+            message,
+        }
+    }
+}
+
+/// Parses the complete textual form of a program, reporting the line and column on failure.
+///
+/// Unlike [`program`], a program without any rules is accepted, as the whole input has to be
+/// consumed and a malformed first rule can't be mistaken for the end of the program.
+pub fn parse_program<O: EuclidParsable + 'static>(
+    input: &str,
+) -> Result<ast::Program<O>, ParseError> {
+    combinator::all_consuming(sequence::terminated(
+        |input| program_with_rules::<O>(input, 0),
+        pchar::multispace0,
+    ))(input)
+    .map(|(_, program)| program)
+    .map_err(|err| match err {
+        nom::Err::Error(error) | nom::Err::Failure(error) => ParseError::new(input, error),
+        nom::Err::Incomplete(_) => ParseError {
+            line: input.lines().count().max(1),
+            column: input.lines().last().map_or(0, |line| line.chars().count()) + 1,
+            message: "unexpected end of input".to_string(),
+        },
+    })
+}
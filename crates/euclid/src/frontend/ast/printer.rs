@@ -0,0 +1,556 @@
+//! Canonical formatter for the textual form of the DSL
+//!
+//! The text produced here is accepted by the parser, such that parsing the formatted text of a
+//! program yields back the same program. The metadata attached to the program and to the
+//! comparisons is not a part of the textual form, and hence is not retained.
+
+use crate::{frontend::ast, types::DummyOutput};
+
+const INDENT: &str = "    ";
+
+pub trait EuclidPrintable {
+    fn print_output(&self) -> String;
+}
+
+impl EuclidPrintable for DummyOutput {
+    fn print_output(&self) -> String {
+        format!(
+            "[{}]",
+            self.outputs
+                .iter()
+                .map(|output| string_str(output))
+                .collect::<Vec<_>>()
+                .join(", ")
+        )
+    }
+}
+
+/// Formats a string as a double quoted literal, escaping `"` and `\`
+pub fn string_str(value: &str) -> String {
+    format!("\"{}\"", value.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+/// Formats a name as is if it is a valid identifier, and as a string literal otherwise
+pub fn identifier(name: &str) -> String {
+    let mut chars = name.chars();
+    let is_identifier = chars
+        .next()
+        .is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_');
+
+    if is_identifier {
+        name.to_string()
+    } else {
+        string_str(name)
+    }
+}
+
+pub fn comparison_type(comparison_type: &ast::ComparisonType) -> &'static str {
+    match comparison_type {
+        ast::ComparisonType::Equal => "=",
+        ast::ComparisonType::NotEqual => "/=",
+        ast::ComparisonType::LessThan => "<",
+        ast::ComparisonType::LessThanEqual => "<=",
+        ast::ComparisonType::GreaterThan => ">",
+        ast::ComparisonType::GreaterThanEqual => ">=",
+    }
+}
+
+pub fn value_type(value: &ast::ValueType) -> String {
+    match value {
+        ast::ValueType::Number(number) => number.to_string(),
+        ast::ValueType::EnumVariant(variant) => variant.clone(),
+        ast::ValueType::MetadataVariant(metadata) => string_str(&metadata.value),
+        ast::ValueType::StrValue(value) => string_str(value),
+        ast::ValueType::NumberArray(numbers) => format!(
+            "({})",
+            numbers
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>()
+                .join(", ")
+        ),
+        ast::ValueType::EnumVariantArray(variants) => format!("({})", variants.join(", ")),
+        ast::ValueType::NumberComparisonArray(comparisons) => format!(
+            "({})",
+            comparisons
+                .iter()
+                .map(|comparison| format!(
+                    "{}{}",
+                    comparison_type(&comparison.comparison_type),
+                    comparison.number
+                ))
+                .collect::<Vec<_>>()
+                .join(", ")
+        ),
+    }
+}
+
+pub fn comparison(comparison: &ast::Comparison) -> String {
+    match &comparison.value {
+        ast::ValueType::MetadataVariant(metadata) => format!(
+            "{} {} {}",
+            string_str(&metadata.key),
+            comparison_type(&comparison.comparison),
+            string_str(&metadata.value)
+        ),
+        value => format!(
+            "{} {} {}",
+            comparison.lhs,
+            comparison_type(&comparison.comparison),
+            value_type(value)
+        ),
+    }
+}
+
+fn if_statement(statement: &ast::IfStatement, depth: usize, out: &mut String) {
+    let indent = INDENT.repeat(depth);
+
+    out.push_str(&indent);
+    out.push_str(
+        &statement
+            .condition
+            .iter()
+            .map(comparison)
+            .collect::<Vec<_>>()
+            .join(" & "),
+    );
+
+    if let Some(nested) = &statement.nested {
+        out.push_str(" {\n");
+        for nested_statement in nested {
+            if_statement(nested_statement, depth + 1, out);
+        }
+        out.push_str(&indent);
+        out.push('}');
+    }
+    out.push('\n');
+}
+
+pub fn rule<O: EuclidPrintable>(rule: &ast::Rule<O>) -> String {
+    let mut out = format!(
+        "{}: {}\n{{\n",
+        identifier(&rule.name),
+        rule.connector_selection.print_output()
+    );
+    for statement in &rule.statements {
+        if_statement(statement, 1, &mut out);
+    }
+    out.push_str("}\n");
+    out
+}
+
+/// Formats the program in its canonical textual form
+pub fn program<O: EuclidPrintable>(program: &ast::Program<O>) -> String {
+    let mut out = String::new();
+
+    if let Some(timezone) = &program.timezone {
+        out.push_str(&format!("timezone: {}\n", string_str(timezone)));
+    }
+    if let Some(base_currency) = &program.base_currency {
+        out.push_str(&format!(
+            "base_currency: {}\n",
+            string_str(&base_currency.to_string())
+        ));
+    }
+    out.push_str(&format!(
+        "default: {}\n",
+        program.default_selection.print_output()
+    ));
+
+    for program_rule in &program.rules {
+        out.push('\n');
+        out.push_str(&rule(program_rule));
+    }
+
+    out
+}
+
+#[cfg(all(test, feature = "ast_parser"))]
+mod tests {
+    use super::*;
+    use crate::frontend::ast::parser;
+
+    const PROGRAM: &str = r#"timezone: "Asia/Kolkata"
+base_currency: "USD"
+default: ["stripe", "adyen"]
+
+rule_1: ["stripe"]
+{
+    payment_method = card & amount >= 100 {
+        card_network = (visa, mastercard)
+        amount = (>500, <=1000)
+    }
+    "order_type" = "digital" & currency /= USD
+    card_bin = "4242\"42" & amount = (-1, 2, 3) {
+    }
+}
+
+"high value rule": ["adyen"]
+{
+    base_currency_amount > 100000
+}
+"#;
+
+    #[test]
+    fn test_program_round_trip() {
+        let program = parser::parse_program::<DummyOutput>(PROGRAM).expect("Program");
+        let formatted = super::program(&program);
+        assert_eq!(formatted, PROGRAM);
+
+        let reparsed = parser::parse_program::<DummyOutput>(&formatted).expect("Program");
+        assert_eq!(
+            serde_json::to_value(&reparsed).expect("Program json"),
+            serde_json::to_value(&program).expect("Program json")
+        );
+    }
+
+    /// Programs used by the tests of the interpreter, analyzer and graph, which have to parse
+    /// the same way as before the textual form was introduced
+    const EXISTING_PROGRAMS: [&str; 25] = [
+        r#"
+default: [ "stripe",  "adyen"]
+
+rule_1: ["stripe"]
+{
+    pay_later = klarna
+}
+
+rule_2: ["adyen"]
+{
+    pay_later = affirm
+}
+"#,
+        r#"
+default: ["stripe", "adyen"]
+rule_1: ["stripe"]
+{
+   payment_type = setup_mandate
+}
+"#,
+        r#"
+default: ["stripe", "adyen"]
+rule_1: ["stripe"]
+{
+   payment_type = ppt_mandate
+}
+"#,
+        r#"
+default: ["stripe", "adyen"]
+rule_1: ["stripe"]
+{
+   mandate_type = single_use
+}
+"#,
+        r#"
+default: ["stripe","adyen"]
+rule_1: ["stripe"]
+{
+   mandate_acceptance_type = online
+}
+"#,
+        r#"
+default: ["stripe", "adyen"]
+
+rule_1: ["stripe"]
+{
+   card_bin="123456"
+}
+"#,
+        r#"
+default: ["stripe", "adyen"]
+
+rule_1: ["stripe"]
+{
+   amount = 32
+}
+"#,
+        r#"
+default: ["stripe", "adyen"]
+
+rule_1: ["stripe"]
+{
+   payment_method = pay_later
+}
+"#,
+        r#"
+default: ["stripe", "adyen"]
+
+rule_1: ["stripe"]
+{
+   setup_future_usage = off_session
+}
+"#,
+        r#"
+default: ["stripe"," adyen"]
+
+rule_1: ["stripe"]
+{
+"metadata_key" = "arbitrary meta"
+}
+"#,
+        r#"
+default: ["stripe", "adyen"]
+
+rule_1: ["stripe"]
+{
+   amount>=123
+}
+"#,
+        r#"
+default: ["stripe", "adyen"]
+
+rule_1: ["stripe"]
+{
+   amount<=123
+}
+"#,
+        r#"
+timezone: "Asia/Kolkata"
+default: ["stripe", "adyen"]
+
+maintenance_window: ["adyen"]
+{
+   time_of_day >= 100 & time_of_day < 300
+}
+
+weekend: ["stripe"]
+{
+   day_of_week = (saturday, sunday) & date >= 20241201
+}
+"#,
+        r#"
+base_currency: "USD"
+default: ["stripe"]
+
+high_value: ["adyen"]
+{
+   base_currency_amount >= 10000
+}
+"#,
+        r#"
+default: ["stripe"]
+
+high_value: ["adyen"]
+{
+   payment_method = card {
+       base_currency_amount >= 10000
+   }
+}
+"#,
+        r#"
+default: ["stripe", "adyen"]
+
+stripe_first: ["stripe", "adyen"]
+{
+    payment_method = wallet {
+        amount > 500 & capture_method = automatic
+        amount < 500 & payment_method = card
+    }
+}
+"#,
+        r#"
+default: ["stripe"]
+
+rule_1: ["adyen"]
+{
+    payment_method /= wallet {
+        capture_method = manual & payment_method /= card {
+            authentication_type = three_ds & payment_method /= pay_later {
+                amount > 1000 & payment_method /= bank_redirect {
+                    payment_method /= crypto
+                        & payment_method /= bank_debit
+                        & payment_method /= bank_transfer
+                        & payment_method /= upi
+                        & payment_method /= reward
+                        & payment_method /= voucher
+                        & payment_method /= gift_card
+                        & payment_method /= card_redirect
+                        & payment_method /= real_time_payment
+                        & payment_method /= open_banking
+                        & payment_method /= mobile_payment
+                }
+            }
+        }
+    }
+}
+"#,
+        r#"
+default: ["stripe"]
+
+rule_1: ["adyen"]
+{
+    payment_method = wallet {
+        amount > 500 {
+            capture_method = automatic
+        }
+
+        amount < 501 {
+            payment_method /= wallet
+        }
+    }
+}
+"#,
+        r#"
+default: ["stripe"]
+
+night_window: ["adyen"]
+{
+    payment_method = card & time_of_day >= 2200 & time_of_day < 600
+}
+"#,
+        r#"
+default: ["stripe"]
+
+card_rule: ["stripe"]
+{
+    payment_method = card
+}
+
+wallet_rule: ["adyen"]
+{
+    payment_method = wallet
+}
+
+night_window: ["adyen"]
+{
+    time_of_day >= 2200 & time_of_day < 600
+}
+"#,
+        r#"
+default: ["stripe"]
+
+card_rule: ["stripe"]
+{
+    payment_method = card
+}
+
+visa_rule: ["adyen"]
+{
+    payment_method = card & card_network = visa
+}
+
+high_amount: ["stripe"]
+{
+    amount > 1000
+}
+
+higher_amount: ["adyen"]
+{
+    amount > 5000 & currency = USD
+    payment_method = wallet
+}
+
+mixed_rule: ["adyen"]
+{
+    payment_method = (card, wallet) & amount > 100
+}
+
+night_window: ["adyen"]
+{
+    payment_method = bank_debit & time_of_day >= 2200 & time_of_day < 600
+    payment_method = pay_later & currency = EUR
+}
+
+never_rule: ["adyen"]
+{
+    payment_method = bank_redirect & payment_method = crypto
+}
+"#,
+        r#"
+default: ["stripe"]
+
+rule_1: ["adyen"]
+{
+    amount > 500 {
+        payment_method = pay_later
+    }
+
+    amount < 500 {
+        payment_method /= wallet & payment_method /= pay_later
+    }
+}
+"#,
+        r#"
+default: ["stripe", "adyen"]
+
+stripe_first: ["stripe", "adyen"]
+{
+    payment_method = wallet {
+        payment_method = (card, bank_redirect) {
+            currency = USD
+            currency = GBP
+        }
+
+        payment_method = pay_later {
+            capture_method = automatic
+            capture_method = manual
+        }
+    }
+
+    payment_method = card {
+        payment_method = (card, bank_redirect) & capture_method = (automatic, manual) {
+            currency = (USD, GBP)
+        }
+    }
+}
+"#,
+        r#"
+default: ["stripe", "adyen"]
+
+rule_1: ["stripe"]
+{
+   payment_method = card
+}
+"#,
+        r#"
+default: ["stripe", "adyen"]
+
+rule_1: ["stripe"]
+{
+   bank_debit = ach
+}
+"#,
+    ];
+
+    #[test]
+    fn test_existing_programs_round_trip() {
+        for program_str in EXISTING_PROGRAMS {
+            let (_, program) = parser::program::<DummyOutput>(program_str).expect("Program");
+            let parsed = parser::parse_program::<DummyOutput>(program_str).expect("Program");
+            assert_eq!(
+                serde_json::to_value(&parsed).expect("Program json"),
+                serde_json::to_value(&program).expect("Program json"),
+                "{program_str}"
+            );
+
+            let formatted = super::program(&program);
+            let reparsed = parser::parse_program::<DummyOutput>(&formatted).expect("Program");
+            assert_eq!(
+                serde_json::to_value(&reparsed).expect("Program json"),
+                serde_json::to_value(&program).expect("Program json"),
+                "{formatted}"
+            );
+            assert_eq!(super::program(&reparsed), formatted);
+        }
+    }
+
+    #[test]
+    fn test_program_without_rules() {
+        let program_str = "default: [\"stripe\"]\n";
+        assert!(parser::program::<DummyOutput>(program_str).is_err());
+
+        let program = parser::parse_program::<DummyOutput>(program_str).expect("Program");
+        assert!(program.rules.is_empty());
+        assert_eq!(super::program(&program), program_str);
+    }
+
+    #[test]
+    fn test_parse_error_position() {
+        let program_str =
+            "default: [\"stripe\"]\n\nrule_1: [\"stripe\"]\n{\n    payment_method == card\n}\n";
+        let error = parser::parse_program::<DummyOutput>(program_str).expect_err("Parse error");
+        assert_eq!(error.line, 5);
+        assert_eq!(error.column, 21);
+    }
+}
@@ -355,6 +355,34 @@ pub enum DirKeyKind {
     )]
     #[serde(rename = "network_token")]
     NetworkTokenType,
+    #[strum(
+        serialize = "time_of_day",
+        detailed_message = "Local time of the payment in the routing timezone, as HHMM (eg. 0930, 2215)",
+        props(Category = "Time")
+    )]
+    #[serde(rename = "time_of_day")]
+    TimeOfDay,
+    #[strum(
+        serialize = "day_of_week",
+        detailed_message = "Local day of the week of the payment in the routing timezone",
+        props(Category = "Time")
+    )]
+    #[serde(rename = "day_of_week")]
+    DayOfWeek,
+    #[strum(
+        serialize = "date",
+        detailed_message = "Local date of the payment in the routing timezone, as YYYYMMDD (eg. 20241225)",
+        props(Category = "Time")
+    )]
+    #[serde(rename = "date")]
+    Date,
+    #[strum(
+        serialize = "base_currency_amount",
+        detailed_message = "Value of the transaction converted to the base currency of the routing program",
+        props(Category = "Payments")
+    )]
+    #[serde(rename = "base_currency_amount")]
+    BaseCurrencyAmount,
 }
 
 pub trait EuclidDirFilter: Sized
@@ -415,6 +443,10 @@ impl DirKeyKind {
             Self::AcquirerFraudRate => types::DataType::Number,
             Self::TransactionInitiator => types::DataType::EnumVariant,
             Self::NetworkTokenType => types::DataType::EnumVariant,
+            Self::TimeOfDay => types::DataType::Number,
+            Self::DayOfWeek => types::DataType::EnumVariant,
+            Self::Date => types::DataType::Number,
+            Self::BaseCurrencyAmount => types::DataType::Number,
         }
     }
     pub fn get_value_set(&self) -> Option<Vec<DirValue>> {
@@ -590,6 +622,10 @@ impl DirKeyKind {
                     .map(DirValue::NetworkTokenType)
                     .collect(),
             ),
+            Self::TimeOfDay => None,
+            Self::DayOfWeek => Some(enums::DayOfWeek::iter().map(DirValue::DayOfWeek).collect()),
+            Self::Date => None,
+            Self::BaseCurrencyAmount => None,
         }
     }
 }
@@ -681,6 +717,14 @@ pub enum DirValue {
     TransactionInitiator(enums::TransactionInitiator),
     #[serde(rename = "network_token")]
     NetworkTokenType(enums::NetworkTokenType),
+    #[serde(rename = "time_of_day")]
+    TimeOfDay(types::NumValue),
+    #[serde(rename = "day_of_week")]
+    DayOfWeek(enums::DayOfWeek),
+    #[serde(rename = "date")]
+    Date(types::NumValue),
+    #[serde(rename = "base_currency_amount")]
+    BaseCurrencyAmount(types::NumValue),
 }
 
 impl DirValue {
@@ -727,6 +771,10 @@ impl DirValue {
             Self::AcquirerFraudRate(_) => (DirKeyKind::AcquirerFraudRate, None),
             Self::TransactionInitiator(_) => (DirKeyKind::TransactionInitiator, None),
             Self::NetworkTokenType(_) => (DirKeyKind::NetworkTokenType, None),
+            Self::TimeOfDay(_) => (DirKeyKind::TimeOfDay, None),
+            Self::DayOfWeek(_) => (DirKeyKind::DayOfWeek, None),
+            Self::Date(_) => (DirKeyKind::Date, None),
+            Self::BaseCurrencyAmount(_) => (DirKeyKind::BaseCurrencyAmount, None),
         };
 
         DirKey::new(kind, data)
@@ -774,6 +822,10 @@ impl DirValue {
             Self::AcquirerFraudRate(_) => None,
             Self::TransactionInitiator(_) => None,
             Self::NetworkTokenType(_) => None,
+            Self::TimeOfDay(_) => None,
+            Self::DayOfWeek(_) => None,
+            Self::Date(_) => None,
+            Self::BaseCurrencyAmount(_) => None,
         }
     }
 
@@ -790,6 +842,9 @@ impl DirValue {
         match self {
             Self::PaymentAmount(val) => Some(val.clone()),
             Self::AcquirerFraudRate(val) => Some(val.clone()),
+            Self::TimeOfDay(val) => Some(val.clone()),
+            Self::Date(val) => Some(val.clone()),
+            Self::BaseCurrencyAmount(val) => Some(val.clone()),
             _ => None,
         }
     }
@@ -832,6 +887,10 @@ impl DirValue {
             (Self::AcquirerFraudRate(r1), Self::AcquirerFraudRate(r2)) => r1 == r2,
             (Self::TransactionInitiator(ti1), Self::TransactionInitiator(ti2)) => ti1 == ti2,
             (Self::NetworkTokenType(ntt1), Self::NetworkTokenType(ntt2)) => ntt1 == ntt2,
+            (Self::TimeOfDay(t1), Self::TimeOfDay(t2)) => t1 == t2,
+            (Self::DayOfWeek(d1), Self::DayOfWeek(d2)) => d1 == d2,
+            (Self::Date(d1), Self::Date(d2)) => d1 == d2,
+            (Self::BaseCurrencyAmount(a1), Self::BaseCurrencyAmount(a2)) => a1 == a2,
             _ => false,
         }
     }
@@ -975,6 +1034,8 @@ pub struct DirProgram<O> {
     pub default_selection: O,
     pub rules: Vec<DirRule<O>>,
     pub metadata: types::Metadata,
+    pub timezone: Option<String>,
+    pub base_currency: Option<euclid_enums::Currency>,
 }
 
 #[cfg(test)]
@@ -1025,6 +1086,10 @@ mod test {
             dirval!(CaptureMethod = Manual),
             dirval!(BillingCountry = UnitedStatesOfAmerica),
             dirval!(BusinessCountry = France),
+            dirval!(TimeOfDay = 930),
+            dirval!(DayOfWeek = Saturday),
+            dirval!(Date = 20241225),
+            dirval!(BaseCurrencyAmount = 5000),
         ];
 
         for val in values {
@@ -301,6 +301,10 @@ fn lower_value(dir_value: dir::DirValue) -> Result<EuclidValue, AnalysisErrorTyp
         dir::DirValue::AcquirerFraudRate(num_value) => EuclidValue::AcquirerFraudRate(num_value),
         dir::DirValue::TransactionInitiator(ti) => EuclidValue::TransactionInitiator(ti),
         dir::DirValue::NetworkTokenType(nt) => EuclidValue::PaymentMethodType(nt.into()),
+        dir::DirValue::TimeOfDay(tod) => EuclidValue::TimeOfDay(tod),
+        dir::DirValue::DayOfWeek(dow) => EuclidValue::DayOfWeek(dow),
+        dir::DirValue::Date(date) => EuclidValue::Date(date),
+        dir::DirValue::BaseCurrencyAmount(amount) => EuclidValue::BaseCurrencyAmount(amount),
     })
 }
 
@@ -372,5 +376,7 @@ pub fn lower_program<O>(
                 metadata: Default::default(),
             })?,
         metadata: dir_program.metadata,
+        timezone: dir_program.timezone,
+        base_currency: dir_program.base_currency,
     })
 }
@@ -1,7 +1,10 @@
 //! Valued Intermediate Representation
 use serde::{Deserialize, Serialize};
 
-use crate::types::{EuclidValue, Metadata};
+use crate::{
+    enums,
+    types::{EuclidValue, Metadata},
+};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum ValuedComparisonLogic {
@@ -36,4 +39,8 @@ pub struct ValuedProgram<O> {
     pub default_selection: O,
     pub rules: Vec<ValuedRule<O>>,
     pub metadata: Metadata,
+    #[serde(default)]
+    pub timezone: Option<String>,
+    #[serde(default)]
+    pub base_currency: Option<enums::Currency>,
 }
@@ -12,7 +12,7 @@ use crate::{
         ast,
         dir::{
             enums::{
-                CustomerDeviceDisplaySize, CustomerDevicePlatform, CustomerDeviceType,
+                CustomerDeviceDisplaySize, CustomerDevicePlatform, CustomerDeviceType, DayOfWeek,
                 TransactionInitiator,
             },
             DirKeyKind, DirValue, EuclidDirFilter,
@@ -87,6 +87,14 @@ pub enum EuclidKey {
     CustomerDevicePlatform,
     #[strum(serialize = "transaction_initiator")]
     TransactionInitiator,
+    #[strum(serialize = "time_of_day")]
+    TimeOfDay,
+    #[strum(serialize = "day_of_week")]
+    DayOfWeek,
+    #[strum(serialize = "date")]
+    Date,
+    #[strum(serialize = "base_currency_amount")]
+    BaseCurrencyAmount,
 }
 
 impl EuclidDirFilter for DummyOutput {
@@ -107,6 +115,10 @@ impl EuclidDirFilter for DummyOutput {
         DirKeyKind::PaymentType,
         DirKeyKind::SetupFutureUsage,
         DirKeyKind::TransactionInitiator,
+        DirKeyKind::TimeOfDay,
+        DirKeyKind::DayOfWeek,
+        DirKeyKind::Date,
+        DirKeyKind::BaseCurrencyAmount,
     ];
 }
 impl EuclidAnalysable for DummyOutput {
@@ -179,6 +191,10 @@ impl EuclidKey {
             Self::CustomerDeviceDisplaySize => DataType::EnumVariant,
             Self::CustomerDevicePlatform => DataType::EnumVariant,
             Self::TransactionInitiator => DataType::EnumVariant,
+            Self::TimeOfDay => DataType::Number,
+            Self::DayOfWeek => DataType::EnumVariant,
+            Self::Date => DataType::Number,
+            Self::BaseCurrencyAmount => DataType::Number,
         }
     }
 }
@@ -305,12 +321,19 @@ pub enum EuclidValue {
     CustomerDeviceDisplaySize(CustomerDeviceDisplaySize),
     CustomerDevicePlatform(CustomerDevicePlatform),
     TransactionInitiator(TransactionInitiator),
+    TimeOfDay(NumValue),
+    DayOfWeek(DayOfWeek),
+    Date(NumValue),
+    BaseCurrencyAmount(NumValue),
 }
 
 impl EuclidValue {
     pub fn get_num_value(&self) -> Option<NumValue> {
         match self {
             Self::PaymentAmount(val) => Some(val.clone()),
+            Self::TimeOfDay(val) => Some(val.clone()),
+            Self::Date(val) => Some(val.clone()),
+            Self::BaseCurrencyAmount(val) => Some(val.clone()),
             _ => None,
         }
     }
@@ -344,10 +367,24 @@ impl EuclidValue {
             Self::CustomerDeviceDisplaySize(_) => EuclidKey::CustomerDeviceDisplaySize,
             Self::CustomerDevicePlatform(_) => EuclidKey::CustomerDevicePlatform,
             Self::TransactionInitiator(_) => EuclidKey::TransactionInitiator,
+            Self::TimeOfDay(_) => EuclidKey::TimeOfDay,
+            Self::DayOfWeek(_) => EuclidKey::DayOfWeek,
+            Self::Date(_) => EuclidKey::Date,
+            Self::BaseCurrencyAmount(_) => EuclidKey::BaseCurrencyAmount,
         }
     }
 }
 
+/// Parses an IANA timezone name (eg. `Europe/Berlin`) in which temporal keys are evaluated
+pub fn parse_timezone(timezone: &str) -> Option<chrono_tz::Tz> {
+    timezone.parse::<chrono_tz::Tz>().ok()
+}
+
+/// Lists all the IANA timezone names accepted by [`parse_timezone`]
+pub fn supported_timezones() -> Vec<&'static str> {
+    chrono_tz::TZ_VARIANTS.iter().map(|tz| tz.name()).collect()
+}
+
 #[cfg(test)]
 mod global_type_tests {
     use super::*;
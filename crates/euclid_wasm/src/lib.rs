@@ -4,7 +4,7 @@ mod utils;
 use std::{
     collections::{HashMap, HashSet},
     str::FromStr,
-    sync::OnceLock,
+    sync::{Arc, OnceLock},
 };
 
 use api_models::{
@@ -257,10 +257,34 @@ pub fn analyze_program(js_program: JsValue) -> JsResult {
     Ok(JsValue::NULL)
 }
 
+/// Analyses each rule of the program against the seeded knowledge graph, returning the list of
+/// unreachable rules, rules containing conditions that can never be satisfied, and rules
+/// shadowed by the rules preceding them. An empty list is returned when no rule has any such
+/// finding, while an error is thrown when the program itself is invalid.
+///
+/// Unlike `analyzeProgram`, which throws on the first condition that can never be satisfied, the
+/// unsatisfiable conditions are reported as findings along with the error causing them.
+#[wasm_bindgen(js_name = analyzeProgramRules)]
+pub fn analyze_program_rules(js_program: JsValue) -> JsResult {
+    let program: ast::Program<ConnectorSelection> = serde_wasm_bindgen::from_value(js_program)?;
+    let dir_program = ast::lowering::lower_program(program).err_to_js()?;
+    let findings =
+        analyzer::analyze_rules(&dir_program, SEED_DATA.get().map(|sd| &sd.cgraph)).err_to_js()?;
+    // Ensures that the program can be lowered for execution, as is done by `analyzeProgram`
+    dir::lowering::lower_program(dir_program).err_to_js()?;
+    Ok(serde_wasm_bindgen::to_value(&findings)?)
+}
+
 #[wasm_bindgen(js_name = runProgram)]
 pub fn run_program(program: JsValue, input: JsValue) -> JsResult {
     let program: ast::Program<ConnectorSelection> = serde_wasm_bindgen::from_value(program)?;
-    let input: inputs::BackendInput = serde_wasm_bindgen::from_value(input)?;
+    let mut input: inputs::BackendInput = serde_wasm_bindgen::from_value(input)?;
+
+    // Use the seeded forex data to evaluate `base_currency_amount` when the input doesn't carry
+    // its own exchange rates
+    if input.exchange_rates.is_none() {
+        input.exchange_rates = SEED_FOREX.get().cloned().map(Arc::new);
+    }
 
     let backend = InterpreterBackend::with_program(program).err_to_js()?;
 
@@ -366,6 +390,7 @@ pub fn get_variant_values(key: &str) -> Result<JsValue, JsValue> {
             dir_enums::CustomerDeviceDisplaySize::VARIANTS
         }
         dir::DirKeyKind::NetworkTokenType => dir_enums::NetworkTokenType::VARIANTS,
+        dir::DirKeyKind::DayOfWeek => dir_enums::DayOfWeek::VARIANTS,
 
         dir::DirKeyKind::PaymentAmount
         | dir::DirKeyKind::Connector
@@ -374,12 +399,24 @@ pub fn get_variant_values(key: &str) -> Result<JsValue, JsValue> {
         | dir::DirKeyKind::BusinessLabel
         | dir::DirKeyKind::MetaData
         | dir::DirKeyKind::IssuerName
-        | dir::DirKeyKind::AcquirerFraudRate => Err("Key does not have variants".to_string())?,
+        | dir::DirKeyKind::AcquirerFraudRate
+        | dir::DirKeyKind::TimeOfDay
+        | dir::DirKeyKind::Date
+        | dir::DirKeyKind::BaseCurrencyAmount => Err("Key does not have variants".to_string())?,
     };
 
     Ok(serde_wasm_bindgen::to_value(variants)?)
 }
 
+/// This function can be used by the frontend to list the timezones that a routing program
+/// can evaluate its temporal keys (`time_of_day`, `day_of_week`, `date`) in.
+#[wasm_bindgen(js_name = getTimezones)]
+pub fn get_timezones() -> JsResult {
+    Ok(serde_wasm_bindgen::to_value(
+        &euclid::types::supported_timezones(),
+    )?)
+}
+
 #[wasm_bindgen(js_name = addTwo)]
 pub fn add_two(n1: i64, n2: i64) -> i64 {
     n1 + n2
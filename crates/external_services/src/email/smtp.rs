@@ -187,3 +187,111 @@ pub enum SmtpError {
     #[error("Failed to parse given email {0:?}")]
     EmailParsingFailed(AddressError),
 }
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::expect_used)]
+
+    use std::{
+        io::{BufRead, BufReader, Write},
+        net::TcpListener,
+        thread,
+    };
+
+    use super::*;
+    use crate::email::{EmailContents, EmailData, EmailService};
+
+    /// Accepts a single connection and speaks just enough SMTP to receive one message, returning
+    /// the message data once it has been received.
+    fn spawn_smtp_stand_in(listener: TcpListener) -> thread::JoinHandle<String> {
+        thread::spawn(move || {
+            let (stream, _) = listener.accept().expect("failed to accept connection");
+            let mut writer = stream.try_clone().expect("failed to clone stream");
+            let mut reader = BufReader::new(stream);
+            let mut line = String::new();
+            let mut data = String::new();
+
+            writer
+                .write_all(b"220 localhost ESMTP\r\n")
+                .expect("failed to write greeting");
+            loop {
+                line.clear();
+                if reader.read_line(&mut line).expect("failed to read command") == 0 {
+                    break;
+                }
+
+                if line.to_uppercase().starts_with("DATA") {
+                    writer
+                        .write_all(b"354 End data with <CR><LF>.<CR><LF>\r\n")
+                        .expect("failed to write reply");
+                    loop {
+                        line.clear();
+                        if reader.read_line(&mut line).expect("failed to read data") == 0
+                            || line == ".\r\n"
+                        {
+                            break;
+                        }
+                        data.push_str(&line);
+                    }
+                    writer
+                        .write_all(b"250 OK\r\n")
+                        .expect("failed to write reply");
+                    break;
+                }
+
+                writer
+                    .write_all(b"250 OK\r\n")
+                    .expect("failed to write reply");
+            }
+
+            data
+        })
+    }
+
+    struct TestEmail;
+
+    #[async_trait::async_trait]
+    impl EmailData for TestEmail {
+        async fn get_email_data(&self, base_url: &str) -> CustomResult<EmailContents, EmailError> {
+            Ok(EmailContents {
+                subject: "Your receipt".to_string(),
+                body: IntermediateString::new(format!("<p>Receipt from {base_url}</p>")),
+                recipient: pii::Email::try_from("customer@example.com".to_string())
+                    .expect("invalid recipient email"),
+            })
+        }
+    }
+
+    #[tokio::test]
+    async fn test_send_email_to_smtp_stand_in() {
+        let listener = TcpListener::bind("127.0.0.1:0").expect("failed to bind listener");
+        let port = listener
+            .local_addr()
+            .expect("failed to get listener address")
+            .port();
+        let stand_in = spawn_smtp_stand_in(listener);
+
+        let client = SmtpServer {
+            sender: pii::Email::try_from("merchant@example.com".to_string())
+                .expect("invalid sender email"),
+            smtp_config: SmtpServerConfig {
+                host: "127.0.0.1".to_string(),
+                port,
+                timeout: 10,
+                username: None,
+                password: None,
+                connection: SmtpConnection::Plaintext,
+            },
+        };
+        client
+            .compose_and_send_email("https://example.com", Box::new(TestEmail), None)
+            .await
+            .expect("failed to send email");
+
+        let data = stand_in.join().expect("SMTP stand-in panicked");
+        assert!(data.contains("Subject: Your receipt"));
+        assert!(data.contains("To: customer@example.com"));
+        assert!(data.contains("From: merchant@example.com"));
+        assert!(data.contains("<p>Receipt from https://example.com</p>"));
+    }
+}
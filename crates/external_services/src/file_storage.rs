@@ -59,11 +59,31 @@ pub trait FileStorageInterface: dyn_clone::DynClone + Sync + Send {
         file: Vec<u8>,
     ) -> CustomResult<(), FileStorageError>;
 
+    /// Uploads the file at the given local path to the selected storage scheme, without reading it
+    /// into memory.
+    async fn upload_file_from_path(
+        &self,
+        file_key: &str,
+        path: &std::path::Path,
+    ) -> CustomResult<(), FileStorageError>;
+
     /// Deletes a file from the selected storage scheme.
     async fn delete_file(&self, file_key: &str) -> CustomResult<(), FileStorageError>;
 
     /// Retrieves a file from the selected storage scheme.
     async fn retrieve_file(&self, file_key: &str) -> CustomResult<Vec<u8>, FileStorageError>;
+
+    /// Retrieves the size of a file in bytes from the selected storage scheme.
+    async fn get_file_size(&self, file_key: &str) -> CustomResult<u64, FileStorageError>;
+
+    /// Retrieves `length` bytes of a file starting at `offset` from the selected storage scheme,
+    /// fewer if the file ends before that.
+    async fn retrieve_file_range(
+        &self,
+        file_key: &str,
+        offset: u64,
+        length: u64,
+    ) -> CustomResult<Vec<u8>, FileStorageError>;
 }
 
 dyn_clone::clone_trait_object!(FileStorageInterface);
@@ -1,8 +1,12 @@
 use aws_config::meta::region::RegionProviderChain;
+use std::path::Path;
+
 use aws_sdk_s3::{
     operation::{
-        delete_object::DeleteObjectError, get_object::GetObjectError, put_object::PutObjectError,
+        delete_object::DeleteObjectError, get_object::GetObjectError, head_object::HeadObjectError,
+        put_object::PutObjectError,
     },
+    primitives::ByteStream,
     Client,
 };
 use aws_sdk_sts::config::Region;
@@ -76,6 +80,26 @@ impl AwsFileStorageClient {
         Ok(())
     }
 
+    /// Uploads the file at the given path to AWS S3, streaming it from disk.
+    async fn upload_file_from_path(
+        &self,
+        file_key: &str,
+        path: &Path,
+    ) -> CustomResult<(), AwsS3StorageError> {
+        let body = ByteStream::from_path(path)
+            .await
+            .map_err(AwsS3StorageError::UnknownError)?;
+        self.inner_client
+            .put_object()
+            .bucket(&self.bucket_name)
+            .key(file_key)
+            .body(body)
+            .send()
+            .await
+            .map_err(AwsS3StorageError::UploadFailure)?;
+        Ok(())
+    }
+
     /// Deletes a file from AWS S3.
     async fn delete_file(&self, file_key: &str) -> CustomResult<(), AwsS3StorageError> {
         self.inner_client
@@ -104,6 +128,50 @@ impl AwsFileStorageClient {
             .map_err(AwsS3StorageError::UnknownError)?
             .to_vec())
     }
+
+    /// Retrieves the size of a file from AWS S3.
+    async fn get_file_size(&self, file_key: &str) -> CustomResult<u64, AwsS3StorageError> {
+        let content_length = self
+            .inner_client
+            .head_object()
+            .bucket(&self.bucket_name)
+            .key(file_key)
+            .send()
+            .await
+            .map_err(AwsS3StorageError::HeadFailure)?
+            .content_length
+            .unwrap_or_default();
+        Ok(u64::try_from(content_length).unwrap_or_default())
+    }
+
+    /// Retrieves a part of a file from AWS S3.
+    async fn retrieve_file_range(
+        &self,
+        file_key: &str,
+        offset: u64,
+        length: u64,
+    ) -> CustomResult<Vec<u8>, AwsS3StorageError> {
+        if length == 0 {
+            return Ok(Vec::new());
+        }
+        Ok(self
+            .inner_client
+            .get_object()
+            .bucket(&self.bucket_name)
+            .key(file_key)
+            .range(format!(
+                "bytes={offset}-{}",
+                offset.saturating_add(length - 1)
+            ))
+            .send()
+            .await
+            .map_err(AwsS3StorageError::RetrieveFailure)?
+            .body
+            .collect()
+            .await
+            .map_err(AwsS3StorageError::UnknownError)?
+            .to_vec())
+    }
 }
 
 #[async_trait::async_trait]
@@ -120,6 +188,18 @@ impl FileStorageInterface for AwsFileStorageClient {
         Ok(())
     }
 
+    /// Uploads the file at the given path to AWS S3, streaming it from disk.
+    async fn upload_file_from_path(
+        &self,
+        file_key: &str,
+        path: &Path,
+    ) -> CustomResult<(), FileStorageError> {
+        self.upload_file_from_path(file_key, path)
+            .await
+            .change_context(FileStorageError::UploadFailed)?;
+        Ok(())
+    }
+
     /// Deletes a file from AWS S3.
     async fn delete_file(&self, file_key: &str) -> CustomResult<(), FileStorageError> {
         self.delete_file(file_key)
@@ -135,6 +215,27 @@ impl FileStorageInterface for AwsFileStorageClient {
             .await
             .change_context(FileStorageError::RetrieveFailed)?)
     }
+
+    /// Retrieves the size of a file from AWS S3.
+    async fn get_file_size(&self, file_key: &str) -> CustomResult<u64, FileStorageError> {
+        Ok(self
+            .get_file_size(file_key)
+            .await
+            .change_context(FileStorageError::RetrieveFailed)?)
+    }
+
+    /// Retrieves a part of a file from AWS S3.
+    async fn retrieve_file_range(
+        &self,
+        file_key: &str,
+        offset: u64,
+        length: u64,
+    ) -> CustomResult<Vec<u8>, FileStorageError> {
+        Ok(self
+            .retrieve_file_range(file_key, offset, length)
+            .await
+            .change_context(FileStorageError::RetrieveFailed)?)
+    }
 }
 
 /// Enum representing errors that can occur during AWS S3 file storage operations.
@@ -148,6 +249,10 @@ enum AwsS3StorageError {
     #[error("File retrieve from S3 failed: {0:?}")]
     RetrieveFailure(aws_sdk_s3::error::SdkError<GetObjectError>),
 
+    /// Error indicating that reading the metadata of a file from S3 failed.
+    #[error("File metadata retrieve from S3 failed: {0:?}")]
+    HeadFailure(aws_sdk_s3::error::SdkError<HeadObjectError>),
+
     /// Error indicating that file deletion from S3 failed.
     #[error("File delete from S3 failed: {0:?}")]
     DeleteFailure(aws_sdk_s3::error::SdkError<DeleteObjectError>),
@@ -2,8 +2,8 @@
 
 use std::{
     fs::{remove_file, File},
-    io::{Read, Write},
-    path::PathBuf,
+    io::{Read, Seek, SeekFrom, Write},
+    path::{Path, PathBuf},
 };
 
 use common_utils::errors::CustomResult;
@@ -52,6 +52,26 @@ impl FileSystem {
         Ok(())
     }
 
+    /// Copies the file at the given path to the file system under the specified file key.
+    async fn upload_file_from_path(
+        &self,
+        file_key: &str,
+        path: &Path,
+    ) -> CustomResult<(), FileSystemStorageError> {
+        let file_path = get_file_path(file_key);
+
+        std::fs::create_dir_all(
+            file_path
+                .parent()
+                .ok_or(FileSystemStorageError::CreateDirFailed)
+                .attach_printable("Failed to obtain parent directory")?,
+        )
+        .change_context(FileSystemStorageError::CreateDirFailed)?;
+
+        std::fs::copy(path, file_path).change_context(FileSystemStorageError::WriteFailure)?;
+        Ok(())
+    }
+
     /// Deletes the file associated with the specified file key from the file system.
     async fn delete_file(&self, file_key: &str) -> CustomResult<(), FileSystemStorageError> {
         let file_path = get_file_path(file_key);
@@ -69,6 +89,33 @@ impl FileSystem {
             .change_context(FileSystemStorageError::ReadFailure)?;
         Ok(received_data)
     }
+
+    /// Retrieves the size of the file associated with the specified file key.
+    async fn get_file_size(&self, file_key: &str) -> CustomResult<u64, FileSystemStorageError> {
+        let file_path = get_file_path(file_key);
+        Ok(std::fs::metadata(file_path)
+            .change_context(FileSystemStorageError::FileOpenFailure)?
+            .len())
+    }
+
+    /// Retrieves a part of the file content associated with the specified file key.
+    async fn retrieve_file_range(
+        &self,
+        file_key: &str,
+        offset: u64,
+        length: u64,
+    ) -> CustomResult<Vec<u8>, FileSystemStorageError> {
+        let mut received_data: Vec<u8> = Vec::new();
+        let file_path = get_file_path(file_key);
+        let mut file =
+            File::open(file_path).change_context(FileSystemStorageError::FileOpenFailure)?;
+        file.seek(SeekFrom::Start(offset))
+            .change_context(FileSystemStorageError::ReadFailure)?;
+        file.take(length)
+            .read_to_end(&mut received_data)
+            .change_context(FileSystemStorageError::ReadFailure)?;
+        Ok(received_data)
+    }
 }
 
 #[async_trait::async_trait]
@@ -85,6 +132,18 @@ impl FileStorageInterface for FileSystem {
         Ok(())
     }
 
+    /// Copies the file at the given path to the file system under the specified file key.
+    async fn upload_file_from_path(
+        &self,
+        file_key: &str,
+        path: &Path,
+    ) -> CustomResult<(), FileStorageError> {
+        self.upload_file_from_path(file_key, path)
+            .await
+            .change_context(FileStorageError::UploadFailed)?;
+        Ok(())
+    }
+
     /// Deletes the file associated with the specified file key from the file system.
     async fn delete_file(&self, file_key: &str) -> CustomResult<(), FileStorageError> {
         self.delete_file(file_key)
@@ -100,6 +159,27 @@ impl FileStorageInterface for FileSystem {
             .await
             .change_context(FileStorageError::RetrieveFailed)?)
     }
+
+    /// Retrieves the size of the file associated with the specified file key.
+    async fn get_file_size(&self, file_key: &str) -> CustomResult<u64, FileStorageError> {
+        Ok(self
+            .get_file_size(file_key)
+            .await
+            .change_context(FileStorageError::RetrieveFailed)?)
+    }
+
+    /// Retrieves a part of the file content associated with the specified file key.
+    async fn retrieve_file_range(
+        &self,
+        file_key: &str,
+        offset: u64,
+        length: u64,
+    ) -> CustomResult<Vec<u8>, FileStorageError> {
+        Ok(self
+            .retrieve_file_range(file_key, offset, length)
+            .await
+            .change_context(FileStorageError::RetrieveFailed)?)
+    }
 }
 
 /// Represents an error that can occur during local file system storage operations.
@@ -17,6 +17,7 @@ pub mod hashicorp_vault;
 pub mod http_client;
 /// hubspot_proxy module
 pub mod hubspot_proxy;
+pub mod local_encryption;
 pub mod managers;
 pub mod no_encryption;
 #[cfg(feature = "superposition")]
@@ -63,7 +64,6 @@ pub mod date_time {
 /// Crate specific constants
 pub mod consts {
     /// General purpose base64 engine
-    #[cfg(feature = "aws_kms")]
     pub(crate) const BASE64_ENGINE: base64::engine::GeneralPurpose =
         base64::engine::general_purpose::STANDARD;
 
@@ -0,0 +1,5 @@
+//! Interactions with secrets sealed in a local file
+
+pub mod core;
+
+pub mod implementers;
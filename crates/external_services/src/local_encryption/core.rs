@@ -0,0 +1,280 @@
+//! Envelope encryption with a locally held key and secrets sealed in a local file
+//!
+//! Data is encrypted with a random data key using AES-256-GCM, and the data key is encrypted with
+//! the key encryption key provided through a key file or an environment variable. A sealed value
+//! is the base64 encoding of the encrypted data key followed by the encrypted data.
+//!
+//! The sealed secrets file, as produced by the `seal` subcommand of `config_importer`, holds a
+//! sealed JSON object mapping the dotted paths of the sealed TOML configuration to their values.
+
+use std::{collections::HashMap, path::PathBuf};
+
+use base64::Engine;
+use common_utils::{
+    crypto::{self, DecodeMessage, EncodeMessage},
+    errors::CustomResult,
+    fp_utils::when,
+};
+use error_stack::ResultExt;
+use masking::{PeekInterface, Secret};
+
+use crate::consts;
+
+/// Length of the key encryption key and of the data keys, in bytes
+const KEY_LENGTH: usize = 32;
+
+/// Length of an encrypted data key, made up of the nonce, the data key and the tag
+const ENCRYPTED_DATA_KEY_LENGTH: usize = 12 + KEY_LENGTH + 16;
+
+/// Environment variable the key encryption key is read from if no key file is configured
+const DEFAULT_KEY_ENV: &str = "HYPERSWITCH_SECRETS_KEY";
+
+/// Configuration parameters required for constructing a [`LocalEncryption`] client.
+#[derive(Clone, Debug, Default, serde::Deserialize)]
+#[serde(default)]
+pub struct LocalEncryptionConfig {
+    /// Path of the file holding the hex encoded key encryption key.
+    pub key_file: Option<PathBuf>,
+
+    /// Environment variable holding the hex encoded key encryption key, used if `key_file` is not
+    /// set. Defaults to `HYPERSWITCH_SECRETS_KEY`.
+    pub key_env: Option<String>,
+
+    /// Path of the sealed secrets file, required for secrets management.
+    pub sealed_file: Option<PathBuf>,
+}
+
+impl LocalEncryptionConfig {
+    /// Verifies that the [`LocalEncryption`] configuration is usable for encryption management.
+    pub fn validate(&self) -> Result<(), &'static str> {
+        when(
+            self.key_env
+                .as_ref()
+                .is_some_and(|key_env| key_env.trim().is_empty()),
+            || Err("Local encryption key environment variable must not be empty"),
+        )
+    }
+
+    /// Verifies that the [`LocalEncryption`] configuration is usable for secrets management.
+    pub fn validate_sealed_file(&self) -> Result<(), &'static str> {
+        self.validate()?;
+
+        when(self.sealed_file.is_none(), || {
+            Err("Local encryption sealed file must be configured for secrets management")
+        })
+    }
+}
+
+/// Client for encryption and decryption with a locally held key.
+#[derive(Clone)]
+pub struct LocalEncryption {
+    key: Secret<Vec<u8>>,
+    secrets: HashMap<String, Secret<String>>,
+}
+
+impl std::fmt::Debug for LocalEncryption {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("LocalEncryption")
+            .field("secrets", &self.secrets.keys().collect::<Vec<_>>())
+            .finish_non_exhaustive()
+    }
+}
+
+impl LocalEncryption {
+    /// Constructs a new client, reading the key encryption key and unsealing the sealed secrets
+    /// file if one is configured.
+    pub fn new(config: &LocalEncryptionConfig) -> CustomResult<Self, LocalEncryptionError> {
+        let encoded_key = match &config.key_file {
+            Some(key_file) => std::fs::read_to_string(key_file)
+                .change_context(LocalEncryptionError::KeyNotFound)
+                .attach_printable_lazy(|| format!("Failed to read key file {key_file:?}"))?,
+            None => {
+                let key_env = config.key_env.as_deref().unwrap_or(DEFAULT_KEY_ENV);
+                std::env::var(key_env)
+                    .change_context(LocalEncryptionError::KeyNotFound)
+                    .attach_printable_lazy(|| {
+                        format!("Failed to read environment variable {key_env}")
+                    })?
+            }
+        };
+        let key = hex::decode(encoded_key.trim())
+            .change_context(LocalEncryptionError::InvalidKey)
+            .attach_printable("Failed to hex decode key encryption key")?;
+        when(key.len() != KEY_LENGTH, || {
+            Err(LocalEncryptionError::InvalidKey)
+                .attach_printable("Key encryption key must be 32 bytes long")
+        })?;
+
+        let mut client = Self {
+            key: key.into(),
+            secrets: HashMap::new(),
+        };
+
+        if let Some(sealed_file) = &config.sealed_file {
+            let sealed_secrets = std::fs::read(sealed_file)
+                .change_context(LocalEncryptionError::SealedFileReadFailed)
+                .attach_printable_lazy(|| format!("Failed to read sealed file {sealed_file:?}"))?;
+            let secrets = client.decrypt(sealed_secrets.trim_ascii())?;
+            client.secrets = serde_json::from_slice(&secrets)
+                .change_context(LocalEncryptionError::SealedFileParsingFailed)?;
+        }
+
+        Ok(client)
+    }
+
+    /// Encrypts the data with a new data key, returning the base64 encoded sealed value.
+    pub fn encrypt(&self, data: impl AsRef<[u8]>) -> CustomResult<String, LocalEncryptionError> {
+        let data_key = crypto::generate_cryptographically_secure_random_bytes::<KEY_LENGTH>();
+        let mut sealed_value = crypto::GcmAes256
+            .encode_message(self.key.peek(), &data_key)
+            .change_context(LocalEncryptionError::EncryptionFailed)?;
+        let encrypted_data = crypto::GcmAes256
+            .encode_message(&data_key, data.as_ref())
+            .change_context(LocalEncryptionError::EncryptionFailed)?;
+        sealed_value.extend(encrypted_data);
+
+        Ok(consts::BASE64_ENGINE.encode(sealed_value))
+    }
+
+    /// Decrypts the base64 encoded sealed value.
+    pub fn decrypt(&self, data: impl AsRef<[u8]>) -> CustomResult<Vec<u8>, LocalEncryptionError> {
+        let sealed_value = consts::BASE64_ENGINE
+            .decode(data)
+            .change_context(LocalEncryptionError::Base64DecodingFailed)?;
+        when(sealed_value.len() < ENCRYPTED_DATA_KEY_LENGTH, || {
+            Err(LocalEncryptionError::DecryptionFailed)
+                .attach_printable("Sealed value is shorter than the encrypted data key")
+        })?;
+        let (encrypted_data_key, encrypted_data) = sealed_value.split_at(ENCRYPTED_DATA_KEY_LENGTH);
+
+        let data_key = crypto::GcmAes256
+            .decode_message(self.key.peek(), encrypted_data_key.to_vec().into())
+            .change_context(LocalEncryptionError::DecryptionFailed)
+            .attach_printable("Failed to decrypt data key")?;
+        crypto::GcmAes256
+            .decode_message(&data_key, encrypted_data.to_vec().into())
+            .change_context(LocalEncryptionError::DecryptionFailed)
+    }
+
+    /// Retrieves the secret sealed at the provided path of the sealed secrets file.
+    pub fn get_sealed_secret(
+        &self,
+        path: &str,
+    ) -> CustomResult<Secret<String>, LocalEncryptionError> {
+        self.secrets
+            .get(path)
+            .cloned()
+            .ok_or(LocalEncryptionError::SecretNotFound)
+            .attach_printable_lazy(|| format!("No secret sealed at path {path}"))
+    }
+}
+
+/// Errors that could occur during local encryption operations.
+#[derive(Debug, thiserror::Error)]
+pub enum LocalEncryptionError {
+    /// The key encryption key could not be read.
+    #[error("Failed to read the key encryption key")]
+    KeyNotFound,
+
+    /// The key encryption key is not a hex encoded 32 byte key.
+    #[error("Invalid key encryption key")]
+    InvalidKey,
+
+    /// The sealed secrets file could not be read.
+    #[error("Failed to read the sealed secrets file")]
+    SealedFileReadFailed,
+
+    /// The unsealed secrets file is not a JSON object of strings.
+    #[error("Failed to parse the sealed secrets file")]
+    SealedFileParsingFailed,
+
+    /// An error occurred when base64 decoding input data.
+    #[error("Failed to base64 decode input data")]
+    Base64DecodingFailed,
+
+    /// An error occurred when encrypting input data.
+    #[error("Failed to encrypt input data")]
+    EncryptionFailed,
+
+    /// An error occurred when decrypting input data.
+    #[error("Failed to decrypt input data")]
+    DecryptionFailed,
+
+    /// No secret is sealed at the requested path.
+    #[error("Secret not found in the sealed secrets file")]
+    SecretNotFound,
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::expect_used)]
+
+    use super::*;
+
+    #[test]
+    fn test_local_encryption_round_trip() {
+        let client = LocalEncryption {
+            key: crypto::generate_cryptographically_secure_random_bytes::<KEY_LENGTH>()
+                .to_vec()
+                .into(),
+            secrets: HashMap::new(),
+        };
+
+        let sealed_value = client.encrypt("hello").expect("local encryption failed");
+        let data = client
+            .decrypt(sealed_value)
+            .expect("local decryption failed");
+
+        assert_eq!(data, b"hello");
+    }
+
+    #[test]
+    fn test_sealed_secrets_file_round_trip() {
+        let key = crypto::generate_cryptographically_secure_random_bytes::<KEY_LENGTH>();
+        let sealing_client = LocalEncryption {
+            key: key.to_vec().into(),
+            secrets: HashMap::new(),
+        };
+
+        // The secrets file is sealed the same way as by the `seal` subcommand of `config_importer`
+        let secrets = HashMap::from([
+            ("master_database.password", "database_password"),
+            ("secrets.master_enc_key", "master_enc_key"),
+        ]);
+        let sealed_secrets = sealing_client
+            .encrypt(serde_json::to_vec(&secrets).expect("failed to serialize secrets"))
+            .expect("local encryption failed");
+
+        let directory =
+            std::env::temp_dir().join(format!("local_encryption_test_{}", std::process::id()));
+        std::fs::create_dir_all(&directory).expect("failed to create directory");
+        let key_file = directory.join("secrets.key");
+        let sealed_file = directory.join("secrets.sealed");
+        std::fs::write(&key_file, hex::encode(key)).expect("failed to write key file");
+        std::fs::write(&sealed_file, sealed_secrets).expect("failed to write sealed file");
+
+        let client = LocalEncryption::new(&LocalEncryptionConfig {
+            key_file: Some(key_file),
+            key_env: None,
+            sealed_file: Some(sealed_file),
+        });
+        std::fs::remove_dir_all(&directory).expect("failed to remove directory");
+        let client = client.expect("failed to unseal secrets file");
+
+        assert_eq!(
+            client
+                .get_sealed_secret("master_database.password")
+                .expect("secret not found")
+                .peek(),
+            "database_password"
+        );
+        assert_eq!(
+            client
+                .get_sealed_secret("secrets.master_enc_key")
+                .expect("secret not found")
+                .peek(),
+            "master_enc_key"
+        );
+        assert!(client.get_sealed_secret("secrets.admin_api_key").is_err());
+    }
+}
@@ -3,13 +3,14 @@
 use std::sync::Arc;
 
 use common_utils::errors::CustomResult;
+use error_stack::ResultExt;
 use hyperswitch_interfaces::encryption_interface::{
     EncryptionError, EncryptionManagementInterface,
 };
 
 #[cfg(feature = "aws_kms")]
 use crate::aws_kms;
-use crate::no_encryption::core::NoEncryption;
+use crate::{local_encryption, no_encryption::core::NoEncryption};
 
 /// Enum representing configuration options for encryption management.
 #[derive(Debug, Clone, Default, serde::Deserialize)]
@@ -23,6 +24,12 @@ pub enum EncryptionManagementConfig {
         aws_kms: aws_kms::core::AwsKmsConfig,
     },
 
+    /// Envelope encryption with a locally held key
+    Local {
+        /// Local encryption config
+        local: local_encryption::core::LocalEncryptionConfig,
+    },
+
     /// Variant representing no encryption
     #[default]
     NoEncryption,
@@ -43,6 +50,8 @@ impl EncryptionManagementConfig {
                 })
             }
 
+            Self::Local { local } => local.validate(),
+
             Self::NoEncryption => Ok(()),
         }
     }
@@ -55,6 +64,12 @@ impl EncryptionManagementConfig {
             #[cfg(feature = "aws_kms")]
             Self::AwsKms { aws_kms } => Arc::new(aws_kms::core::AwsKmsClient::new(aws_kms).await),
 
+            Self::Local { local } => Arc::new(
+                local_encryption::core::LocalEncryption::new(local)
+                    .change_context(EncryptionError::EncryptionFailed)
+                    .attach_printable("Failed to create local encryption client")?,
+            ),
+
             Self::NoEncryption => Arc::new(NoEncryption),
         })
     }
@@ -1,7 +1,6 @@
 //! Secrets management util module
 
 use common_utils::errors::CustomResult;
-#[cfg(feature = "hashicorp-vault")]
 use error_stack::ResultExt;
 use hyperswitch_interfaces::secrets_interface::{
     SecretManagementInterface, SecretsManagementError,
@@ -11,7 +10,7 @@ use hyperswitch_interfaces::secrets_interface::{
 use crate::aws_kms;
 #[cfg(feature = "hashicorp-vault")]
 use crate::hashicorp_vault;
-use crate::no_encryption::core::NoEncryption;
+use crate::{local_encryption, no_encryption::core::NoEncryption};
 
 /// Enum representing configuration options for secrets management.
 #[derive(Debug, Clone, Default, serde::Deserialize)]
@@ -32,6 +31,12 @@ pub enum SecretsManagementConfig {
         hc_vault: hashicorp_vault::core::HashiCorpVaultConfig,
     },
 
+    /// Secrets sealed in a local file
+    Local {
+        /// Local encryption config
+        local: local_encryption::core::LocalEncryptionConfig,
+    },
+
     /// Variant representing no encryption
     #[default]
     NoEncryption,
@@ -45,6 +50,7 @@ impl SecretsManagementConfig {
             Self::AwsKms { aws_kms } => aws_kms.validate(),
             #[cfg(feature = "hashicorp-vault")]
             Self::HashiCorpVault { hc_vault } => hc_vault.validate(),
+            Self::Local { local } => local.validate_sealed_file(),
             Self::NoEncryption => Ok(()),
         }
     }
@@ -64,6 +70,9 @@ impl SecretsManagementConfig {
                     .change_context(SecretsManagementError::ClientCreationFailed)
                     .map(|inner| -> Box<dyn SecretManagementInterface> { Box::new(inner) })
             }
+            Self::Local { local } => local_encryption::core::LocalEncryption::new(local)
+                .change_context(SecretsManagementError::ClientCreationFailed)
+                .map(|inner| -> Box<dyn SecretManagementInterface> { Box::new(inner) }),
             Self::NoEncryption => Ok(Box::new(NoEncryption)),
         }
     }
@@ -540,6 +540,7 @@ impl TryFrom<&TrustpayRouterData<&PaymentsAuthorizeRouterData>> for TrustpayPaym
             device_model: None,
             accept_language: Some(browser_info.accept_language.unwrap_or("en".to_string())),
             referer: None,
+            device_fingerprint: None,
         };
         let params = get_mandatory_fields(item.router_data)?;
         let amount = item.amount.to_owned();
@@ -16,8 +16,12 @@ pub enum ApplicationResponse<R> {
     Form(Box<RedirectionFormData>),
     PaymentLinkForm(Box<PaymentLinkAction>),
     FileData((Vec<u8>, mime::Mime)),
+    /// A file sent in chunks as it is read, for files too large to be held in memory
+    FileStream((FileStream, mime::Mime)),
     JsonWithHeaders((R, Vec<(String, masking::Maskable<String>)>)),
     GenericLinkForm(Box<GenericLinks>),
+    /// A json response stored for an earlier request, which is replayed as is
+    ReplayedJson(Box<ReplayedJsonResponse>),
 }
 
 impl<R> ApplicationResponse<R> {
@@ -33,7 +37,9 @@ impl<R> ApplicationResponse<R> {
             | Self::Form(_)
             | Self::PaymentLinkForm(_)
             | Self::FileData(_)
+            | Self::FileStream(_)
             | Self::GenericLinkForm(_)
+            | Self::ReplayedJson(_)
             | Self::StatusOk => Err(common_utils::errors::ValidationError::InvalidValue {
                 message: "expected either Json or JsonWithHeaders Response".to_string(),
             }
@@ -54,6 +60,30 @@ impl<T: ApiEventMetric> ApiEventMetric for ApplicationResponse<T> {
 
 impl_api_event_type!(Miscellaneous, (GenericLinkFormData));
 
+/// The chunks of a file, read as the response is sent
+pub struct FileStream(pub futures::stream::BoxStream<'static, Result<Vec<u8>, std::io::Error>>);
+
+impl std::fmt::Debug for FileStream {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("FileStream")
+    }
+}
+
+/// Streams are consumed as they are read, so no two of them are considered equal
+impl PartialEq for FileStream {
+    fn eq(&self, _other: &Self) -> bool {
+        false
+    }
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct ReplayedJsonResponse {
+    pub status_code: u16,
+    /// The serialized json body of the response
+    pub body: String,
+    pub headers: Vec<(String, String)>,
+}
+
 #[derive(Debug, PartialEq)]
 pub struct RedirectionFormData {
     pub redirect_form: crate::router_response_types::RedirectForm,
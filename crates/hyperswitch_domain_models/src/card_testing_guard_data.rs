@@ -9,4 +9,6 @@ pub struct CardTestingGuardData {
     pub is_customer_id_blocking_enabled: bool,
     pub customer_id_blocking_cache_key: String,
     pub card_testing_guard_expiry: i32,
+    #[serde(default)]
+    pub should_force_3ds_challenge: bool,
 }
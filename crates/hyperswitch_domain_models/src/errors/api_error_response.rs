@@ -85,6 +85,8 @@ pub enum ApiErrorResponse {
     },
     #[error(error_type = ErrorType::DuplicateRequest, code = "HE_01", message = "The config with the specified key already exists in our records")]
     DuplicateConfig,
+    #[error(error_type = ErrorType::DuplicateRequest, code = "HE_01", message = "A request with the same idempotency key is currently being processed")]
+    IdempotencyKeyInUse,
     #[error(error_type = ErrorType::ObjectNotFound, code = "HE_02", message = "Refund does not exist in our records")]
     RefundNotFound,
     #[error(error_type = ErrorType::ObjectNotFound, code = "HE_02", message = "Payment Link does not exist in our records")]
@@ -312,6 +314,14 @@ pub enum ApiErrorResponse {
         message = "Access forbidden, invalid Basic authentication credentials"
     )]
     InvalidBasicAuth,
+    #[error(error_type = ErrorType::InvalidRequestError, code = "IR_52", message = "The idempotency key has already been used for a different request")]
+    IdempotencyKeyReused,
+    #[error(error_type = ErrorType::InvalidRequestError, code = "IR_53", message = "Too many requests, please retry after {retry_after_in_secs} seconds")]
+    RateLimitExceeded {
+        limit: u32,
+        reset_in_secs: u64,
+        retry_after_in_secs: u64,
+    },
     #[error(error_type = ErrorType::InvalidRequestError, code = "WE_01", message = "Failed to authenticate the webhook")]
     WebhookAuthenticationFailed,
     #[error(error_type = ErrorType::InvalidRequestError, code = "WE_02", message = "Bad request received in webhook")]
@@ -430,6 +440,9 @@ impl ErrorSwitch<api_models::errors::types::ApiErrorResponse> for ApiErrorRespon
             Self::DuplicateConfig => {
                 AER::BadRequest(ApiError::new("HE", 1, "The config with the specified key already exists in our records", None))
             }
+            Self::IdempotencyKeyInUse => {
+                AER::Conflict(ApiError::new("HE", 1, "A request with the same idempotency key is currently being processed", None))
+            }
             Self::RefundNotFound => {
                 AER::NotFound(ApiError::new("HE", 2, "Refund does not exist in our records.", None))
             }
@@ -609,6 +622,15 @@ impl ErrorSwitch<api_models::errors::types::ApiErrorResponse> for ApiErrorRespon
             }
             Self::InvalidJwtToken => AER::Unauthorized(ApiError::new("IR", 17, "Access forbidden, invalid JWT token was used", None)),
             Self::InvalidBasicAuth => AER::Unauthorized(ApiError::new("IR", 51, "Access forbidden, invalid Basic authentication credentials", None)),
+            Self::IdempotencyKeyReused => AER::Unprocessable(ApiError::new("IR", 52, "The idempotency key has already been used for a different request", None)),
+            Self::RateLimitExceeded { limit, reset_in_secs, retry_after_in_secs } => AER::TooManyRequests(
+                ApiError::new("IR", 53, format!("Too many requests, please retry after {retry_after_in_secs} seconds"), None),
+                api_models::errors::types::RateLimitInfo {
+                    limit: *limit,
+                    reset_in_secs: *reset_in_secs,
+                    retry_after_in_secs: *retry_after_in_secs,
+                },
+            ),
             Self::GenericUnauthorized { message } => {
                 AER::Unauthorized(ApiError::new("IR", 18, message.to_string(), None))
             },
@@ -18,6 +18,8 @@ pub struct GatewayStatusMap {
     pub standardised_code: Option<common_enums::StandardisedCode>,
     pub description: Option<String>,
     pub user_guidance_message: Option<String>,
+    pub code_match_type: common_enums::GsmMatchType,
+    pub message_match_type: common_enums::GsmMatchType,
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
@@ -35,6 +37,34 @@ pub struct GatewayStatusMappingUpdate {
     pub standardised_code: Option<common_enums::StandardisedCode>,
     pub description: Option<String>,
     pub user_guidance_message: Option<String>,
+    pub code_match_type: Option<common_enums::GsmMatchType>,
+    pub message_match_type: Option<common_enums::GsmMatchType>,
+}
+
+impl From<GatewayStatusMap> for GatewayStatusMappingUpdate {
+    fn from(value: GatewayStatusMap) -> Self {
+        let retry_feature_data = value.feature_data.get_retry_feature_data();
+        Self {
+            status: Some(value.status),
+            router_error: Some(value.router_error),
+            decision: Some(value.feature_data.get_decision()),
+            step_up_possible: retry_feature_data
+                .as_ref()
+                .map(|retry_feature_data| retry_feature_data.is_step_up_possible()),
+            unified_code: value.unified_code,
+            unified_message: value.unified_message,
+            error_category: value.error_category,
+            clear_pan_possible: retry_feature_data
+                .map(|retry_feature_data| retry_feature_data.is_clear_pan_possible()),
+            feature_data: Some(value.feature_data),
+            feature: Some(value.feature),
+            standardised_code: value.standardised_code,
+            description: value.description,
+            user_guidance_message: value.user_guidance_message,
+            code_match_type: Some(value.code_match_type),
+            message_match_type: Some(value.message_match_type),
+        }
+    }
 }
 
 impl TryFrom<GatewayStatusMap> for diesel_models::gsm::GatewayStatusMappingNew {
@@ -68,6 +98,8 @@ impl TryFrom<GatewayStatusMap> for diesel_models::gsm::GatewayStatusMappingNew {
             standardised_code: value.standardised_code,
             description: value.description,
             user_guidance_message: value.user_guidance_message,
+            code_match_type: Some(value.code_match_type),
+            message_match_type: Some(value.message_match_type),
         })
     }
 }
@@ -90,6 +122,8 @@ impl TryFrom<GatewayStatusMappingUpdate> for diesel_models::gsm::GatewayStatusMa
             standardised_code: value.standardised_code,
             description: value.description,
             user_guidance_message: value.user_guidance_message,
+            code_match_type: value.code_match_type,
+            message_match_type: value.message_match_type,
         })
     }
 }
@@ -139,6 +173,8 @@ impl TryFrom<diesel_models::gsm::GatewayStatusMap> for GatewayStatusMap {
             standardised_code: item.standardised_code,
             description: item.description,
             user_guidance_message: item.user_guidance_message,
+            code_match_type: item.code_match_type.unwrap_or_default(),
+            message_match_type: item.message_match_type.unwrap_or_default(),
         })
     }
 }
@@ -17,6 +17,22 @@ pub struct MerchantKeyStore {
     pub key: Encryptable<Secret<Vec<u8>>>,
     #[serde(with = "custom_serde::iso8601")]
     pub created_at: PrimitiveDateTime,
+    /// Incremented every time the merchant data encryption key is rotated
+    pub key_version: i32,
+    /// Data encryption keys replaced by `key`, concatenated newest first
+    pub previous_keys: Option<Encryptable<Secret<Vec<u8>>>>,
+}
+
+impl MerchantKeyStore {
+    /// The current data encryption key followed by the keys it replaced, to decrypt data which
+    /// may not have been re-encrypted since the key was rotated
+    pub fn get_key_ring(&self) -> Secret<Vec<u8>> {
+        let mut key_ring = self.key.get_inner().peek().clone();
+        if let Some(previous_keys) = &self.previous_keys {
+            key_ring.extend_from_slice(previous_keys.get_inner().peek());
+        }
+        Secret::new(key_ring)
+    }
 }
 
 #[async_trait::async_trait]
@@ -28,6 +44,8 @@ impl super::behaviour::Conversion for MerchantKeyStore {
             key: self.key.into(),
             merchant_id: self.merchant_id,
             created_at: self.created_at,
+            key_version: self.key_version,
+            previous_keys: self.previous_keys.map(Into::into),
         })
     }
 
@@ -48,6 +66,25 @@ impl super::behaviour::Conversion for MerchantKeyStore {
             CryptoOperation::DecryptLocally(item.key)
         };
 
+        let previous_keys = match item.previous_keys {
+            // Rotations are only performed when keys are encrypted locally
+            Some(previous_keys) => Some(
+                crypto_operation(
+                    state,
+                    type_name!(Self::DstType),
+                    CryptoOperation::DecryptLocally(previous_keys),
+                    identifier.clone(),
+                    key.peek(),
+                )
+                .await
+                .and_then(|val| val.try_into_operation())
+                .change_context(ValidationError::InvalidValue {
+                    message: "Failed while decrypting previous merchant keys".to_string(),
+                })?,
+            ),
+            None => None,
+        };
+
         Ok(Self {
             key: crypto_operation(
                 state,
@@ -63,6 +100,8 @@ impl super::behaviour::Conversion for MerchantKeyStore {
             })?,
             merchant_id: item.merchant_id,
             created_at: item.created_at,
+            key_version: item.key_version,
+            previous_keys,
         })
     }
 
@@ -71,6 +110,8 @@ impl super::behaviour::Conversion for MerchantKeyStore {
             merchant_id: self.merchant_id,
             key: self.key.into(),
             created_at: date_time::now(),
+            key_version: self.key_version,
+            previous_keys: self.previous_keys.map(Into::into),
         })
     }
 }
@@ -90,6 +131,12 @@ pub trait MerchantKeyStoreInterface {
         key: &Secret<Vec<u8>>,
     ) -> CustomResult<MerchantKeyStore, Self::Error>;
 
+    async fn update_merchant_key_store(
+        &self,
+        merchant_key_store: MerchantKeyStore,
+        key: &Secret<Vec<u8>>,
+    ) -> CustomResult<MerchantKeyStore, Self::Error>;
+
     async fn delete_merchant_key_store_by_merchant_id(
         &self,
         merchant_id: &common_utils::id_type::MerchantId,
@@ -108,4 +155,13 @@ pub trait MerchantKeyStoreInterface {
         from: u32,
         to: u32,
     ) -> CustomResult<Vec<MerchantKeyStore>, Self::Error>;
+
+    /// Lists the key stores whose merchant IDs sort after `cursor`, in merchant ID order, reading
+    /// from the primary database
+    async fn list_key_stores_after_merchant_id(
+        &self,
+        key: &Secret<Vec<u8>>,
+        cursor: String,
+        limit: u32,
+    ) -> CustomResult<Vec<MerchantKeyStore>, Self::Error>;
 }
@@ -27,7 +27,7 @@ use common_utils::{
 #[cfg(feature = "v1")]
 use diesel_models::{
     ConnectorMandateReferenceId, ErrorDetails as DieselErrorDetails, NetworkDetails,
-    PaymentAttemptUpdate as DieselPaymentAttemptUpdate,
+    PaymentAttemptUpdate as DieselPaymentAttemptUpdate, RetryHop,
 };
 use diesel_models::{
     PaymentAttempt as DieselPaymentAttempt, PaymentAttemptNew as DieselPaymentAttemptNew,
@@ -1429,6 +1429,8 @@ pub struct PaymentAttempt {
     pub retry_type: Option<storage_enums::RetryType>,
     /// Installment data selected by the customer (number of installments and billing frequency)
     pub installment_data: Option<common_types::payments::InstallmentData>,
+    /// Auto retry hop which created this payment attempt, used to trace the cascade path
+    pub retry_hop: Option<RetryHop>,
 }
 
 #[cfg(feature = "v1")]
@@ -2914,6 +2916,7 @@ impl behaviour::Conversion for PaymentAttempt {
             authorized_amount: self.authorized_amount,
             encrypted_payment_method_data: self.encrypted_payment_method_data.map(Encryption::from),
             retry_type: self.retry_type,
+            retry_hop: self.retry_hop,
         })
     }
 
@@ -3047,6 +3050,7 @@ impl behaviour::Conversion for PaymentAttempt {
                 error_details: storage_model.error_details.map(Into::into),
                 retry_type: storage_model.retry_type,
                 installment_data: storage_model.installment_data,
+                retry_hop: storage_model.retry_hop,
             })
         }
         .await
@@ -3147,6 +3151,7 @@ impl behaviour::Conversion for PaymentAttempt {
             error_details: self.error_details.map(Into::into),
             retry_type: self.retry_type,
             installment_data: self.installment_data,
+            retry_hop: self.retry_hop,
         })
     }
 }
@@ -3331,6 +3336,7 @@ impl behaviour::Conversion for PaymentAttempt {
             error_details: None,
             retry_type: None,
             installment_data: None,
+            retry_hop: None,
         })
     }
 
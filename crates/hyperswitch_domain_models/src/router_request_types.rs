@@ -1094,6 +1094,7 @@ pub struct BrowserInformation {
     pub device_model: Option<String>,
     pub accept_language: Option<String>,
     pub referer: Option<String>,
+    pub device_fingerprint: Option<String>,
 }
 
 #[cfg(feature = "v2")]
@@ -1115,6 +1116,7 @@ impl From<common_utils::types::BrowserInformation> for BrowserInformation {
             device_model: value.device_model,
             accept_language: value.accept_language,
             referer: value.referer,
+            device_fingerprint: value.device_fingerprint,
         }
     }
 }
@@ -1138,6 +1140,7 @@ impl From<api_models::payments::BrowserInformation> for BrowserInformation {
             device_model: value.device_model,
             accept_language: value.accept_language,
             referer: value.referer,
+            device_fingerprint: value.device_fingerprint,
         }
     }
 }
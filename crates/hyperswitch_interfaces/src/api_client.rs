@@ -33,6 +33,8 @@ use crate::{
     types::Proxy,
 };
 
+pub mod cassette;
+
 /// A trait representing a converter for connector names to their corresponding enum variants.
 pub trait ConnectorConverter: Send + Sync {
     /// Get the connector enum variant by its name
@@ -127,6 +127,9 @@ Never share your secret api keys. Keep them guarded and secure.
         routes::gsm::get_gsm_rule,
         routes::gsm::update_gsm_rule,
         routes::gsm::delete_gsm_rule,
+        routes::gsm::import_gsm_rules,
+        routes::gsm::import_gsm_rules_json,
+        routes::gsm::export_gsm_rules,
 
         // Routes for mandates
         routes::mandates::get_mandate,
@@ -156,6 +159,9 @@ Never share your secret api keys. Keep them guarded and secure.
         routes::profile::profile_retrieve,
         routes::profile::profile_update,
         routes::profile::profile_delete,
+        routes::profile::webhook_signing_key_rotate,
+        routes::profile::webhook_signing_key_list,
+        routes::profile::webhook_signing_key_retire,
 
         // Routes for disputes
         routes::disputes::retrieve_dispute,
@@ -164,6 +170,7 @@ Never share your secret api keys. Keep them guarded and secure.
         // Routes for routing
         routes::routing::routing_create_config,
         routes::routing::routing_link_config,
+        routes::routing::routing_simulate_config,
         routes::routing::routing_retrieve_config,
         routes::routing::list_routing_configs,
         routes::routing::routing_unlink_config,
@@ -188,6 +195,8 @@ Never share your secret api keys. Keep them guarded and secure.
         routes::blocklist::list_blocked_payment_methods,
         routes::blocklist::add_entry_to_blocklist,
         routes::blocklist::toggle_blocklist_guard,
+        routes::blocklist::import_blocklist_entries,
+        routes::blocklist::export_blocklist_entries,
 
         // Routes for payouts
         routes::payouts::payouts_create,
@@ -349,6 +358,9 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::admin::BusinessPayoutLinkConfig,
         api_models::admin::CardTestingGuardConfig,
         api_models::admin::CardTestingGuardStatus,
+        api_models::admin::VelocityRule,
+        api_models::enums::VelocityRuleGroupBy,
+        api_models::enums::VelocityRuleAction,
         api_models::merchant_connector_webhook_management::ConnectorWebhookRegisterRequest,
         api_models::merchant_connector_webhook_management::RegisterConnectorWebhookResponse,
         api_models::merchant_connector_webhook_management::ConnectorWebhookResponse,
@@ -491,8 +503,13 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::gsm::GsmDeleteRequest,
         api_models::gsm::GsmDeleteResponse,
         api_models::gsm::GsmResponse,
+        api_models::gsm::GsmRecord,
+        api_models::gsm::GsmImportStatus,
+        api_models::gsm::GsmImportResponse,
+        api_models::gsm::GsmExportFormat,
         api_models::enums::GsmDecision,
         api_models::enums::GsmFeature,
+        api_models::enums::GsmMatchType,
         api_models::enums::StandardisedCode,
         api_models::enums::UnifiedCode,
         api_models::enums::RecommendedAction,
@@ -795,6 +812,8 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::webhooks::OutgoingWebhookContent,
         api_models::enums::EventClass,
         api_models::enums::EventType,
+        api_models::enums::OutgoingWebhookEndpointStatus,
+        api_models::enums::WebhookSigningKeyStatus,
         api_models::enums::DecoupledAuthenticationType,
         api_models::enums::Tokenization,
         api_models::enums::AuthenticationStatus,
@@ -804,7 +823,13 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::admin::ToggleKVRequest,
         api_models::admin::ToggleKVResponse,
         api_models::admin::WebhookDetails,
+        api_models::admin::MultipleWebhookDetail,
+        api_models::admin::WebhookSigningKeyRotateRequest,
+        api_models::admin::WebhookSigningKeyResponse,
         api_models::api_keys::ApiKeyExpiration,
+        api_models::api_keys::ApiKeyScope,
+        api_models::enums::ApiKeyResource,
+        api_models::enums::ApiKeyAccess,
         api_models::api_keys::CreateApiKeyRequest,
         api_models::api_keys::CreateApiKeyResponse,
         api_models::api_keys::RetrieveApiKeyResponse,
@@ -851,6 +876,11 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::routing::ProgramThreeDsDecisionRule,
         api_models::routing::RuleThreeDsDecisionRule,
         api_models::routing::RoutingVolumeSplitResponse,
+        api_models::routing::RoutingSimulationRequest,
+        api_models::routing::RoutingSimulationResponse,
+        api_models::routing::ConnectorSimulationResult,
+        api_models::routing::CurrencyAmountDelta,
+        api_models::routing::UnmatchedRoutingRule,
         api_models::routing::ast::RoutableChoiceKind,
         api_models::enums::RoutableConnectors,
         api_models::enums::PostCaptureVoidStatus,
@@ -865,9 +895,11 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::payment_methods::RequestPaymentMethodTypes,
         api_models::payments::PaymentLinkStatus,
         api_models::blocklist::BlocklistRequest,
+        api_models::blocklist::AddToBlocklistRequest,
         api_models::blocklist::BlocklistResponse,
         api_models::blocklist::ToggleBlocklistResponse,
         api_models::blocklist::ListBlocklistQuery,
+        api_models::blocklist::ExportBlocklistQuery,
         api_models::enums::BlocklistDataKind,
         api_models::enums::ErrorCategory,
         api_models::webhook_events::EventListConstraints,
@@ -881,6 +913,8 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::enums::StripeChargeType,
         api_models::payments::CustomerDetailsResponse,
         api_models::payments::PaymentErrorDetails,
+        api_models::payments::PaymentRetryHop,
+        api_models::enums::RetryHopReason,
         api_models::payments::ApiUnifiedErrorDetails,
         api_models::payments::ApiIssuerErrorDetails,
         api_models::payments::ApiNetworkErrorDetails,
@@ -394,6 +394,9 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::admin::AdditionalMerchantData,
         api_models::admin::CardTestingGuardConfig,
         api_models::admin::CardTestingGuardStatus,
+        api_models::admin::VelocityRule,
+        api_models::enums::VelocityRuleGroupBy,
+        api_models::enums::VelocityRuleAction,
         api_models::admin::ConnectorWalletDetails,
         api_models::admin::MerchantRecipientData,
         api_models::admin::MerchantAccountData,
@@ -421,8 +424,13 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::gsm::GsmDeleteRequest,
         api_models::gsm::GsmDeleteResponse,
         api_models::gsm::GsmResponse,
+        api_models::gsm::GsmRecord,
+        api_models::gsm::GsmImportStatus,
+        api_models::gsm::GsmImportResponse,
+        api_models::gsm::GsmExportFormat,
         api_models::enums::GsmDecision,
         api_models::enums::GsmFeature,
+        api_models::enums::GsmMatchType,
         api_models::enums::StandardisedCode,
         api_models::enums::UnifiedCode,
         common_types::domain::GsmFeatureData,
@@ -754,6 +762,7 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::webhooks::OutgoingWebhookContent,
         api_models::enums::EventClass,
         api_models::enums::EventType,
+        api_models::enums::OutgoingWebhookEndpointStatus,
         api_models::enums::DecoupledAuthenticationType,
         api_models::enums::Tokenization,
         api_models::enums::AuthenticationStatus,
@@ -764,7 +773,11 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::admin::ToggleKVRequest,
         api_models::admin::ToggleKVResponse,
         api_models::admin::WebhookDetails,
+        api_models::admin::MultipleWebhookDetail,
         api_models::api_keys::ApiKeyExpiration,
+        api_models::api_keys::ApiKeyScope,
+        api_models::enums::ApiKeyResource,
+        api_models::enums::ApiKeyAccess,
         api_models::api_keys::CreateApiKeyRequest,
         api_models::api_keys::CreateApiKeyResponse,
         api_models::api_keys::RetrieveApiKeyResponse,
@@ -825,6 +838,7 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::payment_methods::RequestPaymentMethodTypes,
         api_models::payments::PaymentLinkStatus,
         api_models::blocklist::BlocklistRequest,
+        api_models::blocklist::AddToBlocklistRequest,
         api_models::blocklist::BlocklistResponse,
         api_models::blocklist::ToggleBlocklistResponse,
         api_models::blocklist::ListBlocklistQuery,
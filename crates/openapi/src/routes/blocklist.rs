@@ -17,7 +17,7 @@ pub async fn toggle_blocklist_guard() {}
 #[utoipa::path(
     post,
     path = "/blocklist",
-    request_body = BlocklistRequest,
+    request_body = AddToBlocklistRequest,
     responses(
         (status = 200, description = "Fingerprint Blocked", body = BlocklistResponse),
         (status = 400, description = "Invalid Data")
@@ -57,3 +57,33 @@ pub async fn remove_entry_from_blocklist() {}
     security(("api_key" = []))
 )]
 pub async fn list_blocked_payment_methods() {}
+
+#[utoipa::path(
+    post,
+    path = "/blocklist/import",
+    request_body(content = String, content_type = "multipart/form-data", description = "A CSV file with the `data_kind`, `data` and optional `ttl_in_secs` or `expires_at` columns"),
+    responses(
+        (status = 200, description = "Blocklist entries imported"),
+        (status = 400, description = "Invalid Data")
+    ),
+    tag = "Blocklist",
+    operation_id = "Import blocklist entries from a CSV file",
+    security(("api_key" = []))
+)]
+pub async fn import_blocklist_entries() {}
+
+#[utoipa::path(
+    get,
+    path = "/blocklist/export",
+    params (
+        ("data_kind" = Option<BlocklistDataKind>, Query, description = "Kind of the entries to be exported"),
+    ),
+    responses(
+        (status = 200, description = "Blocklist entries exported as a CSV file", content_type = "text/csv", body = String),
+        (status = 400, description = "Invalid Data")
+    ),
+    tag = "Blocklist",
+    operation_id = "Export blocklist entries as a CSV file",
+    security(("api_key" = []))
+)]
+pub async fn export_blocklist_entries() {}
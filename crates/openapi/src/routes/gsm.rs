@@ -73,3 +73,60 @@ pub async fn update_gsm_rule() {}
     security(("admin_api_key" = [])),
 )]
 pub async fn delete_gsm_rule() {}
+
+/// Gsm - Import
+///
+/// Creates or updates Gsm Rules in bulk from a CSV file, the `code` and `message` of a rule can be matched exactly, by prefix, by a wildcard pattern or by a regex
+#[utoipa::path(
+    post,
+    path = "/gsm/import",
+    request_body(content = String, content_type = "multipart/form-data", description = "A CSV file with a column for each field of a GsmRecord"),
+    responses(
+        (status = 200, description = "Gsm Rules imported", body = Vec<GsmImportResponse>),
+        (status = 400, description = "Invalid Data")
+    ),
+    tag = "Gsm",
+    operation_id = "Import Gsm Rules from a CSV file",
+    security(("admin_api_key" = [])),
+)]
+pub async fn import_gsm_rules() {}
+
+/// Gsm - Import JSON
+///
+/// Creates or updates Gsm Rules in bulk from a JSON array
+#[utoipa::path(
+    post,
+    path = "/gsm/import/json",
+    request_body(
+        content = Vec<GsmRecord>,
+    ),
+    responses(
+        (status = 200, description = "Gsm Rules imported", body = Vec<GsmImportResponse>),
+        (status = 400, description = "Invalid Data")
+    ),
+    tag = "Gsm",
+    operation_id = "Import Gsm Rules from JSON",
+    security(("admin_api_key" = [])),
+)]
+pub async fn import_gsm_rules_json() {}
+
+/// Gsm - Export
+///
+/// Exports Gsm Rules as a CSV file or a JSON array, in a format that can be imported again
+#[utoipa::path(
+    get,
+    path = "/gsm/export",
+    params(
+        ("connector" = Option<String>, Query, description = "Export only the rules of this connector"),
+        ("flow" = Option<String>, Query, description = "Export only the rules of this flow"),
+        ("format" = Option<GsmExportFormat>, Query, description = "The format of the exported rules, defaults to csv"),
+    ),
+    responses(
+        (status = 200, description = "Gsm Rules exported", body = Vec<GsmRecord>),
+        (status = 400, description = "Invalid Data")
+    ),
+    tag = "Gsm",
+    operation_id = "Export Gsm Rules",
+    security(("admin_api_key" = [])),
+)]
+pub async fn export_gsm_rules() {}
@@ -90,6 +90,75 @@ pub async fn profile_update() {}
 )]
 pub async fn profile_retrieve() {}
 
+#[cfg(feature = "v1")]
+/// Profile - Rotate Webhook Signing Key
+///
+/// Create a new key for signing the outgoing webhooks of the *profile*. The previously active key
+/// continues to sign webhooks alongside the new key until the overlap window elapses.
+#[utoipa::path(
+    post,
+    path = "/account/{account_id}/business_profile/{profile_id}/webhook_signing_keys/rotate",
+    params(
+        ("account_id" = String, Path, description = "The unique identifier for the merchant account"),
+        ("profile_id" = String, Path, description = "The unique identifier for the profile")
+    ),
+    request_body = WebhookSigningKeyRotateRequest,
+    responses(
+        (status = 200, description = "Webhook Signing Key Rotated", body = WebhookSigningKeyResponse),
+        (status = 400, description = "Invalid data"),
+        (status = 404, description = "Profile not found")
+    ),
+    tag = "Profile",
+    operation_id = "Rotate the Webhook Signing Key of a Profile",
+    security(("api_key" = []))
+)]
+pub async fn webhook_signing_key_rotate() {}
+
+#[cfg(feature = "v1")]
+/// Profile - List Webhook Signing Keys
+///
+/// List the keys used for signing the outgoing webhooks of the *profile*
+#[utoipa::path(
+    get,
+    path = "/account/{account_id}/business_profile/{profile_id}/webhook_signing_keys",
+    params(
+        ("account_id" = String, Path, description = "The unique identifier for the merchant account"),
+        ("profile_id" = String, Path, description = "The unique identifier for the profile")
+    ),
+    responses(
+        (status = 200, description = "Webhook Signing Keys Retrieved", body = Vec<WebhookSigningKeyResponse>),
+        (status = 404, description = "Profile not found")
+    ),
+    tag = "Profile",
+    operation_id = "List the Webhook Signing Keys of a Profile",
+    security(("api_key" = []))
+)]
+pub async fn webhook_signing_key_list() {}
+
+#[cfg(feature = "v1")]
+/// Profile - Retire Webhook Signing Key
+///
+/// Stop signing the outgoing webhooks of the *profile* with an expiring key before its overlap
+/// window elapses
+#[utoipa::path(
+    post,
+    path = "/account/{account_id}/business_profile/{profile_id}/webhook_signing_keys/{key_id}/retire",
+    params(
+        ("account_id" = String, Path, description = "The unique identifier for the merchant account"),
+        ("profile_id" = String, Path, description = "The unique identifier for the profile"),
+        ("key_id" = String, Path, description = "The unique identifier for the webhook signing key")
+    ),
+    responses(
+        (status = 200, description = "Webhook Signing Key Retired", body = WebhookSigningKeyResponse),
+        (status = 400, description = "The active webhook signing key cannot be retired"),
+        (status = 404, description = "Webhook signing key not found")
+    ),
+    tag = "Profile",
+    operation_id = "Retire a Webhook Signing Key of a Profile",
+    security(("api_key" = []))
+)]
+pub async fn webhook_signing_key_retire() {}
+
 // ******************************************** Common profile routes ******************************************** //
 
 /// Profile - Delete
@@ -64,6 +64,30 @@ pub async fn routing_create_config() {}
 )]
 pub async fn routing_link_config() {}
 
+#[cfg(feature = "v1")]
+/// Routing - Simulate config
+///
+/// Replay the payments created within a time range through a routing config, and compare the
+/// connectors they would have been routed to with those of the active routing config
+#[utoipa::path(
+    post,
+    path = "/routing/{routing_algorithm_id}/simulate",
+    params(
+        ("routing_algorithm_id" = String, Path, description = "The unique identifier for a config"),
+    ),
+    request_body = RoutingSimulationRequest,
+    responses(
+        (status = 200, description = "Routing config simulated", body = RoutingSimulationResponse),
+        (status = 500, description = "Internal server error"),
+        (status = 404, description = "Resource missing"),
+        (status = 400, description = "Bad request")
+    ),
+   tag = "Routing",
+   operation_id = "Simulate a routing config",
+   security(("api_key" = []), ("jwt_key" = []))
+)]
+pub async fn routing_simulate_config() {}
+
 #[cfg(feature = "v1")]
 /// Routing - Retrieve
 ///
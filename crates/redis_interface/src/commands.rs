@@ -18,6 +18,7 @@ use fred::{
     types::{
         Expiration, FromRedis, MultipleIDs, MultipleKeys, MultipleOrderedPairs, MultipleStrings,
         MultipleValues, RedisMap, RedisValue, ScanType, Scanner, SetOptions, XCap, XReadResponse,
+        XReadValue,
     },
 };
 use futures::StreamExt;
@@ -517,6 +518,39 @@ impl super::RedisConnectionPool {
             .await
     }
 
+    /// Sets the fields of a hash, leaving its expiry untouched, so that a hash created by this
+    /// command never expires.
+    #[instrument(level = "DEBUG", skip(self))]
+    pub async fn set_hash_fields_without_modifying_ttl<V>(
+        &self,
+        key: &RedisKey,
+        values: V,
+    ) -> CustomResult<(), errors::RedisError>
+    where
+        V: TryInto<RedisMap> + Debug + Send + Sync,
+        V::Error: Into<fred::error::RedisError> + Send + Sync,
+    {
+        self.pool
+            .hset(key.tenant_aware_key(self), values)
+            .await
+            .change_context(errors::RedisError::SetHashFailed)
+    }
+
+    #[instrument(level = "DEBUG", skip(self))]
+    pub async fn delete_hash_fields<F>(
+        &self,
+        key: &RedisKey,
+        fields: F,
+    ) -> CustomResult<usize, errors::RedisError>
+    where
+        F: Into<MultipleKeys> + Debug + Send,
+    {
+        self.pool
+            .hdel(key.tenant_aware_key(self), fields)
+            .await
+            .change_context(errors::RedisError::DeleteHashFieldFailed)
+    }
+
     #[instrument(level = "DEBUG", skip(self))]
     pub async fn set_hash_field_if_not_exist<V>(
         &self,
@@ -904,6 +938,22 @@ impl super::RedisConnectionPool {
             })
     }
 
+    /// Reads at most `count` entries with IDs between `start` and `end` (both inclusive) from the
+    /// stream, in the order of their IDs.
+    #[instrument(level = "DEBUG", skip(self))]
+    pub async fn stream_read_range(
+        &self,
+        stream: &RedisKey,
+        start: &str,
+        end: &str,
+        count: Option<u64>,
+    ) -> CustomResult<Vec<XReadValue<String, String, String>>, errors::RedisError> {
+        self.pool
+            .xrange_values(stream.tenant_aware_key(self), start, end, count)
+            .await
+            .change_context(errors::RedisError::StreamReadFailed)
+    }
+
     #[instrument(level = "DEBUG", skip(self))]
     pub async fn stream_read_with_options<K, Ids>(
         &self,
@@ -0,0 +1,1587 @@
+//! An in-process stand-in for a Redis server
+//!
+//! Implements the subset of Redis commands used through [`crate::RedisConnectionPool`] on top of
+//! an in-memory keyspace, and is plugged into `fred` as a mock, so that the application can be run
+//! without a Redis server for tests and local demos. All the pools created in the process share the
+//! same keyspace, the same way they would share a Redis server. Lua scripts are not supported.
+
+use std::{
+    collections::{BTreeMap, HashMap, HashSet, VecDeque},
+    sync::{Arc, Mutex, OnceLock, PoisonError},
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use fred::{
+    error::{RedisError, RedisErrorKind},
+    mocks::{MockCommand, Mocks},
+    types::RedisValue,
+};
+
+type Bytes = Vec<u8>;
+
+/// An in-memory keyspace that answers the commands sent by `fred` clients configured with it
+#[derive(Debug, Default)]
+pub struct InMemoryRedis {
+    keyspace: Mutex<HashMap<Bytes, Entry>>,
+}
+
+#[derive(Debug)]
+struct Entry {
+    value: Value,
+    expires_at: Option<SystemTime>,
+}
+
+#[derive(Debug)]
+enum Value {
+    String(Bytes),
+    Hash(HashMap<Bytes, Bytes>),
+    List(VecDeque<Bytes>),
+    Set(HashSet<Bytes>),
+    Stream(Stream),
+}
+
+impl Value {
+    fn type_name(&self) -> &'static str {
+        match self {
+            Self::String(_) => "string",
+            Self::Hash(_) => "hash",
+            Self::List(_) => "list",
+            Self::Set(_) => "set",
+            Self::Stream(_) => "stream",
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+struct Stream {
+    entries: BTreeMap<StreamId, Vec<(Bytes, Bytes)>>,
+    last_id: StreamId,
+    groups: HashMap<Bytes, ConsumerGroup>,
+}
+
+#[derive(Debug, Default)]
+struct ConsumerGroup {
+    last_delivered_id: StreamId,
+    /// The entries delivered to the consumers of the group that are yet to be acknowledged, along
+    /// with the consumer that owns them
+    pending: BTreeMap<StreamId, Bytes>,
+    consumers: HashSet<Bytes>,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
+struct StreamId {
+    milliseconds: u64,
+    sequence_number: u64,
+}
+
+impl StreamId {
+    const MAX: Self = Self {
+        milliseconds: u64::MAX,
+        sequence_number: u64::MAX,
+    };
+
+    /// Parses an entry ID, filling in the sequence number with `default_sequence_number` when it
+    /// is left out
+    fn parse(id: &[u8], default_sequence_number: u64) -> Result<Self, RedisError> {
+        let id = std::str::from_utf8(id).map_err(|_| invalid_stream_id())?;
+        let (milliseconds, sequence_number) = match id.split_once('-') {
+            Some((milliseconds, sequence_number)) => (
+                milliseconds,
+                sequence_number.parse().map_err(|_| invalid_stream_id())?,
+            ),
+            None => (id, default_sequence_number),
+        };
+        Ok(Self {
+            milliseconds: milliseconds.parse().map_err(|_| invalid_stream_id())?,
+            sequence_number,
+        })
+    }
+
+    /// Parses the start of a range, where `-` is the smallest ID and a leading `(` excludes the ID
+    fn parse_range_start(id: &[u8]) -> Result<Self, RedisError> {
+        match id {
+            b"-" => Ok(Self::default()),
+            b"+" => Ok(Self::MAX),
+            [b'(', id @ ..] => Self::parse(id, 0)?
+                .successor()
+                .ok_or_else(invalid_stream_id),
+            id => Self::parse(id, 0),
+        }
+    }
+
+    /// Parses the end of a range, where `+` is the greatest ID and a leading `(` excludes the ID
+    fn parse_range_end(id: &[u8]) -> Result<Self, RedisError> {
+        match id {
+            b"-" => Ok(Self::default()),
+            b"+" => Ok(Self::MAX),
+            [b'(', id @ ..] => Self::parse(id, u64::MAX)?
+                .predecessor()
+                .ok_or_else(invalid_stream_id),
+            id => Self::parse(id, u64::MAX),
+        }
+    }
+
+    fn successor(self) -> Option<Self> {
+        match self.sequence_number.checked_add(1) {
+            Some(sequence_number) => Some(Self {
+                sequence_number,
+                ..self
+            }),
+            None => Some(Self {
+                milliseconds: self.milliseconds.checked_add(1)?,
+                sequence_number: 0,
+            }),
+        }
+    }
+
+    fn predecessor(self) -> Option<Self> {
+        match self.sequence_number.checked_sub(1) {
+            Some(sequence_number) => Some(Self {
+                sequence_number,
+                ..self
+            }),
+            None => Some(Self {
+                milliseconds: self.milliseconds.checked_sub(1)?,
+                sequence_number: u64::MAX,
+            }),
+        }
+    }
+
+    fn to_value(self) -> RedisValue {
+        RedisValue::from(format!("{}-{}", self.milliseconds, self.sequence_number))
+    }
+}
+
+impl InMemoryRedis {
+    /// The keyspace shared by all the clients in the process
+    pub fn shared() -> Arc<Self> {
+        static SHARED: OnceLock<Arc<InMemoryRedis>> = OnceLock::new();
+        Arc::clone(SHARED.get_or_init(Default::default))
+    }
+
+    fn execute(&self, command: MockCommand) -> Result<RedisValue, RedisError> {
+        let mut args = Arguments::new(command.args);
+        let mut name = command.cmd.to_uppercase();
+        if let Some(subcommand) = command.subcommand {
+            name = format!("{name} {}", subcommand.to_uppercase());
+        } else if matches!(name.as_str(), "XGROUP" | "CLIENT" | "CONFIG" | "SCRIPT") {
+            name = format!("{name} {}", args.next_string()?.to_uppercase());
+        }
+
+        let mut keyspace = self.keyspace.lock().unwrap_or_else(PoisonError::into_inner);
+        let mut keyspace = Keyspace::new(&mut keyspace);
+
+        match name.as_str() {
+            "PING" => Ok(RedisValue::from("PONG")),
+            "ECHO" => Ok(bulk(args.next_bytes()?)),
+            "SELECT" | "MULTI" | "DISCARD" | "WATCH" | "UNWATCH" | "QUIT" => Ok(ok()),
+            "EXEC" => Ok(RedisValue::Array(Vec::new())),
+            "INFO" => Ok(RedisValue::from("")),
+            "PUBLISH" | "SPUBLISH" => Ok(RedisValue::Integer(0)),
+            "SUBSCRIBE" | "UNSUBSCRIBE" | "PSUBSCRIBE" | "PUNSUBSCRIBE" | "SSUBSCRIBE"
+            | "SUNSUBSCRIBE" => Ok(RedisValue::Null),
+            name if name.starts_with("CLIENT ") || name.starts_with("CONFIG ") => Ok(ok()),
+            "FLUSHALL" | "FLUSHDB" => {
+                keyspace.entries.clear();
+                Ok(ok())
+            }
+            "EVAL" | "EVALSHA" | "FCALL" | "SCRIPT LOAD" => Err(RedisError::new(
+                RedisErrorKind::InvalidCommand,
+                "Scripts are not supported by the in-memory Redis",
+            )),
+
+            // Keys
+            "DEL" | "UNLINK" => {
+                let count = args
+                    .remaining_bytes()
+                    .filter(|key| keyspace.remove(key).is_some())
+                    .count();
+                Ok(integer(count))
+            }
+            "EXISTS" => {
+                let keys: Vec<_> = args.remaining_bytes().collect();
+                let count = keys
+                    .iter()
+                    .filter(|key| keyspace.get(key).is_some())
+                    .count();
+                Ok(integer(count))
+            }
+            "TYPE" => {
+                let key = args.next_bytes()?;
+                Ok(RedisValue::from(
+                    keyspace
+                        .get(&key)
+                        .map_or("none", |entry| entry.value.type_name()),
+                ))
+            }
+            "EXPIRE" | "PEXPIRE" | "EXPIREAT" | "PEXPIREAT" => {
+                let key = args.next_bytes()?;
+                let amount = args.next_i64()?;
+                let expires_at = match name.as_str() {
+                    "EXPIRE" => offset_from_now(amount, Duration::from_secs),
+                    "PEXPIRE" => offset_from_now(amount, Duration::from_millis),
+                    "EXPIREAT" => offset_from_epoch(amount, Duration::from_secs),
+                    _ => offset_from_epoch(amount, Duration::from_millis),
+                };
+                Ok(match keyspace.get_mut(&key) {
+                    Some(entry) => {
+                        entry.expires_at = Some(expires_at);
+                        RedisValue::Integer(1)
+                    }
+                    None => RedisValue::Integer(0),
+                })
+            }
+            "PERSIST" => {
+                let key = args.next_bytes()?;
+                Ok(match keyspace.get_mut(&key) {
+                    Some(entry) => integer(usize::from(entry.expires_at.take().is_some())),
+                    None => RedisValue::Integer(0),
+                })
+            }
+            "TTL" | "PTTL" => {
+                let key = args.next_bytes()?;
+                Ok(match keyspace.get(&key) {
+                    None => RedisValue::Integer(-2),
+                    Some(Entry {
+                        expires_at: None, ..
+                    }) => RedisValue::Integer(-1),
+                    Some(Entry {
+                        expires_at: Some(expires_at),
+                        ..
+                    }) => {
+                        let remaining = expires_at
+                            .duration_since(SystemTime::now())
+                            .unwrap_or_default();
+                        if name == "TTL" {
+                            RedisValue::Integer(
+                                i64::try_from(remaining.as_secs()).unwrap_or(i64::MAX),
+                            )
+                        } else {
+                            RedisValue::Integer(
+                                i64::try_from(remaining.as_millis()).unwrap_or(i64::MAX),
+                            )
+                        }
+                    }
+                })
+            }
+            "SCAN" => {
+                // Every key is returned in a single iteration
+                let _cursor = args.next_bytes()?;
+                let options = ScanOptions::parse(&mut args)?;
+                let keys = keyspace
+                    .live_keys()
+                    .into_iter()
+                    .filter(|key| {
+                        options.type_name.as_deref().is_none_or(|type_name| {
+                            keyspace.get(key).map(|entry| entry.value.type_name())
+                                == Some(type_name)
+                        })
+                    })
+                    .filter(|key| options.matches(key))
+                    .map(bulk)
+                    .collect();
+                Ok(RedisValue::Array(vec![
+                    RedisValue::from("0"),
+                    RedisValue::Array(keys),
+                ]))
+            }
+
+            // Strings
+            "GET" => {
+                let key = args.next_bytes()?;
+                Ok(keyspace
+                    .string(&key)?
+                    .map_or(RedisValue::Null, |value| bulk(value.clone())))
+            }
+            "GETDEL" => {
+                let key = args.next_bytes()?;
+                let value = keyspace.string(&key)?.cloned();
+                keyspace.remove(&key);
+                Ok(value.map_or(RedisValue::Null, bulk))
+            }
+            "MGET" => {
+                let keys: Vec<_> = args.remaining_bytes().collect();
+                Ok(RedisValue::Array(
+                    keys.iter()
+                        .map(|key| match keyspace.get(key) {
+                            Some(Entry {
+                                value: Value::String(value),
+                                ..
+                            }) => bulk(value.clone()),
+                            _ => RedisValue::Null,
+                        })
+                        .collect(),
+                ))
+            }
+            "SET" => {
+                let key = args.next_bytes()?;
+                let value = args.next_bytes()?;
+                let mut expires_at = None;
+                let mut keep_ttl = false;
+                let mut only_if_absent = false;
+                let mut only_if_present = false;
+                let mut return_previous = false;
+                while let Some(option) = args.next_option() {
+                    match option.as_str() {
+                        "EX" => {
+                            expires_at =
+                                Some(offset_from_now(args.next_i64()?, Duration::from_secs))
+                        }
+                        "PX" => {
+                            expires_at =
+                                Some(offset_from_now(args.next_i64()?, Duration::from_millis))
+                        }
+                        "EXAT" => {
+                            expires_at =
+                                Some(offset_from_epoch(args.next_i64()?, Duration::from_secs))
+                        }
+                        "PXAT" => {
+                            expires_at =
+                                Some(offset_from_epoch(args.next_i64()?, Duration::from_millis))
+                        }
+                        "KEEPTTL" => keep_ttl = true,
+                        "NX" => only_if_absent = true,
+                        "XX" => only_if_present = true,
+                        "GET" => return_previous = true,
+                        option => return Err(syntax_error(option)),
+                    }
+                }
+
+                let previous = keyspace.get(&key);
+                let previous_ttl = previous.and_then(|entry| entry.expires_at);
+                let exists = previous.is_some();
+                let previous_value = if return_previous {
+                    keyspace.string(&key)?.cloned()
+                } else {
+                    None
+                };
+                let is_set = !(only_if_absent && exists || only_if_present && !exists);
+                if is_set {
+                    keyspace.insert(
+                        key,
+                        Value::String(value),
+                        if keep_ttl { previous_ttl } else { expires_at },
+                    );
+                }
+
+                Ok(if return_previous {
+                    previous_value.map_or(RedisValue::Null, bulk)
+                } else if is_set {
+                    ok()
+                } else {
+                    RedisValue::Null
+                })
+            }
+            "SETNX" => {
+                let key = args.next_bytes()?;
+                let value = args.next_bytes()?;
+                if keyspace.get(&key).is_some() {
+                    Ok(RedisValue::Integer(0))
+                } else {
+                    keyspace.insert(key, Value::String(value), None);
+                    Ok(RedisValue::Integer(1))
+                }
+            }
+            "MSET" | "MSETNX" => {
+                let pairs = args.remaining_pairs()?;
+                if name == "MSETNX" && pairs.iter().any(|(key, _)| keyspace.get(key).is_some()) {
+                    return Ok(RedisValue::Integer(0));
+                }
+                for (key, value) in pairs {
+                    keyspace.insert(key, Value::String(value), None);
+                }
+                Ok(if name == "MSETNX" {
+                    RedisValue::Integer(1)
+                } else {
+                    ok()
+                })
+            }
+            "INCR" | "DECR" | "INCRBY" | "DECRBY" => {
+                let key = args.next_bytes()?;
+                let increment = match name.as_str() {
+                    "INCR" => 1,
+                    "DECR" => -1,
+                    "INCRBY" => args.next_i64()?,
+                    _ => args
+                        .next_i64()?
+                        .checked_neg()
+                        .ok_or_else(|| invalid_argument("Increment is out of range"))?,
+                };
+                let current = match keyspace.string(&key)? {
+                    Some(value) => parse_integer(value)?,
+                    None => 0,
+                };
+                let updated = current
+                    .checked_add(increment)
+                    .ok_or_else(|| invalid_argument("Increment would overflow"))?;
+                let expires_at = keyspace.get(&key).and_then(|entry| entry.expires_at);
+                keyspace.insert(
+                    key,
+                    Value::String(updated.to_string().into_bytes()),
+                    expires_at,
+                );
+                Ok(RedisValue::Integer(updated))
+            }
+
+            // Hashes
+            "HSET" | "HMSET" => {
+                let key = args.next_bytes()?;
+                let pairs = args.remaining_pairs()?;
+                let hash = keyspace.hash_or_insert(key)?;
+                let count = pairs
+                    .into_iter()
+                    .filter(|(field, value)| hash.insert(field.clone(), value.clone()).is_none())
+                    .count();
+                Ok(if name == "HSET" { integer(count) } else { ok() })
+            }
+            "HSETNX" => {
+                let key = args.next_bytes()?;
+                let field = args.next_bytes()?;
+                let value = args.next_bytes()?;
+                let hash = keyspace.hash_or_insert(key)?;
+                Ok(if hash.contains_key(&field) {
+                    RedisValue::Integer(0)
+                } else {
+                    hash.insert(field, value);
+                    RedisValue::Integer(1)
+                })
+            }
+            "HGET" => {
+                let key = args.next_bytes()?;
+                let field = args.next_bytes()?;
+                Ok(keyspace
+                    .hash(&key)?
+                    .and_then(|hash| hash.get(&field))
+                    .map_or(RedisValue::Null, |value| bulk(value.clone())))
+            }
+            "HMGET" => {
+                let key = args.next_bytes()?;
+                let hash = keyspace.hash(&key)?;
+                Ok(RedisValue::Array(
+                    args.remaining_bytes()
+                        .map(|field| {
+                            hash.and_then(|hash| hash.get(&field))
+                                .map_or(RedisValue::Null, |value| bulk(value.clone()))
+                        })
+                        .collect(),
+                ))
+            }
+            "HGETALL" => {
+                let key = args.next_bytes()?;
+                Ok(RedisValue::Array(
+                    keyspace
+                        .hash(&key)?
+                        .map(|hash| {
+                            hash.iter()
+                                .flat_map(|(field, value)| {
+                                    [bulk(field.clone()), bulk(value.clone())]
+                                })
+                                .collect()
+                        })
+                        .unwrap_or_default(),
+                ))
+            }
+            "HKEYS" | "HVALS" => {
+                let key = args.next_bytes()?;
+                Ok(RedisValue::Array(
+                    keyspace
+                        .hash(&key)?
+                        .map(|hash| {
+                            hash.iter()
+                                .map(|(field, value)| {
+                                    let element = if name == "HKEYS" { field } else { value };
+                                    bulk(element.clone())
+                                })
+                                .collect()
+                        })
+                        .unwrap_or_default(),
+                ))
+            }
+            "HLEN" => {
+                let key = args.next_bytes()?;
+                Ok(integer(keyspace.hash(&key)?.map_or(0, HashMap::len)))
+            }
+            "HEXISTS" => {
+                let key = args.next_bytes()?;
+                let field = args.next_bytes()?;
+                Ok(integer(usize::from(
+                    keyspace
+                        .hash(&key)?
+                        .is_some_and(|hash| hash.contains_key(&field)),
+                )))
+            }
+            "HDEL" => {
+                let key = args.next_bytes()?;
+                let fields: Vec<_> = args.remaining_bytes().collect();
+                let count = match keyspace.hash_mut(&key)? {
+                    Some(hash) => fields
+                        .iter()
+                        .filter(|field| hash.remove(*field).is_some())
+                        .count(),
+                    None => 0,
+                };
+                keyspace.remove_if_empty(&key);
+                Ok(integer(count))
+            }
+            "HINCRBY" => {
+                let key = args.next_bytes()?;
+                let field = args.next_bytes()?;
+                let increment = args.next_i64()?;
+                let hash = keyspace.hash_or_insert(key)?;
+                let current = match hash.get(&field) {
+                    Some(value) => parse_integer(value)?,
+                    None => 0,
+                };
+                let updated = current
+                    .checked_add(increment)
+                    .ok_or_else(|| invalid_argument("Increment would overflow"))?;
+                hash.insert(field, updated.to_string().into_bytes());
+                Ok(RedisValue::Integer(updated))
+            }
+            "HSCAN" => {
+                // Every field is returned in a single iteration
+                let key = args.next_bytes()?;
+                let _cursor = args.next_bytes()?;
+                let options = ScanOptions::parse(&mut args)?;
+                let fields = keyspace
+                    .hash(&key)?
+                    .map(|hash| {
+                        hash.iter()
+                            .filter(|(field, _)| options.matches(field))
+                            .flat_map(|(field, value)| [bulk(field.clone()), bulk(value.clone())])
+                            .collect()
+                    })
+                    .unwrap_or_default();
+                Ok(RedisValue::Array(vec![
+                    RedisValue::from("0"),
+                    RedisValue::Array(fields),
+                ]))
+            }
+
+            // Lists
+            "RPUSH" | "LPUSH" => {
+                let key = args.next_bytes()?;
+                let elements: Vec<_> = args.remaining_bytes().collect();
+                let list = keyspace.list_or_insert(key)?;
+                for element in elements {
+                    if name == "RPUSH" {
+                        list.push_back(element);
+                    } else {
+                        list.push_front(element);
+                    }
+                }
+                Ok(integer(list.len()))
+            }
+            "LPOP" | "RPOP" => {
+                let key = args.next_bytes()?;
+                let count = args.next_optional_usize()?;
+                let Some(list) = keyspace.list_mut(&key)? else {
+                    return Ok(RedisValue::Null);
+                };
+                let mut popped = Vec::new();
+                for _ in 0..count.unwrap_or(1) {
+                    let element = if name == "LPOP" {
+                        list.pop_front()
+                    } else {
+                        list.pop_back()
+                    };
+                    match element {
+                        Some(element) => popped.push(bulk(element)),
+                        None => break,
+                    }
+                }
+                keyspace.remove_if_empty(&key);
+                Ok(match count {
+                    Some(_) => RedisValue::Array(popped),
+                    None => popped.pop().unwrap_or(RedisValue::Null),
+                })
+            }
+            "LRANGE" => {
+                let key = args.next_bytes()?;
+                let start = args.next_i64()?;
+                let stop = args.next_i64()?;
+                Ok(RedisValue::Array(
+                    keyspace
+                        .list(&key)?
+                        .map(|list| {
+                            let (start, end) = index_range(list.len(), start, stop);
+                            list.iter()
+                                .skip(start)
+                                .take(end.saturating_sub(start))
+                                .cloned()
+                                .map(bulk)
+                                .collect()
+                        })
+                        .unwrap_or_default(),
+                ))
+            }
+            "LLEN" => {
+                let key = args.next_bytes()?;
+                Ok(integer(keyspace.list(&key)?.map_or(0, VecDeque::len)))
+            }
+
+            // Sets
+            "SADD" => {
+                let key = args.next_bytes()?;
+                let members: Vec<_> = args.remaining_bytes().collect();
+                let set = keyspace.set_or_insert(key)?;
+                Ok(integer(
+                    members
+                        .into_iter()
+                        .filter(|member| set.insert(member.clone()))
+                        .count(),
+                ))
+            }
+            "SREM" => {
+                let key = args.next_bytes()?;
+                let members: Vec<_> = args.remaining_bytes().collect();
+                let count = match keyspace.set_mut(&key)? {
+                    Some(set) => members.iter().filter(|member| set.remove(*member)).count(),
+                    None => 0,
+                };
+                keyspace.remove_if_empty(&key);
+                Ok(integer(count))
+            }
+            "SMEMBERS" => {
+                let key = args.next_bytes()?;
+                Ok(RedisValue::Array(
+                    keyspace
+                        .set(&key)?
+                        .map(|set| set.iter().cloned().map(bulk).collect())
+                        .unwrap_or_default(),
+                ))
+            }
+            "SISMEMBER" => {
+                let key = args.next_bytes()?;
+                let member = args.next_bytes()?;
+                Ok(integer(usize::from(
+                    keyspace.set(&key)?.is_some_and(|set| set.contains(&member)),
+                )))
+            }
+            "SCARD" => {
+                let key = args.next_bytes()?;
+                Ok(integer(keyspace.set(&key)?.map_or(0, HashSet::len)))
+            }
+
+            // Streams
+            "XADD" => {
+                let key = args.next_bytes()?;
+                let mut create_stream = true;
+                let mut trim = None;
+                let id = loop {
+                    match args.next_option().as_deref() {
+                        Some("NOMKSTREAM") => create_stream = false,
+                        Some(strategy @ ("MAXLEN" | "MINID")) => {
+                            trim = Some(Trim::parse(strategy, &mut args)?);
+                        }
+                        Some(id) => break id.to_owned(),
+                        None => return Err(syntax_error("XADD")),
+                    }
+                };
+                let fields = args.remaining_pairs()?;
+                if fields.is_empty() {
+                    return Err(syntax_error("XADD"));
+                }
+                if !create_stream && keyspace.stream(&key)?.is_none() {
+                    return Ok(RedisValue::Null);
+                }
+
+                let stream = keyspace.stream_or_insert(key)?;
+                let id = if id == "*" {
+                    let milliseconds = now_in_millis().max(stream.last_id.milliseconds);
+                    if milliseconds == stream.last_id.milliseconds {
+                        stream.last_id.successor().ok_or_else(invalid_stream_id)?
+                    } else {
+                        StreamId {
+                            milliseconds,
+                            sequence_number: 0,
+                        }
+                    }
+                } else {
+                    StreamId::parse(id.as_bytes(), 0)?
+                };
+                if id <= stream.last_id {
+                    return Err(invalid_argument(
+                        "The ID specified in XADD is equal or smaller than the target stream top item",
+                    ));
+                }
+                stream.entries.insert(id, fields);
+                stream.last_id = id;
+                if let Some(trim) = trim {
+                    trim.apply(stream);
+                }
+                Ok(id.to_value())
+            }
+            "XLEN" => {
+                let key = args.next_bytes()?;
+                Ok(integer(
+                    keyspace
+                        .stream(&key)?
+                        .map_or(0, |stream| stream.entries.len()),
+                ))
+            }
+            "XRANGE" | "XREVRANGE" => {
+                let key = args.next_bytes()?;
+                let (start, end) = if name == "XRANGE" {
+                    let start = args.next_bytes()?;
+                    (start, args.next_bytes()?)
+                } else {
+                    let end = args.next_bytes()?;
+                    (args.next_bytes()?, end)
+                };
+                let start = StreamId::parse_range_start(&start)?;
+                let end = StreamId::parse_range_end(&end)?;
+                let count = match args.next_option().as_deref() {
+                    Some("COUNT") => Some(args.next_usize()?),
+                    Some(option) => return Err(syntax_error(option)),
+                    None => None,
+                };
+                let Some(stream) = keyspace.stream(&key)? else {
+                    return Ok(RedisValue::Array(Vec::new()));
+                };
+                if start > end {
+                    return Ok(RedisValue::Array(Vec::new()));
+                }
+                let range = stream.entries.range(start..=end);
+                let entries: Vec<_> = if name == "XRANGE" {
+                    range
+                        .take(count.unwrap_or(usize::MAX))
+                        .map(stream_entry)
+                        .collect()
+                } else {
+                    range
+                        .rev()
+                        .take(count.unwrap_or(usize::MAX))
+                        .map(stream_entry)
+                        .collect()
+                };
+                Ok(RedisValue::Array(entries))
+            }
+            "XDEL" => {
+                let key = args.next_bytes()?;
+                let ids = args
+                    .remaining_bytes()
+                    .map(|id| StreamId::parse(&id, 0))
+                    .collect::<Result<Vec<_>, _>>()?;
+                let count = match keyspace.stream_mut(&key)? {
+                    Some(stream) => ids
+                        .iter()
+                        .filter(|id| stream.entries.remove(id).is_some())
+                        .count(),
+                    None => 0,
+                };
+                Ok(integer(count))
+            }
+            "XTRIM" => {
+                let key = args.next_bytes()?;
+                let strategy = args.next_string()?.to_uppercase();
+                let trim = Trim::parse(&strategy, &mut args)?;
+                Ok(integer(
+                    keyspace
+                        .stream_mut(&key)?
+                        .map_or(0, |stream| trim.apply(stream)),
+                ))
+            }
+            "XREAD" => {
+                let mut count = None;
+                loop {
+                    match args.next_option().as_deref() {
+                        Some("COUNT") => count = Some(args.next_usize()?),
+                        Some("BLOCK") => {
+                            args.next_i64()?;
+                        }
+                        Some("STREAMS") => break,
+                        Some(option) => return Err(syntax_error(option)),
+                        None => return Err(syntax_error("XREAD")),
+                    }
+                }
+                let streams = args.remaining_streams()?;
+                let mut result = Vec::new();
+                for (key, id) in streams {
+                    let Some(stream) = keyspace.stream(&key)? else {
+                        continue;
+                    };
+                    let after = if id == b"$" {
+                        stream.last_id
+                    } else {
+                        StreamId::parse(&id, 0)?
+                    };
+                    let entries: Vec<_> = stream
+                        .entries
+                        .range(after..)
+                        .filter(|(entry_id, _)| **entry_id > after)
+                        .take(count.unwrap_or(usize::MAX))
+                        .map(stream_entry)
+                        .collect();
+                    if !entries.is_empty() {
+                        result.push(RedisValue::Array(vec![
+                            bulk(key),
+                            RedisValue::Array(entries),
+                        ]));
+                    }
+                }
+                Ok(if result.is_empty() {
+                    RedisValue::Null
+                } else {
+                    RedisValue::Array(result)
+                })
+            }
+            "XGROUP CREATE" => {
+                let key = args.next_bytes()?;
+                let group = args.next_bytes()?;
+                let id = args.next_bytes()?;
+                let mut create_stream = false;
+                while let Some(option) = args.next_option() {
+                    match option.as_str() {
+                        "MKSTREAM" => create_stream = true,
+                        "ENTRIESREAD" => {
+                            args.next_i64()?;
+                        }
+                        option => return Err(syntax_error(option)),
+                    }
+                }
+                let stream = if create_stream {
+                    keyspace.stream_or_insert(key)?
+                } else {
+                    keyspace.stream_mut(&key)?.ok_or_else(|| {
+                        invalid_argument(
+                            "The XGROUP subcommand requires the key to exist. Note that for CREATE you may want to use the MKSTREAM option to create an empty stream automatically.",
+                        )
+                    })?
+                };
+                if stream.groups.contains_key(&group) {
+                    return Err(invalid_argument(
+                        "BUSYGROUP Consumer Group name already exists",
+                    ));
+                }
+                let last_delivered_id = if id == b"$" {
+                    stream.last_id
+                } else {
+                    StreamId::parse(&id, 0)?
+                };
+                stream.groups.insert(
+                    group,
+                    ConsumerGroup {
+                        last_delivered_id,
+                        ..Default::default()
+                    },
+                );
+                Ok(ok())
+            }
+            "XGROUP DESTROY" => {
+                let key = args.next_bytes()?;
+                let group = args.next_bytes()?;
+                Ok(integer(usize::from(
+                    keyspace
+                        .stream_mut(&key)?
+                        .is_some_and(|stream| stream.groups.remove(&group).is_some()),
+                )))
+            }
+            "XGROUP CREATECONSUMER" => {
+                let key = args.next_bytes()?;
+                let group = args.next_bytes()?;
+                let consumer = args.next_bytes()?;
+                let group = keyspace
+                    .stream_mut(&key)?
+                    .and_then(|stream| stream.groups.get_mut(&group))
+                    .ok_or_else(no_group)?;
+                Ok(integer(usize::from(group.consumers.insert(consumer))))
+            }
+            "XGROUP DELCONSUMER" => {
+                let key = args.next_bytes()?;
+                let group = args.next_bytes()?;
+                let consumer = args.next_bytes()?;
+                let group = keyspace
+                    .stream_mut(&key)?
+                    .and_then(|stream| stream.groups.get_mut(&group))
+                    .ok_or_else(no_group)?;
+                let pending_count = group.pending.len();
+                group.pending.retain(|_, owner| *owner != consumer);
+                group.consumers.remove(&consumer);
+                Ok(integer(pending_count.saturating_sub(group.pending.len())))
+            }
+            "XGROUP SETID" => {
+                let key = args.next_bytes()?;
+                let group = args.next_bytes()?;
+                let id = args.next_bytes()?;
+                let stream = keyspace.stream_mut(&key)?.ok_or_else(no_group)?;
+                let last_id = stream.last_id;
+                let group = stream.groups.get_mut(&group).ok_or_else(no_group)?;
+                group.last_delivered_id = if id == b"$" {
+                    last_id
+                } else {
+                    StreamId::parse(&id, 0)?
+                };
+                Ok(ok())
+            }
+            "XREADGROUP" => {
+                if args.next_option().as_deref() != Some("GROUP") {
+                    return Err(syntax_error("XREADGROUP"));
+                }
+                let group_name = args.next_bytes()?;
+                let consumer = args.next_bytes()?;
+                let mut count = None;
+                let mut acknowledge = false;
+                loop {
+                    match args.next_option().as_deref() {
+                        Some("COUNT") => count = Some(args.next_usize()?),
+                        Some("BLOCK") => {
+                            args.next_i64()?;
+                        }
+                        Some("NOACK") => acknowledge = true,
+                        Some("STREAMS") => break,
+                        Some(option) => return Err(syntax_error(option)),
+                        None => return Err(syntax_error("XREADGROUP")),
+                    }
+                }
+                let streams = args.remaining_streams()?;
+                let mut result = Vec::new();
+                for (key, id) in streams {
+                    let stream = keyspace.stream_mut(&key)?.ok_or_else(no_group)?;
+                    let group = stream.groups.get_mut(&group_name).ok_or_else(no_group)?;
+                    group.consumers.insert(consumer.clone());
+
+                    let entries: Vec<_> = if id == b">" {
+                        // New entries are delivered to the consumer and become pending
+                        let delivered: Vec<_> = stream
+                            .entries
+                            .range(group.last_delivered_id..)
+                            .filter(|(entry_id, _)| **entry_id > group.last_delivered_id)
+                            .take(count.unwrap_or(usize::MAX))
+                            .collect();
+                        if let Some((last_id, _)) = delivered.last() {
+                            group.last_delivered_id = **last_id;
+                        }
+                        if !acknowledge {
+                            for (entry_id, _) in &delivered {
+                                group.pending.insert(**entry_id, consumer.clone());
+                            }
+                        }
+                        delivered.into_iter().map(stream_entry).collect()
+                    } else {
+                        // Otherwise the entries pending for the consumer are delivered again
+                        let after = StreamId::parse(&id, 0)?;
+                        group
+                            .pending
+                            .range(after..)
+                            .filter(|(entry_id, owner)| **entry_id >= after && **owner == consumer)
+                            .take(count.unwrap_or(usize::MAX))
+                            .map(|(entry_id, _)| match stream.entries.get(entry_id) {
+                                Some(fields) => stream_entry((entry_id, fields)),
+                                None => {
+                                    RedisValue::Array(vec![entry_id.to_value(), RedisValue::Null])
+                                }
+                            })
+                            .collect()
+                    };
+                    if !entries.is_empty() || id != b">" {
+                        result.push(RedisValue::Array(vec![
+                            bulk(key),
+                            RedisValue::Array(entries),
+                        ]));
+                    }
+                }
+                Ok(if result.is_empty() {
+                    RedisValue::Null
+                } else {
+                    RedisValue::Array(result)
+                })
+            }
+            "XACK" => {
+                let key = args.next_bytes()?;
+                let group = args.next_bytes()?;
+                let ids = args
+                    .remaining_bytes()
+                    .map(|id| StreamId::parse(&id, 0))
+                    .collect::<Result<Vec<_>, _>>()?;
+                let count = match keyspace
+                    .stream_mut(&key)?
+                    .and_then(|stream| stream.groups.get_mut(&group))
+                {
+                    Some(group) => ids
+                        .iter()
+                        .filter(|id| group.pending.remove(id).is_some())
+                        .count(),
+                    None => 0,
+                };
+                Ok(integer(count))
+            }
+            "XCLAIM" => {
+                // Idle times are not tracked, so the entries are claimed whatever their idle time
+                let key = args.next_bytes()?;
+                let group_name = args.next_bytes()?;
+                let consumer = args.next_bytes()?;
+                let _min_idle_time = args.next_i64()?;
+                let mut ids = Vec::new();
+                let mut just_id = false;
+                while let Some(argument) = args.next_bytes_optional() {
+                    match String::from_utf8_lossy(&argument).to_uppercase().as_str() {
+                        "IDLE" | "TIME" | "RETRYCOUNT" | "LASTID" => {
+                            args.next_bytes()?;
+                        }
+                        "FORCE" => {}
+                        "JUSTID" => just_id = true,
+                        _ => ids.push(StreamId::parse(&argument, 0)?),
+                    }
+                }
+                let stream = keyspace.stream_mut(&key)?.ok_or_else(no_group)?;
+                let group = stream.groups.get_mut(&group_name).ok_or_else(no_group)?;
+                group.consumers.insert(consumer.clone());
+                let mut claimed = Vec::new();
+                for id in ids {
+                    if let Some(owner) = group.pending.get_mut(&id) {
+                        *owner = consumer.clone();
+                        match stream.entries.get(&id) {
+                            Some(_) if just_id => claimed.push(id.to_value()),
+                            Some(fields) => claimed.push(stream_entry((&id, fields))),
+                            None => {
+                                group.pending.remove(&id);
+                            }
+                        }
+                    }
+                }
+                Ok(RedisValue::Array(claimed))
+            }
+
+            name => Err(RedisError::new(
+                RedisErrorKind::InvalidCommand,
+                format!("Command `{name}` is not supported by the in-memory Redis"),
+            )),
+        }
+    }
+}
+
+impl Mocks for InMemoryRedis {
+    fn process_command(&self, command: MockCommand) -> Result<RedisValue, RedisError> {
+        self.execute(command)
+    }
+}
+
+/// The keyspace while locked, which drops the keys that have expired as they are looked up
+struct Keyspace<'a> {
+    entries: &'a mut HashMap<Bytes, Entry>,
+}
+
+macro_rules! typed_accessors {
+    ($variant:ident, $ty:ty, $get:ident, $get_mut:ident, $get_or_insert:ident) => {
+        fn $get(&mut self, key: &[u8]) -> Result<Option<&$ty>, RedisError> {
+            match self.get(key) {
+                Some(Entry {
+                    value: Value::$variant(value),
+                    ..
+                }) => Ok(Some(value)),
+                Some(_) => Err(wrong_type()),
+                None => Ok(None),
+            }
+        }
+
+        #[allow(dead_code)]
+        fn $get_mut(&mut self, key: &[u8]) -> Result<Option<&mut $ty>, RedisError> {
+            match self.get_mut(key) {
+                Some(Entry {
+                    value: Value::$variant(value),
+                    ..
+                }) => Ok(Some(value)),
+                Some(_) => Err(wrong_type()),
+                None => Ok(None),
+            }
+        }
+
+        #[allow(dead_code)]
+        fn $get_or_insert(&mut self, key: Bytes) -> Result<&mut $ty, RedisError> {
+            if self.get(&key).is_none() {
+                self.insert(key.clone(), Value::$variant(Default::default()), None);
+            }
+            self.$get_mut(&key)?
+                .ok_or_else(|| RedisError::new(RedisErrorKind::Unknown, "Failed to insert key"))
+        }
+    };
+}
+
+impl<'a> Keyspace<'a> {
+    fn new(entries: &'a mut HashMap<Bytes, Entry>) -> Self {
+        Self { entries }
+    }
+
+    fn evict_if_expired(&mut self, key: &[u8]) {
+        let now = SystemTime::now();
+        if self
+            .entries
+            .get(key)
+            .and_then(|entry| entry.expires_at)
+            .is_some_and(|expires_at| expires_at <= now)
+        {
+            self.entries.remove(key);
+        }
+    }
+
+    fn get(&mut self, key: &[u8]) -> Option<&Entry> {
+        self.evict_if_expired(key);
+        self.entries.get(key)
+    }
+
+    fn get_mut(&mut self, key: &[u8]) -> Option<&mut Entry> {
+        self.evict_if_expired(key);
+        self.entries.get_mut(key)
+    }
+
+    fn insert(&mut self, key: Bytes, value: Value, expires_at: Option<SystemTime>) {
+        self.entries.insert(key, Entry { value, expires_at });
+    }
+
+    fn remove(&mut self, key: &[u8]) -> Option<Entry> {
+        self.evict_if_expired(key);
+        self.entries.remove(key)
+    }
+
+    /// Drops the key once its hash, list or set has no elements left, as Redis does
+    fn remove_if_empty(&mut self, key: &[u8]) {
+        let is_empty = self
+            .entries
+            .get(key)
+            .is_some_and(|entry| match &entry.value {
+                Value::Hash(hash) => hash.is_empty(),
+                Value::List(list) => list.is_empty(),
+                Value::Set(set) => set.is_empty(),
+                Value::String(_) | Value::Stream(_) => false,
+            });
+        if is_empty {
+            self.entries.remove(key);
+        }
+    }
+
+    fn live_keys(&mut self) -> Vec<Bytes> {
+        let now = SystemTime::now();
+        self.entries
+            .retain(|_, entry| entry.expires_at.is_none_or(|expires_at| expires_at > now));
+        self.entries.keys().cloned().collect()
+    }
+
+    typed_accessors!(String, Bytes, string, string_mut, string_or_insert);
+    typed_accessors!(Hash, HashMap<Bytes, Bytes>, hash, hash_mut, hash_or_insert);
+    typed_accessors!(List, VecDeque<Bytes>, list, list_mut, list_or_insert);
+    typed_accessors!(Set, HashSet<Bytes>, set, set_mut, set_or_insert);
+    typed_accessors!(Stream, Stream, stream, stream_mut, stream_or_insert);
+}
+
+/// The arguments of a command, consumed from the front
+struct Arguments {
+    inner: std::vec::IntoIter<RedisValue>,
+}
+
+impl Arguments {
+    fn new(args: Vec<RedisValue>) -> Self {
+        Self {
+            inner: args.into_iter(),
+        }
+    }
+
+    fn next_bytes_optional(&mut self) -> Option<Bytes> {
+        self.inner.next().map(|value| value_to_bytes(&value))
+    }
+
+    fn next_bytes(&mut self) -> Result<Bytes, RedisError> {
+        self.next_bytes_optional()
+            .ok_or_else(|| invalid_argument("Wrong number of arguments"))
+    }
+
+    fn next_string(&mut self) -> Result<String, RedisError> {
+        String::from_utf8(self.next_bytes()?)
+            .map_err(|_| invalid_argument("Argument is not valid UTF-8"))
+    }
+
+    /// Returns the next argument in uppercase, to be matched against the options of a command
+    fn next_option(&mut self) -> Option<String> {
+        self.next_bytes_optional()
+            .map(|option| String::from_utf8_lossy(&option).to_uppercase())
+    }
+
+    fn next_i64(&mut self) -> Result<i64, RedisError> {
+        parse_integer(&self.next_bytes()?)
+    }
+
+    fn next_usize(&mut self) -> Result<usize, RedisError> {
+        usize::try_from(self.next_i64()?)
+            .map_err(|_| invalid_argument("Value is out of range, must be positive"))
+    }
+
+    fn next_optional_usize(&mut self) -> Result<Option<usize>, RedisError> {
+        match self.inner.len() {
+            0 => Ok(None),
+            _ => self.next_usize().map(Some),
+        }
+    }
+
+    fn remaining_bytes(&mut self) -> impl Iterator<Item = Bytes> + '_ {
+        self.inner.by_ref().map(|value| value_to_bytes(&value))
+    }
+
+    fn remaining_pairs(&mut self) -> Result<Vec<(Bytes, Bytes)>, RedisError> {
+        let values: Vec<_> = self.remaining_bytes().collect();
+        let mut pairs = Vec::with_capacity(values.len() / 2);
+        let mut values = values.into_iter();
+        while let Some(key) = values.next() {
+            let value = values
+                .next()
+                .ok_or_else(|| invalid_argument("Wrong number of arguments"))?;
+            pairs.push((key, value));
+        }
+        Ok(pairs)
+    }
+
+    /// Returns the keys following `STREAMS` paired with their IDs
+    fn remaining_streams(&mut self) -> Result<Vec<(Bytes, Bytes)>, RedisError> {
+        let values: Vec<_> = self.remaining_bytes().collect();
+        if values.is_empty() || values.len() % 2 != 0 {
+            return Err(invalid_argument(
+                "Unbalanced list of streams: for each stream key an ID must be specified",
+            ));
+        }
+        let (keys, ids) = values.split_at(values.len() / 2);
+        Ok(keys.iter().cloned().zip(ids.iter().cloned()).collect())
+    }
+}
+
+struct ScanOptions {
+    pattern: Option<Bytes>,
+    type_name: Option<String>,
+}
+
+impl ScanOptions {
+    fn parse(args: &mut Arguments) -> Result<Self, RedisError> {
+        let mut options = Self {
+            pattern: None,
+            type_name: None,
+        };
+        while let Some(option) = args.next_option() {
+            match option.as_str() {
+                "MATCH" => options.pattern = Some(args.next_bytes()?),
+                "COUNT" => {
+                    args.next_i64()?;
+                }
+                "TYPE" => options.type_name = Some(args.next_string()?.to_lowercase()),
+                option => return Err(syntax_error(option)),
+            }
+        }
+        Ok(options)
+    }
+
+    fn matches(&self, value: &[u8]) -> bool {
+        self.pattern
+            .as_deref()
+            .is_none_or(|pattern| glob_matches(pattern, value))
+    }
+}
+
+/// How a stream is trimmed, by `XTRIM` or the trimming options of `XADD`
+enum Trim {
+    MaxLength(usize),
+    MinimumId(StreamId),
+}
+
+impl Trim {
+    fn parse(strategy: &str, args: &mut Arguments) -> Result<Self, RedisError> {
+        let mut threshold = args.next_bytes()?;
+        if matches!(threshold.as_slice(), b"=" | b"~") {
+            threshold = args.next_bytes()?;
+        }
+        let trim = match strategy {
+            "MAXLEN" => Self::MaxLength(
+                usize::try_from(parse_integer(&threshold)?)
+                    .map_err(|_| invalid_argument("The MAXLEN argument must be >= 0"))?,
+            ),
+            "MINID" => Self::MinimumId(StreamId::parse(&threshold, 0)?),
+            strategy => return Err(syntax_error(strategy)),
+        };
+        // Trimming is always exact, so the limit on the entries evicted is ignored
+        if args
+            .inner
+            .as_slice()
+            .first()
+            .is_some_and(|argument| value_to_bytes(argument).eq_ignore_ascii_case(b"LIMIT"))
+        {
+            args.next_bytes()?;
+            args.next_bytes()?;
+        }
+        Ok(trim)
+    }
+
+    /// Trims the stream, returning the number of entries evicted
+    fn apply(&self, stream: &mut Stream) -> usize {
+        let length = stream.entries.len();
+        match self {
+            Self::MaxLength(max_length) => {
+                while stream.entries.len() > *max_length {
+                    stream.entries.pop_first();
+                }
+            }
+            Self::MinimumId(minimum_id) => {
+                stream.entries = stream.entries.split_off(minimum_id);
+            }
+        }
+        length.saturating_sub(stream.entries.len())
+    }
+}
+
+fn value_to_bytes(value: &RedisValue) -> Bytes {
+    match value.as_bytes() {
+        Some(bytes) => bytes.to_vec(),
+        None => value
+            .as_str()
+            .map(|value| value.as_bytes().to_vec())
+            .unwrap_or_default(),
+    }
+}
+
+fn bulk(value: Bytes) -> RedisValue {
+    match String::from_utf8(value) {
+        Ok(value) => RedisValue::from(value),
+        Err(error) => RedisValue::Bytes(error.into_bytes().into()),
+    }
+}
+
+fn stream_entry((id, fields): (&StreamId, &Vec<(Bytes, Bytes)>)) -> RedisValue {
+    RedisValue::Array(vec![
+        id.to_value(),
+        RedisValue::Array(
+            fields
+                .iter()
+                .flat_map(|(field, value)| [bulk(field.clone()), bulk(value.clone())])
+                .collect(),
+        ),
+    ])
+}
+
+fn ok() -> RedisValue {
+    RedisValue::from("OK")
+}
+
+fn integer(value: usize) -> RedisValue {
+    RedisValue::Integer(i64::try_from(value).unwrap_or(i64::MAX))
+}
+
+fn parse_integer(value: &[u8]) -> Result<i64, RedisError> {
+    std::str::from_utf8(value)
+        .ok()
+        .and_then(|value| value.parse().ok())
+        .ok_or_else(|| invalid_argument("Value is not an integer or out of range"))
+}
+
+fn now_in_millis() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|duration| u64::try_from(duration.as_millis()).unwrap_or(u64::MAX))
+        .unwrap_or_default()
+}
+
+fn offset_from_now(amount: i64, unit: fn(u64) -> Duration) -> SystemTime {
+    let now = SystemTime::now();
+    match u64::try_from(amount) {
+        Ok(amount) => now.checked_add(unit(amount)).unwrap_or(now),
+        // A key with a TTL in the past expires right away
+        Err(_) => UNIX_EPOCH,
+    }
+}
+
+fn offset_from_epoch(amount: i64, unit: fn(u64) -> Duration) -> SystemTime {
+    UNIX_EPOCH
+        .checked_add(unit(u64::try_from(amount).unwrap_or_default()))
+        .unwrap_or(UNIX_EPOCH)
+}
+
+/// Resolves the inclusive `start` and `stop` indices of `LRANGE`, which can be negative to count
+/// from the end of the list, into a half-open range of positions
+fn index_range(length: usize, start: i64, stop: i64) -> (usize, usize) {
+    let length = i64::try_from(length).unwrap_or(i64::MAX);
+    let resolve = |index: i64| {
+        if index < 0 {
+            length.saturating_add(index).max(0)
+        } else {
+            index
+        }
+    };
+    let start = usize::try_from(resolve(start)).unwrap_or_default();
+    let end = usize::try_from(resolve(stop).saturating_add(1).min(length)).unwrap_or_default();
+    (start, end)
+}
+
+/// Matches a glob-style pattern, supporting `*`, `?` and `\` escapes
+fn glob_matches(pattern: &[u8], value: &[u8]) -> bool {
+    match (pattern.split_first(), value.split_first()) {
+        (None, None) => true,
+        (Some((b'*', rest)), _) => {
+            glob_matches(rest, value)
+                || value
+                    .split_first()
+                    .is_some_and(|(_, value)| glob_matches(pattern, value))
+        }
+        (Some((b'?', rest)), Some((_, value))) => glob_matches(rest, value),
+        (Some((b'\\', [escaped, rest @ ..])), Some((character, value))) => {
+            escaped == character && glob_matches(rest, value)
+        }
+        (Some((expected, rest)), Some((character, value))) => {
+            expected == character && glob_matches(rest, value)
+        }
+        (Some(_), None) | (None, Some(_)) => false,
+    }
+}
+
+fn invalid_argument(message: &'static str) -> RedisError {
+    RedisError::new(RedisErrorKind::InvalidArgument, message)
+}
+
+fn syntax_error(argument: &str) -> RedisError {
+    RedisError::new(
+        RedisErrorKind::InvalidArgument,
+        format!("Syntax error near `{argument}`"),
+    )
+}
+
+fn invalid_stream_id() -> RedisError {
+    invalid_argument("Invalid stream ID specified as stream command argument")
+}
+
+fn wrong_type() -> RedisError {
+    invalid_argument("WRONGTYPE Operation against a key holding the wrong kind of value")
+}
+
+fn no_group() -> RedisError {
+    invalid_argument("NOGROUP No such key or consumer group")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn command(name: &'static str, args: &[&str]) -> MockCommand {
+        MockCommand {
+            cmd: name.into(),
+            subcommand: None,
+            args: args
+                .iter()
+                .map(|arg| RedisValue::from(arg.to_string()))
+                .collect(),
+        }
+    }
+
+    fn run(redis: &InMemoryRedis, name: &'static str, args: &[&str]) -> RedisValue {
+        redis
+            .process_command(command(name, args))
+            .expect("command failed")
+    }
+
+    #[test]
+    fn test_set_if_not_exists() {
+        let redis = InMemoryRedis::default();
+
+        assert_eq!(
+            run(&redis, "SET", &["key", "first", "EX", "30", "NX"]),
+            ok()
+        );
+        assert_eq!(
+            run(&redis, "SET", &["key", "second", "EX", "30", "NX"]),
+            RedisValue::Null
+        );
+        assert_eq!(run(&redis, "GET", &["key"]), RedisValue::from("first"));
+    }
+
+    #[test]
+    fn test_expired_keys_are_not_returned() {
+        let redis = InMemoryRedis::default();
+
+        run(&redis, "SET", &["key", "value"]);
+        run(&redis, "EXPIRE", &["key", "-1"]);
+
+        assert_eq!(run(&redis, "GET", &["key"]), RedisValue::Null);
+        assert_eq!(run(&redis, "TTL", &["key"]), RedisValue::Integer(-2));
+    }
+
+    #[test]
+    fn test_wrong_type() {
+        let redis = InMemoryRedis::default();
+
+        run(&redis, "HSET", &["key", "field", "value"]);
+
+        assert!(redis.process_command(command("GET", &["key"])).is_err());
+    }
+
+    #[test]
+    fn test_consumer_group_delivers_and_acknowledges_entries() {
+        let redis = InMemoryRedis::default();
+
+        run(
+            &redis,
+            "XGROUP",
+            &["CREATE", "stream", "group", "$", "MKSTREAM"],
+        );
+        let id = run(&redis, "XADD", &["stream", "*", "field", "value"]);
+        let id = id.as_string().unwrap();
+
+        let delivered = run(
+            &redis,
+            "XREADGROUP",
+            &[
+                "GROUP", "group", "consumer", "COUNT", "10", "STREAMS", "stream", ">",
+            ],
+        );
+        assert_eq!(
+            delivered,
+            RedisValue::Array(vec![RedisValue::Array(vec![
+                RedisValue::from("stream"),
+                RedisValue::Array(vec![RedisValue::Array(vec![
+                    RedisValue::from(id.clone()),
+                    RedisValue::Array(vec![RedisValue::from("field"), RedisValue::from("value")]),
+                ])]),
+            ])])
+        );
+        assert_eq!(
+            run(
+                &redis,
+                "XREADGROUP",
+                &["GROUP", "group", "consumer", "STREAMS", "stream", ">"],
+            ),
+            RedisValue::Null
+        );
+
+        assert_eq!(
+            run(&redis, "XACK", &["stream", "group", &id]),
+            RedisValue::Integer(1)
+        );
+        assert_eq!(
+            run(&redis, "XACK", &["stream", "group", &id]),
+            RedisValue::Integer(0)
+        );
+    }
+
+    #[test]
+    fn test_stream_range_and_trim() {
+        let redis = InMemoryRedis::default();
+
+        for id in ["1-1", "2-1", "3-1"] {
+            run(&redis, "XADD", &["stream", id, "field", "value"]);
+        }
+
+        let range = run(&redis, "XRANGE", &["stream", "(1-1", "+", "COUNT", "1"]);
+        assert_eq!(
+            range,
+            RedisValue::Array(vec![RedisValue::Array(vec![
+                RedisValue::from("2-1"),
+                RedisValue::Array(vec![RedisValue::from("field"), RedisValue::from("value")]),
+            ])])
+        );
+
+        assert_eq!(
+            run(&redis, "XTRIM", &["stream", "MAXLEN", "1"]),
+            RedisValue::Integer(2)
+        );
+        assert_eq!(run(&redis, "XLEN", &["stream"]), RedisValue::Integer(1));
+    }
+
+    #[test]
+    fn test_glob_matches() {
+        assert!(glob_matches(b"pa_*_ref_*", b"pa_1234_ref_1211"));
+        assert!(glob_matches(b"key?", b"key1"));
+        assert!(!glob_matches(b"key?", b"key"));
+        assert!(!glob_matches(b"pa_*", b"ref_1"));
+    }
+}
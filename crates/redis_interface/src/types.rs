@@ -69,6 +69,9 @@ pub struct RedisSettings {
     pub unresponsive_timeout: u64,
     pub unresponsive_check_interval: u64,
     pub broadcast_channel_capacity: usize,
+    /// Serve the commands from an in-process keyspace instead of connecting to a Redis server,
+    /// requires the `in_memory` feature
+    pub in_memory: bool,
 }
 
 impl RedisSettings {
@@ -88,6 +91,12 @@ impl RedisSettings {
             ))
         })?;
 
+        when(self.in_memory && cfg!(not(feature = "in_memory")), || {
+            Err(errors::RedisError::InvalidConfiguration(
+                "Redis `in_memory` requires the `in_memory` feature to be enabled".into(),
+            ))
+        })?;
+
         when(
             self.default_command_timeout < self.unresponsive_timeout,
             || {
@@ -122,6 +131,7 @@ impl Default for RedisSettings {
             unresponsive_timeout: 10,
             unresponsive_check_interval: 2,
             broadcast_channel_capacity: 32,
+            in_memory: false,
         }
     }
 }
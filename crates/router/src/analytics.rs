@@ -1,5 +1,7 @@
 pub use analytics::*;
 
+pub mod reports;
+
 pub mod routes {
     use std::{
         collections::{HashMap, HashSet},
@@ -9,7 +11,7 @@ pub mod routes {
     use actix_web::{web, Responder, Scope};
     use analytics::{
         api_event::api_events_core, connector_events::connector_events_core, enums::AuthInfo,
-        errors::AnalyticsError, lambda_utils::invoke_lambda, opensearch::OpenSearchError,
+        errors::AnalyticsError, opensearch::OpenSearchError,
         outgoing_webhook_event::outgoing_webhook_events_core, routing_events::routing_events_core,
         sdk_events::sdk_events_core, AnalyticsFlow,
     };
@@ -24,7 +26,8 @@ pub mod routes {
         GetAuthEventMetricRequest, GetDisputeMetricRequest, GetFrmFilterRequest,
         GetFrmMetricRequest, GetPaymentFiltersRequest, GetPaymentIntentFiltersRequest,
         GetPaymentIntentMetricRequest, GetPaymentMetricRequest, GetRefundFilterRequest,
-        GetRefundMetricRequest, GetSdkEventFiltersRequest, GetSdkEventMetricRequest, ReportRequest,
+        GetRefundMetricRequest, GetSdkEventFiltersRequest, GetSdkEventMetricRequest, ReportDomain,
+        ReportRequest,
     };
     use common_enums::EntityType;
     use common_utils::{pii::Email, types::TimeRange};
@@ -33,6 +36,7 @@ pub mod routes {
     use masking::ExposeInterface;
     use router_env::logger;
 
+    use super::reports;
     use crate::{
         analytics_validator::request_validator,
         consts::opensearch::SEARCH_INDEXES,
@@ -56,22 +60,49 @@ pub mod routes {
             web::scope("/v2/analytics")
                 .app_data(web::Data::new(state))
                 .service(
-                    web::scope("/profile").service(
-                        web::resource("report/payments")
-                            .route(web::post().to(generate_profile_payment_report)),
-                    ),
+                    web::scope("/profile")
+                        .service(
+                            web::resource("report/payments")
+                                .route(web::post().to(generate_profile_payment_report)),
+                        )
+                        .service(
+                            web::resource("report/jobs/{job_id}")
+                                .route(web::get().to(get_profile_report_job)),
+                        )
+                        .service(
+                            web::resource("report/jobs/{job_id}/download")
+                                .route(web::get().to(download_profile_report)),
+                        ),
                 )
                 .service(
-                    web::scope("/merchant").service(
-                        web::resource("report/payments")
-                            .route(web::post().to(generate_merchant_payment_report)),
-                    ),
+                    web::scope("/merchant")
+                        .service(
+                            web::resource("report/payments")
+                                .route(web::post().to(generate_merchant_payment_report)),
+                        )
+                        .service(
+                            web::resource("report/jobs/{job_id}")
+                                .route(web::get().to(get_merchant_report_job)),
+                        )
+                        .service(
+                            web::resource("report/jobs/{job_id}/download")
+                                .route(web::get().to(download_merchant_report)),
+                        ),
                 )
                 .service(
-                    web::scope("/org").service(
-                        web::resource("report/payments")
-                            .route(web::post().to(generate_org_payment_report)),
-                    ),
+                    web::scope("/org")
+                        .service(
+                            web::resource("report/payments")
+                                .route(web::post().to(generate_org_payment_report)),
+                        )
+                        .service(
+                            web::resource("report/jobs/{job_id}")
+                                .route(web::get().to(get_org_report_job)),
+                        )
+                        .service(
+                            web::resource("report/jobs/{job_id}/download")
+                                .route(web::get().to(download_org_report)),
+                        ),
                 )
         }
         #[cfg(feature = "v1")]
@@ -128,6 +159,14 @@ pub mod routes {
                             web::resource("report/authentications")
                                 .route(web::post().to(generate_merchant_authentication_report)),
                         )
+                        .service(
+                            web::resource("report/jobs/{job_id}")
+                                .route(web::get().to(get_merchant_report_job)),
+                        )
+                        .service(
+                            web::resource("report/jobs/{job_id}/download")
+                                .route(web::get().to(download_merchant_report)),
+                        )
                         .service(
                             web::resource("metrics/sdk_events")
                                 .route(web::post().to(get_sdk_event_metrics)),
@@ -265,6 +304,14 @@ pub mod routes {
                                         web::post().to(generate_merchant_authentication_report),
                                     ),
                                 )
+                                .service(
+                                    web::resource("report/jobs/{job_id}")
+                                        .route(web::get().to(get_merchant_report_job)),
+                                )
+                                .service(
+                                    web::resource("report/jobs/{job_id}/download")
+                                        .route(web::get().to(download_merchant_report)),
+                                )
                                 .service(
                                     web::resource("metrics/api_events")
                                         .route(web::post().to(get_merchant_api_events_metrics)),
@@ -355,6 +402,14 @@ pub mod routes {
                                     web::resource("report/authentications")
                                         .route(web::post().to(generate_org_authentication_report)),
                                 )
+                                .service(
+                                    web::resource("report/jobs/{job_id}")
+                                        .route(web::get().to(get_org_report_job)),
+                                )
+                                .service(
+                                    web::resource("report/jobs/{job_id}/download")
+                                        .route(web::get().to(download_org_report)),
+                                )
                                 .service(
                                     web::resource("metrics/sankey")
                                         .route(web::post().to(get_org_sankey)),
@@ -442,6 +497,14 @@ pub mod routes {
                                         web::post().to(generate_profile_authentication_report),
                                     ),
                                 )
+                                .service(
+                                    web::resource("report/jobs/{job_id}")
+                                        .route(web::get().to(get_profile_report_job)),
+                                )
+                                .service(
+                                    web::resource("report/jobs/{job_id}/download")
+                                        .route(web::get().to(download_profile_report)),
+                                )
                                 .service(
                                     web::resource("api_event_logs")
                                         .route(web::get().to(get_profile_api_events)),
@@ -1917,7 +1980,7 @@ pub mod routes {
 
                 let org_id = auth.platform.get_processor().get_account().get_org_id();
                 let merchant_id = auth.platform.get_processor().get_account().get_id();
-                let lambda_req = GenerateReportRequest {
+                let report_req = GenerateReportRequest {
                     request: payload,
                     merchant_id: Some(merchant_id.clone()),
                     auth: AuthInfo::MerchantLevel {
@@ -1927,15 +1990,7 @@ pub mod routes {
                     email: user_email,
                 };
 
-                let json_bytes =
-                    serde_json::to_vec(&lambda_req).map_err(|_| AnalyticsError::UnknownError)?;
-                invoke_lambda(
-                    &state.conf.report_download_config.refund_function,
-                    &state.conf.report_download_config.region,
-                    &json_bytes,
-                )
-                .await
-                .map(ApplicationResponse::Json)
+                reports::generate_report(&state, ReportDomain::Refunds, report_req).await
             },
             auth::auth_type(
                 &auth::ApiKeyAuth {
@@ -2009,7 +2064,7 @@ pub mod routes {
                 };
 
                 let org_id = auth.platform.get_processor().get_account().get_org_id();
-                let lambda_req = GenerateReportRequest {
+                let report_req = GenerateReportRequest {
                     request: payload,
                     merchant_id: None,
                     auth: AuthInfo::OrgLevel {
@@ -2018,15 +2073,7 @@ pub mod routes {
                     email: user_email,
                 };
 
-                let json_bytes =
-                    serde_json::to_vec(&lambda_req).map_err(|_| AnalyticsError::UnknownError)?;
-                invoke_lambda(
-                    &state.conf.report_download_config.refund_function,
-                    &state.conf.report_download_config.region,
-                    &json_bytes,
-                )
-                .await
-                .map(ApplicationResponse::Json)
+                reports::generate_report(&state, ReportDomain::Refunds, report_req).await
             },
             auth::auth_type(
                 &auth::ApiKeyAuth {
@@ -2107,7 +2154,7 @@ pub mod routes {
                     .change_context(AnalyticsError::AccessForbiddenError)?
                     .get_id()
                     .clone();
-                let lambda_req = GenerateReportRequest {
+                let report_req = GenerateReportRequest {
                     request: payload,
                     merchant_id: Some(merchant_id.clone()),
                     auth: AuthInfo::ProfileLevel {
@@ -2118,15 +2165,7 @@ pub mod routes {
                     email: user_email,
                 };
 
-                let json_bytes =
-                    serde_json::to_vec(&lambda_req).map_err(|_| AnalyticsError::UnknownError)?;
-                invoke_lambda(
-                    &state.conf.report_download_config.refund_function,
-                    &state.conf.report_download_config.region,
-                    &json_bytes,
-                )
-                .await
-                .map(ApplicationResponse::Json)
+                reports::generate_report(&state, ReportDomain::Refunds, report_req).await
             },
             auth::auth_type(
                 &auth::ApiKeyAuth {
@@ -2200,7 +2239,7 @@ pub mod routes {
 
                 let org_id = auth.platform.get_processor().get_account().get_org_id();
                 let merchant_id = auth.platform.get_processor().get_account().get_id();
-                let lambda_req = GenerateReportRequest {
+                let report_req = GenerateReportRequest {
                     request: payload,
                     merchant_id: Some(merchant_id.clone()),
                     auth: AuthInfo::MerchantLevel {
@@ -2210,15 +2249,7 @@ pub mod routes {
                     email: user_email,
                 };
 
-                let json_bytes =
-                    serde_json::to_vec(&lambda_req).map_err(|_| AnalyticsError::UnknownError)?;
-                invoke_lambda(
-                    &state.conf.report_download_config.dispute_function,
-                    &state.conf.report_download_config.region,
-                    &json_bytes,
-                )
-                .await
-                .map(ApplicationResponse::Json)
+                reports::generate_report(&state, ReportDomain::Disputes, report_req).await
             },
             auth::auth_type(
                 &auth::ApiKeyAuth {
@@ -2291,7 +2322,7 @@ pub mod routes {
                 };
 
                 let org_id = auth.platform.get_processor().get_account().get_org_id();
-                let lambda_req = GenerateReportRequest {
+                let report_req = GenerateReportRequest {
                     request: payload,
                     merchant_id: None,
                     auth: AuthInfo::OrgLevel {
@@ -2300,15 +2331,7 @@ pub mod routes {
                     email: user_email,
                 };
 
-                let json_bytes =
-                    serde_json::to_vec(&lambda_req).map_err(|_| AnalyticsError::UnknownError)?;
-                invoke_lambda(
-                    &state.conf.report_download_config.dispute_function,
-                    &state.conf.report_download_config.region,
-                    &json_bytes,
-                )
-                .await
-                .map(ApplicationResponse::Json)
+                reports::generate_report(&state, ReportDomain::Disputes, report_req).await
             },
             auth::auth_type(
                 &auth::ApiKeyAuth {
@@ -2389,7 +2412,7 @@ pub mod routes {
                     .change_context(AnalyticsError::AccessForbiddenError)?
                     .get_id()
                     .clone();
-                let lambda_req = GenerateReportRequest {
+                let report_req = GenerateReportRequest {
                     request: payload,
                     merchant_id: Some(merchant_id.clone()),
                     auth: AuthInfo::ProfileLevel {
@@ -2400,15 +2423,7 @@ pub mod routes {
                     email: user_email,
                 };
 
-                let json_bytes =
-                    serde_json::to_vec(&lambda_req).map_err(|_| AnalyticsError::UnknownError)?;
-                invoke_lambda(
-                    &state.conf.report_download_config.dispute_function,
-                    &state.conf.report_download_config.region,
-                    &json_bytes,
-                )
-                .await
-                .map(ApplicationResponse::Json)
+                reports::generate_report(&state, ReportDomain::Disputes, report_req).await
             },
             auth::auth_type(
                 &auth::ApiKeyAuth {
@@ -2483,7 +2498,7 @@ pub mod routes {
 
                 let org_id = auth.platform.get_processor().get_account().get_org_id();
                 let merchant_id = auth.platform.get_processor().get_account().get_id();
-                let lambda_req = GenerateReportRequest {
+                let report_req = GenerateReportRequest {
                     request: payload,
                     merchant_id: Some(merchant_id.clone()),
                     auth: AuthInfo::MerchantLevel {
@@ -2493,15 +2508,7 @@ pub mod routes {
                     email: user_email,
                 };
 
-                let json_bytes =
-                    serde_json::to_vec(&lambda_req).map_err(|_| AnalyticsError::UnknownError)?;
-                invoke_lambda(
-                    &state.conf.report_download_config.payout_function,
-                    &state.conf.report_download_config.region,
-                    &json_bytes,
-                )
-                .await
-                .map(ApplicationResponse::Json)
+                reports::generate_report(&state, ReportDomain::Payouts, report_req).await
             },
             auth::auth_type(
                 &auth::ApiKeyAuth {
@@ -2575,7 +2582,7 @@ pub mod routes {
                 };
 
                 let org_id = auth.platform.get_processor().get_account().get_org_id();
-                let lambda_req = GenerateReportRequest {
+                let report_req = GenerateReportRequest {
                     request: payload,
                     merchant_id: None,
                     auth: AuthInfo::OrgLevel {
@@ -2584,15 +2591,7 @@ pub mod routes {
                     email: user_email,
                 };
 
-                let json_bytes =
-                    serde_json::to_vec(&lambda_req).map_err(|_| AnalyticsError::UnknownError)?;
-                invoke_lambda(
-                    &state.conf.report_download_config.payout_function,
-                    &state.conf.report_download_config.region,
-                    &json_bytes,
-                )
-                .await
-                .map(ApplicationResponse::Json)
+                reports::generate_report(&state, ReportDomain::Payouts, report_req).await
             },
             auth::auth_type(
                 &auth::ApiKeyAuth {
@@ -2672,7 +2671,7 @@ pub mod routes {
                     .change_context(AnalyticsError::AccessForbiddenError)?
                     .get_id()
                     .clone();
-                let lambda_req = GenerateReportRequest {
+                let report_req = GenerateReportRequest {
                     request: payload,
                     merchant_id: Some(merchant_id.clone()),
                     auth: AuthInfo::ProfileLevel {
@@ -2683,15 +2682,7 @@ pub mod routes {
                     email: user_email,
                 };
 
-                let json_bytes =
-                    serde_json::to_vec(&lambda_req).map_err(|_| AnalyticsError::UnknownError)?;
-                invoke_lambda(
-                    &state.conf.report_download_config.payout_function,
-                    &state.conf.report_download_config.region,
-                    &json_bytes,
-                )
-                .await
-                .map(ApplicationResponse::Json)
+                reports::generate_report(&state, ReportDomain::Payouts, report_req).await
             },
             auth::auth_type(
                 &auth::ApiKeyAuth {
@@ -2765,7 +2756,7 @@ pub mod routes {
 
                 let org_id = auth.platform.get_processor().get_account().get_org_id();
                 let merchant_id = auth.platform.get_processor().get_account().get_id();
-                let lambda_req = GenerateReportRequest {
+                let report_req = GenerateReportRequest {
                     request: payload,
                     merchant_id: Some(merchant_id.clone()),
                     auth: AuthInfo::MerchantLevel {
@@ -2775,15 +2766,7 @@ pub mod routes {
                     email: user_email,
                 };
 
-                let json_bytes =
-                    serde_json::to_vec(&lambda_req).map_err(|_| AnalyticsError::UnknownError)?;
-                invoke_lambda(
-                    &state.conf.report_download_config.payment_function,
-                    &state.conf.report_download_config.region,
-                    &json_bytes,
-                )
-                .await
-                .map(ApplicationResponse::Json)
+                reports::generate_report(&state, ReportDomain::Payments, report_req).await
             },
             auth::auth_type::<auth::AuthenticationDataWithUserId, _>(
                 &auth::ApiKeyAuth {
@@ -2856,7 +2839,7 @@ pub mod routes {
                 };
 
                 let org_id = auth.platform.get_processor().get_account().get_org_id();
-                let lambda_req = GenerateReportRequest {
+                let report_req = GenerateReportRequest {
                     request: payload,
                     merchant_id: None,
                     auth: AuthInfo::OrgLevel {
@@ -2865,15 +2848,7 @@ pub mod routes {
                     email: user_email,
                 };
 
-                let json_bytes =
-                    serde_json::to_vec(&lambda_req).map_err(|_| AnalyticsError::UnknownError)?;
-                invoke_lambda(
-                    &state.conf.report_download_config.payment_function,
-                    &state.conf.report_download_config.region,
-                    &json_bytes,
-                )
-                .await
-                .map(ApplicationResponse::Json)
+                reports::generate_report(&state, ReportDomain::Payments, report_req).await
             },
             auth::auth_type::<auth::AuthenticationDataWithUserId, _>(
                 &auth::ApiKeyAuth {
@@ -2960,7 +2935,7 @@ pub mod routes {
                         auth.profile.get_id().clone()
                     }
                 };
-                let lambda_req = GenerateReportRequest {
+                let report_req = GenerateReportRequest {
                     request: payload,
                     merchant_id: Some(merchant_id.clone()),
                     auth: AuthInfo::ProfileLevel {
@@ -2971,15 +2946,7 @@ pub mod routes {
                     email: user_email,
                 };
 
-                let json_bytes =
-                    serde_json::to_vec(&lambda_req).map_err(|_| AnalyticsError::UnknownError)?;
-                invoke_lambda(
-                    &state.conf.report_download_config.payment_function,
-                    &state.conf.report_download_config.region,
-                    &json_bytes,
-                )
-                .await
-                .map(ApplicationResponse::Json)
+                reports::generate_report(&state, ReportDomain::Payments, report_req).await
             },
             auth::auth_type::<auth::AuthenticationDataWithUserId, _>(
                 &auth::ApiKeyAuth {
@@ -3054,7 +3021,7 @@ pub mod routes {
 
                 let org_id = auth.platform.get_processor().get_account().get_org_id();
                 let merchant_id = auth.platform.get_processor().get_account().get_id();
-                let lambda_req = GenerateReportRequest {
+                let report_req = GenerateReportRequest {
                     request: payload,
                     merchant_id: Some(merchant_id.clone()),
                     auth: AuthInfo::MerchantLevel {
@@ -3064,15 +3031,7 @@ pub mod routes {
                     email: user_email,
                 };
 
-                let json_bytes =
-                    serde_json::to_vec(&lambda_req).map_err(|_| AnalyticsError::UnknownError)?;
-                invoke_lambda(
-                    &state.conf.report_download_config.authentication_function,
-                    &state.conf.report_download_config.region,
-                    &json_bytes,
-                )
-                .await
-                .map(ApplicationResponse::Json)
+                reports::generate_report(&state, ReportDomain::Authentications, report_req).await
             },
             auth::auth_type(
                 &auth::ApiKeyAuth {
@@ -3146,7 +3105,7 @@ pub mod routes {
                 };
 
                 let org_id = auth.platform.get_processor().get_account().get_org_id();
-                let lambda_req = GenerateReportRequest {
+                let report_req = GenerateReportRequest {
                     request: payload,
                     merchant_id: None,
                     auth: AuthInfo::OrgLevel {
@@ -3155,15 +3114,7 @@ pub mod routes {
                     email: user_email,
                 };
 
-                let json_bytes =
-                    serde_json::to_vec(&lambda_req).map_err(|_| AnalyticsError::UnknownError)?;
-                invoke_lambda(
-                    &state.conf.report_download_config.authentication_function,
-                    &state.conf.report_download_config.region,
-                    &json_bytes,
-                )
-                .await
-                .map(ApplicationResponse::Json)
+                reports::generate_report(&state, ReportDomain::Authentications, report_req).await
             },
             auth::auth_type(
                 &auth::ApiKeyAuth {
@@ -3243,7 +3194,7 @@ pub mod routes {
                     .change_context(AnalyticsError::AccessForbiddenError)?
                     .get_id()
                     .clone();
-                let lambda_req = GenerateReportRequest {
+                let report_req = GenerateReportRequest {
                     request: payload,
                     merchant_id: Some(merchant_id.clone()),
                     auth: AuthInfo::ProfileLevel {
@@ -3254,15 +3205,7 @@ pub mod routes {
                     email: user_email,
                 };
 
-                let json_bytes =
-                    serde_json::to_vec(&lambda_req).map_err(|_| AnalyticsError::UnknownError)?;
-                invoke_lambda(
-                    &state.conf.report_download_config.authentication_function,
-                    &state.conf.report_download_config.region,
-                    &json_bytes,
-                )
-                .await
-                .map(ApplicationResponse::Json)
+                reports::generate_report(&state, ReportDomain::Authentications, report_req).await
             },
             auth::auth_type(
                 &auth::ApiKeyAuth {
@@ -3281,6 +3224,254 @@ pub mod routes {
         .await
     }
 
+    pub async fn get_merchant_report_job(
+        state: web::Data<AppState>,
+        req: actix_web::HttpRequest,
+        job_id: web::Path<String>,
+    ) -> impl Responder {
+        let flow = AnalyticsFlow::GetReportJob;
+        Box::pin(api::server_wrap(
+            flow,
+            state,
+            &req,
+            job_id.into_inner(),
+            |state, (auth, _): auth::AuthenticationDataWithUserId, job_id, _| async move {
+                let org_id = auth.platform.get_processor().get_account().get_org_id();
+                let merchant_id = auth.platform.get_processor().get_account().get_id();
+                let auth = AuthInfo::MerchantLevel {
+                    org_id: org_id.clone(),
+                    merchant_ids: vec![merchant_id.clone()],
+                };
+                reports::get_report_job(&state, &job_id, &auth).await
+            },
+            auth::auth_type::<auth::AuthenticationDataWithUserId, _>(
+                &auth::ApiKeyAuth {
+                    allow_connected_scope_operation: true,
+                    allow_platform_self_operation: false,
+                },
+                &auth::JWTAuth {
+                    permission: Permission::MerchantReportRead,
+                    allow_connected: true,
+                    allow_platform: false,
+                },
+                req.headers(),
+            ),
+            api_locking::LockAction::NotApplicable,
+        ))
+        .await
+    }
+
+    pub async fn download_merchant_report(
+        state: web::Data<AppState>,
+        req: actix_web::HttpRequest,
+        job_id: web::Path<String>,
+    ) -> impl Responder {
+        let flow = AnalyticsFlow::DownloadReport;
+        Box::pin(api::server_wrap(
+            flow,
+            state,
+            &req,
+            job_id.into_inner(),
+            |state, (auth, _): auth::AuthenticationDataWithUserId, job_id, _| async move {
+                let org_id = auth.platform.get_processor().get_account().get_org_id();
+                let merchant_id = auth.platform.get_processor().get_account().get_id();
+                let auth = AuthInfo::MerchantLevel {
+                    org_id: org_id.clone(),
+                    merchant_ids: vec![merchant_id.clone()],
+                };
+                reports::download_report(&state, &job_id, &auth).await
+            },
+            auth::auth_type::<auth::AuthenticationDataWithUserId, _>(
+                &auth::ApiKeyAuth {
+                    allow_connected_scope_operation: true,
+                    allow_platform_self_operation: false,
+                },
+                &auth::JWTAuth {
+                    permission: Permission::MerchantReportRead,
+                    allow_connected: true,
+                    allow_platform: false,
+                },
+                req.headers(),
+            ),
+            api_locking::LockAction::NotApplicable,
+        ))
+        .await
+    }
+
+    pub async fn get_org_report_job(
+        state: web::Data<AppState>,
+        req: actix_web::HttpRequest,
+        job_id: web::Path<String>,
+    ) -> impl Responder {
+        let flow = AnalyticsFlow::GetReportJob;
+        Box::pin(api::server_wrap(
+            flow,
+            state,
+            &req,
+            job_id.into_inner(),
+            |state, (auth, _): auth::AuthenticationDataWithUserId, job_id, _| async move {
+                let org_id = auth.platform.get_processor().get_account().get_org_id();
+                let auth = AuthInfo::OrgLevel {
+                    org_id: org_id.clone(),
+                };
+                reports::get_report_job(&state, &job_id, &auth).await
+            },
+            auth::auth_type::<auth::AuthenticationDataWithUserId, _>(
+                &auth::ApiKeyAuth {
+                    allow_connected_scope_operation: true,
+                    allow_platform_self_operation: false,
+                },
+                &auth::JWTAuth {
+                    permission: Permission::OrganizationReportRead,
+                    allow_connected: true,
+                    allow_platform: false,
+                },
+                req.headers(),
+            ),
+            api_locking::LockAction::NotApplicable,
+        ))
+        .await
+    }
+
+    pub async fn download_org_report(
+        state: web::Data<AppState>,
+        req: actix_web::HttpRequest,
+        job_id: web::Path<String>,
+    ) -> impl Responder {
+        let flow = AnalyticsFlow::DownloadReport;
+        Box::pin(api::server_wrap(
+            flow,
+            state,
+            &req,
+            job_id.into_inner(),
+            |state, (auth, _): auth::AuthenticationDataWithUserId, job_id, _| async move {
+                let org_id = auth.platform.get_processor().get_account().get_org_id();
+                let auth = AuthInfo::OrgLevel {
+                    org_id: org_id.clone(),
+                };
+                reports::download_report(&state, &job_id, &auth).await
+            },
+            auth::auth_type::<auth::AuthenticationDataWithUserId, _>(
+                &auth::ApiKeyAuth {
+                    allow_connected_scope_operation: true,
+                    allow_platform_self_operation: false,
+                },
+                &auth::JWTAuth {
+                    permission: Permission::OrganizationReportRead,
+                    allow_connected: true,
+                    allow_platform: false,
+                },
+                req.headers(),
+            ),
+            api_locking::LockAction::NotApplicable,
+        ))
+        .await
+    }
+
+    pub async fn get_profile_report_job(
+        state: web::Data<AppState>,
+        req: actix_web::HttpRequest,
+        job_id: web::Path<String>,
+    ) -> impl Responder {
+        let flow = AnalyticsFlow::GetReportJob;
+        Box::pin(api::server_wrap(
+            flow,
+            state,
+            &req,
+            job_id.into_inner(),
+            |state, (auth, _): auth::AuthenticationDataWithUserId, job_id, _| async move {
+                let org_id = auth.platform.get_processor().get_account().get_org_id();
+                let merchant_id = auth.platform.get_processor().get_account().get_id();
+                let profile_id = {
+                    #[cfg(feature = "v1")]
+                    {
+                        auth.profile
+                            .ok_or(report!(UserErrors::JwtProfileIdMissing))
+                            .change_context(AnalyticsError::AccessForbiddenError)?
+                            .get_id()
+                            .clone()
+                    }
+                    #[cfg(feature = "v2")]
+                    {
+                        auth.profile.get_id().clone()
+                    }
+                };
+                let auth = AuthInfo::ProfileLevel {
+                    org_id: org_id.clone(),
+                    merchant_id: merchant_id.clone(),
+                    profile_ids: vec![profile_id],
+                };
+                reports::get_report_job(&state, &job_id, &auth).await
+            },
+            auth::auth_type::<auth::AuthenticationDataWithUserId, _>(
+                &auth::ApiKeyAuth {
+                    allow_connected_scope_operation: true,
+                    allow_platform_self_operation: false,
+                },
+                &auth::JWTAuth {
+                    permission: Permission::ProfileReportRead,
+                    allow_connected: true,
+                    allow_platform: false,
+                },
+                req.headers(),
+            ),
+            api_locking::LockAction::NotApplicable,
+        ))
+        .await
+    }
+
+    pub async fn download_profile_report(
+        state: web::Data<AppState>,
+        req: actix_web::HttpRequest,
+        job_id: web::Path<String>,
+    ) -> impl Responder {
+        let flow = AnalyticsFlow::DownloadReport;
+        Box::pin(api::server_wrap(
+            flow,
+            state,
+            &req,
+            job_id.into_inner(),
+            |state, (auth, _): auth::AuthenticationDataWithUserId, job_id, _| async move {
+                let org_id = auth.platform.get_processor().get_account().get_org_id();
+                let merchant_id = auth.platform.get_processor().get_account().get_id();
+                let profile_id = {
+                    #[cfg(feature = "v1")]
+                    {
+                        auth.profile
+                            .ok_or(report!(UserErrors::JwtProfileIdMissing))
+                            .change_context(AnalyticsError::AccessForbiddenError)?
+                            .get_id()
+                            .clone()
+                    }
+                    #[cfg(feature = "v2")]
+                    {
+                        auth.profile.get_id().clone()
+                    }
+                };
+                let auth = AuthInfo::ProfileLevel {
+                    org_id: org_id.clone(),
+                    merchant_id: merchant_id.clone(),
+                    profile_ids: vec![profile_id],
+                };
+                reports::download_report(&state, &job_id, &auth).await
+            },
+            auth::auth_type::<auth::AuthenticationDataWithUserId, _>(
+                &auth::ApiKeyAuth {
+                    allow_connected_scope_operation: true,
+                    allow_platform_self_operation: false,
+                },
+                &auth::JWTAuth {
+                    permission: Permission::ProfileReportRead,
+                    allow_connected: true,
+                    allow_platform: false,
+                },
+                req.headers(),
+            ),
+            api_locking::LockAction::NotApplicable,
+        ))
+        .await
+    }
+
     /// # Panics
     ///
     /// Panics if `json_payload` array does not contain one `GetApiEventMetricRequest` element.
@@ -0,0 +1,732 @@
+use std::{collections::HashMap, path::Path};
+
+use analytics::{
+    enums::AuthInfo,
+    errors::{AnalyticsError, AnalyticsResult},
+    lambda_utils::invoke_lambda,
+    ReportBackend, ReportConfig,
+};
+use api_models::analytics::{
+    GenerateReportRequest, ReportDomain, ReportFormat, ReportJobResponse, ReportJobStatus,
+    TimeRange,
+};
+use common_utils::date_time;
+use error_stack::{report, ResultExt};
+use futures::StreamExt;
+use redis_interface::{errors::RedisError, RedisConnectionPool};
+use router_env::{logger, tracing::Instrument};
+use time::PrimitiveDateTime;
+
+use crate::{
+    consts,
+    routes::SessionState,
+    services::{ApplicationResponse, FileStream},
+};
+
+/// Hash of the generated report files, along with the time after which they are deleted
+const REPORT_FILES_KEY: &str = "report_files";
+
+/// Size of the chunks a report is read in when it is downloaded
+const DOWNLOAD_CHUNK_SIZE: u64 = 8 * 1024 * 1024;
+
+/// A report generated in process, tracked in redis until it expires
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+struct ReportJob {
+    id: String,
+    domain: ReportDomain,
+    status: ReportJobStatus,
+    format: ReportFormat,
+    /// Entities whose data is included in the report, used to authorize access to the report
+    auth: AuthInfo,
+    file_key: String,
+    row_count: Option<usize>,
+    error_message: Option<String>,
+    created_at: PrimitiveDateTime,
+    completed_at: Option<PrimitiveDateTime>,
+}
+
+impl From<ReportJob> for ReportJobResponse {
+    fn from(job: ReportJob) -> Self {
+        Self {
+            job_id: job.id,
+            report: job.domain,
+            status: job.status,
+            format: job.format,
+            row_count: job.row_count,
+            error_message: job.error_message,
+            created_at: job.created_at,
+            completed_at: job.completed_at,
+        }
+    }
+}
+
+fn get_lambda_function(config: &ReportConfig, domain: ReportDomain) -> &str {
+    match domain {
+        ReportDomain::Payments => &config.payment_function,
+        ReportDomain::Refunds => &config.refund_function,
+        ReportDomain::Disputes => &config.dispute_function,
+        ReportDomain::Authentications => &config.authentication_function,
+        ReportDomain::Payouts => &config.payout_function,
+    }
+}
+
+fn get_report_job_key(job_id: &str) -> String {
+    format!("report_job_{job_id}")
+}
+
+/// Hash of the jobs being generated for a merchant, or for an organization in the case of
+/// organization level reports, along with the time they were started at
+fn get_running_report_jobs_key(scope: &str) -> String {
+    format!("report_jobs_running_{scope}")
+}
+
+/// The merchants, or the organization, whose limit of concurrent report jobs the job counts
+/// towards
+fn get_report_job_scopes(auth: &AuthInfo) -> Vec<String> {
+    match auth {
+        AuthInfo::OrgLevel { org_id } => vec![format!("org_{}", org_id.get_string_repr())],
+        AuthInfo::MerchantLevel { merchant_ids, .. } => merchant_ids
+            .iter()
+            .map(|merchant_id| format!("merchant_{}", merchant_id.get_string_repr()))
+            .collect(),
+        AuthInfo::ProfileLevel { merchant_id, .. } => {
+            vec![format!("merchant_{}", merchant_id.get_string_repr())]
+        }
+    }
+}
+
+fn get_redis_conn(state: &SessionState) -> AnalyticsResult<std::sync::Arc<RedisConnectionPool>> {
+    state
+        .store
+        .get_redis_conn()
+        .change_context(AnalyticsError::UnknownError)
+        .attach_printable("Failed to get redis connection")
+}
+
+/// Generates the report using the configured backend. Reports generated in process are returned
+/// as a job which can be polled for its status, while the Lambda functions email the report once
+/// it has been generated.
+pub async fn generate_report(
+    state: &SessionState,
+    domain: ReportDomain,
+    request: GenerateReportRequest,
+) -> AnalyticsResult<ApplicationResponse<Option<ReportJobResponse>>> {
+    let config = &state.conf.report_download_config;
+
+    match config.backend {
+        ReportBackend::Lambda => {
+            let json_bytes =
+                serde_json::to_vec(&request).map_err(|_| AnalyticsError::UnknownError)?;
+            invoke_lambda(
+                get_lambda_function(config, domain),
+                &config.region,
+                &json_bytes,
+            )
+            .await
+            .map(|()| ApplicationResponse::Json(None))
+        }
+        ReportBackend::InProcess => {
+            if domain == ReportDomain::Payouts {
+                return Err(report!(AnalyticsError::NotImplemented(
+                    "In-process payout reports"
+                )));
+            }
+
+            let cleanup_state = state.clone();
+            tokio::spawn(
+                async move {
+                    delete_expired_report_files(&cleanup_state)
+                        .await
+                        .map_err(|error| logger::error!(?error, "Failed to delete expired reports"))
+                        .ok();
+                }
+                .in_current_span(),
+            );
+
+            let format = config.in_process.format;
+            let job_id = common_utils::generate_id(consts::ID_LENGTH, "report");
+            let job = ReportJob {
+                file_key: format!("reports/{job_id}.{}", format.get_file_extension()),
+                id: job_id,
+                domain,
+                status: ReportJobStatus::Pending,
+                format,
+                auth: request.auth,
+                row_count: None,
+                error_message: None,
+                created_at: date_time::now(),
+                completed_at: None,
+            };
+            reserve_report_job(state, &job).await?;
+            store_report_job(state, &job).await?;
+
+            let response = ReportJobResponse::from(job.clone());
+            let state = state.clone();
+            let time_range = request.request.time_range;
+            tokio::spawn(
+                async move { run_report_job(state, job, time_range).await }.in_current_span(),
+            );
+
+            Ok(ApplicationResponse::Json(Some(response)))
+        }
+    }
+}
+
+async fn run_report_job(state: SessionState, mut job: ReportJob, time_range: TimeRange) {
+    job.status = ReportJobStatus::Processing;
+    store_report_job(&state, &job)
+        .await
+        .map_err(|error| logger::error!(?error, job_id = %job.id, "Failed to update report job"))
+        .ok();
+
+    match generate_and_upload_report(&state, &job, &time_range).await {
+        Ok(row_count) => {
+            job.status = ReportJobStatus::Completed;
+            job.row_count = Some(row_count);
+        }
+        Err(error) => {
+            logger::error!(?error, job_id = %job.id, "Report generation failed");
+            job.status = ReportJobStatus::Failed;
+            job.error_message = Some(error.current_context().to_string());
+        }
+    }
+    job.completed_at = Some(date_time::now());
+
+    if job.status == ReportJobStatus::Completed {
+        register_report_file(&state, &job)
+            .await
+            .map_err(|error| logger::error!(?error, job_id = %job.id, "Failed to register report"))
+            .ok();
+    }
+    store_report_job(&state, &job)
+        .await
+        .map_err(|error| logger::error!(?error, job_id = %job.id, "Failed to update report job"))
+        .ok();
+    release_report_job(&state, &job)
+        .await
+        .map_err(|error| logger::error!(?error, job_id = %job.id, "Failed to release report job"))
+        .ok();
+}
+
+/// Generates the report into a temporary file which is then uploaded, so that the report is never
+/// held in memory as a whole
+async fn generate_and_upload_report(
+    state: &SessionState,
+    job: &ReportJob,
+    time_range: &TimeRange,
+) -> AnalyticsResult<usize> {
+    let path = std::env::temp_dir().join(format!("{}.{}", job.id, job.format.get_file_extension()));
+
+    let result = async {
+        let row_count = generate_report_file(state, job, time_range, &path).await?;
+        state
+            .file_storage_client
+            .upload_file_from_path(&job.file_key, &path)
+            .await
+            .change_context(AnalyticsError::UnknownError)
+            .attach_printable("Failed to store the generated report")?;
+        Ok(row_count)
+    }
+    .await;
+
+    std::fs::remove_file(&path)
+        .map_err(|error| logger::warn!(?error, job_id = %job.id, "Failed to remove report file"))
+        .ok();
+    result
+}
+
+async fn generate_report_file(
+    state: &SessionState,
+    job: &ReportJob,
+    time_range: &TimeRange,
+    path: &Path,
+) -> AnalyticsResult<usize> {
+    let file = std::fs::File::create(path)
+        .change_context(AnalyticsError::UnknownError)
+        .attach_printable("Failed to create the report file")?;
+    let generated_report = analytics::reports::generate_report(
+        &state.pool,
+        job.domain,
+        &job.auth,
+        time_range,
+        job.format,
+        state.conf.report_download_config.in_process.batch_size,
+        std::io::BufWriter::new(file),
+    )
+    .await?;
+    generated_report
+        .output
+        .into_inner()
+        .map_err(|error| report!(error.into_error()))
+        .change_context(AnalyticsError::UnknownError)
+        .attach_printable("Failed to flush the report file")?;
+
+    Ok(generated_report.row_count)
+}
+
+/// Counts the job towards the concurrent jobs of the merchants it is generated for, failing if
+/// any of them already has the maximum number of jobs running. The check is not atomic, so
+/// concurrent requests may briefly exceed the limit.
+async fn reserve_report_job(state: &SessionState, job: &ReportJob) -> AnalyticsResult<()> {
+    let redis_conn = get_redis_conn(state)?;
+    let config = &state.conf.report_download_config.in_process;
+    let now = date_time::now_unix_timestamp();
+    let scopes = get_report_job_scopes(&job.auth);
+
+    for scope in &scopes {
+        let key = get_running_report_jobs_key(scope);
+        let running_jobs = redis_conn
+            .get_hash_fields::<HashMap<String, String>>(&key.as_str().into())
+            .await
+            .change_context(AnalyticsError::UnknownError)
+            .attach_printable("Failed to get running report jobs")?;
+
+        // Jobs left behind by an instance which stopped while generating them no longer count
+        let (timed_out_jobs, active_jobs): (Vec<_>, Vec<_>) =
+            running_jobs.into_iter().partition(|(_, started_at)| {
+                started_at.parse::<i64>().map_or(true, |started_at| {
+                    started_at.saturating_add(config.job_timeout_in_seconds) < now
+                })
+            });
+        if !timed_out_jobs.is_empty() {
+            redis_conn
+                .delete_hash_fields(
+                    &key.as_str().into(),
+                    timed_out_jobs
+                        .into_iter()
+                        .map(|(job_id, _)| job_id)
+                        .collect::<Vec<_>>(),
+                )
+                .await
+                .change_context(AnalyticsError::UnknownError)
+                .attach_printable("Failed to remove timed out report jobs")?;
+        }
+        if active_jobs.len() >= config.max_concurrent_jobs_per_merchant {
+            return Err(report!(AnalyticsError::ReportJobLimitReached))
+                .attach_printable_lazy(|| format!("{scope} has reached its report job limit"));
+        }
+    }
+
+    for scope in &scopes {
+        redis_conn
+            .set_hash_fields(
+                &get_running_report_jobs_key(scope).as_str().into(),
+                (job.id.as_str(), now),
+                Some(config.job_timeout_in_seconds),
+            )
+            .await
+            .change_context(AnalyticsError::UnknownError)
+            .attach_printable("Failed to store running report job")?;
+    }
+    Ok(())
+}
+
+async fn release_report_job(state: &SessionState, job: &ReportJob) -> AnalyticsResult<()> {
+    let redis_conn = get_redis_conn(state)?;
+    for scope in get_report_job_scopes(&job.auth) {
+        redis_conn
+            .delete_hash_fields(
+                &get_running_report_jobs_key(&scope).as_str().into(),
+                job.id.as_str(),
+            )
+            .await
+            .change_context(AnalyticsError::UnknownError)
+            .attach_printable("Failed to remove running report job")?;
+    }
+    Ok(())
+}
+
+/// Records the report file so that it is deleted once the job it was generated for expires
+async fn register_report_file(state: &SessionState, job: &ReportJob) -> AnalyticsResult<()> {
+    let expires_at = date_time::now_unix_timestamp().saturating_add(
+        state
+            .conf
+            .report_download_config
+            .in_process
+            .job_ttl_in_seconds,
+    );
+    get_redis_conn(state)?
+        .set_hash_fields_without_modifying_ttl(
+            &REPORT_FILES_KEY.into(),
+            (job.file_key.as_str(), expires_at),
+        )
+        .await
+        .change_context(AnalyticsError::UnknownError)
+        .attach_printable("Failed to store report file expiry")
+}
+
+/// Deletes the report files whose jobs have expired
+async fn delete_expired_report_files(state: &SessionState) -> AnalyticsResult<()> {
+    let redis_conn = get_redis_conn(state)?;
+    let now = date_time::now_unix_timestamp();
+    let report_files = redis_conn
+        .get_hash_fields::<HashMap<String, String>>(&REPORT_FILES_KEY.into())
+        .await
+        .change_context(AnalyticsError::UnknownError)
+        .attach_printable("Failed to get report files")?;
+
+    for (file_key, expires_at) in report_files {
+        if expires_at
+            .parse::<i64>()
+            .is_ok_and(|expires_at| expires_at >= now)
+        {
+            continue;
+        }
+        // The file is forgotten even if it could not be deleted, so that a file which is already
+        // gone is not retried forever
+        state
+            .file_storage_client
+            .delete_file(&file_key)
+            .await
+            .map_err(|error| logger::error!(?error, %file_key, "Failed to delete expired report"))
+            .ok();
+        redis_conn
+            .delete_hash_fields(&REPORT_FILES_KEY.into(), file_key.as_str())
+            .await
+            .change_context(AnalyticsError::UnknownError)
+            .attach_printable("Failed to remove report file")?;
+    }
+    Ok(())
+}
+
+/// Marks a job which has not completed within the timeout as failed, since it was left behind by
+/// an instance which stopped while generating it
+async fn fail_timed_out_report_job(state: &SessionState, mut job: ReportJob) -> ReportJob {
+    let timeout = time::Duration::seconds(
+        state
+            .conf
+            .report_download_config
+            .in_process
+            .job_timeout_in_seconds,
+    );
+    let now = date_time::now();
+    if matches!(
+        job.status,
+        ReportJobStatus::Pending | ReportJobStatus::Processing
+    ) && job.created_at.saturating_add(timeout) < now
+    {
+        job.status = ReportJobStatus::Failed;
+        job.error_message = Some("Report generation did not complete in time".to_string());
+        job.completed_at = Some(now);
+        store_report_job(state, &job)
+            .await
+            .map_err(
+                |error| logger::error!(?error, job_id = %job.id, "Failed to update report job"),
+            )
+            .ok();
+    }
+    job
+}
+
+async fn store_report_job(state: &SessionState, job: &ReportJob) -> AnalyticsResult<()> {
+    get_redis_conn(state)?
+        .serialize_and_set_key_with_expiry(
+            &get_report_job_key(&job.id).as_str().into(),
+            job,
+            state
+                .conf
+                .report_download_config
+                .in_process
+                .job_ttl_in_seconds,
+        )
+        .await
+        .change_context(AnalyticsError::UnknownError)
+        .attach_printable("Failed to store report job in redis")
+}
+
+async fn find_report_job(
+    state: &SessionState,
+    job_id: &str,
+    auth: &AuthInfo,
+) -> AnalyticsResult<ReportJob> {
+    let job = get_redis_conn(state)?
+        .get_and_deserialize_key::<ReportJob>(
+            &get_report_job_key(job_id).as_str().into(),
+            "ReportJob",
+        )
+        .await
+        .map_err(|error| {
+            if error.current_context() == &RedisError::NotFound {
+                error.change_context(AnalyticsError::ReportJobNotFound)
+            } else {
+                error.change_context(AnalyticsError::UnknownError)
+            }
+        })?;
+
+    // Jobs outside the requester's scope are reported as missing, so as to not reveal them
+    if !is_report_accessible(&job.auth, auth) {
+        return Err(report!(AnalyticsError::ReportJobNotFound));
+    }
+    Ok(fail_timed_out_report_job(state, job).await)
+}
+
+/// Checks whether the entities included in a report are all within the requester's scope
+fn is_report_accessible(report_auth: &AuthInfo, auth: &AuthInfo) -> bool {
+    match (auth, report_auth) {
+        (
+            AuthInfo::OrgLevel { org_id },
+            AuthInfo::OrgLevel {
+                org_id: report_org_id,
+            }
+            | AuthInfo::MerchantLevel {
+                org_id: report_org_id,
+                ..
+            }
+            | AuthInfo::ProfileLevel {
+                org_id: report_org_id,
+                ..
+            },
+        ) => org_id == report_org_id,
+        (
+            AuthInfo::MerchantLevel {
+                org_id,
+                merchant_ids,
+            },
+            AuthInfo::MerchantLevel {
+                org_id: report_org_id,
+                merchant_ids: report_merchant_ids,
+            },
+        ) => {
+            org_id == report_org_id
+                && report_merchant_ids
+                    .iter()
+                    .all(|merchant_id| merchant_ids.contains(merchant_id))
+        }
+        (
+            AuthInfo::MerchantLevel {
+                org_id,
+                merchant_ids,
+            },
+            AuthInfo::ProfileLevel {
+                org_id: report_org_id,
+                merchant_id: report_merchant_id,
+                ..
+            },
+        ) => org_id == report_org_id && merchant_ids.contains(report_merchant_id),
+        (
+            AuthInfo::ProfileLevel {
+                org_id,
+                merchant_id,
+                profile_ids,
+            },
+            AuthInfo::ProfileLevel {
+                org_id: report_org_id,
+                merchant_id: report_merchant_id,
+                profile_ids: report_profile_ids,
+            },
+        ) => {
+            org_id == report_org_id
+                && merchant_id == report_merchant_id
+                && report_profile_ids
+                    .iter()
+                    .all(|profile_id| profile_ids.contains(profile_id))
+        }
+        (AuthInfo::MerchantLevel { .. }, AuthInfo::OrgLevel { .. })
+        | (
+            AuthInfo::ProfileLevel { .. },
+            AuthInfo::OrgLevel { .. } | AuthInfo::MerchantLevel { .. },
+        ) => false,
+    }
+}
+
+pub async fn get_report_job(
+    state: &SessionState,
+    job_id: &str,
+    auth: &AuthInfo,
+) -> AnalyticsResult<ApplicationResponse<ReportJobResponse>> {
+    find_report_job(state, job_id, auth)
+        .await
+        .map(|job| ApplicationResponse::Json(job.into()))
+}
+
+pub async fn download_report(
+    state: &SessionState,
+    job_id: &str,
+    auth: &AuthInfo,
+) -> AnalyticsResult<ApplicationResponse<()>> {
+    let job = find_report_job(state, job_id, auth).await?;
+
+    if job.status != ReportJobStatus::Completed {
+        return Err(report!(AnalyticsError::ReportNotAvailable));
+    }
+
+    let file_size = state
+        .file_storage_client
+        .get_file_size(&job.file_key)
+        .await
+        .change_context(AnalyticsError::UnknownError)
+        .attach_printable("Failed to retrieve the generated report")?;
+    let content_type = match job.format {
+        ReportFormat::Csv => mime::TEXT_CSV,
+        ReportFormat::Parquet => mime::APPLICATION_OCTET_STREAM,
+    };
+
+    // The report is read a chunk at a time as the response is sent
+    let file_storage_client = state.file_storage_client.clone();
+    let file_key = job.file_key;
+    let chunks = futures::stream::try_unfold(0, move |offset| {
+        let file_storage_client = file_storage_client.clone();
+        let file_key = file_key.clone();
+        async move {
+            if offset >= file_size {
+                return Ok::<_, std::io::Error>(None);
+            }
+            let chunk = file_storage_client
+                .retrieve_file_range(&file_key, offset, DOWNLOAD_CHUNK_SIZE)
+                .await
+                .map_err(|error| {
+                    logger::error!(?error, %file_key, "Failed to read the generated report");
+                    std::io::Error::other("Failed to read the generated report")
+                })?;
+            if chunk.is_empty() {
+                return Ok(None);
+            }
+            let next_offset =
+                offset.saturating_add(u64::try_from(chunk.len()).unwrap_or(DOWNLOAD_CHUNK_SIZE));
+            Ok(Some((chunk, next_offset)))
+        }
+    });
+
+    Ok(ApplicationResponse::FileStream((
+        FileStream(chunks.boxed()),
+        content_type,
+    )))
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use std::borrow::Cow;
+
+    use common_utils::id_type;
+
+    use super::*;
+
+    fn org(id: &'static str) -> id_type::OrganizationId {
+        id_type::OrganizationId::try_from(Cow::from(id)).unwrap()
+    }
+
+    fn merchant(id: &'static str) -> id_type::MerchantId {
+        id_type::MerchantId::try_from(Cow::from(id)).unwrap()
+    }
+
+    fn profile(id: &'static str) -> id_type::ProfileId {
+        id_type::ProfileId::try_from(Cow::from(id)).unwrap()
+    }
+
+    fn org_level(org_id: &'static str) -> AuthInfo {
+        AuthInfo::OrgLevel {
+            org_id: org(org_id),
+        }
+    }
+
+    fn merchant_level(org_id: &'static str, merchant_ids: &[&'static str]) -> AuthInfo {
+        AuthInfo::MerchantLevel {
+            org_id: org(org_id),
+            merchant_ids: merchant_ids.iter().map(|id| merchant(id)).collect(),
+        }
+    }
+
+    fn profile_level(
+        org_id: &'static str,
+        merchant_id: &'static str,
+        profile_ids: &[&'static str],
+    ) -> AuthInfo {
+        AuthInfo::ProfileLevel {
+            org_id: org(org_id),
+            merchant_id: merchant(merchant_id),
+            profile_ids: profile_ids.iter().map(|id| profile(id)).collect(),
+        }
+    }
+
+    #[test]
+    fn test_org_level_report_access() {
+        let report_auth = org_level("org_1");
+
+        assert!(is_report_accessible(&report_auth, &org_level("org_1")));
+        assert!(!is_report_accessible(&report_auth, &org_level("org_2")));
+        assert!(!is_report_accessible(
+            &report_auth,
+            &merchant_level("org_1", &["merchant_1"])
+        ));
+        assert!(!is_report_accessible(
+            &report_auth,
+            &profile_level("org_1", "merchant_1", &["profile_1"])
+        ));
+    }
+
+    #[test]
+    fn test_merchant_level_report_access() {
+        let report_auth = merchant_level("org_1", &["merchant_1", "merchant_2"]);
+
+        assert!(is_report_accessible(&report_auth, &org_level("org_1")));
+        assert!(!is_report_accessible(&report_auth, &org_level("org_2")));
+        assert!(is_report_accessible(
+            &report_auth,
+            &merchant_level("org_1", &["merchant_2", "merchant_1", "merchant_3"])
+        ));
+        assert!(!is_report_accessible(
+            &report_auth,
+            &merchant_level("org_1", &["merchant_1"])
+        ));
+        assert!(!is_report_accessible(
+            &report_auth,
+            &merchant_level("org_2", &["merchant_1", "merchant_2"])
+        ));
+        assert!(!is_report_accessible(
+            &report_auth,
+            &profile_level("org_1", "merchant_1", &["profile_1"])
+        ));
+    }
+
+    #[test]
+    fn test_profile_level_report_access() {
+        let report_auth = profile_level("org_1", "merchant_1", &["profile_1", "profile_2"]);
+
+        assert!(is_report_accessible(&report_auth, &org_level("org_1")));
+        assert!(is_report_accessible(
+            &report_auth,
+            &merchant_level("org_1", &["merchant_1"])
+        ));
+        assert!(!is_report_accessible(
+            &report_auth,
+            &merchant_level("org_1", &["merchant_2"])
+        ));
+        assert!(is_report_accessible(
+            &report_auth,
+            &profile_level(
+                "org_1",
+                "merchant_1",
+                &["profile_1", "profile_2", "profile_3"]
+            )
+        ));
+        assert!(!is_report_accessible(
+            &report_auth,
+            &profile_level("org_1", "merchant_1", &["profile_1"])
+        ));
+        assert!(!is_report_accessible(
+            &report_auth,
+            &profile_level("org_1", "merchant_2", &["profile_1", "profile_2"])
+        ));
+    }
+
+    #[test]
+    fn test_report_job_scopes() {
+        assert_eq!(
+            get_report_job_scopes(&org_level("org_1")),
+            vec!["org_org_1".to_string()]
+        );
+        assert_eq!(
+            get_report_job_scopes(&merchant_level("org_1", &["merchant_1", "merchant_2"])),
+            vec![
+                "merchant_merchant_1".to_string(),
+                "merchant_merchant_2".to_string()
+            ]
+        );
+        assert_eq!(
+            get_report_job_scopes(&profile_level("org_1", "merchant_1", &["profile_1"])),
+            vec!["merchant_merchant_1".to_string()]
+        );
+    }
+}
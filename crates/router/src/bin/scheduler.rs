@@ -333,6 +333,33 @@ impl ProcessTrackerWorkflows<routes::SessionState> for WorkflowRunner {
                 storage::ProcessTrackerRunner::PassiveRecoveryWorkflow => {
                     Ok(Box::new(workflows::revenue_recovery::ExecutePcrWorkflow))
                 }
+                storage::ProcessTrackerRunner::BlocklistPurgeWorkflow => {
+                    Ok(Box::new(workflows::blocklist_purge::BlocklistPurgeWorkflow))
+                }
+                storage::ProcessTrackerRunner::KeyRotationWorkflow => {
+                    #[cfg(feature = "v1")]
+                    {
+                        Ok(Box::new(workflows::key_rotation::KeyRotationWorkflow))
+                    }
+                    #[cfg(not(feature = "v1"))]
+                    {
+                        Err(error_stack::report!(ProcessTrackerError::UnexpectedFlow))
+                            .attach_printable("Cannot run key rotation workflow for v2")
+                    }
+                }
+                storage::ProcessTrackerRunner::CustomerEmailWorkflow => {
+                    #[cfg(all(feature = "email", feature = "v1"))]
+                    {
+                        Ok(Box::new(workflows::customer_email::CustomerEmailWorkflow))
+                    }
+                    #[cfg(not(all(feature = "email", feature = "v1")))]
+                    {
+                        Err(error_stack::report!(ProcessTrackerError::UnexpectedFlow))
+                            .attach_printable(
+                                "Cannot run customer email workflow for v2 or without email",
+                            )
+                    }
+                }
             }
         };
 
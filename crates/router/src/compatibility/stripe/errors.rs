@@ -291,6 +291,16 @@ pub enum StripeErrorCode {
     ProfileAcquirerNotFound,
     #[error(error_type = StripeErrorType::HyperswitchError, code = "Subscription Error", message = "Subscription operation: {operation} failed with connector")]
     SubscriptionError { operation: String },
+    #[error(error_type = StripeErrorType::InvalidRequestError, code = "idempotency_key_in_use", message = "There is currently another in-progress request using this idempotency key")]
+    IdempotencyKeyInUse,
+    #[error(error_type = StripeErrorType::InvalidRequestError, code = "idempotency_error", message = "Keys for idempotent requests can only be used with the same parameters they were first used with")]
+    IdempotencyError,
+    #[error(error_type = StripeErrorType::InvalidRequestError, code = "rate_limit", message = "Too many requests hit the API too quickly, please retry after {retry_after_in_secs} seconds")]
+    RateLimit {
+        limit: u32,
+        reset_in_secs: u64,
+        retry_after_in_secs: u64,
+    },
     // [#216]: https://github.com/juspay/hyperswitch/issues/216
     // Implement the remaining stripe error codes
 
@@ -333,7 +343,6 @@ pub enum StripeErrorCode {
         DebitNotAuthorized,
         EmailInvalid,
         ExpiredCard,
-        IdempotencyKeyInUse,
         IncorrectAddress,
         IncorrectCvc,
         IncorrectNumber,
@@ -713,6 +722,17 @@ impl From<errors::ApiErrorResponse> for StripeErrorCode {
             errors::ApiErrorResponse::SubscriptionError { operation } => {
                 Self::SubscriptionError { operation }
             }
+            errors::ApiErrorResponse::IdempotencyKeyInUse => Self::IdempotencyKeyInUse,
+            errors::ApiErrorResponse::IdempotencyKeyReused => Self::IdempotencyError,
+            errors::ApiErrorResponse::RateLimitExceeded {
+                limit,
+                reset_in_secs,
+                retry_after_in_secs,
+            } => Self::RateLimit {
+                limit,
+                reset_in_secs,
+                retry_after_in_secs,
+            },
         }
     }
 }
@@ -790,7 +810,8 @@ impl actix_web::ResponseError for StripeErrorCode {
             | Self::ExtendedCardInfoNotFound
             | Self::PlatformBadRequest
             | Self::ConnectedBadRequest
-            | Self::LinkConfigurationError { .. } => StatusCode::BAD_REQUEST,
+            | Self::LinkConfigurationError { .. }
+            | Self::IdempotencyError => StatusCode::BAD_REQUEST,
             Self::RefundFailed
             | Self::PayoutFailed
             | Self::PaymentLinkNotFound
@@ -811,6 +832,8 @@ impl actix_web::ResponseError for StripeErrorCode {
                 StatusCode::from_u16(*code).unwrap_or(StatusCode::OK)
             }
             Self::LockTimeout => StatusCode::LOCKED,
+            Self::IdempotencyKeyInUse => StatusCode::CONFLICT,
+            Self::RateLimit { .. } => StatusCode::TOO_MANY_REQUESTS,
             Self::ProfileAcquirerNotFound => StatusCode::NOT_FOUND,
         }
     }
@@ -818,7 +841,22 @@ impl actix_web::ResponseError for StripeErrorCode {
     fn error_response(&self) -> actix_web::HttpResponse {
         use actix_web::http::header;
 
-        actix_web::HttpResponseBuilder::new(self.status_code())
+        let mut response_builder = actix_web::HttpResponseBuilder::new(self.status_code());
+
+        if let Self::RateLimit {
+            limit,
+            reset_in_secs,
+            retry_after_in_secs,
+        } = self
+        {
+            response_builder
+                .insert_header((header::RETRY_AFTER, *retry_after_in_secs))
+                .insert_header(("RateLimit-Limit", *limit))
+                .insert_header(("RateLimit-Remaining", 0))
+                .insert_header(("RateLimit-Reset", *reset_in_secs));
+        }
+
+        response_builder
             .insert_header((header::CONTENT_TYPE, mime::APPLICATION_JSON))
             .body(self.to_string())
     }
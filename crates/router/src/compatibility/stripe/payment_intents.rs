@@ -84,7 +84,7 @@ pub async fn payment_intents_create(
                 state,
                 req_state,
                 auth.platform,
-                None,
+                auth.profile.map(|profile| profile.get_id().clone()),
                 payments::PaymentCreate,
                 req,
                 api::AuthFlow::Merchant,
@@ -167,7 +167,7 @@ pub async fn payment_intents_retrieve(
                 state,
                 req_state,
                 auth.platform,
-                None,
+                auth.profile.map(|profile| profile.get_id().clone()),
                 payments::PaymentStatus,
                 payload,
                 auth_flow,
@@ -256,7 +256,7 @@ pub async fn payment_intents_retrieve_with_gateway_creds(
                 state,
                 req_state,
                 auth.platform,
-                None,
+                auth.profile.map(|profile| profile.get_id().clone()),
                 payments::PaymentStatus,
                 req,
                 api::AuthFlow::Merchant,
@@ -342,7 +342,7 @@ pub async fn payment_intents_update(
                 state,
                 req_state,
                 auth.platform,
-                None,
+                auth.profile.map(|profile| profile.get_id().clone()),
                 payments::PaymentUpdate,
                 req,
                 auth_flow,
@@ -436,7 +436,7 @@ pub async fn payment_intents_confirm(
                 state,
                 req_state,
                 auth.platform,
-                None,
+                auth.profile.map(|profile| profile.get_id().clone()),
                 payments::PaymentConfirm,
                 req,
                 auth_flow,
@@ -507,7 +507,7 @@ pub async fn payment_intents_capture(
                 state,
                 req_state,
                 auth.platform,
-                None,
+                auth.profile.map(|profile| profile.get_id().clone()),
                 payments::PaymentCapture,
                 payload,
                 api::AuthFlow::Merchant,
@@ -591,7 +591,7 @@ pub async fn payment_intents_cancel(
                 state,
                 req_state,
                 auth.platform,
-                None,
+                auth.profile.map(|profile| profile.get_id().clone()),
                 payments::PaymentCancel,
                 req,
                 auth_flow,
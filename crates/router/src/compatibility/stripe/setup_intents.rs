@@ -70,7 +70,7 @@ pub async fn setup_intents_create(
                 state,
                 req_state,
                 auth.platform,
-                None,
+                auth.profile.map(|profile| profile.get_id().clone()),
                 payments::PaymentCreate,
                 req,
                 api::AuthFlow::Merchant,
@@ -153,7 +153,7 @@ pub async fn setup_intents_retrieve(
                 state,
                 req_state,
                 auth.platform,
-                None,
+                auth.profile.map(|profile| profile.get_id().clone()),
                 payments::PaymentStatus,
                 payload,
                 auth_flow,
@@ -238,7 +238,7 @@ pub async fn setup_intents_update(
                 state,
                 req_state,
                 auth.platform,
-                None,
+                auth.profile.map(|profile| profile.get_id().clone()),
                 payments::PaymentUpdate,
                 req,
                 auth_flow,
@@ -324,7 +324,7 @@ pub async fn setup_intents_confirm(
                 state,
                 req_state,
                 auth.platform,
-                None,
+                auth.profile.map(|profile| profile.get_id().clone()),
                 payments::PaymentConfirm,
                 req,
                 auth_flow,
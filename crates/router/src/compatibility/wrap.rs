@@ -6,7 +6,7 @@ use router_env::{instrument, tracing, Tag};
 use serde::Serialize;
 
 use crate::{
-    core::{api_locking, errors},
+    core::{api_locking, errors, idempotency},
     events::api_logs::ApiEventMetric,
     routes::{
         app::{AppStateInfo, ReqState},
@@ -52,6 +52,7 @@ where
         func,
         api_authentication,
         lock_action,
+        idempotency::IdempotencyAction::DeduplicateConvertedResponse,
     )
     .await
     .map(|response| {
@@ -59,12 +60,21 @@ where
         response
     });
 
+    // The response of an idempotent request is stored in the format it is served in
+    let mut replayable_response = None;
     let res = match server_wrap_util_res {
         Ok(api::ApplicationResponse::Json(response)) => {
             let response = S::try_from(response);
             match response {
                 Ok(response) => match serde_json::to_string(&response) {
-                    Ok(res) => api::http_response_json(res),
+                    Ok(res) => {
+                        replayable_response = Some(api::ReplayedJsonResponse {
+                            status_code: http::StatusCode::OK.as_u16(),
+                            body: res.clone(),
+                            headers: Vec::new(),
+                        });
+                        api::http_response_json(res)
+                    }
                     Err(_) => api::http_response_err(
                         r#"{
                                 "error": {
@@ -86,7 +96,14 @@ where
             let response = S::try_from(response);
             match response {
                 Ok(response) => match serde_json::to_string(&response) {
-                    Ok(res) => api::http_response_json_with_headers(res, headers, None, None),
+                    Ok(res) => {
+                        replayable_response = Some(api::ReplayedJsonResponse {
+                            status_code: http::StatusCode::OK.as_u16(),
+                            body: res.clone(),
+                            headers: idempotency::get_replayable_headers(&headers),
+                        });
+                        api::http_response_json_with_headers(res, headers, None, None)
+                    }
                     Err(_) => api::http_response_err(
                         r#"{
                                 "error": {
@@ -105,10 +122,16 @@ where
             }
         }
         Ok(api::ApplicationResponse::StatusOk) => api::http_response_ok(),
+        Ok(api::ApplicationResponse::ReplayedJson(response)) => {
+            api::http_response_replayed_json(*response)
+        }
         Ok(api::ApplicationResponse::TextPlain(text)) => api::http_response_plaintext(text),
         Ok(api::ApplicationResponse::FileData((file_data, content_type))) => {
             api::http_response_file_data(file_data, content_type)
         }
+        Ok(api::ApplicationResponse::FileStream((file_stream, content_type))) => {
+            api::http_response_file_stream(file_stream, content_type)
+        }
         Ok(api::ApplicationResponse::JsonForRedirection(response)) => {
             match serde_json::to_string(&response) {
                 Ok(res) => api::http_redirect_response(res, response),
@@ -178,6 +201,14 @@ where
         Err(error) => api::log_and_return_error_response(error),
     };
 
+    if let Some(pending_request) = idempotency::PendingIdempotentRequest::take(request) {
+        pending_request
+            .complete(replayable_response)
+            .await
+            .map_err(|error| logger::error!(?error))
+            .ok();
+    }
+
     let response_code = res.status().as_u16();
     let end_instant = Instant::now();
     let request_duration = end_instant.saturating_duration_since(start_instant);
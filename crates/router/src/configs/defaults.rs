@@ -14,6 +14,7 @@ impl Default for super::settings::Server {
             keep_alive: 5,
             client_request_timeout: 5000,
             client_disconnect_timeout: 1000,
+            trusted_proxies: HashSet::new(),
             #[cfg(feature = "tls")]
             tls: None,
         }
@@ -123,6 +124,30 @@ impl Default for super::settings::KvConfig {
     }
 }
 
+impl Default for super::settings::IdempotencySettings {
+    fn default() -> Self {
+        Self {
+            // 24 hours
+            response_expiry_in_seconds: 86400,
+        }
+    }
+}
+
+#[cfg(feature = "email")]
+impl Default for super::settings::CustomerEmailSettings {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            provider: None,
+            max_retries: 3,
+            // 5 minutes
+            retry_interval_in_seconds: 300,
+            // 24 hours
+            payment_link_reminder_before_expiry_in_seconds: 86400,
+        }
+    }
+}
+
 #[allow(clippy::derivable_impls)]
 impl Default for super::settings::ApiKeys {
     fn default() -> Self {
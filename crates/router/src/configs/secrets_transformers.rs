@@ -267,10 +267,20 @@ impl SecretsHandler for settings::Secrets {
             secret_management_client.get_secret(secrets.master_enc_key.clone())
         )?;
 
+        let previous_master_enc_key = match secrets.previous_master_enc_key.clone() {
+            Some(previous_master_enc_key) => Some(
+                secret_management_client
+                    .get_secret(previous_master_enc_key)
+                    .await?,
+            ),
+            None => None,
+        };
+
         Ok(value.transition_state(|_| Self {
             jwt_secret,
             admin_api_key,
             master_enc_key,
+            previous_master_enc_key,
         }))
     }
 }
@@ -535,6 +545,7 @@ pub(crate) async fn fetch_raw_secrets(
         chat,
         master_database,
         redis: conf.redis,
+        storage: conf.storage,
         log: conf.log,
         #[cfg(feature = "kv_store")]
         drainer: conf.drainer,
@@ -566,6 +577,8 @@ pub(crate) async fn fetch_raw_secrets(
         dummy_connector: conf.dummy_connector,
         #[cfg(feature = "email")]
         email: conf.email,
+        #[cfg(feature = "email")]
+        customer_email: conf.customer_email,
         user: conf.user,
         oidc,
         mandates: conf.mandates,
@@ -591,6 +604,8 @@ pub(crate) async fn fetch_raw_secrets(
         multiple_api_version_supported_connectors: conf.multiple_api_version_supported_connectors,
         applepay_merchant_configs,
         lock_settings: conf.lock_settings,
+        idempotency: conf.idempotency,
+        rate_limit: conf.rate_limit,
         temp_locker_enable_config: conf.temp_locker_enable_config,
         generic_link: conf.generic_link,
         payment_link: conf.payment_link,
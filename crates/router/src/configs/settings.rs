@@ -85,6 +85,8 @@ pub struct Settings<S: SecretState> {
     #[cfg(feature = "olap")]
     pub replica_database: SecretStateContainer<Database, S>,
     pub redis: RedisSettings,
+    #[serde(default)]
+    pub storage: StorageSettings,
     pub log: Log,
     pub secrets: SecretStateContainer<Secrets, S>,
     pub fallback_merchant_ids_api_key_auth: Option<FallbackMerchantIds>,
@@ -111,6 +113,8 @@ pub struct Settings<S: SecretState> {
     pub dummy_connector: DummyConnector,
     #[cfg(feature = "email")]
     pub email: EmailSettings,
+    #[cfg(feature = "email")]
+    pub customer_email: CustomerEmailSettings,
     pub user: UserSettings,
     pub oidc: SecretStateContainer<OidcSettings, S>,
     pub crm: CrmManagerConfig,
@@ -140,6 +144,10 @@ pub struct Settings<S: SecretState> {
     pub multiple_api_version_supported_connectors: MultipleApiVersionSupportedConnectors,
     pub applepay_merchant_configs: SecretStateContainer<ApplepayMerchantConfigs, S>,
     pub lock_settings: LockSettings,
+    #[serde(default)]
+    pub idempotency: IdempotencySettings,
+    #[serde(default)]
+    pub rate_limit: RateLimitSettings,
     pub temp_locker_enable_config: TempLockerEnableConfig,
     pub generic_link: GenericLink,
     pub payment_link: PaymentLink,
@@ -308,6 +316,38 @@ impl TenantConfig {
     /// # Panics
     ///
     /// Panics if Failed to create event handler
+    pub async fn get_common_store_interface_map(
+        &self,
+        storage_impl: &app::StorageImpl,
+        conf: &configs::Settings,
+        cache_store: Arc<storage_impl::redis::RedisStore>,
+        testable: bool,
+    ) -> HashMap<id_type::TenantId, Box<dyn app::CommonStorageInterface>> {
+        #[allow(clippy::expect_used)]
+        let event_handler = conf
+            .events
+            .get_event_handler()
+            .await
+            .expect("Failed to create event handler");
+        futures::future::join_all(self.0.iter().map(|(tenant_name, tenant)| async {
+            let store = Box::pin(AppState::get_store_interface(
+                storage_impl,
+                &event_handler,
+                conf,
+                tenant,
+                cache_store.clone(),
+                testable,
+            ))
+            .await;
+            (tenant_name.clone(), store)
+        }))
+        .await
+        .into_iter()
+        .collect()
+    }
+    /// # Panics
+    ///
+    /// Panics if Failed to create event handler
     pub async fn get_accounts_store_interface_map(
         &self,
         storage_impl: &app::StorageImpl,
@@ -465,6 +505,24 @@ pub struct ForexApi {
     pub data_expiration_delay_in_seconds: u32,
     pub redis_lock_timeout_in_seconds: u32,
     pub redis_ttl_in_seconds: u32,
+    /// Providers from which the forex rates are fetched, in the order of preference
+    pub providers: Vec<api_models::currency::ForexProvider>,
+    /// Path to the JSON file containing the rates served by the static file provider
+    pub static_rates_file_path: Option<String>,
+}
+
+impl ForexApi {
+    /// Returns the configured providers, defaulting to the primary and fallback forex APIs
+    pub fn get_providers(&self) -> Vec<api_models::currency::ForexProvider> {
+        if self.providers.is_empty() {
+            vec![
+                api_models::currency::ForexProvider::OpenExchangeRates,
+                api_models::currency::ForexProvider::ApiLayer,
+            ]
+        } else {
+            self.providers.clone()
+        }
+    }
 }
 
 #[derive(Debug, Deserialize, Clone, Default)]
@@ -714,6 +772,8 @@ pub struct Secrets {
     pub jwt_secret: Secret<String>,
     pub admin_api_key: Secret<String>,
     pub master_enc_key: Secret<String>,
+    /// Master key replaced by `master_enc_key`, used to read key stores until they are re-encrypted
+    pub previous_master_enc_key: Option<Secret<String>>,
 }
 
 #[derive(Debug, Default, Deserialize, Clone)]
@@ -828,6 +888,10 @@ pub struct Server {
     pub keep_alive: u64,
     pub client_request_timeout: u64,
     pub client_disconnect_timeout: u64,
+    /// CIDR ranges of the proxies which are trusted to forward the address of the client in the
+    /// `X-Forwarded-For` header. The header is ignored for requests made from other addresses.
+    #[serde(deserialize_with = "deserialize_hashset")]
+    pub trusted_proxies: HashSet<ipnet::IpNet>,
     #[cfg(feature = "tls")]
     pub tls: Option<ServerTls>,
 }
@@ -1208,6 +1272,7 @@ impl Settings<SecuredSecret> {
         #[cfg(feature = "kv_store")]
         self.drainer.validate()?;
         self.api_keys.get_inner().validate()?;
+        self.forex_api.get_inner().validate()?;
 
         self.file_storage
             .validate()
@@ -1218,6 +1283,7 @@ impl Settings<SecuredSecret> {
             .map_err(|err| ApplicationError::InvalidConfigurationValueError(err.to_string()))?;
 
         self.lock_settings.validate()?;
+        self.rate_limit.validate()?;
         self.events.validate()?;
 
         #[cfg(feature = "olap")]
@@ -1255,6 +1321,8 @@ impl Settings<SecuredSecret> {
         self.email
             .validate()
             .map_err(|err| ApplicationError::InvalidConfigurationValueError(err.into()))?;
+        #[cfg(feature = "email")]
+        self.customer_email.validate()?;
 
         self.theme
             .storage
@@ -1332,6 +1400,96 @@ impl<'de> Deserialize<'de> for LockSettings {
     }
 }
 
+#[derive(Debug, Deserialize, Clone, Default)]
+#[serde(default)]
+pub struct StorageSettings {
+    /// Keep all tables in process memory instead of connecting to the database, so that the
+    /// application can be run without Postgres. Data is lost when the process exits.
+    pub in_memory: bool,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct IdempotencySettings {
+    /// Duration for which the response of an idempotent request is stored and replayed for
+    /// retries made with the same idempotency key
+    pub response_expiry_in_seconds: u32,
+}
+
+#[cfg(feature = "email")]
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct CustomerEmailSettings {
+    /// Whether emails can be sent to the customers of merchants, which merchants additionally
+    /// enable per profile
+    pub enabled: bool,
+    /// Email provider used for emails to customers, the `email` provider is used if not set
+    pub provider: Option<EmailSettings>,
+    /// Number of times sending an email is retried before it's given up on
+    pub max_retries: i32,
+    /// Delay between retries of sending an email, in seconds
+    pub retry_interval_in_seconds: i64,
+    /// How long before the expiry of a payment link its reminder is sent, in seconds
+    pub payment_link_reminder_before_expiry_in_seconds: i64,
+}
+
+#[derive(Debug, Deserialize, Clone, Default)]
+#[serde(default)]
+pub struct RateLimitSettings {
+    pub enabled: bool,
+    /// Whether requests are rejected when the limits cannot be checked because redis is
+    /// unavailable. Requests are allowed in that case by default.
+    pub fail_closed: bool,
+    /// Limits applied to the tenants which do not override them
+    pub default: RateLimitConfig,
+    /// Limits overridden per tenant
+    pub tenants: HashMap<id_type::TenantId, RateLimitConfig>,
+}
+
+impl RateLimitSettings {
+    pub fn get_tenant_config(&self, tenant_id: &id_type::TenantId) -> &RateLimitConfig {
+        self.tenants.get(tenant_id).unwrap_or(&self.default)
+    }
+}
+
+/// Token bucket limits applied to the requests made by a merchant. The limits can be overridden
+/// per merchant through the configs table, in which case the limits set in the override take
+/// precedence over the ones configured for the tenant.
+#[derive(Debug, Deserialize, Clone, Default)]
+#[serde(default)]
+pub struct RateLimitConfig {
+    /// Limit shared by all requests made by the merchant
+    pub merchant: Option<TokenBucketConfig>,
+    /// Limit for the requests authenticated for each profile of the merchant
+    pub profile: Option<TokenBucketConfig>,
+    /// Limit for the requests made with each API key of the merchant
+    pub api_key: Option<TokenBucketConfig>,
+    /// Limits for the requests made by the merchant to each API flow, keyed by the flow name
+    pub flows: HashMap<String, TokenBucketConfig>,
+}
+
+impl RateLimitConfig {
+    pub fn merge(&self, overrides: Self) -> Self {
+        let mut flows = self.flows.clone();
+        flows.extend(overrides.flows);
+
+        Self {
+            merchant: overrides.merchant.or(self.merchant),
+            profile: overrides.profile.or(self.profile),
+            api_key: overrides.api_key.or(self.api_key),
+            flows,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Clone, Copy)]
+pub struct TokenBucketConfig {
+    /// Maximum number of requests which can be made in a burst
+    pub capacity: u32,
+    /// Number of requests replenished every second
+    pub refill_per_second: u32,
+}
+
 #[cfg(feature = "olap")]
 #[derive(Debug, Deserialize, Clone, Default)]
 pub struct ConnectorOnboarding {
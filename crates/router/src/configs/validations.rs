@@ -22,7 +22,22 @@ impl super::settings::Secrets {
             Err(ApplicationError::InvalidConfigurationValueError(
                 "Master encryption key must not be empty".into(),
             ))
-        })
+        })?;
+
+        when(
+            self.previous_master_enc_key
+                .as_ref()
+                .is_some_and(|previous_master_enc_key| {
+                    previous_master_enc_key.is_default_or_empty()
+                        || previous_master_enc_key.peek() == self.master_enc_key.peek()
+                }),
+            || {
+                Err(ApplicationError::InvalidConfigurationValueError(
+                    "previous master encryption key must be non-empty and differ from the master key"
+                        .into(),
+                ))
+            },
+        )
     }
 }
 
@@ -123,6 +138,35 @@ impl super::settings::DrainerSettings {
     }
 }
 
+impl super::settings::ForexApi {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        use api_models::currency::ForexProvider;
+        use common_utils::fp_utils::when;
+
+        when(
+            self.providers.contains(&ForexProvider::MerchantRateTable),
+            || {
+                Err(ApplicationError::InvalidConfigurationValueError(
+                    "forex providers must not include the merchant rate table, which is selected per request".into(),
+                ))
+            },
+        )?;
+
+        when(
+            self.providers.contains(&ForexProvider::StaticFile)
+                && self
+                    .static_rates_file_path
+                    .as_ref()
+                    .is_none_or(|path| path.is_empty()),
+            || {
+                Err(ApplicationError::InvalidConfigurationValueError(
+                    "forex static rates file path must be set when the static file provider is used".into(),
+                ))
+            },
+        )
+    }
+}
+
 impl super::settings::ApiKeys {
     pub fn validate(&self) -> Result<(), ApplicationError> {
         use common_utils::fp_utils::when;
@@ -144,6 +188,61 @@ impl super::settings::ApiKeys {
     }
 }
 
+#[cfg(feature = "email")]
+impl super::settings::CustomerEmailSettings {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        use common_utils::fp_utils::when;
+
+        self.provider
+            .as_ref()
+            .map(|provider| provider.validate())
+            .transpose()
+            .map_err(|err| ApplicationError::InvalidConfigurationValueError(err.into()))?;
+
+        when(self.max_retries < 0, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "customer email max retries must not be negative".into(),
+            ))
+        })?;
+
+        when(self.retry_interval_in_seconds <= 0, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "customer email retry interval must be positive".into(),
+            ))
+        })
+    }
+}
+
+impl super::settings::RateLimitSettings {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        std::iter::once(&self.default)
+            .chain(self.tenants.values())
+            .try_for_each(|rate_limit_config| rate_limit_config.validate())
+    }
+}
+
+impl super::settings::RateLimitConfig {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        use common_utils::fp_utils::when;
+
+        [&self.merchant, &self.profile, &self.api_key]
+            .into_iter()
+            .flatten()
+            .chain(self.flows.values())
+            .try_for_each(|token_bucket_config| {
+                when(
+                    token_bucket_config.capacity.is_default_or_empty()
+                        || token_bucket_config.refill_per_second.is_default_or_empty(),
+                    || {
+                        Err(ApplicationError::InvalidConfigurationValueError(
+                            "rate limit capacity and refill_per_second must not be 0".into(),
+                        ))
+                    },
+                )
+            })
+    }
+}
+
 impl super::settings::LockSettings {
     pub fn validate(&self) -> Result<(), ApplicationError> {
         use common_utils::fp_utils::when;
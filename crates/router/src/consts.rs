@@ -55,6 +55,10 @@ pub(crate) const MERCHANT_ID_FIELD_EXTENSION_ID: &str = "1.2.840.113635.100.6.32
 
 pub const MAX_ROUTING_CONFIGS_PER_MERCHANT: usize = 100;
 pub const ROUTING_CONFIG_ID_LENGTH: usize = 10;
+/// Default number of payments replayed in a routing simulation
+pub const ROUTING_SIMULATION_DEFAULT_LIMIT: u32 = 1000;
+/// Max number of payments replayed in a routing simulation
+pub const ROUTING_SIMULATION_MAX_LIMIT: u32 = 10000;
 
 pub const LOCKER_REDIS_PREFIX: &str = "LOCKER_PM_TOKEN";
 pub const LOCKER_REDIS_EXPIRY_SECONDS: u32 = 60 * 15; // 15 minutes
@@ -91,6 +95,8 @@ pub const GUEST_USER_CARD_BLOCKING_CACHE_KEY_PREFIX: &str = "GUEST_USER_CARD_BLO
 
 pub const CUSTOMER_ID_BLOCKING_PREFIX: &str = "CUSTOMER_ID_BLOCKING";
 
+pub const VELOCITY_RULE_CACHE_KEY_PREFIX: &str = "VELOCITY_RULE";
+
 #[cfg(feature = "olap")]
 pub const VERIFY_CONNECTOR_ID_PREFIX: &str = "conn_verify";
 #[cfg(feature = "olap")]
@@ -348,6 +354,19 @@ pub const UCS_AUTH_CURRENCY_AUTH_KEY: &str = "currency-auth-key";
 /// Form field name for challenge request during creq submission
 pub const CREQ_CHALLENGE_REQUEST_KEY: &str = "creq";
 
+/// Default duration (in seconds) for which the previous webhook signing key continues to sign
+/// outgoing webhooks after a key rotation (24 hours)
+pub const DEFAULT_WEBHOOK_SIGNING_KEY_OVERLAP_WINDOW_IN_SECS: u32 = 24 * 60 * 60;
+
+/// Maximum duration (in seconds) for the webhook signing key rotation overlap window (30 days)
+pub const MAX_WEBHOOK_SIGNING_KEY_OVERLAP_WINDOW_IN_SECS: u32 = 30 * 24 * 60 * 60;
+
+/// Length of the randomly generated webhook signing key secret
+pub const WEBHOOK_SIGNING_KEY_SECRET_LENGTH: usize = 64;
+
+/// Prefix of the webhook signing key identifier
+pub const WEBHOOK_SIGNING_KEY_ID_PREFIX: &str = "whsk";
+
 /// Superposition configuration keys
 pub mod superposition {
     /// CVV requirement configuration key
@@ -17,6 +17,8 @@ pub mod connector_onboarding;
 pub mod connector_validation;
 #[cfg(any(feature = "olap", feature = "oltp"))]
 pub mod currency;
+#[cfg(all(feature = "email", feature = "v1"))]
+pub mod customer_email;
 pub mod customers;
 #[cfg(feature = "v1")]
 pub mod debit_routing;
@@ -29,6 +31,9 @@ pub mod files;
 pub mod fraud_check;
 pub mod gsm;
 pub mod health_check;
+pub mod idempotency;
+#[cfg(feature = "v1")]
+pub mod key_rotation;
 #[cfg(feature = "v1")]
 pub mod locker_migration;
 pub mod mandate;
@@ -52,6 +57,7 @@ pub mod poll;
 pub mod profile_acquirer;
 #[cfg(feature = "v2")]
 pub mod proxy;
+pub mod rate_limit;
 #[cfg(feature = "recon")]
 pub mod recon;
 #[cfg(feature = "v1")]
@@ -361,6 +361,8 @@ pub async fn create_merchant_account(
         .change_context(errors::ApiErrorResponse::InternalServerError)
         .attach_printable("Failed to decrypt data from key store")?,
         created_at: date_time::now(),
+        key_version: 1,
+        previous_keys: None,
     };
 
     let domain_merchant_account = req
@@ -3405,7 +3407,13 @@ impl ProfileCreateBridge for api::ProfileCreate {
 
         let card_testing_guard_config = self
             .card_testing_guard_config
-            .map(CardTestingGuardConfig::foreign_from)
+            .map(|guard_conf| match guard_conf.validate() {
+                Ok(_) => Ok(CardTestingGuardConfig::foreign_from(guard_conf)),
+                Err(e) => Err(error_stack::report!(
+                    errors::ApiErrorResponse::InvalidRequestData { message: e }
+                )),
+            })
+            .transpose()?
             .or(Some(CardTestingGuardConfig::default()));
 
         let mut dynamic_routing_algorithm_ref =
@@ -3604,7 +3612,13 @@ impl ProfileCreateBridge for api::ProfileCreate {
 
         let card_testing_guard_config = self
             .card_testing_guard_config
-            .map(CardTestingGuardConfig::foreign_from)
+            .map(|guard_conf| match guard_conf.validate() {
+                Ok(_) => Ok(CardTestingGuardConfig::foreign_from(guard_conf)),
+                Err(e) => Err(error_stack::report!(
+                    errors::ApiErrorResponse::InvalidRequestData { message: e }
+                )),
+            })
+            .transpose()?
             .or(Some(CardTestingGuardConfig::default()));
 
         Ok(domain::Profile::from(domain::ProfileSetter {
@@ -3745,11 +3759,14 @@ pub async fn create_profile(
         .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
     }
 
-    Ok(service_api::ApplicationResponse::Json(
-        api_models::admin::ProfileResponse::foreign_try_from(business_profile)
-            .change_context(errors::ApiErrorResponse::InternalServerError)
-            .attach_printable("Failed to parse business profile details")?,
-    ))
+    let webhook_details = business_profile.webhook_details.clone();
+    let mut response = api_models::admin::ProfileResponse::foreign_try_from(business_profile)
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to parse business profile details")?;
+    // The secrets of the webhook endpoints are returned in full only when the profile is created
+    response.webhook_details = webhook_details.map(ForeignInto::foreign_into);
+
+    Ok(service_api::ApplicationResponse::Json(response))
 }
 
 #[cfg(feature = "olap")]
@@ -3913,6 +3930,16 @@ impl ProfileUpdateBridge for api::ProfileUpdate {
             })
             .transpose()?;
 
+        let card_testing_guard_config = self
+            .card_testing_guard_config
+            .map(|guard_conf| match guard_conf.validate() {
+                Ok(_) => Ok(guard_conf.foreign_into()),
+                Err(e) => Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                    message: e
+                })),
+            })
+            .transpose()?;
+
         let key = key_store.key.clone().into_inner();
         let key_manager_state = state.into();
 
@@ -4025,9 +4052,7 @@ impl ProfileUpdateBridge for api::ProfileUpdate {
                 max_auto_retries_enabled: self.max_auto_retries_enabled.map(i16::from),
                 is_click_to_pay_enabled: self.is_click_to_pay_enabled,
                 authentication_product_ids: self.authentication_product_ids,
-                card_testing_guard_config: self
-                    .card_testing_guard_config
-                    .map(ForeignInto::foreign_into),
+                card_testing_guard_config,
                 card_testing_secret_key,
                 is_clear_pan_retries_enabled: self.is_clear_pan_retries_enabled,
                 force_3ds_challenge: self.force_3ds_challenge,
@@ -4109,6 +4134,16 @@ impl ProfileUpdateBridge for api::ProfileUpdate {
             })
             .transpose()?;
 
+        let card_testing_guard_config = self
+            .card_testing_guard_config
+            .map(|guard_conf| match guard_conf.validate() {
+                Ok(_) => Ok(guard_conf.foreign_into()),
+                Err(e) => Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                    message: e
+                })),
+            })
+            .transpose()?;
+
         let key = key_store.key.clone().into_inner();
         let key_manager_state = state.into();
 
@@ -4176,9 +4211,7 @@ impl ProfileUpdateBridge for api::ProfileUpdate {
                 is_click_to_pay_enabled: self.is_click_to_pay_enabled,
                 authentication_product_ids: self.authentication_product_ids,
                 three_ds_decision_manager_config: None,
-                card_testing_guard_config: self
-                    .card_testing_guard_config
-                    .map(ForeignInto::foreign_into),
+                card_testing_guard_config,
                 card_testing_secret_key,
                 is_debit_routing_enabled: self.is_debit_routing_enabled,
                 merchant_business_country: self.merchant_business_country,
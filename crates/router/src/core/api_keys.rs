@@ -12,7 +12,10 @@ use crate::{
     db::domain,
     routes::{metrics, SessionState},
     services::{authentication, ApplicationResponse},
-    types::{api, storage, transformers::ForeignInto},
+    types::{
+        api, storage,
+        transformers::{ForeignFrom, ForeignInto},
+    },
 };
 
 #[cfg(feature = "email")]
@@ -119,6 +122,8 @@ pub async fn create_api_key(
 
     let merchant_id = key_store.merchant_id.clone();
 
+    validate_create_api_key_request(&state, &api_key, &key_store).await?;
+
     let hash_key = api_key_config.get_hash_key()?;
     let plaintext_api_key = PlaintextApiKey::new(consts::API_KEY_LENGTH);
     let api_key = storage::ApiKeyNew {
@@ -131,6 +136,11 @@ pub async fn create_api_key(
         created_at: date_time::now(),
         expires_at: api_key.expiration.into(),
         last_used: None,
+        profile_id: api_key.profile_id,
+        scopes: api_key.scopes.map(|scopes| {
+            storage::ApiKeyScopes(scopes.into_iter().map(ForeignFrom::foreign_from).collect())
+        }),
+        ip_allowlist: api_key.ip_allowlist.map(storage::ApiKeyIpAllowlist),
     };
 
     let api_key = store
@@ -188,6 +198,55 @@ pub async fn create_api_key(
     ))
 }
 
+async fn validate_create_api_key_request(
+    state: &SessionState,
+    api_key: &api::CreateApiKeyRequest,
+    key_store: &domain::MerchantKeyStore,
+) -> errors::RouterResult<()> {
+    if let Some(profile_id) = &api_key.profile_id {
+        state
+            .store
+            .find_business_profile_by_merchant_id_profile_id(
+                key_store,
+                &key_store.merchant_id,
+                profile_id,
+            )
+            .await
+            .to_not_found_response(errors::ApiErrorResponse::ProfileNotFound {
+                id: profile_id.get_string_repr().to_owned(),
+            })?;
+    }
+
+    if api_key
+        .scopes
+        .as_ref()
+        .is_some_and(|scopes| scopes.is_empty())
+    {
+        return Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: "`scopes` must contain at least one resource".to_string(),
+        }));
+    }
+
+    match api_key.ip_allowlist.as_deref() {
+        Some([]) => Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: "`ip_allowlist` must contain at least one IP address or CIDR range"
+                .to_string(),
+        })),
+        Some(ip_allowlist) => ip_allowlist.iter().try_for_each(|entry| {
+            authentication::parse_ip_allowlist_entry(entry)
+                .map(|_| ())
+                .ok_or_else(|| {
+                    report!(errors::ApiErrorResponse::InvalidRequestData {
+                        message: format!(
+                            "`{entry}` in `ip_allowlist` is not a valid IP address or CIDR range"
+                        ),
+                    })
+                })
+        }),
+        None => Ok(()),
+    }
+}
+
 // Add api_key_expiry task to the process_tracker table.
 // Construct ProcessTrackerNew struct with all required fields, and schedule the first email.
 // After first email has been sent, update the schedule_time based on retry_count in execute_workflow().
@@ -1,13 +1,16 @@
 pub mod transformers;
 pub mod utils;
 
+use actix_multipart::form::{bytes::Bytes, MultipartForm};
 use api_models::blocklist as api_blocklist;
+use error_stack::ResultExt;
+use router_env::{instrument, logger, tracing};
 
 use crate::{
     core::errors::{self, RouterResponse},
     routes::SessionState,
     services,
-    types::domain,
+    types::{domain, transformers::ForeignInto},
 };
 
 pub async fn add_entry_to_blocklist(
@@ -15,13 +18,30 @@ pub async fn add_entry_to_blocklist(
     platform: domain::Platform,
     body: api_blocklist::AddToBlocklistRequest,
 ) -> RouterResponse<api_blocklist::AddToBlocklistResponse> {
-    utils::insert_entry_into_blocklist(
+    let expires_at = utils::get_blocklist_entry_expiry(
+        body.expires_at,
+        body.ttl_in_secs,
+        common_utils::date_time::now(),
+    )?;
+
+    let response = utils::insert_entry_into_blocklist(
         &state,
-        platform.get_processor().get_account().get_id(),
-        body,
+        platform.get_processor(),
+        body.entry,
+        expires_at,
     )
-    .await
-    .map(services::ApplicationResponse::Json)
+    .await?;
+
+    if let Some(expires_at) = response.expires_at {
+        utils::add_or_update_blocklist_purge_task(
+            &state,
+            platform.get_processor().get_account().get_id(),
+            expires_at,
+        )
+        .await?;
+    }
+
+    Ok(services::ApplicationResponse::Json(response))
 }
 
 pub async fn remove_entry_from_blocklist(
@@ -65,3 +85,218 @@ pub async fn toggle_blocklist_guard(
     .await
     .map(services::ApplicationResponse::Json)
 }
+
+#[derive(Debug, MultipartForm)]
+pub struct BlocklistImportForm {
+    #[multipart(limit = "1MB")]
+    pub file: Bytes,
+}
+
+fn parse_blocklist_csv(data: &[u8]) -> csv::Result<Vec<api_blocklist::BlocklistRecord>> {
+    let mut csv_reader = csv::Reader::from_reader(data);
+    let mut records = Vec::new();
+    let mut id_counter = 0;
+    for result in csv_reader.deserialize() {
+        let mut record: api_blocklist::BlocklistRecord = result?;
+        id_counter += 1;
+        record.line_number = Some(id_counter);
+        records.push(record);
+    }
+    Ok(records)
+}
+
+pub fn get_blocklist_records(
+    form: BlocklistImportForm,
+) -> Result<Vec<api_blocklist::BlocklistRecord>, errors::ApiErrorResponse> {
+    parse_blocklist_csv(&form.file.data).map_err(|e| errors::ApiErrorResponse::PreconditionFailed {
+        message: e.to_string(),
+    })
+}
+
+#[instrument(skip_all)]
+pub async fn import_blocklist_entries(
+    state: SessionState,
+    platform: domain::Platform,
+    records: Vec<api_blocklist::BlocklistRecord>,
+) -> RouterResponse<Vec<api_blocklist::BlocklistImportResponse>> {
+    let merchant_id = platform.get_processor().get_account().get_id();
+    let now = common_utils::date_time::now();
+    let mut earliest_expiry: Option<time::PrimitiveDateTime> = None;
+    let mut result = Vec::with_capacity(records.len());
+
+    for record in records {
+        let response =
+            match utils::get_blocklist_entry_expiry(record.expires_at, record.ttl_in_secs, now) {
+                Ok(expires_at) => utils::insert_entry_into_blocklist(
+                    &state,
+                    platform.get_processor(),
+                    record.get_blocklist_request(),
+                    expires_at,
+                )
+                .await
+                .map_err(|error| error.current_context().to_string()),
+                Err(error) => Err(error.current_context().to_string()),
+            };
+
+        if let Ok(api_blocklist::BlocklistResponse {
+            expires_at: Some(expires_at),
+            ..
+        }) = &response
+        {
+            earliest_expiry =
+                Some(earliest_expiry.map_or(*expires_at, |earliest| earliest.min(*expires_at)));
+        }
+
+        result.push(api_blocklist::BlocklistImportResponse::from((
+            response, record,
+        )));
+    }
+
+    if let Some(earliest_expiry) = earliest_expiry {
+        utils::add_or_update_blocklist_purge_task(&state, merchant_id, earliest_expiry).await?;
+    }
+
+    Ok(services::ApplicationResponse::Json(result))
+}
+
+#[instrument(skip_all)]
+pub async fn export_blocklist_entries(
+    state: SessionState,
+    platform: domain::Platform,
+    query: api_blocklist::ExportBlocklistQuery,
+) -> RouterResponse<Vec<u8>> {
+    let now = common_utils::date_time::now();
+    let key_store = platform.get_processor().get_key_store();
+    let entries = state
+        .store
+        .list_blocklist_entries_by_merchant_id(platform.get_processor().get_account().get_id())
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("failed to list blocklist entries")?;
+
+    let mut csv_writer = csv::Writer::from_writer(Vec::new());
+    for entry in entries.into_iter().filter(|entry| {
+        !entry.is_expired_at(now)
+            && query
+                .data_kind
+                .is_none_or(|data_kind| data_kind == entry.data_kind)
+    }) {
+        // customer and device attributes are blocked by their fingerprint, the exported value is
+        // the attribute itself so that the file can be imported again
+        let Some(data) = utils::get_blocklist_entry_value(&state, key_store, &entry).await? else {
+            logger::error!(
+                fingerprint_id = %entry.fingerprint_id,
+                "blocklisted value not found, skipping the entry from export"
+            );
+            continue;
+        };
+        let record = api_blocklist::BlocklistRecord {
+            data,
+            ..entry.foreign_into()
+        };
+        csv_writer
+            .serialize(record)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("failed to serialize blocklist entry")?;
+    }
+
+    let data = csv_writer
+        .into_inner()
+        .map_err(|error| error.into_error())
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("failed to write blocklist entries")?;
+
+    Ok(services::ApplicationResponse::FileData((
+        data,
+        mime::TEXT_CSV,
+    )))
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use common_enums::BlocklistDataKind;
+    use time::macros::datetime;
+
+    use super::{parse_blocklist_csv, utils};
+
+    #[test]
+    fn test_parse_blocklist_csv() {
+        let data = b"data_kind,data,ttl_in_secs,expires_at
+card_bin,424242,3600,
+payment_method,fingerprint_1,,2030-01-01T00:00:00Z
+email,someone@example.com,,
+";
+
+        let records = parse_blocklist_csv(data).unwrap();
+
+        assert_eq!(records.len(), 3);
+        let card_bin = records.first().unwrap();
+        assert_eq!(card_bin.data_kind, BlocklistDataKind::CardBin);
+        assert_eq!(card_bin.data, "424242");
+        assert_eq!(card_bin.ttl_in_secs, Some(3600));
+        assert_eq!(card_bin.expires_at, None);
+        assert_eq!(card_bin.line_number, Some(1));
+
+        let fingerprint = records.get(1).unwrap();
+        assert_eq!(fingerprint.data_kind, BlocklistDataKind::PaymentMethod);
+        assert_eq!(fingerprint.ttl_in_secs, None);
+        assert_eq!(fingerprint.expires_at, Some(datetime!(2030-01-01 00:00)));
+        assert_eq!(fingerprint.line_number, Some(2));
+
+        let email = records.get(2).unwrap();
+        assert_eq!(email.data_kind, BlocklistDataKind::Email);
+        assert_eq!(email.expires_at, None);
+        assert_eq!(email.line_number, Some(3));
+    }
+
+    #[test]
+    fn test_parse_blocklist_csv_without_expiry_columns() {
+        let records = parse_blocklist_csv(b"data_kind,data\nextended_card_bin,42424242\n").unwrap();
+
+        assert_eq!(records.len(), 1);
+        let record = records.first().unwrap();
+        assert_eq!(record.data_kind, BlocklistDataKind::ExtendedCardBin);
+        assert_eq!(record.ttl_in_secs, None);
+        assert_eq!(record.expires_at, None);
+    }
+
+    #[test]
+    fn test_parse_blocklist_csv_rejects_invalid_records() {
+        assert!(parse_blocklist_csv(b"data_kind,data\nunknown_kind,424242\n").is_err());
+        assert!(parse_blocklist_csv(b"data_kind,data,ttl_in_secs\ncard_bin,424242,-1\n").is_err());
+        assert!(parse_blocklist_csv(b"data\n424242\n").is_err());
+        assert!(parse_blocklist_csv(b"").unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_get_blocklist_entry_expiry() {
+        let now = datetime!(2024-01-01 00:00);
+
+        assert_eq!(
+            utils::get_blocklist_entry_expiry(None, None, now).unwrap(),
+            None
+        );
+        assert_eq!(
+            utils::get_blocklist_entry_expiry(None, Some(60), now).unwrap(),
+            Some(datetime!(2024-01-01 00:01))
+        );
+        assert_eq!(
+            utils::get_blocklist_entry_expiry(Some(datetime!(2024-02-01 00:00)), None, now)
+                .unwrap(),
+            Some(datetime!(2024-02-01 00:00))
+        );
+
+        // both the expiry time and the time to live cannot be provided
+        assert!(utils::get_blocklist_entry_expiry(
+            Some(datetime!(2024-02-01 00:00)),
+            Some(60),
+            now
+        )
+        .is_err());
+        // the entry must expire in the future
+        assert!(utils::get_blocklist_entry_expiry(Some(now), None, now).is_err());
+        assert!(utils::get_blocklist_entry_expiry(None, Some(0), now).is_err());
+    }
+}
@@ -20,6 +20,19 @@ impl ForeignFrom<storage::Blocklist> for blocklist::AddToBlocklistResponse {
             fingerprint_id: from.fingerprint_id,
             data_kind: from.data_kind,
             created_at: from.created_at,
+            expires_at: from.expires_at,
+        }
+    }
+}
+
+impl ForeignFrom<storage::Blocklist> for blocklist::BlocklistRecord {
+    fn foreign_from(from: storage::Blocklist) -> Self {
+        Self {
+            data_kind: from.data_kind,
+            data: from.fingerprint_id,
+            ttl_in_secs: None,
+            expires_at: from.expires_at,
+            line_number: None,
         }
     }
 }
@@ -1,9 +1,19 @@
+use std::str::FromStr;
+
 use api_models::blocklist as api_blocklist;
+use base64::Engine;
 use common_enums::MerchantDecision;
-use common_utils::errors::CustomResult;
+use common_utils::{
+    crypto::SignMessage, errors::CustomResult, ext_traits::ValueExt, pii, type_name,
+    types::keymanager::Identifier,
+};
 use diesel_models::configs;
 use error_stack::ResultExt;
-use masking::StrongSecret;
+use hyperswitch_domain_models::{
+    payments::payment_intent::CustomerData,
+    type_encryption::{crypto_operation, CryptoOperation},
+};
+use masking::{ExposeInterface, PeekInterface, Secret, StrongSecret};
 
 use super::{errors, transformers::generate_fingerprint, SessionState};
 use crate::{
@@ -13,16 +23,139 @@ use crate::{
         payments::PaymentData,
     },
     logger,
-    types::{domain, storage, transformers::ForeignInto},
+    types::{self, domain, storage, transformers::ForeignInto},
     utils,
 };
 
+/// Customer and device attributes of a payment, which are matched against the blocklist in
+/// addition to the payment method data.
+#[derive(Debug, Default)]
+pub struct BlocklistPaymentAttributes {
+    pub emails: Vec<pii::Email>,
+    pub ip_address: Option<std::net::IpAddr>,
+    pub customer_id: Option<String>,
+    pub device_fingerprint: Option<String>,
+}
+
+impl BlocklistPaymentAttributes {
+    pub fn new(
+        payment_intent: &storage::PaymentIntent,
+        billing_email: Option<pii::Email>,
+        browser_info: Option<types::BrowserInformation>,
+    ) -> Self {
+        let customer_email = payment_intent
+            .customer_details
+            .as_ref()
+            .and_then(|customer_details| {
+                customer_details
+                    .clone()
+                    .into_inner()
+                    .expose()
+                    .parse_value::<CustomerData>("CustomerData")
+                    .map_err(|error| {
+                        logger::error!(?error, "failed to parse customer details for blocklist");
+                    })
+                    .ok()
+            })
+            .and_then(|customer_data| customer_data.email);
+
+        let mut emails: Vec<pii::Email> = customer_email.into_iter().collect();
+        if let Some(billing_email) = billing_email {
+            if !emails.contains(&billing_email) {
+                emails.push(billing_email);
+            }
+        }
+
+        Self {
+            emails,
+            ip_address: browser_info
+                .as_ref()
+                .and_then(|browser_info| browser_info.ip_address),
+            customer_id: payment_intent
+                .customer_id
+                .as_ref()
+                .map(|customer_id| customer_id.get_string_repr().to_owned()),
+            device_fingerprint: browser_info
+                .and_then(|browser_info| browser_info.device_fingerprint),
+        }
+    }
+
+    fn get_blocklist_attributes(&self) -> Vec<(common_enums::BlocklistDataKind, String)> {
+        self.emails
+            .iter()
+            .map(|email| {
+                (
+                    common_enums::BlocklistDataKind::Email,
+                    email.peek().to_owned(),
+                )
+            })
+            .chain(self.ip_address.map(|ip_address| {
+                (
+                    common_enums::BlocklistDataKind::IpAddress,
+                    ip_address.to_string(),
+                )
+            }))
+            .chain(
+                self.customer_id
+                    .clone()
+                    .map(|customer_id| (common_enums::BlocklistDataKind::CustomerId, customer_id)),
+            )
+            .chain(self.device_fingerprint.clone().map(|device_fingerprint| {
+                (
+                    common_enums::BlocklistDataKind::DeviceFingerprint,
+                    device_fingerprint,
+                )
+            }))
+            .collect()
+    }
+}
+
 pub async fn delete_entry_from_blocklist(
     state: &SessionState,
     merchant_id: &common_utils::id_type::MerchantId,
     request: api_blocklist::DeleteFromBlocklistRequest,
 ) -> RouterResult<api_blocklist::DeleteFromBlocklistResponse> {
     let blocklist_entry = match request {
+        api_blocklist::DeleteFromBlocklistRequest::Email(email) => {
+            delete_attribute_blocklist_entry(
+                state,
+                merchant_id,
+                common_enums::BlocklistDataKind::Email,
+                &email,
+            )
+            .await?
+        }
+
+        api_blocklist::DeleteFromBlocklistRequest::IpAddress(ip_address) => {
+            delete_attribute_blocklist_entry(
+                state,
+                merchant_id,
+                common_enums::BlocklistDataKind::IpAddress,
+                &ip_address,
+            )
+            .await?
+        }
+
+        api_blocklist::DeleteFromBlocklistRequest::CustomerId(customer_id) => {
+            delete_attribute_blocklist_entry(
+                state,
+                merchant_id,
+                common_enums::BlocklistDataKind::CustomerId,
+                &customer_id,
+            )
+            .await?
+        }
+
+        api_blocklist::DeleteFromBlocklistRequest::DeviceFingerprint(device_fingerprint) => {
+            delete_attribute_blocklist_entry(
+                state,
+                merchant_id,
+                common_enums::BlocklistDataKind::DeviceFingerprint,
+                &device_fingerprint,
+            )
+            .await?
+        }
+
         api_blocklist::DeleteFromBlocklistRequest::CardBin(bin) => {
             delete_card_bin_blocklist_entry(state, &bin, merchant_id).await?
         }
@@ -131,41 +264,140 @@ fn validate_extended_card_bin(bin: &str) -> RouterResult<()> {
     }
 }
 
+/// Validates and normalizes the value of a customer or device attribute, so that the same
+/// attribute always results in the same blocklist fingerprint.
+fn normalize_blocklist_attribute(
+    data_kind: common_enums::BlocklistDataKind,
+    value: &str,
+) -> RouterResult<String> {
+    let value = value.trim();
+    let (normalized_value, expected_format) = match data_kind {
+        common_enums::BlocklistDataKind::Email => (
+            pii::Email::from_str(value)
+                .ok()
+                .map(|_| value.to_lowercase()),
+            "a valid email address",
+        ),
+        common_enums::BlocklistDataKind::IpAddress => (
+            value
+                .parse::<std::net::IpAddr>()
+                .ok()
+                .map(|ip_address| ip_address.to_string()),
+            "a valid IPv4 or IPv6 address",
+        ),
+        common_enums::BlocklistDataKind::CustomerId
+        | common_enums::BlocklistDataKind::DeviceFingerprint => (
+            (!value.is_empty()).then(|| value.to_owned()),
+            "a non-empty string",
+        ),
+        common_enums::BlocklistDataKind::PaymentMethod
+        | common_enums::BlocklistDataKind::CardBin
+        | common_enums::BlocklistDataKind::ExtendedCardBin => {
+            Err(errors::ApiErrorResponse::InternalServerError).attach_printable_lazy(|| {
+                format!("{data_kind} is not a blocklist attribute kind")
+            })?
+        }
+    };
+
+    normalized_value.ok_or_else(|| {
+        errors::ApiErrorResponse::InvalidDataFormat {
+            field_name: "data".to_string(),
+            expected_format: expected_format.to_string(),
+        }
+        .into()
+    })
+}
+
+/// Generates the fingerprint with which a customer or device attribute is stored in the
+/// blocklist, as the hex encoded HMAC-SHA256 of the data kind and the normalized value, keyed by
+/// the fingerprint secret of the merchant.
+fn generate_attribute_fingerprint(
+    data_kind: common_enums::BlocklistDataKind,
+    normalized_value: &str,
+    merchant_fingerprint_secret: &str,
+) -> RouterResult<String> {
+    common_utils::crypto::HmacSha256
+        .sign_message(
+            merchant_fingerprint_secret.as_bytes(),
+            format!("{data_kind}:{normalized_value}").as_bytes(),
+        )
+        .map(hex::encode)
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("failed to generate blocklist attribute fingerprint")
+}
+
+/// Computes the expiry of a blocklist entry from either the expiry time or the time to live of
+/// the entry, at most one of which can be provided.
+pub fn get_blocklist_entry_expiry(
+    expires_at: Option<time::PrimitiveDateTime>,
+    ttl_in_secs: Option<u32>,
+    now: time::PrimitiveDateTime,
+) -> RouterResult<Option<time::PrimitiveDateTime>> {
+    let expires_at = match (expires_at, ttl_in_secs) {
+        (Some(_), Some(_)) => Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: "only one of `expires_at` and `ttl_in_secs` can be provided".to_string(),
+        })?,
+        (expires_at, ttl_in_secs) => expires_at
+            .or(ttl_in_secs
+                .map(|ttl_in_secs| now + time::Duration::seconds(i64::from(ttl_in_secs)))),
+    };
+
+    utils::when(
+        expires_at.is_some_and(|expires_at| expires_at <= now),
+        || {
+            Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: "the entry must expire in the future".to_string(),
+            })
+        },
+    )?;
+
+    Ok(expires_at)
+}
+
 pub async fn insert_entry_into_blocklist(
     state: &SessionState,
-    merchant_id: &common_utils::id_type::MerchantId,
-    to_block: api_blocklist::AddToBlocklistRequest,
+    processor: &domain::Processor,
+    to_block: api_blocklist::BlocklistRequest,
+    expires_at: Option<time::PrimitiveDateTime>,
 ) -> RouterResult<api_blocklist::AddToBlocklistResponse> {
+    let merchant_id = processor.get_account().get_id();
     let blocklist_entry = match &to_block {
-        api_blocklist::AddToBlocklistRequest::CardBin(bin) => {
+        api_blocklist::BlocklistRequest::CardBin(bin) => {
             validate_card_bin(bin)?;
             duplicate_check_insert_bin(
                 bin,
                 state,
                 merchant_id,
                 common_enums::BlocklistDataKind::CardBin,
+                expires_at,
             )
             .await?
         }
 
-        api_blocklist::AddToBlocklistRequest::ExtendedCardBin(bin) => {
+        api_blocklist::BlocklistRequest::ExtendedCardBin(bin) => {
             validate_extended_card_bin(bin)?;
             duplicate_check_insert_bin(
                 bin,
                 state,
                 merchant_id,
                 common_enums::BlocklistDataKind::ExtendedCardBin,
+                expires_at,
             )
             .await?
         }
 
-        api_blocklist::AddToBlocklistRequest::Fingerprint(fingerprint_id) => {
+        api_blocklist::BlocklistRequest::Fingerprint(fingerprint_id) => {
             let blocklist_entry_result = state
                 .store
                 .find_blocklist_entry_by_merchant_id_fingerprint_id(merchant_id, fingerprint_id)
                 .await;
 
             match blocklist_entry_result {
+                // an expired entry which has not been purged yet is replaced by the new entry
+                Ok(entry) if entry.is_expired_at(common_utils::date_time::now()) => {
+                    delete_expired_blocklist_entry(state, merchant_id, &entry).await?;
+                }
+
                 Ok(_) => {
                     return Err(errors::ApiErrorResponse::PreconditionFailed {
                         message: "data associated with the given fingerprint is already blocked"
@@ -191,15 +423,139 @@ pub async fn insert_entry_into_blocklist(
                     data_kind: api_models::enums::enums::BlocklistDataKind::PaymentMethod,
                     metadata: None,
                     created_at: common_utils::date_time::now(),
+                    expires_at,
                 })
                 .await
                 .change_context(errors::ApiErrorResponse::InternalServerError)
                 .attach_printable("failed to add fingerprint to blocklist")?
         }
+
+        api_blocklist::BlocklistRequest::Email(email) => {
+            insert_attribute_into_blocklist(
+                state,
+                processor,
+                common_enums::BlocklistDataKind::Email,
+                email,
+                expires_at,
+            )
+            .await?
+        }
+
+        api_blocklist::BlocklistRequest::IpAddress(ip_address) => {
+            insert_attribute_into_blocklist(
+                state,
+                processor,
+                common_enums::BlocklistDataKind::IpAddress,
+                ip_address,
+                expires_at,
+            )
+            .await?
+        }
+
+        api_blocklist::BlocklistRequest::CustomerId(customer_id) => {
+            insert_attribute_into_blocklist(
+                state,
+                processor,
+                common_enums::BlocklistDataKind::CustomerId,
+                customer_id,
+                expires_at,
+            )
+            .await?
+        }
+
+        api_blocklist::BlocklistRequest::DeviceFingerprint(device_fingerprint) => {
+            insert_attribute_into_blocklist(
+                state,
+                processor,
+                common_enums::BlocklistDataKind::DeviceFingerprint,
+                device_fingerprint,
+                expires_at,
+            )
+            .await?
+        }
     };
     Ok(blocklist_entry.foreign_into())
 }
 
+const BLOCKLIST_PURGE_TASK: &str = "BLOCKLIST_PURGE";
+const BLOCKLIST_PURGE_TAG: &str = "BLOCKLIST";
+
+fn generate_task_id_for_blocklist_purge_workflow(
+    merchant_id: &common_utils::id_type::MerchantId,
+) -> String {
+    format!(
+        "{}_{BLOCKLIST_PURGE_TASK}_{}",
+        storage::ProcessTrackerRunner::BlocklistPurgeWorkflow,
+        merchant_id.get_string_repr()
+    )
+}
+
+/// Ensures that the blocklist purge task of the merchant is scheduled to run no later than the
+/// specified expiry time. A single task is maintained per merchant, which reschedules itself for
+/// the next expiring entry after purging the expired entries.
+pub async fn add_or_update_blocklist_purge_task(
+    state: &SessionState,
+    merchant_id: &common_utils::id_type::MerchantId,
+    expires_at: time::PrimitiveDateTime,
+) -> RouterResult<()> {
+    let db = &*state.store;
+    let process_tracker_id = generate_task_id_for_blocklist_purge_workflow(merchant_id);
+
+    let existing_process = db
+        .find_process_by_id(&process_tracker_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to retrieve blocklist purge task from process tracker")?;
+
+    match existing_process {
+        Some(process) => {
+            let is_scheduled_before_expiry = process.status
+                != storage::enums::ProcessTrackerStatus::Finish
+                && process
+                    .schedule_time
+                    .is_some_and(|schedule_time| schedule_time <= expires_at);
+
+            if !is_scheduled_before_expiry {
+                db.as_scheduler()
+                    .reset_process(process, expires_at)
+                    .await
+                    .change_context(errors::ApiErrorResponse::InternalServerError)
+                    .attach_printable("Failed to reschedule blocklist purge task")?;
+            }
+        }
+        None => {
+            let tracking_data = storage::BlocklistPurgeTrackingData {
+                merchant_id: merchant_id.to_owned(),
+            };
+            let process_tracker_entry = storage::ProcessTrackerNew::new(
+                process_tracker_id,
+                BLOCKLIST_PURGE_TASK,
+                storage::ProcessTrackerRunner::BlocklistPurgeWorkflow,
+                [BLOCKLIST_PURGE_TAG],
+                tracking_data,
+                None,
+                expires_at,
+                common_types::consts::API_VERSION,
+                state.conf.application_source,
+            )
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to construct blocklist purge process tracker task")?;
+
+            db.insert_process(process_tracker_entry)
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable_lazy(|| {
+                    format!(
+                        "Failed while inserting blocklist purge task to process_tracker for merchant_id: {}",
+                        merchant_id.get_string_repr()
+                    )
+                })?;
+        }
+    }
+
+    Ok(())
+}
+
 pub async fn get_merchant_fingerprint_secret(
     state: &SessionState,
     merchant_id: &common_utils::id_type::MerchantId,
@@ -239,16 +595,41 @@ async fn duplicate_check_insert_bin(
     state: &SessionState,
     merchant_id: &common_utils::id_type::MerchantId,
     data_kind: common_enums::BlocklistDataKind,
+    expires_at: Option<time::PrimitiveDateTime>,
+) -> RouterResult<storage::Blocklist> {
+    duplicate_check_insert_entry(
+        bin,
+        state,
+        merchant_id,
+        data_kind,
+        expires_at,
+        "provided bin is already blocked",
+    )
+    .await
+}
+
+async fn duplicate_check_insert_entry(
+    fingerprint_id: &str,
+    state: &SessionState,
+    merchant_id: &common_utils::id_type::MerchantId,
+    data_kind: common_enums::BlocklistDataKind,
+    expires_at: Option<time::PrimitiveDateTime>,
+    already_blocked_message: &str,
 ) -> RouterResult<storage::Blocklist> {
     let blocklist_entry_result = state
         .store
-        .find_blocklist_entry_by_merchant_id_fingerprint_id(merchant_id, bin)
+        .find_blocklist_entry_by_merchant_id_fingerprint_id(merchant_id, fingerprint_id)
         .await;
 
     match blocklist_entry_result {
+        // an expired entry which has not been purged yet is replaced by the new entry
+        Ok(entry) if entry.is_expired_at(common_utils::date_time::now()) => {
+            delete_expired_blocklist_entry(state, merchant_id, &entry).await?;
+        }
+
         Ok(_) => {
             return Err(errors::ApiErrorResponse::PreconditionFailed {
-                message: "provided bin is already blocked".to_string(),
+                message: already_blocked_message.to_string(),
             }
             .into());
         }
@@ -266,16 +647,214 @@ async fn duplicate_check_insert_bin(
         .store
         .insert_blocklist_entry(storage::BlocklistNew {
             merchant_id: merchant_id.to_owned(),
-            fingerprint_id: bin.to_string(),
+            fingerprint_id: fingerprint_id.to_string(),
             data_kind,
             metadata: None,
             created_at: common_utils::date_time::now(),
+            expires_at,
         })
         .await
         .change_context(errors::ApiErrorResponse::InternalServerError)
         .attach_printable("error inserting pm blocklist item")
 }
 
+/// Blocks a customer or device attribute. The entry is stored under the fingerprint of the
+/// attribute, while the attribute itself is stored encrypted in the blocklist fingerprint table,
+/// so that it can be retrieved when exporting the blocklist.
+async fn insert_attribute_into_blocklist(
+    state: &SessionState,
+    processor: &domain::Processor,
+    data_kind: common_enums::BlocklistDataKind,
+    value: &str,
+    expires_at: Option<time::PrimitiveDateTime>,
+) -> RouterResult<storage::Blocklist> {
+    let merchant_id = processor.get_account().get_id();
+    let key_store = processor.get_key_store();
+    let normalized_value = normalize_blocklist_attribute(data_kind, value)?;
+    let merchant_fingerprint_secret = get_merchant_fingerprint_secret(state, merchant_id).await?;
+    let fingerprint_id =
+        generate_attribute_fingerprint(data_kind, &normalized_value, &merchant_fingerprint_secret)?;
+
+    let blocklist_entry = duplicate_check_insert_entry(
+        &fingerprint_id,
+        state,
+        merchant_id,
+        data_kind,
+        expires_at,
+        &format!("provided {data_kind} is already blocked"),
+    )
+    .await?;
+
+    let encrypted_value = crypto_operation(
+        &state.into(),
+        type_name!(storage::BlocklistFingerprint),
+        CryptoOperation::Encrypt(Secret::new(normalized_value)),
+        Identifier::Merchant(key_store.merchant_id.clone()),
+        key_store.key.get_inner().peek(),
+    )
+    .await
+    .and_then(|val| val.try_into_operation())
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("failed to encrypt blocklist attribute")?;
+
+    let insert_result = state
+        .store
+        .insert_blocklist_fingerprint_entry(storage::BlocklistFingerprintNew {
+            merchant_id: merchant_id.to_owned(),
+            fingerprint_id,
+            data_kind,
+            encrypted_fingerprint: consts::BASE64_ENGINE.encode(
+                common_utils::encryption::Encryption::from(encrypted_value)
+                    .get_inner()
+                    .peek(),
+            ),
+            created_at: common_utils::date_time::now(),
+        })
+        .await;
+
+    match insert_result {
+        Ok(_) => {}
+        // the attribute is retained from an earlier entry which has expired
+        Err(error) if error.current_context().is_db_unique_violation() => {}
+        Err(error) => Err(error)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("failed to store blocklist attribute")?,
+    }
+
+    Ok(blocklist_entry)
+}
+
+/// Retrieves the blocklisted value of an entry, which is the fingerprint id itself for all data
+/// kinds except customer and device attributes, whose value is decrypted from the blocklist
+/// fingerprint table. Returns `None` if the value of an attribute is no longer available.
+pub async fn get_blocklist_entry_value(
+    state: &SessionState,
+    key_store: &domain::MerchantKeyStore,
+    entry: &storage::Blocklist,
+) -> RouterResult<Option<String>> {
+    match entry.data_kind {
+        common_enums::BlocklistDataKind::PaymentMethod
+        | common_enums::BlocklistDataKind::CardBin
+        | common_enums::BlocklistDataKind::ExtendedCardBin => {
+            return Ok(Some(entry.fingerprint_id.clone()));
+        }
+        common_enums::BlocklistDataKind::Email
+        | common_enums::BlocklistDataKind::IpAddress
+        | common_enums::BlocklistDataKind::CustomerId
+        | common_enums::BlocklistDataKind::DeviceFingerprint => {}
+    }
+
+    let blocklist_fingerprint = match state
+        .store
+        .find_blocklist_fingerprint_by_merchant_id_fingerprint_id(
+            &entry.merchant_id,
+            &entry.fingerprint_id,
+        )
+        .await
+    {
+        Ok(blocklist_fingerprint) => blocklist_fingerprint,
+        Err(error) if error.current_context().is_db_not_found() => return Ok(None),
+        Err(error) => Err(error)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("failed to fetch blocklist attribute")?,
+    };
+
+    let encrypted_value = consts::BASE64_ENGINE
+        .decode(blocklist_fingerprint.encrypted_fingerprint)
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("failed to decode blocklist attribute")?;
+
+    crypto_operation::<String, masking::WithType>(
+        &state.into(),
+        type_name!(storage::BlocklistFingerprint),
+        CryptoOperation::Decrypt(common_utils::encryption::Encryption::new(Secret::new(
+            encrypted_value,
+        ))),
+        Identifier::Merchant(key_store.merchant_id.clone()),
+        key_store.get_key_ring().peek(),
+    )
+    .await
+    .and_then(|val| val.try_into_operation())
+    .map(|value| Some(value.into_inner().expose()))
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("failed to decrypt blocklist attribute")
+}
+
+async fn delete_attribute_blocklist_entry(
+    state: &SessionState,
+    merchant_id: &common_utils::id_type::MerchantId,
+    data_kind: common_enums::BlocklistDataKind,
+    value: &str,
+) -> RouterResult<storage::Blocklist> {
+    let normalized_value = normalize_blocklist_attribute(data_kind, value)?;
+    let merchant_fingerprint_secret = get_merchant_fingerprint_secret(state, merchant_id).await?;
+    let fingerprint_id =
+        generate_attribute_fingerprint(data_kind, &normalized_value, &merchant_fingerprint_secret)?;
+
+    let blocklist_entry = state
+        .store
+        .delete_blocklist_entry_by_merchant_id_fingerprint_id(merchant_id, &fingerprint_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::GenericNotFoundError {
+            message: format!("no blocklist record for the given {data_kind} was found"),
+        })?;
+
+    delete_blocklist_attribute(state, &blocklist_entry)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("failed to delete blocklist attribute")?;
+
+    Ok(blocklist_entry)
+}
+
+async fn delete_expired_blocklist_entry(
+    state: &SessionState,
+    merchant_id: &common_utils::id_type::MerchantId,
+    entry: &storage::Blocklist,
+) -> RouterResult<()> {
+    state
+        .store
+        .delete_blocklist_entry_by_merchant_id_fingerprint_id(merchant_id, &entry.fingerprint_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("unable to delete expired blocklist entry")?;
+
+    delete_blocklist_attribute(state, entry)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("failed to delete blocklist attribute")
+}
+
+/// Deletes the attribute stored in the blocklist fingerprint table for customer and device
+/// attribute entries, once the entry itself has been deleted.
+pub async fn delete_blocklist_attribute(
+    state: &SessionState,
+    entry: &storage::Blocklist,
+) -> CustomResult<(), errors::StorageError> {
+    match entry.data_kind {
+        common_enums::BlocklistDataKind::PaymentMethod
+        | common_enums::BlocklistDataKind::CardBin
+        | common_enums::BlocklistDataKind::ExtendedCardBin => return Ok(()),
+        common_enums::BlocklistDataKind::Email
+        | common_enums::BlocklistDataKind::IpAddress
+        | common_enums::BlocklistDataKind::CustomerId
+        | common_enums::BlocklistDataKind::DeviceFingerprint => {}
+    }
+
+    match state
+        .store
+        .delete_blocklist_fingerprint_by_merchant_id_fingerprint_id(
+            &entry.merchant_id,
+            &entry.fingerprint_id,
+        )
+        .await
+    {
+        Ok(_) => Ok(()),
+        Err(error) if error.current_context().is_db_not_found() => Ok(()),
+        Err(error) => Err(error),
+    }
+}
+
 async fn delete_card_bin_blocklist_entry(
     state: &SessionState,
     bin: &str,
@@ -294,6 +873,7 @@ pub async fn should_payment_be_blocked(
     state: &SessionState,
     processor: &domain::Processor,
     payment_method_data: &Option<domain::PaymentMethodData>,
+    payment_attributes: &BlocklistPaymentAttributes,
 ) -> CustomResult<bool, errors::ApiErrorResponse> {
     let db = &state.store;
     let merchant_id = processor.get_account().get_id();
@@ -340,6 +920,26 @@ pub async fn should_payment_be_blocked(
                 _ => None,
             });
 
+    // Hashed customer and device attributes to check whether or not this payment should be blocked.
+    let attribute_fingerprints = payment_attributes
+        .get_blocklist_attributes()
+        .into_iter()
+        .filter_map(|(data_kind, value)| {
+            normalize_blocklist_attribute(data_kind, &value)
+                .and_then(|normalized_value| {
+                    generate_attribute_fingerprint(
+                        data_kind,
+                        &normalized_value,
+                        &merchant_fingerprint_secret,
+                    )
+                })
+                .map_err(|error| {
+                    logger::error!(?error, %data_kind, "failed to generate attribute fingerprint");
+                })
+                .ok()
+        })
+        .collect::<Vec<_>>();
+
     //validating the payment method.
     let mut blocklist_futures = Vec::new();
     if let Some(card_number_fingerprint) = card_number_fingerprint.as_ref() {
@@ -365,11 +965,21 @@ pub async fn should_payment_be_blocked(
         ));
     }
 
+    for attribute_fingerprint in attribute_fingerprints.iter() {
+        blocklist_futures.push(db.find_blocklist_entry_by_merchant_id_fingerprint_id(
+            merchant_id,
+            attribute_fingerprint,
+        ));
+    }
+
     let blocklist_lookups = futures::future::join_all(blocklist_futures).await;
 
+    let now = common_utils::date_time::now();
     let mut should_payment_be_blocked = false;
     for lookup in blocklist_lookups {
         match lookup {
+            // expired entries are purged asynchronously, and must not block payments until then
+            Ok(entry) if entry.is_expired_at(now) => {}
             Ok(_) => {
                 should_payment_be_blocked = true;
             }
@@ -390,8 +1000,44 @@ where
     F: Send + Clone,
 {
     let db = &state.store;
-    let should_payment_be_blocked =
-        should_payment_be_blocked(state, processor, &payment_data.payment_method_data).await?;
+    #[cfg(feature = "v1")]
+    let browser_info = payment_data
+        .payment_attempt
+        .browser_info
+        .clone()
+        .and_then(|browser_info| {
+            browser_info
+                .parse_value::<types::BrowserInformation>("BrowserInformation")
+                .map_err(|error| {
+                    logger::error!(?error, "failed to parse browser info for blocklist");
+                })
+                .ok()
+        });
+    #[cfg(feature = "v2")]
+    let browser_info = payment_data
+        .payment_attempt
+        .browser_info
+        .clone()
+        .map(types::BrowserInformation::from);
+    let billing_email = payment_data
+        .address
+        .get_payment_method_billing()
+        .and_then(|billing| billing.email.clone())
+        .or_else(|| {
+            payment_data
+                .address
+                .get_payment_billing()
+                .and_then(|billing| billing.email.clone())
+        });
+    let payment_attributes =
+        BlocklistPaymentAttributes::new(&payment_data.payment_intent, billing_email, browser_info);
+    let should_payment_be_blocked = should_payment_be_blocked(
+        state,
+        processor,
+        &payment_data.payment_method_data,
+        &payment_attributes,
+    )
+    .await?;
     if should_payment_be_blocked {
         // Update db for attempt and intent status.
         db.update_payment_intent(
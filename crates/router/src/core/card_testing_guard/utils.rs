@@ -1,3 +1,6 @@
+use std::net::IpAddr;
+
+use common_utils::pii;
 use error_stack::ResultExt;
 use hyperswitch_domain_models::{
     card_testing_guard_data::CardTestingGuardData, router_request_types::BrowserInformation,
@@ -7,24 +10,49 @@ use router_env::logger;
 
 use super::errors;
 use crate::{
+    consts,
     core::{errors::RouterResult, payments::helpers},
-    routes::SessionState,
+    events::card_testing_guard_events::VelocityRuleHitEvent,
+    routes::{app::SessionStateInfo, SessionState},
     services,
     types::domain,
     utils::crypto::{self, SignMessage},
 };
 
+/// The attributes of a card payment by which attempts are grouped when evaluating velocity rules.
+pub struct VelocityRuleAttributes<'a> {
+    pub payment_id: &'a common_utils::id_type::PaymentId,
+    pub email: Option<&'a pii::Email>,
+    /// Whether the evaluated payment attempt is counted towards the limits of the velocity rules.
+    /// Checks which do not result in a payment attempt are evaluated without being recorded.
+    pub record_attempt: bool,
+}
+
 pub async fn validate_card_testing_guard_checks(
     state: &SessionState,
     #[cfg(feature = "v1")] browser_info: Option<&serde_json::Value>,
     #[cfg(feature = "v2")] browser_info: Option<&BrowserInformation>,
     card_number: cards::CardNumber,
     customer_id: &Option<common_utils::id_type::CustomerId>,
+    velocity_rule_attributes: VelocityRuleAttributes<'_>,
     business_profile: &domain::Profile,
 ) -> RouterResult<Option<CardTestingGuardData>> {
     match &business_profile.card_testing_guard_config {
         Some(card_testing_guard_config) => {
-            let fingerprint = generate_fingerprint(card_number, business_profile).await?;
+            let fingerprint = generate_fingerprint(card_number.clone(), business_profile).await?;
+
+            let ip_address = get_ip_address(browser_info)?;
+
+            let should_force_3ds_challenge = evaluate_velocity_rules(
+                state,
+                card_testing_guard_config,
+                ip_address,
+                &card_number,
+                &fingerprint,
+                velocity_rule_attributes,
+                business_profile,
+            )
+            .await?;
 
             let card_testing_guard_expiry = card_testing_guard_config.card_testing_guard_expiry;
 
@@ -33,39 +61,15 @@ pub async fn validate_card_testing_guard_checks(
             let mut customer_id_blocking_cache_key = String::new();
 
             if card_testing_guard_config.is_card_ip_blocking_enabled {
-                if let Some(browser_info) = browser_info {
-                    #[cfg(feature = "v1")]
-                    {
-                        let browser_info =
-                            serde_json::from_value::<BrowserInformation>(browser_info.clone())
-                                .change_context(errors::ApiErrorResponse::InternalServerError)
-                                .attach_printable("could not parse browser_info")?;
-
-                        if let Some(browser_info_ip) = browser_info.ip_address {
-                            card_ip_blocking_cache_key =
-                                helpers::validate_card_ip_blocking_for_business_profile(
-                                    state,
-                                    browser_info_ip,
-                                    fingerprint.clone(),
-                                    card_testing_guard_config,
-                                )
-                                .await?;
-                        }
-                    }
-
-                    #[cfg(feature = "v2")]
-                    {
-                        if let Some(browser_info_ip) = browser_info.ip_address {
-                            card_ip_blocking_cache_key =
-                                helpers::validate_card_ip_blocking_for_business_profile(
-                                    state,
-                                    browser_info_ip,
-                                    fingerprint.clone(),
-                                    card_testing_guard_config,
-                                )
-                                .await?;
-                        }
-                    }
+                if let Some(browser_info_ip) = ip_address {
+                    card_ip_blocking_cache_key =
+                        helpers::validate_card_ip_blocking_for_business_profile(
+                            state,
+                            browser_info_ip,
+                            fingerprint.clone(),
+                            card_testing_guard_config,
+                        )
+                        .await?;
                 }
             }
 
@@ -103,16 +107,160 @@ pub async fn validate_card_testing_guard_checks(
                     .is_customer_id_blocking_enabled,
                 customer_id_blocking_cache_key,
                 card_testing_guard_expiry,
+                should_force_3ds_challenge,
             }))
         }
         None => Ok(None),
     }
 }
 
+#[cfg(feature = "v1")]
+fn get_ip_address(browser_info: Option<&serde_json::Value>) -> RouterResult<Option<IpAddr>> {
+    browser_info
+        .map(|browser_info| {
+            serde_json::from_value::<BrowserInformation>(browser_info.clone())
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("could not parse browser_info")
+        })
+        .transpose()
+        .map(|browser_info| browser_info.and_then(|browser_info| browser_info.ip_address))
+}
+
+#[cfg(feature = "v2")]
+fn get_ip_address(browser_info: Option<&BrowserInformation>) -> RouterResult<Option<IpAddr>> {
+    Ok(browser_info.and_then(|browser_info| browser_info.ip_address))
+}
+
+/// Evaluates the velocity rules of the profile against the attempts made within their sliding
+/// windows. Every rule hit is reported as an analytics event, and the action of the rule is
+/// applied: the payment is blocked for `block` rules, while the returned value indicates whether
+/// a 3DS challenge must be forced for `challenge` rules.
+async fn evaluate_velocity_rules(
+    state: &SessionState,
+    card_testing_guard_config: &diesel_models::business_profile::CardTestingGuardConfig,
+    ip_address: Option<IpAddr>,
+    card_number: &cards::CardNumber,
+    fingerprint: &Secret<String>,
+    velocity_rule_attributes: VelocityRuleAttributes<'_>,
+    business_profile: &domain::Profile,
+) -> RouterResult<bool> {
+    let mut should_force_3ds_challenge = false;
+
+    for velocity_rule in &card_testing_guard_config.velocity_rules {
+        let group_key = get_velocity_rule_group_key(
+            velocity_rule.group_by,
+            ip_address,
+            card_number,
+            fingerprint,
+            velocity_rule_attributes.email,
+            |data| generate_hash(data, business_profile),
+        )?;
+
+        let Some(group_key) = group_key else {
+            continue;
+        };
+
+        let cache_key = format!(
+            "{}_{}_{}_{}",
+            consts::VELOCITY_RULE_CACHE_KEY_PREFIX,
+            business_profile.get_id().get_string_repr(),
+            velocity_rule.name,
+            group_key.peek()
+        );
+
+        let attempt_count = services::card_testing_guard::get_attempt_count_in_sliding_window(
+            state,
+            &cache_key,
+            i64::from(velocity_rule.window_in_mins).saturating_mul(60),
+            velocity_rule_attributes.record_attempt,
+        )
+        .await?;
+
+        if attempt_count <= u64::from(velocity_rule.max_attempts) {
+            continue;
+        }
+
+        logger::info!(
+            rule_name = %velocity_rule.name,
+            action = %velocity_rule.action,
+            attempt_count,
+            "Velocity rule hit"
+        );
+
+        // Hits are reported only for recorded attempts, so that checks which do not result in a
+        // payment attempt are not reported as hits
+        if velocity_rule_attributes.record_attempt {
+            state.event_handler().log_event(&VelocityRuleHitEvent {
+                merchant_id: business_profile.merchant_id.clone(),
+                profile_id: business_profile.get_id().clone(),
+                payment_id: velocity_rule_attributes.payment_id.clone(),
+                rule_name: velocity_rule.name.clone(),
+                group_by: velocity_rule.group_by,
+                action: velocity_rule.action,
+                attempt_count,
+                max_attempts: velocity_rule.max_attempts,
+                window_in_mins: velocity_rule.window_in_mins,
+                created_at: common_utils::date_time::now(),
+            });
+        }
+
+        if apply_velocity_rule_action(velocity_rule.action)? {
+            should_force_3ds_challenge = true;
+        }
+    }
+
+    Ok(should_force_3ds_challenge)
+}
+
+/// Returns the key by which attempts are grouped for a velocity rule, or `None` if the attribute
+/// the rule groups by is not available for the payment. Emails and IP addresses are hashed so that
+/// they are not stored in the cache as is.
+fn get_velocity_rule_group_key(
+    group_by: common_enums::VelocityRuleGroupBy,
+    ip_address: Option<IpAddr>,
+    card_number: &cards::CardNumber,
+    fingerprint: &Secret<String>,
+    email: Option<&pii::Email>,
+    hash: impl Fn(&[u8]) -> RouterResult<Secret<String>>,
+) -> RouterResult<Option<Secret<String>>> {
+    match group_by {
+        common_enums::VelocityRuleGroupBy::Ip => ip_address
+            .map(|ip_address| hash(ip_address.to_string().as_bytes()))
+            .transpose(),
+        common_enums::VelocityRuleGroupBy::Fingerprint => Ok(Some(fingerprint.clone())),
+        common_enums::VelocityRuleGroupBy::Email => email
+            .map(|email| hash(email.peek().to_lowercase().as_bytes()))
+            .transpose(),
+        common_enums::VelocityRuleGroupBy::Bin => {
+            Ok(Some(Secret::new(card_number.get_card_isin())))
+        }
+    }
+}
+
+/// Applies the action of a velocity rule which has been hit. Returns an error if the payment must
+/// be blocked, and otherwise whether a 3DS challenge must be forced.
+fn apply_velocity_rule_action(action: common_enums::VelocityRuleAction) -> RouterResult<bool> {
+    match action {
+        common_enums::VelocityRuleAction::Block => {
+            Err(errors::ApiErrorResponse::PreconditionFailed {
+                message: "Blocked due to suspicious activity".to_string(),
+            })?
+        }
+        common_enums::VelocityRuleAction::Challenge => Ok(true),
+        common_enums::VelocityRuleAction::Flag => Ok(false),
+    }
+}
+
 pub async fn generate_fingerprint(
     card_number: cards::CardNumber,
     business_profile: &domain::Profile,
 ) -> RouterResult<Secret<String>> {
+    generate_hash(card_number.get_card_no().as_bytes(), business_profile)
+}
+
+/// Generates the hex encoded HMAC-SHA512 of the data, keyed by the card testing secret key of the
+/// profile.
+fn generate_hash(data: &[u8], business_profile: &domain::Profile) -> RouterResult<Secret<String>> {
     let card_testing_secret_key = &business_profile.card_testing_secret_key;
 
     match card_testing_secret_key {
@@ -120,7 +268,7 @@ pub async fn generate_fingerprint(
             let card_number_fingerprint = crypto::HmacSha512::sign_message(
                 &crypto::HmacSha512,
                 card_testing_secret_key.get_inner().peek().as_bytes(),
-                card_number.clone().get_card_no().as_bytes(),
+                data,
             )
             .attach_printable("error in pm fingerprint creation")
             .map_or_else(
@@ -188,3 +336,94 @@ pub async fn increment_blocked_count_in_cache(
     }
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use std::str::FromStr;
+
+    use super::*;
+
+    fn identity_hash(data: &[u8]) -> RouterResult<Secret<String>> {
+        Ok(Secret::new(String::from_utf8_lossy(data).into_owned()))
+    }
+
+    fn get_group_key(
+        group_by: common_enums::VelocityRuleGroupBy,
+        ip_address: Option<IpAddr>,
+        email: Option<&pii::Email>,
+    ) -> Option<String> {
+        let card_number = cards::CardNumber::from_str("4242424242424242").unwrap();
+        let fingerprint = Secret::new("fingerprint".to_string());
+
+        get_velocity_rule_group_key(
+            group_by,
+            ip_address,
+            &card_number,
+            &fingerprint,
+            email,
+            identity_hash,
+        )
+        .unwrap()
+        .map(|group_key| group_key.peek().clone())
+    }
+
+    #[test]
+    fn test_velocity_rule_group_key_by_ip() {
+        let ip_address = IpAddr::from_str("127.0.0.1").unwrap();
+
+        assert_eq!(
+            get_group_key(
+                common_enums::VelocityRuleGroupBy::Ip,
+                Some(ip_address),
+                None
+            ),
+            Some("127.0.0.1".to_string())
+        );
+        assert_eq!(
+            get_group_key(common_enums::VelocityRuleGroupBy::Ip, None, None),
+            None
+        );
+    }
+
+    #[test]
+    fn test_velocity_rule_group_key_by_fingerprint() {
+        assert_eq!(
+            get_group_key(common_enums::VelocityRuleGroupBy::Fingerprint, None, None),
+            Some("fingerprint".to_string())
+        );
+    }
+
+    #[test]
+    fn test_velocity_rule_group_key_by_email() {
+        let email = pii::Email::from_str("Guest@Example.com").unwrap();
+
+        assert_eq!(
+            get_group_key(common_enums::VelocityRuleGroupBy::Email, None, Some(&email)),
+            Some("guest@example.com".to_string())
+        );
+        assert_eq!(
+            get_group_key(common_enums::VelocityRuleGroupBy::Email, None, None),
+            None
+        );
+    }
+
+    #[test]
+    fn test_velocity_rule_group_key_by_bin() {
+        assert_eq!(
+            get_group_key(common_enums::VelocityRuleGroupBy::Bin, None, None),
+            Some("424242".to_string())
+        );
+    }
+
+    #[test]
+    fn test_velocity_rule_actions() {
+        assert!(matches!(
+            apply_velocity_rule_action(common_enums::VelocityRuleAction::Block)
+                .map_err(|error| error.current_context().clone()),
+            Err(errors::ApiErrorResponse::PreconditionFailed { .. })
+        ));
+        assert!(apply_velocity_rule_action(common_enums::VelocityRuleAction::Challenge).unwrap());
+        assert!(!apply_velocity_rule_action(common_enums::VelocityRuleAction::Flag).unwrap());
+    }
+}
@@ -1,12 +1,19 @@
-use analytics::errors::AnalyticsError;
-use common_utils::errors::CustomResult;
+use analytics::{errors::AnalyticsError, forex::ReportingExchangeRates};
+use api_models::{
+    analytics::TimeRange,
+    currency::{ForexProvider, ForexRateTableRequest, ForexRateTableResponse},
+};
+use common_utils::{date_time, errors::CustomResult, ext_traits::Encode};
 use currency_conversion::types::ExchangeRates;
-use error_stack::ResultExt;
+use diesel_models::configs;
+use error_stack::{report, ResultExt};
 use router_env::logger;
+use rust_decimal::Decimal;
+use time::PrimitiveDateTime;
 
 use crate::{
     consts::DEFAULT_ANALYTICS_FOREX_RETRY_ATTEMPTS,
-    core::errors::ApiErrorResponse,
+    core::errors::{ApiErrorResponse, StorageErrorExt},
     services::ApplicationResponse,
     utils::currency::{self, convert_currency, get_forex_rates, ForexError as ForexCacheError},
     SessionState,
@@ -14,10 +21,11 @@ use crate::{
 
 pub async fn retrieve_forex(
     state: SessionState,
+    merchant_id: common_utils::id_type::MerchantId,
+    provider: Option<ForexProvider>,
 ) -> CustomResult<ApplicationResponse<currency::FxExchangeRatesCacheEntry>, ApiErrorResponse> {
-    let forex_api = state.conf.forex_api.get_inner();
     Ok(ApplicationResponse::Json(
-        get_forex_rates(&state, forex_api.data_expiration_delay_in_seconds)
+        currency::get_forex_rates_from_provider(&state, &merchant_id, provider)
             .await
             .change_context(ApiErrorResponse::GenericNotFoundError {
                 message: "Unable to fetch forex rates".to_string(),
@@ -27,9 +35,12 @@ pub async fn retrieve_forex(
 
 pub async fn convert_forex(
     state: SessionState,
+    merchant_id: common_utils::id_type::MerchantId,
     amount: i64,
     to_currency: String,
     from_currency: String,
+    provider: Option<ForexProvider>,
+    as_of: Option<PrimitiveDateTime>,
 ) -> CustomResult<
     ApplicationResponse<api_models::currency::CurrencyConversionResponse>,
     ApiErrorResponse,
@@ -37,15 +48,149 @@ pub async fn convert_forex(
     Ok(ApplicationResponse::Json(
         Box::pin(convert_currency(
             state.clone(),
+            &merchant_id,
             amount,
             to_currency,
             from_currency,
+            provider,
+            as_of,
         ))
         .await
-        .change_context(ApiErrorResponse::InternalServerError)?,
+        .map_err(|error| match error.current_context() {
+            ForexCacheError::SnapshotNotFound => {
+                error.change_context(ApiErrorResponse::GenericNotFoundError {
+                    message: "Forex rates not available for the requested time".to_string(),
+                })
+            }
+            _ => error.change_context(ApiErrorResponse::InternalServerError),
+        })?,
     ))
 }
 
+fn validate_forex_rate_table_request(
+    request: &ForexRateTableRequest,
+) -> CustomResult<(), ApiErrorResponse> {
+    if request.rates.is_empty() {
+        return Err(report!(ApiErrorResponse::InvalidRequestData {
+            message: "`rates` must contain at least one currency".to_string(),
+        }));
+    }
+
+    if let Some((currency, _rate)) = request
+        .rates
+        .iter()
+        .find(|(_, rate)| **rate <= Decimal::ZERO)
+    {
+        return Err(report!(ApiErrorResponse::InvalidRequestData {
+            message: format!("rate of {currency} must be greater than zero"),
+        }));
+    }
+
+    if request.markup_percentage < Decimal::ZERO
+        || request.markup_percentage >= Decimal::ONE_HUNDRED
+    {
+        return Err(report!(ApiErrorResponse::InvalidRequestData {
+            message: "`markup_percentage` must be at least 0 and less than 100".to_string(),
+        }));
+    }
+
+    Ok(())
+}
+
+pub async fn upsert_forex_rate_table(
+    state: SessionState,
+    merchant_id: common_utils::id_type::MerchantId,
+    request: ForexRateTableRequest,
+) -> CustomResult<ApplicationResponse<ForexRateTableResponse>, ApiErrorResponse> {
+    validate_forex_rate_table_request(&request)?;
+
+    let rate_table = ForexRateTableResponse {
+        merchant_id: merchant_id.clone(),
+        snapshot_id: common_utils::generate_id_with_default_len("fxsnap"),
+        base_currency: request.base_currency,
+        rates: request.rates,
+        markup_percentage: request.markup_percentage,
+        uploaded_at: date_time::now(),
+    };
+
+    let config = rate_table
+        .encode_to_string_of_json()
+        .change_context(ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to serialize the forex rate table")?;
+
+    let key = merchant_id.get_forex_rate_table_key();
+    match state.store.find_config_by_key_from_db(&key).await {
+        Ok(_config) => {
+            state
+                .store
+                .update_config_by_key(
+                    &key,
+                    configs::ConfigUpdate::Update {
+                        config: Some(config),
+                    },
+                )
+                .await
+                .change_context(ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to update the forex rate table")?;
+        }
+        Err(error) if error.current_context().is_db_not_found() => {
+            state
+                .store
+                .insert_config(configs::ConfigNew { key, config })
+                .await
+                .change_context(ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to insert the forex rate table")?;
+        }
+        Err(error) => {
+            return Err(error
+                .change_context(ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to fetch the forex rate table"));
+        }
+    }
+
+    logger::info!(rate_snapshot_id = %rate_table.snapshot_id, "Forex rate table uploaded");
+
+    Ok(ApplicationResponse::Json(rate_table))
+}
+
+pub async fn retrieve_forex_rate_table(
+    state: SessionState,
+    merchant_id: common_utils::id_type::MerchantId,
+) -> CustomResult<ApplicationResponse<ForexRateTableResponse>, ApiErrorResponse> {
+    let config = state
+        .store
+        .find_config_by_key(&merchant_id.get_forex_rate_table_key())
+        .await
+        .to_not_found_response(ApiErrorResponse::GenericNotFoundError {
+            message: "Forex rate table not found for the merchant".to_string(),
+        })?;
+
+    let rate_table = serde_json::from_str::<ForexRateTableResponse>(&config.config)
+        .change_context(ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to parse the forex rate table")?;
+
+    Ok(ApplicationResponse::Json(rate_table))
+}
+
+pub async fn delete_forex_rate_table(
+    state: SessionState,
+    merchant_id: common_utils::id_type::MerchantId,
+) -> CustomResult<ApplicationResponse<ForexRateTableResponse>, ApiErrorResponse> {
+    let config = state
+        .store
+        .delete_config_by_key(&merchant_id.get_forex_rate_table_key())
+        .await
+        .to_not_found_response(ApiErrorResponse::GenericNotFoundError {
+            message: "Forex rate table not found for the merchant".to_string(),
+        })?;
+
+    let rate_table = serde_json::from_str::<ForexRateTableResponse>(&config.config)
+        .change_context(ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to parse the forex rate table")?;
+
+    Ok(ApplicationResponse::Json(rate_table))
+}
+
 pub async fn get_forex_exchange_rates(
     state: SessionState,
 ) -> CustomResult<ExchangeRates, AnalyticsError> {
@@ -92,3 +237,98 @@ pub async fn get_forex_exchange_rates(
         }
     }
 }
+
+/// Fetches the rates used to report analytics amounts in the reporting currency: the latest rates,
+/// and the snapshots which were valid during the time range. Amounts are converted using the
+/// latest rates if the snapshots cannot be fetched.
+pub async fn get_reporting_exchange_rates(
+    state: SessionState,
+    reporting_currency: common_enums::Currency,
+    time_range: Option<TimeRange>,
+) -> CustomResult<ReportingExchangeRates, AnalyticsError> {
+    let latest = get_forex_exchange_rates(state.clone()).await?;
+
+    let snapshots = match time_range {
+        Some(time_range) => get_forex_rate_snapshots_for_time_range(&state, time_range)
+            .await
+            .map_err(|error| {
+                logger::error!(
+                    ?error,
+                    "Failed to fetch forex rate snapshots, using the latest rates"
+                );
+            })
+            .unwrap_or_default(),
+        None => Vec::new(),
+    };
+
+    Ok(ReportingExchangeRates::new(
+        reporting_currency,
+        latest,
+        snapshots,
+    ))
+}
+
+async fn get_forex_rate_snapshots_for_time_range(
+    state: &SessionState,
+    time_range: TimeRange,
+) -> CustomResult<Vec<(PrimitiveDateTime, ExchangeRates)>, ForexCacheError> {
+    let end_time = time_range.end_time.unwrap_or_else(date_time::now);
+
+    Ok(
+        currency::list_forex_rate_snapshots_valid_within(state, time_range.start_time, end_time)
+            .await?
+            .into_iter()
+            .map(|snapshot| (snapshot.get_created_at(), (*snapshot.data).clone()))
+            .collect(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use std::{collections::HashMap, str::FromStr};
+
+    use api_models::enums::Currency;
+
+    use super::*;
+
+    fn rate_table_request(rates: &[(Currency, &str)], markup: &str) -> ForexRateTableRequest {
+        ForexRateTableRequest {
+            base_currency: Currency::USD,
+            rates: rates
+                .iter()
+                .map(|(currency, rate)| (*currency, Decimal::from_str(rate).unwrap()))
+                .collect::<HashMap<_, _>>(),
+            markup_percentage: Decimal::from_str(markup).unwrap(),
+        }
+    }
+
+    #[test]
+    fn test_valid_forex_rate_table_request() {
+        let request =
+            rate_table_request(&[(Currency::EUR, "0.92"), (Currency::INR, "83.1")], "2.5");
+        assert!(validate_forex_rate_table_request(&request).is_ok());
+
+        let request = rate_table_request(&[(Currency::EUR, "0.92")], "0");
+        assert!(validate_forex_rate_table_request(&request).is_ok());
+    }
+
+    #[test]
+    fn test_invalid_forex_rate_table_request() {
+        let invalid_requests = [
+            rate_table_request(&[], "0"),
+            rate_table_request(&[(Currency::EUR, "0")], "0"),
+            rate_table_request(&[(Currency::EUR, "-0.92")], "0"),
+            rate_table_request(&[(Currency::EUR, "0.92")], "-1"),
+            rate_table_request(&[(Currency::EUR, "0.92")], "100"),
+        ];
+
+        for request in invalid_requests {
+            let error = validate_forex_rate_table_request(&request).unwrap_err();
+            assert!(matches!(
+                error.current_context(),
+                ApiErrorResponse::InvalidRequestData { .. }
+            ));
+        }
+    }
+}
@@ -0,0 +1,482 @@
+use common_utils::{
+    id_type, pii,
+    types::{AmountConvertor, MinorUnit, StringMajorUnitForCore},
+};
+use error_stack::ResultExt;
+use external_services::email::EmailData;
+use hyperswitch_domain_models::payments::payment_intent::CustomerData;
+use masking::{ExposeInterface, PeekInterface};
+use router_env::logger;
+use time::PrimitiveDateTime;
+
+use crate::{
+    core::errors::{self, RouterResult, StorageErrorExt},
+    db::StorageInterface,
+    routes::SessionState,
+    services::{
+        api::generic_link_response::context::get_language,
+        email::types::{
+            CustomerEmailTheme, PaymentLinkReminder, PaymentReceipt, RefundConfirmation,
+        },
+    },
+    types::{
+        api, domain,
+        storage::{self, enums},
+    },
+    utils::{user as user_utils, OptionExt, ValueExt},
+};
+
+const CUSTOMER_EMAIL_TASK: &str = "CUSTOMER_EMAIL";
+const CUSTOMER_EMAIL_TAG: &str = "CUSTOMER_EMAIL";
+
+/// Customer email configuration of a profile, stored as JSON in the configs table. No emails are
+/// sent to the customers of a profile unless enabled in its configuration.
+#[derive(Debug, Clone, Default, serde::Deserialize)]
+#[serde(default)]
+pub struct CustomerEmailConfig {
+    /// The kinds of emails sent to customers
+    pub enabled_emails: Vec<storage::CustomerEmailType>,
+    /// Locale of the emails, defaults to the locale of the request that triggered the email
+    pub locale: Option<String>,
+    /// Branding of the emails, defaults to the payment link configuration of the profile
+    pub branding: CustomerEmailBranding,
+}
+
+#[derive(Debug, Clone, Default, serde::Deserialize)]
+#[serde(default)]
+pub struct CustomerEmailBranding {
+    pub brand_name: Option<String>,
+    pub logo_url: Option<String>,
+    pub primary_color: Option<String>,
+    pub support_email: Option<String>,
+}
+
+pub async fn get_customer_email_config(
+    db: &dyn StorageInterface,
+    profile_id: &id_type::ProfileId,
+) -> CustomerEmailConfig {
+    let key = profile_id.get_customer_email_config_key();
+
+    db.find_config_by_key_unwrap_or(key.as_str(), Some("{}".to_string()))
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .and_then(|email_config| {
+            serde_json::from_str::<CustomerEmailConfig>(&email_config.config)
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Customer email config parsing failed")
+        })
+        .map_err(|err| {
+            logger::error!(customer_email_config_error=?err);
+        })
+        .ok()
+        .unwrap_or_default()
+}
+
+/// The task is identified by the object the email is sent for, so that an email is sent only once
+/// even if the event triggering it is raised again
+fn generate_task_id_for_customer_email(
+    email_type: storage::CustomerEmailType,
+    merchant_id: &id_type::MerchantId,
+    object_id: &str,
+) -> String {
+    format!(
+        "{}_{CUSTOMER_EMAIL_TASK}_{email_type}_{}_{object_id}",
+        storage::ProcessTrackerRunner::CustomerEmailWorkflow,
+        merchant_id.get_string_repr()
+    )
+}
+
+#[allow(clippy::too_many_arguments)]
+async fn add_customer_email_task(
+    state: &SessionState,
+    email_type: storage::CustomerEmailType,
+    merchant_id: &id_type::MerchantId,
+    profile_id: &id_type::ProfileId,
+    payment_id: &id_type::PaymentId,
+    refund_id: Option<String>,
+    payment_link_id: Option<String>,
+    schedule_time: PrimitiveDateTime,
+) -> RouterResult<()> {
+    if !state.conf.customer_email.enabled {
+        return Ok(());
+    }
+
+    let db = &*state.store;
+    let config = get_customer_email_config(db, profile_id).await;
+    if !config.enabled_emails.contains(&email_type) {
+        return Ok(());
+    }
+
+    let object_id = refund_id
+        .as_deref()
+        .or(payment_link_id.as_deref())
+        .unwrap_or(payment_id.get_string_repr());
+    let process_tracker_id =
+        generate_task_id_for_customer_email(email_type, merchant_id, object_id);
+    let tracking_data = storage::CustomerEmailTrackingData {
+        email_type,
+        merchant_id: merchant_id.clone(),
+        profile_id: profile_id.clone(),
+        payment_id: payment_id.clone(),
+        refund_id,
+        payment_link_id,
+        locale: get_language(config.locale.as_deref().unwrap_or(&state.locale)),
+    };
+    let process_tracker_entry = storage::ProcessTrackerNew::new(
+        process_tracker_id,
+        CUSTOMER_EMAIL_TASK,
+        storage::ProcessTrackerRunner::CustomerEmailWorkflow,
+        [CUSTOMER_EMAIL_TAG],
+        tracking_data,
+        None,
+        schedule_time,
+        common_types::consts::API_VERSION,
+        state.conf.application_source,
+    )
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to construct customer email process tracker task")?;
+
+    match db.insert_process(process_tracker_entry).await {
+        Ok(_) => Ok(()),
+        // The email has already been scheduled for the object
+        Err(error) if error.current_context().is_db_unique_violation() => Ok(()),
+        Err(error) => Err(error)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to insert customer email task to process tracker"),
+    }
+}
+
+/// Schedules the emails sent to the customer for an outgoing webhook event, if the event triggers
+/// one. Failures are logged, as emails must not fail the flow that raised the event.
+pub async fn schedule_customer_email_for_event(
+    state: &SessionState,
+    business_profile: &domain::Profile,
+    event_type: enums::EventType,
+    content: &api::OutgoingWebhookContent,
+) {
+    let (email_type, payment_id, refund_id) = match (event_type, content) {
+        (
+            enums::EventType::PaymentSucceeded,
+            api::OutgoingWebhookContent::PaymentDetails(payment),
+        ) => (
+            storage::CustomerEmailType::PaymentReceipt,
+            &payment.payment_id,
+            None,
+        ),
+        (enums::EventType::RefundSucceeded, api::OutgoingWebhookContent::RefundDetails(refund)) => {
+            (
+                storage::CustomerEmailType::RefundConfirmation,
+                &refund.payment_id,
+                Some(refund.refund_id.clone()),
+            )
+        }
+        _ => return,
+    };
+
+    if let Err(error) = add_customer_email_task(
+        state,
+        email_type,
+        &business_profile.merchant_id,
+        business_profile.get_id(),
+        payment_id,
+        refund_id,
+        None,
+        common_utils::date_time::now(),
+    )
+    .await
+    {
+        logger::error!(?error, ?email_type, "Failed to schedule customer email");
+    }
+}
+
+/// Schedules a reminder to the customer to complete the payment of a payment link, sent the
+/// configured time before the link expires. Failures are logged, as emails must not fail the
+/// creation of the payment link.
+pub async fn schedule_payment_link_reminder(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+    profile_id: &id_type::ProfileId,
+    payment_id: &id_type::PaymentId,
+    payment_link_id: &str,
+    expires_at: PrimitiveDateTime,
+) {
+    let schedule_time = expires_at.saturating_sub(time::Duration::seconds(
+        state
+            .conf
+            .customer_email
+            .payment_link_reminder_before_expiry_in_seconds,
+    ));
+    if schedule_time <= common_utils::date_time::now() {
+        return;
+    }
+
+    if let Err(error) = add_customer_email_task(
+        state,
+        storage::CustomerEmailType::PaymentLinkReminder,
+        merchant_id,
+        profile_id,
+        payment_id,
+        None,
+        Some(payment_link_id.to_owned()),
+        schedule_time,
+    )
+    .await
+    {
+        logger::error!(?error, "Failed to schedule payment link reminder");
+    }
+}
+
+/// Renders and sends the email of a customer email task. Emails which are no longer relevant, or
+/// for which the customer has no email address, are skipped.
+pub async fn send_customer_email(
+    state: &SessionState,
+    tracking_data: &storage::CustomerEmailTrackingData,
+) -> RouterResult<()> {
+    let db = &*state.store;
+    let merchant_id = &tracking_data.merchant_id;
+    let key_store = db
+        .get_merchant_key_store_by_merchant_id(merchant_id, &db.get_master_key().to_vec().into())
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+    let merchant_account = db
+        .find_merchant_account_by_merchant_id(merchant_id, &key_store)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+    let business_profile = db
+        .find_business_profile_by_profile_id(&key_store, &tracking_data.profile_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::ProfileNotFound {
+            id: tracking_data.profile_id.get_string_repr().to_owned(),
+        })?;
+
+    // The email may have been disabled since it was scheduled
+    let config = get_customer_email_config(db, &tracking_data.profile_id).await;
+    if !config.enabled_emails.contains(&tracking_data.email_type) {
+        logger::info!(email_type = ?tracking_data.email_type, "Customer email is disabled");
+        return Ok(());
+    }
+
+    let payment_intent = db
+        .find_payment_intent_by_payment_id_processor_merchant_id(
+            &tracking_data.payment_id,
+            merchant_id,
+            &key_store,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+
+    let Some(recipient_email) = get_customer_email(
+        db,
+        &payment_intent,
+        &key_store,
+        merchant_account.storage_scheme,
+    )
+    .await?
+    else {
+        logger::info!(
+            email_type = ?tracking_data.email_type,
+            "Customer has no email address, skipping customer email"
+        );
+        return Ok(());
+    };
+
+    let theme = get_customer_email_theme(&config.branding, &business_profile, &merchant_account);
+    let locale = tracking_data.locale.clone();
+    let payment_id = tracking_data.payment_id.get_string_repr().to_owned();
+
+    let email: Box<dyn EmailData + Send> = match tracking_data.email_type {
+        storage::CustomerEmailType::PaymentReceipt => {
+            let currency = payment_intent.currency.get_required_value("currency")?;
+            Box::new(PaymentReceipt {
+                recipient_email,
+                locale,
+                theme,
+                payment_id,
+                amount: format_amount(
+                    payment_intent
+                        .amount_captured
+                        .unwrap_or(payment_intent.amount),
+                    currency,
+                )?,
+                description: payment_intent.description.clone(),
+                paid_at: format_date(payment_intent.modified_at)?,
+            })
+        }
+        storage::CustomerEmailType::RefundConfirmation => {
+            let refund_id = tracking_data
+                .refund_id
+                .as_deref()
+                .get_required_value("refund_id")?;
+            let refund = db
+                .find_refund_by_merchant_id_refund_id(
+                    merchant_id,
+                    refund_id,
+                    merchant_account.storage_scheme,
+                )
+                .await
+                .to_not_found_response(errors::ApiErrorResponse::RefundNotFound)?;
+            Box::new(RefundConfirmation {
+                recipient_email,
+                locale,
+                theme,
+                refund_id: refund.refund_id.clone(),
+                payment_id,
+                amount: format_amount(refund.refund_amount, refund.currency)?,
+                refunded_at: format_date(refund.modified_at)?,
+            })
+        }
+        storage::CustomerEmailType::PaymentLinkReminder => {
+            let payment_link_id = tracking_data
+                .payment_link_id
+                .as_deref()
+                .get_required_value("payment_link_id")?;
+            let payment_link = db
+                .find_payment_link_by_payment_link_id(payment_link_id)
+                .await
+                .to_not_found_response(errors::ApiErrorResponse::PaymentLinkNotFound)?;
+            let expires_at = payment_link
+                .fulfilment_time
+                .get_required_value("fulfilment_time")?;
+
+            // The customer may have paid, or the link may have expired, since the reminder was
+            // scheduled
+            if payment_intent.status != enums::IntentStatus::RequiresPaymentMethod
+                || expires_at <= common_utils::date_time::now()
+            {
+                logger::info!(
+                    status = ?payment_intent.status,
+                    "Payment link is no longer awaiting payment, skipping reminder"
+                );
+                return Ok(());
+            }
+
+            let currency = payment_link.currency.get_required_value("currency")?;
+            Box::new(PaymentLinkReminder {
+                recipient_email,
+                locale,
+                theme,
+                payment_id,
+                amount: format_amount(payment_link.amount, currency)?,
+                description: payment_link.description.clone(),
+                link: payment_link.link_to_pay.clone(),
+                expires_at: format_date(expires_at)?,
+            })
+        }
+    };
+
+    state
+        .customer_email_client
+        .clone()
+        .compose_and_send_email(
+            user_utils::get_base_url(state),
+            email,
+            state.conf.proxy.https_url.as_ref(),
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to send customer email")
+}
+
+/// The email address of the customer is taken from the customer details of the payment, falling
+/// back to the customer the payment was made by
+async fn get_customer_email(
+    db: &dyn StorageInterface,
+    payment_intent: &storage::PaymentIntent,
+    key_store: &domain::MerchantKeyStore,
+    storage_scheme: enums::MerchantStorageScheme,
+) -> RouterResult<Option<pii::Email>> {
+    let customer_details_email = payment_intent
+        .customer_details
+        .as_ref()
+        .and_then(|customer_details| {
+            customer_details
+                .clone()
+                .into_inner()
+                .expose()
+                .parse_value::<CustomerData>("CustomerData")
+                .map_err(|error| {
+                    logger::error!(
+                        ?error,
+                        "Failed to parse customer details for customer email"
+                    );
+                })
+                .ok()
+        })
+        .and_then(|customer_data| customer_data.email);
+    if customer_details_email.is_some() {
+        return Ok(customer_details_email);
+    }
+
+    let Some(customer_id) = &payment_intent.customer_id else {
+        return Ok(None);
+    };
+    let customer = db
+        .find_customer_optional_by_customer_id_merchant_id(
+            customer_id,
+            &payment_intent.merchant_id,
+            key_store,
+            storage_scheme,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to find customer for customer email")?;
+
+    Ok(customer
+        .and_then(|customer| customer.email)
+        .map(pii::Email::from))
+}
+
+/// The branding configured for customer emails takes precedence over the payment link
+/// configuration of the profile, which is what customers see when paying
+fn get_customer_email_theme(
+    branding: &CustomerEmailBranding,
+    business_profile: &domain::Profile,
+    merchant_account: &domain::MerchantAccount,
+) -> CustomerEmailTheme {
+    let payment_link_config = business_profile
+        .payment_link_config
+        .as_ref()
+        .and_then(|payment_link_config| payment_link_config.default_config.as_ref());
+
+    CustomerEmailTheme {
+        brand_name: branding
+            .brand_name
+            .clone()
+            .or_else(|| payment_link_config.and_then(|config| config.seller_name.clone()))
+            .or_else(|| {
+                merchant_account
+                    .merchant_name
+                    .as_ref()
+                    .map(|merchant_name| merchant_name.get_inner().peek().clone())
+            })
+            .unwrap_or_else(|| merchant_account.get_id().get_string_repr().to_owned()),
+        logo_url: branding
+            .logo_url
+            .clone()
+            .or_else(|| payment_link_config.and_then(|config| config.logo.clone())),
+        primary_color: branding
+            .primary_color
+            .clone()
+            .or_else(|| payment_link_config.and_then(|config| config.theme.clone()))
+            .unwrap_or_else(|| CustomerEmailTheme::DEFAULT_PRIMARY_COLOR.to_owned()),
+        support_email: branding.support_email.clone(),
+    }
+}
+
+fn format_amount(amount: MinorUnit, currency: enums::Currency) -> RouterResult<String> {
+    StringMajorUnitForCore
+        .convert(amount, currency)
+        .change_context(errors::ApiErrorResponse::AmountConversionFailed {
+            amount_type: "StringMajorUnit",
+        })
+        .map(|amount| format!("{} {currency}", amount.get_amount_as_string()))
+}
+
+fn format_date(date: PrimitiveDateTime) -> RouterResult<String> {
+    date.format(time::macros::format_description!(
+        "[year]-[month]-[day] [hour]:[minute] UTC"
+    ))
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to format date for customer email")
+}
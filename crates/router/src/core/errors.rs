@@ -141,6 +141,8 @@ pub enum WebhooksFlowError {
     MerchantWebhookDetailsNotFound,
     #[error("Merchant does not have a webhook URL configured")]
     MerchantWebhookUrlNotConfigured,
+    #[error("Webhook endpoint is not configured or is not active")]
+    WebhookEndpointNotActive,
     #[error("Webhook event updation failed")]
     WebhookEventUpdationFailed,
     #[error("Outgoing webhook body signing failed")]
@@ -169,6 +171,7 @@ impl WebhooksFlowError {
             Self::MerchantConfigNotFound
             | Self::MerchantWebhookDetailsNotFound
             | Self::MerchantWebhookUrlNotConfigured
+            | Self::WebhookEndpointNotActive
             | Self::OutgoingWebhookResponseEncodingFailed => false,
 
             Self::WebhookEventUpdationFailed
@@ -352,6 +355,8 @@ pub enum RoutingError {
     OpenRouterError(String),
     #[error("Decision engine responded with validation error: {0}")]
     DecisionEngineValidationError(String),
+    #[error("Routing program uses '{0}', which is not supported by the decision engine")]
+    DecisionEngineUnsupportedField(String),
     #[error("Invalid transaction type")]
     InvalidTransactionType,
     #[error("Routing events error: {message}, status code: {status_code}")]
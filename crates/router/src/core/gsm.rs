@@ -1,6 +1,13 @@
-use api_models::gsm as gsm_api_types;
+use std::{
+    collections::HashMap,
+    str::FromStr,
+    sync::{LazyLock, RwLock},
+};
+
+use actix_multipart::form::{bytes::Bytes, MultipartForm};
+use api_models::{enums as api_enums, gsm as gsm_api_types};
 use error_stack::ResultExt;
-use router_env::{instrument, tracing};
+use router_env::{instrument, logger, tracing};
 
 use crate::{
     core::errors::{self, RouterResponse, StorageErrorExt},
@@ -16,6 +23,14 @@ pub async fn create_gsm_rule(
     gsm_rule: gsm_api_types::GsmCreateRequest,
 ) -> RouterResponse<gsm_api_types::GsmResponse> {
     let db = state.store.as_ref();
+    validate_gsm_rule_patterns(
+        &gsm_rule.code,
+        gsm_rule.code_match_type.unwrap_or_default(),
+        &gsm_rule.message,
+        gsm_rule.message_match_type.unwrap_or_default(),
+    )
+    .map_err(|message| errors::ApiErrorResponse::InvalidRequestData { message })?;
+
     GsmInterface::add_gsm_rule(db, gsm_rule.foreign_into())
         .await
         .to_duplicate_response(errors::ApiErrorResponse::GenericDuplicateError {
@@ -64,6 +79,18 @@ pub async fn update_gsm_rule(
                 message: "GSM with given key does not exist in our records".to_string(),
             })?;
 
+    validate_gsm_rule_patterns(
+        &gsm_request.code,
+        gsm_request
+            .code_match_type
+            .unwrap_or(gsm_db_record.code_match_type),
+        &gsm_request.message,
+        gsm_request
+            .message_match_type
+            .unwrap_or(gsm_db_record.message_match_type),
+    )
+    .map_err(|message| errors::ApiErrorResponse::InvalidRequestData { message })?;
+
     let inferred_feature_info = <(
         common_enums::GsmFeature,
         common_types::domain::GsmFeatureData,
@@ -88,6 +115,8 @@ pub async fn update_gsm_rule(
         standardised_code,
         description,
         user_guidance_message,
+        code_match_type,
+        message_match_type,
     } = gsm_request;
     GsmInterface::update_gsm_rule(
         db,
@@ -118,6 +147,8 @@ pub async fn update_gsm_rule(
             standardised_code,
             description,
             user_guidance_message,
+            code_match_type,
+            message_match_type,
         },
     )
     .await
@@ -174,3 +205,409 @@ pub async fn delete_gsm_rule(
         Err(err) => Err(err),
     }
 }
+
+#[derive(Debug, MultipartForm)]
+pub struct GsmImportForm {
+    #[multipart(limit = "5MB")]
+    pub file: Bytes,
+}
+
+fn parse_gsm_csv(data: &[u8]) -> csv::Result<Vec<gsm_api_types::GsmRecord>> {
+    let mut csv_reader = csv::Reader::from_reader(data);
+    let mut records = Vec::new();
+    let mut id_counter = 0;
+    for result in csv_reader.deserialize() {
+        let mut record: gsm_api_types::GsmRecord = result?;
+        id_counter += 1;
+        record.line_number = Some(id_counter);
+        records.push(record);
+    }
+    Ok(records)
+}
+
+pub fn get_gsm_records(
+    form: GsmImportForm,
+) -> Result<Vec<gsm_api_types::GsmRecord>, errors::ApiErrorResponse> {
+    parse_gsm_csv(&form.file.data).map_err(|e| errors::ApiErrorResponse::PreconditionFailed {
+        message: e.to_string(),
+    })
+}
+
+/// Creates the rules that do not exist yet and updates the existing ones, the result of each rule
+/// is reported separately so that a single invalid rule does not fail the whole import
+#[instrument(skip_all)]
+pub async fn import_gsm_rules(
+    state: SessionState,
+    records: Vec<gsm_api_types::GsmRecord>,
+) -> RouterResponse<Vec<gsm_api_types::GsmImportResponse>> {
+    let mut result = Vec::with_capacity(records.len());
+    for (index, mut record) in records.into_iter().enumerate() {
+        // rules imported as JSON are identified by their position in the array
+        if record.line_number.is_none() {
+            record.line_number = i64::try_from(index).ok().map(|index| index + 1);
+        }
+        let import_status = upsert_gsm_rule(&state, record.clone()).await;
+        result.push(gsm_api_types::GsmImportResponse::from((
+            import_status,
+            record,
+        )));
+    }
+
+    Ok(services::ApplicationResponse::Json(result))
+}
+
+async fn upsert_gsm_rule(
+    state: &SessionState,
+    record: gsm_api_types::GsmRecord,
+) -> Result<gsm_api_types::GsmImportStatus, String> {
+    let db = state.store.as_ref();
+    api_enums::Connector::from_str(&record.connector)
+        .map_err(|_| format!("invalid connector `{}`", record.connector))?;
+    validate_gsm_rule_patterns(
+        &record.code,
+        record.code_match_type,
+        &record.message,
+        record.message_match_type,
+    )?;
+
+    let rule = hyperswitch_domain_models::gsm::GatewayStatusMap::foreign_from(record);
+    match GsmInterface::find_gsm_rule(
+        db,
+        rule.connector.clone(),
+        rule.flow.clone(),
+        rule.sub_flow.clone(),
+        rule.code.clone(),
+        rule.message.clone(),
+    )
+    .await
+    {
+        Ok(_) => GsmInterface::update_gsm_rule(
+            db,
+            rule.connector.clone(),
+            rule.flow.clone(),
+            rule.sub_flow.clone(),
+            rule.code.clone(),
+            rule.message.clone(),
+            rule.into(),
+        )
+        .await
+        .map(|_| gsm_api_types::GsmImportStatus::Updated),
+        Err(error) if error.current_context().is_db_not_found() => {
+            GsmInterface::add_gsm_rule(db, rule)
+                .await
+                .map(|_| gsm_api_types::GsmImportStatus::Created)
+        }
+        Err(error) => Err(error),
+    }
+    .map_err(|error| {
+        logger::error!(?error, "failed to import gsm rule");
+        error.current_context().to_string()
+    })
+}
+
+#[instrument(skip_all)]
+pub async fn export_gsm_rules(
+    state: SessionState,
+    query: gsm_api_types::GsmExportQuery,
+) -> RouterResponse<Vec<gsm_api_types::GsmRecord>> {
+    let db = state.store.as_ref();
+    let records = GsmInterface::list_gsm_rules(db, query.connector, query.flow)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("failed to list gsm rules")?
+        .into_iter()
+        .map(gsm_api_types::GsmRecord::foreign_from);
+
+    match query.format.unwrap_or_default() {
+        gsm_api_types::GsmExportFormat::Json => {
+            Ok(services::ApplicationResponse::Json(records.collect()))
+        }
+        gsm_api_types::GsmExportFormat::Csv => {
+            let mut csv_writer = csv::Writer::from_writer(Vec::new());
+            for record in records {
+                csv_writer
+                    .serialize(record)
+                    .change_context(errors::ApiErrorResponse::InternalServerError)
+                    .attach_printable("failed to serialize gsm rule")?;
+            }
+
+            let data = csv_writer
+                .into_inner()
+                .map_err(|error| error.into_error())
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("failed to write gsm rules")?;
+
+            Ok(services::ApplicationResponse::FileData((
+                data,
+                mime::TEXT_CSV,
+            )))
+        }
+    }
+}
+
+fn validate_gsm_rule_patterns(
+    code: &str,
+    code_match_type: api_enums::GsmMatchType,
+    message: &str,
+    message_match_type: api_enums::GsmMatchType,
+) -> Result<(), String> {
+    for (field, pattern, match_type) in [
+        ("code", code, code_match_type),
+        ("message", message, message_match_type),
+    ] {
+        match match_type {
+            api_enums::GsmMatchType::Exact => {}
+            api_enums::GsmMatchType::Prefix | api_enums::GsmMatchType::Wildcard => {
+                if pattern.is_empty() {
+                    return Err(format!(
+                        "{field} must not be empty for {match_type} matching"
+                    ));
+                }
+            }
+            api_enums::GsmMatchType::Regex => {
+                regex::Regex::new(pattern)
+                    .map_err(|error| format!("{field} is not a valid regex: {error}"))?;
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Finds the rule that matches the error code and message among rules whose code or message is
+/// a pattern.
+///
+/// The code takes precedence over the message and for each of them an exact match takes
+/// precedence over a prefix match, which takes precedence over a wildcard match, which takes
+/// precedence over a regex match. Longer prefixes and wildcards with more literal characters take
+/// precedence over shorter ones. Any remaining tie is broken in favour of the rule whose code and
+/// message sort first, so that the same rule is picked on every lookup.
+pub fn find_matching_pattern_rule(
+    rules: Vec<hyperswitch_domain_models::gsm::GatewayStatusMap>,
+    code: &str,
+    message: &str,
+) -> Option<hyperswitch_domain_models::gsm::GatewayStatusMap> {
+    rules
+        .into_iter()
+        .filter_map(|rule| {
+            let code_precedence = get_match_precedence(rule.code_match_type, &rule.code, code)?;
+            let message_precedence =
+                get_match_precedence(rule.message_match_type, &rule.message, message)?;
+            Some(((code_precedence, message_precedence), rule))
+        })
+        .max_by(|(precedence, rule), (other_precedence, other_rule)| {
+            precedence
+                .cmp(other_precedence)
+                .then_with(|| other_rule.code.cmp(&rule.code))
+                .then_with(|| other_rule.message.cmp(&rule.message))
+        })
+        .map(|(_, rule)| rule)
+}
+
+/// Returns the precedence of the match if the value matches the pattern, matches that compare
+/// greater take precedence
+fn get_match_precedence(
+    match_type: api_enums::GsmMatchType,
+    pattern: &str,
+    value: &str,
+) -> Option<(u8, usize)> {
+    match match_type {
+        api_enums::GsmMatchType::Exact => (pattern == value).then_some((3, 0)),
+        api_enums::GsmMatchType::Prefix => value
+            .starts_with(pattern)
+            .then(|| (2, pattern.chars().count())),
+        api_enums::GsmMatchType::Wildcard => get_wildcard_regex(pattern)
+            .inspect_err(|error| logger::error!(?error, pattern, "invalid gsm wildcard pattern"))
+            .ok()?
+            .is_match(value)
+            .then(|| {
+                let literal_characters = pattern
+                    .chars()
+                    .filter(|character| !matches!(character, '*' | '?'))
+                    .count();
+                (1, literal_characters)
+            }),
+        api_enums::GsmMatchType::Regex => get_compiled_regex(pattern)
+            .inspect_err(|error| logger::error!(?error, pattern, "invalid gsm regex pattern"))
+            .ok()?
+            .is_match(value)
+            .then_some((0, 0)),
+    }
+}
+
+/// Converts a wildcard pattern to a regex that matches the whole value
+fn get_wildcard_regex(pattern: &str) -> Result<regex::Regex, regex::Error> {
+    let pattern = pattern
+        .split('*')
+        .map(|part| {
+            part.split('?')
+                .map(regex::escape)
+                .collect::<Vec<_>>()
+                .join(".")
+        })
+        .collect::<Vec<_>>()
+        .join(".*");
+
+    get_compiled_regex(&format!("^(?s:{pattern})$"))
+}
+
+/// Maximum number of compiled patterns kept in the cache. The cache is cleared once it is full, so
+/// that the patterns of rules which have been updated or deleted do not accumulate.
+const MAX_CACHED_GSM_PATTERNS: usize = 1024;
+
+/// Compiled regexes of the patterns of gsm rules, keyed by the regex, so that the patterns are not
+/// compiled again on every lookup
+static GSM_PATTERN_CACHE: LazyLock<RwLock<HashMap<String, regex::Regex>>> =
+    LazyLock::new(|| RwLock::new(HashMap::new()));
+
+/// Returns the compiled regex from the cache, compiling and caching it if it is not cached yet
+fn get_compiled_regex(pattern: &str) -> Result<regex::Regex, regex::Error> {
+    if let Some(regex) = GSM_PATTERN_CACHE
+        .read()
+        .ok()
+        .and_then(|cache| cache.get(pattern).cloned())
+    {
+        return Ok(regex);
+    }
+
+    let regex = regex::Regex::new(pattern)?;
+
+    if let Ok(mut cache) = GSM_PATTERN_CACHE.write() {
+        if cache.len() >= MAX_CACHED_GSM_PATTERNS {
+            cache.clear();
+        }
+        cache.insert(pattern.to_string(), regex.clone());
+    }
+
+    Ok(regex)
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    fn rule(
+        code: &str,
+        code_match_type: api_enums::GsmMatchType,
+        message: &str,
+        message_match_type: api_enums::GsmMatchType,
+    ) -> hyperswitch_domain_models::gsm::GatewayStatusMap {
+        hyperswitch_domain_models::gsm::GatewayStatusMap {
+            connector: "stripe".to_string(),
+            flow: "Authorize".to_string(),
+            sub_flow: "sub_flow".to_string(),
+            code: code.to_string(),
+            message: message.to_string(),
+            status: "Failure".to_string(),
+            router_error: None,
+            unified_code: None,
+            unified_message: None,
+            error_category: None,
+            feature_data: common_types::domain::GsmFeatureData::Retry(
+                common_types::domain::RetryFeatureData {
+                    step_up_possible: false,
+                    clear_pan_possible: false,
+                    alternate_network_possible: false,
+                    decision: api_enums::GsmDecision::Retry,
+                },
+            ),
+            feature: api_enums::GsmFeature::Retry,
+            standardised_code: None,
+            description: None,
+            user_guidance_message: None,
+            code_match_type,
+            message_match_type,
+        }
+    }
+
+    #[test]
+    fn test_pattern_rule_precedence() {
+        use api_enums::GsmMatchType::{Exact, Prefix, Regex, Wildcard};
+
+        let rules = vec![
+            rule("^card_", Regex, "*", Wildcard),
+            rule("card_*", Wildcard, "*", Wildcard),
+            rule("card", Prefix, "*", Wildcard),
+            rule("card_dec", Prefix, "*", Wildcard),
+            rule("card_declined", Exact, "*declined*", Wildcard),
+            rule("card_declined", Exact, "Card", Prefix),
+        ];
+
+        let matched = find_matching_pattern_rule(rules.clone(), "card_declined", "Card declined");
+        assert_eq!(matched.unwrap().message, "Card");
+
+        let matched = find_matching_pattern_rule(rules.clone(), "card_declined", "Was declined");
+        assert_eq!(matched.unwrap().message, "*declined*");
+
+        let matched = find_matching_pattern_rule(rules.clone(), "card_decline", "Declined");
+        assert_eq!(matched.unwrap().code, "card_dec");
+
+        let matched = find_matching_pattern_rule(rules.clone(), "card_expired", "Expired");
+        assert_eq!(matched.unwrap().code, "card");
+
+        let matched = find_matching_pattern_rule(rules, "do_not_honor", "Do not honor");
+        assert!(matched.is_none());
+    }
+
+    #[test]
+    fn test_wildcard_takes_precedence_over_regex() {
+        use api_enums::GsmMatchType::{Regex, Wildcard};
+
+        let rules = vec![
+            rule("^5", Regex, ".*", Regex),
+            rule("5*", Wildcard, ".*", Regex),
+        ];
+
+        let matched = find_matching_pattern_rule(rules, "51", "Insufficient funds");
+        assert_eq!(matched.unwrap().code, "5*");
+    }
+
+    #[test]
+    fn test_pattern_rule_ties_are_deterministic() {
+        use api_enums::GsmMatchType::{Exact, Wildcard};
+
+        let rules = vec![
+            rule("5?", Wildcard, "Declined", Exact),
+            rule("?1", Wildcard, "Declined", Exact),
+        ];
+        let mut reversed_rules = rules.clone();
+        reversed_rules.reverse();
+
+        let matched = find_matching_pattern_rule(rules, "51", "Declined");
+        let reversed_matched = find_matching_pattern_rule(reversed_rules, "51", "Declined");
+        assert_eq!(matched.unwrap().code, "5?");
+        assert_eq!(reversed_matched.unwrap().code, "5?");
+    }
+
+    #[test]
+    fn test_wildcard_matches_whole_value() {
+        assert!(get_wildcard_regex("card.*")
+            .unwrap()
+            .is_match("card.declined"));
+        assert!(!get_wildcard_regex("card.*")
+            .unwrap()
+            .is_match("cardxdeclined"));
+        assert!(!get_wildcard_regex("card?")
+            .unwrap()
+            .is_match("card_declined"));
+    }
+
+    #[test]
+    fn test_regex_patterns_are_compiled_once() {
+        let pattern = "^insufficient_funds_[0-9]+$";
+
+        assert_eq!(
+            get_match_precedence(
+                api_enums::GsmMatchType::Regex,
+                pattern,
+                "insufficient_funds_51"
+            ),
+            Some((0, 0))
+        );
+        assert!(GSM_PATTERN_CACHE.read().unwrap().contains_key(pattern));
+        assert_eq!(
+            get_match_precedence(api_enums::GsmMatchType::Regex, pattern, "do_not_honor"),
+            None
+        );
+    }
+}
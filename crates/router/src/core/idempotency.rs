@@ -0,0 +1,478 @@
+use std::sync::Arc;
+
+use actix_web::{http::Method, HttpRequest};
+use common_utils::crypto::{GenerateDigest, Sha256};
+use error_stack::{report, ResultExt};
+use hyperswitch_domain_models::api::{ApplicationResponse, ReplayedJsonResponse};
+use redis_interface::{self as redis, RedisKey};
+use router_env::{instrument, logger, tracing};
+use serde::{Deserialize, Serialize};
+
+use super::{
+    api_locking::LockAction,
+    errors::{self, RouterResult},
+};
+use crate::{configs::settings::LockSettings, headers};
+
+pub const IDEMPOTENCY_KEY_PREFIX: &str = "IDEMPOTENCY";
+
+pub const MAX_IDEMPOTENCY_KEY_LENGTH: usize = 255;
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum IdempotencyAction {
+    // Deduplicate requests made to mutating APIs with the `Idempotency-Key` header
+    Deduplicate,
+    // Deduplicate requests like `Deduplicate`, but leave storing the response to the caller, which
+    // converts the response to the format it is served in before it can be replayed
+    DeduplicateConvertedResponse,
+    // Idempotency keys are not honoured
+    NotApplicable,
+}
+
+/// The state of a request made with an idempotency key, as stored in redis
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(tag = "status", rename_all = "snake_case")]
+enum IdempotencyRecord {
+    InProgress {
+        request_fingerprint: String,
+    },
+    Completed {
+        request_fingerprint: String,
+        response: ReplayedJsonResponse,
+    },
+}
+
+#[derive(Debug)]
+pub enum IdempotencyCheck {
+    // The request is being processed for the first time with the idempotency key
+    Proceed,
+    // The request has already been processed, and its response must be replayed
+    Replay(ReplayedJsonResponse),
+}
+
+#[derive(Clone, Debug)]
+pub struct IdempotencyContext {
+    redis_key: String,
+    request_fingerprint: String,
+}
+
+impl IdempotencyContext {
+    /// Builds the context for requests made to mutating APIs with the `Idempotency-Key` header.
+    /// The idempotency keys are scoped to the merchant, and the request is identified by a
+    /// fingerprint of its method, path and body.
+    pub fn new<T: Serialize>(
+        request: &HttpRequest,
+        merchant_id: &common_utils::id_type::MerchantId,
+        payload: &T,
+    ) -> RouterResult<Option<Self>> {
+        if !matches!(
+            *request.method(),
+            Method::POST | Method::PUT | Method::PATCH | Method::DELETE
+        ) {
+            return Ok(None);
+        }
+
+        let Some(idempotency_key) = request.headers().get(headers::IDEMPOTENCY_KEY) else {
+            return Ok(None);
+        };
+
+        let idempotency_key = idempotency_key
+            .to_str()
+            .ok()
+            .map(str::trim)
+            .filter(|key| !key.is_empty() && key.len() <= MAX_IDEMPOTENCY_KEY_LENGTH)
+            .ok_or_else(|| errors::ApiErrorResponse::InvalidRequestData {
+                message: format!(
+                    "`{}` header must be a non-empty string of at most {MAX_IDEMPOTENCY_KEY_LENGTH} characters",
+                    headers::IDEMPOTENCY_KEY
+                ),
+            })?;
+
+        let serialized_payload = serde_json::to_vec(payload)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to serialize request for idempotency fingerprint")?;
+
+        let fingerprint_input = [
+            request.method().as_str().as_bytes(),
+            request.path().as_bytes(),
+            serialized_payload.as_slice(),
+        ]
+        .join(&b'\n');
+
+        let request_fingerprint = Sha256
+            .generate_digest(&fingerprint_input)
+            .map(hex::encode)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to generate idempotency fingerprint")?;
+
+        Ok(Some(Self {
+            redis_key: format!(
+                "{}_{}_{}",
+                IDEMPOTENCY_KEY_PREFIX,
+                merchant_id.get_string_repr(),
+                idempotency_key
+            ),
+            request_fingerprint,
+        }))
+    }
+
+    /// Reserves the idempotency key for the request, or returns the stored response if the same
+    /// request has already been processed. A retry made while the first request is still in
+    /// flight waits for it to complete if the API holds locks, and is rejected otherwise.
+    #[instrument(skip_all)]
+    pub async fn begin(
+        &self,
+        redis_conn: &redis::RedisConnectionPool,
+        lock_settings: &LockSettings,
+        lock_action: &LockAction,
+    ) -> RouterResult<IdempotencyCheck> {
+        let redis_key = RedisKey::from(self.redis_key.as_str());
+
+        let in_progress_record = serde_json::to_string(&IdempotencyRecord::InProgress {
+            request_fingerprint: self.request_fingerprint.clone(),
+        })
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to serialize idempotency record")?;
+
+        let retries = match lock_action {
+            LockAction::Hold { input } => input
+                .override_lock_retries
+                .unwrap_or(lock_settings.lock_retries),
+            LockAction::HoldMultiple { inputs } => inputs
+                .iter()
+                .find_map(|input| input.override_lock_retries)
+                .unwrap_or(lock_settings.lock_retries),
+            LockAction::QueueWithOk | LockAction::Drop | LockAction::NotApplicable => 0,
+        };
+
+        for retry in 0..=retries {
+            let set_result = redis_conn
+                .set_key_if_not_exists_with_expiry(
+                    &redis_key,
+                    in_progress_record.clone(),
+                    Some(i64::from(lock_settings.redis_lock_expiry_seconds)),
+                )
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)?;
+
+            if set_result == redis::SetnxReply::KeySet {
+                logger::info!("Idempotency key reserved for the request");
+                return Ok(IdempotencyCheck::Proceed);
+            }
+
+            let stored_record = redis_conn
+                .get_key::<Option<String>>(&redis_key)
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)?
+                .map(|record| serde_json::from_str::<IdempotencyRecord>(&record))
+                .transpose()
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to deserialize idempotency record")?;
+
+            match stored_record {
+                // The record expired after the reservation was attempted
+                None => continue,
+                Some(IdempotencyRecord::Completed {
+                    request_fingerprint,
+                    response,
+                }) => {
+                    return if request_fingerprint == self.request_fingerprint {
+                        logger::info!("Replaying the stored response for the idempotency key");
+                        Ok(IdempotencyCheck::Replay(response))
+                    } else {
+                        Err(report!(errors::ApiErrorResponse::IdempotencyKeyReused))
+                    };
+                }
+                Some(IdempotencyRecord::InProgress {
+                    request_fingerprint,
+                }) => {
+                    if request_fingerprint != self.request_fingerprint {
+                        return Err(report!(errors::ApiErrorResponse::IdempotencyKeyReused));
+                    }
+                    if retry < retries {
+                        logger::info!("Request with the idempotency key is in flight, waiting");
+                        actix_web::rt::time::sleep(tokio::time::Duration::from_millis(u64::from(
+                            lock_settings.delay_between_retries_in_milliseconds,
+                        )))
+                        .await;
+                    }
+                }
+            }
+        }
+
+        Err(report!(errors::ApiErrorResponse::IdempotencyKeyInUse))
+    }
+
+    /// Stores the response of the request to be replayed for retries. Only json responses are
+    /// replayed; for any other outcome, including errors, the idempotency key is released so that
+    /// the request can be retried.
+    #[instrument(skip_all)]
+    pub async fn complete(
+        &self,
+        redis_conn: &redis::RedisConnectionPool,
+        response: Option<ReplayedJsonResponse>,
+        response_expiry_in_seconds: u32,
+    ) -> RouterResult<()> {
+        let redis_key = RedisKey::from(self.redis_key.as_str());
+
+        match response {
+            Some(response) => {
+                let completed_record = serde_json::to_string(&IdempotencyRecord::Completed {
+                    request_fingerprint: self.request_fingerprint.clone(),
+                    response,
+                })
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to serialize idempotency record")?;
+
+                redis_conn
+                    .set_key_with_expiry(
+                        &redis_key,
+                        completed_record,
+                        i64::from(response_expiry_in_seconds),
+                    )
+                    .await
+                    .change_context(errors::ApiErrorResponse::InternalServerError)
+                    .attach_printable("Failed to store the response for the idempotency key")
+            }
+            None => redis_conn
+                .delete_key(&redis_key)
+                .await
+                .map(|_| ())
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to release the idempotency key"),
+        }
+    }
+}
+
+/// A request whose idempotency key has been reserved, and whose response is yet to be stored by
+/// the caller of [`crate::services::api::server_wrap_util`]
+#[derive(Clone, Debug)]
+pub struct PendingIdempotentRequest {
+    pub context: IdempotencyContext,
+    pub redis_conn: Arc<redis::RedisConnectionPool>,
+    pub response_expiry_in_seconds: u32,
+}
+
+impl PendingIdempotentRequest {
+    /// Takes the request pending completion out of the request extensions, if there is any
+    pub fn take(request: &HttpRequest) -> Option<Self> {
+        request.extensions_mut().remove::<Self>()
+    }
+
+    pub async fn complete(self, response: Option<ReplayedJsonResponse>) -> RouterResult<()> {
+        self.context
+            .complete(&self.redis_conn, response, self.response_expiry_in_seconds)
+            .await
+    }
+}
+
+/// Builds the response to be replayed for retries, if the response of the API can be replayed
+pub fn get_replayable_response<Q: Serialize>(
+    output: &ApplicationResponse<Q>,
+    proxy_status_mapping: &crate::configs::settings::ProxyStatusMapping,
+) -> RouterResult<Option<ReplayedJsonResponse>> {
+    let (body, headers, status_code) = match output {
+        ApplicationResponse::Json(body) => (body, Vec::new(), http::StatusCode::OK),
+        ApplicationResponse::JsonWithHeaders((body, headers)) => (
+            body,
+            get_replayable_headers(headers),
+            proxy_status_mapping
+                .extract_connector_http_status_code(headers)
+                .unwrap_or(http::StatusCode::OK),
+        ),
+        ApplicationResponse::ReplayedJson(response) => return Ok(Some(*response.clone())),
+        ApplicationResponse::StatusOk
+        | ApplicationResponse::TextPlain(_)
+        | ApplicationResponse::JsonForRedirection(_)
+        | ApplicationResponse::Form(_)
+        | ApplicationResponse::PaymentLinkForm(_)
+        | ApplicationResponse::FileData(_)
+        | ApplicationResponse::FileStream(_)
+        | ApplicationResponse::GenericLinkForm(_) => return Ok(None),
+    };
+
+    let body = serde_json::to_string(body)
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to serialize response for idempotency key")?;
+
+    Ok(Some(ReplayedJsonResponse {
+        status_code: status_code.as_u16(),
+        body,
+        headers,
+    }))
+}
+
+/// Sensitive headers are not stored, and latency is specific to the first request
+pub fn get_replayable_headers(
+    headers: &[(String, masking::Maskable<String>)],
+) -> Vec<(String, String)> {
+    headers
+        .iter()
+        .filter(|(key, value)| !value.is_masked() && key != common_utils::consts::X_HS_LATENCY)
+        .map(|(key, value)| (key.clone(), value.clone().into_inner()))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use std::borrow::Cow;
+
+    use actix_web::test::TestRequest;
+
+    use super::*;
+
+    async fn in_memory_redis_conn() -> redis::RedisConnectionPool {
+        redis::RedisConnectionPool::new(&redis::RedisSettings {
+            in_memory: true,
+            ..Default::default()
+        })
+        .await
+        .unwrap()
+    }
+
+    fn lock_settings() -> LockSettings {
+        LockSettings {
+            redis_lock_expiry_seconds: 30,
+            delay_between_retries_in_milliseconds: 10,
+            lock_retries: 2,
+        }
+    }
+
+    fn idempotency_context(
+        idempotency_key: &str,
+        payload: &serde_json::Value,
+    ) -> IdempotencyContext {
+        let request = TestRequest::post()
+            .uri("/payments")
+            .insert_header((headers::IDEMPOTENCY_KEY, idempotency_key))
+            .to_http_request();
+        let merchant_id =
+            common_utils::id_type::MerchantId::try_from(Cow::from("merchant_idempotency")).unwrap();
+
+        IdempotencyContext::new(&request, &merchant_id, payload)
+            .unwrap()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_idempotency_context_not_built_for_reads_or_without_key() {
+        let merchant_id =
+            common_utils::id_type::MerchantId::try_from(Cow::from("merchant_idempotency")).unwrap();
+        let get_request = TestRequest::get()
+            .uri("/payments/pay_1")
+            .insert_header((headers::IDEMPOTENCY_KEY, "key"))
+            .to_http_request();
+        let post_request = TestRequest::post().uri("/payments").to_http_request();
+
+        assert!(IdempotencyContext::new(&get_request, &merchant_id, &())
+            .unwrap()
+            .is_none());
+        assert!(IdempotencyContext::new(&post_request, &merchant_id, &())
+            .unwrap()
+            .is_none());
+    }
+
+    #[tokio::test]
+    async fn test_completed_request_is_replayed() {
+        let redis_conn = in_memory_redis_conn().await;
+        let payload = serde_json::json!({ "amount": 100 });
+        let context = idempotency_context("test_replay", &payload);
+        let response = ReplayedJsonResponse {
+            status_code: 200,
+            body: r#"{"payment_id":"pay_1"}"#.to_string(),
+            headers: vec![("x-request-id".to_string(), "req_1".to_string())],
+        };
+
+        let check = context
+            .begin(&redis_conn, &lock_settings(), &LockAction::NotApplicable)
+            .await
+            .unwrap();
+        assert!(matches!(check, IdempotencyCheck::Proceed));
+
+        context
+            .complete(&redis_conn, Some(response.clone()), 60)
+            .await
+            .unwrap();
+
+        let retry = idempotency_context("test_replay", &payload)
+            .begin(&redis_conn, &lock_settings(), &LockAction::NotApplicable)
+            .await
+            .unwrap();
+        assert!(matches!(retry, IdempotencyCheck::Replay(replayed) if replayed == response));
+    }
+
+    #[tokio::test]
+    async fn test_key_reused_with_different_request_is_rejected() {
+        let redis_conn = in_memory_redis_conn().await;
+        let context = idempotency_context("test_conflict", &serde_json::json!({ "amount": 100 }));
+
+        context
+            .begin(&redis_conn, &lock_settings(), &LockAction::NotApplicable)
+            .await
+            .unwrap();
+
+        let conflicting_request =
+            idempotency_context("test_conflict", &serde_json::json!({ "amount": 200 }));
+        let in_progress_error = conflicting_request
+            .begin(&redis_conn, &lock_settings(), &LockAction::NotApplicable)
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            in_progress_error.current_context(),
+            errors::ApiErrorResponse::IdempotencyKeyReused
+        ));
+
+        context
+            .complete(
+                &redis_conn,
+                Some(ReplayedJsonResponse {
+                    status_code: 200,
+                    body: "{}".to_string(),
+                    headers: Vec::new(),
+                }),
+                60,
+            )
+            .await
+            .unwrap();
+
+        let completed_error = conflicting_request
+            .begin(&redis_conn, &lock_settings(), &LockAction::NotApplicable)
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            completed_error.current_context(),
+            errors::ApiErrorResponse::IdempotencyKeyReused
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_retry_of_in_flight_request_is_rejected_until_released() {
+        let redis_conn = in_memory_redis_conn().await;
+        let payload = serde_json::json!({ "amount": 100 });
+        let context = idempotency_context("test_in_flight", &payload);
+
+        context
+            .begin(&redis_conn, &lock_settings(), &LockAction::NotApplicable)
+            .await
+            .unwrap();
+
+        let error = idempotency_context("test_in_flight", &payload)
+            .begin(&redis_conn, &lock_settings(), &LockAction::NotApplicable)
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            error.current_context(),
+            errors::ApiErrorResponse::IdempotencyKeyInUse
+        ));
+
+        // A failed request releases the key, so that it can be retried
+        context.complete(&redis_conn, None, 60).await.unwrap();
+
+        let retry = idempotency_context("test_in_flight", &payload)
+            .begin(&redis_conn, &lock_settings(), &LockAction::NotApplicable)
+            .await
+            .unwrap();
+        assert!(matches!(retry, IdempotencyCheck::Proceed));
+    }
+}
@@ -0,0 +1,1082 @@
+use api_models::admin::{KeyRotationResponse, KeyRotationStage, KeyRotationStatus};
+use common_utils::{
+    crypto::{self, DecodeMessage, EncodeMessage, Encryptable, GenerateDigest},
+    encryption::Encryption,
+    ext_traits::ValueExt,
+    fp_utils::when,
+    id_type, type_name,
+    types::keymanager::{Identifier, KeyManagerState},
+};
+use error_stack::ResultExt;
+use masking::{PeekInterface, Secret};
+
+use crate::{
+    core::errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+    routes::SessionState,
+    services::{self, ApplicationResponse},
+    types::{
+        domain::{
+            self,
+            types::{crypto_operation, CryptoOperation},
+        },
+        storage,
+    },
+};
+
+const KEY_ROTATION_TASK: &str = "KEY_ROTATION";
+const MASTER_KEY_ROTATION_TASK: &str = "MASTER_KEY_ROTATION";
+const KEY_ROTATION_TAG: &str = "KEY_ROTATION";
+
+/// Number of records re-encrypted before the progress of a rotation is persisted
+const KEY_ROTATION_BATCH_SIZE: u32 = 100;
+
+/// Number of times a record modified while it is being re-encrypted is re-read and re-encrypted
+const KEY_ROTATION_UPDATE_ATTEMPTS: u8 = 3;
+
+fn is_last_batch(batch_length: usize) -> bool {
+    u32::try_from(batch_length).map_or(false, |length| length < KEY_ROTATION_BATCH_SIZE)
+}
+
+fn generate_task_id_for_merchant_key_rotation(
+    merchant_id: &id_type::MerchantId,
+    key_version: i32,
+) -> String {
+    format!(
+        "{}_{KEY_ROTATION_TASK}_{}_{key_version}",
+        storage::ProcessTrackerRunner::KeyRotationWorkflow,
+        merchant_id.get_string_repr()
+    )
+}
+
+/// The task is identified by the master key it re-encrypts the key stores with, so that a single
+/// task exists per master key
+fn generate_task_id_for_master_key_rotation(master_key: &[u8]) -> RouterResult<String> {
+    let digest = crypto::Sha256
+        .generate_digest(master_key)
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to generate digest of the master key")?;
+    let key_id = hex::encode(digest.iter().take(8).copied().collect::<Vec<_>>());
+
+    Ok(format!(
+        "{}_{MASTER_KEY_ROTATION_TASK}_{key_id}",
+        storage::ProcessTrackerRunner::KeyRotationWorkflow,
+    ))
+}
+
+async fn encrypt_key_locally(
+    key_manager_state: &KeyManagerState,
+    key: Secret<Vec<u8>>,
+    identifier: Identifier,
+    master_key: &[u8],
+) -> RouterResult<Encryptable<Secret<Vec<u8>>>> {
+    crypto_operation(
+        key_manager_state,
+        type_name!(domain::MerchantKeyStore),
+        CryptoOperation::EncryptLocally(key),
+        identifier,
+        master_key,
+    )
+    .await
+    .and_then(|val| val.try_into_operation())
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to encrypt merchant key with the master key")
+}
+
+fn is_process_running(process: &storage::ProcessTracker) -> bool {
+    process.status != storage::enums::ProcessTrackerStatus::Finish
+}
+
+fn is_process_completed(process: &storage::ProcessTracker) -> bool {
+    process.status == storage::enums::ProcessTrackerStatus::Finish
+        && process.business_status == storage::business_status::COMPLETED_BY_PT
+}
+
+fn get_key_rotation_response(
+    process: &storage::ProcessTracker,
+) -> RouterResult<KeyRotationResponse> {
+    let tracking_data: storage::KeyRotationTrackingData = process
+        .tracking_data
+        .clone()
+        .parse_value("KeyRotationTrackingData")
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to parse key rotation tracking data")?;
+
+    let status = if is_process_completed(process) {
+        KeyRotationStatus::Completed
+    } else if is_process_running(process) {
+        KeyRotationStatus::InProgress
+    } else {
+        KeyRotationStatus::Failed
+    };
+
+    Ok(KeyRotationResponse {
+        rotation_id: process.id.clone(),
+        merchant_id: tracking_data.merchant_id,
+        key_version: tracking_data.key_version,
+        status,
+        stage: tracking_data.stage,
+        key_stores_re_encrypted: tracking_data.key_stores_re_encrypted,
+        customers_re_encrypted: tracking_data.customers_re_encrypted,
+        addresses_re_encrypted: tracking_data.addresses_re_encrypted,
+        payment_methods_re_encrypted: tracking_data.payment_methods_re_encrypted,
+    })
+}
+
+async fn schedule_key_rotation_task(
+    state: &SessionState,
+    process_tracker_id: String,
+    task: &'static str,
+    tracking_data: storage::KeyRotationTrackingData,
+) -> RouterResult<storage::ProcessTracker> {
+    let process_tracker_entry = storage::ProcessTrackerNew::new(
+        process_tracker_id,
+        task,
+        storage::ProcessTrackerRunner::KeyRotationWorkflow,
+        [KEY_ROTATION_TAG],
+        tracking_data,
+        None,
+        common_utils::date_time::now(),
+        common_types::consts::API_VERSION,
+        state.conf.application_source,
+    )
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to construct key rotation process tracker task")?;
+
+    state
+        .store
+        .insert_process(process_tracker_entry)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed while inserting key rotation task to process_tracker")
+}
+
+/// Resumes a rotation that stopped after repeated failures from the progress it has recorded
+async fn resume_key_rotation_task(
+    state: &SessionState,
+    process: storage::ProcessTracker,
+) -> RouterResult<KeyRotationResponse> {
+    let mut response = get_key_rotation_response(&process)?;
+
+    state
+        .store
+        .as_scheduler()
+        .reset_process(process, common_utils::date_time::now())
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to reschedule key rotation task")?;
+    response.status = KeyRotationStatus::InProgress;
+
+    Ok(response)
+}
+
+async fn find_master_key_rotation_task(
+    state: &SessionState,
+) -> RouterResult<Option<storage::ProcessTracker>> {
+    let process_tracker_id =
+        generate_task_id_for_master_key_rotation(state.store.get_master_key())?;
+
+    state
+        .store
+        .find_process_by_id(&process_tracker_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to retrieve master key rotation task from process tracker")
+}
+
+/// Replaces the data encryption key of the merchant and schedules a task re-encrypting the
+/// customers, addresses and payment methods of the merchant with the new key.
+///
+/// The replaced key is retained in the key store, so that records which haven't been re-encrypted
+/// yet, as well as the remaining encrypted merchant data, stay readable. Starting a rotation while
+/// the records are still being re-encrypted with the current key resumes that rotation instead.
+pub async fn rotate_merchant_key(
+    state: SessionState,
+    merchant_id: id_type::MerchantId,
+) -> RouterResponse<KeyRotationResponse> {
+    let db = state.store.as_ref();
+    let key_manager_state: &KeyManagerState = &(&state).into();
+
+    when(key_manager_state.enabled, || {
+        Err(errors::ApiErrorResponse::PreconditionFailed {
+            message: "Merchant keys held by the key manager service cannot be rotated".to_string(),
+        })
+    })?;
+
+    let master_key = db.get_master_key();
+    let key_store = db
+        .get_merchant_key_store_by_merchant_id(&merchant_id, &master_key.to_vec().into())
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+
+    let master_key_rotation = find_master_key_rotation_task(&state).await?;
+    when(
+        master_key_rotation.as_ref().is_some_and(is_process_running),
+        || {
+            Err(errors::ApiErrorResponse::PreconditionFailed {
+                message: "Merchant keys cannot be rotated while the master key is being rotated"
+                    .to_string(),
+            })
+        },
+    )?;
+
+    let current_task_id =
+        generate_task_id_for_merchant_key_rotation(&merchant_id, key_store.key_version);
+    let current_rotation = db
+        .find_process_by_id(&current_task_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to retrieve key rotation task from process tracker")?;
+
+    match current_rotation {
+        Some(process) if is_process_running(&process) => {
+            return Err(errors::ApiErrorResponse::PreconditionFailed {
+                message: "A key rotation is already in progress for the merchant".to_string(),
+            }
+            .into());
+        }
+        Some(process) if !is_process_completed(&process) => {
+            return resume_key_rotation_task(&state, process)
+                .await
+                .map(ApplicationResponse::Json);
+        }
+        // The key was rotated, but the task re-encrypting the records wasn't scheduled
+        None if key_store.key_version > 1 => {
+            let tracking_data = storage::KeyRotationTrackingData {
+                merchant_id: Some(merchant_id),
+                key_version: Some(key_store.key_version),
+                stage: KeyRotationStage::Customers,
+                cursor: String::new(),
+                key_stores_re_encrypted: 0,
+                customers_re_encrypted: 0,
+                addresses_re_encrypted: 0,
+                payment_methods_re_encrypted: 0,
+            };
+            let process = schedule_key_rotation_task(
+                &state,
+                current_task_id,
+                KEY_ROTATION_TASK,
+                tracking_data,
+            )
+            .await?;
+            return get_key_rotation_response(&process).map(ApplicationResponse::Json);
+        }
+        Some(_) | None => {}
+    }
+
+    let new_key = services::generate_aes256_key()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Unable to generate aes 256 key")?;
+
+    // The key ring of the current key becomes the previous keys, ordered newest first, which is
+    // the order they are tried in on decryption
+    let previous_keys = key_store.get_key_ring();
+
+    let identifier = Identifier::Merchant(merchant_id.clone());
+    let key_version = key_store.key_version + 1;
+    let rotated_key_store = domain::MerchantKeyStore {
+        key: encrypt_key_locally(
+            key_manager_state,
+            new_key.to_vec().into(),
+            identifier.clone(),
+            master_key,
+        )
+        .await?,
+        previous_keys: Some(
+            encrypt_key_locally(key_manager_state, previous_keys, identifier, master_key).await?,
+        ),
+        key_version,
+        ..key_store
+    };
+
+    db.update_merchant_key_store(rotated_key_store, &master_key.to_vec().into())
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)
+        .attach_printable("Failed to update merchant key store with the rotated key")?;
+
+    let tracking_data = storage::KeyRotationTrackingData {
+        merchant_id: Some(merchant_id.clone()),
+        key_version: Some(key_version),
+        stage: KeyRotationStage::Customers,
+        cursor: String::new(),
+        key_stores_re_encrypted: 0,
+        customers_re_encrypted: 0,
+        addresses_re_encrypted: 0,
+        payment_methods_re_encrypted: 0,
+    };
+    let process = schedule_key_rotation_task(
+        &state,
+        generate_task_id_for_merchant_key_rotation(&merchant_id, key_version),
+        KEY_ROTATION_TASK,
+        tracking_data,
+    )
+    .await?;
+
+    get_key_rotation_response(&process).map(ApplicationResponse::Json)
+}
+
+pub async fn retrieve_merchant_key_rotation(
+    state: SessionState,
+    merchant_id: id_type::MerchantId,
+) -> RouterResponse<KeyRotationResponse> {
+    let db = state.store.as_ref();
+    let key_store = db
+        .get_merchant_key_store_by_merchant_id(&merchant_id, &db.get_master_key().to_vec().into())
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+
+    let process = db
+        .find_process_by_id(&generate_task_id_for_merchant_key_rotation(
+            &merchant_id,
+            key_store.key_version,
+        ))
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to retrieve key rotation task from process tracker")?
+        .ok_or(errors::ApiErrorResponse::GenericNotFoundError {
+            message: "The merchant key has not been rotated".to_string(),
+        })?;
+
+    get_key_rotation_response(&process).map(ApplicationResponse::Json)
+}
+
+/// Schedules a task re-encrypting the keys of all merchant key stores with the current master
+/// key. Key stores encrypted with the previous master key are readable meanwhile, as long as the
+/// previous master key is configured.
+pub async fn rotate_master_key(state: SessionState) -> RouterResponse<KeyRotationResponse> {
+    when(
+        state
+            .conf
+            .secrets
+            .get_inner()
+            .previous_master_enc_key
+            .is_none(),
+        || {
+            Err(errors::ApiErrorResponse::PreconditionFailed {
+                message: "The previous master key must be configured to rotate the master key"
+                    .to_string(),
+            })
+        },
+    )?;
+
+    match find_master_key_rotation_task(&state).await? {
+        Some(process) if is_process_running(&process) || is_process_completed(&process) => {
+            get_key_rotation_response(&process).map(ApplicationResponse::Json)
+        }
+        Some(process) => resume_key_rotation_task(&state, process)
+            .await
+            .map(ApplicationResponse::Json),
+        None => {
+            let tracking_data = storage::KeyRotationTrackingData {
+                merchant_id: None,
+                key_version: None,
+                stage: KeyRotationStage::KeyStores,
+                cursor: String::new(),
+                key_stores_re_encrypted: 0,
+                customers_re_encrypted: 0,
+                addresses_re_encrypted: 0,
+                payment_methods_re_encrypted: 0,
+            };
+            let process = schedule_key_rotation_task(
+                &state,
+                generate_task_id_for_master_key_rotation(state.store.get_master_key())?,
+                MASTER_KEY_ROTATION_TASK,
+                tracking_data,
+            )
+            .await?;
+
+            get_key_rotation_response(&process).map(ApplicationResponse::Json)
+        }
+    }
+}
+
+pub async fn retrieve_master_key_rotation(
+    state: SessionState,
+) -> RouterResponse<KeyRotationResponse> {
+    let process = find_master_key_rotation_task(&state).await?.ok_or(
+        errors::ApiErrorResponse::GenericNotFoundError {
+            message: "The master key has not been rotated".to_string(),
+        },
+    )?;
+
+    get_key_rotation_response(&process).map(ApplicationResponse::Json)
+}
+
+/// Decrypts the field with any key of the key ring, and encrypts it with the current key, which
+/// is the first key of the ring
+fn re_encrypt_field(
+    field: Option<&Encryption>,
+    key_ring: &[u8],
+) -> RouterResult<Option<Encryption>> {
+    let key = key_ring
+        .get(..crypto::GCM_AES_256_KEY_LENGTH)
+        .ok_or(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Merchant key ring is shorter than a key")?;
+
+    field
+        .map(|field| {
+            let decrypted =
+                crypto::GcmAes256.decode_message(key_ring, field.get_inner().clone())?;
+            crypto::GcmAes256
+                .encode_message(key, &decrypted)
+                .map(|encrypted| Encryption::new(encrypted.into()))
+        })
+        .transpose()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to re-encrypt field with the rotated key")
+}
+
+async fn re_encrypt_key_stores(
+    state: &SessionState,
+    tracking_data: &mut storage::KeyRotationTrackingData,
+) -> RouterResult<bool> {
+    let db = state.store.as_ref();
+    let key_manager_state: &KeyManagerState = &state.into();
+    let master_key = db.get_master_key();
+
+    let key_stores = db
+        .list_key_stores_after_merchant_id(
+            &master_key.to_vec().into(),
+            tracking_data.cursor.clone(),
+            KEY_ROTATION_BATCH_SIZE,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list merchant key stores")?;
+    let is_stage_completed = is_last_batch(key_stores.len());
+
+    for key_store in key_stores {
+        let merchant_id = key_store.merchant_id.clone();
+        let identifier = Identifier::Merchant(merchant_id.clone());
+        let previous_keys = match key_store.previous_keys.as_ref() {
+            Some(previous_keys) => Some(
+                encrypt_key_locally(
+                    key_manager_state,
+                    previous_keys.get_inner().clone(),
+                    identifier.clone(),
+                    master_key,
+                )
+                .await?,
+            ),
+            None => None,
+        };
+        let re_encrypted_key_store = domain::MerchantKeyStore {
+            key: encrypt_key_locally(
+                key_manager_state,
+                key_store.key.get_inner().clone(),
+                identifier,
+                master_key,
+            )
+            .await?,
+            previous_keys,
+            ..key_store
+        };
+
+        db.update_merchant_key_store(re_encrypted_key_store, &master_key.to_vec().into())
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to update merchant key store")?;
+
+        tracking_data.cursor = merchant_id.get_string_repr().to_owned();
+        tracking_data.key_stores_re_encrypted += 1;
+    }
+
+    Ok(is_stage_completed)
+}
+
+/// Re-encrypts the customer, re-reading and re-encrypting it again whenever it was modified
+/// after it was read
+async fn re_encrypt_customer(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+    key_ring: &[u8],
+    mut customer: storage::Customer,
+) -> RouterResult<()> {
+    let db = state.store.as_ref();
+
+    for _ in 0..KEY_ROTATION_UPDATE_ATTEMPTS {
+        let update = storage::CustomerEncryptionUpdateInternal {
+            name: re_encrypt_field(customer.name.as_ref(), key_ring)?,
+            email: re_encrypt_field(customer.email.as_ref(), key_ring)?,
+            phone: re_encrypt_field(customer.phone.as_ref(), key_ring)?,
+            tax_registration_id: re_encrypt_field(customer.tax_registration_id.as_ref(), key_ring)?,
+            document_details: re_encrypt_field(customer.document_details.as_ref(), key_ring)?,
+        };
+
+        let has_encrypted_fields = update.name.is_some()
+            || update.email.is_some()
+            || update.phone.is_some()
+            || update.tax_registration_id.is_some()
+            || update.document_details.is_some();
+        if !has_encrypted_fields {
+            return Ok(());
+        }
+
+        let is_updated = db
+            .update_customer_encrypted_fields(
+                &customer.customer_id,
+                merchant_id,
+                customer.modified_at,
+                update,
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to update re-encrypted customer")?;
+        if is_updated {
+            return Ok(());
+        }
+
+        match db
+            .find_customer_for_key_rotation(&customer.customer_id, merchant_id)
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to retrieve modified customer")?
+        {
+            Some(modified_customer) => customer = modified_customer,
+            None => return Ok(()),
+        }
+    }
+
+    Err(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Customer kept being modified while it was re-encrypted")
+}
+
+async fn re_encrypt_customers(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+    key_ring: &[u8],
+    tracking_data: &mut storage::KeyRotationTrackingData,
+) -> RouterResult<bool> {
+    let customers = state
+        .store
+        .list_customers_for_key_rotation(
+            merchant_id,
+            tracking_data.cursor.clone(),
+            i64::from(KEY_ROTATION_BATCH_SIZE),
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list customers")?;
+    let is_stage_completed = is_last_batch(customers.len());
+
+    for customer in customers {
+        let cursor = customer.customer_id.get_string_repr().to_owned();
+        re_encrypt_customer(state, merchant_id, key_ring, customer).await?;
+
+        tracking_data.cursor = cursor;
+        tracking_data.customers_re_encrypted += 1;
+    }
+
+    Ok(is_stage_completed)
+}
+
+/// Re-encrypts the address, re-reading and re-encrypting it again whenever it was modified after
+/// it was read
+async fn re_encrypt_address(
+    state: &SessionState,
+    key_ring: &[u8],
+    mut address: storage::Address,
+) -> RouterResult<()> {
+    let db = state.store.as_ref();
+
+    for _ in 0..KEY_ROTATION_UPDATE_ATTEMPTS {
+        let update = storage::AddressEncryptionUpdateInternal {
+            line1: re_encrypt_field(address.line1.as_ref(), key_ring)?,
+            line2: re_encrypt_field(address.line2.as_ref(), key_ring)?,
+            line3: re_encrypt_field(address.line3.as_ref(), key_ring)?,
+            state: re_encrypt_field(address.state.as_ref(), key_ring)?,
+            zip: re_encrypt_field(address.zip.as_ref(), key_ring)?,
+            first_name: re_encrypt_field(address.first_name.as_ref(), key_ring)?,
+            last_name: re_encrypt_field(address.last_name.as_ref(), key_ring)?,
+            phone_number: re_encrypt_field(address.phone_number.as_ref(), key_ring)?,
+            email: re_encrypt_field(address.email.as_ref(), key_ring)?,
+            origin_zip: re_encrypt_field(address.origin_zip.as_ref(), key_ring)?,
+        };
+
+        let has_encrypted_fields = [
+            &update.line1,
+            &update.line2,
+            &update.line3,
+            &update.state,
+            &update.zip,
+            &update.first_name,
+            &update.last_name,
+            &update.phone_number,
+            &update.email,
+            &update.origin_zip,
+        ]
+        .iter()
+        .any(|field| field.is_some());
+        if !has_encrypted_fields {
+            return Ok(());
+        }
+
+        let is_updated = db
+            .update_address_encrypted_fields(
+                address.address_id.clone(),
+                address.modified_at,
+                update,
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to update re-encrypted address")?;
+        if is_updated {
+            return Ok(());
+        }
+
+        match db
+            .find_address_for_key_rotation(&address.address_id)
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to retrieve modified address")?
+        {
+            Some(modified_address) => address = modified_address,
+            None => return Ok(()),
+        }
+    }
+
+    Err(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Address kept being modified while it was re-encrypted")
+}
+
+async fn re_encrypt_addresses(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+    key_ring: &[u8],
+    tracking_data: &mut storage::KeyRotationTrackingData,
+) -> RouterResult<bool> {
+    let addresses = state
+        .store
+        .list_addresses_for_key_rotation(
+            merchant_id,
+            tracking_data.cursor.clone(),
+            i64::from(KEY_ROTATION_BATCH_SIZE),
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list addresses")?;
+    let is_stage_completed = is_last_batch(addresses.len());
+
+    for address in addresses {
+        let cursor = address.address_id.clone();
+        re_encrypt_address(state, key_ring, address).await?;
+
+        tracking_data.cursor = cursor;
+        tracking_data.addresses_re_encrypted += 1;
+    }
+
+    Ok(is_stage_completed)
+}
+
+/// Re-encrypts the payment method, re-reading and re-encrypting it again whenever it was modified
+/// after it was read
+async fn re_encrypt_payment_method(
+    state: &SessionState,
+    key_ring: &[u8],
+    mut payment_method: storage::PaymentMethod,
+) -> RouterResult<()> {
+    let db = state.store.as_ref();
+
+    for _ in 0..KEY_ROTATION_UPDATE_ATTEMPTS {
+        let update = storage::PaymentMethodEncryptionUpdateInternal {
+            payment_method_data: re_encrypt_field(
+                payment_method.payment_method_data.as_ref(),
+                key_ring,
+            )?,
+            payment_method_billing_address: re_encrypt_field(
+                payment_method.payment_method_billing_address.as_ref(),
+                key_ring,
+            )?,
+            network_token_payment_method_data: re_encrypt_field(
+                payment_method.network_token_payment_method_data.as_ref(),
+                key_ring,
+            )?,
+            customer_details: re_encrypt_field(payment_method.customer_details.as_ref(), key_ring)?,
+        };
+
+        let has_encrypted_fields = update.payment_method_data.is_some()
+            || update.payment_method_billing_address.is_some()
+            || update.network_token_payment_method_data.is_some()
+            || update.customer_details.is_some();
+        if !has_encrypted_fields {
+            return Ok(());
+        }
+
+        let is_updated = db
+            .update_payment_method_encrypted_fields(
+                payment_method.payment_method_id.clone(),
+                payment_method.last_modified,
+                update,
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to update re-encrypted payment method")?;
+        if is_updated {
+            return Ok(());
+        }
+
+        match db
+            .find_payment_method_for_key_rotation(&payment_method.payment_method_id)
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to retrieve modified payment method")?
+        {
+            Some(modified_payment_method) => payment_method = modified_payment_method,
+            None => return Ok(()),
+        }
+    }
+
+    Err(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Payment method kept being modified while it was re-encrypted")
+}
+
+async fn re_encrypt_payment_methods(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+    key_ring: &[u8],
+    tracking_data: &mut storage::KeyRotationTrackingData,
+) -> RouterResult<bool> {
+    let payment_methods = state
+        .store
+        .list_payment_methods_for_key_rotation(
+            merchant_id,
+            tracking_data.cursor.clone(),
+            i64::from(KEY_ROTATION_BATCH_SIZE),
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list payment methods")?;
+    let is_stage_completed = is_last_batch(payment_methods.len());
+
+    for payment_method in payment_methods {
+        let cursor = payment_method.payment_method_id.clone();
+        re_encrypt_payment_method(state, key_ring, payment_method).await?;
+
+        tracking_data.cursor = cursor;
+        tracking_data.payment_methods_re_encrypted += 1;
+    }
+
+    Ok(is_stage_completed)
+}
+
+/// Re-encrypts the next batch of records of the rotation and records the progress in the
+/// tracking data. Returns `true` once all records have been re-encrypted.
+pub async fn re_encrypt_next_batch(
+    state: &SessionState,
+    tracking_data: &mut storage::KeyRotationTrackingData,
+) -> RouterResult<bool> {
+    let (stage, next_stage) = match tracking_data.stage {
+        KeyRotationStage::KeyStores => (KeyRotationStage::KeyStores, KeyRotationStage::Completed),
+        KeyRotationStage::Customers => (KeyRotationStage::Customers, KeyRotationStage::Addresses),
+        KeyRotationStage::Addresses => (
+            KeyRotationStage::Addresses,
+            KeyRotationStage::PaymentMethods,
+        ),
+        KeyRotationStage::PaymentMethods => (
+            KeyRotationStage::PaymentMethods,
+            KeyRotationStage::Completed,
+        ),
+        KeyRotationStage::Completed => return Ok(true),
+    };
+
+    let is_stage_completed = if stage == KeyRotationStage::KeyStores {
+        re_encrypt_key_stores(state, tracking_data).await?
+    } else {
+        let merchant_id = tracking_data
+            .merchant_id
+            .clone()
+            .ok_or(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Missing merchant_id in key rotation tracking data")?;
+        let db = state.store.as_ref();
+        let key_store = db
+            .get_merchant_key_store_by_merchant_id(
+                &merchant_id,
+                &db.get_master_key().to_vec().into(),
+            )
+            .await
+            .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+        let key_ring = key_store.get_key_ring();
+
+        match stage {
+            KeyRotationStage::Customers => {
+                re_encrypt_customers(state, &merchant_id, key_ring.peek(), tracking_data).await?
+            }
+            KeyRotationStage::Addresses => {
+                re_encrypt_addresses(state, &merchant_id, key_ring.peek(), tracking_data).await?
+            }
+            _ => {
+                re_encrypt_payment_methods(state, &merchant_id, key_ring.peek(), tracking_data)
+                    .await?
+            }
+        }
+    };
+
+    if is_stage_completed {
+        tracking_data.stage = next_stage;
+        tracking_data.cursor = String::new();
+    }
+
+    Ok(tracking_data.stage == KeyRotationStage::Completed)
+}
+
+#[cfg(all(test, feature = "v1"))]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use std::{borrow::Cow, sync::Arc};
+
+    use super::*;
+    use crate::{
+        configs::settings::Settings, db::StorageImpl, routes, types::domain::behaviour::Conversion,
+    };
+
+    const CUSTOMER_NAME: &[u8] = b"John Doe";
+
+    async fn mock_session_state() -> SessionState {
+        let conf = Settings::new().unwrap();
+        let tx = tokio::sync::oneshot::channel().0;
+        let app_state = Box::pin(routes::AppState::with_storage(
+            conf,
+            StorageImpl::Mock,
+            tx,
+            Box::new(services::MockApiClient),
+        ))
+        .await;
+
+        Arc::new(app_state)
+            .get_session_state(
+                &id_type::TenantId::try_from_string("public".to_string()).unwrap(),
+                None,
+                || {},
+            )
+            .unwrap()
+    }
+
+    async fn insert_rotated_key_store(
+        state: &SessionState,
+        merchant_id: &str,
+        key: &[u8],
+        previous_key: &[u8],
+    ) -> domain::MerchantKeyStore {
+        let key_manager_state: &KeyManagerState = &state.into();
+        let master_key = state.store.get_master_key();
+        let merchant_id = id_type::MerchantId::try_from(Cow::from(merchant_id.to_owned())).unwrap();
+        let identifier = Identifier::Merchant(merchant_id.clone());
+        let key_store = domain::MerchantKeyStore {
+            merchant_id,
+            key: encrypt_key_locally(
+                key_manager_state,
+                key.to_vec().into(),
+                identifier.clone(),
+                master_key,
+            )
+            .await
+            .unwrap(),
+            created_at: common_utils::date_time::now(),
+            key_version: 2,
+            previous_keys: Some(
+                encrypt_key_locally(
+                    key_manager_state,
+                    previous_key.to_vec().into(),
+                    identifier,
+                    master_key,
+                )
+                .await
+                .unwrap(),
+            ),
+        };
+
+        state
+            .store
+            .insert_merchant_key_store(key_store, &master_key.to_vec().into())
+            .await
+            .unwrap()
+    }
+
+    /// Inserts a customer whose name is encrypted with `key`
+    async fn insert_customer(
+        state: &SessionState,
+        key_store: &domain::MerchantKeyStore,
+        customer_id: &str,
+        key: &[u8],
+    ) {
+        let now = common_utils::date_time::now();
+        let name = crypto::GcmAes256
+            .encode_message(key, CUSTOMER_NAME)
+            .unwrap();
+        let customer = storage::Customer::from(storage::CustomerNew {
+            customer_id: id_type::CustomerId::try_from(Cow::from(customer_id.to_owned())).unwrap(),
+            merchant_id: key_store.merchant_id.clone(),
+            name: Some(Encryption::new(name.into())),
+            email: None,
+            phone: None,
+            description: None,
+            phone_country_code: None,
+            metadata: None,
+            connector_customer: None,
+            created_at: now,
+            modified_at: now,
+            address_id: None,
+            updated_by: None,
+            version: common_enums::ApiVersion::V1,
+            tax_registration_id: None,
+            created_by: None,
+            last_modified_by: None,
+            document_details: None,
+        });
+        let customer = domain::Customer::convert_back(
+            &state.into(),
+            customer,
+            &key_store.get_key_ring(),
+            key_store.merchant_id.clone().into(),
+        )
+        .await
+        .unwrap();
+
+        state
+            .store
+            .insert_customer(
+                customer,
+                key_store,
+                common_enums::MerchantStorageScheme::PostgresOnly,
+            )
+            .await
+            .unwrap();
+    }
+
+    /// Returns whether the name of each customer of the merchant, in ID order, decrypts with `key`
+    async fn customer_names_decrypt_with(
+        state: &SessionState,
+        merchant_id: &id_type::MerchantId,
+        key: &[u8],
+    ) -> Vec<bool> {
+        state
+            .store
+            .list_customers_for_key_rotation(merchant_id, String::new(), 10)
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|customer| {
+                customer
+                    .name
+                    .and_then(|name| {
+                        crypto::GcmAes256
+                            .decode_message(key, name.into_inner())
+                            .ok()
+                    })
+                    .is_some_and(|name| name == CUSTOMER_NAME)
+            })
+            .collect()
+    }
+
+    fn merchant_tracking_data(
+        merchant_id: &id_type::MerchantId,
+        stage: KeyRotationStage,
+    ) -> storage::KeyRotationTrackingData {
+        storage::KeyRotationTrackingData {
+            merchant_id: Some(merchant_id.clone()),
+            key_version: Some(2),
+            stage,
+            cursor: String::new(),
+            key_stores_re_encrypted: 0,
+            customers_re_encrypted: 0,
+            addresses_re_encrypted: 0,
+            payment_methods_re_encrypted: 0,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_re_encrypt_next_batch_moves_through_merchant_stages() {
+        let state = mock_session_state().await;
+        let key = services::generate_aes256_key().unwrap();
+        let previous_key = services::generate_aes256_key().unwrap();
+        let key_store =
+            insert_rotated_key_store(&state, "merchant_rotation_stages", &key, &previous_key).await;
+        insert_customer(&state, &key_store, "cus_rotated", &previous_key).await;
+        let mut tracking_data =
+            merchant_tracking_data(&key_store.merchant_id, KeyRotationStage::Customers);
+
+        assert!(!re_encrypt_next_batch(&state, &mut tracking_data)
+            .await
+            .unwrap());
+        assert_eq!(tracking_data.stage, KeyRotationStage::Addresses);
+        assert_eq!(tracking_data.customers_re_encrypted, 1);
+        assert!(tracking_data.cursor.is_empty());
+        assert_eq!(
+            customer_names_decrypt_with(&state, &key_store.merchant_id, &key).await,
+            vec![true]
+        );
+
+        assert!(!re_encrypt_next_batch(&state, &mut tracking_data)
+            .await
+            .unwrap());
+        assert_eq!(tracking_data.stage, KeyRotationStage::PaymentMethods);
+
+        assert!(re_encrypt_next_batch(&state, &mut tracking_data)
+            .await
+            .unwrap());
+        assert_eq!(tracking_data.stage, KeyRotationStage::Completed);
+
+        // A completed rotation has nothing left to re-encrypt
+        assert!(re_encrypt_next_batch(&state, &mut tracking_data)
+            .await
+            .unwrap());
+        assert_eq!(tracking_data.customers_re_encrypted, 1);
+        assert_eq!(tracking_data.addresses_re_encrypted, 0);
+        assert_eq!(tracking_data.payment_methods_re_encrypted, 0);
+    }
+
+    #[tokio::test]
+    async fn test_re_encrypt_next_batch_resumes_after_cursor() {
+        let state = mock_session_state().await;
+        let key = services::generate_aes256_key().unwrap();
+        let previous_key = services::generate_aes256_key().unwrap();
+        let key_store =
+            insert_rotated_key_store(&state, "merchant_rotation_resume", &key, &previous_key).await;
+        insert_customer(&state, &key_store, "cus_a", &previous_key).await;
+        insert_customer(&state, &key_store, "cus_b", &previous_key).await;
+        let mut tracking_data =
+            merchant_tracking_data(&key_store.merchant_id, KeyRotationStage::Customers);
+        tracking_data.cursor = "cus_a".to_string();
+        tracking_data.customers_re_encrypted = 1;
+
+        assert!(!re_encrypt_next_batch(&state, &mut tracking_data)
+            .await
+            .unwrap());
+        assert_eq!(tracking_data.stage, KeyRotationStage::Addresses);
+        assert_eq!(tracking_data.customers_re_encrypted, 2);
+        // Only the customer after the cursor was re-encrypted
+        assert_eq!(
+            customer_names_decrypt_with(&state, &key_store.merchant_id, &key).await,
+            vec![false, true]
+        );
+        assert_eq!(
+            customer_names_decrypt_with(&state, &key_store.merchant_id, &previous_key).await,
+            vec![true, false]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_re_encrypt_next_batch_pages_key_stores_by_merchant_id() {
+        let state = mock_session_state().await;
+        let key = services::generate_aes256_key().unwrap();
+        let previous_key = services::generate_aes256_key().unwrap();
+        insert_rotated_key_store(&state, "merchant_key_store_a", &key, &previous_key).await;
+        insert_rotated_key_store(&state, "merchant_key_store_b", &key, &previous_key).await;
+        let mut tracking_data = storage::KeyRotationTrackingData {
+            merchant_id: None,
+            key_version: None,
+            stage: KeyRotationStage::KeyStores,
+            cursor: "merchant_key_store_a".to_string(),
+            key_stores_re_encrypted: 1,
+            customers_re_encrypted: 0,
+            addresses_re_encrypted: 0,
+            payment_methods_re_encrypted: 0,
+        };
+
+        assert!(re_encrypt_next_batch(&state, &mut tracking_data)
+            .await
+            .unwrap());
+        assert_eq!(tracking_data.stage, KeyRotationStage::Completed);
+        assert_eq!(tracking_data.key_stores_re_encrypted, 2);
+        assert!(tracking_data.cursor.is_empty());
+    }
+}
@@ -86,6 +86,8 @@ counter_metric!(
     ROUTING_RETRIEVE_CONFIG_FOR_PROFILE_SUCCESS_RESPONSE,
     GLOBAL_METER
 );
+counter_metric!(ROUTING_SIMULATION, GLOBAL_METER);
+counter_metric!(ROUTING_SIMULATION_SUCCESS_RESPONSE, GLOBAL_METER);
 counter_metric!(DYNAMIC_SUCCESS_BASED_ROUTING, GLOBAL_METER);
 counter_metric!(DYNAMIC_CONTRACT_BASED_ROUTING, GLOBAL_METER);
 
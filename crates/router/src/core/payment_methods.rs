@@ -1811,7 +1811,7 @@ pub async fn create_volatile_payment_method_card_core(
             let domain_payment_method = domain::PaymentMethod::convert_back(
                 keymanager_state,
                 payment_method,
-                merchant_key_store.key.get_inner(),
+                &merchant_key_store.get_key_ring(),
                 merchant_key_store.merchant_id.clone().into(),
             )
             .await
@@ -4036,7 +4036,7 @@ async fn fetch_volatile_payment_method_record(
     let domain_payment_method = domain::PaymentMethod::convert_back(
         keymanager_state,
         payment_method,
-        key_store.key.get_inner(),
+        &key_store.get_key_ring(),
         key_store.merchant_id.clone().into(),
     )
     .await
@@ -2252,7 +2252,7 @@ pub async fn decode_and_decrypt_locker_data(
     key_store: &domain::MerchantKeyStore,
     enc_card_data: String,
 ) -> errors::CustomResult<Secret<String>, errors::VaultError> {
-    let key = key_store.key.get_inner().peek();
+    let key_ring = key_store.get_key_ring();
     let decoded_bytes = hex::decode(&enc_card_data)
         .change_context(errors::VaultError::ResponseDeserializationFailed)
         .attach_printable("Failed to decode hex string into bytes")?;
@@ -2264,7 +2264,7 @@ pub async fn decode_and_decrypt_locker_data(
             decoded_bytes.into(),
         ))),
         Identifier::Merchant(key_store.merchant_id.clone()),
-        key,
+        key_ring.peek(),
     )
     .await
     .and_then(|val| val.try_into_optionaloperation())
@@ -4960,14 +4960,14 @@ pub async fn decrypt_generic_data<T>(
 where
     T: serde::de::DeserializeOwned,
 {
-    let key = key_store.key.get_inner().peek();
+    let key_ring = key_store.get_key_ring();
     let identifier = Identifier::Merchant(key_store.merchant_id.clone());
     let decrypted_data = domain::types::crypto_operation::<serde_json::Value, masking::WithType>(
         &state.into(),
         type_name!(T),
         domain::types::CryptoOperation::DecryptOptional(data),
         identifier,
-        key,
+        key_ring.peek(),
     )
     .await
     .and_then(|val| val.try_into_optionaloperation())
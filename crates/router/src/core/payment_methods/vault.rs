@@ -1191,7 +1191,7 @@ impl Vault {
         merchant_key_store: &domain::MerchantKeyStore,
     ) -> RouterResult<(Option<domain::PaymentMethodData>, SupplementaryVaultData)> {
         let de_tokenize =
-            get_tokenized_data(state, lookup_key, true, merchant_key_store.key.get_inner()).await?;
+            get_tokenized_data(state, lookup_key, true, &merchant_key_store.get_key_ring()).await?;
         let (payment_method, customer_id) =
             domain::PaymentMethodData::from_values(de_tokenize.value1, de_tokenize.value2)
                 .change_context(errors::ApiErrorResponse::InternalServerError)
@@ -1248,7 +1248,7 @@ impl Vault {
         merchant_key_store: &domain::MerchantKeyStore,
     ) -> RouterResult<(Option<api::PayoutMethodData>, SupplementaryVaultData)> {
         let de_tokenize =
-            get_tokenized_data(state, lookup_key, true, merchant_key_store.key.get_inner()).await?;
+            get_tokenized_data(state, lookup_key, true, &merchant_key_store.get_key_ring()).await?;
         let (payout_method, supp_data) =
             api::PayoutMethodData::from_values(de_tokenize.value1, de_tokenize.value2)
                 .change_context(errors::ApiErrorResponse::InternalServerError)
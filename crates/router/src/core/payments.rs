@@ -623,7 +623,7 @@ pub async fn payments_operation_core<'a, F, Req, Op, FData, D>(
     platform: &domain::Platform,
     profile_id_from_auth_layer: Option<id_type::ProfileId>,
     operation: Op,
-    req: Req,
+    mut req: Req,
     call_connector_action: CallConnectorAction,
     shadow_ucs_call_connector_action: Option<CallConnectorAction>,
     auth_flow: services::AuthFlow,
@@ -649,6 +649,9 @@ where
     PaymentResponse: Operation<F, FData, Data = D>,
     FData: Send + Sync + Clone + router_types::Capturable + 'static + serde::Serialize,
 {
+    // Resources are created only in the profile the request is authenticated for
+    req.apply_profile_id_from_auth_layer(profile_id_from_auth_layer.as_ref())?;
+
     let operation: BoxedOperation<'_, F, Req, D> = Box::new(operation);
 
     tracing::Span::current().record(
@@ -1456,7 +1459,7 @@ pub async fn proxy_for_payments_operation_core<F, Req, Op, FData, D>(
     platform: domain::Platform,
     profile_id_from_auth_layer: Option<id_type::ProfileId>,
     operation: Op,
-    req: Req,
+    mut req: Req,
     call_connector_action: CallConnectorAction,
     auth_flow: services::AuthFlow,
     header_payload: HeaderPayload,
@@ -1482,6 +1485,9 @@ where
     PaymentResponse: Operation<F, FData, Data = D>,
     FData: Send + Sync + Clone,
 {
+    // Resources are created only in the profile the request is authenticated for
+    req.apply_profile_id_from_auth_layer(profile_id_from_auth_layer.as_ref())?;
+
     let operation: BoxedOperation<'_, F, Req, D> = Box::new(operation);
 
     tracing::Span::current().record(
@@ -8334,6 +8340,7 @@ pub struct PaymentEligibilityData {
     pub payment_method_data: Option<domain::PaymentMethodData>,
     pub payment_intent: storage::PaymentIntent,
     pub browser_info: Option<pii::SecretSerdeValue>,
+    pub billing_email: Option<pii::Email>,
 }
 
 #[cfg(feature = "v1")]
@@ -8368,10 +8375,16 @@ impl PaymentEligibilityData {
             )
             .await
             .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+        let billing_email = payments_eligibility_request
+            .payment_method_data
+            .billing
+            .as_ref()
+            .and_then(|billing| billing.email.clone());
         Ok(Self {
             payment_method_data,
             browser_info,
             payment_intent,
+            billing_email,
         })
     }
 }
@@ -11515,10 +11528,28 @@ impl EligibilityCheck for BlockListCheck {
         payment_elgibility_data: &PaymentEligibilityData,
         _business_profile: &domain::Profile,
     ) -> CustomResult<CheckResult, errors::ApiErrorResponse> {
+        let browser_info = payment_elgibility_data
+            .browser_info
+            .clone()
+            .and_then(|browser_info| {
+                browser_info
+                    .expose()
+                    .parse_value::<BrowserInformation>("BrowserInformation")
+                    .map_err(|error| {
+                        logger::error!(?error, "Failed to parse browser info for blocklist check");
+                    })
+                    .ok()
+            });
+        let payment_attributes = blocklist_utils::BlocklistPaymentAttributes::new(
+            &payment_elgibility_data.payment_intent,
+            payment_elgibility_data.billing_email.clone(),
+            browser_info,
+        );
         let should_payment_be_blocked = blocklist_utils::should_payment_be_blocked(
             state,
             platform.get_processor(),
             &payment_elgibility_data.payment_method_data,
+            &payment_attributes,
         )
         .await?;
         if should_payment_be_blocked {
@@ -11570,6 +11601,11 @@ impl EligibilityCheck for CardTestingCheck {
                         .map(|browser_info| browser_info.peek()),
                     card.card_number.clone(),
                     &payment_elgibility_data.payment_intent.customer_id,
+                    card_testing_guard_utils::VelocityRuleAttributes {
+                        payment_id: &payment_elgibility_data.payment_intent.payment_id,
+                        email: payment_elgibility_data.billing_email.as_ref(),
+                        record_attempt: false,
+                    },
                     business_profile,
                 )
                 .await
@@ -90,6 +90,7 @@ use crate::{
         authentication,
         configs::dimension_state::DimensionsWithMerchantIdAndProfileId,
         errors::{self, CustomResult, RouterResult, StorageErrorExt},
+        gsm,
         mandate::helpers::MandateGenericData,
         payment_methods::{
             self,
@@ -5177,6 +5178,7 @@ impl AttemptType {
             error_details: None,
             retry_type: Some(enums::RetryType::ManualRetry),
             installment_data: None,
+            retry_hop: None,
         }
     }
 
@@ -7343,14 +7345,38 @@ async fn perform_gsm_lookup(
     flow: &str,
     sub_flow: &str,
 ) -> Option<hyperswitch_domain_models::gsm::GatewayStatusMap> {
-    let get_gsm = || async {
-        state.store.find_gsm_rule(
+    let find_gsm_rule = || async {
+        let code = error_code.clone().unwrap_or_default(); // TODO: make changes in connector to get a mandatory code in case of success or error response
+        let message = error_message.clone().unwrap_or_default();
+        let not_found_error = match state
+            .store
+            .find_gsm_rule(
+                connector_name.clone(),
+                flow.to_string(),
+                sub_flow.to_string(),
+                code.clone(),
+                message.clone(),
+            )
+            .await
+        {
+            Err(err) if err.current_context().is_db_not_found() => err,
+            result => return result,
+        };
+
+        // Rules matching the code or the message with a pattern are only looked up when no rule
+        // matches both exactly
+        let pattern_rules = state
+            .store
+            .find_gsm_pattern_rules(
                 connector_name.clone(),
                 flow.to_string(),
                 sub_flow.to_string(),
-                error_code.clone().unwrap_or_default(), // TODO: make changes in connector to get a mandatory code in case of success or error response
-                error_message.clone().unwrap_or_default(),
             )
+            .await?;
+        gsm::find_matching_pattern_rule(pattern_rules, &code, &message).ok_or(not_found_error)
+    };
+    let get_gsm = || async {
+        find_gsm_rule()
             .await
             .map_err(|err| {
                 if err.current_context().is_db_not_found() {
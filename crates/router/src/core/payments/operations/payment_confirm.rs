@@ -971,15 +971,37 @@ impl<F: Send + Clone + Sync> GetTracker<F, PaymentData<F>, api::PaymentsRequest>
 
         match payment_method_data {
             Some(api_models::payments::PaymentMethodData::Card(card)) => {
-                payment_data.card_testing_guard_data =
+                let email = request.email.as_ref().or(payment_data
+                    .address
+                    .get_payment_billing()
+                    .and_then(|billing| billing.email.as_ref()));
+
+                let card_testing_guard_data =
                     card_testing_guard_utils::validate_card_testing_guard_checks(
                         state,
                         request.browser_info.as_ref(),
                         card.card_number.clone(),
                         customer_id,
+                        card_testing_guard_utils::VelocityRuleAttributes {
+                            payment_id: &payment_data.payment_intent.payment_id,
+                            email,
+                            record_attempt: true,
+                        },
                         business_profile,
                     )
                     .await?;
+
+                // Payments hitting a velocity rule with the `challenge` action are forced through 3DS
+                if card_testing_guard_data
+                    .as_ref()
+                    .is_some_and(|data| data.should_force_3ds_challenge)
+                {
+                    payment_data.payment_attempt.authentication_type =
+                        Some(common_enums::AuthenticationType::ThreeDs);
+                    payment_data.payment_intent.force_3ds_challenge = Some(true);
+                }
+
+                payment_data.card_testing_guard_data = card_testing_guard_data;
                 Ok(())
             }
             _ => Ok(()),
@@ -1068,6 +1068,26 @@ impl<F: Clone + Sync> UpdateTracker<F, PaymentData<F>, api::PaymentsRequest> for
             )
             .await
             .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+
+        // Scheduled once the payment exists, so that the reminder never refers to a payment whose
+        // creation failed
+        #[cfg(feature = "email")]
+        if let (Some(payment_link), Some(profile_id), Some(session_expiry)) = (
+            &payment_data.payment_link_data,
+            &payment_data.payment_intent.profile_id,
+            payment_data.payment_intent.session_expiry,
+        ) {
+            crate::core::customer_email::schedule_payment_link_reminder(
+                state,
+                &payment_data.payment_intent.merchant_id,
+                profile_id,
+                &payment_data.payment_intent.payment_id,
+                &payment_link.payment_link_id,
+                session_expiry,
+            )
+            .await;
+        }
+
         req_state
             .event_context
             .event(AuditEvent::new(AuditEventType::PaymentCreate))
@@ -1564,6 +1584,7 @@ impl PaymentCreate {
                 error_details: None,
                 retry_type: None,
                 installment_data: None,
+                retry_hop: None,
             },
             additional_pm_data,
 
@@ -1,7 +1,14 @@
-use std::vec::IntoIter;
+use std::{
+    time::{Duration, Instant},
+    vec::IntoIter,
+};
 
+#[cfg(feature = "v1")]
+use common_utils::crypto::SignMessage;
 use common_utils::{ext_traits::Encode, types::MinorUnit};
 use diesel_models::enums as storage_enums;
+#[cfg(feature = "v1")]
+use diesel_models::RetryHop;
 use error_stack::ResultExt;
 use hyperswitch_domain_models::ext_traits::OptionExt;
 use router_env::{
@@ -64,9 +71,20 @@ where
     dyn api::Connector: services::api::ConnectorIntegration<F, FData, types::PaymentsResponseData>,
 {
     let mut retries = None;
+    let retries_started_at = Instant::now();
 
     metrics::AUTO_RETRY_ELIGIBLE_REQUEST_COUNT.add(1, &[]);
 
+    let merchant_id = platform.get_processor().get_account().get_id();
+    let retry_policy = get_merchant_auto_retry_policy(state.store.as_ref(), merchant_id).await;
+    let card_fingerprint = get_card_fingerprint_for_soft_decline_ban(
+        state,
+        merchant_id,
+        &retry_policy,
+        payment_data.get_payment_method_data(),
+    )
+    .await;
+
     let card_network = payment_data.get_payment_attempt().extract_card_network();
 
     let mut initial_gsm = get_gsm(state, &router_data, card_network.clone()).await?;
@@ -101,6 +119,13 @@ where
     };
 
     if should_step_up {
+        let retry_hop = get_retry_hop(
+            storage_enums::RetryHopReason::StepUp,
+            payment_data.get_payment_attempt(),
+            &router_data,
+            initial_gsm.as_ref(),
+            Vec::new(),
+        );
         router_data = do_retry(
             &state.clone(),
             req_state.clone(),
@@ -119,6 +144,7 @@ where
             None,
             initial_gsm.clone(),
             feature_config,
+            retry_hop,
         )
         .await?;
     }
@@ -131,15 +157,19 @@ where
                 None => get_gsm(state, &router_data, card_network.clone()).await?,
             };
 
+            ban_soft_declined_connector(
+                state,
+                merchant_id,
+                &retry_policy,
+                card_fingerprint.as_deref(),
+                &router_data.connector,
+                gsm.as_ref(),
+            )
+            .await;
+
             match get_gsm_decision(gsm.clone()) {
                 storage_enums::GsmDecision::Retry => {
-                    retries = get_retries(
-                        state,
-                        retries,
-                        platform.get_processor().get_account().get_id(),
-                        business_profile,
-                    )
-                    .await;
+                    retries = get_retries(state, retries, merchant_id, business_profile).await;
 
                     if retries.is_none() || retries == Some(0) {
                         metrics::AUTO_RETRY_EXHAUSTED_COUNT.add(1, &[]);
@@ -147,6 +177,31 @@ where
                         break;
                     }
 
+                    if let Some(stop_reason) = retry_policy.get_stop_reason(
+                        retries_started_at,
+                        payment_data
+                            .get_payment_attempt()
+                            .net_amount
+                            .get_total_amount(),
+                        gsm.as_ref().and_then(|gsm| gsm.error_category),
+                    ) {
+                        metrics::AUTO_RETRY_POLICY_STOP_COUNT
+                            .add(1, router_env::metric_attributes!(("reason", stop_reason)));
+                        logger::info!(
+                            stop_reason,
+                            "auto retry policy of the merchant disallows further retries"
+                        );
+                        break;
+                    }
+
+                    let skipped_connectors = skip_soft_declined_connectors(
+                        state,
+                        merchant_id,
+                        card_fingerprint.as_deref(),
+                        &mut connector_routing_data,
+                    )
+                    .await;
+
                     if connector_routing_data.len() == 0 {
                         logger::info!("connectors exhausted for auto_retry payment");
                         metrics::AUTO_RETRY_EXHAUSTED_COUNT.add(1, &[]);
@@ -207,6 +262,18 @@ where
                         (connector_routing_data.connector_data, routing_decision)
                     };
 
+                    let retry_hop = get_retry_hop(
+                        if should_retry_with_pan {
+                            storage_enums::RetryHopReason::ClearPanRetry
+                        } else {
+                            storage_enums::RetryHopReason::GsmRetry
+                        },
+                        payment_data.get_payment_attempt(),
+                        &router_data,
+                        gsm.as_ref(),
+                        skipped_connectors,
+                    );
+
                     router_data = do_retry(
                         &state.clone(),
                         req_state.clone(),
@@ -226,6 +293,7 @@ where
                         routing_decision,
                         gsm.clone(),
                         feature_config,
+                        retry_hop,
                     )
                     .await?;
 
@@ -303,6 +371,232 @@ pub async fn get_retries(
     }
 }
 
+/// Policy bounding the auto retries of a merchant, stored as JSON in the configs table. Every
+/// limit is optional, so auto retries are only bounded by the retry count unless configured.
+#[derive(Debug, Clone, Default, serde::Deserialize)]
+#[serde(default)]
+pub struct AutoRetryPolicy {
+    /// Time in seconds since the first failure, after which no further retries are attempted
+    pub time_budget_in_secs: Option<u64>,
+    /// Payments with a net amount above this are not retried
+    pub max_amount: Option<MinorUnit>,
+    /// Time in seconds for which a connector which soft declined a card is skipped when retrying
+    /// payments made with the same card
+    pub soft_decline_ban_in_secs: Option<i64>,
+    /// Error categories which are not retried, as the connector may have acted on the request
+    pub non_retryable_error_categories: Vec<storage_enums::ErrorCategory>,
+}
+
+impl AutoRetryPolicy {
+    /// Returns the reason for which the policy disallows another retry, if any
+    pub fn get_stop_reason(
+        &self,
+        retries_started_at: Instant,
+        net_amount: MinorUnit,
+        error_category: Option<storage_enums::ErrorCategory>,
+    ) -> Option<&'static str> {
+        if self
+            .time_budget_in_secs
+            .is_some_and(|budget| retries_started_at.elapsed() >= Duration::from_secs(budget))
+        {
+            Some("time_budget_exhausted")
+        } else if self
+            .max_amount
+            .is_some_and(|max_amount| net_amount > max_amount)
+        {
+            Some("amount_above_ceiling")
+        } else if error_category
+            .is_some_and(|category| self.non_retryable_error_categories.contains(&category))
+        {
+            Some("non_retryable_error_category")
+        } else {
+            None
+        }
+    }
+}
+
+#[cfg(feature = "v1")]
+pub async fn get_merchant_auto_retry_policy(
+    db: &dyn StorageInterface,
+    merchant_id: &common_utils::id_type::MerchantId,
+) -> AutoRetryPolicy {
+    let key = merchant_id.get_auto_retry_policy_key();
+
+    db.find_config_by_key_unwrap_or(key.as_str(), Some("{}".to_string()))
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .and_then(|policy_config| {
+            serde_json::from_str::<AutoRetryPolicy>(&policy_config.config)
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Auto retry policy parsing failed")
+        })
+        .map_err(|err| {
+            logger::error!(auto_retry_policy_error=?err);
+        })
+        .ok()
+        .unwrap_or_default()
+}
+
+/// Fingerprints the card with the fingerprint secret of the merchant, so that soft declines can be
+/// tracked across payments made with the same card. Returns `None` if the merchant has not
+/// configured a soft decline ban or if the payment was not made with a card.
+#[cfg(feature = "v1")]
+async fn get_card_fingerprint_for_soft_decline_ban(
+    state: &app::SessionState,
+    merchant_id: &common_utils::id_type::MerchantId,
+    retry_policy: &AutoRetryPolicy,
+    payment_method_data: Option<&domain::PaymentMethodData>,
+) -> Option<String> {
+    retry_policy.soft_decline_ban_in_secs?;
+
+    let card_number = match payment_method_data {
+        Some(domain::PaymentMethodData::Card(card)) => card.card_number.get_card_no(),
+        _ => return None,
+    };
+
+    let fingerprint_secret =
+        crate::core::blocklist::utils::get_merchant_fingerprint_secret(state, merchant_id)
+            .await
+            .map_err(|error| {
+                logger::error!(
+                    ?error,
+                    "Failed to fetch the fingerprint secret of the merchant"
+                );
+            })
+            .ok()?;
+
+    common_utils::crypto::HmacSha256
+        .sign_message(fingerprint_secret.as_bytes(), card_number.as_bytes())
+        .map(hex::encode)
+        .map_err(|error| {
+            logger::error!(
+                ?error,
+                "Failed to fingerprint the card for soft decline ban"
+            );
+        })
+        .ok()
+}
+
+/// Bans the connector from being retried for the card if it soft declined the payment
+#[cfg(feature = "v1")]
+async fn ban_soft_declined_connector(
+    state: &app::SessionState,
+    merchant_id: &common_utils::id_type::MerchantId,
+    retry_policy: &AutoRetryPolicy,
+    card_fingerprint: Option<&str>,
+    connector: &str,
+    gsm: Option<&hyperswitch_domain_models::gsm::GatewayStatusMap>,
+) {
+    let (Some(ban_in_secs), Some(card_fingerprint)) =
+        (retry_policy.soft_decline_ban_in_secs, card_fingerprint)
+    else {
+        return;
+    };
+
+    if gsm.and_then(|gsm| gsm.error_category) != Some(storage_enums::ErrorCategory::SoftDecline) {
+        return;
+    }
+
+    let key = merchant_id.get_soft_decline_ban_key(card_fingerprint, connector);
+    let result = match state.store.get_redis_conn() {
+        Ok(redis_conn) => {
+            redis_conn
+                .set_key_with_expiry(&key.as_str().into(), true.to_string(), ban_in_secs)
+                .await
+        }
+        Err(error) => Err(error),
+    };
+
+    if let Err(error) = result {
+        logger::error!(
+            ?error,
+            "Failed to ban the soft declined connector for the card"
+        );
+    }
+}
+
+/// Skips the connectors at the head of the routing list which soft declined the card recently, so
+/// that the next retry is attempted on an eligible connector. Returns the skipped connectors.
+#[cfg(feature = "v1")]
+async fn skip_soft_declined_connectors(
+    state: &app::SessionState,
+    merchant_id: &common_utils::id_type::MerchantId,
+    card_fingerprint: Option<&str>,
+    connector_routing_data: &mut IntoIter<api::ConnectorRoutingData>,
+) -> Vec<String> {
+    let mut skipped_connectors = Vec::new();
+
+    let Some(card_fingerprint) = card_fingerprint else {
+        return skipped_connectors;
+    };
+
+    let redis_conn = match state.store.get_redis_conn() {
+        Ok(redis_conn) => redis_conn,
+        Err(error) => {
+            logger::error!(
+                ?error,
+                "Failed to get redis connection for soft decline ban"
+            );
+            return skipped_connectors;
+        }
+    };
+
+    while let Some(next_connector) = connector_routing_data.as_slice().first() {
+        let connector = next_connector.connector_data.connector_name.to_string();
+        let key = merchant_id.get_soft_decline_ban_key(card_fingerprint, &connector);
+
+        let is_banned = redis_conn
+            .exists::<Vec<u8>>(&key.as_str().into())
+            .await
+            .map_err(|error| {
+                logger::error!(
+                    ?error,
+                    "Failed to check the soft decline ban of the connector"
+                );
+            })
+            .unwrap_or(false);
+
+        if !is_banned {
+            break;
+        }
+
+        metrics::AUTO_RETRY_SOFT_DECLINED_CONNECTOR_SKIP_COUNT.add(
+            1,
+            router_env::metric_attributes!(("connector", connector.clone())),
+        );
+        logger::info!(
+            %connector,
+            "skipping connector which soft declined the card for auto_retry payment"
+        );
+        connector_routing_data.next();
+        skipped_connectors.push(connector);
+    }
+
+    skipped_connectors
+}
+
+#[cfg(feature = "v1")]
+fn get_retry_hop<F, FData>(
+    reason: storage_enums::RetryHopReason,
+    previous_attempt: &storage::PaymentAttempt,
+    router_data: &types::RouterData<F, FData, types::PaymentsResponseData>,
+    gsm: Option<&hyperswitch_domain_models::gsm::GatewayStatusMap>,
+    skipped_connectors: Vec<String>,
+) -> RetryHop {
+    RetryHop {
+        reason,
+        previous_attempt_id: previous_attempt.attempt_id.clone(),
+        previous_connector: previous_attempt.connector.clone(),
+        error_code: router_data
+            .response
+            .as_ref()
+            .err()
+            .map(|error_response| error_response.code.clone()),
+        error_category: gsm.and_then(|gsm| gsm.error_category),
+        skipped_connectors,
+    }
+}
+
 #[instrument(skip_all)]
 pub async fn get_gsm<F, FData>(
     state: &app::SessionState,
@@ -375,6 +669,7 @@ pub async fn do_retry<'a, F, ApiRequest, FData, D>(
     routing_decision: Option<routing_helpers::RoutingDecisionData>,
     initial_gsm: Option<hyperswitch_domain_models::gsm::GatewayStatusMap>,
     feature_config: &core_utils::FeatureConfig,
+    retry_hop: RetryHop,
 ) -> RouterResult<types::RouterData<F, FData, types::PaymentsResponseData>>
 where
     F: Clone + Send + Sync + std::fmt::Debug + 'static,
@@ -400,6 +695,7 @@ where
         router_data,
         is_step_up,
         initial_gsm,
+        retry_hop,
     )
     .await?;
 
@@ -506,6 +802,7 @@ pub async fn modify_trackers<F, FData, D>(
     router_data: types::RouterData<F, FData, types::PaymentsResponseData>,
     is_step_up: bool,
     initial_gsm: Option<hyperswitch_domain_models::gsm::GatewayStatusMap>,
+    retry_hop: RetryHop,
 ) -> RouterResult<()>
 where
     F: Clone + Send,
@@ -519,6 +816,7 @@ where
         new_attempt_count,
         is_step_up,
         payment_data.get_payment_intent().setup_future_usage,
+        retry_hop,
     );
 
     let db = &*state.store;
@@ -766,6 +1064,7 @@ pub fn make_new_auto_retry_payment_attempt(
     new_attempt_count: i16,
     is_step_up: bool,
     setup_future_usage_intent: Option<storage_enums::FutureUsage>,
+    retry_hop: RetryHop,
 ) -> storage::PaymentAttempt {
     let created_at @ modified_at @ last_synced = common_utils::date_time::now();
     storage::PaymentAttempt {
@@ -856,6 +1155,7 @@ pub fn make_new_auto_retry_payment_attempt(
         error_details: Default::default(),
         retry_type: Some(storage_enums::RetryType::AutoRetry),
         installment_data: Default::default(),
+        retry_hop: Some(retry_hop),
     }
 }
 
@@ -948,3 +1248,61 @@ impl<F: Send + Clone + Sync, FData: Send + Sync>
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_auto_retry_policy_without_limits_allows_retry() {
+        let policy = AutoRetryPolicy::default();
+
+        assert_eq!(
+            policy.get_stop_reason(
+                Instant::now(),
+                MinorUnit::new(1_000_000),
+                Some(storage_enums::ErrorCategory::ProcessorDowntime),
+            ),
+            None
+        );
+    }
+
+    #[test]
+    fn test_auto_retry_policy_stop_reasons() {
+        let policy = AutoRetryPolicy {
+            time_budget_in_secs: Some(0),
+            max_amount: Some(MinorUnit::new(5000)),
+            soft_decline_ban_in_secs: None,
+            non_retryable_error_categories: vec![storage_enums::ErrorCategory::ProcessorDowntime],
+        };
+        assert_eq!(
+            policy.get_stop_reason(Instant::now(), MinorUnit::new(100), None),
+            Some("time_budget_exhausted")
+        );
+
+        let policy = AutoRetryPolicy {
+            time_budget_in_secs: None,
+            ..policy
+        };
+        assert_eq!(
+            policy.get_stop_reason(Instant::now(), MinorUnit::new(5001), None),
+            Some("amount_above_ceiling")
+        );
+        assert_eq!(
+            policy.get_stop_reason(
+                Instant::now(),
+                MinorUnit::new(5000),
+                Some(storage_enums::ErrorCategory::ProcessorDowntime),
+            ),
+            Some("non_retryable_error_category")
+        );
+        assert_eq!(
+            policy.get_stop_reason(
+                Instant::now(),
+                MinorUnit::new(5000),
+                Some(storage_enums::ErrorCategory::SoftDecline),
+            ),
+            None
+        );
+    }
+}
@@ -74,7 +74,7 @@ use crate::{
         domain, storage as oss_storage,
         transformers::{ForeignFrom, ForeignInto, ForeignTryFrom},
     },
-    utils::{OptionExt, ValueExt},
+    utils::{currency, OptionExt, ValueExt},
     SessionState,
 };
 
@@ -215,6 +215,10 @@ pub fn make_dsl_input_for_payouts(
         acquirer_data: None,
         customer_device_data: None,
         issuer_data: None,
+        temporal: Some(dsl_inputs::TemporalInput {
+            timestamp: payout_data.payouts.created_at,
+        }),
+        exchange_rates: None,
     })
 }
 
@@ -340,6 +344,10 @@ pub fn make_dsl_input(
         acquirer_data: None,
         customer_device_data: None,
         issuer_data: None,
+        temporal: Some(dsl_inputs::TemporalInput {
+            timestamp: payments_dsl_input.payment_intent.created_at,
+        }),
+        exchange_rates: None,
     })
 }
 
@@ -651,6 +659,10 @@ pub fn make_dsl_input(
         acquirer_data: None,
         customer_device_data: None,
         issuer_data,
+        temporal: Some(dsl_inputs::TemporalInput {
+            timestamp: payments_dsl_input.payment_intent.created_at,
+        }),
+        exchange_rates: None,
     })
 }
 
@@ -869,7 +881,14 @@ pub async fn perform_static_routing_with_de(
         })
         .await;
 
-    let static_input = StaticRoutingInput { backend_input };
+    let mut backend_input = backend_input.clone();
+    if let Some(CachedAlgorithm::Advanced(interpreter)) = cached_algorithm.as_deref() {
+        add_exchange_rates_to_dsl_input(state, interpreter, &mut backend_input).await;
+    }
+
+    let static_input = StaticRoutingInput {
+        backend_input: &backend_input,
+    };
 
     let static_stage = cached_algorithm.map(|algo| StaticRoutingStage {
         ctx: RoutingContext {
@@ -1624,12 +1643,16 @@ pub async fn perform_static_routing_v1(
         }
     };
 
-    let backend_input = match transaction_data {
+    let mut backend_input = match transaction_data {
         routing::TransactionData::Payment(payment_data) => make_dsl_input(payment_data)?,
         #[cfg(feature = "payouts")]
         routing::TransactionData::Payout(payout_data) => make_dsl_input_for_payouts(payout_data)?,
     };
 
+    if let CachedAlgorithm::Advanced(interpreter) = cached_algorithm.as_ref() {
+        add_exchange_rates_to_dsl_input(state, interpreter, &mut backend_input).await;
+    }
+
     let payment_id = match transaction_data {
         routing::TransactionData::Payment(payment_data) => payment_data
             .payment_attempt
@@ -1812,6 +1835,30 @@ pub fn perform_routing_for_single_straight_through_algorithm(
     })
 }
 
+/// Attaches the forex rates to the input when the program evaluates `base_currency_amount` in a
+/// currency other than that of the payment. When the rates can't be fetched, the input is left
+/// as is and the rules on `base_currency_amount` do not match.
+pub async fn add_exchange_rates_to_dsl_input(
+    state: &SessionState,
+    interpreter: &backend::VirInterpreterBackend<ConnectorSelection>,
+    backend_input: &mut dsl_inputs::BackendInput,
+) {
+    let requires_conversion = interpreter
+        .get_base_currency()
+        .is_some_and(|base_currency| base_currency != backend_input.payment.currency);
+
+    if requires_conversion && backend_input.exchange_rates.is_none() {
+        let forex_api = state.conf.forex_api.get_inner();
+        match currency::get_forex_rates(state, forex_api.data_expiration_delay_in_seconds).await {
+            Ok(rates) => backend_input.exchange_rates = Some(rates.data),
+            Err(error) => logger::warn!(
+                ?error,
+                "euclid_routing: unable to fetch forex rates for evaluating base_currency_amount"
+            ),
+        }
+    }
+}
+
 fn execute_dsl_and_get_connector_v1(
     backend_input: dsl_inputs::BackendInput,
     interpreter: &backend::VirInterpreterBackend<ConnectorSelection>,
@@ -2433,6 +2480,10 @@ pub async fn perform_session_flow_routing<'a>(
         acquirer_data: None,
         customer_device_data: None,
         issuer_data: None,
+        temporal: Some(dsl_inputs::TemporalInput {
+            timestamp: session_input.payment_intent.created_at,
+        }),
+        exchange_rates: None,
     };
 
     for connector_data in session_input.chosen.iter() {
@@ -2589,6 +2640,10 @@ pub async fn perform_session_flow_routing(
         acquirer_data: None,
         customer_device_data: None,
         issuer_data: None,
+        temporal: Some(dsl_inputs::TemporalInput {
+            timestamp: session_input.payment_intent.created_at,
+        }),
+        exchange_rates: None,
     };
 
     for connector_data in session_input.chosen.iter() {
@@ -2702,13 +2757,20 @@ async fn perform_session_routing_for_pm_type(
                     .change_context(errors::RoutingError::ConnectorSelectionFailed)?,
                 Some(common_enums::RoutingApproach::VolumeBasedRouting),
             ),
-            CachedAlgorithm::Advanced(interpreter) => (
-                execute_dsl_and_get_connector_v1(
-                    session_pm_input.backend_input.clone(),
+            CachedAlgorithm::Advanced(interpreter) => {
+                let mut backend_input = session_pm_input.backend_input.clone();
+                add_exchange_rates_to_dsl_input(
+                    session_pm_input.state,
                     interpreter,
-                )?,
-                Some(common_enums::RoutingApproach::RuleBasedRouting),
-            ),
+                    &mut backend_input,
+                )
+                .await;
+
+                (
+                    execute_dsl_and_get_connector_v1(backend_input, interpreter)?,
+                    Some(common_enums::RoutingApproach::RuleBasedRouting),
+                )
+            }
         }
     } else {
         (
@@ -2791,10 +2853,11 @@ async fn get_chosen_connectors<'a>(
             CachedAlgorithm::Priority(plist) => plist.clone(),
             CachedAlgorithm::VolumeSplit(splits) => perform_volume_split(splits.to_vec())
                 .change_context(errors::RoutingError::ConnectorSelectionFailed)?,
-            CachedAlgorithm::Advanced(interpreter) => execute_dsl_and_get_connector_v1(
-                session_pm_input.backend_input.clone(),
-                interpreter,
-            )?,
+            CachedAlgorithm::Advanced(interpreter) => {
+                let mut backend_input = session_pm_input.backend_input.clone();
+                add_exchange_rates_to_dsl_input(state, interpreter, &mut backend_input).await;
+                execute_dsl_and_get_connector_v1(backend_input, interpreter)?
+            }
         }
     } else {
         profile_wrapper
@@ -2926,6 +2989,10 @@ pub fn make_dsl_input_for_surcharge(
         acquirer_data: None,
         customer_device_data: None,
         issuer_data: None,
+        temporal: Some(dsl_inputs::TemporalInput {
+            timestamp: payment_intent.created_at,
+        }),
+        exchange_rates: None,
     };
     Ok(backend_input)
 }
@@ -1363,6 +1363,24 @@ impl TryFrom<ast::Program<ConnectorSelection>> for Program {
     type Error = error_stack::Report<errors::RoutingError>;
 
     fn try_from(p: ast::Program<ConnectorSelection>) -> Result<Self, Self::Error> {
+        // The decision engine has no notion of the timezone and base currency of a program, so
+        // programs depending on them are not converted rather than evaluated differently there
+        if let Some(field) = p
+            .timezone
+            .as_ref()
+            .map(|_| "timezone")
+            .or(p.base_currency.as_ref().map(|_| "base_currency"))
+            .or_else(|| {
+                p.rules
+                    .iter()
+                    .find_map(|rule| find_decision_engine_unsupported_key(&rule.statements))
+            })
+        {
+            return Err(error_stack::report!(
+                errors::RoutingError::DecisionEngineUnsupportedField(field.to_string())
+            ));
+        }
+
         let rules = p
             .rules
             .into_iter()
@@ -1387,6 +1405,29 @@ impl TryFrom<ast::Program<ConnectorSelection>> for StaticRoutingAlgorithm {
     }
 }
 
+/// Keys evaluated in the timezone or base currency of the program
+const DECISION_ENGINE_UNSUPPORTED_KEYS: [&str; 4] =
+    ["time_of_day", "day_of_week", "date", "base_currency_amount"];
+
+fn find_decision_engine_unsupported_key(statements: &[ast::IfStatement]) -> Option<&'static str> {
+    statements.iter().find_map(|statement| {
+        statement
+            .condition
+            .iter()
+            .find_map(|comparison| {
+                DECISION_ENGINE_UNSUPPORTED_KEYS
+                    .into_iter()
+                    .find(|key| comparison.lhs == *key)
+            })
+            .or_else(|| {
+                statement
+                    .nested
+                    .as_deref()
+                    .and_then(find_decision_engine_unsupported_key)
+            })
+    })
+}
+
 fn convert_rule(rule: ast::Rule<ConnectorSelection>) -> RoutingResult<Rule> {
     let routing_type = match &rule.connector_selection {
         ConnectorSelection::Priority(_) => RoutingType::Priority,
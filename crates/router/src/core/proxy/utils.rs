@@ -102,7 +102,7 @@ impl ProxyRequestWrapper {
                         let domain_payment_method = domain::PaymentMethod::convert_back(
                             keymanager_state,
                             payment_method,
-                            key_store.key.get_inner(),
+                            &key_store.get_key_ring(),
                             key_store.merchant_id.clone().into(),
                         )
                         .await
@@ -0,0 +1,397 @@
+use error_stack::{report, ResultExt};
+use redis_interface::RedisKey;
+use router_env::{instrument, logger, tracing};
+
+use super::errors::{self, RouterResult};
+use crate::{
+    configs::settings::{RateLimitConfig, TokenBucketConfig},
+    routes::{metrics, SessionState},
+    services::authentication::AuthenticationType,
+};
+
+pub const RATE_LIMIT_PREFIX: &str = "RATE_LIMIT";
+
+/// Consumes a token from the bucket, after replenishing the tokens accumulated since the bucket
+/// was last updated. Returns whether the request is allowed, the tokens remaining in the bucket
+/// and the milliseconds after which a token would be available.
+const TOKEN_BUCKET_SCRIPT: &str = r#"
+local key = KEYS[1]
+local capacity = tonumber(ARGV[1])
+local refill_per_second = tonumber(ARGV[2])
+local now = tonumber(ARGV[3])
+local bucket = redis.call('HMGET', key, 'tokens', 'updated_at')
+local tokens = tonumber(bucket[1])
+local updated_at = tonumber(bucket[2])
+if tokens == nil or updated_at == nil then
+    tokens = capacity
+    updated_at = now
+end
+tokens = math.min(capacity, tokens + math.max(0, now - updated_at) * refill_per_second / 1000)
+local allowed = 0
+if tokens >= 1 then
+    tokens = tokens - 1
+    allowed = 1
+end
+redis.call('HSET', key, 'tokens', tostring(tokens), 'updated_at', now)
+redis.call('PEXPIRE', key, math.ceil(capacity * 1000 / refill_per_second))
+local retry_after = 0
+if allowed == 0 then
+    retry_after = math.ceil((1 - tokens) * 1000 / refill_per_second)
+end
+return {allowed, math.floor(tokens), retry_after}
+"#;
+
+/// Applies the token bucket limits configured for the tenant, or overridden for the merchant, to
+/// the request. Requests which are not made on behalf of a merchant are not limited.
+#[instrument(skip_all)]
+pub async fn check_rate_limits(
+    state: &SessionState,
+    auth_type: &AuthenticationType,
+    flow: &str,
+) -> RouterResult<()> {
+    if !state.conf.rate_limit.enabled {
+        return Ok(());
+    }
+
+    let Some(merchant_id) = auth_type.get_merchant_id() else {
+        return Ok(());
+    };
+
+    let rate_limit_config = get_rate_limit_config(state, merchant_id).await?;
+
+    // Only the profile the request is authenticated for is limited, since the profile
+    // requested in the headers or the body can be chosen freely by the client
+    let profile_id = auth_type.get_profile_id();
+
+    let buckets = [
+        rate_limit_config
+            .merchant
+            .map(|bucket| ("merchant".to_string(), bucket)),
+        rate_limit_config
+            .profile
+            .zip(profile_id)
+            .map(|(bucket, profile_id)| {
+                (format!("profile_{}", profile_id.get_string_repr()), bucket)
+            }),
+        rate_limit_config
+            .api_key
+            .zip(auth_type.get_api_key_id())
+            .map(|(bucket, key_id)| (format!("api_key_{}", key_id.get_string_repr()), bucket)),
+        rate_limit_config
+            .flows
+            .get(flow)
+            .map(|bucket| (format!("flow_{flow}"), *bucket)),
+    ];
+
+    for (scope, bucket) in buckets.into_iter().flatten() {
+        let key = format!(
+            "{}_{}_{}",
+            RATE_LIMIT_PREFIX,
+            merchant_id.get_string_repr(),
+            scope
+        );
+
+        consume_token(state, &key, bucket).await?;
+    }
+
+    Ok(())
+}
+
+async fn get_rate_limit_config(
+    state: &SessionState,
+    merchant_id: &common_utils::id_type::MerchantId,
+) -> RouterResult<RateLimitConfig> {
+    let tenant_config = state
+        .conf
+        .rate_limit
+        .get_tenant_config(&state.tenant.tenant_id);
+
+    // The default is cached when the merchant has no overrides, to avoid querying the database
+    // on every request
+    let merchant_overrides = state
+        .store
+        .find_config_by_key_unwrap_or(
+            &merchant_id.get_rate_limit_config_key(),
+            Some("{}".to_string()),
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the rate limit config of the merchant")?;
+
+    let merchant_overrides = serde_json::from_str::<RateLimitConfig>(&merchant_overrides.config)
+        .map_err(|error| {
+            logger::error!(
+                ?error,
+                "Failed to parse the rate limit config of the merchant"
+            );
+        })
+        .ok()
+        .filter(|overrides| {
+            overrides
+                .validate()
+                .map_err(|error| {
+                    logger::error!(?error, "Invalid rate limit config for the merchant");
+                })
+                .is_ok()
+        });
+
+    Ok(match merchant_overrides {
+        Some(overrides) => tenant_config.merge(overrides),
+        None => tenant_config.clone(),
+    })
+}
+
+/// Handles a failure to check the limits of a request. Requests are allowed unless the rate limiter
+/// is configured to fail closed, so that the APIs are not affected by an outage of the rate limiter.
+fn handle_rate_limiter_failure(fail_closed: bool, key: &str) -> RouterResult<()> {
+    metrics::RATE_LIMITER_FAILURES.add(
+        1,
+        router_env::metric_attributes!(("fail_closed", fail_closed)),
+    );
+
+    if fail_closed {
+        Err(report!(errors::ApiErrorResponse::InternalServerError))
+            .attach_printable_lazy(|| format!("Failed to check the rate limit for {key}"))
+    } else {
+        Ok(())
+    }
+}
+
+/// Consumes a token from the bucket
+async fn consume_token(
+    state: &SessionState,
+    key: &str,
+    bucket: TokenBucketConfig,
+) -> RouterResult<()> {
+    let fail_closed = state.conf.rate_limit.fail_closed;
+    let redis_conn = match state.store.get_redis_conn() {
+        Ok(redis_conn) => redis_conn,
+        Err(error) => {
+            logger::error!(?error, "Failed to get redis connection for rate limiting");
+            return handle_rate_limiter_failure(fail_closed, key);
+        }
+    };
+
+    let now_in_millis = common_utils::date_time::now()
+        .assume_utc()
+        .unix_timestamp_nanos()
+        / 1_000_000;
+
+    let result = redis_conn
+        .evaluate_redis_script::<_, Vec<i64>>(
+            TOKEN_BUCKET_SCRIPT,
+            vec![RedisKey::from(key).tenant_aware_key(&redis_conn)],
+            vec![
+                bucket.capacity.to_string(),
+                bucket.refill_per_second.to_string(),
+                now_in_millis.to_string(),
+            ],
+        )
+        .await;
+
+    handle_token_bucket_result(result, key, bucket, fail_closed)
+}
+
+/// Maps the result of the token bucket script to the outcome of the request, which is rejected
+/// with the limits of the bucket if no token was available.
+fn handle_token_bucket_result<E: std::fmt::Debug>(
+    result: Result<Vec<i64>, E>,
+    key: &str,
+    bucket: TokenBucketConfig,
+    fail_closed: bool,
+) -> RouterResult<()> {
+    let (allowed, remaining_tokens, retry_after_in_millis) = match result.as_deref() {
+        Ok([allowed, remaining_tokens, retry_after_in_millis]) => {
+            (*allowed, *remaining_tokens, *retry_after_in_millis)
+        }
+        Ok(_) | Err(_) => {
+            logger::error!(
+                ?result,
+                "Failed to evaluate the token bucket for rate limiting"
+            );
+            return handle_rate_limiter_failure(fail_closed, key);
+        }
+    };
+
+    if allowed == 1 {
+        return Ok(());
+    }
+
+    let capacity = u64::from(bucket.capacity);
+    let refill_per_second = u64::from(bucket.refill_per_second);
+    let remaining_tokens = u64::try_from(remaining_tokens).unwrap_or_default();
+
+    Err(report!(errors::ApiErrorResponse::RateLimitExceeded {
+        limit: bucket.capacity,
+        reset_in_secs: capacity
+            .saturating_sub(remaining_tokens)
+            .div_ceil(refill_per_second),
+        retry_after_in_secs: u64::try_from(retry_after_in_millis)
+            .unwrap_or_default()
+            .div_ceil(1000)
+            .max(1),
+    }))
+    .attach_printable_lazy(|| format!("Rate limit exceeded for {key}"))
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use std::collections::HashMap;
+
+    use actix_web::ResponseError;
+
+    use super::*;
+    use crate::configs::settings::RateLimitSettings;
+
+    const KEY: &str = "RATE_LIMIT_merchant_1_merchant";
+
+    fn bucket(capacity: u32, refill_per_second: u32) -> TokenBucketConfig {
+        TokenBucketConfig {
+            capacity,
+            refill_per_second,
+        }
+    }
+
+    #[test]
+    fn test_merchant_overrides_take_precedence_and_flows_are_merged() {
+        let tenant_config = RateLimitConfig {
+            merchant: Some(bucket(100, 10)),
+            profile: Some(bucket(50, 5)),
+            api_key: None,
+            flows: HashMap::from([
+                ("PaymentsCreate".to_string(), bucket(20, 2)),
+                ("RefundsCreate".to_string(), bucket(10, 1)),
+            ]),
+        };
+        let merchant_overrides = RateLimitConfig {
+            merchant: Some(bucket(500, 50)),
+            profile: None,
+            api_key: Some(bucket(30, 3)),
+            flows: HashMap::from([
+                ("PaymentsCreate".to_string(), bucket(40, 4)),
+                ("PaymentsRetrieve".to_string(), bucket(60, 6)),
+            ]),
+        };
+
+        let merged = tenant_config.merge(merchant_overrides);
+
+        assert_eq!(merged.merchant.map(|bucket| bucket.capacity), Some(500));
+        assert_eq!(merged.profile.map(|bucket| bucket.capacity), Some(50));
+        assert_eq!(merged.api_key.map(|bucket| bucket.capacity), Some(30));
+        assert_eq!(merged.flows.len(), 3);
+        assert_eq!(
+            merged
+                .flows
+                .get("PaymentsCreate")
+                .map(|bucket| bucket.capacity),
+            Some(40)
+        );
+        assert_eq!(
+            merged
+                .flows
+                .get("RefundsCreate")
+                .map(|bucket| bucket.capacity),
+            Some(10)
+        );
+        assert_eq!(
+            merged
+                .flows
+                .get("PaymentsRetrieve")
+                .map(|bucket| bucket.capacity),
+            Some(60)
+        );
+    }
+
+    #[test]
+    fn test_rate_limit_settings_validation() {
+        let valid_settings = RateLimitSettings {
+            enabled: true,
+            default: RateLimitConfig {
+                merchant: Some(bucket(100, 10)),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        assert!(valid_settings.validate().is_ok());
+
+        let zero_capacity_settings = RateLimitSettings {
+            enabled: true,
+            default: RateLimitConfig {
+                merchant: Some(bucket(0, 10)),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        assert!(zero_capacity_settings.validate().is_err());
+
+        let zero_refill_tenant_settings = RateLimitSettings {
+            enabled: true,
+            tenants: HashMap::from([(
+                common_utils::id_type::TenantId::try_from_string("public".to_string()).unwrap(),
+                RateLimitConfig {
+                    flows: HashMap::from([("PaymentsCreate".to_string(), bucket(10, 0))]),
+                    ..Default::default()
+                },
+            )]),
+            ..Default::default()
+        };
+        assert!(zero_refill_tenant_settings.validate().is_err());
+    }
+
+    #[test]
+    fn test_request_allowed_when_token_available() {
+        assert!(
+            handle_token_bucket_result::<()>(Ok(vec![1, 9, 0]), KEY, bucket(10, 2), true).is_ok()
+        );
+    }
+
+    #[test]
+    fn test_request_rejected_with_rate_limit_headers() {
+        let error =
+            handle_token_bucket_result::<()>(Ok(vec![0, 0, 1500]), KEY, bucket(10, 2), false)
+                .unwrap_err();
+
+        assert!(matches!(
+            error.current_context(),
+            errors::ApiErrorResponse::RateLimitExceeded {
+                limit: 10,
+                reset_in_secs: 5,
+                retry_after_in_secs: 2,
+            }
+        ));
+
+        let response = error.current_context().error_response();
+        let headers = response.headers();
+        assert_eq!(response.status(), actix_web::http::StatusCode::TOO_MANY_REQUESTS);
+        assert_eq!(headers.get("Retry-After").unwrap(), "2");
+        assert_eq!(headers.get("RateLimit-Limit").unwrap(), "10");
+        assert_eq!(headers.get("RateLimit-Remaining").unwrap(), "0");
+        assert_eq!(headers.get("RateLimit-Reset").unwrap(), "5");
+    }
+
+    #[test]
+    fn test_redis_failure_allowed_when_failing_open() {
+        assert!(
+            handle_token_bucket_result(Err("connection refused"), KEY, bucket(10, 2), false)
+                .is_ok()
+        );
+    }
+
+    #[test]
+    fn test_redis_failure_rejected_when_failing_closed() {
+        let error = handle_token_bucket_result(Err("connection refused"), KEY, bucket(10, 2), true)
+            .unwrap_err();
+
+        assert!(matches!(
+            error.current_context(),
+            errors::ApiErrorResponse::InternalServerError
+        ));
+    }
+
+    #[test]
+    fn test_malformed_script_result_handled_as_failure() {
+        assert!(handle_token_bucket_result::<()>(Ok(vec![1]), KEY, bucket(10, 2), false).is_ok());
+        assert!(handle_token_bucket_result::<()>(Ok(vec![1]), KEY, bucket(10, 2), true).is_err());
+    }
+}
@@ -31,6 +31,8 @@ use external_services::grpc_client::dynamic_routing::{
 use helpers::{
     enable_decision_engine_dynamic_routing_setup, update_decision_engine_dynamic_routing_setup,
 };
+#[cfg(all(feature = "v1", feature = "olap"))]
+use hyperswitch_domain_models::payments::payment_intent::PaymentIntentFetchConstraints;
 use hyperswitch_domain_models::{mandates, payment_address};
 use payment_methods::helpers::StorageErrorExt;
 use rustc_hash::FxHashSet;
@@ -975,6 +977,422 @@ pub async fn retrieve_routing_algorithm_from_algorithm_id(
     Ok(service_api::ApplicationResponse::Json(response))
 }
 
+/// A routing algorithm prepared for replaying payments through it
+#[cfg(all(feature = "v1", feature = "olap"))]
+struct SimulatedAlgorithm {
+    algorithm: payments_routing::CachedAlgorithm,
+    program: Option<euclid::frontend::ast::Program<routing_types::ConnectorSelection>>,
+}
+
+#[cfg(all(feature = "v1", feature = "olap"))]
+impl SimulatedAlgorithm {
+    fn new(routing_algorithm: RoutingAlgorithm) -> RouterResult<Self> {
+        let algorithm: routing_types::StaticRoutingAlgorithm = routing_algorithm
+            .algorithm_data
+            .parse_value("RoutingAlgorithm")
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("unable to parse routing algorithm")?;
+
+        Ok(match algorithm {
+            routing_types::StaticRoutingAlgorithm::Single(connector) => Self {
+                algorithm: payments_routing::CachedAlgorithm::Single(connector),
+                program: None,
+            },
+            routing_types::StaticRoutingAlgorithm::Priority(connectors) => Self {
+                algorithm: payments_routing::CachedAlgorithm::Priority(connectors),
+                program: None,
+            },
+            routing_types::StaticRoutingAlgorithm::VolumeSplit(splits) => Self {
+                algorithm: payments_routing::CachedAlgorithm::VolumeSplit(splits),
+                program: None,
+            },
+            routing_types::StaticRoutingAlgorithm::Advanced(program) => Self {
+                algorithm: payments_routing::CachedAlgorithm::Advanced(
+                    euclid::backend::VirInterpreterBackend::with_program(program.clone())
+                        .change_context(errors::ApiErrorResponse::InternalServerError)
+                        .attach_printable("Error initializing DSL interpreter backend")?,
+                ),
+                program: Some(program),
+            },
+            routing_types::StaticRoutingAlgorithm::ThreeDsDecisionRule(_) => {
+                Err(errors::ApiErrorResponse::InvalidRequestData {
+                    message: "Only payment routing algorithms can be simulated".to_string(),
+                })?
+            }
+        })
+    }
+
+    /// Whether the algorithm converts amounts to a base currency, and hence needs exchange rates
+    fn requires_exchange_rates(&self) -> bool {
+        match &self.algorithm {
+            payments_routing::CachedAlgorithm::Advanced(interpreter) => {
+                interpreter.get_base_currency().is_some()
+            }
+            payments_routing::CachedAlgorithm::Single(_)
+            | payments_routing::CachedAlgorithm::Priority(_)
+            | payments_routing::CachedAlgorithm::VolumeSplit(_) => false,
+        }
+    }
+
+    /// Returns the connector the payment would have been routed to, along with the name of the
+    /// rule that matched the payment, if any
+    fn route(
+        &self,
+        backend_input: &euclid::backend::inputs::BackendInput,
+        payment_id: &common_utils::id_type::PaymentId,
+    ) -> RouterResult<(
+        Option<routing_types::RoutableConnectorChoice>,
+        Option<String>,
+    )> {
+        Ok(match &self.algorithm {
+            payments_routing::CachedAlgorithm::Single(connector) => {
+                (Some(connector.as_ref().clone()), None)
+            }
+            payments_routing::CachedAlgorithm::Priority(connectors) => {
+                (connectors.first().cloned(), None)
+            }
+            payments_routing::CachedAlgorithm::VolumeSplit(splits) => {
+                (simulate_volume_split(splits, payment_id), None)
+            }
+            payments_routing::CachedAlgorithm::Advanced(interpreter) => {
+                let output =
+                    euclid::backend::EuclidBackend::execute(interpreter, backend_input.clone())
+                        .change_context(errors::ApiErrorResponse::InternalServerError)
+                        .attach_printable("Error executing routing algorithm")?;
+
+                let connector = match output.connector_selection {
+                    routing_types::ConnectorSelection::Priority(connectors) => {
+                        connectors.first().cloned()
+                    }
+                    routing_types::ConnectorSelection::VolumeSplit(splits) => {
+                        simulate_volume_split(&splits, payment_id)
+                    }
+                };
+
+                (connector, output.rule_name)
+            }
+        })
+    }
+}
+
+/// Picks a connector from the volume split based on the payment id instead of at random, such
+/// that replaying a payment through the same split always yields the same connector
+#[cfg(all(feature = "v1", feature = "olap"))]
+fn simulate_volume_split(
+    splits: &[routing_types::ConnectorVolumeSplit],
+    payment_id: &common_utils::id_type::PaymentId,
+) -> Option<routing_types::RoutableConnectorChoice> {
+    use sha2::Digest;
+
+    let total_split: u64 = splits.iter().map(|split| u64::from(split.split)).sum();
+    // A cryptographic digest is used, as its output, unlike that of the standard library hashers,
+    // is stable across Rust releases and hence simulations remain reproducible
+    let digest = sha2::Sha256::digest(payment_id.get_string_repr().as_bytes());
+    let (prefix, _) = digest.split_first_chunk::<8>()?;
+    let mut bucket = u64::from_be_bytes(*prefix).checked_rem(total_split)?;
+
+    splits
+        .iter()
+        .find(|split| {
+            let split = u64::from(split.split);
+            if bucket < split {
+                true
+            } else {
+                bucket -= split;
+                false
+            }
+        })
+        .map(|split| split.connector.clone())
+}
+
+#[cfg(all(feature = "v1", feature = "olap"))]
+#[derive(Default)]
+struct ConnectorSimulationTally {
+    active_volume: u64,
+    simulated_volume: u64,
+    amounts: std::collections::HashMap<
+        enums::Currency,
+        (
+            common_utils::types::MinorUnit,
+            common_utils::types::MinorUnit,
+        ),
+    >,
+}
+
+#[cfg(all(feature = "v1", feature = "olap"))]
+pub async fn simulate_routing_algorithm(
+    state: SessionState,
+    platform: domain::Platform,
+    authentication_profile_id: Option<common_utils::id_type::ProfileId>,
+    algorithm_id: common_utils::id_type::RoutingId,
+    request: routing_types::RoutingSimulationRequest,
+) -> RouterResponse<routing_types::RoutingSimulationResponse> {
+    metrics::ROUTING_SIMULATION.add(1, &[]);
+    let db = state.store.as_ref();
+    let processor = platform.get_processor();
+
+    let limit = request
+        .limit
+        .unwrap_or(crate::consts::ROUTING_SIMULATION_DEFAULT_LIMIT);
+    utils::when(
+        !(1..=crate::consts::ROUTING_SIMULATION_MAX_LIMIT).contains(&limit),
+        || {
+            Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!(
+                    "limit should be in between 1 and {}",
+                    crate::consts::ROUTING_SIMULATION_MAX_LIMIT
+                ),
+            })
+        },
+    )?;
+
+    let routing_algorithm = db
+        .find_routing_algorithm_by_algorithm_id_merchant_id(
+            &algorithm_id,
+            processor.get_account().get_id(),
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::ResourceIdNotFound)?;
+
+    utils::when(
+        routing_algorithm.algorithm_for != enums::TransactionType::Payment,
+        || {
+            Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: "Only payment routing algorithms can be simulated".to_string(),
+            })
+        },
+    )?;
+
+    let business_profile = core_utils::validate_and_get_business_profile(
+        db,
+        processor,
+        Some(&routing_algorithm.profile_id),
+    )
+    .await?
+    .get_required_value("Profile")
+    .change_context(errors::ApiErrorResponse::ResourceIdNotFound)?;
+
+    core_utils::validate_profile_id_from_auth_layer(authentication_profile_id, &business_profile)?;
+
+    let active_algorithm_id = business_profile
+        .routing_algorithm
+        .clone()
+        .map(|val| val.parse_value::<routing_types::RoutingAlgorithmRef>("RoutingAlgorithmRef"))
+        .transpose()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("unable to deserialize routing algorithm ref from business profile")?
+        .and_then(|routing_ref| routing_ref.algorithm_id);
+
+    let active_algorithm = match &active_algorithm_id {
+        Some(active_algorithm_id) => Some(SimulatedAlgorithm::new(
+            db.find_routing_algorithm_by_profile_id_algorithm_id(
+                business_profile.get_id(),
+                active_algorithm_id,
+            )
+            .await
+            .to_not_found_response(errors::ApiErrorResponse::ResourceIdNotFound)?,
+        )?),
+        None => None,
+    };
+    let simulated_algorithm = SimulatedAlgorithm::new(routing_algorithm)?;
+
+    let mut payments = Vec::new();
+    let mut offset = 0;
+    while offset < limit {
+        let page_limit = (limit - offset).min(common_utils::consts::PAYMENTS_LIST_MAX_LIMIT_V1);
+        let mut constraints = PaymentIntentFetchConstraints::from(request.time_range);
+        if let PaymentIntentFetchConstraints::List(params) = &mut constraints {
+            params.offset = offset;
+            params.limit = Some(page_limit);
+            params.profile_id = Some(vec![business_profile.get_id().clone()]);
+        }
+
+        let page = db
+            .get_filtered_payment_intents_attempt(
+                processor.get_account().get_id(),
+                &constraints,
+                processor.get_key_store(),
+                processor.get_account().storage_scheme,
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to fetch payments for routing simulation")?;
+
+        let is_last_page = page.len() < usize::try_from(page_limit).unwrap_or(usize::MAX);
+        offset += page_limit;
+        payments.extend(page);
+
+        if is_last_page {
+            break;
+        }
+    }
+
+    let mut total_payments = 0;
+    let mut rerouted_payments = 0;
+    let mut matched_rules = HashSet::new();
+    let mut tally: std::collections::HashMap<
+        (
+            enums::RoutableConnectors,
+            Option<common_utils::id_type::MerchantConnectorAccountId>,
+        ),
+        ConnectorSimulationTally,
+    > = std::collections::HashMap::new();
+
+    // The rates are fetched once for the entire run, so that every payment is converted using the
+    // same set of rates
+    let exchange_rates = if simulated_algorithm.requires_exchange_rates()
+        || active_algorithm
+            .as_ref()
+            .is_some_and(SimulatedAlgorithm::requires_exchange_rates)
+    {
+        let forex_api = state.conf.forex_api.get_inner();
+        crate::utils::currency::get_forex_rates(&state, forex_api.data_expiration_delay_in_seconds)
+            .await
+            .map(|rates| rates.data)
+            .map_err(|error| {
+                router_env::logger::warn!(
+                    ?error,
+                    "routing_simulation: unable to fetch forex rates for evaluating base_currency_amount"
+                )
+            })
+            .ok()
+    } else {
+        None
+    };
+
+    for (payment_intent, payment_attempt) in &payments {
+        // Only the payments that went through routing are replayed
+        let Some(currency) = payment_attempt
+            .connector
+            .as_ref()
+            .and(payment_attempt.currency.or(payment_intent.currency))
+        else {
+            continue;
+        };
+
+        let payment_address = payment_address::PaymentAddress::default();
+        let dsl_input = PaymentsDslInput::new(
+            None,
+            payment_attempt,
+            payment_intent,
+            None,
+            &payment_address,
+            None,
+            currency,
+        );
+        let mut backend_input = payments_routing::make_dsl_input(&dsl_input)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to construct routing input for routing simulation")?;
+        backend_input.payment_method.card_network = backend_input
+            .payment_method
+            .card_network
+            .or_else(|| payment_attempt.extract_card_network());
+        backend_input.exchange_rates = exchange_rates.clone();
+
+        let active_connector = match &active_algorithm {
+            Some(active_algorithm) => {
+                active_algorithm
+                    .route(&backend_input, &payment_attempt.payment_id)?
+                    .0
+            }
+            None => None,
+        };
+        let (simulated_connector, rule_name) =
+            simulated_algorithm.route(&backend_input, &payment_attempt.payment_id)?;
+
+        total_payments += 1;
+        if let Some(rule_name) = rule_name {
+            matched_rules.insert(rule_name);
+        }
+        if active_connector != simulated_connector {
+            rerouted_payments += 1;
+        }
+
+        let amount = backend_input.payment.amount;
+        if let Some(connector) = active_connector {
+            let connector_tally = tally
+                .entry((connector.connector, connector.merchant_connector_id))
+                .or_default();
+            connector_tally.active_volume += 1;
+            let amounts = connector_tally.amounts.entry(currency).or_default();
+            amounts.0 = amounts.0 + amount;
+        }
+        if let Some(connector) = simulated_connector {
+            let connector_tally = tally
+                .entry((connector.connector, connector.merchant_connector_id))
+                .or_default();
+            connector_tally.simulated_volume += 1;
+            let amounts = connector_tally.amounts.entry(currency).or_default();
+            amounts.1 = amounts.1 + amount;
+        }
+    }
+
+    let mut connectors: Vec<routing_types::ConnectorSimulationResult> = tally
+        .into_iter()
+        .map(|((connector, merchant_connector_id), connector_tally)| {
+            let mut amounts: Vec<routing_types::CurrencyAmountDelta> = connector_tally
+                .amounts
+                .into_iter()
+                .map(|(currency, (active_amount, simulated_amount))| {
+                    routing_types::CurrencyAmountDelta {
+                        currency,
+                        active_amount,
+                        simulated_amount,
+                        amount_delta: simulated_amount - active_amount,
+                    }
+                })
+                .collect();
+            amounts.sort_by_key(|amount| amount.currency.to_string());
+
+            routing_types::ConnectorSimulationResult {
+                connector,
+                merchant_connector_id,
+                active_volume: connector_tally.active_volume,
+                simulated_volume: connector_tally.simulated_volume,
+                volume_delta: i64::try_from(connector_tally.simulated_volume)
+                    .unwrap_or(i64::MAX)
+                    .saturating_sub(
+                        i64::try_from(connector_tally.active_volume).unwrap_or(i64::MAX),
+                    ),
+                amounts,
+            }
+        })
+        .collect();
+    connectors.sort_by_key(|result| result.connector.to_string());
+
+    let unmatched_rules = match simulated_algorithm.program {
+        Some(program) => {
+            let dir_program = euclid::frontend::ast::lowering::lower_program(program)
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to lower routing program for analysis")?;
+            let matched_rules: FxHashSet<&str> = matched_rules.iter().map(String::as_str).collect();
+
+            euclid::dssa::analyzer::analyze_unmatched_rules(&dir_program, &matched_rules, None)
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to analyze unmatched routing rules")?
+                .into_iter()
+                .map(|rule| routing_types::UnmatchedRoutingRule {
+                    rule_name: rule.rule_name,
+                    unreachable_reason: rule.unreachable.map(|error| error.to_string()),
+                })
+                .collect()
+        }
+        None => Vec::new(),
+    };
+
+    metrics::ROUTING_SIMULATION_SUCCESS_RESPONSE.add(1, &[]);
+    Ok(service_api::ApplicationResponse::Json(
+        routing_types::RoutingSimulationResponse {
+            algorithm_id,
+            active_algorithm_id,
+            total_payments,
+            rerouted_payments,
+            connectors,
+            unmatched_rules,
+        },
+    ))
+}
+
 #[cfg(feature = "v2")]
 pub async fn unlink_routing_config_under_profile(
     state: SessionState,
@@ -69,6 +69,12 @@ impl ForeignTryFrom<RoutingAlgorithm> for MerchantRoutingAlgorithm {
                 .parse_value::<api_models::routing::StaticRoutingAlgorithm>("RoutingAlgorithm")
                 .map(RoutingAlgorithmWrapper::Static)?,
         };
+        let algorithm_text = match &algorithm {
+            RoutingAlgorithmWrapper::Static(static_algorithm) => {
+                static_algorithm.get_program_text()
+            }
+            RoutingAlgorithmWrapper::Dynamic(_) => None,
+        };
 
         Ok(Self {
             id: value.algorithm_id,
@@ -76,6 +82,7 @@ impl ForeignTryFrom<RoutingAlgorithm> for MerchantRoutingAlgorithm {
             profile_id: value.profile_id,
             description: value.description.unwrap_or_default(),
             algorithm,
+            algorithm_text,
             created_at: value.created_at.assume_utc().unix_timestamp(),
             modified_at: value.modified_at.assume_utc().unix_timestamp(),
             algorithm_for: value.algorithm_for,
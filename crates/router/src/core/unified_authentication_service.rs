@@ -858,7 +858,11 @@ pub async fn authentication_create_core(
                 common_utils::types::keymanager::Identifier::Merchant(
                     platform.get_processor().get_key_store().merchant_id.clone(),
                 ),
-                platform.get_processor().get_key_store().key.peek(),
+                platform
+                    .get_processor()
+                    .get_key_store()
+                    .get_key_ring()
+                    .peek(),
             )
             .await
             .and_then(|val| val.try_into_optionaloperation())
@@ -2356,7 +2360,7 @@ pub async fn get_session_token_for_click_to_pay(
                 common_utils::types::keymanager::Identifier::Merchant(
                     processor.get_key_store().merchant_id.clone(),
                 ),
-                processor.get_key_store().key.peek(),
+                processor.get_key_store().get_key_ring().peek(),
             )
             .await
             .and_then(|val| val.try_into_optionaloperation())
@@ -915,6 +915,48 @@ mod tests {
         ];
         assert_eq!(filtered_list, expected_result);
     }
+
+    #[test]
+    fn test_get_request_profile_id_from_auth_layer() {
+        let auth_profile_id =
+            common_utils::id_type::ProfileId::try_from(std::borrow::Cow::from("pro_auth"))
+                .expect("invalid profile ID");
+        let other_profile_id =
+            common_utils::id_type::ProfileId::try_from(std::borrow::Cow::from("pro_other"))
+                .expect("invalid profile ID");
+
+        // The request defaults to the authenticated profile
+        assert_eq!(
+            get_request_profile_id_from_auth_layer(Some(&auth_profile_id), None)
+                .expect("profile id should be resolved"),
+            Some(auth_profile_id.clone())
+        );
+        assert_eq!(
+            get_request_profile_id_from_auth_layer(
+                Some(&auth_profile_id),
+                Some(auth_profile_id.clone())
+            )
+            .expect("profile id should be resolved"),
+            Some(auth_profile_id.clone())
+        );
+        assert!(get_request_profile_id_from_auth_layer(
+            Some(&auth_profile_id),
+            Some(other_profile_id.clone())
+        )
+        .is_err());
+
+        // Requests not authenticated for a profile can use any profile
+        assert_eq!(
+            get_request_profile_id_from_auth_layer(None, Some(other_profile_id.clone()))
+                .expect("profile id should be resolved"),
+            Some(other_profile_id)
+        );
+        assert_eq!(
+            get_request_profile_id_from_auth_layer(None, None)
+                .expect("profile id should be resolved"),
+            None
+        );
+    }
 }
 
 // Dispute Stage can move linearly from PreDispute -> Dispute -> PreArbitration -> Arbitration -> DisputeReversal
@@ -2506,6 +2548,28 @@ pub(crate) fn validate_profile_id_from_auth_layer<T: GetProfileId + std::fmt::De
     }
 }
 
+/// Resolves the profile provided in a request made by a client authenticated for a profile, such as
+/// with an API key restricted to the profile. The profile in the request must be the one the client
+/// is authenticated for, and defaults to it when not provided, so that resources are never created
+/// in a different profile.
+pub(crate) fn get_request_profile_id_from_auth_layer(
+    profile_id_auth_layer: Option<&common_utils::id_type::ProfileId>,
+    request_profile_id: Option<common_utils::id_type::ProfileId>,
+) -> RouterResult<Option<common_utils::id_type::ProfileId>> {
+    match (profile_id_auth_layer, request_profile_id) {
+        (Some(auth_profile_id), Some(request_profile_id))
+            if *auth_profile_id != request_profile_id =>
+        {
+            Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+                message: "Profile id authentication failed. The profile id in the request must be the authenticated profile id"
+                    .to_string(),
+            }))
+        }
+        (Some(auth_profile_id), _) => Ok(Some(auth_profile_id.clone())),
+        (None, request_profile_id) => Ok(request_profile_id),
+    }
+}
+
 pub async fn construct_vault_router_data<F>(
     state: &SessionState,
     merchant_id: &common_utils::id_type::MerchantId,
@@ -10,6 +10,8 @@ mod outgoing;
 mod outgoing_v2;
 #[cfg(all(feature = "revenue_recovery", feature = "v2"))]
 pub mod recovery_incoming;
+#[cfg(feature = "v1")]
+pub mod signing_keys;
 pub mod types;
 pub mod utils;
 #[cfg(feature = "olap")]
@@ -20,7 +22,7 @@ pub(crate) use self::{
     incoming::{incoming_webhooks_wrapper, network_token_incoming_webhooks_wrapper},
     outgoing::{
         create_event_and_trigger_outgoing_webhook, get_outgoing_webhook_request,
-        trigger_webhook_and_raise_event,
+        get_webhook_endpoint_from_business_profile, trigger_webhook_and_raise_event,
     },
 };
 #[cfg(feature = "v2")]
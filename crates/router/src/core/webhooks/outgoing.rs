@@ -32,7 +32,7 @@ use crate::{
     events::outgoing_webhook_logs::{
         OutgoingWebhookEvent, OutgoingWebhookEventContent, OutgoingWebhookEventMetric,
     },
-    logger,
+    headers, logger,
     routes::{app::SessionStateInfo, SessionState},
     services,
     types::{
@@ -58,26 +58,83 @@ pub(crate) async fn create_event_and_trigger_outgoing_webhook(
     content: api::OutgoingWebhookContent,
     primary_object_created_at: Option<time::PrimitiveDateTime>,
 ) -> CustomResult<(), errors::ApiErrorResponse> {
-    let delivery_attempt = enums::WebhookDeliveryAttempt::InitialAttempt;
-    let idempotent_event_id =
-        utils::get_idempotent_event_id(&primary_object_id, event_type, delivery_attempt)
-            .change_context(errors::ApiErrorResponse::WebhookProcessingFailure)
-            .attach_printable("Failed to generate idempotent event ID")?;
-    let webhook_url_result = get_webhook_url_from_business_profile(&business_profile);
+    // Customer emails are sent regardless of whether the merchant receives webhooks
+    #[cfg(feature = "email")]
+    crate::core::customer_email::schedule_customer_email_for_event(
+        &state,
+        &business_profile,
+        event_type,
+        &content,
+    )
+    .await;
 
-    if !state.conf.webhooks.outgoing_enabled
-        || webhook_url_result.is_err()
-        || webhook_url_result.as_ref().is_ok_and(String::is_empty)
-    {
+    let webhook_endpoints = get_webhook_endpoints_for_event(&business_profile, event_type);
+
+    if !state.conf.webhooks.outgoing_enabled || webhook_endpoints.is_empty() {
         logger::debug!(
             business_profile_id=?business_profile.get_id(),
-            %idempotent_event_id,
-            "Outgoing webhooks are disabled in application configuration, or merchant webhook URL \
-             could not be obtained; skipping outgoing webhooks for event"
+            %primary_object_id,
+            ?event_type,
+            "Outgoing webhooks are disabled in application configuration, or no merchant webhook \
+             endpoints are configured for the event; skipping outgoing webhooks for event"
         );
         return Ok(());
     }
 
+    let mut result = Ok(());
+    for webhook_endpoint in webhook_endpoints {
+        let webhook_endpoint_id = webhook_endpoint.webhook_endpoint_id.clone();
+        // A failure for one endpoint must not prevent delivery to the other endpoints
+        if let Err(error) = Box::pin(create_event_and_trigger_outgoing_webhook_for_endpoint(
+            state.clone(),
+            processor.clone(),
+            business_profile.clone(),
+            webhook_endpoint,
+            event_type,
+            event_class,
+            primary_object_id.clone(),
+            primary_object_type,
+            content.clone(),
+            primary_object_created_at,
+        ))
+        .await
+        {
+            logger::error!(
+                ?error,
+                ?webhook_endpoint_id,
+                "Failed to create and trigger outgoing webhook for endpoint"
+            );
+            result = Err(error);
+        }
+    }
+
+    result
+}
+
+#[allow(clippy::too_many_arguments)]
+#[instrument(skip_all)]
+async fn create_event_and_trigger_outgoing_webhook_for_endpoint(
+    state: SessionState,
+    processor: domain::Processor,
+    business_profile: domain::Profile,
+    webhook_endpoint: WebhookEndpoint,
+    event_type: enums::EventType,
+    event_class: enums::EventClass,
+    primary_object_id: String,
+    primary_object_type: enums::EventObjectType,
+    content: api::OutgoingWebhookContent,
+    primary_object_created_at: Option<time::PrimitiveDateTime>,
+) -> CustomResult<(), errors::ApiErrorResponse> {
+    let delivery_attempt = enums::WebhookDeliveryAttempt::InitialAttempt;
+    let idempotent_event_id = utils::get_idempotent_event_id(
+        &primary_object_id,
+        event_type,
+        delivery_attempt,
+        webhook_endpoint.webhook_endpoint_id.as_ref(),
+    )
+    .change_context(errors::ApiErrorResponse::WebhookProcessingFailure)
+    .attach_printable("Failed to generate idempotent event ID")?;
+
     let event_id = utils::generate_event_id();
     let merchant_id = business_profile.merchant_id.clone();
     let now = common_utils::date_time::now();
@@ -90,10 +147,14 @@ pub(crate) async fn create_event_and_trigger_outgoing_webhook(
         timestamp: now,
     };
 
-    let request_content =
-        get_outgoing_webhook_request(&processor, outgoing_webhook, &business_profile)
-            .change_context(errors::ApiErrorResponse::WebhookProcessingFailure)
-            .attach_printable("Failed to construct outgoing webhook request content")?;
+    let request_content = get_outgoing_webhook_request(
+        &processor,
+        outgoing_webhook,
+        &business_profile,
+        &webhook_endpoint,
+    )
+    .change_context(errors::ApiErrorResponse::WebhookProcessingFailure)
+    .attach_printable("Failed to construct outgoing webhook request content")?;
 
     let event_metadata = storage::EventMetadata::foreign_from(&content);
     let key_manager_state = &(&state).into();
@@ -133,6 +194,7 @@ pub(crate) async fn create_event_and_trigger_outgoing_webhook(
         delivery_attempt: Some(delivery_attempt),
         metadata: Some(event_metadata),
         is_overall_delivery_successful: Some(false),
+        webhook_endpoint_id: webhook_endpoint.webhook_endpoint_id,
     };
 
     let lock_value = utils::perform_redis_lock(
@@ -281,17 +343,23 @@ async fn trigger_webhook_to_merchant(
     delivery_attempt: enums::WebhookDeliveryAttempt,
     process_tracker: Option<storage::ProcessTracker>,
 ) -> CustomResult<(), errors::WebhooksFlowError> {
-    let webhook_url = match (
-        get_webhook_url_from_business_profile(&business_profile),
+    let webhook_endpoint = match (
+        get_webhook_endpoint_from_business_profile(
+            &business_profile,
+            event.webhook_endpoint_id.as_ref(),
+        ),
         process_tracker.clone(),
     ) {
-        (Ok(webhook_url), _) => Ok(webhook_url),
+        (Ok(webhook_endpoint), _) => Ok(webhook_endpoint),
         (Err(error), Some(process_tracker)) => {
             if !error
                 .current_context()
                 .is_webhook_delivery_retryable_error()
             {
-                logger::debug!("Failed to obtain merchant webhook URL, aborting retries");
+                logger::debug!(
+                    webhook_endpoint_id=?event.webhook_endpoint_id,
+                    "Failed to obtain merchant webhook URL, aborting retries"
+                );
                 state
                     .store
                     .as_scheduler()
@@ -308,14 +376,39 @@ async fn trigger_webhook_to_merchant(
 
     let event_id = event.event_id;
 
+    // The timestamped signature is computed at the time of sending the webhook, so that retries
+    // carry a fresh timestamp and are signed with the signing keys valid at that time. The webhook
+    // is still delivered with the legacy signature if the timestamped signature cannot be computed.
+    let timestamped_signature = super::signing_keys::get_timestamped_webhook_signature(
+        &state,
+        &business_profile,
+        merchant_key_store,
+        &webhook_endpoint,
+        request_content.body.peek(),
+    )
+    .await
+    .unwrap_or_else(|error| {
+        logger::error!(
+            ?error,
+            "Failed to compute timestamped webhook signature, sending the legacy signature alone"
+        );
+        None
+    });
+
     let headers = request_content
         .headers
         .into_iter()
         .map(|(name, value)| (name, value.into_masked()))
+        .chain(timestamped_signature.map(|signature| {
+            (
+                headers::X_WEBHOOK_SIGNATURE_V2.to_string(),
+                signature.into_masked(),
+            )
+        }))
         .collect();
     let request = services::RequestBuilder::new()
         .method(services::Method::Post)
-        .url(&webhook_url)
+        .url(&webhook_endpoint.webhook_url)
         .attach_default_headers()
         .headers(headers)
         .set_body(RequestContent::RawBytes(
@@ -622,6 +715,19 @@ pub(crate) async fn add_outgoing_webhook_retry_task_to_process_tracker(
     }
 }
 
+/// A destination for outgoing webhooks configured on a business profile.
+#[derive(Clone, Debug)]
+pub(crate) struct WebhookEndpoint {
+    /// `None` for the primary webhook URL of the business profile.
+    pub(crate) webhook_endpoint_id: Option<common_utils::id_type::WebhookEndpointId>,
+    pub(crate) webhook_url: String,
+    /// The key used to sign webhooks delivered to the endpoint.
+    pub(crate) signing_key: Option<Secret<String>>,
+    /// The secret configured specifically for the endpoint, which is used in place of the
+    /// signing keys of the profile for the timestamped signature.
+    pub(crate) endpoint_secret: Option<Secret<String>>,
+}
+
 fn get_webhook_url_from_business_profile(
     business_profile: &domain::Profile,
 ) -> CustomResult<String, errors::WebhooksFlowError> {
@@ -638,15 +744,98 @@ fn get_webhook_url_from_business_profile(
         .map(ExposeInterface::expose)
 }
 
+/// Obtain the webhook endpoint with the specified ID, or the primary webhook URL of the business
+/// profile if no ID is specified. Endpoints which are not active are treated as not configured.
+pub(crate) fn get_webhook_endpoint_from_business_profile(
+    business_profile: &domain::Profile,
+    webhook_endpoint_id: Option<&common_utils::id_type::WebhookEndpointId>,
+) -> CustomResult<WebhookEndpoint, errors::WebhooksFlowError> {
+    let profile_signing_key = business_profile
+        .payment_response_hash_key
+        .clone()
+        .map(Secret::new);
+
+    let Some(webhook_endpoint_id) = webhook_endpoint_id else {
+        return get_webhook_url_from_business_profile(business_profile).map(|webhook_url| {
+            WebhookEndpoint {
+                webhook_endpoint_id: None,
+                webhook_url,
+                signing_key: profile_signing_key,
+                endpoint_secret: None,
+            }
+        });
+    };
+
+    business_profile
+        .webhook_details
+        .as_ref()
+        .and_then(|webhook_details| webhook_details.multiple_webhooks_list.as_ref())
+        .and_then(|webhook_endpoints| {
+            webhook_endpoints.iter().find(|webhook_endpoint| {
+                &webhook_endpoint.webhook_endpoint_id == webhook_endpoint_id
+                    && webhook_endpoint.status == enums::OutgoingWebhookEndpointStatus::Active
+            })
+        })
+        .map(|webhook_endpoint| WebhookEndpoint {
+            webhook_endpoint_id: Some(webhook_endpoint.webhook_endpoint_id.clone()),
+            webhook_url: webhook_endpoint.webhook_url.clone().expose(),
+            signing_key: webhook_endpoint
+                .webhook_secret
+                .clone()
+                .or(profile_signing_key),
+            endpoint_secret: webhook_endpoint.webhook_secret.clone(),
+        })
+        .ok_or(report!(errors::WebhooksFlowError::WebhookEndpointNotActive))
+        .attach_printable_lazy(|| {
+            format!("Webhook endpoint `{webhook_endpoint_id:?}` is not configured or not active")
+        })
+}
+
+/// Obtain all webhook endpoints of the business profile to which the event must be delivered:
+/// the primary webhook URL, if configured, and the active endpoints subscribed to the event type.
+fn get_webhook_endpoints_for_event(
+    business_profile: &domain::Profile,
+    event_type: enums::EventType,
+) -> Vec<WebhookEndpoint> {
+    let primary_webhook_endpoint =
+        get_webhook_endpoint_from_business_profile(business_profile, None)
+            .ok()
+            .filter(|webhook_endpoint| !webhook_endpoint.webhook_url.is_empty());
+
+    let additional_webhook_endpoints = business_profile
+        .webhook_details
+        .as_ref()
+        .and_then(|webhook_details| webhook_details.multiple_webhooks_list.as_ref())
+        .into_iter()
+        .flatten()
+        .filter(|webhook_endpoint| {
+            webhook_endpoint.events.is_empty() || webhook_endpoint.events.contains(&event_type)
+        })
+        .filter_map(|webhook_endpoint| {
+            get_webhook_endpoint_from_business_profile(
+                business_profile,
+                Some(&webhook_endpoint.webhook_endpoint_id),
+            )
+            .ok()
+        });
+
+    primary_webhook_endpoint
+        .into_iter()
+        .chain(additional_webhook_endpoints)
+        .collect()
+}
+
 pub(crate) fn get_outgoing_webhook_request(
     processor: &domain::Processor,
     outgoing_webhook: api::OutgoingWebhook,
     business_profile: &domain::Profile,
+    webhook_endpoint: &WebhookEndpoint,
 ) -> CustomResult<OutgoingWebhookRequestContent, errors::WebhooksFlowError> {
     #[inline]
     fn get_outgoing_webhook_request_inner<WebhookType: types::OutgoingWebhookType>(
         outgoing_webhook: api::OutgoingWebhook,
         business_profile: &domain::Profile,
+        webhook_endpoint: &WebhookEndpoint,
     ) -> CustomResult<OutgoingWebhookRequestContent, errors::WebhooksFlowError> {
         let mut headers = vec![
             (
@@ -660,7 +849,7 @@ pub(crate) fn get_outgoing_webhook_request(
         ];
 
         let transformed_outgoing_webhook = WebhookType::from(outgoing_webhook);
-        let payment_response_hash_key = business_profile.payment_response_hash_key.clone();
+        let signing_key = webhook_endpoint.signing_key.clone();
         let custom_headers = business_profile
             .outgoing_webhook_custom_http_headers
             .clone()
@@ -680,7 +869,7 @@ pub(crate) fn get_outgoing_webhook_request(
             );
         };
         let outgoing_webhooks_signature = transformed_outgoing_webhook
-            .get_outgoing_webhooks_signature(payment_response_hash_key)?;
+            .get_outgoing_webhooks_signature(signing_key.as_ref().map(|key| key.peek()))?;
 
         if let Some(signature) = outgoing_webhooks_signature.signature {
             WebhookType::add_webhook_header(&mut headers, signature)
@@ -697,12 +886,17 @@ pub(crate) fn get_outgoing_webhook_request(
 
     match processor.get_account().get_compatible_connector() {
         #[cfg(feature = "stripe")]
-        Some(api_models::enums::Connector::Stripe) => get_outgoing_webhook_request_inner::<
-            stripe_webhooks::StripeOutgoingWebhook,
-        >(outgoing_webhook, business_profile),
+        Some(api_models::enums::Connector::Stripe) => {
+            get_outgoing_webhook_request_inner::<stripe_webhooks::StripeOutgoingWebhook>(
+                outgoing_webhook,
+                business_profile,
+                webhook_endpoint,
+            )
+        }
         _ => get_outgoing_webhook_request_inner::<webhooks::OutgoingWebhook>(
             outgoing_webhook,
             business_profile,
+            webhook_endpoint,
         ),
     }
 }
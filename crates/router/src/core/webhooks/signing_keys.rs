@@ -0,0 +1,385 @@
+#[cfg(feature = "olap")]
+use api_models::admin;
+use common_utils::{crypto::SignMessage, type_name, types::keymanager::Identifier};
+use error_stack::ResultExt;
+use hyperswitch_domain_models::type_encryption::{crypto_operation, CryptoOperation};
+use masking::{PeekInterface, Secret};
+use router_env::{instrument, tracing};
+
+use super::outgoing::WebhookEndpoint;
+#[cfg(feature = "olap")]
+use crate::{
+    consts,
+    core::errors::{RouterResponse, StorageErrorExt},
+    services::ApplicationResponse,
+};
+use crate::{
+    core::errors::{self, CustomResult},
+    routes::SessionState,
+    types::{domain, storage},
+};
+
+/// Generates the value of the timestamped webhook signature header for the specified payload.
+///
+/// The header value is of the form `t=<unix timestamp>,v1=<signature>[,v1=<signature>...]`,
+/// where each signature is the hex encoded HMAC-SHA512 of `<unix timestamp>.<payload>`. Webhooks
+/// delivered to an endpoint with its own secret are signed with that secret alone, while the
+/// others are signed with every signing key of the profile that is valid at the time of sending
+/// the webhook, newest key first. Returns `None` if no signing keys have been created for the
+/// profile.
+#[instrument(skip_all)]
+pub(crate) async fn get_timestamped_webhook_signature(
+    state: &SessionState,
+    business_profile: &domain::Profile,
+    merchant_key_store: &domain::MerchantKeyStore,
+    webhook_endpoint: &WebhookEndpoint,
+    payload: &str,
+) -> CustomResult<Option<String>, errors::WebhooksFlowError> {
+    let now = common_utils::date_time::now();
+    let timestamp = now.assume_utc().unix_timestamp();
+
+    if let Some(endpoint_secret) = &webhook_endpoint.endpoint_secret {
+        return get_timestamped_signature_header(timestamp, payload, [endpoint_secret]).map(Some);
+    }
+
+    let signing_keys = state
+        .store
+        .list_webhook_signing_keys_by_profile_id(business_profile.get_id())
+        .await
+        .change_context(errors::WebhooksFlowError::OutgoingWebhookSigningFailed)
+        .attach_printable("Failed to list webhook signing keys for profile")?
+        .into_iter()
+        .filter(|signing_key| signing_key.is_valid_at(now))
+        .collect::<Vec<_>>();
+
+    if signing_keys.is_empty() {
+        return Ok(None);
+    }
+
+    let mut secrets = Vec::with_capacity(signing_keys.len());
+    for signing_key in signing_keys {
+        secrets
+            .push(decrypt_signing_key_secret(state, merchant_key_store, signing_key.secret).await?);
+    }
+
+    get_timestamped_signature_header(timestamp, payload, &secrets).map(Some)
+}
+
+fn get_timestamped_signature_header<'a>(
+    timestamp: i64,
+    payload: &str,
+    secrets: impl IntoIterator<Item = &'a Secret<String>>,
+) -> CustomResult<String, errors::WebhooksFlowError> {
+    let signed_payload = format!("{timestamp}.{payload}");
+    let mut header_value = format!("t={timestamp}");
+
+    for secret in secrets {
+        let signature = common_utils::crypto::HmacSha512
+            .sign_message(secret.peek().as_bytes(), signed_payload.as_bytes())
+            .change_context(errors::WebhooksFlowError::OutgoingWebhookSigningFailed)
+            .attach_printable("Failed to sign the message")?;
+        header_value.push_str(",v1=");
+        header_value.push_str(&hex::encode(signature));
+    }
+
+    Ok(header_value)
+}
+
+async fn decrypt_signing_key_secret(
+    state: &SessionState,
+    merchant_key_store: &domain::MerchantKeyStore,
+    secret: common_utils::encryption::Encryption,
+) -> CustomResult<Secret<String>, errors::WebhooksFlowError> {
+    crypto_operation(
+        &state.into(),
+        type_name!(storage::WebhookSigningKey),
+        CryptoOperation::Decrypt(secret),
+        Identifier::Merchant(merchant_key_store.merchant_id.clone()),
+        merchant_key_store.get_key_ring().peek(),
+    )
+    .await
+    .and_then(|val| val.try_into_operation())
+    .map(|secret| secret.into_inner())
+    .change_context(errors::WebhooksFlowError::OutgoingWebhookSigningFailed)
+    .attach_printable("Failed to decrypt webhook signing key secret")
+}
+
+#[cfg(feature = "olap")]
+#[instrument(skip(state, merchant_key_store))]
+pub async fn rotate_webhook_signing_key(
+    state: SessionState,
+    merchant_id: common_utils::id_type::MerchantId,
+    merchant_key_store: domain::MerchantKeyStore,
+    request: admin::WebhookSigningKeyRotateRequestInternal,
+) -> RouterResponse<admin::WebhookSigningKeyResponse> {
+    let db = state.store.as_ref();
+    let profile_id = request.profile_id;
+    let overlap_window_in_secs = request
+        .request
+        .overlap_window_in_secs
+        .unwrap_or(consts::DEFAULT_WEBHOOK_SIGNING_KEY_OVERLAP_WINDOW_IN_SECS);
+
+    common_utils::fp_utils::when(
+        overlap_window_in_secs > consts::MAX_WEBHOOK_SIGNING_KEY_OVERLAP_WINDOW_IN_SECS,
+        || {
+            Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!(
+                    "`overlap_window_in_secs` must not exceed {}",
+                    consts::MAX_WEBHOOK_SIGNING_KEY_OVERLAP_WINDOW_IN_SECS
+                ),
+            })
+        },
+    )?;
+
+    db.find_business_profile_by_merchant_id_profile_id(
+        &merchant_key_store,
+        &merchant_id,
+        &profile_id,
+    )
+    .await
+    .to_not_found_response(errors::ApiErrorResponse::ProfileNotFound {
+        id: profile_id.get_string_repr().to_owned(),
+    })?;
+
+    let existing_keys = db
+        .list_webhook_signing_keys_by_profile_id(&profile_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list webhook signing keys for profile")?;
+
+    let now = common_utils::date_time::now();
+    let version = existing_keys
+        .iter()
+        .map(|signing_key| signing_key.version)
+        .max()
+        .unwrap_or(0)
+        .checked_add(1)
+        .ok_or(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Webhook signing key version overflowed")?;
+
+    let secret = Secret::new(
+        common_utils::crypto::generate_cryptographically_secure_random_string(
+            consts::WEBHOOK_SIGNING_KEY_SECRET_LENGTH,
+        ),
+    );
+    let encrypted_secret = crypto_operation(
+        &(&state).into(),
+        type_name!(storage::WebhookSigningKey),
+        CryptoOperation::Encrypt(secret.clone()),
+        Identifier::Merchant(merchant_key_store.merchant_id.clone()),
+        merchant_key_store.key.get_inner().peek(),
+    )
+    .await
+    .and_then(|val| val.try_into_operation())
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to encrypt webhook signing key secret")?;
+
+    // The previously active keys continue to sign webhooks until the overlap window elapses
+    let previous_key_update = if overlap_window_in_secs == 0 {
+        storage::WebhookSigningKeyUpdate::StatusUpdate {
+            status: common_enums::WebhookSigningKeyStatus::Retired,
+            expires_at: Some(now),
+        }
+    } else {
+        storage::WebhookSigningKeyUpdate::StatusUpdate {
+            status: common_enums::WebhookSigningKeyStatus::Expiring,
+            expires_at: Some(now + time::Duration::seconds(i64::from(overlap_window_in_secs))),
+        }
+    };
+
+    let new_signing_key = db
+        .rotate_webhook_signing_key(
+            storage::WebhookSigningKeyNew {
+                key_id: common_utils::generate_id(
+                    consts::ID_LENGTH,
+                    consts::WEBHOOK_SIGNING_KEY_ID_PREFIX,
+                ),
+                merchant_id,
+                profile_id,
+                version,
+                secret: encrypted_secret.into(),
+                status: common_enums::WebhookSigningKeyStatus::Active,
+                created_at: now,
+                modified_at: now,
+                expires_at: None,
+            },
+            previous_key_update,
+        )
+        .await
+        .to_duplicate_response(errors::ApiErrorResponse::GenericDuplicateError {
+            message: "A webhook signing key rotation is already in progress for the profile"
+                .to_string(),
+        })?;
+
+    Ok(ApplicationResponse::Json(get_webhook_signing_key_response(
+        new_signing_key,
+        Some(secret),
+    )))
+}
+
+#[cfg(feature = "olap")]
+#[instrument(skip(state))]
+pub async fn list_webhook_signing_keys(
+    state: SessionState,
+    merchant_id: common_utils::id_type::MerchantId,
+    merchant_key_store: domain::MerchantKeyStore,
+    profile_id: common_utils::id_type::ProfileId,
+) -> RouterResponse<Vec<admin::WebhookSigningKeyResponse>> {
+    let db = state.store.as_ref();
+    db.find_business_profile_by_merchant_id_profile_id(
+        &merchant_key_store,
+        &merchant_id,
+        &profile_id,
+    )
+    .await
+    .to_not_found_response(errors::ApiErrorResponse::ProfileNotFound {
+        id: profile_id.get_string_repr().to_owned(),
+    })?;
+
+    let signing_keys = db
+        .list_webhook_signing_keys_by_profile_id(&profile_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list webhook signing keys for profile")?;
+
+    Ok(ApplicationResponse::Json(
+        signing_keys
+            .into_iter()
+            .map(|signing_key| get_webhook_signing_key_response(signing_key, None))
+            .collect(),
+    ))
+}
+
+#[cfg(feature = "olap")]
+#[instrument(skip(state))]
+pub async fn retire_webhook_signing_key(
+    state: SessionState,
+    merchant_id: common_utils::id_type::MerchantId,
+    merchant_key_store: domain::MerchantKeyStore,
+    request: admin::WebhookSigningKeyRetireRequest,
+) -> RouterResponse<admin::WebhookSigningKeyResponse> {
+    let db = state.store.as_ref();
+    let profile_id = request.profile_id;
+    db.find_business_profile_by_merchant_id_profile_id(
+        &merchant_key_store,
+        &merchant_id,
+        &profile_id,
+    )
+    .await
+    .to_not_found_response(errors::ApiErrorResponse::ProfileNotFound {
+        id: profile_id.get_string_repr().to_owned(),
+    })?;
+
+    let signing_key = db
+        .find_webhook_signing_key_by_profile_id_key_id(&profile_id, &request.key_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::GenericNotFoundError {
+            message: format!("Webhook signing key `{}` not found", request.key_id),
+        })?;
+
+    match signing_key.status {
+        common_enums::WebhookSigningKeyStatus::Active => {
+            Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: "The active webhook signing key cannot be retired, rotate the key instead"
+                    .to_string(),
+            })?
+        }
+        common_enums::WebhookSigningKeyStatus::Retired => {
+            return Ok(ApplicationResponse::Json(get_webhook_signing_key_response(
+                signing_key,
+                None,
+            )));
+        }
+        common_enums::WebhookSigningKeyStatus::Expiring => {}
+    }
+
+    let updated_signing_key = db
+        .update_webhook_signing_key_by_profile_id_key_id(
+            &profile_id,
+            &request.key_id,
+            storage::WebhookSigningKeyUpdate::StatusUpdate {
+                status: common_enums::WebhookSigningKeyStatus::Retired,
+                expires_at: Some(common_utils::date_time::now()),
+            },
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to retire webhook signing key")?;
+
+    Ok(ApplicationResponse::Json(get_webhook_signing_key_response(
+        updated_signing_key,
+        None,
+    )))
+}
+
+#[cfg(feature = "olap")]
+fn get_webhook_signing_key_response(
+    signing_key: storage::WebhookSigningKey,
+    secret: Option<Secret<String>>,
+) -> admin::WebhookSigningKeyResponse {
+    admin::WebhookSigningKeyResponse {
+        key_id: signing_key.key_id,
+        profile_id: signing_key.profile_id,
+        version: signing_key.version,
+        status: signing_key.status,
+        secret,
+        created_at: signing_key.created_at,
+        expires_at: signing_key.expires_at,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use common_utils::crypto::{SignMessage, VerifySignature};
+    use masking::{PeekInterface, Secret};
+
+    use super::get_timestamped_signature_header;
+
+    #[test]
+    fn test_timestamped_signature_header() {
+        let payload = r#"{"event_type":"payment_succeeded"}"#;
+        let new_secret = Secret::new("new_secret".to_string());
+        let old_secret = Secret::new("old_secret".to_string());
+
+        let header_value =
+            get_timestamped_signature_header(1700000000, payload, [&new_secret, &old_secret])
+                .unwrap();
+
+        let mut parts = header_value.split(',');
+        assert_eq!(parts.next(), Some("t=1700000000"));
+
+        let signed_payload = format!("1700000000.{payload}");
+        for secret in [&new_secret, &old_secret] {
+            let signature = parts.next().unwrap().strip_prefix("v1=").unwrap();
+            assert!(common_utils::crypto::HmacSha512
+                .verify_signature(
+                    secret.peek().as_bytes(),
+                    &hex::decode(signature).unwrap(),
+                    signed_payload.as_bytes(),
+                )
+                .unwrap());
+        }
+        assert_eq!(parts.next(), None);
+    }
+
+    #[test]
+    fn test_timestamped_signature_header_covers_timestamp() {
+        let secret = Secret::new("secret".to_string());
+        let header_value = get_timestamped_signature_header(1700000000, "{}", [&secret]).unwrap();
+
+        let expected_signature = common_utils::crypto::HmacSha512
+            .sign_message(secret.peek().as_bytes(), b"1700000000.{}")
+            .unwrap();
+        let replayed_signature = common_utils::crypto::HmacSha512
+            .sign_message(secret.peek().as_bytes(), b"1700000001.{}")
+            .unwrap();
+
+        assert_eq!(
+            header_value,
+            format!("t=1700000000,v1={}", hex::encode(expected_signature))
+        );
+        assert!(!header_value.contains(&hex::encode(replayed_signature)));
+    }
+}
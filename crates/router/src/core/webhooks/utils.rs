@@ -157,12 +157,20 @@ pub(crate) fn get_idempotent_event_id(
     primary_object_id: &str,
     event_type: types::storage::enums::EventType,
     delivery_attempt: types::storage::enums::WebhookDeliveryAttempt,
+    webhook_endpoint_id: Option<&common_utils::id_type::WebhookEndpointId>,
 ) -> Result<String, Report<errors::WebhooksFlowError>> {
     use crate::types::storage::enums::WebhookDeliveryAttempt;
 
     const EVENT_ID_SUFFIX_LENGTH: usize = 8;
 
-    let common_prefix = format!("{primary_object_id}_{event_type}");
+    // Deliveries of the same event to different webhook endpoints are distinct events
+    let common_prefix = match webhook_endpoint_id {
+        Some(webhook_endpoint_id) => format!(
+            "{primary_object_id}_{event_type}_{}",
+            webhook_endpoint_id.get_string_repr()
+        ),
+        None => format!("{primary_object_id}_{event_type}"),
+    };
 
     // Hash the common prefix with SHA256 and encode with URL-safe base64 without padding
     let digest = crypto::Sha256
@@ -16,12 +16,14 @@ pub mod dynamic_routing_stats;
 pub mod ephemeral_key;
 pub mod events;
 pub mod file;
+pub mod forex_rate_snapshot;
 pub mod fraud_check;
 pub mod generic_link;
 pub mod gsm;
 pub mod health_check;
 pub mod hyperswitch_ai_interaction;
 pub mod kafka_store;
+pub mod key_rotation;
 pub mod locker_mock_up;
 pub mod mandate;
 pub mod merchant_account;
@@ -40,6 +42,7 @@ pub mod user;
 pub mod user_authentication_method;
 pub mod user_key_store;
 pub mod user_role;
+pub mod webhook_signing_key;
 
 use ::payment_methods::state::PaymentMethodsStorageInterface;
 use common_utils::{id_type, types::keymanager::KeyManagerState};
@@ -106,6 +109,8 @@ pub trait StorageInterface:
     + ephemeral_key::ClientSecretInterface
     + events::EventInterface
     + file::FileMetadataInterface
+    + forex_rate_snapshot::ForexRateSnapshotInterface
+    + key_rotation::KeyRotationInterface
     + FraudCheckInterface
     + locker_mock_up::LockerMockUpInterface
     + mandate::MandateInterface
@@ -147,6 +152,7 @@ pub trait StorageInterface:
     + callback_mapper::CallbackMapperInterface
     + storage_impl::subscription::SubscriptionInterface<Error = StorageError>
     + storage_impl::invoice::InvoiceInterface<Error = StorageError>
+    + webhook_signing_key::WebhookSigningKeyInterface
     + 'static
 {
     fn get_scheduler_db(&self) -> Box<dyn scheduler::SchedulerInterface>;
@@ -110,7 +110,7 @@ mod storage {
                         .convert(
                             self.get_keymanager_state()
                                 .attach_printable("Missing KeyManagerState")?,
-                            key_store.key.get_inner(),
+                            &key_store.get_key_ring(),
                             key_store.merchant_id.clone().into(),
                         )
                         .await
@@ -139,7 +139,7 @@ mod storage {
             .map_err(|error| report!(errors::StorageError::from(error)))
             .async_and_then(|address| async {
                 address
-                    .convert(state, key_store.key.get_inner(), merchant_id.clone().into())
+                    .convert(state, &key_store.get_key_ring(), merchant_id.clone().into())
                     .await
                     .change_context(errors::StorageError::DecryptionError)
             })
@@ -162,7 +162,7 @@ mod storage {
                         .convert(
                             self.get_keymanager_state()
                                 .attach_printable("Missing KeyManagerState")?,
-                            key_store.key.get_inner(),
+                            &key_store.get_key_ring(),
                             key_store.merchant_id.clone().into(),
                         )
                         .await
@@ -193,7 +193,7 @@ mod storage {
                         .convert(
                             self.get_keymanager_state()
                                 .attach_printable("Missing KeyManagerState")?,
-                            key_store.key.get_inner(),
+                            &key_store.get_key_ring(),
                             key_store.merchant_id.clone().into(),
                         )
                         .await
@@ -223,7 +223,7 @@ mod storage {
                         .convert(
                             self.get_keymanager_state()
                                 .attach_printable("Missing KeyManagerState")?,
-                            key_store.key.get_inner(),
+                            &key_store.get_key_ring(),
                             key_store.merchant_id.clone().into(),
                         )
                         .await
@@ -251,7 +251,7 @@ mod storage {
                         .convert(
                             self.get_keymanager_state()
                                 .attach_printable("Missing KeyManagerState")?,
-                            key_store.key.get_inner(),
+                            &key_store.get_key_ring(),
                             key_store.merchant_id.clone().into(),
                         )
                         .await
@@ -282,7 +282,7 @@ mod storage {
                 for address in addresses.into_iter() {
                     output.push(
                         address
-                            .convert(state, key_store.key.get_inner(), merchant_id.clone().into())
+                            .convert(state, &key_store.get_key_ring(), merchant_id.clone().into())
                             .await
                             .change_context(errors::StorageError::DecryptionError)?,
                     )
@@ -336,7 +336,7 @@ mod storage {
                         .convert(
                             self.get_keymanager_state()
                                 .attach_printable("Missing KeyManagerState")?,
-                            key_store.key.get_inner(),
+                            &key_store.get_key_ring(),
                             key_store.merchant_id.clone().into(),
                         )
                         .await
@@ -398,7 +398,7 @@ mod storage {
                 .convert(
                     self.get_keymanager_state()
                         .attach_printable("Missing KeyManagerState")?,
-                    key_store.key.get_inner(),
+                    &key_store.get_key_ring(),
                     common_utils::types::keymanager::Identifier::Merchant(
                         key_store.merchant_id.clone(),
                     ),
@@ -423,7 +423,7 @@ mod storage {
                         .convert(
                             self.get_keymanager_state()
                                 .attach_printable("Missing KeyManagerState")?,
-                            key_store.key.get_inner(),
+                            &key_store.get_key_ring(),
                             key_store.merchant_id.clone().into(),
                         )
                         .await
@@ -468,7 +468,7 @@ mod storage {
                                 .convert(
                                     self.get_keymanager_state()
                                         .attach_printable("Missing KeyManagerState")?,
-                                    key_store.key.get_inner(),
+                                    &key_store.get_key_ring(),
                                     key_store.merchant_id.clone().into(),
                                 )
                                 .await
@@ -510,7 +510,7 @@ mod storage {
                         .convert(
                             self.get_keymanager_state()
                                 .attach_printable("Missing KeyManagerState")?,
-                            key_store.key.get_inner(),
+                            &key_store.get_key_ring(),
                             key_store.merchant_id.clone().into(),
                         )
                         .await
@@ -551,7 +551,7 @@ mod storage {
                                 .convert(
                                     self.get_keymanager_state()
                                         .attach_printable("Missing KeyManagerState")?,
-                                    key_store.key.get_inner(),
+                                    &key_store.get_key_ring(),
                                     key_store.merchant_id.clone().into(),
                                 )
                                 .await
@@ -616,7 +616,7 @@ mod storage {
                             .convert(
                                 self.get_keymanager_state()
                                     .attach_printable("Missing KeyManagerState")?,
-                                key_store.key.get_inner(),
+                                &key_store.get_key_ring(),
                                 key_store.merchant_id.clone().into(),
                             )
                             .await
@@ -646,7 +646,7 @@ mod storage {
                         .convert(
                             self.get_keymanager_state()
                                 .attach_printable("Missing KeyManagerState")?,
-                            key_store.key.get_inner(),
+                            &key_store.get_key_ring(),
                             key_store.merchant_id.clone().into(),
                         )
                         .await
@@ -680,7 +680,7 @@ mod storage {
                             .convert(
                                 self.get_keymanager_state()
                                     .attach_printable("Missing KeyManagerState")?,
-                                key_store.key.get_inner(),
+                                &key_store.get_key_ring(),
                                 key_store.merchant_id.clone().into(),
                             )
                             .await
@@ -713,7 +713,7 @@ impl AddressInterface for MockDb {
                 .convert(
                     self.get_keymanager_state()
                         .attach_printable("Missing KeyManagerState")?,
-                    key_store.key.get_inner(),
+                    &key_store.get_key_ring(),
                     key_store.merchant_id.clone().into(),
                 )
                 .await
@@ -746,7 +746,7 @@ impl AddressInterface for MockDb {
                 .convert(
                     self.get_keymanager_state()
                         .attach_printable("Missing KeyManagerState")?,
-                    key_store.key.get_inner(),
+                    &key_store.get_key_ring(),
                     key_store.merchant_id.clone().into(),
                 )
                 .await
@@ -782,7 +782,7 @@ impl AddressInterface for MockDb {
                 .convert(
                     self.get_keymanager_state()
                         .attach_printable("Missing KeyManagerState")?,
-                    key_store.key.get_inner(),
+                    &key_store.get_key_ring(),
                     key_store.merchant_id.clone().into(),
                 )
                 .await
@@ -819,7 +819,7 @@ impl AddressInterface for MockDb {
                 .convert(
                     self.get_keymanager_state()
                         .attach_printable("Missing KeyManagerState")?,
-                    key_store.key.get_inner(),
+                    &key_store.get_key_ring(),
                     key_store.merchant_id.clone().into(),
                 )
                 .await
@@ -850,7 +850,7 @@ impl AddressInterface for MockDb {
             .convert(
                 self.get_keymanager_state()
                     .attach_printable("Missing KeyManagerState")?,
-                key_store.key.get_inner(),
+                &key_store.get_key_ring(),
                 key_store.merchant_id.clone().into(),
             )
             .await
@@ -874,7 +874,7 @@ impl AddressInterface for MockDb {
             .convert(
                 self.get_keymanager_state()
                     .attach_printable("Missing KeyManagerState")?,
-                key_store.key.get_inner(),
+                &key_store.get_key_ring(),
                 key_store.merchant_id.clone().into(),
             )
             .await
@@ -909,7 +909,7 @@ impl AddressInterface for MockDb {
                     .convert(
                         self.get_keymanager_state()
                             .attach_printable("Missing KeyManagerState")?,
-                        key_store.key.get_inner(),
+                        &key_store.get_key_ring(),
                         key_store.merchant_id.clone().into(),
                     )
                     .await
@@ -233,6 +233,9 @@ impl ApiKeyInterface for MockDb {
             created_at: api_key.created_at,
             expires_at: api_key.expires_at,
             last_used: api_key.last_used,
+            profile_id: api_key.profile_id,
+            scopes: api_key.scopes,
+            ip_allowlist: api_key.ip_allowlist,
         };
         locked_api_keys.push(stored_key.clone());
 
@@ -420,6 +423,9 @@ mod tests {
                 created_at: datetime!(2023-02-01 0:00),
                 expires_at: Some(datetime!(2023-03-01 0:00)),
                 last_used: None,
+                profile_id: None,
+                scopes: None,
+                ip_allowlist: None,
             })
             .await
             .unwrap();
@@ -435,6 +441,9 @@ mod tests {
                 created_at: datetime!(2023-03-01 0:00),
                 expires_at: None,
                 last_used: None,
+                profile_id: None,
+                scopes: None,
+                ip_allowlist: None,
             })
             .await
             .unwrap();
@@ -517,6 +526,9 @@ mod tests {
             created_at: datetime!(2023-06-01 0:00),
             expires_at: None,
             last_used: None,
+            profile_id: None,
+            scopes: None,
+            ip_allowlist: None,
         };
 
         let api = db.insert_api_key(api).await.unwrap();
@@ -64,7 +64,7 @@ impl AuthenticationInterface for Store {
             .map_err(|error| report!(StorageError::from(error)))?
             .convert(
                 state,
-                merchant_key_store.key.get_inner(),
+                &merchant_key_store.get_key_ring(),
                 merchant_key_store.merchant_id.clone().into(),
             )
             .await
@@ -91,7 +91,7 @@ impl AuthenticationInterface for Store {
             authn
                 .convert(
                     state,
-                    merchant_key_store.key.get_inner(),
+                    &merchant_key_store.get_key_ring(),
                     merchant_key_store.merchant_id.clone().into(),
                 )
                 .await
@@ -120,7 +120,7 @@ impl AuthenticationInterface for Store {
             authn
                 .convert(
                     state,
-                    merchant_key_store.key.get_inner(),
+                    &merchant_key_store.get_key_ring(),
                     merchant_key_store.merchant_id.clone().into(),
                 )
                 .await
@@ -150,7 +150,7 @@ impl AuthenticationInterface for Store {
             authn
                 .convert(
                     state,
-                    merchant_key_store.key.get_inner(),
+                    &merchant_key_store.get_key_ring(),
                     merchant_key_store.merchant_id.clone().into(),
                 )
                 .await
@@ -266,7 +266,7 @@ impl AuthenticationInterface for MockDb {
             authentication_new
                 .convert(
                     state,
-                    merchant_key_store.key.get_inner(),
+                    &merchant_key_store.get_key_ring(),
                     merchant_key_store.merchant_id.clone().into(),
                 )
                 .await
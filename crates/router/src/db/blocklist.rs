@@ -41,6 +41,17 @@ pub trait BlocklistInterface {
         limit: i64,
         offset: i64,
     ) -> CustomResult<Vec<storage::Blocklist>, errors::StorageError>;
+
+    async fn find_next_expiring_blocklist_entry_by_merchant_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+    ) -> CustomResult<Option<storage::Blocklist>, errors::StorageError>;
+
+    async fn delete_expired_blocklist_entries_by_merchant_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        expired_before: time::PrimitiveDateTime,
+    ) -> CustomResult<Vec<storage::Blocklist>, errors::StorageError>;
 }
 
 #[async_trait::async_trait]
@@ -111,6 +122,29 @@ impl BlocklistInterface for Store {
             .await
             .map_err(|error| report!(errors::StorageError::from(error)))
     }
+
+    #[instrument(skip_all)]
+    async fn find_next_expiring_blocklist_entry_by_merchant_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+    ) -> CustomResult<Option<storage::Blocklist>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::Blocklist::find_next_expiring_by_merchant_id(&conn, merchant_id)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn delete_expired_blocklist_entries_by_merchant_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        expired_before: time::PrimitiveDateTime,
+    ) -> CustomResult<Vec<storage::Blocklist>, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::Blocklist::delete_expired_by_merchant_id(&conn, merchant_id, expired_before)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
 }
 
 #[async_trait::async_trait]
@@ -118,42 +152,137 @@ impl BlocklistInterface for MockDb {
     #[instrument(skip_all)]
     async fn insert_blocklist_entry(
         &self,
-        _pm_blocklist: storage::BlocklistNew,
+        pm_blocklist: storage::BlocklistNew,
     ) -> CustomResult<storage::Blocklist, errors::StorageError> {
-        Err(errors::StorageError::MockDbError)?
+        let mut blocklists = self.blocklists.lock().await;
+        if blocklists.iter().any(|blocklist| {
+            blocklist.merchant_id == pm_blocklist.merchant_id
+                && blocklist.fingerprint_id == pm_blocklist.fingerprint_id
+        }) {
+            Err(errors::StorageError::DuplicateValue {
+                entity: "blocklist",
+                key: Some(pm_blocklist.fingerprint_id.clone()),
+            })?
+        }
+
+        let blocklist = storage::Blocklist {
+            merchant_id: pm_blocklist.merchant_id,
+            fingerprint_id: pm_blocklist.fingerprint_id,
+            data_kind: pm_blocklist.data_kind,
+            metadata: pm_blocklist.metadata,
+            created_at: pm_blocklist.created_at,
+            expires_at: pm_blocklist.expires_at,
+        };
+        blocklists.push(blocklist.clone());
+        Ok(blocklist)
     }
 
     async fn find_blocklist_entry_by_merchant_id_fingerprint_id(
         &self,
-        _merchant_id: &common_utils::id_type::MerchantId,
-        _fingerprint_id: &str,
+        merchant_id: &common_utils::id_type::MerchantId,
+        fingerprint_id: &str,
     ) -> CustomResult<storage::Blocklist, errors::StorageError> {
-        Err(errors::StorageError::MockDbError)?
+        self.blocklists
+            .lock()
+            .await
+            .iter()
+            .find(|blocklist| {
+                blocklist.merchant_id == *merchant_id && blocklist.fingerprint_id == fingerprint_id
+            })
+            .cloned()
+            .ok_or(
+                errors::StorageError::ValueNotFound(format!(
+                    "No blocklist entry available for merchant_id = {merchant_id:?} and fingerprint_id = {fingerprint_id}"
+                ))
+                .into(),
+            )
     }
 
     async fn list_blocklist_entries_by_merchant_id(
         &self,
-        _merchant_id: &common_utils::id_type::MerchantId,
+        merchant_id: &common_utils::id_type::MerchantId,
     ) -> CustomResult<Vec<storage::Blocklist>, errors::StorageError> {
-        Err(errors::StorageError::MockDbError)?
+        let mut blocklists: Vec<_> = self
+            .blocklists
+            .lock()
+            .await
+            .iter()
+            .filter(|blocklist| blocklist.merchant_id == *merchant_id)
+            .cloned()
+            .collect();
+        blocklists.sort_by_key(|blocklist| std::cmp::Reverse(blocklist.created_at));
+        Ok(blocklists)
     }
 
     async fn list_blocklist_entries_by_merchant_id_data_kind(
         &self,
-        _merchant_id: &common_utils::id_type::MerchantId,
-        _data_kind: common_enums::BlocklistDataKind,
-        _limit: i64,
-        _offset: i64,
+        merchant_id: &common_utils::id_type::MerchantId,
+        data_kind: common_enums::BlocklistDataKind,
+        limit: i64,
+        offset: i64,
     ) -> CustomResult<Vec<storage::Blocklist>, errors::StorageError> {
-        Err(errors::StorageError::MockDbError)?
+        let mut blocklists: Vec<_> = self
+            .blocklists
+            .lock()
+            .await
+            .iter()
+            .filter(|blocklist| {
+                blocklist.merchant_id == *merchant_id && blocklist.data_kind == data_kind
+            })
+            .cloned()
+            .collect();
+        blocklists.sort_by_key(|blocklist| std::cmp::Reverse(blocklist.created_at));
+        Ok(blocklists
+            .into_iter()
+            .skip(usize::try_from(offset).unwrap_or(0))
+            .take(usize::try_from(limit).unwrap_or(usize::MAX))
+            .collect())
     }
 
     async fn delete_blocklist_entry_by_merchant_id_fingerprint_id(
         &self,
-        _merchant_id: &common_utils::id_type::MerchantId,
-        _fingerprint_id: &str,
+        merchant_id: &common_utils::id_type::MerchantId,
+        fingerprint_id: &str,
     ) -> CustomResult<storage::Blocklist, errors::StorageError> {
-        Err(errors::StorageError::MockDbError)?
+        let mut blocklists = self.blocklists.lock().await;
+        let index = blocklists
+            .iter()
+            .position(|blocklist| {
+                blocklist.merchant_id == *merchant_id && blocklist.fingerprint_id == fingerprint_id
+            })
+            .ok_or(errors::StorageError::ValueNotFound(format!(
+                "No blocklist entry available for merchant_id = {merchant_id:?} and fingerprint_id = {fingerprint_id}"
+            )))?;
+        Ok(blocklists.remove(index))
+    }
+
+    async fn find_next_expiring_blocklist_entry_by_merchant_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+    ) -> CustomResult<Option<storage::Blocklist>, errors::StorageError> {
+        Ok(self
+            .blocklists
+            .lock()
+            .await
+            .iter()
+            .filter(|blocklist| {
+                blocklist.merchant_id == *merchant_id && blocklist.expires_at.is_some()
+            })
+            .min_by_key(|blocklist| blocklist.expires_at)
+            .cloned())
+    }
+
+    async fn delete_expired_blocklist_entries_by_merchant_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        expired_before: time::PrimitiveDateTime,
+    ) -> CustomResult<Vec<storage::Blocklist>, errors::StorageError> {
+        let mut blocklists = self.blocklists.lock().await;
+        let (expired, retained): (Vec<_>, Vec<_>) = blocklists.drain(..).partition(|blocklist| {
+            blocklist.merchant_id == *merchant_id && blocklist.is_expired_at(expired_before)
+        });
+        *blocklists = retained;
+        Ok(expired)
     }
 }
 
@@ -211,4 +340,25 @@ impl BlocklistInterface for KafkaStore {
             .list_blocklist_entries_by_merchant_id(merchant_id)
             .await
     }
+
+    #[instrument(skip_all)]
+    async fn find_next_expiring_blocklist_entry_by_merchant_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+    ) -> CustomResult<Option<storage::Blocklist>, errors::StorageError> {
+        self.diesel_store
+            .find_next_expiring_blocklist_entry_by_merchant_id(merchant_id)
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn delete_expired_blocklist_entries_by_merchant_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        expired_before: time::PrimitiveDateTime,
+    ) -> CustomResult<Vec<storage::Blocklist>, errors::StorageError> {
+        self.diesel_store
+            .delete_expired_blocklist_entries_by_merchant_id(merchant_id, expired_before)
+            .await
+    }
 }
@@ -22,6 +22,12 @@ pub trait BlocklistFingerprintInterface {
         merchant_id: &common_utils::id_type::MerchantId,
         fingerprint_id: &str,
     ) -> CustomResult<storage::BlocklistFingerprint, errors::StorageError>;
+
+    async fn delete_blocklist_fingerprint_by_merchant_id_fingerprint_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        fingerprint_id: &str,
+    ) -> CustomResult<storage::BlocklistFingerprint, errors::StorageError>;
 }
 
 #[async_trait::async_trait]
@@ -53,23 +59,90 @@ impl BlocklistFingerprintInterface for Store {
         .await
         .map_err(|error| report!(errors::StorageError::from(error)))
     }
+
+    #[instrument(skip_all)]
+    async fn delete_blocklist_fingerprint_by_merchant_id_fingerprint_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        fingerprint_id: &str,
+    ) -> CustomResult<storage::BlocklistFingerprint, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::BlocklistFingerprint::delete_by_merchant_id_fingerprint_id(
+            &conn,
+            merchant_id,
+            fingerprint_id,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
 }
 
 #[async_trait::async_trait]
 impl BlocklistFingerprintInterface for MockDb {
     async fn insert_blocklist_fingerprint_entry(
         &self,
-        _pm_fingerprint_new: storage::BlocklistFingerprintNew,
+        pm_fingerprint_new: storage::BlocklistFingerprintNew,
     ) -> CustomResult<storage::BlocklistFingerprint, errors::StorageError> {
-        Err(errors::StorageError::MockDbError)?
+        let mut blocklist_fingerprints = self.blocklist_fingerprints.lock().await;
+        if blocklist_fingerprints.iter().any(|fingerprint| {
+            fingerprint.merchant_id == pm_fingerprint_new.merchant_id
+                && fingerprint.fingerprint_id == pm_fingerprint_new.fingerprint_id
+        }) {
+            Err(errors::StorageError::DuplicateValue {
+                entity: "blocklist_fingerprint",
+                key: Some(pm_fingerprint_new.fingerprint_id.clone()),
+            })?
+        }
+
+        let fingerprint = storage::BlocklistFingerprint {
+            merchant_id: pm_fingerprint_new.merchant_id,
+            fingerprint_id: pm_fingerprint_new.fingerprint_id,
+            data_kind: pm_fingerprint_new.data_kind,
+            encrypted_fingerprint: pm_fingerprint_new.encrypted_fingerprint,
+            created_at: pm_fingerprint_new.created_at,
+        };
+        blocklist_fingerprints.push(fingerprint.clone());
+        Ok(fingerprint)
     }
 
     async fn find_blocklist_fingerprint_by_merchant_id_fingerprint_id(
         &self,
-        _merchant_id: &common_utils::id_type::MerchantId,
-        _fingerprint_id: &str,
+        merchant_id: &common_utils::id_type::MerchantId,
+        fingerprint_id: &str,
+    ) -> CustomResult<storage::BlocklistFingerprint, errors::StorageError> {
+        self.blocklist_fingerprints
+            .lock()
+            .await
+            .iter()
+            .find(|fingerprint| {
+                fingerprint.merchant_id == *merchant_id
+                    && fingerprint.fingerprint_id == fingerprint_id
+            })
+            .cloned()
+            .ok_or(
+                errors::StorageError::ValueNotFound(format!(
+                    "No blocklist fingerprint available for merchant_id = {merchant_id:?} and fingerprint_id = {fingerprint_id}"
+                ))
+                .into(),
+            )
+    }
+
+    async fn delete_blocklist_fingerprint_by_merchant_id_fingerprint_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        fingerprint_id: &str,
     ) -> CustomResult<storage::BlocklistFingerprint, errors::StorageError> {
-        Err(errors::StorageError::MockDbError)?
+        let mut blocklist_fingerprints = self.blocklist_fingerprints.lock().await;
+        let index = blocklist_fingerprints
+            .iter()
+            .position(|fingerprint| {
+                fingerprint.merchant_id == *merchant_id
+                    && fingerprint.fingerprint_id == fingerprint_id
+            })
+            .ok_or(errors::StorageError::ValueNotFound(format!(
+                "No blocklist fingerprint available for merchant_id = {merchant_id:?} and fingerprint_id = {fingerprint_id}"
+            )))?;
+        Ok(blocklist_fingerprints.remove(index))
     }
 }
 
@@ -95,4 +168,15 @@ impl BlocklistFingerprintInterface for KafkaStore {
             .find_blocklist_fingerprint_by_merchant_id_fingerprint_id(merchant_id, fingerprint)
             .await
     }
+
+    #[instrument(skip_all)]
+    async fn delete_blocklist_fingerprint_by_merchant_id_fingerprint_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        fingerprint_id: &str,
+    ) -> CustomResult<storage::BlocklistFingerprint, errors::StorageError> {
+        self.diesel_store
+            .delete_blocklist_fingerprint_by_merchant_id_fingerprint_id(merchant_id, fingerprint_id)
+            .await
+    }
 }
@@ -74,25 +74,61 @@ impl BlocklistLookupInterface for MockDb {
     #[instrument(skip_all)]
     async fn insert_blocklist_lookup_entry(
         &self,
-        _blocklist_lookup_entry: storage::BlocklistLookupNew,
+        blocklist_lookup_entry: storage::BlocklistLookupNew,
     ) -> CustomResult<storage::BlocklistLookup, errors::StorageError> {
-        Err(errors::StorageError::MockDbError)?
+        let mut blocklist_lookups = self.blocklist_lookups.lock().await;
+        if blocklist_lookups.iter().any(|lookup| {
+            lookup.merchant_id == blocklist_lookup_entry.merchant_id
+                && lookup.fingerprint == blocklist_lookup_entry.fingerprint
+        }) {
+            Err(errors::StorageError::DuplicateValue {
+                entity: "blocklist_lookup",
+                key: None,
+            })?
+        }
+
+        let lookup = storage::BlocklistLookup {
+            merchant_id: blocklist_lookup_entry.merchant_id,
+            fingerprint: blocklist_lookup_entry.fingerprint,
+        };
+        blocklist_lookups.push(lookup.clone());
+        Ok(lookup)
     }
 
     async fn find_blocklist_lookup_entry_by_merchant_id_fingerprint(
         &self,
-        _merchant_id: &common_utils::id_type::MerchantId,
-        _fingerprint: &str,
+        merchant_id: &common_utils::id_type::MerchantId,
+        fingerprint: &str,
     ) -> CustomResult<storage::BlocklistLookup, errors::StorageError> {
-        Err(errors::StorageError::MockDbError)?
+        self.blocklist_lookups
+            .lock()
+            .await
+            .iter()
+            .find(|lookup| lookup.merchant_id == *merchant_id && lookup.fingerprint == fingerprint)
+            .cloned()
+            .ok_or(
+                errors::StorageError::ValueNotFound(format!(
+                    "No blocklist lookup entry available for merchant_id = {merchant_id:?}"
+                ))
+                .into(),
+            )
     }
 
     async fn delete_blocklist_lookup_entry_by_merchant_id_fingerprint(
         &self,
-        _merchant_id: &common_utils::id_type::MerchantId,
-        _fingerprint: &str,
+        merchant_id: &common_utils::id_type::MerchantId,
+        fingerprint: &str,
     ) -> CustomResult<storage::BlocklistLookup, errors::StorageError> {
-        Err(errors::StorageError::MockDbError)?
+        let mut blocklist_lookups = self.blocklist_lookups.lock().await;
+        let index = blocklist_lookups
+            .iter()
+            .position(|lookup| {
+                lookup.merchant_id == *merchant_id && lookup.fingerprint == fingerprint
+            })
+            .ok_or(errors::StorageError::ValueNotFound(format!(
+                "No blocklist lookup entry available for merchant_id = {merchant_id:?}"
+            )))?;
+        Ok(blocklist_lookups.remove(index))
     }
 }
 
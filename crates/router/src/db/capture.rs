@@ -209,21 +209,39 @@ impl CaptureInterface for MockDb {
     #[instrument(skip_all)]
     async fn update_capture_with_capture_id(
         &self,
-        _this: types::Capture,
-        _capture: types::CaptureUpdate,
+        this: types::Capture,
+        capture: types::CaptureUpdate,
         _storage_scheme: enums::MerchantStorageScheme,
     ) -> CustomResult<types::Capture, errors::StorageError> {
-        //Implement function for `MockDb`
-        Err(errors::StorageError::MockDbError)?
+        let mut captures = self.captures.lock().await;
+        let existing = captures
+            .iter_mut()
+            .find(|existing| existing.capture_id == this.capture_id)
+            .ok_or(errors::StorageError::ValueNotFound(format!(
+                "No capture available for capture_id = {}",
+                this.capture_id
+            )))?;
+        *existing = capture.apply_changeset(existing.clone());
+        Ok(existing.clone())
     }
     async fn find_all_captures_by_merchant_id_payment_id_authorized_attempt_id(
         &self,
-        _merchant_id: &common_utils::id_type::MerchantId,
-        _payment_id: &common_utils::id_type::PaymentId,
-        _authorized_attempt_id: &str,
+        merchant_id: &common_utils::id_type::MerchantId,
+        payment_id: &common_utils::id_type::PaymentId,
+        authorized_attempt_id: &str,
         _storage_scheme: enums::MerchantStorageScheme,
     ) -> CustomResult<Vec<types::Capture>, errors::StorageError> {
-        //Implement function for `MockDb`
-        Err(errors::StorageError::MockDbError)?
+        Ok(self
+            .captures
+            .lock()
+            .await
+            .iter()
+            .filter(|capture| {
+                capture.merchant_id == *merchant_id
+                    && capture.payment_id == *payment_id
+                    && capture.authorized_attempt_id == authorized_attempt_id
+            })
+            .cloned()
+            .collect())
     }
 }
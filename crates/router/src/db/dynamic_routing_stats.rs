@@ -78,26 +78,70 @@ impl DynamicRoutingStatsInterface for MockDb {
     #[instrument(skip_all)]
     async fn insert_dynamic_routing_stat_entry(
         &self,
-        _dynamic_routing_stat: storage::DynamicRoutingStatsNew,
+        dynamic_routing_stat: storage::DynamicRoutingStatsNew,
     ) -> CustomResult<storage::DynamicRoutingStats, errors::StorageError> {
-        Err(errors::StorageError::MockDbError)?
+        let mut dynamic_routing_stats = self.dynamic_routing_stats.lock().await;
+        if dynamic_routing_stats.iter().any(|stat| {
+            stat.attempt_id == dynamic_routing_stat.attempt_id
+                && stat.merchant_id == dynamic_routing_stat.merchant_id
+        }) {
+            Err(errors::StorageError::DuplicateValue {
+                entity: "dynamic_routing_stats",
+                key: Some(dynamic_routing_stat.attempt_id.clone()),
+            })?
+        }
+
+        let dynamic_routing_stat = storage::DynamicRoutingStats {
+            payment_id: dynamic_routing_stat.payment_id,
+            attempt_id: dynamic_routing_stat.attempt_id,
+            merchant_id: dynamic_routing_stat.merchant_id,
+            profile_id: dynamic_routing_stat.profile_id,
+            amount: dynamic_routing_stat.amount,
+            success_based_routing_connector: dynamic_routing_stat.success_based_routing_connector,
+            payment_connector: dynamic_routing_stat.payment_connector,
+            currency: dynamic_routing_stat.currency,
+            payment_method: dynamic_routing_stat.payment_method,
+            capture_method: dynamic_routing_stat.capture_method,
+            authentication_type: dynamic_routing_stat.authentication_type,
+            payment_status: dynamic_routing_stat.payment_status,
+            conclusive_classification: dynamic_routing_stat.conclusive_classification,
+            created_at: dynamic_routing_stat.created_at,
+            payment_method_type: dynamic_routing_stat.payment_method_type,
+            global_success_based_connector: dynamic_routing_stat.global_success_based_connector,
+        };
+        dynamic_routing_stats.push(dynamic_routing_stat.clone());
+        Ok(dynamic_routing_stat)
     }
 
     async fn find_dynamic_routing_stats_optional_by_attempt_id_merchant_id(
         &self,
-        _attempt_id: String,
-        _merchant_id: &common_utils::id_type::MerchantId,
+        attempt_id: String,
+        merchant_id: &common_utils::id_type::MerchantId,
     ) -> CustomResult<Option<storage::DynamicRoutingStats>, errors::StorageError> {
-        Err(errors::StorageError::MockDbError)?
+        Ok(self
+            .dynamic_routing_stats
+            .lock()
+            .await
+            .iter()
+            .find(|stat| stat.attempt_id == attempt_id && stat.merchant_id == *merchant_id)
+            .cloned())
     }
 
     async fn update_dynamic_routing_stats(
         &self,
-        _attempt_id: String,
-        _merchant_id: &common_utils::id_type::MerchantId,
-        _data: storage::DynamicRoutingStatsUpdate,
+        attempt_id: String,
+        merchant_id: &common_utils::id_type::MerchantId,
+        data: storage::DynamicRoutingStatsUpdate,
     ) -> CustomResult<storage::DynamicRoutingStats, errors::StorageError> {
-        Err(errors::StorageError::MockDbError)?
+        let mut dynamic_routing_stats = self.dynamic_routing_stats.lock().await;
+        let dynamic_routing_stat = dynamic_routing_stats
+            .iter_mut()
+            .find(|stat| stat.attempt_id == attempt_id && stat.merchant_id == *merchant_id)
+            .ok_or(errors::StorageError::ValueNotFound(format!(
+                "No dynamic routing stats available for attempt_id = {attempt_id}"
+            )))?;
+        *dynamic_routing_stat = data.apply_changeset(dynamic_routing_stat.clone());
+        Ok(dynamic_routing_stat.clone())
     }
 }
 
@@ -143,7 +143,7 @@ impl EventInterface for Store {
             .convert(
                 self.get_keymanager_state()
                     .attach_printable("Missing KeyManagerState")?,
-                merchant_key_store.key.get_inner(),
+                &merchant_key_store.get_key_ring(),
                 merchant_key_store.merchant_id.clone().into(),
             )
             .await
@@ -164,7 +164,7 @@ impl EventInterface for Store {
             .convert(
                 self.get_keymanager_state()
                     .attach_printable("Missing KeyManagerState")?,
-                merchant_key_store.key.get_inner(),
+                &merchant_key_store.get_key_ring(),
                 merchant_key_store.merchant_id.clone().into(),
             )
             .await
@@ -189,7 +189,7 @@ impl EventInterface for Store {
         .convert(
             self.get_keymanager_state()
                 .attach_printable("Missing KeyManagerState")?,
-            merchant_key_store.key.get_inner(),
+            &merchant_key_store.get_key_ring(),
             merchant_key_store.merchant_id.clone().into(),
         )
         .await
@@ -227,7 +227,7 @@ impl EventInterface for Store {
                     .convert(
                         self.get_keymanager_state()
                             .attach_printable("Missing KeyManagerState")?,
-                        merchant_key_store.key.get_inner(),
+                        &merchant_key_store.get_key_ring(),
                         merchant_key_store.merchant_id.clone().into(),
                     )
                     .await
@@ -258,7 +258,7 @@ impl EventInterface for Store {
                     .convert(
                         self.get_keymanager_state()
                             .attach_printable("Missing KeyManagerState")?,
-                        merchant_key_store.key.get_inner(),
+                        &merchant_key_store.get_key_ring(),
                         merchant_key_store.merchant_id.clone().into(),
                     )
                     .await
@@ -289,7 +289,7 @@ impl EventInterface for Store {
                     .convert(
                         self.get_keymanager_state()
                             .attach_printable("Missing KeyManagerState")?,
-                        merchant_key_store.key.get_inner(),
+                        &merchant_key_store.get_key_ring(),
                         merchant_key_store.merchant_id.clone().into(),
                     )
                     .await
@@ -321,7 +321,7 @@ impl EventInterface for Store {
                         .convert(
                             self.get_keymanager_state()
                                 .attach_printable("Missing KeyManagerState")?,
-                            merchant_key_store.key.get_inner(),
+                            &merchant_key_store.get_key_ring(),
                             merchant_key_store.merchant_id.clone().into(),
                         )
                         .await
@@ -364,7 +364,7 @@ impl EventInterface for Store {
                     .convert(
                         self.get_keymanager_state()
                             .attach_printable("Missing KeyManagerState")?,
-                        merchant_key_store.key.get_inner(),
+                        &merchant_key_store.get_key_ring(),
                         common_utils::types::keymanager::Identifier::Merchant(
                             merchant_key_store.merchant_id.clone(),
                         ),
@@ -397,7 +397,7 @@ impl EventInterface for Store {
                     .convert(
                         self.get_keymanager_state()
                             .attach_printable("Missing KeyManagerState")?,
-                        merchant_key_store.key.get_inner(),
+                        &merchant_key_store.get_key_ring(),
                         merchant_key_store.merchant_id.clone().into(),
                     )
                     .await
@@ -429,7 +429,7 @@ impl EventInterface for Store {
                         .convert(
                             self.get_keymanager_state()
                                 .attach_printable("Missing KeyManagerState")?,
-                            merchant_key_store.key.get_inner(),
+                            &merchant_key_store.get_key_ring(),
                             common_utils::types::keymanager::Identifier::Merchant(
                                 merchant_key_store.merchant_id.clone(),
                             ),
@@ -458,7 +458,7 @@ impl EventInterface for Store {
             .convert(
                 self.get_keymanager_state()
                     .attach_printable("Missing KeyManagerState")?,
-                merchant_key_store.key.get_inner(),
+                &merchant_key_store.get_key_ring(),
                 merchant_key_store.merchant_id.clone().into(),
             )
             .await
@@ -508,7 +508,7 @@ impl EventInterface for MockDb {
             .convert(
                 self.get_keymanager_state()
                     .attach_printable("Missing KeyManagerState")?,
-                merchant_key_store.key.get_inner(),
+                &merchant_key_store.get_key_ring(),
                 merchant_key_store.merchant_id.clone().into(),
             )
             .await
@@ -531,7 +531,7 @@ impl EventInterface for MockDb {
             .async_map(|event| async {
                 event
                     .convert(self.get_keymanager_state().attach_printable("Missing KeyManagerState")?,
-                        merchant_key_store.key.get_inner(),
+                        &merchant_key_store.get_key_ring(),
                         merchant_key_store.merchant_id.clone().into(),
                     )
                     .await
@@ -563,7 +563,7 @@ impl EventInterface for MockDb {
             .async_map(|event| async {
                 event
                     .convert(self.get_keymanager_state().attach_printable("Missing KeyManagerState")?,
-                        merchant_key_store.key.get_inner(),
+                        &merchant_key_store.get_key_ring(),
                         merchant_key_store.merchant_id.clone().into(),
                     )
                     .await
@@ -636,7 +636,7 @@ impl EventInterface for MockDb {
                 .convert(
                     self.get_keymanager_state()
                         .attach_printable("Missing KeyManagerState")?,
-                    merchant_key_store.key.get_inner(),
+                    &merchant_key_store.get_key_ring(),
                     merchant_key_store.merchant_id.clone().into(),
                 )
                 .await
@@ -669,7 +669,7 @@ impl EventInterface for MockDb {
                 .convert(
                     self.get_keymanager_state()
                         .attach_printable("Missing KeyManagerState")?,
-                    merchant_key_store.key.get_inner(),
+                    &merchant_key_store.get_key_ring(),
                     merchant_key_store.merchant_id.clone().into(),
                 )
                 .await
@@ -704,7 +704,7 @@ impl EventInterface for MockDb {
                 .convert(
                     self.get_keymanager_state()
                         .attach_printable("Missing KeyManagerState")?,
-                    merchant_key_store.key.get_inner(),
+                    &merchant_key_store.get_key_ring(),
                     merchant_key_store.merchant_id.clone().into(),
                 )
                 .await
@@ -735,7 +735,7 @@ impl EventInterface for MockDb {
                     .convert(
                         self.get_keymanager_state()
                             .attach_printable("Missing KeyManagerState")?,
-                        merchant_key_store.key.get_inner(),
+                        &merchant_key_store.get_key_ring(),
                         merchant_key_store.merchant_id.clone().into(),
                     )
                     .await
@@ -803,7 +803,7 @@ impl EventInterface for MockDb {
                 .convert(
                     self.get_keymanager_state()
                         .attach_printable("Missing KeyManagerState")?,
-                    merchant_key_store.key.get_inner(),
+                    &merchant_key_store.get_key_ring(),
                     merchant_key_store.merchant_id.clone().into(),
                 )
                 .await
@@ -838,7 +838,7 @@ impl EventInterface for MockDb {
                 .convert(
                     self.get_keymanager_state()
                         .attach_printable("Missing KeyManagerState")?,
-                    merchant_key_store.key.get_inner(),
+                    &merchant_key_store.get_key_ring(),
                     merchant_key_store.merchant_id.clone().into(),
                 )
                 .await
@@ -869,7 +869,7 @@ impl EventInterface for MockDb {
                     .convert(
                         self.get_keymanager_state()
                             .attach_printable("Missing KeyManagerState")?,
-                        merchant_key_store.key.get_inner(),
+                        &merchant_key_store.get_key_ring(),
                         merchant_key_store.merchant_id.clone().into(),
                     )
                     .await
@@ -916,7 +916,7 @@ impl EventInterface for MockDb {
             .convert(
                 self.get_keymanager_state()
                     .attach_printable("Missing KeyManagerState")?,
-                merchant_key_store.key.get_inner(),
+                &merchant_key_store.get_key_ring(),
                 merchant_key_store.merchant_id.clone().into(),
             )
             .await
@@ -1045,6 +1045,8 @@ mod tests {
                     .and_then(|val| val.try_into_operation())
                     .unwrap(),
                     created_at: datetime!(2023-02-01 0:00),
+                    key_version: 1,
+                    previous_keys: None,
                 },
                 &master_key.to_vec().into(),
             )
@@ -1080,6 +1082,7 @@ mod tests {
                         .unwrap(),
                     }),
                     is_overall_delivery_successful: Some(false),
+                    webhook_endpoint_id: None,
                 },
                 &merchant_key_store,
             )
@@ -1158,6 +1161,8 @@ mod tests {
                     .and_then(|val| val.try_into_operation())
                     .unwrap(),
                     created_at: datetime!(2023-02-01 0:00),
+                    key_version: 1,
+                    previous_keys: None,
                 },
                 &master_key.to_vec().into(),
             )
@@ -1193,6 +1198,7 @@ mod tests {
                         .unwrap(),
                     }),
                     is_overall_delivery_successful: Some(false),
+                    webhook_endpoint_id: None,
                 },
                 &merchant_key_store,
             )
@@ -1267,6 +1273,8 @@ mod tests {
                     .await?
                     .try_into_operation()?,
                     created_at: datetime!(2023-02-01 0:00),
+                    key_version: 1,
+                    previous_keys: None,
                 },
                 &master_key.to_vec().into(),
             )
@@ -88,36 +88,85 @@ impl FileMetadataInterface for Store {
 impl FileMetadataInterface for MockDb {
     async fn insert_file_metadata(
         &self,
-        _file: storage::FileMetadataNew,
+        file: storage::FileMetadataNew,
     ) -> CustomResult<storage::FileMetadata, errors::StorageError> {
-        // TODO: Implement function for `MockDb`
-        Err(errors::StorageError::MockDbError)?
+        let mut file_metadata = self.file_metadata.lock().await;
+        if file_metadata.iter().any(|existing| {
+            existing.file_id == file.file_id && existing.merchant_id == file.merchant_id
+        }) {
+            Err(errors::StorageError::DuplicateValue {
+                entity: "file_id",
+                key: Some(file.file_id.clone()),
+            })?
+        }
+
+        let file = storage::FileMetadata {
+            file_id: file.file_id,
+            merchant_id: file.merchant_id,
+            file_name: file.file_name,
+            file_size: file.file_size,
+            file_type: file.file_type,
+            provider_file_id: file.provider_file_id,
+            file_upload_provider: file.file_upload_provider,
+            available: file.available,
+            created_at: common_utils::date_time::now(),
+            connector_label: file.connector_label,
+            profile_id: file.profile_id,
+            merchant_connector_id: file.merchant_connector_id,
+        };
+        file_metadata.push(file.clone());
+        Ok(file)
     }
 
     async fn find_file_metadata_by_merchant_id_file_id(
         &self,
-        _merchant_id: &common_utils::id_type::MerchantId,
-        _file_id: &str,
+        merchant_id: &common_utils::id_type::MerchantId,
+        file_id: &str,
     ) -> CustomResult<storage::FileMetadata, errors::StorageError> {
-        // TODO: Implement function for `MockDb`
-        Err(errors::StorageError::MockDbError)?
+        self.file_metadata
+            .lock()
+            .await
+            .iter()
+            .find(|file| file.merchant_id == *merchant_id && file.file_id == file_id)
+            .cloned()
+            .ok_or(
+                errors::StorageError::ValueNotFound(format!(
+                    "No file metadata available for file_id = {file_id}"
+                ))
+                .into(),
+            )
     }
 
     async fn delete_file_metadata_by_merchant_id_file_id(
         &self,
-        _merchant_id: &common_utils::id_type::MerchantId,
-        _file_id: &str,
+        merchant_id: &common_utils::id_type::MerchantId,
+        file_id: &str,
     ) -> CustomResult<bool, errors::StorageError> {
-        // TODO: Implement function for `MockDb`
-        Err(errors::StorageError::MockDbError)?
+        let mut file_metadata = self.file_metadata.lock().await;
+        let index = file_metadata
+            .iter()
+            .position(|file| file.merchant_id == *merchant_id && file.file_id == file_id)
+            .ok_or(errors::StorageError::ValueNotFound(format!(
+                "No file metadata available for file_id = {file_id}"
+            )))?;
+        file_metadata.remove(index);
+        Ok(true)
     }
 
     async fn update_file_metadata(
         &self,
-        _this: storage::FileMetadata,
-        _file_metadata: storage::FileMetadataUpdate,
+        this: storage::FileMetadata,
+        file_metadata: storage::FileMetadataUpdate,
     ) -> CustomResult<storage::FileMetadata, errors::StorageError> {
-        // TODO: Implement function for `MockDb`
-        Err(errors::StorageError::MockDbError)?
+        let mut files = self.file_metadata.lock().await;
+        let file = files
+            .iter_mut()
+            .find(|file| file.merchant_id == this.merchant_id && file.file_id == this.file_id)
+            .ok_or(errors::StorageError::ValueNotFound(format!(
+                "No file metadata available for file_id = {}",
+                this.file_id
+            )))?;
+        *file = file_metadata.apply_changeset(file.clone());
+        Ok(file.clone())
     }
 }
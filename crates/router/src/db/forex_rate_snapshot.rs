@@ -0,0 +1,136 @@
+use error_stack::report;
+use router_env::{instrument, tracing};
+
+use super::{MockDb, Store};
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+    types::storage,
+};
+
+#[async_trait::async_trait]
+pub trait ForexRateSnapshotInterface {
+    async fn insert_forex_rate_snapshot(
+        &self,
+        new: storage::ForexRateSnapshotNew,
+    ) -> CustomResult<storage::ForexRateSnapshot, errors::StorageError>;
+
+    /// Finds the latest snapshot taken at or before the given time, of the provider if provided
+    async fn find_latest_forex_rate_snapshot_as_of(
+        &self,
+        provider: Option<&str>,
+        as_of: time::PrimitiveDateTime,
+    ) -> CustomResult<Option<storage::ForexRateSnapshot>, errors::StorageError>;
+
+    /// Lists the snapshots taken at or before the given time, latest first
+    async fn list_latest_forex_rate_snapshots_as_of(
+        &self,
+        as_of: time::PrimitiveDateTime,
+        limit: i64,
+    ) -> CustomResult<Vec<storage::ForexRateSnapshot>, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl ForexRateSnapshotInterface for Store {
+    #[instrument(skip_all)]
+    async fn insert_forex_rate_snapshot(
+        &self,
+        new: storage::ForexRateSnapshotNew,
+    ) -> CustomResult<storage::ForexRateSnapshot, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        new.insert(&conn)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn find_latest_forex_rate_snapshot_as_of(
+        &self,
+        provider: Option<&str>,
+        as_of: time::PrimitiveDateTime,
+    ) -> CustomResult<Option<storage::ForexRateSnapshot>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::ForexRateSnapshot::find_latest_as_of(&conn, provider, as_of)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn list_latest_forex_rate_snapshots_as_of(
+        &self,
+        as_of: time::PrimitiveDateTime,
+        limit: i64,
+    ) -> CustomResult<Vec<storage::ForexRateSnapshot>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::ForexRateSnapshot::list_latest_as_of(&conn, None, as_of, limit)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+}
+
+#[async_trait::async_trait]
+impl ForexRateSnapshotInterface for MockDb {
+    async fn insert_forex_rate_snapshot(
+        &self,
+        new: storage::ForexRateSnapshotNew,
+    ) -> CustomResult<storage::ForexRateSnapshot, errors::StorageError> {
+        let mut snapshots = self.forex_rate_snapshots.lock().await;
+
+        if snapshots
+            .iter()
+            .any(|snapshot| snapshot.snapshot_id == new.snapshot_id)
+        {
+            Err(errors::StorageError::DuplicateValue {
+                entity: "snapshot_id",
+                key: Some(new.snapshot_id.clone()),
+            })?
+        }
+
+        let snapshot = storage::ForexRateSnapshot {
+            snapshot_id: new.snapshot_id,
+            provider: new.provider,
+            rates: new.rates,
+            created_at: new.created_at,
+        };
+        snapshots.push(snapshot.clone());
+
+        Ok(snapshot)
+    }
+
+    async fn find_latest_forex_rate_snapshot_as_of(
+        &self,
+        provider: Option<&str>,
+        as_of: time::PrimitiveDateTime,
+    ) -> CustomResult<Option<storage::ForexRateSnapshot>, errors::StorageError> {
+        Ok(self
+            .forex_rate_snapshots
+            .lock()
+            .await
+            .iter()
+            .filter(|snapshot| {
+                snapshot.created_at <= as_of
+                    && provider.is_none_or(|provider| snapshot.provider == provider)
+            })
+            .max_by_key(|snapshot| snapshot.created_at)
+            .cloned())
+    }
+
+    async fn list_latest_forex_rate_snapshots_as_of(
+        &self,
+        as_of: time::PrimitiveDateTime,
+        limit: i64,
+    ) -> CustomResult<Vec<storage::ForexRateSnapshot>, errors::StorageError> {
+        let mut snapshots = self
+            .forex_rate_snapshots
+            .lock()
+            .await
+            .iter()
+            .filter(|snapshot| snapshot.created_at <= as_of)
+            .cloned()
+            .collect::<Vec<_>>();
+        snapshots.sort_by_key(|snapshot| std::cmp::Reverse(snapshot.created_at));
+        snapshots.truncate(usize::try_from(limit).unwrap_or_default());
+
+        Ok(snapshots)
+    }
+}
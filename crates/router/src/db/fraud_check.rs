@@ -89,30 +89,86 @@ impl FraudCheckInterface for Store {
 impl FraudCheckInterface for MockDb {
     async fn insert_fraud_check_response(
         &self,
-        _new: storage::FraudCheckNew,
+        new: storage::FraudCheckNew,
     ) -> CustomResult<FraudCheck, errors::StorageError> {
-        Err(errors::StorageError::MockDbError)?
+        let mut fraud_checks = self.fraud_checks.lock().await;
+        if fraud_checks.iter().any(|fraud_check| {
+            fraud_check.payment_id == new.payment_id && fraud_check.merchant_id == new.merchant_id
+        }) {
+            Err(errors::StorageError::DuplicateValue {
+                entity: "fraud_check",
+                key: Some(new.payment_id.get_string_repr().to_owned()),
+            })?
+        }
+
+        let fraud_check = FraudCheck {
+            frm_id: new.frm_id,
+            payment_id: new.payment_id,
+            merchant_id: new.merchant_id,
+            attempt_id: new.attempt_id,
+            created_at: new.created_at,
+            frm_name: new.frm_name,
+            frm_transaction_id: new.frm_transaction_id,
+            frm_transaction_type: new.frm_transaction_type,
+            frm_status: new.frm_status,
+            frm_score: new.frm_score,
+            frm_reason: new.frm_reason,
+            frm_error: new.frm_error,
+            payment_details: new.payment_details,
+            metadata: new.metadata,
+            modified_at: new.modified_at,
+            last_step: new.last_step,
+            payment_capture_method: new.payment_capture_method,
+        };
+        fraud_checks.push(fraud_check.clone());
+        Ok(fraud_check)
     }
     async fn update_fraud_check_response_with_attempt_id(
         &self,
-        _this: FraudCheck,
-        _fraud_check: FraudCheckUpdate,
+        this: FraudCheck,
+        fraud_check: FraudCheckUpdate,
     ) -> CustomResult<FraudCheck, errors::StorageError> {
-        Err(errors::StorageError::MockDbError)?
+        let mut fraud_checks = self.fraud_checks.lock().await;
+        let existing = fraud_checks
+            .iter_mut()
+            .find(|existing| {
+                existing.attempt_id == this.attempt_id && existing.merchant_id == this.merchant_id
+            })
+            .ok_or(errors::StorageError::ValueNotFound(format!(
+                "No fraud check available for attempt_id = {}",
+                this.attempt_id
+            )))?;
+        *existing = fraud_check.apply_changeset(existing.clone());
+        Ok(existing.clone())
     }
     async fn find_fraud_check_by_payment_id(
         &self,
-        _payment_id: common_utils::id_type::PaymentId,
-        _merchant_id: common_utils::id_type::MerchantId,
+        payment_id: common_utils::id_type::PaymentId,
+        merchant_id: common_utils::id_type::MerchantId,
     ) -> CustomResult<FraudCheck, errors::StorageError> {
-        Err(errors::StorageError::MockDbError)?
+        self.find_fraud_check_by_payment_id_if_present(payment_id.clone(), merchant_id)
+            .await?
+            .ok_or(
+                errors::StorageError::ValueNotFound(format!(
+                    "No fraud check available for payment_id = {payment_id:?}"
+                ))
+                .into(),
+            )
     }
 
     async fn find_fraud_check_by_payment_id_if_present(
         &self,
-        _payment_id: common_utils::id_type::PaymentId,
-        _merchant_id: common_utils::id_type::MerchantId,
+        payment_id: common_utils::id_type::PaymentId,
+        merchant_id: common_utils::id_type::MerchantId,
     ) -> CustomResult<Option<FraudCheck>, errors::StorageError> {
-        Err(errors::StorageError::MockDbError)?
+        Ok(self
+            .fraud_checks
+            .lock()
+            .await
+            .iter()
+            .find(|fraud_check| {
+                fraud_check.payment_id == payment_id && fraud_check.merchant_id == merchant_id
+            })
+            .cloned())
     }
 }
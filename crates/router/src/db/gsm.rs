@@ -31,6 +31,17 @@ pub trait GsmInterface {
         code: String,
         message: String,
     ) -> CustomResult<hyperswitch_domain_models::gsm::GatewayStatusMap, errors::StorageError>;
+    async fn find_gsm_pattern_rules(
+        &self,
+        connector: String,
+        flow: String,
+        sub_flow: String,
+    ) -> CustomResult<Vec<hyperswitch_domain_models::gsm::GatewayStatusMap>, errors::StorageError>;
+    async fn list_gsm_rules(
+        &self,
+        connector: Option<String>,
+        flow: Option<String>,
+    ) -> CustomResult<Vec<hyperswitch_domain_models::gsm::GatewayStatusMap>, errors::StorageError>;
     async fn update_gsm_rule(
         &self,
         connector: String,
@@ -108,6 +119,47 @@ impl GsmInterface for Store {
             .attach_printable("Failed to convert gsm diesel models to domain models")
     }
 
+    #[instrument(skip_all)]
+    async fn find_gsm_pattern_rules(
+        &self,
+        connector: String,
+        flow: String,
+        sub_flow: String,
+    ) -> CustomResult<Vec<hyperswitch_domain_models::gsm::GatewayStatusMap>, errors::StorageError>
+    {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::GatewayStatusMap::find_pattern_rules(&conn, connector, flow, sub_flow)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))?
+            .into_iter()
+            .map(|gsm_db_record| {
+                hyperswitch_domain_models::gsm::GatewayStatusMap::try_from(gsm_db_record)
+                    .change_context(errors::StorageError::DeserializationFailed)
+                    .attach_printable("Failed to convert gsm diesel models to domain models")
+            })
+            .collect()
+    }
+
+    #[instrument(skip_all)]
+    async fn list_gsm_rules(
+        &self,
+        connector: Option<String>,
+        flow: Option<String>,
+    ) -> CustomResult<Vec<hyperswitch_domain_models::gsm::GatewayStatusMap>, errors::StorageError>
+    {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::GatewayStatusMap::list(&conn, connector, flow)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))?
+            .into_iter()
+            .map(|gsm_db_record| {
+                hyperswitch_domain_models::gsm::GatewayStatusMap::try_from(gsm_db_record)
+                    .change_context(errors::StorageError::DeserializationFailed)
+                    .attach_printable("Failed to convert gsm diesel models to domain models")
+            })
+            .collect()
+    }
+
     #[instrument(skip_all)]
     async fn update_gsm_rule(
         &self,
@@ -158,53 +210,235 @@ impl GsmInterface for Store {
 impl GsmInterface for MockDb {
     async fn add_gsm_rule(
         &self,
-        _rule: hyperswitch_domain_models::gsm::GatewayStatusMap,
+        rule: hyperswitch_domain_models::gsm::GatewayStatusMap,
     ) -> CustomResult<hyperswitch_domain_models::gsm::GatewayStatusMap, errors::StorageError> {
-        Err(errors::StorageError::MockDbError)?
+        let rule = diesel_models::gsm::GatewayStatusMappingNew::try_from(rule)
+            .change_context(errors::StorageError::SerializationFailed)
+            .attach_printable("Failed to convert gsm domain models to diesel models")?;
+        let mut gateway_status_maps = self.gateway_status_maps.lock().await;
+        if gateway_status_maps.iter().any(|gsm| {
+            gsm.connector == rule.connector
+                && gsm.flow == rule.flow
+                && gsm.sub_flow == rule.sub_flow
+                && gsm.code == rule.code
+                && gsm.message == rule.message
+        }) {
+            Err(errors::StorageError::DuplicateValue {
+                entity: "connector, flow, sub_flow, code and message",
+                key: None,
+            })?
+        }
+
+        let now = common_utils::date_time::now();
+        let gsm_db_record = storage::GatewayStatusMap {
+            connector: rule.connector,
+            flow: rule.flow,
+            sub_flow: rule.sub_flow,
+            code: rule.code,
+            message: rule.message,
+            status: rule.status,
+            router_error: rule.router_error,
+            decision: rule.decision,
+            created_at: now,
+            last_modified: now,
+            step_up_possible: rule.step_up_possible,
+            unified_code: rule.unified_code,
+            unified_message: rule.unified_message,
+            error_category: rule.error_category,
+            clear_pan_possible: rule.clear_pan_possible,
+            feature_data: rule.feature_data,
+            feature: rule.feature,
+            standardised_code: rule.standardised_code,
+            description: rule.description,
+            user_guidance_message: rule.user_guidance_message,
+            code_match_type: rule.code_match_type,
+            message_match_type: rule.message_match_type,
+        };
+        gateway_status_maps.push(gsm_db_record.clone());
+
+        hyperswitch_domain_models::gsm::GatewayStatusMap::try_from(gsm_db_record)
+            .change_context(errors::StorageError::DeserializationFailed)
+            .attach_printable("Failed to convert gsm diesel models to domain models")
     }
 
     async fn find_gsm_decision(
         &self,
-        _connector: String,
-        _flow: String,
-        _sub_flow: String,
-        _code: String,
-        _message: String,
+        connector: String,
+        flow: String,
+        sub_flow: String,
+        code: String,
+        message: String,
     ) -> CustomResult<String, errors::StorageError> {
-        Err(errors::StorageError::MockDbError)?
+        self.gateway_status_maps
+            .lock()
+            .await
+            .iter()
+            .find(|gsm| {
+                gsm.connector == connector
+                    && gsm.flow == flow
+                    && gsm.sub_flow == sub_flow
+                    && gsm.code == code
+                    && gsm.message == message
+            })
+            .map(|gsm| gsm.decision.clone())
+            .ok_or(errors::StorageError::ValueNotFound("cannot find gsm rule".to_string()).into())
     }
 
     async fn find_gsm_rule(
         &self,
-        _connector: String,
-        _flow: String,
-        _sub_flow: String,
-        _code: String,
-        _message: String,
+        connector: String,
+        flow: String,
+        sub_flow: String,
+        code: String,
+        message: String,
     ) -> CustomResult<hyperswitch_domain_models::gsm::GatewayStatusMap, errors::StorageError> {
-        Err(errors::StorageError::MockDbError)?
+        let gsm_db_record = self
+            .gateway_status_maps
+            .lock()
+            .await
+            .iter()
+            .find(|gsm| {
+                gsm.connector == connector
+                    && gsm.flow == flow
+                    && gsm.sub_flow == sub_flow
+                    && gsm.code == code
+                    && gsm.message == message
+            })
+            .cloned()
+            .ok_or(errors::StorageError::ValueNotFound(
+                "cannot find gsm rule".to_string(),
+            ))?;
+
+        hyperswitch_domain_models::gsm::GatewayStatusMap::try_from(gsm_db_record)
+            .change_context(errors::StorageError::DeserializationFailed)
+            .attach_printable("Failed to convert gsm diesel models to domain models")
+    }
+
+    async fn find_gsm_pattern_rules(
+        &self,
+        connector: String,
+        flow: String,
+        sub_flow: String,
+    ) -> CustomResult<Vec<hyperswitch_domain_models::gsm::GatewayStatusMap>, errors::StorageError>
+    {
+        let mut gsm_db_records: Vec<_> = self
+            .gateway_status_maps
+            .lock()
+            .await
+            .iter()
+            .filter(|gsm| {
+                gsm.connector == connector
+                    && gsm.flow == flow
+                    && gsm.sub_flow == sub_flow
+                    && (gsm.code_match_type != Some(common_enums::GsmMatchType::Exact)
+                        || gsm.message_match_type != Some(common_enums::GsmMatchType::Exact))
+            })
+            .cloned()
+            .collect();
+        gsm_db_records.sort_by(|a, b| (&a.code, &a.message).cmp(&(&b.code, &b.message)));
+
+        gsm_db_records
+            .into_iter()
+            .map(|gsm_db_record| {
+                hyperswitch_domain_models::gsm::GatewayStatusMap::try_from(gsm_db_record)
+                    .change_context(errors::StorageError::DeserializationFailed)
+                    .attach_printable("Failed to convert gsm diesel models to domain models")
+            })
+            .collect()
+    }
+
+    async fn list_gsm_rules(
+        &self,
+        connector: Option<String>,
+        flow: Option<String>,
+    ) -> CustomResult<Vec<hyperswitch_domain_models::gsm::GatewayStatusMap>, errors::StorageError>
+    {
+        let mut gsm_db_records: Vec<_> = self
+            .gateway_status_maps
+            .lock()
+            .await
+            .iter()
+            .filter(|gsm| {
+                connector
+                    .as_ref()
+                    .is_none_or(|connector| gsm.connector == *connector)
+                    && flow.as_ref().is_none_or(|flow| gsm.flow == *flow)
+            })
+            .cloned()
+            .collect();
+        gsm_db_records.sort_by(|a, b| {
+            (&a.connector, &a.flow, &a.sub_flow, &a.code, &a.message).cmp(&(
+                &b.connector,
+                &b.flow,
+                &b.sub_flow,
+                &b.code,
+                &b.message,
+            ))
+        });
+
+        gsm_db_records
+            .into_iter()
+            .map(|gsm_db_record| {
+                hyperswitch_domain_models::gsm::GatewayStatusMap::try_from(gsm_db_record)
+                    .change_context(errors::StorageError::DeserializationFailed)
+                    .attach_printable("Failed to convert gsm diesel models to domain models")
+            })
+            .collect()
     }
 
     async fn update_gsm_rule(
         &self,
-        _connector: String,
-        _flow: String,
-        _sub_flow: String,
-        _code: String,
-        _message: String,
-        _data: hyperswitch_domain_models::gsm::GatewayStatusMappingUpdate,
+        connector: String,
+        flow: String,
+        sub_flow: String,
+        code: String,
+        message: String,
+        data: hyperswitch_domain_models::gsm::GatewayStatusMappingUpdate,
     ) -> CustomResult<hyperswitch_domain_models::gsm::GatewayStatusMap, errors::StorageError> {
-        Err(errors::StorageError::MockDbError)?
+        let gsm_update_data = diesel_models::gsm::GatewayStatusMappingUpdate::try_from(data)
+            .change_context(errors::StorageError::SerializationFailed)?;
+        let mut gateway_status_maps = self.gateway_status_maps.lock().await;
+        let gsm_db_record = gateway_status_maps
+            .iter_mut()
+            .find(|gsm| {
+                gsm.connector == connector
+                    && gsm.flow == flow
+                    && gsm.sub_flow == sub_flow
+                    && gsm.code == code
+                    && gsm.message == message
+            })
+            .ok_or(errors::StorageError::ValueNotFound(
+                "cannot find gsm rule to update".to_string(),
+            ))?;
+        *gsm_db_record = gsm_update_data.apply_changeset(gsm_db_record.clone());
+
+        hyperswitch_domain_models::gsm::GatewayStatusMap::try_from(gsm_db_record.clone())
+            .change_context(errors::StorageError::DeserializationFailed)
+            .attach_printable("Failed to convert gsm diesel models to domain models")
     }
 
     async fn delete_gsm_rule(
         &self,
-        _connector: String,
-        _flow: String,
-        _sub_flow: String,
-        _code: String,
-        _message: String,
+        connector: String,
+        flow: String,
+        sub_flow: String,
+        code: String,
+        message: String,
     ) -> CustomResult<bool, errors::StorageError> {
-        Err(errors::StorageError::MockDbError)?
+        let mut gateway_status_maps = self.gateway_status_maps.lock().await;
+        let initial_count = gateway_status_maps.len();
+        gateway_status_maps.retain(|gsm| {
+            !(gsm.connector == connector
+                && gsm.flow == flow
+                && gsm.sub_flow == sub_flow
+                && gsm.code == code
+                && gsm.message == message)
+        });
+        if gateway_status_maps.len() == initial_count {
+            Err(errors::StorageError::ValueNotFound(
+                "cannot find gsm rule to delete".to_string(),
+            ))?
+        }
+        Ok(true)
     }
 }
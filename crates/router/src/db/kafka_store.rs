@@ -77,9 +77,11 @@ use crate::{
         ephemeral_key::EphemeralKeyInterface,
         events::EventInterface,
         file::FileMetadataInterface,
+        forex_rate_snapshot::ForexRateSnapshotInterface,
         generic_link::GenericLinkInterface,
         gsm::GsmInterface,
         health_check::HealthCheckDbInterface,
+        key_rotation::KeyRotationInterface,
         locker_mock_up::LockerMockUpInterface,
         mandate::MandateInterface,
         merchant_account::MerchantAccountInterface,
@@ -1175,6 +1177,147 @@ impl ConnectorAccessToken for KafkaStore {
     }
 }
 
+#[async_trait::async_trait]
+impl ForexRateSnapshotInterface for KafkaStore {
+    async fn insert_forex_rate_snapshot(
+        &self,
+        new: storage::ForexRateSnapshotNew,
+    ) -> CustomResult<storage::ForexRateSnapshot, errors::StorageError> {
+        self.diesel_store.insert_forex_rate_snapshot(new).await
+    }
+
+    async fn find_latest_forex_rate_snapshot_as_of(
+        &self,
+        provider: Option<&str>,
+        as_of: PrimitiveDateTime,
+    ) -> CustomResult<Option<storage::ForexRateSnapshot>, errors::StorageError> {
+        self.diesel_store
+            .find_latest_forex_rate_snapshot_as_of(provider, as_of)
+            .await
+    }
+
+    async fn list_latest_forex_rate_snapshots_as_of(
+        &self,
+        as_of: PrimitiveDateTime,
+        limit: i64,
+    ) -> CustomResult<Vec<storage::ForexRateSnapshot>, errors::StorageError> {
+        self.diesel_store
+            .list_latest_forex_rate_snapshots_as_of(as_of, limit)
+            .await
+    }
+}
+
+#[async_trait::async_trait]
+impl KeyRotationInterface for KafkaStore {
+    #[cfg(feature = "v1")]
+    async fn list_customers_for_key_rotation(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        cursor: String,
+        limit: i64,
+    ) -> CustomResult<Vec<storage::Customer>, errors::StorageError> {
+        self.diesel_store
+            .list_customers_for_key_rotation(merchant_id, cursor, limit)
+            .await
+    }
+
+    #[cfg(feature = "v1")]
+    async fn find_customer_for_key_rotation(
+        &self,
+        customer_id: &id_type::CustomerId,
+        merchant_id: &id_type::MerchantId,
+    ) -> CustomResult<Option<storage::Customer>, errors::StorageError> {
+        self.diesel_store
+            .find_customer_for_key_rotation(customer_id, merchant_id)
+            .await
+    }
+
+    #[cfg(feature = "v1")]
+    async fn update_customer_encrypted_fields(
+        &self,
+        customer_id: &id_type::CustomerId,
+        merchant_id: &id_type::MerchantId,
+        modified_at: PrimitiveDateTime,
+        customer: storage::CustomerEncryptionUpdateInternal,
+    ) -> CustomResult<bool, errors::StorageError> {
+        self.diesel_store
+            .update_customer_encrypted_fields(customer_id, merchant_id, modified_at, customer)
+            .await
+    }
+
+    #[cfg(feature = "v1")]
+    async fn list_addresses_for_key_rotation(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        cursor: String,
+        limit: i64,
+    ) -> CustomResult<Vec<storage::Address>, errors::StorageError> {
+        self.diesel_store
+            .list_addresses_for_key_rotation(merchant_id, cursor, limit)
+            .await
+    }
+
+    #[cfg(feature = "v1")]
+    async fn find_address_for_key_rotation(
+        &self,
+        address_id: &str,
+    ) -> CustomResult<Option<storage::Address>, errors::StorageError> {
+        self.diesel_store
+            .find_address_for_key_rotation(address_id)
+            .await
+    }
+
+    #[cfg(feature = "v1")]
+    async fn update_address_encrypted_fields(
+        &self,
+        address_id: String,
+        modified_at: PrimitiveDateTime,
+        address: storage::AddressEncryptionUpdateInternal,
+    ) -> CustomResult<bool, errors::StorageError> {
+        self.diesel_store
+            .update_address_encrypted_fields(address_id, modified_at, address)
+            .await
+    }
+
+    #[cfg(feature = "v1")]
+    async fn list_payment_methods_for_key_rotation(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        cursor: String,
+        limit: i64,
+    ) -> CustomResult<Vec<storage::PaymentMethod>, errors::StorageError> {
+        self.diesel_store
+            .list_payment_methods_for_key_rotation(merchant_id, cursor, limit)
+            .await
+    }
+
+    #[cfg(feature = "v1")]
+    async fn find_payment_method_for_key_rotation(
+        &self,
+        payment_method_id: &str,
+    ) -> CustomResult<Option<storage::PaymentMethod>, errors::StorageError> {
+        self.diesel_store
+            .find_payment_method_for_key_rotation(payment_method_id)
+            .await
+    }
+
+    #[cfg(feature = "v1")]
+    async fn update_payment_method_encrypted_fields(
+        &self,
+        payment_method_id: String,
+        last_modified: PrimitiveDateTime,
+        payment_method: storage::PaymentMethodEncryptionUpdateInternal,
+    ) -> CustomResult<bool, errors::StorageError> {
+        self.diesel_store
+            .update_payment_method_encrypted_fields(
+                payment_method_id,
+                last_modified,
+                payment_method,
+            )
+            .await
+    }
+}
+
 #[async_trait::async_trait]
 impl FileMetadataInterface for KafkaStore {
     async fn insert_file_metadata(
@@ -2963,6 +3106,16 @@ impl MerchantKeyStoreInterface for KafkaStore {
             .await
     }
 
+    async fn update_merchant_key_store(
+        &self,
+        merchant_key_store: domain::MerchantKeyStore,
+        key: &Secret<Vec<u8>>,
+    ) -> CustomResult<domain::MerchantKeyStore, errors::StorageError> {
+        self.diesel_store
+            .update_merchant_key_store(merchant_key_store, key)
+            .await
+    }
+
     async fn delete_merchant_key_store_by_merchant_id(
         &self,
         merchant_id: &id_type::MerchantId,
@@ -2990,6 +3143,17 @@ impl MerchantKeyStoreInterface for KafkaStore {
     ) -> CustomResult<Vec<domain::MerchantKeyStore>, errors::StorageError> {
         self.diesel_store.get_all_key_stores(key, from, to).await
     }
+
+    async fn list_key_stores_after_merchant_id(
+        &self,
+        key: &Secret<Vec<u8>>,
+        cursor: String,
+        limit: u32,
+    ) -> CustomResult<Vec<domain::MerchantKeyStore>, errors::StorageError> {
+        self.diesel_store
+            .list_key_stores_after_merchant_id(key, cursor, limit)
+            .await
+    }
 }
 
 #[async_trait::async_trait]
@@ -3220,6 +3384,27 @@ impl GsmInterface for KafkaStore {
             .await
     }
 
+    async fn find_gsm_pattern_rules(
+        &self,
+        connector: String,
+        flow: String,
+        sub_flow: String,
+    ) -> CustomResult<Vec<hyperswitch_domain_models::gsm::GatewayStatusMap>, errors::StorageError>
+    {
+        self.diesel_store
+            .find_gsm_pattern_rules(connector, flow, sub_flow)
+            .await
+    }
+
+    async fn list_gsm_rules(
+        &self,
+        connector: Option<String>,
+        flow: Option<String>,
+    ) -> CustomResult<Vec<hyperswitch_domain_models::gsm::GatewayStatusMap>, errors::StorageError>
+    {
+        self.diesel_store.list_gsm_rules(connector, flow).await
+    }
+
     async fn update_gsm_rule(
         &self,
         connector: String,
@@ -0,0 +1,456 @@
+#[cfg(feature = "v1")]
+use error_stack::report;
+#[cfg(feature = "v1")]
+use router_env::{instrument, tracing};
+#[cfg(feature = "v1")]
+use time::PrimitiveDateTime;
+
+use super::{MockDb, Store};
+#[cfg(feature = "v1")]
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+    types::storage,
+};
+
+/// Batch access to the encrypted records of a merchant, used to re-encrypt them with a rotated
+/// data encryption key. Records are paged in primary key order, starting after the cursor, and
+/// read from the primary database so that updates are made against their latest version.
+///
+/// Updates are only applied if the record hasn't been modified since it was read, and report
+/// whether they were applied. They are written to the database directly, copies held in redis by
+/// the KV store keep values encrypted with a previous key, which remain readable through the
+/// merchant key store.
+#[async_trait::async_trait]
+pub trait KeyRotationInterface {
+    #[cfg(feature = "v1")]
+    async fn list_customers_for_key_rotation(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        cursor: String,
+        limit: i64,
+    ) -> CustomResult<Vec<storage::Customer>, errors::StorageError>;
+
+    #[cfg(feature = "v1")]
+    async fn find_customer_for_key_rotation(
+        &self,
+        customer_id: &common_utils::id_type::CustomerId,
+        merchant_id: &common_utils::id_type::MerchantId,
+    ) -> CustomResult<Option<storage::Customer>, errors::StorageError>;
+
+    #[cfg(feature = "v1")]
+    async fn update_customer_encrypted_fields(
+        &self,
+        customer_id: &common_utils::id_type::CustomerId,
+        merchant_id: &common_utils::id_type::MerchantId,
+        modified_at: PrimitiveDateTime,
+        customer: storage::CustomerEncryptionUpdateInternal,
+    ) -> CustomResult<bool, errors::StorageError>;
+
+    #[cfg(feature = "v1")]
+    async fn list_addresses_for_key_rotation(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        cursor: String,
+        limit: i64,
+    ) -> CustomResult<Vec<storage::Address>, errors::StorageError>;
+
+    #[cfg(feature = "v1")]
+    async fn find_address_for_key_rotation(
+        &self,
+        address_id: &str,
+    ) -> CustomResult<Option<storage::Address>, errors::StorageError>;
+
+    #[cfg(feature = "v1")]
+    async fn update_address_encrypted_fields(
+        &self,
+        address_id: String,
+        modified_at: PrimitiveDateTime,
+        address: storage::AddressEncryptionUpdateInternal,
+    ) -> CustomResult<bool, errors::StorageError>;
+
+    #[cfg(feature = "v1")]
+    async fn list_payment_methods_for_key_rotation(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        cursor: String,
+        limit: i64,
+    ) -> CustomResult<Vec<storage::PaymentMethod>, errors::StorageError>;
+
+    #[cfg(feature = "v1")]
+    async fn find_payment_method_for_key_rotation(
+        &self,
+        payment_method_id: &str,
+    ) -> CustomResult<Option<storage::PaymentMethod>, errors::StorageError>;
+
+    #[cfg(feature = "v1")]
+    async fn update_payment_method_encrypted_fields(
+        &self,
+        payment_method_id: String,
+        last_modified: PrimitiveDateTime,
+        payment_method: storage::PaymentMethodEncryptionUpdateInternal,
+    ) -> CustomResult<bool, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl KeyRotationInterface for Store {
+    #[cfg(feature = "v1")]
+    #[instrument(skip_all)]
+    async fn list_customers_for_key_rotation(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        cursor: String,
+        limit: i64,
+    ) -> CustomResult<Vec<storage::Customer>, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::Customer::list_by_merchant_id_after_customer_id(&conn, merchant_id, cursor, limit)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[cfg(feature = "v1")]
+    #[instrument(skip_all)]
+    async fn find_customer_for_key_rotation(
+        &self,
+        customer_id: &common_utils::id_type::CustomerId,
+        merchant_id: &common_utils::id_type::MerchantId,
+    ) -> CustomResult<Option<storage::Customer>, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::Customer::find_optional_by_customer_id_merchant_id(&conn, customer_id, merchant_id)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[cfg(feature = "v1")]
+    #[instrument(skip_all)]
+    async fn update_customer_encrypted_fields(
+        &self,
+        customer_id: &common_utils::id_type::CustomerId,
+        merchant_id: &common_utils::id_type::MerchantId,
+        modified_at: PrimitiveDateTime,
+        customer: storage::CustomerEncryptionUpdateInternal,
+    ) -> CustomResult<bool, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::Customer::update_encrypted_fields_by_customer_id_merchant_id(
+            &conn,
+            customer_id,
+            merchant_id,
+            modified_at,
+            customer,
+        )
+        .await
+        .map(|updated_rows| updated_rows > 0)
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[cfg(feature = "v1")]
+    #[instrument(skip_all)]
+    async fn list_addresses_for_key_rotation(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        cursor: String,
+        limit: i64,
+    ) -> CustomResult<Vec<storage::Address>, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::Address::list_by_merchant_id_after_address_id(&conn, merchant_id, cursor, limit)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[cfg(feature = "v1")]
+    #[instrument(skip_all)]
+    async fn find_address_for_key_rotation(
+        &self,
+        address_id: &str,
+    ) -> CustomResult<Option<storage::Address>, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::Address::find_optional_by_address_id(&conn, address_id)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[cfg(feature = "v1")]
+    #[instrument(skip_all)]
+    async fn update_address_encrypted_fields(
+        &self,
+        address_id: String,
+        modified_at: PrimitiveDateTime,
+        address: storage::AddressEncryptionUpdateInternal,
+    ) -> CustomResult<bool, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::Address::update_encrypted_fields_by_address_id(
+            &conn,
+            address_id,
+            modified_at,
+            address,
+        )
+        .await
+        .map(|updated_rows| updated_rows > 0)
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[cfg(feature = "v1")]
+    #[instrument(skip_all)]
+    async fn list_payment_methods_for_key_rotation(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        cursor: String,
+        limit: i64,
+    ) -> CustomResult<Vec<storage::PaymentMethod>, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::PaymentMethod::list_by_merchant_id_after_payment_method_id(
+            &conn,
+            merchant_id,
+            cursor,
+            limit,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[cfg(feature = "v1")]
+    #[instrument(skip_all)]
+    async fn find_payment_method_for_key_rotation(
+        &self,
+        payment_method_id: &str,
+    ) -> CustomResult<Option<storage::PaymentMethod>, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::PaymentMethod::find_optional_by_payment_method_id(&conn, payment_method_id)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[cfg(feature = "v1")]
+    #[instrument(skip_all)]
+    async fn update_payment_method_encrypted_fields(
+        &self,
+        payment_method_id: String,
+        last_modified: PrimitiveDateTime,
+        payment_method: storage::PaymentMethodEncryptionUpdateInternal,
+    ) -> CustomResult<bool, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::PaymentMethod::update_encrypted_fields_by_payment_method_id(
+            &conn,
+            payment_method_id,
+            last_modified,
+            payment_method,
+        )
+        .await
+        .map(|updated_rows| updated_rows > 0)
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+}
+
+#[async_trait::async_trait]
+impl KeyRotationInterface for MockDb {
+    #[cfg(feature = "v1")]
+    async fn list_customers_for_key_rotation(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        cursor: String,
+        limit: i64,
+    ) -> CustomResult<Vec<storage::Customer>, errors::StorageError> {
+        let mut customers: Vec<_> = self
+            .customers
+            .lock()
+            .await
+            .iter()
+            .filter(|customer| {
+                customer.merchant_id == *merchant_id
+                    && customer.customer_id.get_string_repr() > cursor.as_str()
+            })
+            .cloned()
+            .collect();
+        customers.sort_by(|a, b| {
+            a.customer_id
+                .get_string_repr()
+                .cmp(b.customer_id.get_string_repr())
+        });
+        customers.truncate(usize::try_from(limit).unwrap_or_default());
+
+        Ok(customers)
+    }
+
+    #[cfg(feature = "v1")]
+    async fn find_customer_for_key_rotation(
+        &self,
+        customer_id: &common_utils::id_type::CustomerId,
+        merchant_id: &common_utils::id_type::MerchantId,
+    ) -> CustomResult<Option<storage::Customer>, errors::StorageError> {
+        Ok(self
+            .customers
+            .lock()
+            .await
+            .iter()
+            .find(|customer| {
+                customer.customer_id == *customer_id && customer.merchant_id == *merchant_id
+            })
+            .cloned())
+    }
+
+    #[cfg(feature = "v1")]
+    async fn update_customer_encrypted_fields(
+        &self,
+        customer_id: &common_utils::id_type::CustomerId,
+        merchant_id: &common_utils::id_type::MerchantId,
+        modified_at: PrimitiveDateTime,
+        customer: storage::CustomerEncryptionUpdateInternal,
+    ) -> CustomResult<bool, errors::StorageError> {
+        let mut customers = self.customers.lock().await;
+        let Some(stored_customer) = customers.iter_mut().find(|stored_customer| {
+            stored_customer.customer_id == *customer_id
+                && stored_customer.merchant_id == *merchant_id
+                && stored_customer.modified_at == modified_at
+        }) else {
+            return Ok(false);
+        };
+
+        stored_customer.name = customer.name.or(stored_customer.name.take());
+        stored_customer.email = customer.email.or(stored_customer.email.take());
+        stored_customer.phone = customer.phone.or(stored_customer.phone.take());
+        stored_customer.tax_registration_id = customer
+            .tax_registration_id
+            .or(stored_customer.tax_registration_id.take());
+        stored_customer.document_details = customer
+            .document_details
+            .or(stored_customer.document_details.take());
+
+        Ok(true)
+    }
+
+    #[cfg(feature = "v1")]
+    async fn list_addresses_for_key_rotation(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        cursor: String,
+        limit: i64,
+    ) -> CustomResult<Vec<storage::Address>, errors::StorageError> {
+        let mut addresses: Vec<_> = self
+            .addresses
+            .lock()
+            .await
+            .iter()
+            .filter(|address| address.merchant_id == *merchant_id && address.address_id > cursor)
+            .cloned()
+            .collect();
+        addresses.sort_by(|a, b| a.address_id.cmp(&b.address_id));
+        addresses.truncate(usize::try_from(limit).unwrap_or_default());
+
+        Ok(addresses)
+    }
+
+    #[cfg(feature = "v1")]
+    async fn find_address_for_key_rotation(
+        &self,
+        address_id: &str,
+    ) -> CustomResult<Option<storage::Address>, errors::StorageError> {
+        Ok(self
+            .addresses
+            .lock()
+            .await
+            .iter()
+            .find(|address| address.address_id == address_id)
+            .cloned())
+    }
+
+    #[cfg(feature = "v1")]
+    async fn update_address_encrypted_fields(
+        &self,
+        address_id: String,
+        modified_at: PrimitiveDateTime,
+        address: storage::AddressEncryptionUpdateInternal,
+    ) -> CustomResult<bool, errors::StorageError> {
+        let mut addresses = self.addresses.lock().await;
+        let Some(stored_address) = addresses.iter_mut().find(|stored_address| {
+            stored_address.address_id == address_id && stored_address.modified_at == modified_at
+        }) else {
+            return Ok(false);
+        };
+
+        stored_address.line1 = address.line1.or(stored_address.line1.take());
+        stored_address.line2 = address.line2.or(stored_address.line2.take());
+        stored_address.line3 = address.line3.or(stored_address.line3.take());
+        stored_address.state = address.state.or(stored_address.state.take());
+        stored_address.zip = address.zip.or(stored_address.zip.take());
+        stored_address.first_name = address.first_name.or(stored_address.first_name.take());
+        stored_address.last_name = address.last_name.or(stored_address.last_name.take());
+        stored_address.phone_number = address.phone_number.or(stored_address.phone_number.take());
+        stored_address.email = address.email.or(stored_address.email.take());
+        stored_address.origin_zip = address.origin_zip.or(stored_address.origin_zip.take());
+
+        Ok(true)
+    }
+
+    #[cfg(feature = "v1")]
+    async fn list_payment_methods_for_key_rotation(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        cursor: String,
+        limit: i64,
+    ) -> CustomResult<Vec<storage::PaymentMethod>, errors::StorageError> {
+        let mut payment_methods: Vec<_> = self
+            .payment_methods
+            .lock()
+            .await
+            .iter()
+            .filter(|payment_method| {
+                payment_method.merchant_id == *merchant_id
+                    && payment_method.payment_method_id > cursor
+            })
+            .cloned()
+            .collect();
+        payment_methods.sort_by(|a, b| a.payment_method_id.cmp(&b.payment_method_id));
+        payment_methods.truncate(usize::try_from(limit).unwrap_or_default());
+
+        Ok(payment_methods)
+    }
+
+    #[cfg(feature = "v1")]
+    async fn find_payment_method_for_key_rotation(
+        &self,
+        payment_method_id: &str,
+    ) -> CustomResult<Option<storage::PaymentMethod>, errors::StorageError> {
+        Ok(self
+            .payment_methods
+            .lock()
+            .await
+            .iter()
+            .find(|payment_method| payment_method.payment_method_id == payment_method_id)
+            .cloned())
+    }
+
+    #[cfg(feature = "v1")]
+    async fn update_payment_method_encrypted_fields(
+        &self,
+        payment_method_id: String,
+        last_modified: PrimitiveDateTime,
+        payment_method: storage::PaymentMethodEncryptionUpdateInternal,
+    ) -> CustomResult<bool, errors::StorageError> {
+        let mut payment_methods = self.payment_methods.lock().await;
+        let Some(stored_payment_method) =
+            payment_methods.iter_mut().find(|stored_payment_method| {
+                stored_payment_method.payment_method_id == payment_method_id
+                    && stored_payment_method.last_modified == last_modified
+            })
+        else {
+            return Ok(false);
+        };
+
+        stored_payment_method.payment_method_data = payment_method
+            .payment_method_data
+            .or(stored_payment_method.payment_method_data.take());
+        stored_payment_method.payment_method_billing_address = payment_method
+            .payment_method_billing_address
+            .or(stored_payment_method.payment_method_billing_address.take());
+        stored_payment_method.network_token_payment_method_data = payment_method
+            .network_token_payment_method_data
+            .or(stored_payment_method
+                .network_token_payment_method_data
+                .take());
+        stored_payment_method.customer_details = payment_method
+            .customer_details
+            .or(stored_payment_method.customer_details.take());
+
+        Ok(true)
+    }
+}
@@ -187,6 +187,8 @@ mod merchant_connector_account_cache_tests {
                 .and_then(|val| val.try_into_operation())
                 .unwrap(),
                 created_at: datetime!(2023-02-01 0:00),
+                key_version: 1,
+                previous_keys: None,
             },
             &master_key.to_vec().into(),
         )
@@ -368,6 +370,8 @@ mod merchant_connector_account_cache_tests {
                 .and_then(|val| val.try_into_operation())
                 .unwrap(),
                 created_at: datetime!(2023-02-01 0:00),
+                key_version: 1,
+                previous_keys: None,
             },
             &master_key.to_vec().into(),
         )
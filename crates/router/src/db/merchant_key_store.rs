@@ -68,6 +68,8 @@ mod tests {
                     .and_then(|val| val.try_into_operation())
                     .unwrap(),
                     created_at: datetime!(2023-02-01 0:00),
+                    key_version: 1,
+                    previous_keys: None,
                 },
                 &master_key.to_vec().into(),
             )
@@ -99,6 +101,8 @@ mod tests {
                     .and_then(|val| val.try_into_operation())
                     .unwrap(),
                     created_at: datetime!(2023-02-01 0:00),
+                    key_version: 1,
+                    previous_keys: None,
                 },
                 &master_key.to_vec().into(),
             )
@@ -57,7 +57,7 @@ impl RelayInterface for Store {
             .convert(
                 self.get_keymanager_state()
                     .attach_printable("Missing KeyManagerState")?,
-                merchant_key_store.key.get_inner(),
+                &merchant_key_store.get_key_ring(),
                 merchant_key_store.merchant_id.clone().into(),
             )
             .await
@@ -83,7 +83,7 @@ impl RelayInterface for Store {
             .convert(
                 self.get_keymanager_state()
                     .attach_printable("Missing KeyManagerState")?,
-                merchant_key_store.key.get_inner(),
+                &merchant_key_store.get_key_ring(),
                 merchant_key_store.merchant_id.clone().into(),
             )
             .await
@@ -102,7 +102,7 @@ impl RelayInterface for Store {
             .convert(
                 self.get_keymanager_state()
                     .attach_printable("Missing KeyManagerState")?,
-                merchant_key_store.key.get_inner(),
+                &merchant_key_store.get_key_ring(),
                 merchant_key_store.merchant_id.clone().into(),
             )
             .await
@@ -126,7 +126,7 @@ impl RelayInterface for Store {
         .convert(
             self.get_keymanager_state()
                 .attach_printable("Missing KeyManagerState")?,
-            merchant_key_store.key.get_inner(),
+            &merchant_key_store.get_key_ring(),
             merchant_key_store.merchant_id.clone().into(),
         )
         .await
@@ -138,36 +138,120 @@ impl RelayInterface for Store {
 impl RelayInterface for MockDb {
     async fn insert_relay(
         &self,
-        _merchant_key_store: &domain::MerchantKeyStore,
-        _new: hyperswitch_domain_models::relay::Relay,
+        merchant_key_store: &domain::MerchantKeyStore,
+        new: hyperswitch_domain_models::relay::Relay,
     ) -> CustomResult<hyperswitch_domain_models::relay::Relay, errors::StorageError> {
-        Err(errors::StorageError::MockDbError)?
+        let relay = Conversion::convert(new)
+            .await
+            .change_context(errors::StorageError::EncryptionError)?;
+        let mut relays = self.relays.lock().await;
+        if relays.iter().any(|existing| existing.id == relay.id) {
+            Err(errors::StorageError::DuplicateValue {
+                entity: "relay",
+                key: Some(relay.id.get_string_repr().to_owned()),
+            })?
+        }
+        relays.push(relay.clone());
+        drop(relays);
+
+        relay
+            .convert(
+                self.get_keymanager_state()
+                    .attach_printable("Missing KeyManagerState")?,
+                &merchant_key_store.get_key_ring(),
+                merchant_key_store.merchant_id.clone().into(),
+            )
+            .await
+            .change_context(errors::StorageError::DecryptionError)
     }
 
     async fn update_relay(
         &self,
-        _merchant_key_store: &domain::MerchantKeyStore,
-        _current_state: hyperswitch_domain_models::relay::Relay,
-        _relay_update: hyperswitch_domain_models::relay::RelayUpdate,
+        merchant_key_store: &domain::MerchantKeyStore,
+        current_state: hyperswitch_domain_models::relay::Relay,
+        relay_update: hyperswitch_domain_models::relay::RelayUpdate,
     ) -> CustomResult<hyperswitch_domain_models::relay::Relay, errors::StorageError> {
-        Err(errors::StorageError::MockDbError)?
+        let updated_relay = {
+            let mut relays = self.relays.lock().await;
+            let relay = relays
+                .iter_mut()
+                .find(|relay| relay.id == current_state.id)
+                .ok_or(errors::StorageError::ValueNotFound(format!(
+                    "No relay available for id = {:?}",
+                    current_state.id
+                )))?;
+            *relay = diesel_models::relay::RelayUpdateInternal::from(relay_update)
+                .apply_changeset(relay.clone());
+            relay.clone()
+        };
+
+        updated_relay
+            .convert(
+                self.get_keymanager_state()
+                    .attach_printable("Missing KeyManagerState")?,
+                &merchant_key_store.get_key_ring(),
+                merchant_key_store.merchant_id.clone().into(),
+            )
+            .await
+            .change_context(errors::StorageError::DecryptionError)
     }
 
     async fn find_relay_by_id(
         &self,
-        _merchant_key_store: &domain::MerchantKeyStore,
-        _relay_id: &common_utils::id_type::RelayId,
+        merchant_key_store: &domain::MerchantKeyStore,
+        relay_id: &common_utils::id_type::RelayId,
     ) -> CustomResult<hyperswitch_domain_models::relay::Relay, errors::StorageError> {
-        Err(errors::StorageError::MockDbError)?
+        let relay = self
+            .relays
+            .lock()
+            .await
+            .iter()
+            .find(|relay| relay.id == *relay_id)
+            .cloned()
+            .ok_or(errors::StorageError::ValueNotFound(format!(
+                "No relay available for id = {relay_id:?}"
+            )))?;
+
+        relay
+            .convert(
+                self.get_keymanager_state()
+                    .attach_printable("Missing KeyManagerState")?,
+                &merchant_key_store.get_key_ring(),
+                merchant_key_store.merchant_id.clone().into(),
+            )
+            .await
+            .change_context(errors::StorageError::DecryptionError)
     }
 
     async fn find_relay_by_profile_id_connector_reference_id(
         &self,
-        _merchant_key_store: &domain::MerchantKeyStore,
-        _profile_id: &common_utils::id_type::ProfileId,
-        _connector_reference_id: &str,
+        merchant_key_store: &domain::MerchantKeyStore,
+        profile_id: &common_utils::id_type::ProfileId,
+        connector_reference_id: &str,
     ) -> CustomResult<hyperswitch_domain_models::relay::Relay, errors::StorageError> {
-        Err(errors::StorageError::MockDbError)?
+        let relay = self
+            .relays
+            .lock()
+            .await
+            .iter()
+            .find(|relay| {
+                relay.profile_id == *profile_id
+                    && relay.connector_reference_id.as_deref() == Some(connector_reference_id)
+            })
+            .cloned()
+            .ok_or(errors::StorageError::ValueNotFound(format!(
+                "No relay available for profile_id = {profile_id:?} and connector_reference_id = {connector_reference_id}"
+            )))?;
+
+        relay
+            .convert(
+                self.get_keymanager_state()
+                    .attach_printable("Missing KeyManagerState")?,
+                &merchant_key_store.get_key_ring(),
+                merchant_key_store.merchant_id.clone().into(),
+            )
+            .await
+            .change_context(errors::StorageError::DecryptionError)
     }
 }
 
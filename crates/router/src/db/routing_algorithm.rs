@@ -178,60 +178,154 @@ impl RoutingAlgorithmInterface for Store {
 impl RoutingAlgorithmInterface for MockDb {
     async fn insert_routing_algorithm(
         &self,
-        _routing_algorithm: routing_storage::RoutingAlgorithm,
+        routing_algorithm: routing_storage::RoutingAlgorithm,
     ) -> StorageResult<routing_storage::RoutingAlgorithm> {
-        Err(errors::StorageError::MockDbError)?
+        let mut routing_algorithms = self.routing_algorithms.lock().await;
+        if routing_algorithms
+            .iter()
+            .any(|algorithm| algorithm.algorithm_id == routing_algorithm.algorithm_id)
+        {
+            Err(errors::StorageError::DuplicateValue {
+                entity: "routing_algorithm",
+                key: Some(routing_algorithm.algorithm_id.get_string_repr().to_owned()),
+            })?
+        }
+
+        routing_algorithms.push(routing_algorithm.clone());
+        Ok(routing_algorithm)
     }
 
     async fn find_routing_algorithm_by_profile_id_algorithm_id(
         &self,
-        _profile_id: &common_utils::id_type::ProfileId,
-        _algorithm_id: &common_utils::id_type::RoutingId,
+        profile_id: &common_utils::id_type::ProfileId,
+        algorithm_id: &common_utils::id_type::RoutingId,
     ) -> StorageResult<routing_storage::RoutingAlgorithm> {
-        Err(errors::StorageError::MockDbError)?
+        self.routing_algorithms
+            .lock()
+            .await
+            .iter()
+            .find(|algorithm| {
+                algorithm.profile_id == *profile_id && algorithm.algorithm_id == *algorithm_id
+            })
+            .cloned()
+            .ok_or(
+                errors::StorageError::ValueNotFound(format!(
+                    "No routing algorithm available for profile_id = {profile_id:?} and algorithm_id = {algorithm_id:?}"
+                ))
+                .into(),
+            )
     }
 
     async fn find_routing_algorithm_by_algorithm_id_merchant_id(
         &self,
-        _algorithm_id: &common_utils::id_type::RoutingId,
-        _merchant_id: &common_utils::id_type::MerchantId,
+        algorithm_id: &common_utils::id_type::RoutingId,
+        merchant_id: &common_utils::id_type::MerchantId,
     ) -> StorageResult<routing_storage::RoutingAlgorithm> {
-        Err(errors::StorageError::MockDbError)?
+        self.routing_algorithms
+            .lock()
+            .await
+            .iter()
+            .find(|algorithm| {
+                algorithm.algorithm_id == *algorithm_id && algorithm.merchant_id == *merchant_id
+            })
+            .cloned()
+            .ok_or(
+                errors::StorageError::ValueNotFound(format!(
+                    "No routing algorithm available for algorithm_id = {algorithm_id:?} and merchant_id = {merchant_id:?}"
+                ))
+                .into(),
+            )
     }
 
     async fn find_routing_algorithm_metadata_by_algorithm_id_profile_id(
         &self,
-        _algorithm_id: &common_utils::id_type::RoutingId,
-        _profile_id: &common_utils::id_type::ProfileId,
+        algorithm_id: &common_utils::id_type::RoutingId,
+        profile_id: &common_utils::id_type::ProfileId,
     ) -> StorageResult<routing_storage::RoutingProfileMetadata> {
-        Err(errors::StorageError::MockDbError)?
+        self.find_routing_algorithm_by_profile_id_algorithm_id(profile_id, algorithm_id)
+            .await
+            .map(|algorithm| to_profile_metadata(&algorithm))
     }
 
     async fn list_routing_algorithm_metadata_by_profile_id(
         &self,
-        _profile_id: &common_utils::id_type::ProfileId,
-        _limit: i64,
-        _offset: i64,
+        profile_id: &common_utils::id_type::ProfileId,
+        limit: i64,
+        offset: i64,
     ) -> StorageResult<Vec<routing_storage::RoutingProfileMetadata>> {
-        Err(errors::StorageError::MockDbError)?
+        let routing_algorithms = self.routing_algorithms.lock().await;
+        Ok(paginate_metadata(
+            routing_algorithms
+                .iter()
+                .filter(|algorithm| algorithm.profile_id == *profile_id),
+            limit,
+            offset,
+        ))
     }
 
     async fn list_routing_algorithm_metadata_by_merchant_id(
         &self,
-        _merchant_id: &common_utils::id_type::MerchantId,
-        _limit: i64,
-        _offset: i64,
+        merchant_id: &common_utils::id_type::MerchantId,
+        limit: i64,
+        offset: i64,
     ) -> StorageResult<Vec<routing_storage::RoutingProfileMetadata>> {
-        Err(errors::StorageError::MockDbError)?
+        let routing_algorithms = self.routing_algorithms.lock().await;
+        Ok(paginate_metadata(
+            routing_algorithms
+                .iter()
+                .filter(|algorithm| algorithm.merchant_id == *merchant_id),
+            limit,
+            offset,
+        ))
     }
 
     async fn list_routing_algorithm_metadata_by_merchant_id_transaction_type(
         &self,
-        _merchant_id: &common_utils::id_type::MerchantId,
-        _transaction_type: &common_enums::TransactionType,
-        _limit: i64,
-        _offset: i64,
+        merchant_id: &common_utils::id_type::MerchantId,
+        transaction_type: &common_enums::TransactionType,
+        limit: i64,
+        offset: i64,
     ) -> StorageResult<Vec<routing_storage::RoutingProfileMetadata>> {
-        Err(errors::StorageError::MockDbError)?
+        let routing_algorithms = self.routing_algorithms.lock().await;
+        Ok(paginate_metadata(
+            routing_algorithms.iter().filter(|algorithm| {
+                algorithm.merchant_id == *merchant_id
+                    && algorithm.algorithm_for == *transaction_type
+            }),
+            limit,
+            offset,
+        ))
+    }
+}
+
+fn to_profile_metadata(
+    algorithm: &routing_storage::RoutingAlgorithm,
+) -> routing_storage::RoutingProfileMetadata {
+    routing_storage::RoutingProfileMetadata {
+        profile_id: algorithm.profile_id.clone(),
+        algorithm_id: algorithm.algorithm_id.clone(),
+        name: algorithm.name.clone(),
+        description: algorithm.description.clone(),
+        kind: algorithm.kind,
+        created_at: algorithm.created_at,
+        modified_at: algorithm.modified_at,
+        algorithm_for: algorithm.algorithm_for,
     }
 }
+
+/// Orders the algorithms by most recently modified first and applies `offset` and `limit`, the
+/// same way the database queries do.
+fn paginate_metadata<'a>(
+    algorithms: impl Iterator<Item = &'a routing_storage::RoutingAlgorithm>,
+    limit: i64,
+    offset: i64,
+) -> Vec<routing_storage::RoutingProfileMetadata> {
+    let mut algorithms = algorithms.collect::<Vec<_>>();
+    algorithms.sort_by(|a, b| b.modified_at.cmp(&a.modified_at));
+    algorithms
+        .into_iter()
+        .skip(usize::try_from(offset).unwrap_or(0))
+        .take(usize::try_from(limit).unwrap_or(usize::MAX))
+        .map(to_profile_metadata)
+        .collect()
+}
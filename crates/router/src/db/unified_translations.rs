@@ -111,36 +111,96 @@ impl UnifiedTranslationsInterface for Store {
 impl UnifiedTranslationsInterface for MockDb {
     async fn add_unfied_translation(
         &self,
-        _translation: storage::UnifiedTranslationsNew,
+        translation: storage::UnifiedTranslationsNew,
     ) -> CustomResult<storage::UnifiedTranslations, errors::StorageError> {
-        Err(errors::StorageError::MockDbError)?
+        let mut unified_translations = self.unified_translations.lock().await;
+        if unified_translations.iter().any(|existing| {
+            existing.unified_code == translation.unified_code
+                && existing.unified_message == translation.unified_message
+                && existing.locale == translation.locale
+        }) {
+            Err(errors::StorageError::DuplicateValue {
+                entity: "unified_translations",
+                key: None,
+            })?
+        }
+
+        let translation = storage::UnifiedTranslations {
+            unified_code: translation.unified_code,
+            unified_message: translation.unified_message,
+            locale: translation.locale,
+            translation: translation.translation,
+            created_at: translation.created_at,
+            last_modified_at: translation.last_modified_at,
+        };
+        unified_translations.push(translation.clone());
+        Ok(translation)
     }
 
     async fn find_translation(
         &self,
-        _unified_code: String,
-        _unified_message: String,
-        _locale: String,
+        unified_code: String,
+        unified_message: String,
+        locale: String,
     ) -> CustomResult<String, errors::StorageError> {
-        Err(errors::StorageError::MockDbError)?
+        self.unified_translations
+            .lock()
+            .await
+            .iter()
+            .find(|translation| {
+                translation.unified_code == unified_code
+                    && translation.unified_message == unified_message
+                    && translation.locale == locale
+            })
+            .map(|translation| translation.translation.clone())
+            .ok_or(
+                errors::StorageError::ValueNotFound(format!(
+                    "No translation available for unified_code = {unified_code} and locale = {locale}"
+                ))
+                .into(),
+            )
     }
 
     async fn update_translation(
         &self,
-        _unified_code: String,
-        _unified_message: String,
-        _locale: String,
-        _data: storage::UnifiedTranslationsUpdate,
+        unified_code: String,
+        unified_message: String,
+        locale: String,
+        data: storage::UnifiedTranslationsUpdate,
     ) -> CustomResult<storage::UnifiedTranslations, errors::StorageError> {
-        Err(errors::StorageError::MockDbError)?
+        let mut unified_translations = self.unified_translations.lock().await;
+        let translation = unified_translations
+            .iter_mut()
+            .find(|translation| {
+                translation.unified_code == unified_code
+                    && translation.unified_message == unified_message
+                    && translation.locale == locale
+            })
+            .ok_or(errors::StorageError::ValueNotFound(format!(
+                "No translation available for unified_code = {unified_code} and locale = {locale}"
+            )))?;
+        *translation = data.apply_changeset(translation.clone());
+        Ok(translation.clone())
     }
 
     async fn delete_translation(
         &self,
-        _unified_code: String,
-        _unified_message: String,
-        _locale: String,
+        unified_code: String,
+        unified_message: String,
+        locale: String,
     ) -> CustomResult<bool, errors::StorageError> {
-        Err(errors::StorageError::MockDbError)?
+        let mut unified_translations = self.unified_translations.lock().await;
+        let index = unified_translations
+            .iter()
+            .position(|translation| {
+                translation.unified_code == unified_code
+                    && translation.unified_message == unified_message
+                    && translation.locale == locale
+            })
+            .ok_or(errors::StorageError::ValueNotFound(format!(
+                "No translation available for unified_code = {unified_code} and locale = {locale}"
+            )))?;
+        unified_translations.remove(index);
+        Ok(true)
     }
 }
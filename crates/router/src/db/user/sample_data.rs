@@ -1,3 +1,4 @@
+use common_enums::MerchantStorageScheme;
 use common_utils::types::keymanager::KeyManagerState;
 #[cfg(feature = "v1")]
 use diesel_models::user::sample_data::PaymentAttemptBatchNew;
@@ -14,9 +15,15 @@ use hyperswitch_domain_models::{
     merchant_key_store::MerchantKeyStore,
     payments::{payment_attempt::PaymentAttempt, PaymentIntent},
 };
+use masking::Secret;
 use storage_impl::errors::StorageError;
 
-use crate::{connection::pg_connection_write, core::errors::CustomResult, services::Store};
+use crate::{
+    connection::pg_connection_write,
+    core::errors::CustomResult,
+    db::{dispute::DisputeInterface, refund::RefundInterface},
+    services::Store,
+};
 
 #[async_trait::async_trait]
 pub trait BatchSampleDataInterface {
@@ -104,7 +111,7 @@ impl BatchSampleDataInterface for Store {
                     PaymentIntent::convert_back(
                         state,
                         payment_intent,
-                        key_store.key.get_inner(),
+                        &key_store.get_key_ring(),
                         key_store.merchant_id.clone().into(),
                     )
                 }))
@@ -131,7 +138,7 @@ impl BatchSampleDataInterface for Store {
                     PaymentAttempt::convert_back(
                         state,
                         payment_intent,
-                        key_store.key.get_inner(),
+                        &key_store.get_key_ring(),
                         key_store.merchant_id.clone().into(),
                     )
                 }))
@@ -184,7 +191,7 @@ impl BatchSampleDataInterface for Store {
                     PaymentIntent::convert_back(
                         state,
                         payment_intent,
-                        key_store.key.get_inner(),
+                        &key_store.get_key_ring(),
                         key_store.merchant_id.clone().into(),
                     )
                 }))
@@ -211,7 +218,7 @@ impl BatchSampleDataInterface for Store {
                     PaymentAttempt::convert_back(
                         state,
                         res,
-                        key_store.key.get_inner(),
+                        &key_store.get_key_ring(),
                         key_store.merchant_id.clone().into(),
                     )
                 }))
@@ -253,72 +260,124 @@ impl BatchSampleDataInterface for storage_impl::MockDb {
     async fn insert_payment_intents_batch_for_sample_data(
         &self,
         _state: &KeyManagerState,
-        _batch: Vec<PaymentIntent>,
+        batch: Vec<PaymentIntent>,
         _key_store: &MerchantKeyStore,
     ) -> CustomResult<Vec<PaymentIntent>, StorageError> {
-        Err(StorageError::MockDbError)?
+        let mut payment_intents = self.payment_intents.lock().await;
+        payment_intents.extend(batch.iter().cloned());
+        Ok(batch)
     }
 
     #[cfg(feature = "v1")]
     async fn insert_payment_attempts_batch_for_sample_data(
         &self,
-        _batch: Vec<PaymentAttemptBatchNew>,
-        _state: &KeyManagerState,
-        _key_store: &MerchantKeyStore,
+        batch: Vec<PaymentAttemptBatchNew>,
+        state: &KeyManagerState,
+        key_store: &MerchantKeyStore,
     ) -> CustomResult<Vec<PaymentAttempt>, StorageError> {
-        Err(StorageError::MockDbError)?
+        let inserted = try_join_all(batch.into_iter().map(|payment_attempt| {
+            PaymentAttempt::convert_back(
+                state,
+                payment_attempt.into(),
+                &key_store.get_key_ring(),
+                key_store.merchant_id.clone().into(),
+            )
+        }))
+        .await
+        .change_context(StorageError::DecryptionError)?;
+        let mut payment_attempts = self.payment_attempts.lock().await;
+        payment_attempts.extend(inserted.iter().cloned());
+        Ok(inserted)
     }
 
     #[cfg(feature = "v1")]
     async fn insert_refunds_batch_for_sample_data(
         &self,
-        _batch: Vec<RefundNew>,
+        batch: Vec<RefundNew>,
     ) -> CustomResult<Vec<Refund>, StorageError> {
-        Err(StorageError::MockDbError)?
+        let mut inserted = Vec::with_capacity(batch.len());
+        for refund in batch {
+            inserted.push(
+                RefundInterface::insert_refund(self, refund, MerchantStorageScheme::PostgresOnly)
+                    .await?,
+            );
+        }
+        Ok(inserted)
     }
 
     #[cfg(feature = "v1")]
     async fn insert_disputes_batch_for_sample_data(
         &self,
-        _batch: Vec<DisputeNew>,
+        batch: Vec<DisputeNew>,
     ) -> CustomResult<Vec<Dispute>, StorageError> {
-        Err(StorageError::MockDbError)?
+        let mut inserted = Vec::with_capacity(batch.len());
+        for dispute in batch {
+            // The evidence column defaults to an empty object in the database
+            let dispute = DisputeNew {
+                evidence: dispute
+                    .evidence
+                    .or_else(|| Some(Secret::new(serde_json::json!({})))),
+                ..dispute
+            };
+            inserted.push(DisputeInterface::insert_dispute(self, dispute).await?);
+        }
+        Ok(inserted)
     }
 
     #[cfg(feature = "v1")]
     async fn delete_payment_intents_for_sample_data(
         &self,
         _state: &KeyManagerState,
-        _merchant_id: &common_utils::id_type::MerchantId,
+        merchant_id: &common_utils::id_type::MerchantId,
         _key_store: &MerchantKeyStore,
     ) -> CustomResult<Vec<PaymentIntent>, StorageError> {
-        Err(StorageError::MockDbError)?
+        let mut payment_intents = self.payment_intents.lock().await;
+        let (deleted, retained) = std::mem::take(&mut *payment_intents)
+            .into_iter()
+            .partition(|payment_intent| payment_intent.merchant_id == *merchant_id);
+        *payment_intents = retained;
+        Ok(deleted)
     }
 
     #[cfg(feature = "v1")]
     async fn delete_payment_attempts_for_sample_data(
         &self,
-        _merchant_id: &common_utils::id_type::MerchantId,
+        merchant_id: &common_utils::id_type::MerchantId,
         _state: &KeyManagerState,
         _key_store: &MerchantKeyStore,
     ) -> CustomResult<Vec<PaymentAttempt>, StorageError> {
-        Err(StorageError::MockDbError)?
+        let mut payment_attempts = self.payment_attempts.lock().await;
+        let (deleted, retained) = std::mem::take(&mut *payment_attempts)
+            .into_iter()
+            .partition(|payment_attempt| payment_attempt.merchant_id == *merchant_id);
+        *payment_attempts = retained;
+        Ok(deleted)
     }
 
     #[cfg(feature = "v1")]
     async fn delete_refunds_for_sample_data(
         &self,
-        _merchant_id: &common_utils::id_type::MerchantId,
+        merchant_id: &common_utils::id_type::MerchantId,
     ) -> CustomResult<Vec<Refund>, StorageError> {
-        Err(StorageError::MockDbError)?
+        let mut refunds = self.refunds.lock().await;
+        let (deleted, retained) = std::mem::take(&mut *refunds)
+            .into_iter()
+            .partition(|refund| refund.merchant_id == *merchant_id);
+        *refunds = retained;
+        Ok(deleted)
     }
 
     #[cfg(feature = "v1")]
     async fn delete_disputes_for_sample_data(
         &self,
-        _merchant_id: &common_utils::id_type::MerchantId,
+        merchant_id: &common_utils::id_type::MerchantId,
     ) -> CustomResult<Vec<Dispute>, StorageError> {
-        Err(StorageError::MockDbError)?
+        let mut disputes = self.disputes.lock().await;
+        let (deleted, retained) = std::mem::take(&mut *disputes)
+            .into_iter()
+            .partition(|dispute| dispute.merchant_id == *merchant_id);
+        *disputes = retained;
+        Ok(deleted)
     }
 }
 
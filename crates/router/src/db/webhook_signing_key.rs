@@ -0,0 +1,517 @@
+use async_bb8_diesel::AsyncConnection;
+use error_stack::report;
+use router_env::{instrument, tracing};
+#[cfg(feature = "accounts_cache")]
+use storage_impl::redis::cache::{self, CacheKind, ACCOUNTS_CACHE};
+use storage_impl::MockDb;
+
+use super::Store;
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+    db::kafka_store::KafkaStore,
+    types::storage,
+};
+
+#[async_trait::async_trait]
+pub trait WebhookSigningKeyInterface {
+    async fn insert_webhook_signing_key(
+        &self,
+        webhook_signing_key: storage::WebhookSigningKeyNew,
+    ) -> CustomResult<storage::WebhookSigningKey, errors::StorageError>;
+
+    async fn find_webhook_signing_key_by_profile_id_key_id(
+        &self,
+        profile_id: &common_utils::id_type::ProfileId,
+        key_id: &str,
+    ) -> CustomResult<storage::WebhookSigningKey, errors::StorageError>;
+
+    async fn list_webhook_signing_keys_by_profile_id(
+        &self,
+        profile_id: &common_utils::id_type::ProfileId,
+    ) -> CustomResult<Vec<storage::WebhookSigningKey>, errors::StorageError>;
+
+    async fn update_webhook_signing_key_by_profile_id_key_id(
+        &self,
+        profile_id: &common_utils::id_type::ProfileId,
+        key_id: &str,
+        webhook_signing_key_update: storage::WebhookSigningKeyUpdate,
+    ) -> CustomResult<storage::WebhookSigningKey, errors::StorageError>;
+
+    /// Inserts the new active signing key of the profile and applies the update to the previously
+    /// active signing keys of the profile, in a single transaction.
+    async fn rotate_webhook_signing_key(
+        &self,
+        new_webhook_signing_key: storage::WebhookSigningKeyNew,
+        previous_webhook_signing_key_update: storage::WebhookSigningKeyUpdate,
+    ) -> CustomResult<storage::WebhookSigningKey, errors::StorageError>;
+}
+
+/// The key under which the signing keys of the profile are cached
+#[cfg(feature = "accounts_cache")]
+fn get_webhook_signing_keys_cache_key(profile_id: &common_utils::id_type::ProfileId) -> String {
+    format!("webhook_signing_keys_{}", profile_id.get_string_repr())
+}
+
+#[async_trait::async_trait]
+impl WebhookSigningKeyInterface for Store {
+    #[instrument(skip_all)]
+    async fn insert_webhook_signing_key(
+        &self,
+        webhook_signing_key: storage::WebhookSigningKeyNew,
+    ) -> CustomResult<storage::WebhookSigningKey, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        #[cfg(feature = "accounts_cache")]
+        let cache_key = get_webhook_signing_keys_cache_key(&webhook_signing_key.profile_id);
+        let insert_call = || async {
+            webhook_signing_key
+                .insert(&conn)
+                .await
+                .map_err(|error| report!(errors::StorageError::from(error)))
+        };
+
+        #[cfg(not(feature = "accounts_cache"))]
+        {
+            insert_call().await
+        }
+
+        #[cfg(feature = "accounts_cache")]
+        {
+            cache::publish_and_redact(self, CacheKind::Accounts(cache_key.into()), insert_call)
+                .await
+        }
+    }
+
+    #[instrument(skip_all)]
+    async fn find_webhook_signing_key_by_profile_id_key_id(
+        &self,
+        profile_id: &common_utils::id_type::ProfileId,
+        key_id: &str,
+    ) -> CustomResult<storage::WebhookSigningKey, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::WebhookSigningKey::find_by_profile_id_key_id(&conn, profile_id, key_id)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn list_webhook_signing_keys_by_profile_id(
+        &self,
+        profile_id: &common_utils::id_type::ProfileId,
+    ) -> CustomResult<Vec<storage::WebhookSigningKey>, errors::StorageError> {
+        let list_call = || async {
+            let conn = connection::pg_connection_read(self).await?;
+            storage::WebhookSigningKey::list_by_profile_id(&conn, profile_id)
+                .await
+                .map_err(|error| report!(errors::StorageError::from(error)))
+        };
+
+        #[cfg(not(feature = "accounts_cache"))]
+        {
+            list_call().await
+        }
+
+        // The signing keys are looked up for every outgoing webhook, hence they are cached, and
+        // redacted whenever the keys of the profile are modified
+        #[cfg(feature = "accounts_cache")]
+        {
+            cache::get_or_populate_in_memory(
+                self,
+                &get_webhook_signing_keys_cache_key(profile_id),
+                list_call,
+                &ACCOUNTS_CACHE,
+            )
+            .await
+        }
+    }
+
+    #[instrument(skip_all)]
+    async fn update_webhook_signing_key_by_profile_id_key_id(
+        &self,
+        profile_id: &common_utils::id_type::ProfileId,
+        key_id: &str,
+        webhook_signing_key_update: storage::WebhookSigningKeyUpdate,
+    ) -> CustomResult<storage::WebhookSigningKey, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        let update_call = || async {
+            storage::WebhookSigningKey::update_by_profile_id_key_id(
+                &conn,
+                profile_id,
+                key_id,
+                webhook_signing_key_update,
+            )
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+        };
+
+        #[cfg(not(feature = "accounts_cache"))]
+        {
+            update_call().await
+        }
+
+        #[cfg(feature = "accounts_cache")]
+        {
+            cache::publish_and_redact(
+                self,
+                CacheKind::Accounts(get_webhook_signing_keys_cache_key(profile_id).into()),
+                update_call,
+            )
+            .await
+        }
+    }
+
+    #[instrument(skip_all)]
+    async fn rotate_webhook_signing_key(
+        &self,
+        new_webhook_signing_key: storage::WebhookSigningKeyNew,
+        previous_webhook_signing_key_update: storage::WebhookSigningKeyUpdate,
+    ) -> CustomResult<storage::WebhookSigningKey, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        #[cfg(feature = "accounts_cache")]
+        let cache_key = get_webhook_signing_keys_cache_key(&new_webhook_signing_key.profile_id);
+        let rotate_call = || async {
+            conn.transaction_async(|conn| async move {
+                storage::WebhookSigningKey::update_by_profile_id_status(
+                    &conn,
+                    &new_webhook_signing_key.profile_id,
+                    common_enums::WebhookSigningKeyStatus::Active,
+                    previous_webhook_signing_key_update,
+                )
+                .await
+                .map_err(errors::StorageError::from)?;
+
+                new_webhook_signing_key
+                    .insert(&conn)
+                    .await
+                    .map_err(errors::StorageError::from)
+            })
+            .await
+            .map_err(|error| report!(error))
+        };
+
+        #[cfg(not(feature = "accounts_cache"))]
+        {
+            rotate_call().await
+        }
+
+        #[cfg(feature = "accounts_cache")]
+        {
+            cache::publish_and_redact(self, CacheKind::Accounts(cache_key.into()), rotate_call)
+                .await
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl WebhookSigningKeyInterface for MockDb {
+    async fn insert_webhook_signing_key(
+        &self,
+        webhook_signing_key: storage::WebhookSigningKeyNew,
+    ) -> CustomResult<storage::WebhookSigningKey, errors::StorageError> {
+        let mut webhook_signing_keys = self.webhook_signing_keys.lock().await;
+        if webhook_signing_keys
+            .iter()
+            .any(|key| key.key_id == webhook_signing_key.key_id)
+        {
+            Err(errors::StorageError::DuplicateValue {
+                entity: "webhook_signing_key",
+                key: Some(webhook_signing_key.key_id.clone()),
+            })?
+        }
+
+        let webhook_signing_key = storage::WebhookSigningKey {
+            key_id: webhook_signing_key.key_id,
+            merchant_id: webhook_signing_key.merchant_id,
+            profile_id: webhook_signing_key.profile_id,
+            version: webhook_signing_key.version,
+            secret: webhook_signing_key.secret,
+            status: webhook_signing_key.status,
+            created_at: webhook_signing_key.created_at,
+            modified_at: webhook_signing_key.modified_at,
+            expires_at: webhook_signing_key.expires_at,
+        };
+        webhook_signing_keys.push(webhook_signing_key.clone());
+        Ok(webhook_signing_key)
+    }
+
+    async fn find_webhook_signing_key_by_profile_id_key_id(
+        &self,
+        profile_id: &common_utils::id_type::ProfileId,
+        key_id: &str,
+    ) -> CustomResult<storage::WebhookSigningKey, errors::StorageError> {
+        self.webhook_signing_keys
+            .lock()
+            .await
+            .iter()
+            .find(|key| key.profile_id == *profile_id && key.key_id == key_id)
+            .cloned()
+            .ok_or(
+                errors::StorageError::ValueNotFound(format!(
+                    "No webhook signing key available for key_id = {key_id}"
+                ))
+                .into(),
+            )
+    }
+
+    async fn list_webhook_signing_keys_by_profile_id(
+        &self,
+        profile_id: &common_utils::id_type::ProfileId,
+    ) -> CustomResult<Vec<storage::WebhookSigningKey>, errors::StorageError> {
+        let mut webhook_signing_keys = self
+            .webhook_signing_keys
+            .lock()
+            .await
+            .iter()
+            .filter(|key| key.profile_id == *profile_id)
+            .cloned()
+            .collect::<Vec<_>>();
+        webhook_signing_keys.sort_by(|a, b| b.version.cmp(&a.version));
+        Ok(webhook_signing_keys)
+    }
+
+    async fn update_webhook_signing_key_by_profile_id_key_id(
+        &self,
+        profile_id: &common_utils::id_type::ProfileId,
+        key_id: &str,
+        webhook_signing_key_update: storage::WebhookSigningKeyUpdate,
+    ) -> CustomResult<storage::WebhookSigningKey, errors::StorageError> {
+        let mut webhook_signing_keys = self.webhook_signing_keys.lock().await;
+        let webhook_signing_key = webhook_signing_keys
+            .iter_mut()
+            .find(|key| key.profile_id == *profile_id && key.key_id == key_id)
+            .ok_or(errors::StorageError::ValueNotFound(format!(
+                "No webhook signing key available for key_id = {key_id}"
+            )))?;
+        *webhook_signing_key =
+            webhook_signing_key_update.apply_changeset(webhook_signing_key.clone());
+        Ok(webhook_signing_key.clone())
+    }
+
+    async fn rotate_webhook_signing_key(
+        &self,
+        new_webhook_signing_key: storage::WebhookSigningKeyNew,
+        previous_webhook_signing_key_update: storage::WebhookSigningKeyUpdate,
+    ) -> CustomResult<storage::WebhookSigningKey, errors::StorageError> {
+        let mut webhook_signing_keys = self.webhook_signing_keys.lock().await;
+        if webhook_signing_keys.iter().any(|key| {
+            key.key_id == new_webhook_signing_key.key_id
+                || (key.profile_id == new_webhook_signing_key.profile_id
+                    && key.version == new_webhook_signing_key.version)
+        }) {
+            Err(errors::StorageError::DuplicateValue {
+                entity: "webhook_signing_key",
+                key: Some(new_webhook_signing_key.key_id.clone()),
+            })?
+        }
+
+        for webhook_signing_key in webhook_signing_keys.iter_mut().filter(|key| {
+            key.profile_id == new_webhook_signing_key.profile_id
+                && key.status == common_enums::WebhookSigningKeyStatus::Active
+        }) {
+            *webhook_signing_key = previous_webhook_signing_key_update
+                .clone()
+                .apply_changeset(webhook_signing_key.clone());
+        }
+
+        let webhook_signing_key = storage::WebhookSigningKey {
+            key_id: new_webhook_signing_key.key_id,
+            merchant_id: new_webhook_signing_key.merchant_id,
+            profile_id: new_webhook_signing_key.profile_id,
+            version: new_webhook_signing_key.version,
+            secret: new_webhook_signing_key.secret,
+            status: new_webhook_signing_key.status,
+            created_at: new_webhook_signing_key.created_at,
+            modified_at: new_webhook_signing_key.modified_at,
+            expires_at: new_webhook_signing_key.expires_at,
+        };
+        webhook_signing_keys.push(webhook_signing_key.clone());
+        Ok(webhook_signing_key)
+    }
+}
+
+#[async_trait::async_trait]
+impl WebhookSigningKeyInterface for KafkaStore {
+    #[instrument(skip_all)]
+    async fn insert_webhook_signing_key(
+        &self,
+        webhook_signing_key: storage::WebhookSigningKeyNew,
+    ) -> CustomResult<storage::WebhookSigningKey, errors::StorageError> {
+        self.diesel_store
+            .insert_webhook_signing_key(webhook_signing_key)
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn find_webhook_signing_key_by_profile_id_key_id(
+        &self,
+        profile_id: &common_utils::id_type::ProfileId,
+        key_id: &str,
+    ) -> CustomResult<storage::WebhookSigningKey, errors::StorageError> {
+        self.diesel_store
+            .find_webhook_signing_key_by_profile_id_key_id(profile_id, key_id)
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn list_webhook_signing_keys_by_profile_id(
+        &self,
+        profile_id: &common_utils::id_type::ProfileId,
+    ) -> CustomResult<Vec<storage::WebhookSigningKey>, errors::StorageError> {
+        self.diesel_store
+            .list_webhook_signing_keys_by_profile_id(profile_id)
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn update_webhook_signing_key_by_profile_id_key_id(
+        &self,
+        profile_id: &common_utils::id_type::ProfileId,
+        key_id: &str,
+        webhook_signing_key_update: storage::WebhookSigningKeyUpdate,
+    ) -> CustomResult<storage::WebhookSigningKey, errors::StorageError> {
+        self.diesel_store
+            .update_webhook_signing_key_by_profile_id_key_id(
+                profile_id,
+                key_id,
+                webhook_signing_key_update,
+            )
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn rotate_webhook_signing_key(
+        &self,
+        new_webhook_signing_key: storage::WebhookSigningKeyNew,
+        previous_webhook_signing_key_update: storage::WebhookSigningKeyUpdate,
+    ) -> CustomResult<storage::WebhookSigningKey, errors::StorageError> {
+        self.diesel_store
+            .rotate_webhook_signing_key(
+                new_webhook_signing_key,
+                previous_webhook_signing_key_update,
+            )
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::expect_used, clippy::unwrap_used)]
+
+    use std::borrow::Cow;
+
+    use common_utils::{encryption::Encryption, types::keymanager::KeyManagerState};
+    use masking::Secret;
+    use time::macros::datetime;
+
+    use crate::{
+        db::{webhook_signing_key::WebhookSigningKeyInterface, MockDb},
+        types::storage,
+    };
+
+    fn get_new_signing_key(
+        profile_id: &common_utils::id_type::ProfileId,
+        key_id: &str,
+        version: i32,
+    ) -> storage::WebhookSigningKeyNew {
+        let now = datetime!(2024-01-01 00:00);
+        storage::WebhookSigningKeyNew {
+            key_id: key_id.to_string(),
+            merchant_id: common_utils::id_type::MerchantId::try_from(Cow::from("merchant1"))
+                .unwrap(),
+            profile_id: profile_id.clone(),
+            version,
+            secret: Encryption::new(Secret::new(key_id.as_bytes().to_vec())),
+            status: common_enums::WebhookSigningKeyStatus::Active,
+            created_at: now,
+            modified_at: now,
+            expires_at: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_mockdb_rotate_webhook_signing_key() {
+        let mockdb = MockDb::new(
+            &redis_interface::RedisSettings::default(),
+            KeyManagerState::mock(),
+        )
+        .await
+        .expect("Failed to create Mock store");
+
+        let profile_id = common_utils::id_type::ProfileId::try_from(Cow::from("profile1")).unwrap();
+        let other_profile_id =
+            common_utils::id_type::ProfileId::try_from(Cow::from("profile2")).unwrap();
+        let expires_at = datetime!(2024-01-02 00:00);
+        let previous_key_update = storage::WebhookSigningKeyUpdate::StatusUpdate {
+            status: common_enums::WebhookSigningKeyStatus::Expiring,
+            expires_at: Some(expires_at),
+        };
+
+        mockdb
+            .insert_webhook_signing_key(get_new_signing_key(&profile_id, "key1", 1))
+            .await
+            .unwrap();
+        mockdb
+            .insert_webhook_signing_key(get_new_signing_key(&other_profile_id, "key2", 1))
+            .await
+            .unwrap();
+
+        let new_key = mockdb
+            .rotate_webhook_signing_key(
+                get_new_signing_key(&profile_id, "key3", 2),
+                previous_key_update.clone(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(
+            new_key.status,
+            common_enums::WebhookSigningKeyStatus::Active
+        );
+
+        let signing_keys = mockdb
+            .list_webhook_signing_keys_by_profile_id(&profile_id)
+            .await
+            .unwrap();
+        let statuses = signing_keys
+            .iter()
+            .map(|key| (key.key_id.as_str(), key.status, key.expires_at))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            statuses,
+            vec![
+                ("key3", common_enums::WebhookSigningKeyStatus::Active, None),
+                (
+                    "key1",
+                    common_enums::WebhookSigningKeyStatus::Expiring,
+                    Some(expires_at)
+                ),
+            ]
+        );
+
+        // The keys of other profiles are left untouched
+        let other_key = mockdb
+            .find_webhook_signing_key_by_profile_id_key_id(&other_profile_id, "key2")
+            .await
+            .unwrap();
+        assert_eq!(
+            other_key.status,
+            common_enums::WebhookSigningKeyStatus::Active
+        );
+
+        // A concurrent rotation computing the same version fails without demoting the active key
+        let duplicate_rotation = mockdb
+            .rotate_webhook_signing_key(
+                get_new_signing_key(&profile_id, "key4", 2),
+                previous_key_update,
+            )
+            .await;
+        assert!(duplicate_rotation.is_err());
+        let active_key = mockdb
+            .find_webhook_signing_key_by_profile_id_key_id(&profile_id, "key3")
+            .await
+            .unwrap();
+        assert_eq!(
+            active_key.status,
+            common_enums::WebhookSigningKeyStatus::Active
+        );
+    }
+}
@@ -17,6 +17,7 @@ use crate::{
 
 pub mod api_logs;
 pub mod audit_events;
+pub mod card_testing_guard_events;
 pub mod connector_api_logs;
 pub mod event_logger;
 pub mod outgoing_webhook_logs;
@@ -39,6 +40,7 @@ pub enum EventType {
     Authentication,
     RoutingApiLogs,
     RevenueRecovery,
+    CardTestingGuard,
 }
 
 #[derive(Debug, Default, Deserialize, Clone)]
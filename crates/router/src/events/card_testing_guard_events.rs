@@ -0,0 +1,38 @@
+use common_utils::id_type;
+use serde::Serialize;
+use time::PrimitiveDateTime;
+
+use super::EventType;
+use crate::services::kafka::KafkaMessage;
+
+/// Event emitted when the number of card payment attempts grouped by an attribute of the payment
+/// exceeds the limit of a velocity rule of the card testing guard.
+#[derive(Debug, Clone, Serialize)]
+pub struct VelocityRuleHitEvent {
+    pub merchant_id: id_type::MerchantId,
+    pub profile_id: id_type::ProfileId,
+    pub payment_id: id_type::PaymentId,
+    pub rule_name: String,
+    pub group_by: common_enums::VelocityRuleGroupBy,
+    pub action: common_enums::VelocityRuleAction,
+    pub attempt_count: u64,
+    pub max_attempts: u32,
+    pub window_in_mins: u32,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+}
+
+impl KafkaMessage for VelocityRuleHitEvent {
+    fn event_type(&self) -> EventType {
+        EventType::CardTestingGuard
+    }
+
+    fn key(&self) -> String {
+        format!(
+            "{}-{}-{}",
+            self.merchant_id.get_string_repr(),
+            self.profile_id.get_string_repr(),
+            self.payment_id.get_string_repr()
+        )
+    }
+}
@@ -2,6 +2,8 @@ use actix_web::{web, HttpRequest, HttpResponse};
 use router_env::{instrument, tracing, Flow};
 
 use super::app::AppState;
+#[cfg(feature = "v1")]
+use crate::core::key_rotation;
 use crate::{
     core::{admin::*, api_locking, errors, merchant_connector_webhook_management::*},
     services::{api, authentication as auth, authorization::permissions::Permission},
@@ -1114,6 +1116,99 @@ pub async fn merchant_account_transfer_keys(
     .await
 }
 
+/// Merchant Account - Rotate Key
+///
+/// Rotate the data encryption key of the Merchant Account and re-encrypt its data in the background
+#[cfg(feature = "v1")]
+#[instrument(skip_all, fields(flow = ?Flow::MerchantKeyRotate))]
+pub async fn merchant_key_rotate(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<common_utils::id_type::MerchantId>,
+) -> HttpResponse {
+    let flow = Flow::MerchantKeyRotate;
+    let merchant_id = path.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        merchant_id,
+        |state, _, merchant_id, _| key_rotation::rotate_merchant_key(state, merchant_id),
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Merchant Account - Key Rotation Status
+///
+/// Retrieve the progress of the latest key rotation of the Merchant Account
+#[cfg(feature = "v1")]
+#[instrument(skip_all, fields(flow = ?Flow::KeyRotationRetrieve))]
+pub async fn merchant_key_rotation_retrieve(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<common_utils::id_type::MerchantId>,
+) -> HttpResponse {
+    let flow = Flow::KeyRotationRetrieve;
+    let merchant_id = path.into_inner();
+
+    api::server_wrap(
+        flow,
+        state,
+        &req,
+        merchant_id,
+        |state, _, merchant_id, _| key_rotation::retrieve_merchant_key_rotation(state, merchant_id),
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    )
+    .await
+}
+
+/// Master Key - Rotate
+///
+/// Re-encrypt the keys of all Merchant Accounts with the current master key in the background
+#[cfg(feature = "v1")]
+#[instrument(skip_all, fields(flow = ?Flow::MasterKeyRotate))]
+pub async fn master_key_rotate(state: web::Data<AppState>, req: HttpRequest) -> HttpResponse {
+    let flow = Flow::MasterKeyRotate;
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        (),
+        |state, _, _, _| key_rotation::rotate_master_key(state),
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Master Key - Rotation Status
+///
+/// Retrieve the progress of the rotation of the current master key
+#[cfg(feature = "v1")]
+#[instrument(skip_all, fields(flow = ?Flow::KeyRotationRetrieve))]
+pub async fn master_key_rotation_retrieve(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+) -> HttpResponse {
+    let flow = Flow::KeyRotationRetrieve;
+
+    api::server_wrap(
+        flow,
+        state,
+        &req,
+        (),
+        |state, _, _, _| key_rotation::retrieve_master_key_rotation(state),
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    )
+    .await
+}
+
 /// Configure Connector Webhook - Register
 ///
 /// To setup webhook configuration for an existing Merchant at the connector.
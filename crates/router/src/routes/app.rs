@@ -15,6 +15,7 @@ use common_utils::{
 #[cfg(feature = "email")]
 use external_services::email::{
     no_email::NoEmailClient, ses::AwsSes, smtp::SmtpServer, EmailClientConfigs, EmailService,
+    EmailSettings,
 };
 #[cfg(all(feature = "revenue_recovery", feature = "v2"))]
 use external_services::grpc_client::revenue_recovery::GrpcRecoveryHeaders;
@@ -130,6 +131,9 @@ pub struct SessionState {
     pub event_handler: EventsHandler,
     #[cfg(feature = "email")]
     pub email_client: Arc<Box<dyn EmailService>>,
+    /// Client for emails to the customers of merchants
+    #[cfg(feature = "email")]
+    pub customer_email_client: Arc<Box<dyn EmailService>>,
     #[cfg(feature = "olap")]
     pub pool: AnalyticsProvider,
     pub file_storage_client: Arc<dyn FileStorageInterface>,
@@ -145,7 +149,79 @@ pub struct SessionState {
     pub infra_components: Option<serde_json::Value>,
     pub enhancement: Option<HashMap<String, String>>,
     pub superposition_service: Option<Arc<SuperpositionClient>>,
+    /// Attributes of the API request being served, if any
+    pub request_metadata: Option<RequestMetadata>,
+}
+
+/// Attributes of an API request, which are used to authorize requests made with restricted API
+/// keys
+#[derive(Clone, Debug)]
+pub struct RequestMetadata {
+    pub method: actix_web::http::Method,
+    pub path: String,
+    pub client_ip: Option<std::net::IpAddr>,
+}
+
+impl RequestMetadata {
+    pub fn new(
+        request: &actix_web::HttpRequest,
+        trusted_proxies: &std::collections::HashSet<ipnet::IpNet>,
+    ) -> Self {
+        let client_ip = request.peer_addr().map(|peer_address| {
+            get_client_ip(
+                peer_address.ip(),
+                request
+                    .headers()
+                    .get_all(crate::headers::X_FORWARDED_FOR)
+                    .filter_map(|value| value.to_str().ok()),
+                trusted_proxies,
+            )
+        });
+
+        Self {
+            method: request.method().clone(),
+            path: request.path().to_owned(),
+            client_ip,
+        }
+    }
+}
+
+/// Resolves the address of the client from the address of the peer the request is received from.
+/// The `X-Forwarded-For` header can be set by the client, and is hence honoured only when the peer
+/// is a trusted proxy, in which case the client is the last address in the chain of proxies which
+/// is not a trusted proxy.
+pub fn get_client_ip<'a>(
+    peer_ip: std::net::IpAddr,
+    forwarded_for: impl Iterator<Item = &'a str>,
+    trusted_proxies: &std::collections::HashSet<ipnet::IpNet>,
+) -> std::net::IpAddr {
+    let is_trusted_proxy =
+        |ip: &std::net::IpAddr| trusted_proxies.iter().any(|proxy| proxy.contains(ip));
+
+    if !is_trusted_proxy(&peer_ip) {
+        return peer_ip;
+    }
+
+    let forwarded_ips = forwarded_for
+        .flat_map(|value| value.split(','))
+        .map(str::trim)
+        .collect::<Vec<_>>();
+
+    let mut client_ip = peer_ip;
+    for forwarded_ip in forwarded_ips.into_iter().rev() {
+        // An address which cannot be parsed cannot be trusted, neither can the ones before it
+        let Ok(forwarded_ip) = forwarded_ip.parse::<std::net::IpAddr>() else {
+            break;
+        };
+        client_ip = forwarded_ip;
+        if !is_trusted_proxy(&client_ip) {
+            break;
+        }
+    }
+
+    client_ip
 }
+
 impl scheduler::SchedulerSessionState for SessionState {
     fn get_db(&self) -> Box<dyn SchedulerInterface> {
         self.store.get_scheduler_db()
@@ -220,6 +296,7 @@ pub trait SessionStateInfo {
     fn event_handler(&self) -> EventsHandler;
     fn get_request_id(&self) -> Option<String>;
     fn add_request_id(&mut self, request_id: RequestId);
+    fn get_request_metadata(&self) -> Option<&RequestMetadata>;
     #[cfg(feature = "partial-auth")]
     fn get_detached_auth(&self) -> RouterResult<(Blake3, &[u8])>;
     fn session_state(&self) -> SessionState;
@@ -244,6 +321,9 @@ impl SessionStateInfo for SessionState {
         self.store.add_request_id(request_id.to_string());
         self.request_id.replace(request_id);
     }
+    fn get_request_metadata(&self) -> Option<&RequestMetadata> {
+        self.request_metadata.as_ref()
+    }
 
     #[cfg(feature = "partial-auth")]
     fn get_detached_auth(&self) -> RouterResult<(Blake3, &[u8])> {
@@ -325,6 +405,9 @@ pub struct AppState {
     pub event_handler: EventsHandler,
     #[cfg(feature = "email")]
     pub email_client: Arc<Box<dyn EmailService>>,
+    /// Client for emails to the customers of merchants
+    #[cfg(feature = "email")]
+    pub customer_email_client: Arc<Box<dyn EmailService>>,
     pub api_client: Box<dyn crate::services::ApiClient>,
     #[cfg(feature = "olap")]
     pub pools: HashMap<id_type::TenantId, AnalyticsProvider>,
@@ -393,19 +476,15 @@ impl AsRef<Self> for AppState {
 
 #[cfg(feature = "email")]
 pub async fn create_email_client(
-    settings: &settings::Settings<RawSecret>,
+    email_settings: &EmailSettings,
+    https_proxy_url: Option<String>,
 ) -> Box<dyn EmailService> {
-    match &settings.email.client_config {
-        EmailClientConfigs::Ses { aws_ses } => Box::new(
-            AwsSes::create(
-                &settings.email,
-                aws_ses,
-                settings.proxy.https_url.to_owned(),
-            )
-            .await,
-        ),
+    match &email_settings.client_config {
+        EmailClientConfigs::Ses { aws_ses } => {
+            Box::new(AwsSes::create(email_settings, aws_ses, https_proxy_url).await)
+        }
         EmailClientConfigs::Smtp { smtp } => {
-            Box::new(SmtpServer::create(&settings.email, smtp.clone()).await)
+            Box::new(SmtpServer::create(email_settings, smtp.clone()).await)
         }
         EmailClientConfigs::NoEmailClient => Box::new(NoEmailClient::create().await),
     }
@@ -480,24 +559,70 @@ impl AppState {
                 .tenants
                 .get_pools_map(conf.analytics.get_inner())
                 .await;
-            let stores = conf
-                .multitenancy
-                .tenants
-                .get_store_interface_map(&storage_impl, &conf, Arc::clone(&cache_store), testable)
-                .await;
-            let accounts_store = conf
-                .multitenancy
-                .tenants
-                .get_accounts_store_interface_map(
-                    &storage_impl,
-                    &conf,
-                    Arc::clone(&cache_store),
-                    testable,
-                )
-                .await;
+            let (stores, accounts_store) = match storage_impl {
+                // Every mock store keeps its own tables, so the tenant and accounts stores have
+                // to be views over the same instance for data written by one to be seen by the other
+                StorageImpl::Mock => {
+                    let common_stores = conf
+                        .multitenancy
+                        .tenants
+                        .get_common_store_interface_map(
+                            &storage_impl,
+                            &conf,
+                            Arc::clone(&cache_store),
+                            testable,
+                        )
+                        .await;
+                    let stores = common_stores
+                        .iter()
+                        .map(|(tenant_id, store)| (tenant_id.clone(), store.get_storage_interface()))
+                        .collect::<HashMap<_, _>>();
+                    let accounts_store = common_stores
+                        .iter()
+                        .map(|(tenant_id, store)| {
+                            (tenant_id.clone(), store.get_accounts_storage_interface())
+                        })
+                        .collect::<HashMap<_, _>>();
+                    (stores, accounts_store)
+                }
+                StorageImpl::Postgresql | StorageImpl::PostgresqlTest => {
+                    let stores = conf
+                        .multitenancy
+                        .tenants
+                        .get_store_interface_map(
+                            &storage_impl,
+                            &conf,
+                            Arc::clone(&cache_store),
+                            testable,
+                        )
+                        .await;
+                    let accounts_store = conf
+                        .multitenancy
+                        .tenants
+                        .get_accounts_store_interface_map(
+                            &storage_impl,
+                            &conf,
+                            Arc::clone(&cache_store),
+                            testable,
+                        )
+                        .await;
+                    (stores, accounts_store)
+                }
+            };
 
             #[cfg(feature = "email")]
-            let email_client = Arc::new(create_email_client(&conf).await);
+            let email_client = Arc::new(
+                create_email_client(&conf.email, conf.proxy.https_url.to_owned()).await,
+            );
+            // Emails to customers are sent through the dashboard email provider, unless a separate
+            // provider is configured for them
+            #[cfg(feature = "email")]
+            let customer_email_client = match &conf.customer_email.provider {
+                Some(provider) => Arc::new(
+                    create_email_client(provider, conf.proxy.https_url.to_owned()).await,
+                ),
+                None => Arc::clone(&email_client),
+            };
 
             let file_storage_client = conf.file_storage.get_file_storage_client().await;
             let theme_storage_client = conf.theme.storage.get_file_storage_client().await;
@@ -531,6 +656,8 @@ impl AppState {
                 conf: Arc::new(conf),
                 #[cfg(feature = "email")]
                 email_client,
+                #[cfg(feature = "email")]
+                customer_email_client,
                 api_client,
                 event_handler,
                 #[cfg(feature = "olap")]
@@ -625,9 +752,14 @@ impl AppState {
         shut_down_signal: oneshot::Sender<()>,
         api_client: Box<dyn crate::services::ApiClient>,
     ) -> Self {
+        let storage_impl = if conf.storage.in_memory {
+            StorageImpl::Mock
+        } else {
+            StorageImpl::Postgresql
+        };
         Box::pin(Self::with_storage(
             conf,
-            StorageImpl::Postgresql,
+            storage_impl,
             shut_down_signal,
             api_client,
         ))
@@ -664,6 +796,8 @@ impl AppState {
             tenant: tenant_conf.clone(),
             #[cfg(feature = "email")]
             email_client: Arc::clone(&self.email_client),
+            #[cfg(feature = "email")]
+            customer_email_client: Arc::clone(&self.customer_email_client),
             #[cfg(feature = "olap")]
             opensearch_client: self.opensearch_client.clone(),
             grpc_client: Arc::clone(&self.grpc_client),
@@ -673,6 +807,7 @@ impl AppState {
             infra_components: self.infra_components.clone(),
             enhancement: self.enhancement.clone(),
             superposition_service: self.superposition_service.clone(),
+            request_metadata: None,
         })
     }
 
@@ -1072,6 +1207,12 @@ impl Forex {
             .service(
                 web::resource("/convert_from_minor").route(web::get().to(currency::convert_forex)),
             )
+            .service(
+                web::resource("/rate_table")
+                    .route(web::post().to(currency::upsert_forex_rate_table))
+                    .route(web::get().to(currency::retrieve_forex_rate_table))
+                    .route(web::delete().to(currency::delete_forex_rate_table)),
+            )
     }
 }
 
@@ -1306,6 +1447,10 @@ impl Routing {
                     },
                 )),
             )
+            .service(
+                web::resource("/{algorithm_id}/simulate")
+                    .route(web::post().to(routing::routing_simulate_config)),
+            )
             .service(
                 web::resource("/rule/evaluate")
                     .route(web::post().to(routing::evaluate_routing_rule)),
@@ -1877,6 +2022,12 @@ impl Blocklist {
             .service(
                 web::resource("/toggle").route(web::post().to(blocklist::toggle_blocklist_guard)),
             )
+            .service(
+                web::resource("/import").route(web::post().to(blocklist::import_blocklist_entries)),
+            )
+            .service(
+                web::resource("/export").route(web::get().to(blocklist::export_blocklist_entries)),
+            )
     }
 }
 
@@ -1963,6 +2114,16 @@ impl MerchantAccount {
                     .route(web::post().to(admin::merchant_account_toggle_kv))
                     .route(web::get().to(admin::merchant_account_kv_status)),
             )
+            .service(
+                web::resource("/{id}/key_rotation")
+                    .route(web::post().to(admin::merchant_key_rotate))
+                    .route(web::get().to(admin::merchant_key_rotation_retrieve)),
+            )
+            .service(
+                web::resource("/key_rotation")
+                    .route(web::post().to(admin::master_key_rotate))
+                    .route(web::get().to(admin::master_key_rotation_retrieve)),
+            )
             .service(
                 web::resource("/transfer")
                     .route(web::post().to(admin::merchant_account_transfer_keys)),
@@ -2557,6 +2718,21 @@ impl Profile {
                 .service(
                     web::resource("/toggle_connector_agnostic_mit")
                         .route(web::post().to(profiles::toggle_connector_agnostic_mit)),
+                )
+                .service(
+                    web::scope("/webhook_signing_keys")
+                        .service(
+                            web::resource("")
+                                .route(web::get().to(profiles::webhook_signing_key_list)),
+                        )
+                        .service(
+                            web::resource("/rotate")
+                                .route(web::post().to(profiles::webhook_signing_key_rotate)),
+                        )
+                        .service(
+                            web::resource("/{key_id}/retire")
+                                .route(web::post().to(profiles::webhook_signing_key_retire)),
+                        ),
                 ),
         );
 
@@ -2596,6 +2772,11 @@ impl Gsm {
             .service(web::resource("/get").route(web::post().to(gsm::get_gsm_rule)))
             .service(web::resource("/update").route(web::post().to(gsm::update_gsm_rule)))
             .service(web::resource("/delete").route(web::post().to(gsm::delete_gsm_rule)))
+            .service(web::resource("/import").route(web::post().to(gsm::import_gsm_rules)))
+            .service(
+                web::resource("/import/json").route(web::post().to(gsm::import_gsm_rules_json)),
+            )
+            .service(web::resource("/export").route(web::get().to(gsm::export_gsm_rules)))
     }
 }
 
@@ -2608,6 +2789,11 @@ impl Gsm {
             .service(web::resource("/get").route(web::post().to(gsm::get_gsm_rule)))
             .service(web::resource("/update").route(web::post().to(gsm::update_gsm_rule)))
             .service(web::resource("/delete").route(web::post().to(gsm::delete_gsm_rule)))
+            .service(web::resource("/import").route(web::post().to(gsm::import_gsm_rules)))
+            .service(
+                web::resource("/import/json").route(web::post().to(gsm::import_gsm_rules_json)),
+            )
+            .service(web::resource("/export").route(web::get().to(gsm::export_gsm_rules)))
     }
 }
 pub struct Chat;
@@ -3334,3 +3520,64 @@ impl RecoveryDataBackfill {
             ))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use std::{collections::HashSet, net::IpAddr};
+
+    use super::get_client_ip;
+
+    fn ip(address: &str) -> IpAddr {
+        address.parse().unwrap()
+    }
+
+    fn trusted_proxies() -> HashSet<ipnet::IpNet> {
+        HashSet::from_iter(["10.0.0.0/8".parse().unwrap()])
+    }
+
+    #[test]
+    fn test_forwarded_for_ignored_from_untrusted_peer() {
+        assert_eq!(
+            get_client_ip(
+                ip("203.0.113.5"),
+                ["198.51.100.1"].into_iter(),
+                &trusted_proxies()
+            ),
+            ip("203.0.113.5")
+        );
+        assert_eq!(
+            get_client_ip(
+                ip("10.0.0.1"),
+                ["198.51.100.1"].into_iter(),
+                &HashSet::new()
+            ),
+            ip("10.0.0.1")
+        );
+    }
+
+    #[test]
+    fn test_client_is_last_untrusted_address_forwarded_by_trusted_proxies() {
+        // The first address can be set by the client, and is not trusted
+        assert_eq!(
+            get_client_ip(
+                ip("10.0.0.1"),
+                ["192.0.2.1, 198.51.100.1", "10.0.0.2"].into_iter(),
+                &trusted_proxies()
+            ),
+            ip("198.51.100.1")
+        );
+        assert_eq!(
+            get_client_ip(ip("10.0.0.1"), std::iter::empty(), &trusted_proxies()),
+            ip("10.0.0.1")
+        );
+        assert_eq!(
+            get_client_ip(
+                ip("10.0.0.1"),
+                ["198.51.100.1, not-an-ip, 10.0.0.2"].into_iter(),
+                &trusted_proxies()
+            ),
+            ip("10.0.0.2")
+        );
+    }
+}
@@ -1,3 +1,4 @@
+use actix_multipart::form::MultipartForm;
 use actix_web::{web, HttpRequest, HttpResponse};
 use api_models::blocklist as api_blocklist;
 use error_stack::report;
@@ -12,7 +13,7 @@ use crate::{
 #[utoipa::path(
     post,
     path = "/blocklist",
-    request_body = BlocklistRequest,
+    request_body = AddToBlocklistRequest,
     responses(
         (status = 200, description = "Fingerprint Blocked", body = BlocklistResponse),
         (status = 400, description = "Invalid Data")
@@ -198,3 +199,95 @@ pub async fn toggle_blocklist_guard(
     ))
     .await
 }
+
+#[utoipa::path(
+    post,
+    path = "/blocklist/import",
+    request_body(content = String, content_type = "multipart/form-data", description = "A CSV file with the `data_kind`, `data` and optional `ttl_in_secs` or `expires_at` columns"),
+    responses(
+        (status = 200, description = "Blocklist entries imported"),
+        (status = 400, description = "Invalid Data")
+    ),
+    tag = "Blocklist",
+    operation_id = "Import blocklist entries from a CSV file",
+    security(("api_key" = []))
+)]
+pub async fn import_blocklist_entries(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    MultipartForm(form): MultipartForm<blocklist::BlocklistImportForm>,
+) -> HttpResponse {
+    let flow = Flow::ImportBlocklist;
+    let records = match blocklist::get_blocklist_records(form) {
+        Ok(records) => records,
+        Err(e) => return api::log_and_return_error_response(e.into()),
+    };
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        records,
+        |state, auth: auth::AuthenticationData, records, _| {
+            blocklist::import_blocklist_entries(state, auth.platform, records)
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth {
+                allow_connected_scope_operation: false,
+                allow_platform_self_operation: false,
+            }),
+            &auth::JWTAuth {
+                permission: Permission::MerchantAccountWrite,
+                allow_connected: false,
+                allow_platform: false,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[utoipa::path(
+    get,
+    path = "/blocklist/export",
+    params (
+        ("data_kind" = Option<BlocklistDataKind>, Query, description = "Kind of the entries to be exported"),
+    ),
+    responses(
+        (status = 200, description = "Blocklist entries exported as a CSV file", content_type = "text/csv", body = String),
+        (status = 400, description = "Invalid Data")
+    ),
+    tag = "Blocklist",
+    operation_id = "Export blocklist entries as a CSV file",
+    security(("api_key" = []))
+)]
+pub async fn export_blocklist_entries(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    query_payload: web::Query<api_blocklist::ExportBlocklistQuery>,
+) -> HttpResponse {
+    let flow = Flow::ExportBlocklist;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        query_payload.into_inner(),
+        |state, auth: auth::AuthenticationData, query, _| {
+            blocklist::export_blocklist_entries(state, auth.platform, query)
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth {
+                allow_connected_scope_operation: false,
+                allow_platform_self_operation: false,
+            }),
+            &auth::JWTAuth {
+                permission: Permission::MerchantAccountRead,
+                allow_connected: false,
+                allow_platform: false,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
@@ -4,18 +4,28 @@ use router_env::Flow;
 use crate::{
     core::{api_locking, currency},
     routes::AppState,
-    services::{api, authentication as auth},
+    services::{api, authentication as auth, authorization::permissions::Permission},
 };
 
 #[cfg(feature = "v1")]
-pub async fn retrieve_forex(state: web::Data<AppState>, req: HttpRequest) -> HttpResponse {
+pub async fn retrieve_forex(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    params: web::Query<api_models::currency::ForexRatesParams>,
+) -> HttpResponse {
     let flow = Flow::RetrieveForexFlow;
     Box::pin(api::server_wrap(
         flow,
         state,
         &req,
-        (),
-        |state, _auth: auth::AuthenticationData, _, _| currency::retrieve_forex(state),
+        params.into_inner(),
+        |state, auth: auth::AuthenticationData, params, _| {
+            currency::retrieve_forex(
+                state,
+                auth.platform.get_processor().get_account().get_id().clone(),
+                params.provider,
+            )
+        },
         auth::auth_type(
             &auth::HeaderAuth(auth::ApiKeyAuth {
                 allow_connected_scope_operation: false,
@@ -42,17 +52,22 @@ pub async fn convert_forex(
     let amount = params.amount;
     let to_currency = &params.to_currency;
     let from_currency = &params.from_currency;
+    let provider = params.provider;
+    let as_of = params.as_of;
     Box::pin(api::server_wrap(
         flow,
         state.clone(),
         &req,
         (),
-        |state, _: auth::AuthenticationData, _, _| {
+        |state, auth: auth::AuthenticationData, _, _| {
             currency::convert_forex(
                 state,
+                auth.platform.get_processor().get_account().get_id().clone(),
                 amount.get_amount_as_i64(),
                 to_currency.to_string(),
                 from_currency.to_string(),
+                provider,
+                as_of,
             )
         },
         auth::auth_type(
@@ -70,3 +85,104 @@ pub async fn convert_forex(
     ))
     .await
 }
+
+#[cfg(feature = "v1")]
+pub async fn upsert_forex_rate_table(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<api_models::currency::ForexRateTableRequest>,
+) -> HttpResponse {
+    let flow = Flow::ForexRateTableUpsert;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, auth: auth::AuthenticationData, request, _| {
+            currency::upsert_forex_rate_table(
+                state,
+                auth.platform.get_processor().get_account().get_id().clone(),
+                request,
+            )
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth {
+                allow_connected_scope_operation: false,
+                allow_platform_self_operation: false,
+            }),
+            &auth::JWTAuth {
+                permission: Permission::MerchantAccountWrite,
+                allow_connected: false,
+                allow_platform: false,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[cfg(feature = "v1")]
+pub async fn retrieve_forex_rate_table(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+) -> HttpResponse {
+    let flow = Flow::ForexRateTableRetrieve;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        (),
+        |state, auth: auth::AuthenticationData, _, _| {
+            currency::retrieve_forex_rate_table(
+                state,
+                auth.platform.get_processor().get_account().get_id().clone(),
+            )
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth {
+                allow_connected_scope_operation: false,
+                allow_platform_self_operation: false,
+            }),
+            &auth::JWTAuth {
+                permission: Permission::MerchantAccountRead,
+                allow_connected: false,
+                allow_platform: false,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[cfg(feature = "v1")]
+pub async fn delete_forex_rate_table(state: web::Data<AppState>, req: HttpRequest) -> HttpResponse {
+    let flow = Flow::ForexRateTableDelete;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        (),
+        |state, auth: auth::AuthenticationData, _, _| {
+            currency::delete_forex_rate_table(
+                state,
+                auth.platform.get_processor().get_account().get_id().clone(),
+            )
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth {
+                allow_connected_scope_operation: false,
+                allow_platform_self_operation: false,
+            }),
+            &auth::JWTAuth {
+                permission: Permission::MerchantAccountWrite,
+                allow_connected: false,
+                allow_platform: false,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
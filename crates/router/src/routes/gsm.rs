@@ -1,4 +1,5 @@
-use actix_web::{web, HttpRequest, Responder};
+use actix_multipart::form::MultipartForm;
+use actix_web::{web, HttpRequest, HttpResponse, Responder};
 use api_models::gsm as gsm_api_types;
 use router_env::{instrument, tracing, Flow};
 
@@ -162,3 +163,120 @@ pub async fn delete_gsm_rule(
     ))
     .await
 }
+
+/// Gsm - Import
+///
+/// To create or update Gsm Rules in bulk from a CSV file
+#[utoipa::path(
+    post,
+    path = "/gsm/import",
+    request_body(content = String, content_type = "multipart/form-data", description = "A CSV file with a column for each field of a GsmRecord"),
+    responses(
+        (status = 200, description = "Gsm Rules imported", body = Vec<GsmImportResponse>),
+        (status = 400, description = "Invalid Data")
+    ),
+    tag = "Gsm",
+    operation_id = "Import Gsm Rules from a CSV file",
+    security(("admin_api_key" = [])),
+)]
+#[instrument(skip_all, fields(flow = ?Flow::GsmRulesImport))]
+pub async fn import_gsm_rules(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    MultipartForm(form): MultipartForm<gsm::GsmImportForm>,
+) -> HttpResponse {
+    let flow = Flow::GsmRulesImport;
+    let records = match gsm::get_gsm_records(form) {
+        Ok(records) => records,
+        Err(e) => return api::log_and_return_error_response(e.into()),
+    };
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        records,
+        |state, _, records, _| gsm::import_gsm_rules(state, records),
+        &ADMIN_API_AUTH,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Gsm - Import JSON
+///
+/// To create or update Gsm Rules in bulk from a JSON array
+#[utoipa::path(
+    post,
+    path = "/gsm/import/json",
+    request_body(
+        content = Vec<GsmRecord>,
+    ),
+    responses(
+        (status = 200, description = "Gsm Rules imported", body = Vec<GsmImportResponse>),
+        (status = 400, description = "Invalid Data")
+    ),
+    tag = "Gsm",
+    operation_id = "Import Gsm Rules from JSON",
+    security(("admin_api_key" = [])),
+)]
+#[instrument(skip_all, fields(flow = ?Flow::GsmRulesImport))]
+pub async fn import_gsm_rules_json(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<Vec<gsm_api_types::GsmRecord>>,
+) -> impl Responder {
+    let payload = json_payload.into_inner();
+
+    let flow = Flow::GsmRulesImport;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, _, payload, _| gsm::import_gsm_rules(state, payload),
+        &ADMIN_API_AUTH,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Gsm - Export
+///
+/// To export Gsm Rules as a CSV file or a JSON array
+#[utoipa::path(
+    get,
+    path = "/gsm/export",
+    params(
+        ("connector" = Option<String>, Query, description = "Export only the rules of this connector"),
+        ("flow" = Option<String>, Query, description = "Export only the rules of this flow"),
+        ("format" = Option<GsmExportFormat>, Query, description = "The format of the exported rules, defaults to csv"),
+    ),
+    responses(
+        (status = 200, description = "Gsm Rules exported", body = Vec<GsmRecord>),
+        (status = 400, description = "Invalid Data")
+    ),
+    tag = "Gsm",
+    operation_id = "Export Gsm Rules",
+    security(("admin_api_key" = [])),
+)]
+#[instrument(skip_all, fields(flow = ?Flow::GsmRulesExport))]
+pub async fn export_gsm_rules(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    query_payload: web::Query<gsm_api_types::GsmExportQuery>,
+) -> impl Responder {
+    let payload = query_payload.into_inner();
+
+    let flow = Flow::GsmRulesExport;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, _, payload, _| gsm::export_gsm_rules(state, payload),
+        &ADMIN_API_AUTH,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
@@ -62,6 +62,9 @@ impl From<Flow> for ApiIdentifier {
             | Flow::MerchantsAccountUpdate
             | Flow::MerchantsAccountDelete
             | Flow::MerchantTransferKey
+            | Flow::MerchantKeyRotate
+            | Flow::MasterKeyRotate
+            | Flow::KeyRotationRetrieve
             | Flow::MerchantAccountList
             | Flow::EnablePlatformAccount => Self::MerchantAccount,
             Flow::OrganizationCreate
@@ -72,6 +75,7 @@ impl From<Flow> for ApiIdentifier {
             | Flow::RoutingLinkConfig
             | Flow::RoutingUnlinkConfig
             | Flow::RoutingRetrieveConfig
+            | Flow::RoutingSimulateConfig
             | Flow::RoutingRetrieveActiveConfig
             | Flow::RoutingRetrieveDefaultConfig
             | Flow::RoutingRetrieveDictionary
@@ -99,11 +103,16 @@ impl From<Flow> for ApiIdentifier {
             | Flow::PauseSubscription
             | Flow::ResumeSubscription
             | Flow::CancelSubscription => Self::Subscription,
-            Flow::RetrieveForexFlow => Self::Forex,
+            Flow::RetrieveForexFlow
+            | Flow::ForexRateTableUpsert
+            | Flow::ForexRateTableRetrieve
+            | Flow::ForexRateTableDelete => Self::Forex,
             Flow::AddToBlocklist => Self::Blocklist,
             Flow::DeleteFromBlocklist => Self::Blocklist,
             Flow::ListBlocklist => Self::Blocklist,
             Flow::ToggleBlocklistGuard => Self::Blocklist,
+            Flow::ImportBlocklist => Self::Blocklist,
+            Flow::ExportBlocklist => Self::Blocklist,
             Flow::MerchantConnectorsCreate
             | Flow::MerchantConnectorsRetrieve
             | Flow::MerchantConnectorsUpdate
@@ -236,7 +245,10 @@ impl From<Flow> for ApiIdentifier {
             | Flow::ProfileDelete
             | Flow::ProfileList
             | Flow::ToggleExtendedCardInfo
-            | Flow::ToggleConnectorAgnosticMit => Self::Profile,
+            | Flow::ToggleConnectorAgnosticMit
+            | Flow::WebhookSigningKeyRotate
+            | Flow::WebhookSigningKeyList
+            | Flow::WebhookSigningKeyRetire => Self::Profile,
             Flow::PaymentLinkRetrieve
             | Flow::PaymentLinkInitiate
             | Flow::PaymentSecureLinkInitiate
@@ -247,7 +259,9 @@ impl From<Flow> for ApiIdentifier {
             Flow::GsmRuleCreate
             | Flow::GsmRuleRetrieve
             | Flow::GsmRuleUpdate
-            | Flow::GsmRuleDelete => Self::Gsm,
+            | Flow::GsmRuleDelete
+            | Flow::GsmRulesImport
+            | Flow::GsmRulesExport => Self::Gsm,
             Flow::ApplePayCertificatesMigration => Self::ApplePayCertificatesMigration,
             Flow::UserConnectAccount
             | Flow::UserSignUp
@@ -9,7 +9,9 @@ pub fn track_response_status_code<Q>(response: &ApplicationResponse<Q>) -> i64 {
         | ApplicationResponse::GenericLinkForm(_)
         | ApplicationResponse::PaymentLinkForm(_)
         | ApplicationResponse::FileData(_)
+        | ApplicationResponse::FileStream(_)
         | ApplicationResponse::JsonWithHeaders(_) => 200,
         ApplicationResponse::JsonForRedirection(_) => 302,
+        ApplicationResponse::ReplayedJson(response) => i64::from(response.status_code),
     }
 }
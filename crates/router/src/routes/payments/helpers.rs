@@ -37,6 +37,7 @@ pub fn populate_browser_info(
             device_model: None,
             accept_language: None,
             referer: None,
+            device_fingerprint: None,
         });
 
     let ip_address = req
@@ -553,6 +553,7 @@ pub fn populate_browser_info_for_payouts(
         device_model: None,
         accept_language: None,
         referer: None,
+        device_fingerprint: None,
     });
 
     let ip_address = req
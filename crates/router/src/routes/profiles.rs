@@ -576,3 +576,154 @@ pub async fn payment_connector_list_profile(
     )
     .await
 }
+
+#[cfg(all(feature = "olap", feature = "v1"))]
+#[instrument(skip_all, fields(flow = ?Flow::WebhookSigningKeyRotate))]
+pub async fn webhook_signing_key_rotate(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(
+        common_utils::id_type::MerchantId,
+        common_utils::id_type::ProfileId,
+    )>,
+    json_payload: web::Json<api_models::admin::WebhookSigningKeyRotateRequest>,
+) -> HttpResponse {
+    let flow = Flow::WebhookSigningKeyRotate;
+    let (merchant_id, profile_id) = path.into_inner();
+    let payload = api_models::admin::WebhookSigningKeyRotateRequestInternal {
+        profile_id: profile_id.clone(),
+        request: json_payload.into_inner(),
+    };
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, auth_data, req, _| {
+            crate::core::webhooks::signing_keys::rotate_webhook_signing_key(
+                state,
+                auth_data
+                    .platform
+                    .get_processor()
+                    .get_account()
+                    .get_id()
+                    .clone(),
+                auth_data.platform.get_processor().get_key_store().clone(),
+                req,
+            )
+        },
+        auth::auth_type(
+            &auth::ApiKeyAuthWithMerchantIdFromRoute(merchant_id.clone()),
+            &auth::JWTAuthMerchantAndProfileFromRoute {
+                merchant_id: merchant_id.clone(),
+                profile_id: profile_id.clone(),
+                required_permission: permissions::Permission::ProfileAccountWrite,
+                allow_connected: true,
+                allow_platform: false,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[cfg(all(feature = "olap", feature = "v1"))]
+#[instrument(skip_all, fields(flow = ?Flow::WebhookSigningKeyList))]
+pub async fn webhook_signing_key_list(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(
+        common_utils::id_type::MerchantId,
+        common_utils::id_type::ProfileId,
+    )>,
+) -> HttpResponse {
+    let flow = Flow::WebhookSigningKeyList;
+    let (merchant_id, profile_id) = path.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        profile_id.clone(),
+        |state, auth_data, profile_id, _| {
+            crate::core::webhooks::signing_keys::list_webhook_signing_keys(
+                state,
+                auth_data
+                    .platform
+                    .get_processor()
+                    .get_account()
+                    .get_id()
+                    .clone(),
+                auth_data.platform.get_processor().get_key_store().clone(),
+                profile_id,
+            )
+        },
+        auth::auth_type(
+            &auth::ApiKeyAuthWithMerchantIdFromRoute(merchant_id.clone()),
+            &auth::JWTAuthMerchantAndProfileFromRoute {
+                merchant_id: merchant_id.clone(),
+                profile_id,
+                required_permission: permissions::Permission::ProfileAccountRead,
+                allow_connected: true,
+                allow_platform: false,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[cfg(all(feature = "olap", feature = "v1"))]
+#[instrument(skip_all, fields(flow = ?Flow::WebhookSigningKeyRetire))]
+pub async fn webhook_signing_key_retire(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(
+        common_utils::id_type::MerchantId,
+        common_utils::id_type::ProfileId,
+        String,
+    )>,
+) -> HttpResponse {
+    let flow = Flow::WebhookSigningKeyRetire;
+    let (merchant_id, profile_id, key_id) = path.into_inner();
+    let payload = api_models::admin::WebhookSigningKeyRetireRequest {
+        profile_id: profile_id.clone(),
+        key_id,
+    };
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, auth_data, req, _| {
+            crate::core::webhooks::signing_keys::retire_webhook_signing_key(
+                state,
+                auth_data
+                    .platform
+                    .get_processor()
+                    .get_account()
+                    .get_id()
+                    .clone(),
+                auth_data.platform.get_processor().get_key_store().clone(),
+                req,
+            )
+        },
+        auth::auth_type(
+            &auth::ApiKeyAuthWithMerchantIdFromRoute(merchant_id.clone()),
+            &auth::JWTAuthMerchantAndProfileFromRoute {
+                merchant_id: merchant_id.clone(),
+                profile_id,
+                required_permission: permissions::Permission::ProfileAccountWrite,
+                allow_connected: true,
+                allow_platform: false,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
@@ -71,7 +71,7 @@ pub async fn get_store(
     #[allow(clippy::useless_conversion)]
     let conf = (master_config.into(), replica_config.into());
 
-    let store: RouterStore<StoreType> = if test_transaction {
+    let mut store: RouterStore<StoreType> = if test_transaction {
         RouterStore::test_store(
             conf,
             tenant,
@@ -92,6 +92,15 @@ pub async fn get_store(
         .await?
     };
 
+    // Key stores encrypted with the previous master key stay readable until they are re-encrypted
+    #[allow(clippy::expect_used)]
+    if let Some(previous_master_enc_key) = &config.secrets.get_inner().previous_master_enc_key {
+        let previous_master_enc_key = hex::decode(previous_master_enc_key.clone().expose())
+            .map(StrongSecret::new)
+            .expect("Failed to decode previous master key from hex");
+        store.set_previous_master_key(previous_master_enc_key);
+    }
+
     #[cfg(feature = "kv_store")]
     let store = KVRouterStore::from_store(
         store,
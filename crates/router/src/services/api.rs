@@ -16,7 +16,7 @@ use actix_web::{
     http::header::{HeaderName, HeaderValue},
     web, FromRequest, HttpRequest, HttpResponse, Responder, ResponseError,
 };
-pub use client::{ApiClient, MockApiClient, ProxyClient};
+pub use client::{ApiClient, CassetteApiClient, CassetteMode, MockApiClient, ProxyClient};
 pub use common_enums::enums::PaymentAction;
 pub use common_utils::request::{ContentType, Method, Request, RequestBuilder};
 use common_utils::{
@@ -24,11 +24,13 @@ use common_utils::{
     errors::{ErrorSwitch, ReportSwitchExt},
 };
 use error_stack::{Report, ResultExt};
+use futures::TryStreamExt;
 use hyperswitch_domain_models::router_data_v2::flow_common_types as common_types;
 pub use hyperswitch_domain_models::{
     api::{
-        ApplicationResponse, GenericExpiredLinkData, GenericLinkFormData, GenericLinkStatusData,
-        GenericLinks, PaymentLinkAction, RedirectionFormData,
+        ApplicationResponse, FileStream, GenericExpiredLinkData, GenericLinkFormData,
+        GenericLinkStatusData, GenericLinks, PaymentLinkAction, RedirectionFormData,
+        ReplayedJsonResponse,
     },
     payment_method_data::PaymentMethodData,
     router_response_types::RedirectForm,
@@ -61,11 +63,12 @@ use crate::{
     core::{
         api_locking,
         errors::{self, CustomResult},
+        idempotency, rate_limit,
     },
     events::api_logs::{ApiEvent, ApiEventMetric, ApiEventsType},
     headers, logger,
     routes::{
-        app::{AppStateInfo, ReqState, SessionStateInfo},
+        app::{AppStateInfo, ReqState, RequestMetadata, SessionStateInfo},
         metrics, AppState, SessionState,
     },
     services::generic_link_response::build_generic_link_html,
@@ -171,6 +174,7 @@ pub async fn server_wrap_util<'a, 'b, U, T, Q, F, Fut, E, OErr>(
     func: F,
     api_auth: &dyn AuthenticateAndFetch<U, SessionState>,
     lock_action: api_locking::LockAction,
+    idempotency_action: idempotency::IdempotencyAction,
 ) -> CustomResult<ApplicationResponse<Q>, OErr>
 where
     F: Fn(SessionState, U, T, ReqState) -> Fut,
@@ -236,6 +240,10 @@ where
             .switch()
         })?;
     session_state.add_request_id(request_id.clone());
+    session_state.request_metadata = Some(RequestMetadata::new(
+        request,
+        &session_state.conf.server.trusted_proxies,
+    ));
     let mut request_state = session_state.get_req_state();
 
     request_state.event_context.record_info(request_id.clone());
@@ -263,22 +271,103 @@ where
 
     app_state.add_flow_name(flow.to_string());
 
+    rate_limit::check_rate_limits(&session_state, &auth_type, &flow.to_string())
+        .await
+        .switch()?;
+
     tracing::Span::current().record("merchant_id", merchant_id.get_string_repr().to_owned());
 
-    let output = {
-        lock_action
-            .clone()
-            .perform_locking_action(&session_state, merchant_id.to_owned())
-            .await
-            .switch()?;
-        let res = func(session_state.clone(), auth_out, payload, request_state)
-            .await
-            .switch();
-        lock_action
-            .free_lock_action(&session_state, merchant_id.to_owned())
+    // Idempotency keys are scoped to the merchant, and are not honoured for unauthenticated
+    // requests made without one
+    let idempotency_context = match (idempotency_action, auth_type.get_merchant_id()) {
+        (
+            idempotency::IdempotencyAction::Deduplicate
+            | idempotency::IdempotencyAction::DeduplicateConvertedResponse,
+            Some(merchant_id),
+        ) => idempotency::IdempotencyContext::new(request, merchant_id, &payload).switch()?,
+        (idempotency::IdempotencyAction::NotApplicable, _) | (_, None) => None,
+    };
+
+    let idempotency_redis_conn = idempotency_context
+        .as_ref()
+        .map(|_| session_state.store.get_redis_conn())
+        .transpose()
+        .change_context(errors::ApiErrorResponse::InternalServerError.switch())?;
+
+    let idempotency_check = match (
+        idempotency_context.as_ref(),
+        idempotency_redis_conn.as_ref(),
+    ) {
+        (Some(idempotency_context), Some(redis_conn)) => idempotency_context
+            .begin(redis_conn, &session_state.conf.lock_settings, &lock_action)
             .await
-            .switch()?;
-        res
+            .switch()?,
+        _ => idempotency::IdempotencyCheck::Proceed,
+    };
+
+    let output = match idempotency_check {
+        idempotency::IdempotencyCheck::Replay(response) => {
+            Ok(ApplicationResponse::ReplayedJson(Box::new(response)))
+        }
+        idempotency::IdempotencyCheck::Proceed => {
+            let output = async {
+                lock_action
+                    .clone()
+                    .perform_locking_action(&session_state, merchant_id.to_owned())
+                    .await
+                    .switch()?;
+                let res = func(session_state.clone(), auth_out, payload, request_state)
+                    .await
+                    .switch();
+                lock_action
+                    .free_lock_action(&session_state, merchant_id.to_owned())
+                    .await
+                    .switch()?;
+                Ok::<_, Report<OErr>>(res)
+            }
+            .await;
+
+            if let Some((idempotency_context, redis_conn)) =
+                idempotency_context.zip(idempotency_redis_conn)
+            {
+                let response_expiry_in_seconds =
+                    session_state.conf.idempotency.response_expiry_in_seconds;
+                let response = output.as_ref().ok().and_then(|res| res.as_ref().ok());
+                match (idempotency_action, response) {
+                    // The response is stored by the caller once it has been converted
+                    (idempotency::IdempotencyAction::DeduplicateConvertedResponse, Some(_)) => {
+                        request
+                            .extensions_mut()
+                            .insert(idempotency::PendingIdempotentRequest {
+                                context: idempotency_context,
+                                redis_conn,
+                                response_expiry_in_seconds,
+                            });
+                    }
+                    _ => {
+                        let replayable_response = response
+                            .map(|response| {
+                                idempotency::get_replayable_response(
+                                    response,
+                                    &session_state.conf.proxy_status_mapping,
+                                )
+                            })
+                            .transpose()
+                            .map_err(|error| logger::error!(?error))
+                            .ok()
+                            .flatten()
+                            .flatten();
+                        idempotency_context
+                            .complete(&redis_conn, replayable_response, response_expiry_in_seconds)
+                            .await
+                            .map_err(|error| logger::error!(?error))
+                            .ok();
+                    }
+                }
+            }
+
+            output?
+        }
     };
     let request_duration = Instant::now()
         .saturating_duration_since(start_instant)
@@ -429,6 +518,7 @@ where
         func,
         api_auth,
         lock_action,
+        idempotency::IdempotencyAction::Deduplicate,
     )
     .await
     .map(|response| {
@@ -448,10 +538,14 @@ where
             ),
         },
         Ok(ApplicationResponse::StatusOk) => http_response_ok(),
+        Ok(ApplicationResponse::ReplayedJson(response)) => http_response_replayed_json(*response),
         Ok(ApplicationResponse::TextPlain(text)) => http_response_plaintext(text),
         Ok(ApplicationResponse::FileData((file_data, content_type))) => {
             http_response_file_data(file_data, content_type)
         }
+        Ok(ApplicationResponse::FileStream((file_stream, content_type))) => {
+            http_response_file_stream(file_stream, content_type)
+        }
         Ok(ApplicationResponse::JsonForRedirection(response)) => {
             match serde_json::to_string(&response) {
                 Ok(res) => http_redirect_response(res, response),
@@ -632,6 +726,20 @@ pub fn http_response_json_with_headers<T: body::MessageBody + 'static>(
         .body(response)
 }
 
+pub fn http_response_replayed_json(response: ReplayedJsonResponse) -> HttpResponse {
+    let mut response_builder = HttpResponse::build(
+        http::StatusCode::from_u16(response.status_code).unwrap_or(http::StatusCode::OK),
+    );
+    for (header_name, header_value) in response.headers {
+        response_builder.append_header((header_name, header_value));
+    }
+
+    response_builder
+        .append_header((headers::IDEMPOTENT_REPLAYED, "true"))
+        .content_type(mime::APPLICATION_JSON)
+        .body(response.body)
+}
+
 pub fn http_response_plaintext<T: body::MessageBody + 'static>(res: T) -> HttpResponse {
     HttpResponse::Ok().content_type(mime::TEXT_PLAIN).body(res)
 }
@@ -643,6 +751,15 @@ pub fn http_response_file_data<T: body::MessageBody + 'static>(
     HttpResponse::Ok().content_type(content_type).body(res)
 }
 
+pub fn http_response_file_stream(
+    file_stream: FileStream,
+    content_type: mime::Mime,
+) -> HttpResponse {
+    HttpResponse::Ok()
+        .content_type(content_type)
+        .streaming(file_stream.0.map_ok(bytes::Bytes::from))
+}
+
 pub fn http_response_html_data<T: body::MessageBody + 'static>(
     res: T,
     optional_headers: Option<HashSet<(&'static str, String)>>,
@@ -701,6 +818,15 @@ pub trait Authenticate {
     fn get_payment_method_data(&self) -> Option<api_models::payments::PaymentMethodData> {
         None
     }
+
+    /// Restricts the profile of the resources created by the request to the profile the request
+    /// is authenticated for, if any
+    fn apply_profile_id_from_auth_layer(
+        &mut self,
+        _profile_id_from_auth_layer: Option<&common_utils::id_type::ProfileId>,
+    ) -> errors::RouterResult<()> {
+        Ok(())
+    }
 }
 
 #[cfg(feature = "v2")]
@@ -736,6 +862,17 @@ impl Authenticate for api_models::payments::PaymentsRequest {
             .as_ref()
             .and_then(|pmd| pmd.payment_method_data.clone())
     }
+
+    fn apply_profile_id_from_auth_layer(
+        &mut self,
+        profile_id_from_auth_layer: Option<&common_utils::id_type::ProfileId>,
+    ) -> errors::RouterResult<()> {
+        self.profile_id = crate::core::utils::get_request_profile_id_from_auth_layer(
+            profile_id_from_auth_layer,
+            self.profile_id.take(),
+        )?;
+        Ok(())
+    }
 }
 
 #[cfg(feature = "v1")]
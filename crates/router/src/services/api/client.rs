@@ -5,7 +5,10 @@ use error_stack::ResultExt;
 pub use external_services::http_client::{self, client};
 use http::{HeaderValue, Method};
 pub use hyperswitch_interfaces::{
-    api_client::{ApiClient, ApiClientWrapper, RequestBuilder},
+    api_client::{
+        cassette::{CassetteApiClient, CassetteMode},
+        ApiClient, ApiClientWrapper, RequestBuilder,
+    },
     types::Proxy,
 };
 use masking::PeekInterface;
@@ -2,7 +2,7 @@ use common_utils::consts::DEFAULT_LOCALE;
 use rust_i18n::t;
 use tera::Context;
 
-fn get_language(locale_str: &str) -> String {
+pub fn get_language(locale_str: &str) -> String {
     let lowercase_str = locale_str.to_lowercase();
     let primary_locale = lowercase_str.split(',').next().unwrap_or("").trim();
 
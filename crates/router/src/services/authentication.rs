@@ -49,7 +49,7 @@ use crate::{
         errors::{self, utils::StorageErrorExt, RouterResult},
     },
     headers,
-    routes::app::SessionStateInfo,
+    routes::app::{RequestMetadata, SessionStateInfo},
     services::api,
     types::{domain, storage},
     utils::OptionExt,
@@ -250,6 +250,35 @@ impl AuthenticationType {
             | Self::NoAuth => None,
         }
     }
+
+    pub fn get_profile_id(&self) -> Option<&id_type::ProfileId> {
+        match self {
+            Self::MerchantJwtWithProfileId { profile_id, .. }
+            | Self::InternalMerchantIdProfileId { profile_id, .. } => profile_id.as_ref(),
+            Self::EmbeddedJwt { profile_id, .. } => Some(profile_id),
+            Self::ApiKey { .. }
+            | Self::AdminApiKey
+            | Self::AdminApiAuthWithMerchantId { .. }
+            | Self::OrganizationJwt { .. }
+            | Self::BasicAuth { .. }
+            | Self::MerchantJwt { .. }
+            | Self::UserJwt { .. }
+            | Self::SinglePurposeJwt { .. }
+            | Self::SinglePurposeOrLoginJwt { .. }
+            | Self::MerchantId { .. }
+            | Self::PublishableKey { .. }
+            | Self::SdkAuthorization { .. }
+            | Self::WebhookAuth { .. }
+            | Self::NoAuth => None,
+        }
+    }
+
+    pub fn get_api_key_id(&self) -> Option<&id_type::ApiKeyId> {
+        match self {
+            Self::ApiKey { key_id, .. } => Some(key_id),
+            _ => None,
+        }
+    }
 }
 
 #[derive(Clone, Debug, Eq, PartialEq, Serialize, serde::Deserialize, strum::Display)]
@@ -627,6 +656,8 @@ where
                 .attach_printable("API key has expired");
         }
 
+        validate_api_key_restrictions(state, &stored_api_key, Some(&profile_id))?;
+
         let key_store = state
             .store()
             .get_merchant_key_store_by_merchant_id(
@@ -751,6 +782,9 @@ where
                 })
                 .change_context(errors::ApiErrorResponse::Unauthorized)?;
 
+        validate_api_key_restrictions(state, &stored_api_key, profile_id.as_ref())?;
+        let profile_id = profile_id.or_else(|| stored_api_key.profile_id.clone());
+
         let initiator_merchant = state
             .store()
             .find_merchant_account_by_merchant_id(&stored_api_key.merchant_id, &key_store)
@@ -828,6 +862,129 @@ where
     }
 }
 
+/// Validates that the request is permitted by the restrictions of the API key: the profile
+/// provided in the request must be the one the API key is restricted to, the request must be made
+/// from an allowed IP address, and the API key must have access to the resource requested.
+fn validate_api_key_restrictions<A>(
+    state: &A,
+    stored_api_key: &storage::ApiKey,
+    profile_id_from_header: Option<&id_type::ProfileId>,
+) -> RouterResult<()>
+where
+    A: SessionStateInfo,
+{
+    if let Some(profile_id) = &stored_api_key.profile_id {
+        if profile_id_from_header
+            .is_some_and(|profile_id_from_header| profile_id_from_header != profile_id)
+        {
+            return Err(report!(errors::ApiErrorResponse::Unauthorized))
+                .attach_printable("API key is restricted to a different profile");
+        }
+    }
+
+    if stored_api_key.ip_allowlist.is_none() && stored_api_key.scopes.is_none() {
+        return Ok(());
+    }
+
+    let request_metadata = state
+        .get_request_metadata()
+        .ok_or(errors::ApiErrorResponse::Unauthorized)
+        .attach_printable("Request metadata is required to authenticate restricted API keys")?;
+
+    if let Some(ip_allowlist) = &stored_api_key.ip_allowlist {
+        let is_ip_allowed = request_metadata.client_ip.is_some_and(|client_ip| {
+            ip_allowlist
+                .0
+                .iter()
+                .filter_map(|entry| parse_ip_allowlist_entry(entry))
+                .any(|allowed_network| allowed_network.contains(&client_ip))
+        });
+
+        if !is_ip_allowed {
+            return Err(report!(errors::ApiErrorResponse::Unauthorized)).attach_printable_lazy(
+                || {
+                    format!(
+                        "API key cannot be used from the IP address {:?}",
+                        request_metadata.client_ip
+                    )
+                },
+            );
+        }
+    }
+
+    if let Some(scopes) = &stored_api_key.scopes {
+        let (resource, access) =
+            get_api_key_scope_for_request(request_metadata).ok_or_else(|| {
+                report!(errors::ApiErrorResponse::AccessForbidden {
+                    resource: request_metadata.path.clone(),
+                })
+            })?;
+
+        fp_utils::when(!scopes.allows(resource, access), || {
+            Err(report!(errors::ApiErrorResponse::AccessForbidden {
+                resource: format!("{resource} ({access})"),
+            }))
+        })?;
+    }
+
+    Ok(())
+}
+
+/// Parses an entry of the IP allowlist of an API key, which is either an IP address or a CIDR
+/// range
+pub fn parse_ip_allowlist_entry(entry: &str) -> Option<ipnet::IpNet> {
+    entry.parse::<ipnet::IpNet>().ok().or_else(|| {
+        entry
+            .parse::<std::net::IpAddr>()
+            .ok()
+            .map(ipnet::IpNet::from)
+    })
+}
+
+/// Identifies the resource requested, and the level of access required to it, from the path and
+/// the method of the request. Requests to resources which cannot be scoped are not identified,
+/// and are hence forbidden for API keys restricted to a set of resources.
+fn get_api_key_scope_for_request(
+    request_metadata: &RequestMetadata,
+) -> Option<(common_enums::ApiKeyResource, common_enums::ApiKeyAccess)> {
+    let mut segments = request_metadata
+        .path
+        .split('/')
+        .filter(|segment| !segment.is_empty())
+        .peekable();
+
+    // The version prefix of the v2 APIs is not a part of the resource
+    segments.next_if_eq(&"v2");
+
+    let resource = match segments.next()?.replace('-', "_").as_str() {
+        "payments" => common_enums::ApiKeyResource::Payments,
+        "refunds" => common_enums::ApiKeyResource::Refunds,
+        "customers" => common_enums::ApiKeyResource::Customers,
+        "payment_methods" => common_enums::ApiKeyResource::PaymentMethods,
+        "mandates" => common_enums::ApiKeyResource::Mandates,
+        "disputes" => common_enums::ApiKeyResource::Disputes,
+        "payouts" => common_enums::ApiKeyResource::Payouts,
+        "payment_link" | "payment_links" => common_enums::ApiKeyResource::PaymentLinks,
+        "files" => common_enums::ApiKeyResource::Files,
+        _ => return None,
+    };
+
+    let access = match request_metadata.method {
+        actix_web::http::Method::GET | actix_web::http::Method::HEAD => {
+            common_enums::ApiKeyAccess::Read
+        }
+        // Listing APIs which accept the constraints in the request body only read the resources
+        actix_web::http::Method::POST
+            if matches!(segments.last(), Some("list" | "filter" | "aggregate")) =>
+        {
+            common_enums::ApiKeyAccess::Read
+        }
+        _ => common_enums::ApiKeyAccess::Write,
+    };
+
+    Some((resource, access))
+}
+
 #[derive(Debug)]
 pub struct ApiKeyAuthWithMerchantIdFromRoute(pub id_type::MerchantId);
 
@@ -936,6 +1093,11 @@ where
                 .attach_printable("API key has expired");
         }
 
+        if stored_api_key.is_restricted() {
+            return Err(report!(errors::ApiErrorResponse::Unauthorized))
+                .attach_printable("Restricted API keys cannot be used for this API");
+        }
+
         let key_store = state
             .store()
             .get_merchant_key_store_by_merchant_id(
@@ -1021,6 +1183,11 @@ where
                 .attach_printable("API key has expired");
         }
 
+        if stored_api_key.is_restricted() {
+            return Err(report!(errors::ApiErrorResponse::Unauthorized))
+                .attach_printable("Restricted API keys cannot be used for this API");
+        }
+
         let key_store = state
             .store()
             .get_merchant_key_store_by_merchant_id(
@@ -1177,6 +1344,11 @@ where
                 .attach_printable("API key has expired");
         }
 
+        if stored_api_key.is_restricted() {
+            return Err(report!(errors::ApiErrorResponse::Unauthorized))
+                .attach_printable("Restricted API keys cannot be used for this API");
+        }
+
         let (platform_key_store, platform_merchant) =
             Self::fetch_key_store_and_account(&stored_api_key.merchant_id, state).await?;
 
@@ -1937,6 +2109,11 @@ where
                 .attach_printable("API key has expired");
         }
 
+        if stored_api_key.is_restricted() {
+            return Err(report!(errors::ApiErrorResponse::Unauthorized))
+                .attach_printable("Restricted API keys cannot be used for this API");
+        }
+
         let key_store = state
             .store()
             .get_merchant_key_store_by_merchant_id(
@@ -2087,6 +2264,11 @@ where
                 .attach_printable("API key has expired");
         }
 
+        if stored_api_key.is_restricted() {
+            return Err(report!(errors::ApiErrorResponse::Unauthorized))
+                .attach_printable("Restricted API keys cannot be used for this API");
+        }
+
         if fallback_merchant_ids
             .merchant_ids
             .contains(&stored_api_key.merchant_id)
@@ -2930,6 +3112,8 @@ where
                 .attach_printable("API key has expired");
         }
 
+        validate_api_key_restrictions(state, &stored_api_key, Some(&profile_id))?;
+
         let key_store = state
             .store()
             .get_merchant_key_store_by_merchant_id(
@@ -6607,3 +6791,78 @@ impl ExternalToken {
         )?)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use actix_web::http::Method;
+    use common_enums::{ApiKeyAccess, ApiKeyResource};
+
+    use super::*;
+
+    fn request_metadata(method: Method, path: &str) -> RequestMetadata {
+        RequestMetadata {
+            method,
+            path: path.to_string(),
+            client_ip: None,
+        }
+    }
+
+    #[test]
+    fn test_parse_ip_allowlist_entry() {
+        let address = parse_ip_allowlist_entry("192.168.1.10").unwrap();
+        assert_eq!(address, "192.168.1.10/32".parse::<ipnet::IpNet>().unwrap());
+        assert!(address.contains(&"192.168.1.10".parse::<std::net::IpAddr>().unwrap()));
+        assert!(!address.contains(&"192.168.1.11".parse::<std::net::IpAddr>().unwrap()));
+
+        let range = parse_ip_allowlist_entry("10.0.0.0/8").unwrap();
+        assert!(range.contains(&"10.20.30.40".parse::<std::net::IpAddr>().unwrap()));
+        assert!(!range.contains(&"11.0.0.1".parse::<std::net::IpAddr>().unwrap()));
+
+        let ipv6_range = parse_ip_allowlist_entry("2001:db8::/32").unwrap();
+        assert!(ipv6_range.contains(&"2001:db8::1".parse::<std::net::IpAddr>().unwrap()));
+
+        assert!(parse_ip_allowlist_entry("").is_none());
+        assert!(parse_ip_allowlist_entry("localhost").is_none());
+        assert!(parse_ip_allowlist_entry("10.0.0.0/33").is_none());
+    }
+
+    #[test]
+    fn test_get_api_key_scope_for_request() {
+        assert_eq!(
+            get_api_key_scope_for_request(&request_metadata(Method::GET, "/payments/pay_123")),
+            Some((ApiKeyResource::Payments, ApiKeyAccess::Read))
+        );
+        assert_eq!(
+            get_api_key_scope_for_request(&request_metadata(Method::POST, "/payments")),
+            Some((ApiKeyResource::Payments, ApiKeyAccess::Write))
+        );
+        assert_eq!(
+            get_api_key_scope_for_request(&request_metadata(Method::POST, "/refunds/list")),
+            Some((ApiKeyResource::Refunds, ApiKeyAccess::Read))
+        );
+        assert_eq!(
+            get_api_key_scope_for_request(&request_metadata(Method::DELETE, "/customers/cus_1")),
+            Some((ApiKeyResource::Customers, ApiKeyAccess::Write))
+        );
+        assert_eq!(
+            get_api_key_scope_for_request(&request_metadata(
+                Method::GET,
+                "/v2/payment-methods/pm_1"
+            )),
+            Some((ApiKeyResource::PaymentMethods, ApiKeyAccess::Read))
+        );
+        assert_eq!(
+            get_api_key_scope_for_request(&request_metadata(Method::HEAD, "/payment_link/pl_1")),
+            Some((ApiKeyResource::PaymentLinks, ApiKeyAccess::Read))
+        );
+        assert_eq!(
+            get_api_key_scope_for_request(&request_metadata(Method::POST, "/account/merchant_1")),
+            None
+        );
+        assert_eq!(
+            get_api_key_scope_for_request(&request_metadata(Method::GET, "/")),
+            None
+        );
+    }
+}
@@ -1,7 +1,7 @@
 use std::sync::Arc;
 
 use error_stack::ResultExt;
-use redis_interface::RedisConnectionPool;
+use redis_interface::{RedisConnectionPool, RedisKey};
 
 use crate::{
     core::errors::{ApiErrorResponse, RouterResult},
@@ -76,3 +76,106 @@ where
         .await
         .change_context(ApiErrorResponse::InternalServerError)
 }
+
+/// Removes the attempts which have fallen out of the sliding window, optionally records a new
+/// attempt, and returns the number of attempts within the window. The attempts are stored in a
+/// sorted set scored by the time of the attempt in milliseconds, so that the window slides with
+/// every evaluation instead of being reset at fixed intervals.
+const SLIDING_WINDOW_SCRIPT: &str = r#"
+local key = KEYS[1]
+local now = tonumber(ARGV[1])
+local window = tonumber(ARGV[2])
+redis.call('ZREMRANGEBYSCORE', key, '-inf', now - window)
+if ARGV[4] == '1' then
+    redis.call('ZADD', key, now, ARGV[3])
+    redis.call('PEXPIRE', key, window)
+end
+return redis.call('ZCARD', key)
+"#;
+
+/// Returns the number of attempts made within the sliding window ending now, including the
+/// current attempt. The current attempt is recorded only if `record_attempt` is set, so that
+/// checks which do not result in a payment attempt do not count towards the limit.
+pub async fn get_attempt_count_in_sliding_window<A>(
+    state: &A,
+    cache_key: &str,
+    window_in_secs: i64,
+    record_attempt: bool,
+) -> RouterResult<u64>
+where
+    A: SessionStateInfo + Sync,
+{
+    let redis_conn = get_redis_connection(state)?;
+    let now_in_millis = common_utils::date_time::now()
+        .assume_utc()
+        .unix_timestamp_nanos()
+        / 1_000_000;
+    let attempt_id = uuid::Uuid::new_v4().to_string();
+
+    let attempt_count: u64 = redis_conn
+        .evaluate_redis_script(
+            SLIDING_WINDOW_SCRIPT,
+            vec![RedisKey::from(cache_key).tenant_aware_key(&redis_conn)],
+            get_sliding_window_script_args(
+                now_in_millis,
+                window_in_secs,
+                attempt_id,
+                record_attempt,
+            ),
+        )
+        .await
+        .change_context(ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to evaluate sliding window counter")?;
+
+    Ok(include_current_attempt(attempt_count, record_attempt))
+}
+
+fn get_sliding_window_script_args(
+    now_in_millis: i128,
+    window_in_secs: i64,
+    attempt_id: String,
+    record_attempt: bool,
+) -> Vec<String> {
+    vec![
+        now_in_millis.to_string(),
+        window_in_secs.saturating_mul(1000).to_string(),
+        attempt_id,
+        u8::from(record_attempt).to_string(),
+    ]
+}
+
+/// The count returned by the sliding window script includes the current attempt only if it was
+/// recorded.
+fn include_current_attempt(attempt_count: u64, record_attempt: bool) -> u64 {
+    if record_attempt {
+        attempt_count
+    } else {
+        attempt_count.saturating_add(1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sliding_window_script_args() {
+        assert_eq!(
+            get_sliding_window_script_args(1_700_000_000_000, 600, "attempt".to_string(), true),
+            vec!["1700000000000", "600000", "attempt", "1"]
+        );
+        assert_eq!(
+            get_sliding_window_script_args(1_700_000_000_000, 600, "attempt".to_string(), false),
+            vec!["1700000000000", "600000", "attempt", "0"]
+        );
+    }
+
+    #[test]
+    fn test_include_current_attempt() {
+        // A recorded attempt is already counted by the script
+        assert_eq!(include_current_attempt(3, true), 3);
+        // An attempt which is not recorded is counted as if it were
+        assert_eq!(include_current_attempt(3, false), 4);
+        assert_eq!(include_current_attempt(0, false), 1);
+    }
+}
@@ -5,6 +5,7 @@ use diesel_models::organization::OrganizationBridge;
 use error_stack::ResultExt;
 use external_services::email::{EmailContents, EmailData, EmailError};
 use masking::{ExposeInterface, PeekInterface, Secret};
+use rust_i18n::t;
 
 use crate::{configs, consts, routes::SessionState};
 #[cfg(feature = "olap")]
@@ -92,10 +93,66 @@ pub enum EmailBody {
         background_color: String,
         foreground_color: String,
     },
+    PaymentReceipt {
+        locale: String,
+        theme: CustomerEmailTheme,
+        payment_id: String,
+        amount: String,
+        description: Option<String>,
+        paid_at: String,
+    },
+    RefundConfirmation {
+        locale: String,
+        theme: CustomerEmailTheme,
+        refund_id: String,
+        payment_id: String,
+        amount: String,
+        refunded_at: String,
+    },
+    PaymentLinkReminder {
+        locale: String,
+        theme: CustomerEmailTheme,
+        payment_id: String,
+        amount: String,
+        description: Option<String>,
+        link: String,
+        expires_at: String,
+    },
+}
+
+/// Branding of the emails sent to the customers of a merchant.
+#[derive(Clone, Debug)]
+pub struct CustomerEmailTheme {
+    pub brand_name: String,
+    pub logo_url: Option<String>,
+    /// Used when it is a hex color, see [`CustomerEmailTheme::get_primary_color`]
+    pub primary_color: String,
+    pub support_email: Option<String>,
+}
+
+impl CustomerEmailTheme {
+    /// Color of the customer emails of profiles without a configured theme
+    pub const DEFAULT_PRIMARY_COLOR: &'static str = "#006DF9";
+
+    /// The primary color is interpolated into inline styles, so anything other than a hex color
+    /// such as `#fff` or `#006DF9` is replaced with the default one.
+    pub fn get_primary_color(&self) -> &str {
+        let is_hex_color = self.primary_color.strip_prefix('#').is_some_and(|digits| {
+            matches!(digits.len(), 3 | 6) && digits.chars().all(|c| c.is_ascii_hexdigit())
+        });
+
+        if is_hex_color {
+            &self.primary_color
+        } else {
+            Self::DEFAULT_PRIMARY_COLOR
+        }
+    }
 }
 
 pub mod html {
-    use crate::services::email::types::EmailBody;
+    use rust_i18n::t;
+
+    use crate::services::email::types::{CustomerEmailTheme, EmailBody};
 
     pub fn get_html_body(email_body: EmailBody) -> String {
         match email_body {
@@ -275,8 +332,205 @@ Email         : {user_email}
                     foreground_color = foreground_color
                 )
             }
+            EmailBody::PaymentReceipt {
+                locale,
+                theme,
+                payment_id,
+                amount,
+                description,
+                paid_at,
+            } => {
+                let locale = locale.as_str();
+                let details = [
+                    Some((
+                        t!("customer_email.details.payment_id", locale = locale),
+                        payment_id,
+                    )),
+                    Some((t!("customer_email.details.amount", locale = locale), amount)),
+                    description.map(|description| {
+                        (
+                            t!("customer_email.details.description", locale = locale),
+                            description,
+                        )
+                    }),
+                    Some((t!("customer_email.details.date", locale = locale), paid_at)),
+                ];
+                get_customer_email_body(
+                    locale,
+                    &theme,
+                    &t!("customer_email.payment_receipt.title", locale = locale),
+                    &t!("customer_email.payment_receipt.message", locale = locale),
+                    details.into_iter().flatten(),
+                    None,
+                )
+            }
+            EmailBody::RefundConfirmation {
+                locale,
+                theme,
+                refund_id,
+                payment_id,
+                amount,
+                refunded_at,
+            } => {
+                let locale = locale.as_str();
+                let details = [
+                    (
+                        t!("customer_email.details.refund_id", locale = locale),
+                        refund_id,
+                    ),
+                    (
+                        t!("customer_email.details.payment_id", locale = locale),
+                        payment_id,
+                    ),
+                    (t!("customer_email.details.amount", locale = locale), amount),
+                    (
+                        t!("customer_email.details.date", locale = locale),
+                        refunded_at,
+                    ),
+                ];
+                get_customer_email_body(
+                    locale,
+                    &theme,
+                    &t!("customer_email.refund_confirmation.title", locale = locale),
+                    &t!(
+                        "customer_email.refund_confirmation.message",
+                        locale = locale
+                    ),
+                    details.into_iter(),
+                    None,
+                )
+            }
+            EmailBody::PaymentLinkReminder {
+                locale,
+                theme,
+                payment_id,
+                amount,
+                description,
+                link,
+                expires_at,
+            } => {
+                let locale = locale.as_str();
+                let details = [
+                    Some((
+                        t!("customer_email.details.payment_id", locale = locale),
+                        payment_id,
+                    )),
+                    Some((t!("customer_email.details.amount", locale = locale), amount)),
+                    description.map(|description| {
+                        (
+                            t!("customer_email.details.description", locale = locale),
+                            description,
+                        )
+                    }),
+                    Some((
+                        t!("customer_email.details.expires_at", locale = locale),
+                        expires_at,
+                    )),
+                ];
+                get_customer_email_body(
+                    locale,
+                    &theme,
+                    &t!(
+                        "customer_email.payment_link_reminder.title",
+                        locale = locale
+                    ),
+                    &t!(
+                        "customer_email.payment_link_reminder.message",
+                        locale = locale
+                    ),
+                    details.into_iter().flatten(),
+                    Some((
+                        &*t!(
+                            "customer_email.payment_link_reminder.action",
+                            locale = locale
+                        ),
+                        link.as_str(),
+                    )),
+                )
+            }
         }
     }
+
+    /// Renders an email sent to a customer from the translated title, message and labels of its
+    /// details, along with an optional button linking to the action the customer has to take.
+    fn get_customer_email_body<L: std::fmt::Display>(
+        locale: &str,
+        theme: &CustomerEmailTheme,
+        title: &str,
+        message: &str,
+        details: impl Iterator<Item = (L, String)>,
+        action: Option<(&str, &str)>,
+    ) -> String {
+        let brand_name = escape_html(&theme.brand_name);
+        let primary_color = theme.get_primary_color();
+        let header = match &theme.logo_url {
+            Some(logo_url) => format!(
+                r#"<img src="{}" alt="{brand_name}" style="height: 2rem; width: auto" />"#,
+                escape_html(logo_url)
+            ),
+            None => {
+                format!(r#"<span style="font-size: 1.25rem; font-weight: 600">{brand_name}</span>"#)
+            }
+        };
+        let details = details
+            .map(|(label, value)| {
+                format!(
+                    r#"<tr><td style="padding: 6px 0; color: #666666">{label}</td><td style="padding: 6px 0; text-align: end; color: #111326">{}</td></tr>"#,
+                    escape_html(&value)
+                )
+            })
+            .collect::<String>();
+        let action = action
+            .map(|(label, link)| {
+                format!(
+                    r#"<tr><td style="padding: 12px 24px; text-align: center"><a href="{}" target="_blank" style="display: inline-block; padding: 12px 32px; border-radius: 4px; background-color: {primary_color}; color: #ffffff; text-decoration: none; font-weight: 600">{label}</a></td></tr>"#,
+                    escape_html(link)
+                )
+            })
+            .unwrap_or_default();
+        let support = theme
+            .support_email
+            .as_ref()
+            .map(|support_email| {
+                let support_email = escape_html(support_email);
+                format!(
+                    r#"{} <a href="mailto:{support_email}">{support_email}</a>"#,
+                    t!("customer_email.support", locale = locale)
+                )
+            })
+            .unwrap_or_default();
+
+        format!(
+            include_str!("assets/customer_email.html"),
+            locale = locale,
+            title = title,
+            header = header,
+            primary_color = primary_color,
+            message = message,
+            details = details,
+            action = action,
+            support = support,
+            brand_name = brand_name,
+        )
+    }
+
+    /// Escapes the characters of a value with a special meaning in HTML, as values in customer
+    /// emails are provided by merchants and customers.
+    pub(super) fn escape_html(value: &str) -> String {
+        value
+            .chars()
+            .fold(String::with_capacity(value.len()), |mut escaped, c| {
+                match c {
+                    '&' => escaped.push_str("&amp;"),
+                    '<' => escaped.push_str("&lt;"),
+                    '>' => escaped.push_str("&gt;"),
+                    '"' => escaped.push_str("&quot;"),
+                    '\'' => escaped.push_str("&#39;"),
+                    c => escaped.push(c),
+                }
+                escaped
+            })
+    }
 }
 
 #[derive(serde::Serialize, serde::Deserialize)]
@@ -781,3 +1035,232 @@ impl EmailData for RoleDeleted {
         })
     }
 }
+
+pub struct PaymentReceipt {
+    pub recipient_email: pii::Email,
+    pub locale: String,
+    pub theme: CustomerEmailTheme,
+    pub payment_id: String,
+    pub amount: String,
+    pub description: Option<String>,
+    pub paid_at: String,
+}
+
+#[async_trait::async_trait]
+impl EmailData for PaymentReceipt {
+    async fn get_email_data(&self, _base_url: &str) -> CustomResult<EmailContents, EmailError> {
+        let subject = format!(
+            "{}: {}",
+            self.theme.brand_name,
+            t!(
+                "customer_email.payment_receipt.subject",
+                locale = self.locale.as_str()
+            )
+        );
+        let body = html::get_html_body(EmailBody::PaymentReceipt {
+            locale: self.locale.clone(),
+            theme: self.theme.clone(),
+            payment_id: self.payment_id.clone(),
+            amount: self.amount.clone(),
+            description: self.description.clone(),
+            paid_at: self.paid_at.clone(),
+        });
+
+        Ok(EmailContents {
+            subject,
+            body: external_services::email::IntermediateString::new(body),
+            recipient: self.recipient_email.clone(),
+        })
+    }
+}
+
+pub struct RefundConfirmation {
+    pub recipient_email: pii::Email,
+    pub locale: String,
+    pub theme: CustomerEmailTheme,
+    pub refund_id: String,
+    pub payment_id: String,
+    pub amount: String,
+    pub refunded_at: String,
+}
+
+#[async_trait::async_trait]
+impl EmailData for RefundConfirmation {
+    async fn get_email_data(&self, _base_url: &str) -> CustomResult<EmailContents, EmailError> {
+        let subject = format!(
+            "{}: {}",
+            self.theme.brand_name,
+            t!(
+                "customer_email.refund_confirmation.subject",
+                locale = self.locale.as_str()
+            )
+        );
+        let body = html::get_html_body(EmailBody::RefundConfirmation {
+            locale: self.locale.clone(),
+            theme: self.theme.clone(),
+            refund_id: self.refund_id.clone(),
+            payment_id: self.payment_id.clone(),
+            amount: self.amount.clone(),
+            refunded_at: self.refunded_at.clone(),
+        });
+
+        Ok(EmailContents {
+            subject,
+            body: external_services::email::IntermediateString::new(body),
+            recipient: self.recipient_email.clone(),
+        })
+    }
+}
+
+pub struct PaymentLinkReminder {
+    pub recipient_email: pii::Email,
+    pub locale: String,
+    pub theme: CustomerEmailTheme,
+    pub payment_id: String,
+    pub amount: String,
+    pub description: Option<String>,
+    pub link: String,
+    pub expires_at: String,
+}
+
+#[async_trait::async_trait]
+impl EmailData for PaymentLinkReminder {
+    async fn get_email_data(&self, _base_url: &str) -> CustomResult<EmailContents, EmailError> {
+        let subject = format!(
+            "{}: {}",
+            self.theme.brand_name,
+            t!(
+                "customer_email.payment_link_reminder.subject",
+                locale = self.locale.as_str()
+            )
+        );
+        let body = html::get_html_body(EmailBody::PaymentLinkReminder {
+            locale: self.locale.clone(),
+            theme: self.theme.clone(),
+            payment_id: self.payment_id.clone(),
+            amount: self.amount.clone(),
+            description: self.description.clone(),
+            link: self.link.clone(),
+            expires_at: self.expires_at.clone(),
+        });
+
+        Ok(EmailContents {
+            subject,
+            body: external_services::email::IntermediateString::new(body),
+            recipient: self.recipient_email.clone(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use std::str::FromStr;
+
+    use super::*;
+
+    fn get_theme(primary_color: &str) -> CustomerEmailTheme {
+        CustomerEmailTheme {
+            brand_name: "Acme <Store>".to_string(),
+            logo_url: None,
+            primary_color: primary_color.to_string(),
+            support_email: Some("support@acme.com".to_string()),
+        }
+    }
+
+    #[test]
+    fn test_escape_html() {
+        assert_eq!(
+            html::escape_html(r#"<a href="x">Tom & Jerry's</a>"#),
+            "&lt;a href=&quot;x&quot;&gt;Tom &amp; Jerry&#39;s&lt;/a&gt;"
+        );
+        assert_eq!(html::escape_html("plain text"), "plain text");
+    }
+
+    #[test]
+    fn test_get_primary_color() {
+        assert_eq!(get_theme("#006DF9").get_primary_color(), "#006DF9");
+        assert_eq!(get_theme("#fff").get_primary_color(), "#fff");
+
+        for invalid_color in ["006DF9", "#12345", "#00ZZ00", "red; background: url(x)", ""] {
+            assert_eq!(
+                get_theme(invalid_color).get_primary_color(),
+                CustomerEmailTheme::DEFAULT_PRIMARY_COLOR
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn test_payment_receipt_email() {
+        let email = PaymentReceipt {
+            recipient_email: pii::Email::from_str("customer@example.com").unwrap(),
+            locale: "en".to_string(),
+            theme: get_theme("#112233"),
+            payment_id: "pay_123".to_string(),
+            amount: "10.00 USD".to_string(),
+            description: Some("<script>alert(1)</script>".to_string()),
+            paid_at: "1 January 2024".to_string(),
+        };
+        let contents = email.get_email_data("").await.unwrap();
+        let body = contents.body.into_inner();
+
+        assert_eq!(contents.subject, "Acme <Store>: Payment receipt");
+        assert!(body.contains("Payment successful"));
+        assert!(body.contains("Acme &lt;Store&gt;"));
+        assert!(body.contains("border-top: 4px solid #112233"));
+        assert!(body.contains("pay_123"));
+        assert!(body.contains("10.00 USD"));
+        assert!(body.contains("&lt;script&gt;alert(1)&lt;/script&gt;"));
+        assert!(!body.contains("<script>"));
+        assert!(body.contains(r#"<a href="mailto:support@acme.com">support@acme.com</a>"#));
+    }
+
+    #[tokio::test]
+    async fn test_refund_confirmation_email() {
+        let email = RefundConfirmation {
+            recipient_email: pii::Email::from_str("customer@example.com").unwrap(),
+            locale: "en".to_string(),
+            theme: get_theme("red;display:none"),
+            refund_id: "ref_123".to_string(),
+            payment_id: "pay_123".to_string(),
+            amount: "5.00 USD".to_string(),
+            refunded_at: "2 January 2024".to_string(),
+        };
+        let contents = email.get_email_data("").await.unwrap();
+        let body = contents.body.into_inner();
+
+        assert_eq!(contents.subject, "Acme <Store>: Refund confirmation");
+        assert!(body.contains("Refund processed"));
+        assert!(body.contains("ref_123"));
+        assert!(body.contains("pay_123"));
+        assert!(body.contains("5.00 USD"));
+        assert!(body.contains(&format!(
+            "border-top: 4px solid {}",
+            CustomerEmailTheme::DEFAULT_PRIMARY_COLOR
+        )));
+        assert!(!body.contains("display:none"));
+    }
+
+    #[tokio::test]
+    async fn test_payment_link_reminder_email() {
+        let email = PaymentLinkReminder {
+            recipient_email: pii::Email::from_str("customer@example.com").unwrap(),
+            locale: "en".to_string(),
+            theme: get_theme("#112233"),
+            payment_id: "pay_123".to_string(),
+            amount: "10.00 USD".to_string(),
+            description: None,
+            link: "https://pay.acme.com/link?a=1&b=2".to_string(),
+            expires_at: "3 January 2024".to_string(),
+        };
+        let contents = email.get_email_data("").await.unwrap();
+        let body = contents.body.into_inner();
+
+        assert_eq!(contents.subject, "Acme <Store>: Your payment is pending");
+        assert!(body.contains("Complete your payment"));
+        assert!(body.contains("3 January 2024"));
+        assert!(body.contains(r#"<a href="https://pay.acme.com/link?a=1&amp;b=2""#));
+        assert!(body.contains("background-color: #112233"));
+        assert!(body.contains("Pay now"));
+    }
+}
@@ -163,6 +163,7 @@ pub struct KafkaSettings {
     authentication_analytics_topic: String,
     routing_logs_topic: String,
     revenue_recovery_topic: String,
+    card_testing_guard_events_topic: String,
 }
 
 impl KafkaSettings {
@@ -279,6 +280,7 @@ pub struct KafkaProducer {
     ckh_database_name: Option<String>,
     routing_logs_topic: String,
     revenue_recovery_topic: String,
+    card_testing_guard_events_topic: String,
 }
 
 struct RdKafkaProducer(ThreadedProducer<DefaultProducerContext>);
@@ -330,6 +332,7 @@ impl KafkaProducer {
             ckh_database_name: None,
             routing_logs_topic: conf.routing_logs_topic.clone(),
             revenue_recovery_topic: conf.revenue_recovery_topic.clone(),
+            card_testing_guard_events_topic: conf.card_testing_guard_events_topic.clone(),
         })
     }
 
@@ -669,6 +672,7 @@ impl KafkaProducer {
             EventType::Authentication => &self.authentication_analytics_topic,
             EventType::RoutingApiLogs => &self.routing_logs_topic,
             EventType::RevenueRecovery => &self.revenue_recovery_topic,
+            EventType::CardTestingGuard => &self.card_testing_guard_events_topic,
         }
     }
 }
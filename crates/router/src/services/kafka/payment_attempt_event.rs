@@ -6,6 +6,8 @@ use common_utils::{id_type, types::MinorUnit};
 use diesel_models::enums as storage_enums;
 #[cfg(feature = "v2")]
 use diesel_models::payment_attempt;
+#[cfg(feature = "v1")]
+use diesel_models::payment_attempt::RetryHop;
 #[cfg(feature = "v2")]
 use hyperswitch_domain_models::{
     address, payments::payment_attempt::PaymentAttemptFeatureMetadata,
@@ -75,6 +77,8 @@ pub struct KafkaPaymentAttemptEvent<'a> {
     pub signature_network: Option<common_enums::CardNetwork>,
     pub is_issuer_regulated: Option<bool>,
     pub processor_merchant_id: &'a id_type::MerchantId,
+    pub retry_hop_reason: Option<storage_enums::RetryHopReason>,
+    pub retry_hop: Option<&'a RetryHop>,
 }
 
 #[cfg(feature = "v1")]
@@ -146,6 +150,8 @@ impl<'a> KafkaPaymentAttemptEvent<'a> {
                 .and_then(|data| data.signature_network.clone()),
             is_issuer_regulated: card_payment_method_data.and_then(|data| data.is_regulated),
             processor_merchant_id: &attempt.processor_merchant_id,
+            retry_hop_reason: attempt.retry_hop.as_ref().map(|hop| hop.reason),
+            retry_hop: attempt.retry_hop.as_ref(),
         }
     }
 }
@@ -184,7 +184,9 @@ impl ForeignTryFrom<domain::Profile> for ProfileResponse {
             enable_payment_response_hash: item.enable_payment_response_hash,
             payment_response_hash_key: item.payment_response_hash_key,
             redirect_to_merchant_with_http_post: item.redirect_to_merchant_with_http_post,
-            webhook_details: item.webhook_details.map(ForeignInto::foreign_into),
+            webhook_details: item.webhook_details.map(|webhook_details| {
+                WebhookDetails::foreign_from(webhook_details).mask_webhook_secrets()
+            }),
             metadata: item.metadata,
             routing_algorithm: item.routing_algorithm,
             intent_fulfillment_time: item.intent_fulfillment_time,
@@ -286,7 +288,9 @@ impl ForeignTryFrom<domain::Profile> for ProfileResponse {
             enable_payment_response_hash: item.enable_payment_response_hash,
             payment_response_hash_key: item.payment_response_hash_key,
             redirect_to_merchant_with_http_post: item.redirect_to_merchant_with_http_post,
-            webhook_details: item.webhook_details.map(ForeignInto::foreign_into),
+            webhook_details: item.webhook_details.map(|webhook_details| {
+                WebhookDetails::foreign_from(webhook_details).mask_webhook_secrets()
+            }),
             metadata: item.metadata,
             applepay_verified_domains: item.applepay_verified_domains,
             payment_link_config: item.payment_link_config.map(ForeignInto::foreign_into),
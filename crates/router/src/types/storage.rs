@@ -10,6 +10,7 @@ pub mod callback_mapper;
 pub mod capture;
 pub mod cards_info;
 pub mod configs;
+pub mod customer_email;
 pub mod customers;
 pub mod dashboard_metadata;
 pub mod dispute;
@@ -18,6 +19,7 @@ pub mod enums;
 pub mod ephemeral_key;
 pub mod events;
 pub mod file;
+pub mod forex_rate_snapshot;
 pub mod fraud_check;
 pub mod generic_link;
 pub mod gsm;
@@ -46,6 +48,7 @@ pub mod unified_translations;
 pub mod user;
 pub mod user_authentication_method;
 pub mod user_role;
+pub mod webhook_signing_key;
 
 pub use diesel_models::{
     process_tracker::business_status, ProcessTracker, ProcessTrackerNew, ProcessTrackerRunner,
@@ -71,11 +74,11 @@ pub use scheduler::db::process_tracker;
 pub use self::{
     address::*, api_keys::*, authentication::*, authorization::*, blocklist::*,
     blocklist_fingerprint::*, blocklist_lookup::*, business_profile::*, callback_mapper::*,
-    capture::*, cards_info::*, configs::*, customers::*, dashboard_metadata::*, dispute::*,
-    dynamic_routing_stats::*, ephemeral_key::*, events::*, file::*, fraud_check::*,
-    generic_link::*, gsm::*, hyperswitch_ai_interaction::*, locker_mock_up::*, mandate::*,
-    merchant_account::*, merchant_connector_account::*, merchant_key_store::*, payment_link::*,
-    payment_method::*, process_tracker::*, refund::*, reverse_lookup::*, role::*,
-    routing_algorithm::*, unified_translations::*, user::*, user_authentication_method::*,
-    user_role::*,
+    capture::*, cards_info::*, configs::*, customer_email::*, customers::*, dashboard_metadata::*,
+    dispute::*, dynamic_routing_stats::*, ephemeral_key::*, events::*, file::*,
+    forex_rate_snapshot::*, fraud_check::*, generic_link::*, gsm::*, hyperswitch_ai_interaction::*,
+    locker_mock_up::*, mandate::*, merchant_account::*, merchant_connector_account::*,
+    merchant_key_store::*, payment_link::*, payment_method::*, process_tracker::*, refund::*,
+    reverse_lookup::*, role::*, routing_algorithm::*, unified_translations::*, user::*,
+    user_authentication_method::*, user_role::*, webhook_signing_key::*,
 };
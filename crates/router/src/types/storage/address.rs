@@ -1,3 +1,5 @@
-pub use diesel_models::address::{Address, AddressNew, AddressUpdateInternal};
+pub use diesel_models::address::{
+    Address, AddressEncryptionUpdateInternal, AddressNew, AddressUpdateInternal,
+};
 
 pub use crate::types::domain::AddressUpdate;
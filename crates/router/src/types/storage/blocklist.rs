@@ -1 +1,6 @@
 pub use diesel_models::blocklist::{Blocklist, BlocklistNew};
+
+#[derive(Debug, serde::Deserialize, serde::Serialize, Clone)]
+pub struct BlocklistPurgeTrackingData {
+    pub merchant_id: common_utils::id_type::MerchantId,
+}
@@ -0,0 +1,22 @@
+/// The kinds of emails sent to the customers of a merchant.
+#[derive(
+    Clone, Copy, Debug, Eq, PartialEq, serde::Deserialize, serde::Serialize, strum::Display,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum CustomerEmailType {
+    PaymentReceipt,
+    RefundConfirmation,
+    PaymentLinkReminder,
+}
+
+#[derive(Debug, serde::Deserialize, serde::Serialize, Clone)]
+pub struct CustomerEmailTrackingData {
+    pub email_type: CustomerEmailType,
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub profile_id: common_utils::id_type::ProfileId,
+    pub payment_id: common_utils::id_type::PaymentId,
+    pub refund_id: Option<String>,
+    pub payment_link_id: Option<String>,
+    pub locale: String,
+}
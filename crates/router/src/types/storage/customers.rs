@@ -1,3 +1,5 @@
+#[cfg(feature = "v1")]
+pub use diesel_models::customers::CustomerEncryptionUpdateInternal;
 pub use diesel_models::customers::{Customer, CustomerNew, CustomerUpdateInternal};
 
 #[cfg(feature = "v2")]
@@ -1 +1,20 @@
 pub use diesel_models::merchant_key_store::MerchantKeyStore;
+
+#[derive(Debug, serde::Deserialize, serde::Serialize, Clone)]
+pub struct KeyRotationTrackingData {
+    /// The merchant whose data encryption key is rotated, absent for a master key rotation
+    pub merchant_id: Option<common_utils::id_type::MerchantId>,
+    pub key_version: Option<i32>,
+    pub stage: api_models::admin::KeyRotationStage,
+    /// Primary key of the last record re-encrypted in the current stage
+    #[serde(default)]
+    pub cursor: String,
+    #[serde(default)]
+    pub key_stores_re_encrypted: u64,
+    #[serde(default)]
+    pub customers_re_encrypted: u64,
+    #[serde(default)]
+    pub addresses_re_encrypted: u64,
+    #[serde(default)]
+    pub payment_methods_re_encrypted: u64,
+}
@@ -1,5 +1,7 @@
 use api_models::payment_methods;
 use diesel_models::enums;
+#[cfg(feature = "v1")]
+pub use diesel_models::payment_method::PaymentMethodEncryptionUpdateInternal;
 pub use diesel_models::payment_method::{
     PaymentMethod, PaymentMethodNew, PaymentMethodUpdate, PaymentMethodUpdateInternal,
     TokenizeCoreWorkflow,
@@ -0,0 +1,3 @@
+pub use diesel_models::webhook_signing_key::{
+    WebhookSigningKey, WebhookSigningKeyNew, WebhookSigningKeyUpdate,
+};
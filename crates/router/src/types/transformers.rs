@@ -9,6 +9,7 @@ use common_utils::{
     crypto::Encryptable,
     ext_traits::{Encode, StringExt, ValueExt},
     fp_utils::when,
+    id_type::GenerateId,
     pii,
     types::ConnectorTransactionIdTrait,
 };
@@ -654,6 +655,15 @@ impl
             api_key: StrongSecret::from(plaintext_api_key.peek().to_owned()),
             created: api_key.created_at,
             expiration: api_key.expires_at.into(),
+            profile_id: api_key.profile_id,
+            scopes: api_key.scopes.map(|scopes| {
+                scopes
+                    .0
+                    .into_iter()
+                    .map(ForeignFrom::foreign_from)
+                    .collect()
+            }),
+            ip_allowlist: api_key.ip_allowlist.map(|ip_allowlist| ip_allowlist.0),
         }
     }
 }
@@ -668,6 +678,33 @@ impl ForeignFrom<diesel_models::api_keys::ApiKey> for api_models::api_keys::Retr
             prefix: api_key.prefix.into(),
             created: api_key.created_at,
             expiration: api_key.expires_at.into(),
+            profile_id: api_key.profile_id,
+            scopes: api_key.scopes.map(|scopes| {
+                scopes
+                    .0
+                    .into_iter()
+                    .map(ForeignFrom::foreign_from)
+                    .collect()
+            }),
+            ip_allowlist: api_key.ip_allowlist.map(|ip_allowlist| ip_allowlist.0),
+        }
+    }
+}
+
+impl ForeignFrom<api_models::api_keys::ApiKeyScope> for diesel_models::api_keys::ApiKeyScope {
+    fn foreign_from(scope: api_models::api_keys::ApiKeyScope) -> Self {
+        Self {
+            resource: scope.resource,
+            access: scope.access,
+        }
+    }
+}
+
+impl ForeignFrom<diesel_models::api_keys::ApiKeyScope> for api_models::api_keys::ApiKeyScope {
+    fn foreign_from(scope: diesel_models::api_keys::ApiKeyScope) -> Self {
+        Self {
+            resource: scope.resource,
+            access: scope.access,
         }
     }
 }
@@ -1295,6 +1332,23 @@ impl ForeignFrom<storage::PaymentAttempt> for payments::PaymentAttemptResponse {
             error_details: payment_attempt
                 .error_details
                 .map(payments::PaymentErrorDetails::foreign_from),
+            retry_hop: payment_attempt
+                .retry_hop
+                .map(payments::PaymentRetryHop::foreign_from),
+        }
+    }
+}
+
+#[cfg(feature = "v1")]
+impl ForeignFrom<diesel_models::RetryHop> for payments::PaymentRetryHop {
+    fn foreign_from(retry_hop: diesel_models::RetryHop) -> Self {
+        Self {
+            reason: retry_hop.reason,
+            previous_attempt_id: retry_hop.previous_attempt_id,
+            previous_connector: retry_hop.previous_connector,
+            error_code: retry_hop.error_code,
+            error_category: retry_hop.error_category,
+            skipped_connectors: retry_hop.skipped_connectors,
         }
     }
 }
@@ -1830,6 +1884,75 @@ impl ForeignFrom<gsm_api_types::GsmCreateRequest>
             standardised_code: value.standardised_code,
             description: value.description,
             user_guidance_message: value.user_guidance_message,
+            code_match_type: value.code_match_type.unwrap_or_default(),
+            message_match_type: value.message_match_type.unwrap_or_default(),
+        }
+    }
+}
+
+impl ForeignFrom<gsm_api_types::GsmRecord> for hyperswitch_domain_models::gsm::GatewayStatusMap {
+    fn foreign_from(value: gsm_api_types::GsmRecord) -> Self {
+        Self {
+            connector: value.connector,
+            flow: value.flow,
+            sub_flow: value.sub_flow,
+            code: value.code,
+            message: value.message,
+            status: value.status,
+            router_error: value.router_error,
+            unified_code: value.unified_code,
+            unified_message: value.unified_message,
+            error_category: value.error_category,
+            feature_data: common_types::domain::GsmFeatureData::Retry(
+                common_types::domain::RetryFeatureData {
+                    step_up_possible: value.step_up_possible,
+                    clear_pan_possible: value.clear_pan_possible,
+                    alternate_network_possible: value.alternate_network_possible,
+                    decision: value.decision,
+                },
+            ),
+            feature: api_enums::GsmFeature::Retry,
+            standardised_code: value.standardised_code,
+            description: value.description,
+            user_guidance_message: value.user_guidance_message,
+            code_match_type: value.code_match_type,
+            message_match_type: value.message_match_type,
+        }
+    }
+}
+
+impl ForeignFrom<hyperswitch_domain_models::gsm::GatewayStatusMap> for gsm_api_types::GsmRecord {
+    fn foreign_from(value: hyperswitch_domain_models::gsm::GatewayStatusMap) -> Self {
+        let retry_feature_data = value.feature_data.get_retry_feature_data();
+        Self {
+            connector: value.connector,
+            flow: value.flow,
+            sub_flow: value.sub_flow,
+            code: value.code,
+            code_match_type: value.code_match_type,
+            message: value.message,
+            message_match_type: value.message_match_type,
+            status: value.status,
+            router_error: value.router_error,
+            decision: value.feature_data.get_decision(),
+            step_up_possible: retry_feature_data
+                .as_ref()
+                .map(|data| data.is_step_up_possible())
+                .unwrap_or(false),
+            clear_pan_possible: retry_feature_data
+                .as_ref()
+                .map(|data| data.is_clear_pan_possible())
+                .unwrap_or(false),
+            alternate_network_possible: retry_feature_data
+                .map(|data| data.is_alternate_network_possible())
+                .unwrap_or(false),
+            unified_code: value.unified_code,
+            unified_message: value.unified_message,
+            error_category: value.error_category,
+            standardised_code: value.standardised_code,
+            description: value.description,
+            user_guidance_message: value.user_guidance_message,
+            line_number: None,
         }
     }
 }
@@ -1938,6 +2061,8 @@ impl ForeignFrom<hyperswitch_domain_models::gsm::GatewayStatusMap> for gsm_api_t
             standardised_code: value.standardised_code,
             description: value.description,
             user_guidance_message: value.user_guidance_message,
+            code_match_type: value.code_match_type,
+            message_match_type: value.message_match_type,
         }
     }
 }
@@ -2054,6 +2179,7 @@ impl TryFrom<domain::Event> for api_models::webhook_events::EventListItemRespons
             is_delivery_successful: item.is_overall_delivery_successful,
             initial_attempt_id,
             created: item.created_at,
+            webhook_endpoint_id: item.webhook_endpoint_id,
         })
     }
 }
@@ -2196,6 +2322,11 @@ impl ForeignFrom<api_models::admin::CardTestingGuardConfig>
             },
             customer_id_blocking_threshold: item.customer_id_blocking_threshold,
             card_testing_guard_expiry: item.card_testing_guard_expiry,
+            velocity_rules: item
+                .velocity_rules
+                .into_iter()
+                .map(ForeignFrom::foreign_from)
+                .collect(),
         }
     }
 }
@@ -2221,6 +2352,39 @@ impl ForeignFrom<diesel_models::business_profile::CardTestingGuardConfig>
             },
             customer_id_blocking_threshold: item.customer_id_blocking_threshold,
             card_testing_guard_expiry: item.card_testing_guard_expiry,
+            velocity_rules: item
+                .velocity_rules
+                .into_iter()
+                .map(ForeignFrom::foreign_from)
+                .collect(),
+        }
+    }
+}
+
+impl ForeignFrom<api_models::admin::VelocityRule>
+    for diesel_models::business_profile::VelocityRule
+{
+    fn foreign_from(item: api_models::admin::VelocityRule) -> Self {
+        Self {
+            name: item.name,
+            group_by: item.group_by,
+            max_attempts: item.max_attempts,
+            window_in_mins: item.window_in_mins,
+            action: item.action,
+        }
+    }
+}
+
+impl ForeignFrom<diesel_models::business_profile::VelocityRule>
+    for api_models::admin::VelocityRule
+{
+    fn foreign_from(item: diesel_models::business_profile::VelocityRule) -> Self {
+        Self {
+            name: item.name,
+            group_by: item.group_by,
+            max_attempts: item.max_attempts,
+            window_in_mins: item.window_in_mins,
+            action: item.action,
         }
     }
 }
@@ -2240,7 +2404,12 @@ impl ForeignFrom<api_models::admin::WebhookDetails>
             payment_statuses_enabled: item.payment_statuses_enabled,
             refund_statuses_enabled: item.refund_statuses_enabled,
             payout_statuses_enabled: item.payout_statuses_enabled,
-            multiple_webhooks_list: None,
+            multiple_webhooks_list: item.multiple_webhooks_list.map(|webhook_endpoints| {
+                webhook_endpoints
+                    .into_iter()
+                    .map(ForeignInto::foreign_into)
+                    .collect()
+            }),
         }
     }
 }
@@ -2260,6 +2429,44 @@ impl ForeignFrom<diesel_models::business_profile::WebhookDetails>
             payment_statuses_enabled: item.payment_statuses_enabled,
             refund_statuses_enabled: item.refund_statuses_enabled,
             payout_statuses_enabled: item.payout_statuses_enabled,
+            multiple_webhooks_list: item.multiple_webhooks_list.map(|webhook_endpoints| {
+                webhook_endpoints
+                    .into_iter()
+                    .map(ForeignInto::foreign_into)
+                    .collect()
+            }),
+        }
+    }
+}
+
+impl ForeignFrom<api_models::admin::MultipleWebhookDetail>
+    for diesel_models::business_profile::MultipleWebhookDetail
+{
+    fn foreign_from(item: api_models::admin::MultipleWebhookDetail) -> Self {
+        Self {
+            webhook_endpoint_id: item
+                .webhook_endpoint_id
+                .unwrap_or_else(common_utils::id_type::WebhookEndpointId::generate),
+            webhook_url: item.webhook_url,
+            webhook_secret: item.webhook_secret,
+            events: item.events,
+            status: item
+                .status
+                .unwrap_or(common_enums::OutgoingWebhookEndpointStatus::Active),
+        }
+    }
+}
+
+impl ForeignFrom<diesel_models::business_profile::MultipleWebhookDetail>
+    for api_models::admin::MultipleWebhookDetail
+{
+    fn foreign_from(item: diesel_models::business_profile::MultipleWebhookDetail) -> Self {
+        Self {
+            webhook_endpoint_id: Some(item.webhook_endpoint_id),
+            webhook_url: item.webhook_url,
+            webhook_secret: item.webhook_secret,
+            events: item.events,
+            status: Some(item.status),
         }
     }
 }
@@ -1,3 +1,5 @@
+pub mod providers;
+
 use std::{
     collections::HashMap,
     ops::Deref,
@@ -5,39 +7,80 @@ use std::{
     sync::{Arc, LazyLock},
 };
 
-use api_models::enums;
+use api_models::{currency::ForexProvider, enums};
 use common_utils::{date_time, errors::CustomResult, events::ApiEventMetric, ext_traits::AsyncExt};
 use currency_conversion::types::{CurrencyFactors, ExchangeRates};
 use error_stack::ResultExt;
-use masking::PeekInterface;
 use redis_interface::DelReply;
 use router_env::{instrument, tracing};
 use rust_decimal::Decimal;
-use strum::IntoEnumIterator;
+use time::PrimitiveDateTime;
 use tokio::sync::RwLock;
 use tracing_futures::Instrument;
 
+use self::providers::ForexRatesProvider;
 use crate::{
     logger,
-    routes::app::settings::{Conversion, DefaultExchangeRates},
-    services, SessionState,
+    routes::app::settings::{Conversion, DefaultExchangeRates, ForexApi},
+    types::storage,
+    SessionState,
 };
+
 const REDIX_FOREX_CACHE_KEY: &str = "{forex_cache}_lock";
-const REDIX_FOREX_CACHE_DATA: &str = "{forex_cache}_data";
-const FOREX_API_TIMEOUT: u64 = 5;
-const FOREX_BASE_URL: &str = "https://openexchangerates.org/api/latest.json?app_id=";
-const FOREX_BASE_CURRENCY: &str = "&base=USD";
-const FALLBACK_FOREX_BASE_URL: &str = "http://apilayer.net/api/live?access_key=";
-const FALLBACK_FOREX_API_CURRENCY_PREFIX: &str = "USD";
+// Versioned since the provider and the snapshot of the rates were added to the cached entries,
+// which the entries cached by earlier versions lack
+const REDIX_FOREX_CACHE_DATA: &str = "{forex_cache}_data_v2";
+/// The number of snapshots fetched at a time when listing the snapshots valid within a time range
+const FOREX_RATE_SNAPSHOTS_PAGE_SIZE: i64 = 100;
 
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct FxExchangeRatesCacheEntry {
     pub data: Arc<ExchangeRates>,
     timestamp: i64,
+    /// The provider from which the rates were fetched
+    pub provider: ForexProvider,
+    /// Identifies the snapshot of rates, so that the rates used for a conversion can be traced
+    pub snapshot_id: String,
+    /// The percentage deducted from the amounts converted using the rates
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub markup_percentage: Option<Decimal>,
+}
+
+/// The source of a set of cached rates
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
+enum ForexRatesSource {
+    /// The providers configured in the forex settings, in the order of preference
+    ConfiguredProviders,
+    /// A single provider, requested explicitly
+    Provider(ForexProvider),
+}
+
+impl ForexRatesSource {
+    fn get_providers(self, forex_api: &ForexApi) -> Vec<ForexProvider> {
+        match self {
+            Self::ConfiguredProviders => forex_api.get_providers(),
+            Self::Provider(provider) => vec![provider],
+        }
+    }
+
+    fn get_redis_lock_key(self) -> String {
+        match self {
+            Self::ConfiguredProviders => REDIX_FOREX_CACHE_KEY.to_string(),
+            Self::Provider(provider) => format!("{REDIX_FOREX_CACHE_KEY}_{provider}"),
+        }
+    }
+
+    fn get_redis_data_key(self) -> String {
+        match self {
+            Self::ConfiguredProviders => REDIX_FOREX_CACHE_DATA.to_string(),
+            Self::Provider(provider) => format!("{REDIX_FOREX_CACHE_DATA}_{provider}"),
+        }
+    }
 }
 
-static FX_EXCHANGE_RATES_CACHE: LazyLock<RwLock<Option<FxExchangeRatesCacheEntry>>> =
-    LazyLock::new(|| RwLock::new(None));
+static FX_EXCHANGE_RATES_CACHE: LazyLock<
+    RwLock<HashMap<ForexRatesSource, FxExchangeRatesCacheEntry>>,
+> = LazyLock::new(|| RwLock::new(HashMap::new()));
 
 impl ApiEventMetric for FxExchangeRatesCacheEntry {}
 
@@ -81,16 +124,10 @@ pub enum ForexError {
     RedisWriteError,
     #[error("Not able to acquire write lock")]
     WriteLockNotAcquired,
-}
-
-#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
-struct ForexResponse {
-    pub rates: HashMap<String, FloatDecimal>,
-}
-
-#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
-struct FallbackForexResponse {
-    pub quotes: HashMap<String, FloatDecimal>,
+    #[error("Error accessing forex rate snapshots in the database")]
+    DatabaseError,
+    #[error("Forex rate snapshot not found")]
+    SnapshotNotFound,
 }
 
 #[derive(Debug, Copy, Clone, serde::Serialize, serde::Deserialize)]
@@ -105,30 +142,62 @@ impl Deref for FloatDecimal {
 }
 
 impl FxExchangeRatesCacheEntry {
-    fn new(exchange_rate: ExchangeRates) -> Self {
+    fn new(provider: ForexProvider, exchange_rate: ExchangeRates) -> Self {
+        let timestamp = date_time::now_unix_timestamp();
         Self {
             data: Arc::new(exchange_rate),
-            timestamp: date_time::now_unix_timestamp(),
+            timestamp,
+            provider,
+            snapshot_id: format!("{provider}_{timestamp}"),
+            markup_percentage: None,
         }
     }
+    /// The time at which the rates were fetched
+    pub fn get_created_at(&self) -> PrimitiveDateTime {
+        time::OffsetDateTime::from_unix_timestamp(self.timestamp)
+            .map(|created_at| PrimitiveDateTime::new(created_at.date(), created_at.time()))
+            .unwrap_or_else(|_| date_time::now())
+    }
     fn is_expired(&self, data_expiration_delay: u32) -> bool {
         self.timestamp + i64::from(data_expiration_delay) < date_time::now_unix_timestamp()
     }
 }
 
-async fn retrieve_forex_from_local_cache() -> Option<FxExchangeRatesCacheEntry> {
-    FX_EXCHANGE_RATES_CACHE.read().await.clone()
+async fn retrieve_forex_from_local_cache(
+    source: ForexRatesSource,
+) -> Option<FxExchangeRatesCacheEntry> {
+    FX_EXCHANGE_RATES_CACHE.read().await.get(&source).cloned()
 }
 
 async fn save_forex_data_to_local_cache(
+    source: ForexRatesSource,
     exchange_rates_cache_entry: FxExchangeRatesCacheEntry,
 ) -> CustomResult<(), ForexError> {
     let mut local = FX_EXCHANGE_RATES_CACHE.write().await;
-    *local = Some(exchange_rates_cache_entry);
+    local.insert(source, exchange_rates_cache_entry);
     logger::debug!("forex_log: forex saved in cache");
     Ok(())
 }
 
+impl TryFrom<storage::ForexRateSnapshot> for FxExchangeRatesCacheEntry {
+    type Error = error_stack::Report<ForexError>;
+    fn try_from(snapshot: storage::ForexRateSnapshot) -> Result<Self, Self::Error> {
+        let provider = ForexProvider::from_str(&snapshot.provider)
+            .change_context(ForexError::ParsingError)
+            .attach_printable("Unable to parse the provider of the forex rate snapshot")?;
+        let rates = serde_json::from_value::<ExchangeRates>(snapshot.rates)
+            .change_context(ForexError::ParsingError)
+            .attach_printable("Unable to parse the rates of the forex rate snapshot")?;
+        Ok(Self {
+            data: Arc::new(rates),
+            timestamp: snapshot.created_at.assume_utc().unix_timestamp(),
+            provider,
+            snapshot_id: snapshot.snapshot_id,
+            markup_percentage: None,
+        })
+    }
+}
+
 impl TryFrom<DefaultExchangeRates> for ExchangeRates {
     type Error = error_stack::Report<ForexError>;
     fn try_from(value: DefaultExchangeRates) -> Result<Self, Self::Error> {
@@ -158,16 +227,154 @@ impl From<Conversion> for CurrencyFactors {
     }
 }
 
+/// Fetches the rates of the providers configured in the forex settings
 #[instrument(skip_all)]
 pub async fn get_forex_rates(
     state: &SessionState,
     data_expiration_delay: u32,
 ) -> CustomResult<FxExchangeRatesCacheEntry, ForexError> {
-    if let Some(local_rates) = retrieve_forex_from_local_cache().await {
+    get_forex_rates_from_source(
+        state,
+        ForexRatesSource::ConfiguredProviders,
+        data_expiration_delay,
+    )
+    .await
+}
+
+/// Fetches the rates of the provider requested, or of the configured providers if no provider is
+/// requested. The rate table of the merchant is read from the configs table, while the rates of
+/// the other providers are shared by all merchants and cached.
+#[instrument(skip_all)]
+pub async fn get_forex_rates_from_provider(
+    state: &SessionState,
+    merchant_id: &common_utils::id_type::MerchantId,
+    provider: Option<ForexProvider>,
+) -> CustomResult<FxExchangeRatesCacheEntry, ForexError> {
+    let data_expiration_delay = state
+        .conf
+        .forex_api
+        .get_inner()
+        .data_expiration_delay_in_seconds;
+
+    match provider {
+        Some(ForexProvider::MerchantRateTable) => {
+            providers::MerchantRateTable {
+                merchant_id: merchant_id.clone(),
+            }
+            .fetch_rates(state)
+            .await
+        }
+        Some(provider) => {
+            get_forex_rates_from_source(
+                state,
+                ForexRatesSource::Provider(provider),
+                data_expiration_delay,
+            )
+            .await
+        }
+        None => get_forex_rates(state, data_expiration_delay).await,
+    }
+}
+
+/// Fetches the snapshot of rates that was valid at the given time, of the provider if provided.
+/// Snapshots are persisted whenever the rates of the providers shared by all merchants are
+/// refreshed, and are not kept for merchant rate tables.
+#[instrument(skip_all)]
+pub async fn get_forex_rates_as_of(
+    state: &SessionState,
+    provider: Option<ForexProvider>,
+    as_of: PrimitiveDateTime,
+) -> CustomResult<FxExchangeRatesCacheEntry, ForexError> {
+    if provider == Some(ForexProvider::MerchantRateTable) {
+        return Err(ForexError::ConfigurationError(
+            "historical rates are not kept for merchant rate tables".into(),
+        )
+        .into());
+    }
+
+    let provider = provider.map(|provider| provider.to_string());
+    let snapshot = state
+        .store
+        .find_latest_forex_rate_snapshot_as_of(provider.as_deref(), as_of)
+        .await
+        .change_context(ForexError::DatabaseError)?
+        .ok_or(ForexError::SnapshotNotFound)
+        .attach_printable_lazy(|| format!("No forex rate snapshot found as of {as_of}"))?;
+
+    FxExchangeRatesCacheEntry::try_from(snapshot)
+}
+
+/// Lists the snapshots of rates which were valid within the time range, latest first. The
+/// snapshots are paged backwards from the end of the time range, up to the snapshot which was
+/// valid at its start.
+pub async fn list_forex_rate_snapshots_valid_within(
+    state: &SessionState,
+    start_time: PrimitiveDateTime,
+    end_time: PrimitiveDateTime,
+) -> CustomResult<Vec<FxExchangeRatesCacheEntry>, ForexError> {
+    let mut snapshots = Vec::new();
+    let mut as_of = Some(end_time);
+
+    while let Some(page_as_of) = as_of {
+        let page = state
+            .store
+            .list_latest_forex_rate_snapshots_as_of(page_as_of, FOREX_RATE_SNAPSHOTS_PAGE_SIZE)
+            .await
+            .change_context(ForexError::DatabaseError)?;
+
+        as_of = if i64::try_from(page.len()).unwrap_or_default() < FOREX_RATE_SNAPSHOTS_PAGE_SIZE {
+            None
+        } else {
+            page.last()
+                .and_then(|snapshot| snapshot.created_at.checked_sub(time::Duration::MICROSECOND))
+        };
+
+        for snapshot in page {
+            let is_valid_at_start = snapshot.created_at <= start_time;
+            snapshots.push(FxExchangeRatesCacheEntry::try_from(snapshot)?);
+            if is_valid_at_start {
+                return Ok(snapshots);
+            }
+        }
+    }
+
+    Ok(snapshots)
+}
+
+async fn save_forex_rate_snapshot(
+    state: &SessionState,
+    forex: &FxExchangeRatesCacheEntry,
+) -> CustomResult<(), ForexError> {
+    let rates = serde_json::to_value(forex.data.as_ref())
+        .change_context(ForexError::ParsingError)
+        .attach_printable("Unable to serialize the forex rate snapshot")?;
+
+    state
+        .store
+        .insert_forex_rate_snapshot(storage::ForexRateSnapshotNew {
+            snapshot_id: forex.snapshot_id.clone(),
+            provider: forex.provider.to_string(),
+            rates,
+            created_at: date_time::now(),
+        })
+        .await
+        .change_context(ForexError::DatabaseError)
+        .attach_printable("Unable to save the forex rate snapshot")?;
+
+    logger::debug!(rate_snapshot_id = %forex.snapshot_id, "forex_log: forex snapshot saved");
+    Ok(())
+}
+
+async fn get_forex_rates_from_source(
+    state: &SessionState,
+    source: ForexRatesSource,
+    data_expiration_delay: u32,
+) -> CustomResult<FxExchangeRatesCacheEntry, ForexError> {
+    if let Some(local_rates) = retrieve_forex_from_local_cache(source).await {
         if local_rates.is_expired(data_expiration_delay) {
             // expired local data
             logger::debug!("forex_log: Forex stored in cache is expired");
-            call_forex_api_and_save_data_to_cache_and_redis(state, Some(local_rates)).await
+            call_forex_api_and_save_data_to_cache_and_redis(state, source, Some(local_rates)).await
         } else {
             // Valid data present in local
             logger::debug!("forex_log: forex found in cache");
@@ -175,27 +382,28 @@ pub async fn get_forex_rates(
         }
     } else {
         // No data in local
-        call_api_if_redis_forex_data_expired(state, data_expiration_delay).await
+        call_api_if_redis_forex_data_expired(state, source, data_expiration_delay).await
     }
 }
 
 async fn call_api_if_redis_forex_data_expired(
     state: &SessionState,
+    source: ForexRatesSource,
     data_expiration_delay: u32,
 ) -> CustomResult<FxExchangeRatesCacheEntry, ForexError> {
-    match retrieve_forex_data_from_redis(state).await {
+    match retrieve_forex_data_from_redis(state, source).await {
         Ok(Some(data)) => {
-            call_forex_api_if_redis_data_expired(state, data, data_expiration_delay).await
+            call_forex_api_if_redis_data_expired(state, source, data, data_expiration_delay).await
         }
         Ok(None) => {
             // No data in local as well as redis
-            call_forex_api_and_save_data_to_cache_and_redis(state, None).await?;
+            call_forex_api_and_save_data_to_cache_and_redis(state, source, None).await?;
             Err(ForexError::ForexDataUnavailable.into())
         }
         Err(error) => {
             // Error in deriving forex rates from redis
             logger::error!("forex_error: {:?}", error);
-            call_forex_api_and_save_data_to_cache_and_redis(state, None).await?;
+            call_forex_api_and_save_data_to_cache_and_redis(state, source, None).await?;
             Err(ForexError::ForexDataUnavailable.into())
         }
     }
@@ -203,17 +411,24 @@ async fn call_api_if_redis_forex_data_expired(
 
 async fn call_forex_api_and_save_data_to_cache_and_redis(
     state: &SessionState,
+    source: ForexRatesSource,
     stale_redis_data: Option<FxExchangeRatesCacheEntry>,
 ) -> CustomResult<FxExchangeRatesCacheEntry, ForexError> {
     // spawn a new thread and do the api fetch and write operations on redis.
-    let forex_api_key = state.conf.forex_api.get_inner().api_key.peek();
-    if forex_api_key.is_empty() {
-        Err(ForexError::ConfigurationError("api_keys not provided".into()).into())
+    let forex_api = state.conf.forex_api.get_inner();
+    let is_any_provider_configured = source
+        .get_providers(forex_api)
+        .into_iter()
+        .filter_map(|provider| providers::get_global_provider(provider).ok())
+        .any(|provider| provider.is_configured(forex_api));
+
+    if !is_any_provider_configured {
+        Err(ForexError::ConfigurationError("forex providers not configured".into()).into())
     } else {
         let state = state.clone();
         tokio::spawn(
             async move {
-                acquire_redis_lock_and_call_forex_api(&state)
+                acquire_redis_lock_and_call_forex_api(&state, source)
                     .await
                     .map_err(|err| {
                         logger::error!(forex_error=?err);
@@ -228,228 +443,100 @@ async fn call_forex_api_and_save_data_to_cache_and_redis(
 
 async fn acquire_redis_lock_and_call_forex_api(
     state: &SessionState,
+    source: ForexRatesSource,
 ) -> CustomResult<(), ForexError> {
-    let lock_acquired = acquire_redis_lock(state).await?;
+    let lock_acquired = acquire_redis_lock(state, source).await?;
     if !lock_acquired {
         Err(ForexError::CouldNotAcquireLock.into())
     } else {
         logger::debug!("forex_log: redis lock acquired");
-        let api_rates = fetch_forex_rates_from_primary_api(state).await;
-        match api_rates {
-            Ok(rates) => save_forex_data_to_cache_and_redis(state, rates).await,
-            Err(error) => {
-                logger::error!(forex_error=?error,"primary_forex_error");
-                // API not able to fetch data call secondary service
-                let secondary_api_rates = fetch_forex_rates_from_fallback_api(state).await;
-                match secondary_api_rates {
-                    Ok(rates) => save_forex_data_to_cache_and_redis(state, rates).await,
-                    Err(error) => {
-                        release_redis_lock(state).await?;
-                        Err(error)
-                    }
+        let forex_api = state.conf.forex_api.get_inner();
+        let mut last_error = None;
+
+        // The providers are tried in the order of preference, until the rates are fetched
+        for provider in source.get_providers(forex_api) {
+            let rates = match providers::get_global_provider(provider) {
+                Ok(forex_provider) if forex_provider.is_configured(forex_api) => {
+                    forex_provider.fetch_rates(state).await
+                }
+                Ok(_) => continue,
+                Err(error) => Err(error),
+            };
+
+            match rates {
+                Ok(rates) => {
+                    // The snapshot is only used for historical conversions, so failing to save it
+                    // does not prevent the latest rates from being cached
+                    save_forex_rate_snapshot(state, &rates)
+                        .await
+                        .map_err(|error| logger::error!(forex_error=?error))
+                        .ok();
+                    return save_forex_data_to_cache_and_redis(state, source, rates).await;
+                }
+                Err(error) => {
+                    logger::error!(forex_error=?error, %provider, "forex_provider_error");
+                    last_error = Some(error);
                 }
             }
         }
+
+        release_redis_lock(state, source).await?;
+        Err(last_error.unwrap_or_else(|| ForexError::ForexDataUnavailable.into()))
     }
 }
 
 async fn save_forex_data_to_cache_and_redis(
     state: &SessionState,
+    source: ForexRatesSource,
     forex: FxExchangeRatesCacheEntry,
 ) -> CustomResult<(), ForexError> {
-    save_forex_data_to_redis(state, &forex)
+    save_forex_data_to_redis(state, source, &forex)
         .await
-        .async_and_then(|_rates| release_redis_lock(state))
+        .async_and_then(|_rates| release_redis_lock(state, source))
         .await
-        .async_and_then(|_val| save_forex_data_to_local_cache(forex.clone()))
+        .async_and_then(|_val| save_forex_data_to_local_cache(source, forex.clone()))
         .await
 }
 
 async fn call_forex_api_if_redis_data_expired(
     state: &SessionState,
+    source: ForexRatesSource,
     redis_data: FxExchangeRatesCacheEntry,
     data_expiration_delay: u32,
 ) -> CustomResult<FxExchangeRatesCacheEntry, ForexError> {
     match is_redis_expired(Some(redis_data.clone()).as_ref(), data_expiration_delay).await {
-        Some(redis_forex) => {
+        Some(_redis_forex) => {
             // Valid data present in redis
-            let exchange_rates = FxExchangeRatesCacheEntry::new(redis_forex.as_ref().clone());
             logger::debug!("forex_log: forex response found in redis");
-            save_forex_data_to_local_cache(exchange_rates.clone()).await?;
-            Ok(exchange_rates)
+            save_forex_data_to_local_cache(source, redis_data.clone()).await?;
+            Ok(redis_data)
         }
         None => {
             // redis expired
-            call_forex_api_and_save_data_to_cache_and_redis(state, Some(redis_data)).await
-        }
-    }
-}
-
-async fn fetch_forex_rates_from_primary_api(
-    state: &SessionState,
-) -> Result<FxExchangeRatesCacheEntry, error_stack::Report<ForexError>> {
-    let forex_api_key = state.conf.forex_api.get_inner().api_key.peek();
-
-    logger::debug!("forex_log: Primary api call for forex fetch");
-    let forex_url: String = format!("{FOREX_BASE_URL}{forex_api_key}{FOREX_BASE_CURRENCY}");
-    let forex_request = services::RequestBuilder::new()
-        .method(services::Method::Get)
-        .url(&forex_url)
-        .build();
-
-    logger::info!(primary_forex_request=?forex_request,"forex_log: Primary api call for forex fetch");
-    let response = state
-        .api_client
-        .send_request(
-            &state.clone(),
-            forex_request,
-            Some(FOREX_API_TIMEOUT),
-            false,
-        )
-        .await
-        .change_context(ForexError::ApiUnresponsive)
-        .attach_printable("Primary forex fetch api unresponsive")?;
-    let forex_response = response
-        .json::<ForexResponse>()
-        .await
-        .change_context(ForexError::ParsingError)
-        .attach_printable(
-            "Unable to parse response received from primary api into ForexResponse",
-        )?;
-
-    logger::info!(primary_forex_response=?forex_response,"forex_log");
-
-    let mut conversions: HashMap<enums::Currency, CurrencyFactors> = HashMap::new();
-    for enum_curr in enums::Currency::iter() {
-        match forex_response.rates.get(&enum_curr.to_string()) {
-            Some(rate) => {
-                let from_factor = match Decimal::new(1, 0).checked_div(**rate) {
-                    Some(rate) => rate,
-                    None => {
-                        logger::error!(
-                            "forex_error: Rates for {} not received from API",
-                            &enum_curr
-                        );
-                        continue;
-                    }
-                };
-                let currency_factors = CurrencyFactors::new(**rate, from_factor);
-                conversions.insert(enum_curr, currency_factors);
-            }
-            None => {
-                logger::error!(
-                    "forex_error: Rates for {} not received from API",
-                    &enum_curr
-                );
-            }
-        };
-    }
-
-    Ok(FxExchangeRatesCacheEntry::new(ExchangeRates::new(
-        enums::Currency::USD,
-        conversions,
-    )))
-}
-
-pub async fn fetch_forex_rates_from_fallback_api(
-    state: &SessionState,
-) -> CustomResult<FxExchangeRatesCacheEntry, ForexError> {
-    let fallback_forex_api_key = state.conf.forex_api.get_inner().fallback_api_key.peek();
-
-    let fallback_forex_url: String = format!("{FALLBACK_FOREX_BASE_URL}{fallback_forex_api_key}");
-    let fallback_forex_request = services::RequestBuilder::new()
-        .method(services::Method::Get)
-        .url(&fallback_forex_url)
-        .build();
-
-    logger::info!(fallback_forex_request=?fallback_forex_request,"forex_log: Fallback api call for forex fetch");
-    let response = state
-        .api_client
-        .send_request(
-            &state.clone(),
-            fallback_forex_request,
-            Some(FOREX_API_TIMEOUT),
-            false,
-        )
-        .await
-        .change_context(ForexError::ApiUnresponsive)
-        .attach_printable("Fallback forex fetch api unresponsive")?;
-
-    let fallback_forex_response = response
-        .json::<FallbackForexResponse>()
-        .await
-        .change_context(ForexError::ParsingError)
-        .attach_printable(
-            "Unable to parse response received from fallback api into ForexResponse",
-        )?;
-
-    logger::info!(fallback_forex_response=?fallback_forex_response,"forex_log");
-
-    let mut conversions: HashMap<enums::Currency, CurrencyFactors> = HashMap::new();
-    for enum_curr in enums::Currency::iter() {
-        match fallback_forex_response.quotes.get(
-            format!(
-                "{}{}",
-                FALLBACK_FOREX_API_CURRENCY_PREFIX,
-                &enum_curr.to_string()
-            )
-            .as_str(),
-        ) {
-            Some(rate) => {
-                let from_factor = match Decimal::new(1, 0).checked_div(**rate) {
-                    Some(rate) => rate,
-                    None => {
-                        logger::error!(
-                            "forex_error: Rates for {} not received from API",
-                            &enum_curr
-                        );
-                        continue;
-                    }
-                };
-                let currency_factors = CurrencyFactors::new(**rate, from_factor);
-                conversions.insert(enum_curr, currency_factors);
-            }
-            None => {
-                if enum_curr == enums::Currency::USD {
-                    let currency_factors =
-                        CurrencyFactors::new(Decimal::new(1, 0), Decimal::new(1, 0));
-                    conversions.insert(enum_curr, currency_factors);
-                } else {
-                    logger::error!(
-                        "forex_error: Rates for {} not received from API",
-                        &enum_curr
-                    );
-                }
-            }
-        };
-    }
-
-    let rates =
-        FxExchangeRatesCacheEntry::new(ExchangeRates::new(enums::Currency::USD, conversions));
-    match acquire_redis_lock(state).await {
-        Ok(_) => {
-            save_forex_data_to_cache_and_redis(state, rates.clone()).await?;
-            Ok(rates)
+            call_forex_api_and_save_data_to_cache_and_redis(state, source, Some(redis_data)).await
         }
-        Err(e) => Err(e),
     }
 }
 
 async fn release_redis_lock(
     state: &SessionState,
+    source: ForexRatesSource,
 ) -> Result<DelReply, error_stack::Report<ForexError>> {
     logger::debug!("forex_log: Releasing redis lock");
     state
         .store
         .get_redis_conn()
         .change_context(ForexError::RedisConnectionError)?
-        .delete_key(&REDIX_FOREX_CACHE_KEY.into())
+        .delete_key(&source.get_redis_lock_key().as_str().into())
         .await
         .change_context(ForexError::RedisLockReleaseFailed)
         .attach_printable("Unable to release redis lock")
 }
 
-async fn acquire_redis_lock(state: &SessionState) -> CustomResult<bool, ForexError> {
+async fn acquire_redis_lock(
+    state: &SessionState,
+    source: ForexRatesSource,
+) -> CustomResult<bool, ForexError> {
     let forex_api = state.conf.forex_api.get_inner();
     logger::debug!("forex_log: Acquiring redis lock");
     state
@@ -457,7 +544,7 @@ async fn acquire_redis_lock(state: &SessionState) -> CustomResult<bool, ForexErr
         .get_redis_conn()
         .change_context(ForexError::RedisConnectionError)?
         .set_key_if_not_exists_with_expiry(
-            &REDIX_FOREX_CACHE_KEY.into(),
+            &source.get_redis_lock_key().as_str().into(),
             "",
             Some(i64::from(forex_api.redis_lock_timeout_in_seconds)),
         )
@@ -469,6 +556,7 @@ async fn acquire_redis_lock(state: &SessionState) -> CustomResult<bool, ForexErr
 
 async fn save_forex_data_to_redis(
     app_state: &SessionState,
+    source: ForexRatesSource,
     forex_exchange_cache_entry: &FxExchangeRatesCacheEntry,
 ) -> CustomResult<(), ForexError> {
     let forex_api = app_state.conf.forex_api.get_inner();
@@ -478,7 +566,7 @@ async fn save_forex_data_to_redis(
         .get_redis_conn()
         .change_context(ForexError::RedisConnectionError)?
         .serialize_and_set_key_with_expiry(
-            &REDIX_FOREX_CACHE_DATA.into(),
+            &source.get_redis_data_key().as_str().into(),
             forex_exchange_cache_entry,
             i64::from(forex_api.redis_ttl_in_seconds),
         )
@@ -489,13 +577,17 @@ async fn save_forex_data_to_redis(
 
 async fn retrieve_forex_data_from_redis(
     app_state: &SessionState,
+    source: ForexRatesSource,
 ) -> CustomResult<Option<FxExchangeRatesCacheEntry>, ForexError> {
     logger::debug!("forex_log: Retrieving forex from redis");
     app_state
         .store
         .get_redis_conn()
         .change_context(ForexError::RedisConnectionError)?
-        .get_and_deserialize_key(&REDIX_FOREX_CACHE_DATA.into(), "FxExchangeRatesCache")
+        .get_and_deserialize_key(
+            &source.get_redis_data_key().as_str().into(),
+            "FxExchangeRatesCache",
+        )
         .await
         .change_context(ForexError::EntryNotFound)
         .attach_printable("Forex entry not found in redis")
@@ -515,17 +607,31 @@ async fn is_redis_expired(
     })
 }
 
+/// Deducts the markup percentage from the converted amount
+fn apply_markup(amount: Decimal, markup_percentage: Decimal) -> CustomResult<Decimal, ForexError> {
+    Decimal::ONE_HUNDRED
+        .checked_sub(markup_percentage)
+        .and_then(|percentage| amount.checked_mul(percentage))
+        .and_then(|amount| amount.checked_div(Decimal::ONE_HUNDRED))
+        .ok_or(ForexError::ConversionError.into())
+}
+
 #[instrument(skip_all)]
 pub async fn convert_currency(
     state: SessionState,
+    merchant_id: &common_utils::id_type::MerchantId,
     amount: i64,
     to_currency: String,
     from_currency: String,
+    provider: Option<ForexProvider>,
+    as_of: Option<PrimitiveDateTime>,
 ) -> CustomResult<api_models::currency::CurrencyConversionResponse, ForexError> {
-    let forex_api = state.conf.forex_api.get_inner();
-    let rates = get_forex_rates(&state, forex_api.data_expiration_delay_in_seconds)
-        .await
-        .change_context(ForexError::ApiError)?;
+    let rates = match as_of {
+        Some(as_of) => get_forex_rates_as_of(&state, provider, as_of).await?,
+        None => get_forex_rates_from_provider(&state, merchant_id, provider)
+            .await
+            .change_context(ForexError::ApiError)?,
+    };
 
     let to_currency = enums::Currency::from_str(to_currency.as_str())
         .change_context(ForexError::CurrencyNotAcceptable)
@@ -540,8 +646,51 @@ pub async fn convert_currency(
             .change_context(ForexError::ConversionError)
             .attach_printable("Unable to perform currency conversion")?;
 
+    let converted_amount = match rates.markup_percentage {
+        Some(markup_percentage) => apply_markup(converted_amount, markup_percentage)?,
+        None => converted_amount,
+    };
+
+    logger::info!(
+        forex_provider = %rates.provider,
+        rate_snapshot_id = %rates.snapshot_id,
+        %from_currency,
+        %to_currency,
+        "forex_log: Converted currency"
+    );
+
     Ok(api_models::currency::CurrencyConversionResponse {
         converted_amount: converted_amount.to_string(),
         currency: to_currency.to_string(),
+        provider: rates.provider,
+        rate_snapshot_id: rates.snapshot_id,
     })
 }
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use std::str::FromStr;
+
+    use super::*;
+
+    #[test]
+    fn test_apply_markup() {
+        let amount = Decimal::from(200);
+
+        assert_eq!(apply_markup(amount, Decimal::ZERO).unwrap(), amount);
+        assert_eq!(
+            apply_markup(amount, Decimal::from_str("2.5").unwrap()).unwrap(),
+            Decimal::from(195)
+        );
+        assert_eq!(
+            apply_markup(amount, Decimal::ONE_HUNDRED).unwrap(),
+            Decimal::ZERO
+        );
+    }
+
+    #[test]
+    fn test_apply_markup_overflow() {
+        assert!(apply_markup(Decimal::MAX, Decimal::ZERO).is_err());
+    }
+}
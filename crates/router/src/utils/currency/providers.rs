@@ -0,0 +1,430 @@
+use std::collections::HashMap;
+
+use api_models::{currency::ForexProvider, enums};
+use common_utils::errors::CustomResult;
+use currency_conversion::types::{CurrencyFactors, ExchangeRates};
+use error_stack::{report, ResultExt};
+use masking::PeekInterface;
+use rust_decimal::Decimal;
+use strum::IntoEnumIterator;
+
+use super::{FloatDecimal, ForexError, FxExchangeRatesCacheEntry};
+use crate::{
+    logger,
+    routes::app::settings::{DefaultExchangeRates, ForexApi},
+    services, SessionState,
+};
+
+const FOREX_API_TIMEOUT: u64 = 5;
+const FOREX_BASE_URL: &str = "https://openexchangerates.org/api/latest.json?app_id=";
+const FOREX_BASE_CURRENCY: &str = "&base=USD";
+const FALLBACK_FOREX_BASE_URL: &str = "http://apilayer.net/api/live?access_key=";
+const FALLBACK_FOREX_API_CURRENCY_PREFIX: &str = "USD";
+/// Key of the configs table entry holding the rates served by the config table provider
+pub const FOREX_RATES_CONFIG_KEY: &str = "forex_rates";
+
+/// A source of forex rates
+#[async_trait::async_trait]
+pub trait ForexRatesProvider: Send + Sync {
+    /// Whether the provider has been configured in the forex settings
+    fn is_configured(&self, forex_api: &ForexApi) -> bool;
+
+    /// Fetches the latest rates of the provider
+    async fn fetch_rates(
+        &self,
+        state: &SessionState,
+    ) -> CustomResult<FxExchangeRatesCacheEntry, ForexError>;
+}
+
+/// Returns the implementation of a provider whose rates are shared by all merchants
+pub fn get_global_provider(
+    provider: ForexProvider,
+) -> CustomResult<Box<dyn ForexRatesProvider>, ForexError> {
+    match provider {
+        ForexProvider::OpenExchangeRates => Ok(Box::new(OpenExchangeRates)),
+        ForexProvider::ApiLayer => Ok(Box::new(ApiLayer)),
+        ForexProvider::StaticFile => Ok(Box::new(StaticFile)),
+        ForexProvider::ConfigTable => Ok(Box::new(ConfigTable)),
+        ForexProvider::MerchantRateTable => Err(report!(ForexError::ConfigurationError(
+            "merchant rate tables are not shared by merchants".into()
+        ))),
+    }
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+struct ForexResponse {
+    pub rates: HashMap<String, FloatDecimal>,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+struct FallbackForexResponse {
+    pub quotes: HashMap<String, FloatDecimal>,
+}
+
+/// The primary forex API, openexchangerates.org
+pub struct OpenExchangeRates;
+
+#[async_trait::async_trait]
+impl ForexRatesProvider for OpenExchangeRates {
+    fn is_configured(&self, forex_api: &ForexApi) -> bool {
+        !forex_api.api_key.peek().is_empty()
+    }
+
+    async fn fetch_rates(
+        &self,
+        state: &SessionState,
+    ) -> CustomResult<FxExchangeRatesCacheEntry, ForexError> {
+        let forex_api_key = state.conf.forex_api.get_inner().api_key.peek();
+
+        logger::debug!("forex_log: Primary api call for forex fetch");
+        let forex_url: String = format!("{FOREX_BASE_URL}{forex_api_key}{FOREX_BASE_CURRENCY}");
+        let forex_request = services::RequestBuilder::new()
+            .method(services::Method::Get)
+            .url(&forex_url)
+            .build();
+
+        logger::info!(primary_forex_request=?forex_request,"forex_log: Primary api call for forex fetch");
+        let response = state
+            .api_client
+            .send_request(
+                &state.clone(),
+                forex_request,
+                Some(FOREX_API_TIMEOUT),
+                false,
+            )
+            .await
+            .change_context(ForexError::ApiUnresponsive)
+            .attach_printable("Primary forex fetch api unresponsive")?;
+        let forex_response = response
+            .json::<ForexResponse>()
+            .await
+            .change_context(ForexError::ParsingError)
+            .attach_printable(
+                "Unable to parse response received from primary api into ForexResponse",
+            )?;
+
+        logger::info!(primary_forex_response=?forex_response,"forex_log");
+
+        let mut conversions: HashMap<enums::Currency, CurrencyFactors> = HashMap::new();
+        for enum_curr in enums::Currency::iter() {
+            match forex_response.rates.get(&enum_curr.to_string()) {
+                Some(rate) => {
+                    let from_factor = match Decimal::new(1, 0).checked_div(**rate) {
+                        Some(rate) => rate,
+                        None => {
+                            logger::error!(
+                                "forex_error: Rates for {} not received from API",
+                                &enum_curr
+                            );
+                            continue;
+                        }
+                    };
+                    let currency_factors = CurrencyFactors::new(**rate, from_factor);
+                    conversions.insert(enum_curr, currency_factors);
+                }
+                None => {
+                    logger::error!(
+                        "forex_error: Rates for {} not received from API",
+                        &enum_curr
+                    );
+                }
+            };
+        }
+
+        Ok(FxExchangeRatesCacheEntry::new(
+            ForexProvider::OpenExchangeRates,
+            ExchangeRates::new(enums::Currency::USD, conversions),
+        ))
+    }
+}
+
+/// The fallback forex API, apilayer.net
+pub struct ApiLayer;
+
+#[async_trait::async_trait]
+impl ForexRatesProvider for ApiLayer {
+    fn is_configured(&self, forex_api: &ForexApi) -> bool {
+        !forex_api.fallback_api_key.peek().is_empty()
+    }
+
+    async fn fetch_rates(
+        &self,
+        state: &SessionState,
+    ) -> CustomResult<FxExchangeRatesCacheEntry, ForexError> {
+        let fallback_forex_api_key = state.conf.forex_api.get_inner().fallback_api_key.peek();
+
+        let fallback_forex_url: String =
+            format!("{FALLBACK_FOREX_BASE_URL}{fallback_forex_api_key}");
+        let fallback_forex_request = services::RequestBuilder::new()
+            .method(services::Method::Get)
+            .url(&fallback_forex_url)
+            .build();
+
+        logger::info!(fallback_forex_request=?fallback_forex_request,"forex_log: Fallback api call for forex fetch");
+        let response = state
+            .api_client
+            .send_request(
+                &state.clone(),
+                fallback_forex_request,
+                Some(FOREX_API_TIMEOUT),
+                false,
+            )
+            .await
+            .change_context(ForexError::ApiUnresponsive)
+            .attach_printable("Fallback forex fetch api unresponsive")?;
+
+        let fallback_forex_response = response
+            .json::<FallbackForexResponse>()
+            .await
+            .change_context(ForexError::ParsingError)
+            .attach_printable(
+                "Unable to parse response received from fallback api into ForexResponse",
+            )?;
+
+        logger::info!(fallback_forex_response=?fallback_forex_response,"forex_log");
+
+        let mut conversions: HashMap<enums::Currency, CurrencyFactors> = HashMap::new();
+        for enum_curr in enums::Currency::iter() {
+            match fallback_forex_response.quotes.get(
+                format!(
+                    "{}{}",
+                    FALLBACK_FOREX_API_CURRENCY_PREFIX,
+                    &enum_curr.to_string()
+                )
+                .as_str(),
+            ) {
+                Some(rate) => {
+                    let from_factor = match Decimal::new(1, 0).checked_div(**rate) {
+                        Some(rate) => rate,
+                        None => {
+                            logger::error!(
+                                "forex_error: Rates for {} not received from API",
+                                &enum_curr
+                            );
+                            continue;
+                        }
+                    };
+                    let currency_factors = CurrencyFactors::new(**rate, from_factor);
+                    conversions.insert(enum_curr, currency_factors);
+                }
+                None => {
+                    if enum_curr == enums::Currency::USD {
+                        let currency_factors =
+                            CurrencyFactors::new(Decimal::new(1, 0), Decimal::new(1, 0));
+                        conversions.insert(enum_curr, currency_factors);
+                    } else {
+                        logger::error!(
+                            "forex_error: Rates for {} not received from API",
+                            &enum_curr
+                        );
+                    }
+                }
+            };
+        }
+
+        Ok(FxExchangeRatesCacheEntry::new(
+            ForexProvider::ApiLayer,
+            ExchangeRates::new(enums::Currency::USD, conversions),
+        ))
+    }
+}
+
+/// Rates read from a JSON file, in the format of the default exchange rates
+pub struct StaticFile;
+
+#[async_trait::async_trait]
+impl ForexRatesProvider for StaticFile {
+    fn is_configured(&self, forex_api: &ForexApi) -> bool {
+        forex_api
+            .static_rates_file_path
+            .as_ref()
+            .is_some_and(|path| !path.is_empty())
+    }
+
+    async fn fetch_rates(
+        &self,
+        state: &SessionState,
+    ) -> CustomResult<FxExchangeRatesCacheEntry, ForexError> {
+        let path = state
+            .conf
+            .forex_api
+            .get_inner()
+            .static_rates_file_path
+            .clone()
+            .ok_or(ForexError::ConfigurationError(
+                "static rates file path not provided".into(),
+            ))?;
+
+        logger::debug!("forex_log: Reading forex from static file");
+        let contents = tokio::fs::read_to_string(&path)
+            .await
+            .change_context(ForexError::LocalReadError)
+            .attach_printable_lazy(|| format!("Unable to read static forex rates from {path}"))?;
+
+        let default_exchange_rates = serde_json::from_str::<DefaultExchangeRates>(&contents)
+            .change_context(ForexError::ParsingError)
+            .attach_printable("Unable to parse static forex rates")?;
+
+        Ok(FxExchangeRatesCacheEntry::new(
+            ForexProvider::StaticFile,
+            ExchangeRates::try_from(default_exchange_rates)?,
+        ))
+    }
+}
+
+/// Rates stored in the configs table, in the format of the default exchange rates
+pub struct ConfigTable;
+
+#[async_trait::async_trait]
+impl ForexRatesProvider for ConfigTable {
+    fn is_configured(&self, _forex_api: &ForexApi) -> bool {
+        true
+    }
+
+    async fn fetch_rates(
+        &self,
+        state: &SessionState,
+    ) -> CustomResult<FxExchangeRatesCacheEntry, ForexError> {
+        logger::debug!("forex_log: Reading forex from configs table");
+        let config = state
+            .store
+            .find_config_by_key_from_db(FOREX_RATES_CONFIG_KEY)
+            .await
+            .change_context(ForexError::EntryNotFound)
+            .attach_printable("Forex rates not found in configs table")?;
+
+        let default_exchange_rates = serde_json::from_str::<DefaultExchangeRates>(&config.config)
+            .change_context(ForexError::ParsingError)
+            .attach_printable("Unable to parse forex rates from configs table")?;
+
+        Ok(FxExchangeRatesCacheEntry::new(
+            ForexProvider::ConfigTable,
+            ExchangeRates::try_from(default_exchange_rates)?,
+        ))
+    }
+}
+
+/// Rates uploaded by a merchant, whose markup percentage is deducted from the converted amounts
+pub struct MerchantRateTable {
+    pub merchant_id: common_utils::id_type::MerchantId,
+}
+
+#[async_trait::async_trait]
+impl ForexRatesProvider for MerchantRateTable {
+    fn is_configured(&self, _forex_api: &ForexApi) -> bool {
+        true
+    }
+
+    async fn fetch_rates(
+        &self,
+        state: &SessionState,
+    ) -> CustomResult<FxExchangeRatesCacheEntry, ForexError> {
+        let config = state
+            .store
+            .find_config_by_key(&self.merchant_id.get_forex_rate_table_key())
+            .await
+            .change_context(ForexError::EntryNotFound)
+            .attach_printable("Forex rate table not found for the merchant")?;
+
+        let rate_table =
+            serde_json::from_str::<api_models::currency::ForexRateTableResponse>(&config.config)
+                .change_context(ForexError::ParsingError)
+                .attach_printable("Unable to parse the forex rate table of the merchant")?;
+
+        Ok(Self::get_rates_from_rate_table(rate_table))
+    }
+}
+
+impl MerchantRateTable {
+    fn get_rates_from_rate_table(
+        rate_table: api_models::currency::ForexRateTableResponse,
+    ) -> FxExchangeRatesCacheEntry {
+        let mut conversions = rate_table
+            .rates
+            .iter()
+            .filter_map(|(currency, rate)| {
+                Decimal::ONE
+                    .checked_div(*rate)
+                    .map(|from_factor| (*currency, CurrencyFactors::new(*rate, from_factor)))
+            })
+            .collect::<HashMap<_, _>>();
+        conversions.insert(
+            rate_table.base_currency,
+            CurrencyFactors::new(Decimal::ONE, Decimal::ONE),
+        );
+
+        FxExchangeRatesCacheEntry {
+            snapshot_id: rate_table.snapshot_id,
+            markup_percentage: Some(rate_table.markup_percentage),
+            ..FxExchangeRatesCacheEntry::new(
+                ForexProvider::MerchantRateTable,
+                ExchangeRates::new(rate_table.base_currency, conversions),
+            )
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use std::str::FromStr;
+
+    use super::*;
+
+    fn rate_table() -> api_models::currency::ForexRateTableResponse {
+        api_models::currency::ForexRateTableResponse {
+            merchant_id: common_utils::id_type::MerchantId::try_from(std::borrow::Cow::from(
+                "merchant_forex",
+            ))
+            .unwrap(),
+            snapshot_id: "fxsnap_merchant".to_string(),
+            base_currency: enums::Currency::USD,
+            rates: HashMap::from([
+                (enums::Currency::EUR, Decimal::from_str("0.5").unwrap()),
+                (enums::Currency::INR, Decimal::from_str("80").unwrap()),
+                // Rates which cannot be inverted are left out of the table
+                (enums::Currency::GBP, Decimal::ZERO),
+            ]),
+            markup_percentage: Decimal::from_str("2.5").unwrap(),
+            uploaded_at: common_utils::date_time::now(),
+        }
+    }
+
+    #[test]
+    fn test_merchant_rate_table_rates() {
+        let rates = MerchantRateTable::get_rates_from_rate_table(rate_table());
+
+        assert_eq!(rates.provider, ForexProvider::MerchantRateTable);
+        assert_eq!(rates.snapshot_id, "fxsnap_merchant");
+        assert_eq!(
+            rates.markup_percentage,
+            Some(Decimal::from_str("2.5").unwrap())
+        );
+        assert_eq!(rates.data.base_currency, enums::Currency::USD);
+        assert!(!rates.data.conversion.contains_key(&enums::Currency::GBP));
+
+        let eur = rates.data.conversion.get(&enums::Currency::EUR).unwrap();
+        assert_eq!(eur.to_factor, Decimal::from_str("0.5").unwrap());
+        assert_eq!(eur.from_factor, Decimal::TWO);
+
+        let usd = rates.data.conversion.get(&enums::Currency::USD).unwrap();
+        assert_eq!(usd.to_factor, Decimal::ONE);
+        assert_eq!(usd.from_factor, Decimal::ONE);
+    }
+
+    #[test]
+    fn test_merchant_rate_table_conversion() {
+        let rates = MerchantRateTable::get_rates_from_rate_table(rate_table());
+
+        let converted_amount = currency_conversion::conversion::convert(
+            &rates.data,
+            enums::Currency::EUR,
+            enums::Currency::INR,
+            100,
+        )
+        .unwrap();
+
+        // 1 EUR is 2 USD, which is 160 INR
+        assert_eq!(converted_amount, Decimal::from(160));
+    }
+}
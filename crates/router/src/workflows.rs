@@ -2,6 +2,11 @@
 pub mod api_key_expiry;
 #[cfg(feature = "payouts")]
 pub mod attach_payout_account_workflow;
+pub mod blocklist_purge;
+#[cfg(all(feature = "email", feature = "v1"))]
+pub mod customer_email;
+#[cfg(feature = "v1")]
+pub mod key_rotation;
 pub mod outgoing_webhook_retry;
 pub mod payment_method_status_update;
 pub mod payment_sync;
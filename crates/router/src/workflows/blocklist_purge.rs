@@ -0,0 +1,79 @@
+use common_utils::ext_traits::ValueExt;
+use diesel_models::process_tracker::business_status;
+use router_env::logger;
+use scheduler::workflows::ProcessTrackerWorkflow;
+
+use crate::{
+    core::blocklist::utils as blocklist_utils,
+    errors,
+    logger::error,
+    routes::SessionState,
+    types::storage::{self, BlocklistPurgeTrackingData},
+};
+
+pub struct BlocklistPurgeWorkflow;
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<SessionState> for BlocklistPurgeWorkflow {
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        let db = &*state.store;
+        let tracking_data: BlocklistPurgeTrackingData = process
+            .tracking_data
+            .clone()
+            .parse_value("BlocklistPurgeTrackingData")?;
+        let merchant_id = tracking_data.merchant_id;
+
+        // the expired entries may have been removed or re-added by the merchant in the meantime
+        let purged_entries = db
+            .delete_expired_blocklist_entries_by_merchant_id(
+                &merchant_id,
+                common_utils::date_time::now(),
+            )
+            .await?;
+        logger::debug!(
+            ?merchant_id,
+            purged_entries = purged_entries.len(),
+            "Purged expired blocklist entries"
+        );
+
+        for entry in &purged_entries {
+            blocklist_utils::delete_blocklist_attribute(state, entry).await?;
+        }
+
+        // The task is rescheduled for the next expiring entry, if any, so that a single task is
+        // maintained per merchant
+        let next_expiry = db
+            .find_next_expiring_blocklist_entry_by_merchant_id(&merchant_id)
+            .await?
+            .and_then(|entry| entry.expires_at);
+
+        match next_expiry {
+            Some(next_expiry) => {
+                db.as_scheduler()
+                    .reset_process(process, next_expiry)
+                    .await?
+            }
+            None => {
+                db.as_scheduler()
+                    .finish_process_with_business_status(process, business_status::COMPLETED_BY_PT)
+                    .await?
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        _state: &'a SessionState,
+        process: storage::ProcessTracker,
+        _error: errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), errors::ProcessTrackerError> {
+        error!(%process.id, "Failed while executing workflow");
+        Ok(())
+    }
+}
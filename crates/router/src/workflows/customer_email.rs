@@ -0,0 +1,59 @@
+use common_utils::ext_traits::ValueExt;
+use diesel_models::process_tracker::business_status;
+use scheduler::workflows::ProcessTrackerWorkflow;
+
+use crate::{
+    core::customer_email,
+    errors,
+    logger::error,
+    routes::SessionState,
+    types::storage::{self, CustomerEmailTrackingData},
+};
+
+pub struct CustomerEmailWorkflow;
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<SessionState> for CustomerEmailWorkflow {
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        let tracking_data: CustomerEmailTrackingData = process
+            .tracking_data
+            .clone()
+            .parse_value("CustomerEmailTrackingData")?;
+
+        customer_email::send_customer_email(state, &tracking_data).await?;
+
+        state
+            .store
+            .as_scheduler()
+            .finish_process_with_business_status(process, business_status::COMPLETED_BY_PT)
+            .await?;
+
+        Ok(())
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+        error: errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), errors::ProcessTrackerError> {
+        error!(%process.id, ?error, "Failed while executing customer email workflow");
+
+        let db = state.store.as_scheduler();
+        if process.retry_count < state.conf.customer_email.max_retries {
+            let schedule_time = common_utils::date_time::now().saturating_add(
+                time::Duration::seconds(state.conf.customer_email.retry_interval_in_seconds),
+            );
+            db.retry_process(process, schedule_time).await?;
+        } else {
+            db.finish_process_with_business_status(process, business_status::RETRIES_EXCEEDED)
+                .await?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,95 @@
+use common_utils::ext_traits::{Encode, ValueExt};
+use diesel_models::process_tracker::business_status;
+use scheduler::workflows::ProcessTrackerWorkflow;
+
+use crate::{
+    core::key_rotation,
+    errors,
+    logger::error,
+    routes::SessionState,
+    types::storage::{self, KeyRotationTrackingData},
+};
+
+/// Number of batches re-encrypted in a single run of the task, before it yields to other tasks
+const KEY_ROTATION_BATCHES_PER_RUN: usize = 10;
+
+/// Number of times a failing key rotation is retried before it's marked as failed
+const KEY_ROTATION_MAX_RETRIES: i32 = 3;
+
+/// Delay between the retries of a failing key rotation, in seconds
+const KEY_ROTATION_RETRY_DELAY: i64 = 300;
+
+pub struct KeyRotationWorkflow;
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<SessionState> for KeyRotationWorkflow {
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a SessionState,
+        mut process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        let db = &*state.store;
+        let mut tracking_data: KeyRotationTrackingData = process
+            .tracking_data
+            .clone()
+            .parse_value("KeyRotationTrackingData")?;
+
+        for _ in 0..KEY_ROTATION_BATCHES_PER_RUN {
+            let is_completed =
+                key_rotation::re_encrypt_next_batch(state, &mut tracking_data).await?;
+
+            // The progress is persisted after every batch, so that the rotation resumes from the
+            // last re-encrypted record if the task fails or is rescheduled
+            process = db
+                .as_scheduler()
+                .update_process(
+                    process,
+                    storage::ProcessTrackerUpdate::Update {
+                        name: None,
+                        retry_count: None,
+                        schedule_time: None,
+                        tracking_data: Some(tracking_data.encode_to_value()?),
+                        business_status: None,
+                        status: None,
+                        updated_at: Some(common_utils::date_time::now()),
+                    },
+                )
+                .await?;
+
+            if is_completed {
+                db.as_scheduler()
+                    .finish_process_with_business_status(process, business_status::COMPLETED_BY_PT)
+                    .await?;
+                return Ok(());
+            }
+        }
+
+        db.as_scheduler()
+            .reset_process(process, common_utils::date_time::now())
+            .await?;
+
+        Ok(())
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+        error: errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), errors::ProcessTrackerError> {
+        error!(%process.id, ?error, "Failed while executing key rotation workflow");
+
+        let db = state.store.as_scheduler();
+        if process.retry_count < KEY_ROTATION_MAX_RETRIES {
+            let schedule_time = common_utils::date_time::now()
+                .saturating_add(time::Duration::seconds(KEY_ROTATION_RETRY_DELAY));
+            db.retry_process(process, schedule_time).await?;
+        } else {
+            // The rotation can be resumed from its last progress by starting it again
+            db.finish_process_with_business_status(process, business_status::RETRIES_EXCEEDED)
+                .await?;
+        }
+
+        Ok(())
+    }
+}
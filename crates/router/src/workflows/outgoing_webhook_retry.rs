@@ -63,15 +63,6 @@ impl ProcessTrackerWorkflow<SessionState> for OutgoingWebhookRetryWorkflow {
             .find_business_profile_by_profile_id(&key_store, &tracking_data.business_profile_id)
             .await?;
 
-        let event_id = webhooks_core::utils::generate_event_id();
-        let idempotent_event_id = webhooks_core::utils::get_idempotent_event_id(
-            &tracking_data.primary_object_id,
-            tracking_data.event_type,
-            delivery_attempt,
-        )
-        .change_context(errors::ApiErrorResponse::WebhookProcessingFailure)
-        .attach_printable("Failed to generate idempotent event ID")?;
-
         let initial_event = match &tracking_data.initial_attempt_id {
             Some(initial_attempt_id) => {
                 db.find_event_by_merchant_id_event_id(
@@ -97,6 +88,19 @@ impl ProcessTrackerWorkflow<SessionState> for OutgoingWebhookRetryWorkflow {
             }
         };
 
+        // Each webhook endpoint has its own retry task, tied to the initial delivery attempt to
+        // that endpoint
+        let webhook_endpoint_id = initial_event.webhook_endpoint_id;
+        let event_id = webhooks_core::utils::generate_event_id();
+        let idempotent_event_id = webhooks_core::utils::get_idempotent_event_id(
+            &tracking_data.primary_object_id,
+            tracking_data.event_type,
+            delivery_attempt,
+            webhook_endpoint_id.as_ref(),
+        )
+        .change_context(errors::ApiErrorResponse::WebhookProcessingFailure)
+        .attach_printable("Failed to generate idempotent event ID")?;
+
         let now = common_utils::date_time::now();
         let new_event = domain::Event {
             event_id,
@@ -116,6 +120,7 @@ impl ProcessTrackerWorkflow<SessionState> for OutgoingWebhookRetryWorkflow {
             delivery_attempt: Some(delivery_attempt),
             metadata: initial_event.metadata,
             is_overall_delivery_successful: Some(false),
+            webhook_endpoint_id,
         };
 
         let event = db
@@ -180,18 +185,26 @@ impl ProcessTrackerWorkflow<SessionState> for OutgoingWebhookRetryWorkflow {
                             timestamp: event.created_at,
                         };
 
-                        let request_content = webhooks_core::get_outgoing_webhook_request(
-                            platform.get_processor(),
-                            outgoing_webhook,
-                            &business_profile,
-                        )
-                        .map_err(|error| {
-                            logger::error!(
-                                ?error,
-                                "Failed to obtain outgoing webhook request content"
-                            );
-                            errors::ProcessTrackerError::EApiErrorResponse
-                        })?;
+                        let request_content =
+                            webhooks_core::get_webhook_endpoint_from_business_profile(
+                                &business_profile,
+                                event.webhook_endpoint_id.as_ref(),
+                            )
+                            .and_then(|webhook_endpoint| {
+                                webhooks_core::get_outgoing_webhook_request(
+                                    platform.get_processor(),
+                                    outgoing_webhook,
+                                    &business_profile,
+                                    &webhook_endpoint,
+                                )
+                            })
+                            .map_err(|error| {
+                                logger::error!(
+                                    ?error,
+                                    "Failed to obtain outgoing webhook request content"
+                                );
+                                errors::ProcessTrackerError::EApiErrorResponse
+                            })?;
 
                         Box::pin(webhooks_core::trigger_webhook_and_raise_event(
                             state.clone(),
@@ -431,7 +444,9 @@ async fn get_outgoing_webhook_content_and_event_type(
                 | ApplicationResponse::Form(_)
                 | ApplicationResponse::GenericLinkForm(_)
                 | ApplicationResponse::PaymentLinkForm(_)
-                | ApplicationResponse::FileData(_) => {
+                | ApplicationResponse::FileData(_)
+                | ApplicationResponse::FileStream(_)
+                | ApplicationResponse::ReplayedJson(_) => {
                     Err(errors::ProcessTrackerError::ResourceFetchingFailed {
                         resource_name: tracking_data.primary_object_id.clone(),
                     })
@@ -491,7 +506,9 @@ async fn get_outgoing_webhook_content_and_event_type(
                     | ApplicationResponse::Form(_)
                     | ApplicationResponse::GenericLinkForm(_)
                     | ApplicationResponse::PaymentLinkForm(_)
-                    | ApplicationResponse::FileData(_) => {
+                    | ApplicationResponse::FileData(_)
+                    | ApplicationResponse::FileStream(_)
+                    | ApplicationResponse::ReplayedJson(_) => {
                         Err(errors::ProcessTrackerError::ResourceFetchingFailed {
                             resource_name: tracking_data.primary_object_id.clone(),
                         })
@@ -522,7 +539,9 @@ async fn get_outgoing_webhook_content_and_event_type(
                 | ApplicationResponse::Form(_)
                 | ApplicationResponse::GenericLinkForm(_)
                 | ApplicationResponse::PaymentLinkForm(_)
-                | ApplicationResponse::FileData(_) => {
+                | ApplicationResponse::FileData(_)
+                | ApplicationResponse::FileStream(_)
+                | ApplicationResponse::ReplayedJson(_) => {
                     Err(errors::ProcessTrackerError::ResourceFetchingFailed {
                         resource_name: tracking_data.primary_object_id.clone(),
                     })
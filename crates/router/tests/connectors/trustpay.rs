@@ -54,6 +54,7 @@ fn get_default_browser_info() -> BrowserInformation {
         device_model: None,
         accept_language: Some("en".to_string()),
         referer: None,
+        device_fingerprint: None,
     }
 }
 
@@ -1,4 +1,12 @@
-use std::{fmt::Debug, marker::PhantomData, str::FromStr, sync::Arc, time::Duration};
+use std::{
+    collections::HashMap,
+    fmt::Debug,
+    marker::PhantomData,
+    path::PathBuf,
+    str::FromStr,
+    sync::{Arc, LazyLock, Mutex},
+    time::Duration,
+};
 
 use async_trait::async_trait;
 use common_utils::{id_type::GenerateId, pii::Email};
@@ -613,22 +621,7 @@ pub trait ConnectorActions: Connector {
             .connector
             .get_connector_integration();
         let request = self.get_payout_request(None, payout_type, payment_info);
-        let tx: oneshot::Sender<()> = oneshot::channel().0;
-
-        let app_state = Box::pin(routes::AppState::with_storage(
-            Settings::new().unwrap(),
-            StorageImpl::PostgresqlTest,
-            tx,
-            Box::new(services::MockApiClient),
-        ))
-        .await;
-        let state = Arc::new(app_state)
-            .get_session_state(
-                &common_utils::id_type::TenantId::try_from_string("public".to_string()).unwrap(),
-                None,
-                || {},
-            )
-            .unwrap();
+        let state = get_session_state().await;
         let res = services::api::execute_connector_processing_step(
             &state,
             connector_integration,
@@ -658,22 +651,7 @@ pub trait ConnectorActions: Connector {
             .connector
             .get_connector_integration();
         let request = self.get_payout_request(connector_payout_id, payout_type, payment_info);
-        let tx: oneshot::Sender<()> = oneshot::channel().0;
-
-        let app_state = Box::pin(routes::AppState::with_storage(
-            Settings::new().unwrap(),
-            StorageImpl::PostgresqlTest,
-            tx,
-            Box::new(services::MockApiClient),
-        ))
-        .await;
-        let state = Arc::new(app_state)
-            .get_session_state(
-                &common_utils::id_type::TenantId::try_from_string("public".to_string()).unwrap(),
-                None,
-                || {},
-            )
-            .unwrap();
+        let state = get_session_state().await;
         let res = services::api::execute_connector_processing_step(
             &state,
             connector_integration,
@@ -704,22 +682,7 @@ pub trait ConnectorActions: Connector {
             .get_connector_integration();
         let mut request = self.get_payout_request(None, payout_type, payment_info);
         request.connector_customer = connector_customer;
-        let tx: oneshot::Sender<()> = oneshot::channel().0;
-
-        let app_state = Box::pin(routes::AppState::with_storage(
-            Settings::new().unwrap(),
-            StorageImpl::PostgresqlTest,
-            tx,
-            Box::new(services::MockApiClient),
-        ))
-        .await;
-        let state = Arc::new(app_state)
-            .get_session_state(
-                &common_utils::id_type::TenantId::try_from_string("public".to_string()).unwrap(),
-                None,
-                || {},
-            )
-            .unwrap();
+        let state = get_session_state().await;
         let res = services::api::execute_connector_processing_step(
             &state,
             connector_integration,
@@ -749,22 +712,7 @@ pub trait ConnectorActions: Connector {
             .connector
             .get_connector_integration();
         let request = self.get_payout_request(Some(connector_payout_id), payout_type, payment_info);
-        let tx: oneshot::Sender<()> = oneshot::channel().0;
-
-        let app_state = Box::pin(routes::AppState::with_storage(
-            Settings::new().unwrap(),
-            StorageImpl::PostgresqlTest,
-            tx,
-            Box::new(services::MockApiClient),
-        ))
-        .await;
-        let state = Arc::new(app_state)
-            .get_session_state(
-                &common_utils::id_type::TenantId::try_from_string("public".to_string()).unwrap(),
-                None,
-                || {},
-            )
-            .unwrap();
+        let state = get_session_state().await;
         let res = services::api::execute_connector_processing_step(
             &state,
             connector_integration,
@@ -845,22 +793,7 @@ pub trait ConnectorActions: Connector {
             .connector
             .get_connector_integration();
         let request = self.get_payout_request(None, payout_type, payment_info);
-        let tx = oneshot::channel().0;
-
-        let app_state = Box::pin(routes::AppState::with_storage(
-            Settings::new().unwrap(),
-            StorageImpl::PostgresqlTest,
-            tx,
-            Box::new(services::MockApiClient),
-        ))
-        .await;
-        let state = Arc::new(app_state)
-            .get_session_state(
-                &common_utils::id_type::TenantId::try_from_string("public".to_string()).unwrap(),
-                None,
-                || {},
-            )
-            .unwrap();
+        let state = get_session_state().await;
         let res = services::api::execute_connector_processing_step(
             &state,
             connector_integration,
@@ -886,32 +819,102 @@ async fn call_connector<
     request: RouterData<T, Req, Resp>,
     integration: BoxedConnectorIntegrationInterface<T, ResourceCommonData, Req, Resp>,
 ) -> Result<RouterData<T, Req, Resp>, Report<ConnectorError>> {
-    let conf = Settings::new().unwrap();
+    let state = get_session_state().await;
+    services::api::execute_connector_processing_step(
+        &state,
+        integration,
+        &request,
+        payments::CallConnectorAction::Trigger,
+        None,
+        None,
+    )
+    .await
+}
+
+/// Environment variable selecting whether connector calls are recorded into cassettes (`record`)
+/// or replayed from them (`replay`). When unset, calls are made through the mock API client.
+const CASSETTE_MODE_ENV: &str = "CONNECTOR_CASSETTE_MODE";
+/// Environment variable overriding the directory cassettes are kept in
+const CASSETTE_DIR_ENV: &str = "CONNECTOR_CASSETTE_DIR";
+
+static CASSETTES: LazyLock<Mutex<HashMap<PathBuf, services::CassetteApiClient>>> =
+    LazyLock::new(Default::default);
+
+fn get_cassette_mode() -> Option<services::CassetteMode> {
+    std::env::var(CASSETTE_MODE_ENV)
+        .ok()
+        .map(|mode| services::CassetteMode::from_str(&mode).unwrap())
+}
+
+/// The cassette client of the running test, shared by all the connector calls made in it. The
+/// cassette is named after the test, which is the name libtest gives to the test thread.
+fn get_cassette_api_client(
+    mode: services::CassetteMode,
+    proxy: &services::api::client::Proxy,
+) -> services::CassetteApiClient {
+    let directory = std::env::var(CASSETTE_DIR_ENV)
+        .map(PathBuf::from)
+        .unwrap_or_else(|_| {
+            PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests/connectors/cassettes")
+        });
+    let test_name = std::thread::current()
+        .name()
+        .expect("Test thread has no name")
+        .replace("::", "/");
+    let path = directory.join(format!("{test_name}.json"));
+
+    CASSETTES
+        .lock()
+        .unwrap()
+        .entry(path.clone())
+        .or_insert_with(|| {
+            let inner: Box<dyn services::ApiClient> = match mode {
+                services::CassetteMode::Record => {
+                    Box::new(services::ProxyClient::new(proxy).unwrap())
+                }
+                services::CassetteMode::Replay => Box::new(services::MockApiClient),
+            };
+            services::CassetteApiClient::new(mode, path, inner).unwrap()
+        })
+        .clone()
+}
+
+async fn get_session_state() -> routes::SessionState {
+    let mut conf = Settings::new().unwrap();
+    let (storage_impl, api_client): (_, Box<dyn services::ApiClient>) = match get_cassette_mode() {
+        Some(mode @ services::CassetteMode::Record) => (
+            StorageImpl::PostgresqlTest,
+            Box::new(get_cassette_api_client(mode, &conf.proxy)),
+        ),
+        // Replayed tests need neither a database nor a Redis server
+        Some(mode @ services::CassetteMode::Replay) => {
+            conf.redis.in_memory = true;
+            (
+                StorageImpl::Mock,
+                Box::new(get_cassette_api_client(mode, &conf.proxy)),
+            )
+        }
+        None => (
+            StorageImpl::PostgresqlTest,
+            Box::new(services::MockApiClient),
+        ),
+    };
     let tx: oneshot::Sender<()> = oneshot::channel().0;
 
     let app_state = Box::pin(routes::AppState::with_storage(
         conf,
-        StorageImpl::PostgresqlTest,
+        storage_impl,
         tx,
-        Box::new(services::MockApiClient),
+        api_client,
     ))
     .await;
-    let state = Arc::new(app_state)
+    Arc::new(app_state)
         .get_session_state(
             &common_utils::id_type::TenantId::try_from_string("public".to_string()).unwrap(),
             None,
             || {},
         )
-        .unwrap();
-    services::api::execute_connector_processing_step(
-        &state,
-        integration,
-        &request,
-        payments::CallConnectorAction::Trigger,
-        None,
-        None,
-    )
-    .await
+        .unwrap()
 }
 
 pub struct MockConfig {
@@ -1070,6 +1073,7 @@ impl Default for BrowserInfoType {
             os_version: Some("IOS 14.5".to_string()),
             accept_language: Some("en".to_string()),
             referer: None,
+            device_fingerprint: None,
         };
         Self(data)
     }
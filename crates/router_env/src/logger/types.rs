@@ -92,6 +92,12 @@ pub enum Flow {
     MerchantConnectorsList,
     /// Merchant Transfer Keys
     MerchantTransferKey,
+    /// Merchant data encryption key rotation flow
+    MerchantKeyRotate,
+    /// Master key rotation flow
+    MasterKeyRotate,
+    /// Key rotation retrieve flow
+    KeyRotationRetrieve,
     /// Merchant Connector Webhook register flow.
     MerchantConnectorWebhookRegister,
     /// Merchant Connector Webhook list flow.
@@ -255,6 +261,12 @@ pub enum Flow {
     RefundsAggregate,
     // Retrieve forex flow.
     RetrieveForexFlow,
+    /// Upload the forex rate table of a merchant
+    ForexRateTableUpsert,
+    /// Retrieve the forex rate table of a merchant
+    ForexRateTableRetrieve,
+    /// Delete the forex rate table of a merchant
+    ForexRateTableDelete,
     /// Toggles recon service for a merchant.
     ReconMerchantUpdate,
     /// Recon token request flow.
@@ -271,6 +283,8 @@ pub enum Flow {
     RoutingUnlinkConfig,
     /// Routing retrieve config
     RoutingRetrieveConfig,
+    /// Routing simulate config against historical payments
+    RoutingSimulateConfig,
     /// Routing retrieve active config
     RoutingRetrieveActiveConfig,
     /// Routing retrieve default config
@@ -319,6 +333,10 @@ pub enum Flow {
     ListBlocklist,
     /// Toggle blocklist for merchant
     ToggleBlocklistGuard,
+    /// Bulk import entries to blocklist
+    ImportBlocklist,
+    /// Export blocklist entries
+    ExportBlocklist,
     /// Incoming Webhook Receive
     IncomingWebhookReceive,
     /// Recovery incoming webhook receive
@@ -397,6 +415,10 @@ pub enum Flow {
     ApplePayCertificatesMigration,
     /// Gsm Rule Delete flow
     GsmRuleDelete,
+    /// Gsm Rules bulk import flow
+    GsmRulesImport,
+    /// Gsm Rules export flow
+    GsmRulesExport,
     /// Get data from embedded flow
     GetDataFromHyperswitchAiFlow,
     // List all chat interactions
@@ -603,6 +625,12 @@ pub enum Flow {
     WebhookEventDeliveryAttemptList,
     /// Manually retry the delivery for a webhook event
     WebhookEventDeliveryRetry,
+    /// Rotate the key used to sign outgoing webhooks for a profile
+    WebhookSigningKeyRotate,
+    /// List the keys used to sign outgoing webhooks for a profile
+    WebhookSigningKeyList,
+    /// Retire a key used to sign outgoing webhooks for a profile
+    WebhookSigningKeyRetire,
     /// Retrieve status of the Poll
     RetrievePollStatus,
     /// Toggles the extended card info feature in profile level
@@ -213,28 +213,62 @@ impl ProcessTrackerInterface for MockDb {
 
     async fn reinitialize_limbo_processes(
         &self,
-        _ids: Vec<String>,
-        _schedule_time: PrimitiveDateTime,
+        ids: Vec<String>,
+        schedule_time: PrimitiveDateTime,
     ) -> CustomResult<usize, errors::StorageError> {
-        // [#172]: Implement function for `MockDb`
-        Err(errors::StorageError::MockDbError)?
+        let mut processes = self.processes.lock().await;
+        let mut updated = 0;
+        processes
+            .iter_mut()
+            .filter(|process| {
+                process.status == storage_enums::ProcessTrackerStatus::ProcessStarted
+                    && ids.contains(&process.id)
+            })
+            .for_each(|process| {
+                process.status = storage_enums::ProcessTrackerStatus::Processing;
+                process.schedule_time = Some(schedule_time);
+                updated += 1;
+            });
+
+        Ok(updated)
     }
 
     async fn find_processes_by_time_status(
         &self,
-        _time_lower_limit: PrimitiveDateTime,
-        _time_upper_limit: PrimitiveDateTime,
-        _status: storage_enums::ProcessTrackerStatus,
-        _limit: Option<i64>,
+        time_lower_limit: PrimitiveDateTime,
+        time_upper_limit: PrimitiveDateTime,
+        status: storage_enums::ProcessTrackerStatus,
+        limit: Option<i64>,
     ) -> CustomResult<Vec<storage::ProcessTracker>, errors::StorageError> {
-        // [#172]: Implement function for `MockDb`
-        Err(errors::StorageError::MockDbError)?
+        let limit = limit.map_or(usize::MAX, |limit| {
+            usize::try_from(limit).unwrap_or(usize::MAX)
+        });
+        let processes = self.processes.lock().await;
+
+        Ok(processes
+            .iter()
+            .filter(|process| {
+                process.schedule_time.is_some_and(|schedule_time| {
+                    schedule_time >= time_lower_limit && schedule_time <= time_upper_limit
+                }) && process.status == status
+                    && process.version == common_types::consts::API_VERSION
+            })
+            .take(limit)
+            .cloned()
+            .collect())
     }
+
     async fn insert_process(
         &self,
         new: storage::ProcessTrackerNew,
     ) -> CustomResult<storage::ProcessTracker, errors::StorageError> {
         let mut processes = self.processes.lock().await;
+        if processes.iter().any(|process| process.id == new.id) {
+            Err(errors::StorageError::DuplicateValue {
+                entity: "process_tracker",
+                key: Some(new.id),
+            })?
+        }
         let process = storage::ProcessTracker {
             id: new.id,
             name: new.name,
@@ -258,46 +292,94 @@ impl ProcessTrackerInterface for MockDb {
 
     async fn update_process(
         &self,
-        _this: storage::ProcessTracker,
-        _process: storage::ProcessTrackerUpdate,
+        this: storage::ProcessTracker,
+        process: storage::ProcessTrackerUpdate,
     ) -> CustomResult<storage::ProcessTracker, errors::StorageError> {
-        // [#172]: Implement function for `MockDb`
-        Err(errors::StorageError::MockDbError)?
+        let mut processes = self.processes.lock().await;
+        let stored = processes
+            .iter_mut()
+            .find(|stored| stored.id == this.id)
+            .ok_or_else(|| {
+                errors::StorageError::ValueNotFound(format!(
+                    "No process tracker entry found for id = {}",
+                    this.id
+                ))
+            })?;
+        *stored = storage::ProcessTrackerUpdateInternal::from(process).apply_changeset(this);
+
+        Ok(stored.clone())
     }
 
     async fn reset_process(
         &self,
-        _this: storage::ProcessTracker,
-        _schedule_time: PrimitiveDateTime,
+        this: storage::ProcessTracker,
+        schedule_time: PrimitiveDateTime,
     ) -> CustomResult<(), errors::StorageError> {
-        // [#172]: Implement function for `MockDb`
-        Err(errors::StorageError::MockDbError)?
+        self.update_process(
+            this,
+            storage::ProcessTrackerUpdate::StatusRetryUpdate {
+                status: storage_enums::ProcessTrackerStatus::New,
+                retry_count: 0,
+                schedule_time,
+            },
+        )
+        .await?;
+        Ok(())
     }
 
     async fn retry_process(
         &self,
-        _this: storage::ProcessTracker,
-        _schedule_time: PrimitiveDateTime,
+        this: storage::ProcessTracker,
+        schedule_time: PrimitiveDateTime,
     ) -> CustomResult<(), errors::StorageError> {
-        // [#172]: Implement function for `MockDb`
-        Err(errors::StorageError::MockDbError)?
+        metrics::TASK_RETRIED.add(1, &[]);
+        let retry_count = this.retry_count + 1;
+        self.update_process(
+            this,
+            storage::ProcessTrackerUpdate::StatusRetryUpdate {
+                status: storage_enums::ProcessTrackerStatus::Pending,
+                retry_count,
+                schedule_time,
+            },
+        )
+        .await?;
+        Ok(())
     }
 
     async fn finish_process_with_business_status(
         &self,
-        _this: storage::ProcessTracker,
-        _business_status: &'static str,
+        this: storage::ProcessTracker,
+        business_status: &'static str,
     ) -> CustomResult<(), errors::StorageError> {
-        // [#172]: Implement function for `MockDb`
-        Err(errors::StorageError::MockDbError)?
+        self.update_process(
+            this,
+            storage::ProcessTrackerUpdate::StatusUpdate {
+                status: storage_enums::ProcessTrackerStatus::Finish,
+                business_status: Some(String::from(business_status)),
+            },
+        )
+        .await
+        .attach_printable("Failed to update business status of process")?;
+        metrics::TASK_FINISHED.add(1, &[]);
+        Ok(())
     }
 
     async fn process_tracker_update_process_status_by_ids(
         &self,
-        _task_ids: Vec<String>,
-        _task_update: storage::ProcessTrackerUpdate,
+        task_ids: Vec<String>,
+        task_update: storage::ProcessTrackerUpdate,
     ) -> CustomResult<usize, errors::StorageError> {
-        // [#172]: Implement function for `MockDb`
-        Err(errors::StorageError::MockDbError)?
+        let task_update = storage::ProcessTrackerUpdateInternal::from(task_update);
+        let mut processes = self.processes.lock().await;
+        let mut updated = 0;
+        processes
+            .iter_mut()
+            .filter(|process| task_ids.contains(&process.id))
+            .for_each(|process| {
+                *process = task_update.clone().apply_changeset(process.clone());
+                updated += 1;
+            });
+
+        Ok(updated)
     }
 }
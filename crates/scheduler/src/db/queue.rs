@@ -4,7 +4,9 @@ use redis_interface::{errors::RedisError, RedisEntryId, SetnxReply};
 use router_env::logger;
 use storage_impl::{mock_db::MockDb, redis::kv_store::RedisConnInterface};
 
-use crate::{errors::ProcessTrackerError, scheduler::Store};
+use crate::{
+    db::process_tracker::ProcessTrackerInterface, errors::ProcessTrackerError, scheduler::Store,
+};
 
 #[async_trait::async_trait]
 pub trait QueueInterface {
@@ -42,6 +44,72 @@ pub trait QueueInterface {
     async fn get_key(&self, key: &str) -> CustomResult<Vec<u8>, RedisError>;
 }
 
+async fn fetch_consumer_tasks_from_stream(
+    db: &(impl ProcessTrackerInterface + RedisConnInterface),
+    stream_name: &str,
+    group_name: &str,
+    consumer_name: &str,
+) -> CustomResult<Vec<storage::ProcessTracker>, ProcessTrackerError> {
+    crate::consumer::fetch_consumer_tasks(
+        db,
+        &db.get_redis_conn()
+            .map_err(ProcessTrackerError::ERedisError)?
+            .clone(),
+        stream_name,
+        group_name,
+        consumer_name,
+    )
+    .await
+}
+
+async fn acquire_lock(
+    db: &impl RedisConnInterface,
+    tag: &str,
+    lock_key: &str,
+    lock_val: &str,
+    ttl: i64,
+) -> CustomResult<bool, RedisError> {
+    let conn = db.get_redis_conn()?.clone();
+    let is_lock_acquired = conn
+        .set_key_if_not_exists_with_expiry(&lock_key.into(), lock_val, None)
+        .await;
+    Ok(match is_lock_acquired {
+        Ok(SetnxReply::KeySet) => match conn.set_expiry(&lock_key.into(), ttl).await {
+            Ok(()) => true,
+
+            #[allow(unused_must_use)]
+            Err(error) => {
+                logger::error!(?error);
+                conn.delete_key(&lock_key.into()).await;
+                false
+            }
+        },
+        Ok(SetnxReply::KeyNotSet) => {
+            logger::error!(%tag, "Lock not acquired, previous fetch still in progress");
+            false
+        }
+        Err(error) => {
+            logger::error!(?error, %tag, "Error while locking");
+            false
+        }
+    })
+}
+
+async fn release_lock(
+    db: &impl RedisConnInterface,
+    tag: &str,
+    lock_key: &str,
+) -> CustomResult<bool, RedisError> {
+    let is_lock_released = db.get_redis_conn()?.delete_key(&lock_key.into()).await;
+    Ok(match is_lock_released {
+        Ok(_del_reply) => true,
+        Err(error) => {
+            logger::error!(?error, %tag, "Error while releasing lock");
+            false
+        }
+    })
+}
+
 #[async_trait::async_trait]
 impl QueueInterface for Store {
     async fn fetch_consumer_tasks(
@@ -50,17 +118,7 @@ impl QueueInterface for Store {
         group_name: &str,
         consumer_name: &str,
     ) -> CustomResult<Vec<storage::ProcessTracker>, ProcessTrackerError> {
-        crate::consumer::fetch_consumer_tasks(
-            self,
-            &self
-                .get_redis_conn()
-                .map_err(ProcessTrackerError::ERedisError)?
-                .clone(),
-            stream_name,
-            group_name,
-            consumer_name,
-        )
-        .await
+        fetch_consumer_tasks_from_stream(self, stream_name, group_name, consumer_name).await
     }
 
     async fn consumer_group_create(
@@ -81,41 +139,11 @@ impl QueueInterface for Store {
         lock_val: &str,
         ttl: i64,
     ) -> CustomResult<bool, RedisError> {
-        let conn = self.get_redis_conn()?.clone();
-        let is_lock_acquired = conn
-            .set_key_if_not_exists_with_expiry(&lock_key.into(), lock_val, None)
-            .await;
-        Ok(match is_lock_acquired {
-            Ok(SetnxReply::KeySet) => match conn.set_expiry(&lock_key.into(), ttl).await {
-                Ok(()) => true,
-
-                #[allow(unused_must_use)]
-                Err(error) => {
-                    logger::error!(?error);
-                    conn.delete_key(&lock_key.into()).await;
-                    false
-                }
-            },
-            Ok(SetnxReply::KeyNotSet) => {
-                logger::error!(%tag, "Lock not acquired, previous fetch still in progress");
-                false
-            }
-            Err(error) => {
-                logger::error!(?error, %tag, "Error while locking");
-                false
-            }
-        })
+        acquire_lock(self, tag, lock_key, lock_val, ttl).await
     }
 
     async fn release_pt_lock(&self, tag: &str, lock_key: &str) -> CustomResult<bool, RedisError> {
-        let is_lock_released = self.get_redis_conn()?.delete_key(&lock_key.into()).await;
-        Ok(match is_lock_released {
-            Ok(_del_reply) => true,
-            Err(error) => {
-                logger::error!(?error, %tag, "Error while releasing lock");
-                false
-            }
-        })
+        release_lock(self, tag, lock_key).await
     }
 
     async fn stream_append_entry(
@@ -134,57 +162,56 @@ impl QueueInterface for Store {
     }
 }
 
+/// The mock store still holds a Redis connection (usually the in-memory one), so the queue
+/// operations go through it exactly as they do for the database backed store.
 #[async_trait::async_trait]
 impl QueueInterface for MockDb {
     async fn fetch_consumer_tasks(
         &self,
-        _stream_name: &str,
-        _group_name: &str,
-        _consumer_name: &str,
+        stream_name: &str,
+        group_name: &str,
+        consumer_name: &str,
     ) -> CustomResult<Vec<storage::ProcessTracker>, ProcessTrackerError> {
-        // [#172]: Implement function for `MockDb`
-        Err(ProcessTrackerError::ResourceFetchingFailed {
-            resource_name: "consumer_tasks".to_string(),
-        })?
+        fetch_consumer_tasks_from_stream(self, stream_name, group_name, consumer_name).await
     }
 
     async fn consumer_group_create(
         &self,
-        _stream: &str,
-        _group: &str,
-        _id: &RedisEntryId,
+        stream: &str,
+        group: &str,
+        id: &RedisEntryId,
     ) -> CustomResult<(), RedisError> {
-        // [#172]: Implement function for `MockDb`
-        Err(RedisError::ConsumerGroupCreateFailed)?
+        self.get_redis_conn()?
+            .consumer_group_create(&stream.into(), group, id)
+            .await
     }
 
     async fn acquire_pt_lock(
         &self,
-        _tag: &str,
-        _lock_key: &str,
-        _lock_val: &str,
-        _ttl: i64,
+        tag: &str,
+        lock_key: &str,
+        lock_val: &str,
+        ttl: i64,
     ) -> CustomResult<bool, RedisError> {
-        // [#172]: Implement function for `MockDb`
-        Ok(false)
+        acquire_lock(self, tag, lock_key, lock_val, ttl).await
     }
 
-    async fn release_pt_lock(&self, _tag: &str, _lock_key: &str) -> CustomResult<bool, RedisError> {
-        // [#172]: Implement function for `MockDb`
-        Ok(false)
+    async fn release_pt_lock(&self, tag: &str, lock_key: &str) -> CustomResult<bool, RedisError> {
+        release_lock(self, tag, lock_key).await
     }
 
     async fn stream_append_entry(
         &self,
-        _stream: &str,
-        _entry_id: &RedisEntryId,
-        _fields: Vec<(&str, String)>,
+        stream: &str,
+        entry_id: &RedisEntryId,
+        fields: Vec<(&str, String)>,
     ) -> CustomResult<(), RedisError> {
-        // [#172]: Implement function for `MockDb`
-        Err(RedisError::StreamAppendFailed)?
+        self.get_redis_conn()?
+            .stream_append_entry(&stream.into(), entry_id, fields)
+            .await
     }
 
-    async fn get_key(&self, _key: &str) -> CustomResult<Vec<u8>, RedisError> {
-        Err(RedisError::RedisConnectionError.into())
+    async fn get_key(&self, key: &str) -> CustomResult<Vec<u8>, RedisError> {
+        self.get_redis_conn()?.get_key::<Vec<u8>>(&key.into()).await
     }
 }
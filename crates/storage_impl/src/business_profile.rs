@@ -126,7 +126,7 @@ impl<T: DatabaseStore> ProfileInterface for RouterStore<T> {
             .convert(
                 self.get_keymanager_state()
                     .attach_printable("Missing KeyManagerState")?,
-                merchant_key_store.key.get_inner(),
+                &merchant_key_store.get_key_ring(),
                 merchant_key_store.merchant_id.clone().into(),
             )
             .await
@@ -201,7 +201,7 @@ impl<T: DatabaseStore> ProfileInterface for RouterStore<T> {
             .convert(
                 self.get_keymanager_state()
                     .attach_printable("Missing KeyManagerState")?,
-                merchant_key_store.key.get_inner(),
+                &merchant_key_store.get_key_ring(),
                 merchant_key_store.merchant_id.clone().into(),
             )
             .await
@@ -256,7 +256,7 @@ impl ProfileInterface for MockDb {
             .convert(
                 self.get_keymanager_state()
                     .attach_printable("Missing KeyManagerState")?,
-                merchant_key_store.key.get_inner(),
+                &merchant_key_store.get_key_ring(),
                 merchant_key_store.merchant_id.clone().into(),
             )
             .await
@@ -279,7 +279,7 @@ impl ProfileInterface for MockDb {
                     .convert(
                         self.get_keymanager_state()
                             .attach_printable("Missing KeyManagerState")?,
-                        merchant_key_store.key.get_inner(),
+                        &merchant_key_store.get_key_ring(),
                         merchant_key_store.merchant_id.clone().into(),
                     )
                     .await
@@ -314,7 +314,7 @@ impl ProfileInterface for MockDb {
                 business_profile
                     .convert(
                         self.get_keymanager_state().attach_printable("Missing KeyManagerState")?,
-                        merchant_key_store.key.get_inner(),
+                        &merchant_key_store.get_key_ring(),
                         merchant_key_store.merchant_id.clone().into(),
                     )
                     .await
@@ -354,7 +354,7 @@ impl ProfileInterface for MockDb {
                     .convert(
                         self.get_keymanager_state()
                             .attach_printable("Missing KeyManagerState")?,
-                        merchant_key_store.key.get_inner(),
+                        &merchant_key_store.get_key_ring(),
                         merchant_key_store.merchant_id.clone().into(),
                     )
                     .await
@@ -410,7 +410,7 @@ impl ProfileInterface for MockDb {
                 .convert(
                     self.get_keymanager_state()
                         .attach_printable("Missing KeyManagerState")?,
-                    merchant_key_store.key.get_inner(),
+                    &merchant_key_store.get_key_ring(),
                     merchant_key_store.merchant_id.clone().into(),
                 )
                 .await
@@ -440,7 +440,7 @@ impl ProfileInterface for MockDb {
                 business_profile
                     .convert(
                         self.get_keymanager_state().attach_printable("Missing KeyManagerState")?,
-                        merchant_key_store.key.get_inner(),
+                        &merchant_key_store.get_key_ring(),
                         merchant_key_store.merchant_id.clone().into(),
                     )
                     .await
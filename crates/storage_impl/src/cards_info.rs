@@ -87,15 +87,31 @@ impl CardsInfoInterface for MockDb {
             .cloned())
     }
 
-    async fn add_card_info(&self, _data: CardInfo) -> CustomResult<CardInfo, StorageError> {
-        Err(StorageError::MockDbError)?
+    async fn add_card_info(&self, data: CardInfo) -> CustomResult<CardInfo, StorageError> {
+        let mut cards_info = self.cards_info.lock().await;
+        if cards_info.iter().any(|ci| ci.card_iin == data.card_iin) {
+            Err(StorageError::DuplicateValue {
+                entity: "card_iin",
+                key: Some(data.card_iin.clone()),
+            })?
+        }
+        cards_info.push(data.clone());
+        Ok(data)
     }
 
     async fn update_card_info(
         &self,
-        _card_iin: String,
-        _data: UpdateCardInfo,
+        card_iin: String,
+        data: UpdateCardInfo,
     ) -> CustomResult<CardInfo, StorageError> {
-        Err(StorageError::MockDbError)?
+        let mut cards_info = self.cards_info.lock().await;
+        let card_info = cards_info
+            .iter_mut()
+            .find(|ci| ci.card_iin == card_iin)
+            .ok_or(StorageError::ValueNotFound(format!(
+                "No card info available for card_iin = {card_iin}"
+            )))?;
+        *card_info = data.apply_changeset(card_info.clone());
+        Ok(card_info.clone())
     }
 }
@@ -847,12 +847,17 @@ impl domain::CustomerInterface for MockDb {
     #[cfg(feature = "v2")]
     async fn find_optional_by_merchant_id_merchant_reference_id(
         &self,
-        _customer_id: &id_type::CustomerId,
-        _merchant_id: &id_type::MerchantId,
-        _key_store: &MerchantKeyStore,
+        customer_id: &id_type::CustomerId,
+        merchant_id: &id_type::MerchantId,
+        key_store: &MerchantKeyStore,
         _storage_scheme: MerchantStorageScheme,
     ) -> CustomResult<Option<domain::Customer>, StorageError> {
-        todo!()
+        let customers = self.customers.lock().await;
+        self.find_resource(key_store, customers, |customer| {
+            customer.merchant_reference_id.as_ref() == Some(customer_id)
+                && &customer.merchant_id == merchant_id
+        })
+        .await
     }
 
     async fn list_customers_by_merchant_id(
@@ -875,7 +880,7 @@ impl domain::CustomerInterface for MockDb {
                         .convert(
                             self.get_keymanager_state()
                                 .attach_printable("Missing KeyManagerState")?,
-                            key_store.key.get_inner(),
+                            &key_store.get_key_ring(),
                             key_store.merchant_id.clone().into(),
                         )
                         .await
@@ -907,7 +912,7 @@ impl domain::CustomerInterface for MockDb {
                         .convert(
                             self.get_keymanager_state()
                                 .attach_printable("Missing KeyManagerState")?,
-                            key_store.key.get_inner(),
+                            &key_store.get_key_ring(),
                             key_store.merchant_id.clone().into(),
                         )
                         .await
@@ -926,39 +931,75 @@ impl domain::CustomerInterface for MockDb {
     #[instrument(skip_all)]
     async fn update_customer_by_customer_id_merchant_id(
         &self,
-        _customer_id: id_type::CustomerId,
-        _merchant_id: id_type::MerchantId,
-        _customer: domain::Customer,
-        _customer_update: domain::CustomerUpdate,
-        _key_store: &MerchantKeyStore,
+        customer_id: id_type::CustomerId,
+        merchant_id: id_type::MerchantId,
+        customer: domain::Customer,
+        customer_update: domain::CustomerUpdate,
+        key_store: &MerchantKeyStore,
         _storage_scheme: MerchantStorageScheme,
     ) -> CustomResult<domain::Customer, StorageError> {
-        // [#172]: Implement function for `MockDb`
-        Err(StorageError::MockDbError)?
+        let customer = Conversion::convert(customer)
+            .await
+            .change_context(StorageError::EncryptionError)?;
+        let updated_customer =
+            diesel_models::CustomerUpdateInternal::from(customer_update).apply_changeset(customer);
+        self.update_resource(
+            key_store,
+            self.customers.lock().await,
+            updated_customer,
+            |customer| customer.customer_id == customer_id && customer.merchant_id == merchant_id,
+            "cannot find customer to update".to_string(),
+        )
+        .await
     }
 
     #[cfg(feature = "v1")]
     async fn find_customer_by_customer_id_merchant_id(
         &self,
-        _customer_id: &id_type::CustomerId,
-        _merchant_id: &id_type::MerchantId,
-        _key_store: &MerchantKeyStore,
+        customer_id: &id_type::CustomerId,
+        merchant_id: &id_type::MerchantId,
+        key_store: &MerchantKeyStore,
         _storage_scheme: MerchantStorageScheme,
     ) -> CustomResult<domain::Customer, StorageError> {
-        // [#172]: Implement function for `MockDb`
-        Err(StorageError::MockDbError)?
+        let customer: domain::Customer = self
+            .get_resource(
+                key_store,
+                self.customers.lock().await,
+                |customer| {
+                    customer.customer_id == *customer_id && &customer.merchant_id == merchant_id
+                },
+                "cannot find customer".to_string(),
+            )
+            .await?;
+        match customer.name {
+            Some(ref name) if name.peek() == pii::REDACTED => Err(StorageError::CustomerRedacted)?,
+            _ => Ok(customer),
+        }
     }
 
     #[cfg(feature = "v2")]
     async fn find_customer_by_merchant_reference_id_merchant_id(
         &self,
-        _merchant_reference_id: &id_type::CustomerId,
-        _merchant_id: &id_type::MerchantId,
-        _key_store: &MerchantKeyStore,
+        merchant_reference_id: &id_type::CustomerId,
+        merchant_id: &id_type::MerchantId,
+        key_store: &MerchantKeyStore,
         _storage_scheme: MerchantStorageScheme,
     ) -> CustomResult<domain::Customer, StorageError> {
-        // [#172]: Implement function for `MockDb`
-        Err(StorageError::MockDbError)?
+        let customer: domain::Customer = self
+            .get_resource(
+                key_store,
+                self.customers.lock().await,
+                |customer| {
+                    customer.merchant_reference_id.as_ref() == Some(merchant_reference_id)
+                        && &customer.merchant_id == merchant_id
+                },
+                "cannot find customer".to_string(),
+            )
+            .await?;
+        match customer.name {
+            Some(ref name) if name.peek() == pii::REDACTED => Err(StorageError::CustomerRedacted)?,
+            _ => Ok(customer),
+        }
     }
 
     #[allow(clippy::panic)]
@@ -980,7 +1021,7 @@ impl domain::CustomerInterface for MockDb {
             .convert(
                 self.get_keymanager_state()
                     .attach_printable("Missing KeyManagerState")?,
-                key_store.key.get_inner(),
+                &key_store.get_key_ring(),
                 key_store.merchant_id.clone().into(),
             )
             .await
@@ -990,47 +1031,87 @@ impl domain::CustomerInterface for MockDb {
     #[cfg(feature = "v1")]
     async fn delete_customer_by_customer_id_merchant_id(
         &self,
-        _customer_id: &id_type::CustomerId,
-        _merchant_id: &id_type::MerchantId,
+        customer_id: &id_type::CustomerId,
+        merchant_id: &id_type::MerchantId,
     ) -> CustomResult<bool, StorageError> {
-        // [#172]: Implement function for `MockDb`
-        Err(StorageError::MockDbError)?
+        let mut customers = self.customers.lock().await;
+        let index = customers
+            .iter()
+            .position(|customer| {
+                customer.customer_id == *customer_id && &customer.merchant_id == merchant_id
+            })
+            .ok_or(StorageError::ValueNotFound(
+                "cannot find customer to delete".to_string(),
+            ))?;
+        customers.remove(index);
+        Ok(true)
     }
 
     #[cfg(feature = "v2")]
     #[allow(clippy::too_many_arguments)]
     async fn update_customer_by_global_id(
         &self,
-        _id: &id_type::GlobalCustomerId,
-        _customer: domain::Customer,
-        _customer_update: domain::CustomerUpdate,
-        _key_store: &MerchantKeyStore,
+        id: &id_type::GlobalCustomerId,
+        customer: domain::Customer,
+        customer_update: domain::CustomerUpdate,
+        key_store: &MerchantKeyStore,
         _storage_scheme: MerchantStorageScheme,
     ) -> CustomResult<domain::Customer, StorageError> {
-        // [#172]: Implement function for `MockDb`
-        Err(StorageError::MockDbError)?
+        let customer = Conversion::convert(customer)
+            .await
+            .change_context(StorageError::EncryptionError)?;
+        let updated_customer =
+            diesel_models::CustomerUpdateInternal::from(customer_update).apply_changeset(customer);
+        self.update_resource(
+            key_store,
+            self.customers.lock().await,
+            updated_customer,
+            |customer| customer.id == *id,
+            "cannot find customer to update".to_string(),
+        )
+        .await
     }
 
     #[cfg(feature = "v2")]
     async fn find_customer_by_global_id(
         &self,
-        _id: &id_type::GlobalCustomerId,
-        _key_store: &MerchantKeyStore,
+        id: &id_type::GlobalCustomerId,
+        key_store: &MerchantKeyStore,
         _storage_scheme: MerchantStorageScheme,
     ) -> CustomResult<domain::Customer, StorageError> {
-        // [#172]: Implement function for `MockDb`
-        Err(StorageError::MockDbError)?
+        let customer: domain::Customer = self
+            .get_resource(
+                key_store,
+                self.customers.lock().await,
+                |customer| customer.id == *id,
+                "cannot find customer".to_string(),
+            )
+            .await?;
+        match customer.name {
+            Some(ref name) if name.peek() == pii::REDACTED => Err(StorageError::CustomerRedacted)?,
+            _ => Ok(customer),
+        }
     }
 
     #[cfg(feature = "v2")]
     async fn find_customer_by_global_id_merchant_id(
         &self,
-        _id: &id_type::GlobalCustomerId,
-        _merchant_id: &id_type::MerchantId,
-        _key_store: &MerchantKeyStore,
+        id: &id_type::GlobalCustomerId,
+        merchant_id: &id_type::MerchantId,
+        key_store: &MerchantKeyStore,
         _storage_scheme: MerchantStorageScheme,
     ) -> CustomResult<domain::Customer, StorageError> {
-        // [#172]: Implement function for `MockDb`
-        Err(StorageError::MockDbError)?
+        let customer: domain::Customer = self
+            .get_resource(
+                key_store,
+                self.customers.lock().await,
+                |customer| customer.id == *id && &customer.merchant_id == merchant_id,
+                "cannot find customer".to_string(),
+            )
+            .await?;
+        match customer.name {
+            Some(ref name) if name.peek() == pii::REDACTED => Err(StorageError::CustomerRedacted)?,
+            _ => Ok(customer),
+        }
     }
 }
@@ -186,43 +186,113 @@ impl InvoiceInterface for MockDb {
     #[instrument(skip_all)]
     async fn insert_invoice_entry(
         &self,
-        _key_store: &MerchantKeyStore,
-        _invoice_new: DomainInvoice,
+        key_store: &MerchantKeyStore,
+        invoice_new: DomainInvoice,
     ) -> CustomResult<DomainInvoice, StorageError> {
-        Err(StorageError::MockDbError)?
+        let invoice_new = invoice_new
+            .construct_new()
+            .await
+            .change_context(StorageError::DecryptionError)?;
+        let mut invoices = self.invoices.lock().await;
+        invoices.push(Invoice {
+            id: invoice_new.id.clone(),
+            subscription_id: invoice_new.subscription_id,
+            merchant_id: invoice_new.merchant_id,
+            profile_id: invoice_new.profile_id,
+            merchant_connector_id: invoice_new.merchant_connector_id,
+            payment_intent_id: invoice_new.payment_intent_id,
+            payment_method_id: invoice_new.payment_method_id,
+            customer_id: invoice_new.customer_id,
+            amount: invoice_new.amount,
+            currency: invoice_new.currency,
+            status: invoice_new.status,
+            provider_name: invoice_new.provider_name,
+            metadata: invoice_new.metadata,
+            created_at: invoice_new.created_at,
+            modified_at: invoice_new.modified_at,
+            connector_invoice_id: invoice_new.connector_invoice_id,
+        });
+        self.get_resource(
+            key_store,
+            invoices,
+            |invoice| invoice.id == invoice_new.id,
+            "cannot find invoice".to_string(),
+        )
+        .await
     }
 
     async fn find_invoice_by_invoice_id(
         &self,
-        _key_store: &MerchantKeyStore,
-        _invoice_id: String,
+        key_store: &MerchantKeyStore,
+        invoice_id: String,
     ) -> CustomResult<DomainInvoice, StorageError> {
-        Err(StorageError::MockDbError)?
+        self.get_resource(
+            key_store,
+            self.invoices.lock().await,
+            |invoice| invoice.id.get_string_repr() == invoice_id,
+            format!("Invoice not found for invoice_id: {invoice_id}"),
+        )
+        .await
     }
 
     async fn update_invoice_entry(
         &self,
-        _key_store: &MerchantKeyStore,
-        _invoice_id: String,
-        _data: DomainInvoiceUpdate,
+        key_store: &MerchantKeyStore,
+        invoice_id: String,
+        data: DomainInvoiceUpdate,
     ) -> CustomResult<DomainInvoice, StorageError> {
-        Err(StorageError::MockDbError)?
+        let invoice_update = data
+            .construct_new()
+            .await
+            .change_context(StorageError::DecryptionError)?;
+        let invoices = self.invoices.lock().await;
+        let invoice = invoices
+            .iter()
+            .find(|invoice| invoice.id.get_string_repr() == invoice_id)
+            .cloned()
+            .ok_or(StorageError::ValueNotFound(format!(
+                "Invoice not found for invoice_id: {invoice_id}"
+            )))?;
+        self.update_resource(
+            key_store,
+            invoices,
+            invoice_update.apply_changeset(invoice),
+            |invoice| invoice.id.get_string_repr() == invoice_id,
+            format!("Invoice not found for invoice_id: {invoice_id}"),
+        )
+        .await
     }
 
     async fn get_latest_invoice_for_subscription(
         &self,
-        _key_store: &MerchantKeyStore,
-        _subscription_id: String,
+        key_store: &MerchantKeyStore,
+        subscription_id: String,
     ) -> CustomResult<DomainInvoice, StorageError> {
-        Err(StorageError::MockDbError)?
+        let invoices = self.invoices.lock().await;
+        let latest_invoice_id = invoices
+            .iter()
+            .filter(|invoice| invoice.subscription_id.get_string_repr() == subscription_id)
+            .max_by_key(|invoice| invoice.created_at)
+            .map(|invoice| invoice.id.clone());
+        self.get_resource(
+            key_store,
+            invoices,
+            |invoice| latest_invoice_id.as_ref() == Some(&invoice.id),
+            format!("Invoice not found for subscription_id: {subscription_id}"),
+        )
+        .await
     }
 
     async fn find_invoice_by_subscription_id_connector_invoice_id(
         &self,
-        _key_store: &MerchantKeyStore,
-        _subscription_id: String,
-        _connector_invoice_id: common_utils::id_type::InvoiceId,
+        key_store: &MerchantKeyStore,
+        subscription_id: String,
+        connector_invoice_id: common_utils::id_type::InvoiceId,
     ) -> CustomResult<Option<DomainInvoice>, StorageError> {
-        Err(StorageError::MockDbError)?
+        self.find_resource(key_store, self.invoices.lock().await, |invoice| {
+            invoice.subscription_id.get_string_repr() == subscription_id
+                && invoice.connector_invoice_id.as_ref() == Some(&connector_invoice_id)
+        })
+        .await
     }
 }
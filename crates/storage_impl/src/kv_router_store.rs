@@ -277,7 +277,7 @@ impl<T: DatabaseStore> KVRouterStore<T> {
             .convert(
                 self.get_keymanager_state()
                     .attach_printable("Missing KeyManagerState")?,
-                key_store.key.get_inner(),
+                &key_store.get_key_ring(),
                 key_store.merchant_id.clone().into(),
             )
             .await
@@ -348,7 +348,7 @@ impl<T: DatabaseStore> KVRouterStore<T> {
                     .convert(
                         self.get_keymanager_state()
                             .attach_printable("Missing KeyManagerState")?,
-                        key_store.key.get_inner(),
+                        &key_store.get_key_ring(),
                         key_store.merchant_id.clone().into(),
                     )
                     .await
@@ -430,7 +430,7 @@ impl<T: DatabaseStore> KVRouterStore<T> {
         .convert(
             self.get_keymanager_state()
                 .attach_printable("Missing KeyManagerState")?,
-            key_store.key.get_inner(),
+            &key_store.get_key_ring(),
             key_store.merchant_id.clone().into(),
         )
         .await
@@ -496,7 +496,7 @@ impl<T: DatabaseStore> KVRouterStore<T> {
         .convert(
             self.get_keymanager_state()
                 .attach_printable("Missing KeyManagerState")?,
-            key_store.key.get_inner(),
+            &key_store.get_key_ring(),
             key_store.merchant_id.clone().into(),
         )
         .await
@@ -548,7 +548,7 @@ impl<T: DatabaseStore> KVRouterStore<T> {
                 pm.convert(
                     self.get_keymanager_state()
                         .attach_printable("Missing KeyManagerState")?,
-                    key_store.key.get_inner(),
+                    &key_store.get_key_ring(),
                     key_store.merchant_id.clone().into(),
                 )
                 .await
@@ -7,7 +7,7 @@ use hyperswitch_domain_models::{
     behaviour::{Conversion, ReverseConversion},
     merchant_key_store::MerchantKeyStore,
 };
-use masking::StrongSecret;
+use masking::{PeekInterface, Secret, StrongSecret};
 use redis::{kv_store::RedisConnInterface, pub_sub::PubSubInterface, RedisStore};
 mod address;
 pub mod business_profile;
@@ -56,6 +56,8 @@ pub struct RouterStore<T: DatabaseStore> {
     db_store: T,
     cache_store: Arc<RedisStore>,
     master_encryption_key: StrongSecret<Vec<u8>>,
+    /// Master key replaced by `master_encryption_key`, while key stores are being re-encrypted
+    previous_master_encryption_key: Option<StrongSecret<Vec<u8>>>,
     pub request_id: Option<String>,
     key_manager_state: Option<KeyManagerState>,
 }
@@ -64,6 +66,9 @@ impl<T: DatabaseStore> RouterStore<T> {
     pub fn set_key_manager_state(&mut self, state: KeyManagerState) {
         self.key_manager_state = Some(state);
     }
+    pub fn set_previous_master_key(&mut self, previous_master_key: StrongSecret<Vec<u8>>) {
+        self.previous_master_encryption_key = Some(previous_master_key);
+    }
     fn get_keymanager_state(&self) -> Result<&KeyManagerState, StorageError> {
         self.key_manager_state
             .as_ref()
@@ -164,6 +169,7 @@ impl<T: DatabaseStore> RouterStore<T> {
             db_store,
             cache_store,
             master_encryption_key: encryption_key,
+            previous_master_encryption_key: None,
             request_id: None,
             key_manager_state,
         })
@@ -185,6 +191,17 @@ impl<T: DatabaseStore> RouterStore<T> {
         &self.master_encryption_key
     }
 
+    /// `key` followed by the previous master key, if configured, to decrypt key stores which may
+    /// not have been re-encrypted since the master key was rotated
+    pub fn get_master_key_ring(&self, key: &Secret<Vec<u8>>) -> Secret<Vec<u8>> {
+        match &self.previous_master_encryption_key {
+            Some(previous_master_key) => {
+                Secret::new([key.peek().as_slice(), previous_master_key.peek()].concat())
+            }
+            None => key.clone(),
+        }
+    }
+
     pub async fn call_database<D, R, M>(
         &self,
         key_store: &MerchantKeyStore,
@@ -205,7 +222,7 @@ impl<T: DatabaseStore> RouterStore<T> {
             .convert(
                 self.get_keymanager_state()
                     .attach_printable("Missing KeyManagerState")?,
-                key_store.key.get_inner(),
+                &key_store.get_key_ring(),
                 key_store.merchant_id.clone().into(),
             )
             .await
@@ -233,7 +250,7 @@ impl<T: DatabaseStore> RouterStore<T> {
                     .convert(
                         self.get_keymanager_state()
                             .attach_printable("Missing KeyManagerState")?,
-                        key_store.key.get_inner(),
+                        &key_store.get_key_ring(),
                         key_store.merchant_id.clone().into(),
                     )
                     .await
@@ -267,7 +284,7 @@ impl<T: DatabaseStore> RouterStore<T> {
                     .convert(
                         self.get_keymanager_state()
                             .attach_printable("Missing KeyManagerState")?,
-                        key_store.key.get_inner(),
+                        &key_store.get_key_ring(),
                         key_store.merchant_id.clone().into(),
                     )
                     .await
@@ -300,6 +317,7 @@ impl<T: DatabaseStore> RouterStore<T> {
             db_store,
             cache_store: Arc::new(cache_store),
             master_encryption_key: encryption_key,
+            previous_master_encryption_key: None,
             request_id: None,
             key_manager_state,
         })
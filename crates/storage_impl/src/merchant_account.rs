@@ -166,7 +166,7 @@ impl<T: DatabaseStore> MerchantAccountInterface for RouterStore<T> {
             .convert(
                 self.get_keymanager_state()
                     .attach_printable("Missing KeyManagerState")?,
-                merchant_key_store.key.get_inner(),
+                &merchant_key_store.get_key_ring(),
                 merchant_key_store.merchant_id.clone().into(),
             )
             .await
@@ -195,7 +195,7 @@ impl<T: DatabaseStore> MerchantAccountInterface for RouterStore<T> {
                 .await?
                 .convert(
                     state,
-                    merchant_key_store.key.get_inner(),
+                    &merchant_key_store.get_key_ring(),
                     merchant_id.to_owned().into(),
                 )
                 .await
@@ -213,7 +213,7 @@ impl<T: DatabaseStore> MerchantAccountInterface for RouterStore<T> {
             .await?
             .convert(
                 state,
-                merchant_key_store.key.get_inner(),
+                &merchant_key_store.get_key_ring(),
                 merchant_key_store.merchant_id.clone().into(),
             )
             .await
@@ -245,7 +245,7 @@ impl<T: DatabaseStore> MerchantAccountInterface for RouterStore<T> {
             .convert(
                 self.get_keymanager_state()
                     .attach_printable("Missing KeyManagerState")?,
-                merchant_key_store.key.get_inner(),
+                &merchant_key_store.get_key_ring(),
                 merchant_key_store.merchant_id.clone().into(),
             )
             .await
@@ -276,7 +276,7 @@ impl<T: DatabaseStore> MerchantAccountInterface for RouterStore<T> {
             .convert(
                 self.get_keymanager_state()
                     .attach_printable("Missing KeyManagerState")?,
-                merchant_key_store.key.get_inner(),
+                &merchant_key_store.get_key_ring(),
                 merchant_key_store.merchant_id.clone().into(),
             )
             .await
@@ -322,7 +322,7 @@ impl<T: DatabaseStore> MerchantAccountInterface for RouterStore<T> {
             .convert(
                 self.get_keymanager_state()
                     .attach_printable("Missing KeyManagerState")?,
-                key_store.key.get_inner(),
+                &key_store.get_key_ring(),
                 key_store.merchant_id.clone().into(),
             )
             .await
@@ -363,7 +363,7 @@ impl<T: DatabaseStore> MerchantAccountInterface for RouterStore<T> {
                         .convert(
                             self.get_keymanager_state()
                                 .attach_printable("Missing KeyManagerState")?,
-                            key_store.key.get_inner(),
+                            &key_store.get_key_ring(),
                             key_store.merchant_id.clone().into(),
                         )
                         .await
@@ -454,7 +454,7 @@ impl<T: DatabaseStore> MerchantAccountInterface for RouterStore<T> {
                         .convert(
                             self.get_keymanager_state()
                                 .attach_printable("Missing KeyManagerState")?,
-                            key_store.key.get_inner(),
+                            &key_store.get_key_ring(),
                             key_store.merchant_id.clone().into(),
                         )
                         .await
@@ -548,7 +548,7 @@ impl MerchantAccountInterface for MockDb {
             .convert(
                 self.get_keymanager_state()
                     .attach_printable("Missing KeyManagerState")?,
-                merchant_key_store.key.get_inner(),
+                &merchant_key_store.get_key_ring(),
                 merchant_key_store.merchant_id.clone().into(),
             )
             .await
@@ -572,7 +572,7 @@ impl MerchantAccountInterface for MockDb {
             .convert(
                 self.get_keymanager_state()
                     .attach_printable("Missing KeyManagerState")?,
-                merchant_key_store.key.get_inner(),
+                &merchant_key_store.get_key_ring(),
                 merchant_key_store.merchant_id.clone().into(),
             )
             .await
@@ -602,7 +602,7 @@ impl MerchantAccountInterface for MockDb {
                     .convert(
                         self.get_keymanager_state()
                             .attach_printable("Missing KeyManagerState")?,
-                        merchant_key_store.key.get_inner(),
+                        &merchant_key_store.get_key_ring(),
                         merchant_key_store.merchant_id.clone().into(),
                     )
                     .await
@@ -634,7 +634,7 @@ impl MerchantAccountInterface for MockDb {
                     .convert(
                         self.get_keymanager_state()
                             .attach_printable("Missing KeyManagerState")?,
-                        merchant_key_store.key.get_inner(),
+                        &merchant_key_store.get_key_ring(),
                         merchant_key_store.merchant_id.clone().into(),
                     )
                     .await
@@ -675,7 +675,7 @@ impl MerchantAccountInterface for MockDb {
             .convert(
                 self.get_keymanager_state()
                     .attach_printable("Missing KeyManagerState")?,
-                key_store.key.get_inner(),
+                &key_store.get_key_ring(),
                 key_store.merchant_id.clone().into(),
             )
             .await
@@ -727,7 +727,7 @@ impl MerchantAccountInterface for MockDb {
                         .convert(
                             self.get_keymanager_state()
                                 .attach_printable("Missing KeyManagerState")?,
-                            key.key.get_inner(),
+                            &key.get_key_ring(),
                             key.merchant_id.clone().into(),
                         )
                         .await
@@ -763,7 +763,7 @@ impl MerchantAccountInterface for MockDb {
                         .convert(
                             self.get_keymanager_state()
                                 .attach_printable("Missing KeyManagerState")?,
-                            key.key.get_inner(),
+                            &key.get_key_ring(),
                             key.merchant_id.clone().into(),
                         )
                         .await
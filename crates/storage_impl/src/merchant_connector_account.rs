@@ -245,7 +245,7 @@ impl<T: DatabaseStore> MerchantConnectorAccountInterface for RouterStore<T> {
                 .convert(
                     self.get_keymanager_state()
                         .attach_printable("Missing KeyManagerState")?,
-                    key_store.key.get_inner(),
+                    &key_store.get_key_ring(),
                     merchant_id.clone().into(),
                 )
                 .await
@@ -265,7 +265,7 @@ impl<T: DatabaseStore> MerchantConnectorAccountInterface for RouterStore<T> {
                 item.convert(
                     self.get_keymanager_state()
                         .attach_printable("Missing KeyManagerState")?,
-                    key_store.key.get_inner(),
+                    &key_store.get_key_ring(),
                     key_store.merchant_id.clone().into(),
                 )
                 .await
@@ -301,7 +301,7 @@ impl<T: DatabaseStore> MerchantConnectorAccountInterface for RouterStore<T> {
                 .convert(
                     self.get_keymanager_state()
                         .attach_printable("Missing KeyManagerState")?,
-                    key_store.key.get_inner(),
+                    &key_store.get_key_ring(),
                     key_store.merchant_id.clone().into(),
                 )
                 .await
@@ -321,7 +321,7 @@ impl<T: DatabaseStore> MerchantConnectorAccountInterface for RouterStore<T> {
                 item.convert(
                     self.get_keymanager_state()
                         .attach_printable("Missing KeyManagerState")?,
-                    key_store.key.get_inner(),
+                    &key_store.get_key_ring(),
                     key_store.merchant_id.clone().into(),
                 )
                 .await
@@ -354,7 +354,7 @@ impl<T: DatabaseStore> MerchantConnectorAccountInterface for RouterStore<T> {
                     item.convert(
                         self.get_keymanager_state()
                             .attach_printable("Missing KeyManagerState")?,
-                        key_store.key.get_inner(),
+                        &key_store.get_key_ring(),
                         key_store.merchant_id.clone().into(),
                     )
                     .await
@@ -392,7 +392,7 @@ impl<T: DatabaseStore> MerchantConnectorAccountInterface for RouterStore<T> {
                 .convert(
                     self.get_keymanager_state()
                         .attach_printable("Missing KeyManagerState")?,
-                    key_store.key.get_inner(),
+                    &key_store.get_key_ring(),
                     key_store.merchant_id.clone().into(),
                 )
                 .await
@@ -415,7 +415,7 @@ impl<T: DatabaseStore> MerchantConnectorAccountInterface for RouterStore<T> {
             .convert(
                 self.get_keymanager_state()
                     .attach_printable("Missing KeyManagerState")?,
-                key_store.key.get_inner(),
+                &key_store.get_key_ring(),
                 key_store.merchant_id.clone().into(),
             )
             .await
@@ -444,7 +444,7 @@ impl<T: DatabaseStore> MerchantConnectorAccountInterface for RouterStore<T> {
                 .convert(
                     self.get_keymanager_state()
                         .attach_printable("Missing KeyManagerState")?,
-                    key_store.key.get_inner(),
+                    &key_store.get_key_ring(),
                     key_store.merchant_id.clone(),
                 )
                 .await
@@ -463,7 +463,7 @@ impl<T: DatabaseStore> MerchantConnectorAccountInterface for RouterStore<T> {
             .convert(
                 self.get_keymanager_state()
                     .attach_printable("Missing KeyManagerState")?,
-                key_store.key.get_inner(),
+                &key_store.get_key_ring(),
                 common_utils::types::keymanager::Identifier::Merchant(
                     key_store.merchant_id.clone(),
                 ),
@@ -490,7 +490,7 @@ impl<T: DatabaseStore> MerchantConnectorAccountInterface for RouterStore<T> {
                 item.convert(
                     self.get_keymanager_state()
                         .attach_printable("Missing KeyManagerState")?,
-                    key_store.key.get_inner(),
+                    &key_store.get_key_ring(),
                     key_store.merchant_id.clone().into(),
                 )
                 .await
@@ -521,7 +521,7 @@ impl<T: DatabaseStore> MerchantConnectorAccountInterface for RouterStore<T> {
                     item.convert(
                         self.get_keymanager_state()
                             .attach_printable("Missing KeyManagerState")?,
-                        key_store.key.get_inner(),
+                        &key_store.get_key_ring(),
                         key_store.merchant_id.clone().into(),
                     )
                     .await
@@ -556,7 +556,7 @@ impl<T: DatabaseStore> MerchantConnectorAccountInterface for RouterStore<T> {
                         item.convert(
                             self.get_keymanager_state()
                                 .attach_printable("Missing KeyManagerState")?,
-                            key_store.key.get_inner(),
+                            &key_store.get_key_ring(),
                             key_store.merchant_id.clone().into(),
                         )
                         .await
@@ -589,7 +589,7 @@ impl<T: DatabaseStore> MerchantConnectorAccountInterface for RouterStore<T> {
                         item.convert(
                             self.get_keymanager_state()
                                 .attach_printable("Missing KeyManagerState")?,
-                            key_store.key.get_inner(),
+                            &key_store.get_key_ring(),
                             key_store.merchant_id.clone().into(),
                         )
                         .await
@@ -737,7 +737,7 @@ impl<T: DatabaseStore> MerchantConnectorAccountInterface for RouterStore<T> {
                     item.convert(
                         self.get_keymanager_state()
                             .attach_printable("Missing KeyManagerState")?,
-                        key_store.key.get_inner(),
+                        &key_store.get_key_ring(),
                         key_store.merchant_id.clone().into(),
                     )
                     .await
@@ -817,7 +817,7 @@ impl<T: DatabaseStore> MerchantConnectorAccountInterface for RouterStore<T> {
                     item.convert(
                         self.get_keymanager_state()
                             .attach_printable("Missing KeyManagerState")?,
-                        key_store.key.get_inner(),
+                        &key_store.get_key_ring(),
                         common_utils::types::keymanager::Identifier::Merchant(
                             key_store.merchant_id.clone(),
                         ),
@@ -1046,7 +1046,7 @@ impl MerchantConnectorAccountInterface for MockDb {
                     .convert(
                         self.get_keymanager_state()
                             .attach_printable("Missing KeyManagerState")?,
-                        key_store.key.get_inner(),
+                        &key_store.get_key_ring(),
                         key_store.merchant_id.clone().into(),
                     )
                     .await
@@ -1066,11 +1066,47 @@ impl MerchantConnectorAccountInterface for MockDb {
 
     async fn list_enabled_connector_accounts_by_profile_id(
         &self,
-        _profile_id: &common_utils::id_type::ProfileId,
-        _key_store: &MerchantKeyStore,
-        _connector_type: common_enums::ConnectorType,
+        profile_id: &common_utils::id_type::ProfileId,
+        key_store: &MerchantKeyStore,
+        connector_type: common_enums::ConnectorType,
     ) -> CustomResult<Vec<domain::MerchantConnectorAccount>, StorageError> {
-        Err(StorageError::MockDbError)?
+        #[cfg(feature = "v1")]
+        let belongs_to_profile = |account: &storage::MerchantConnectorAccount| {
+            account.profile_id.as_ref() == Some(profile_id)
+        };
+        #[cfg(feature = "v2")]
+        let belongs_to_profile =
+            |account: &storage::MerchantConnectorAccount| account.profile_id == *profile_id;
+
+        let mut accounts = self
+            .merchant_connector_accounts
+            .lock()
+            .await
+            .iter()
+            .filter(|account| {
+                belongs_to_profile(account)
+                    && account.disabled == Some(false)
+                    && account.connector_type == connector_type
+            })
+            .cloned()
+            .collect::<Vec<_>>();
+        accounts.sort_by_key(|account| account.created_at);
+
+        let mut output = Vec::with_capacity(accounts.len());
+        for account in accounts.into_iter() {
+            output.push(
+                account
+                    .convert(
+                        self.get_keymanager_state()
+                            .attach_printable("Missing KeyManagerState")?,
+                        &key_store.get_key_ring(),
+                        key_store.merchant_id.clone().into(),
+                    )
+                    .await
+                    .change_context(StorageError::DecryptionError)?,
+            )
+        }
+        Ok(output)
     }
 
     #[cfg(feature = "v1")]
@@ -1097,7 +1133,7 @@ impl MerchantConnectorAccountInterface for MockDb {
                     .convert(
                         self.get_keymanager_state()
                             .attach_printable("Missing KeyManagerState")?,
-                        key_store.key.get_inner(),
+                        &key_store.get_key_ring(),
                         key_store.merchant_id.clone().into(),
                     )
                     .await
@@ -1131,7 +1167,7 @@ impl MerchantConnectorAccountInterface for MockDb {
                 .convert(
                     self.get_keymanager_state()
                         .attach_printable("Missing KeyManagerState")?,
-                    key_store.key.get_inner(),
+                    &key_store.get_key_ring(),
                     key_store.merchant_id.clone().into(),
                 )
                 .await
@@ -1165,7 +1201,7 @@ impl MerchantConnectorAccountInterface for MockDb {
                     .convert(
                         self.get_keymanager_state()
                             .attach_printable("Missing KeyManagerState")?,
-                        key_store.key.get_inner(),
+                        &key_store.get_key_ring(),
                         key_store.merchant_id.clone().into(),
                     )
                     .await
@@ -1201,7 +1237,7 @@ impl MerchantConnectorAccountInterface for MockDb {
                     .convert(
                         self.get_keymanager_state()
                             .attach_printable("Missing KeyManagerState")?,
-                        key_store.key.get_inner(),
+                        &key_store.get_key_ring(),
                         common_utils::types::keymanager::Identifier::Merchant(
                             key_store.merchant_id.clone(),
                         ),
@@ -1262,7 +1298,7 @@ impl MerchantConnectorAccountInterface for MockDb {
             .convert(
                 self.get_keymanager_state()
                     .attach_printable("Missing KeyManagerState")?,
-                key_store.key.get_inner(),
+                &key_store.get_key_ring(),
                 key_store.merchant_id.clone().into(),
             )
             .await
@@ -1305,7 +1341,7 @@ impl MerchantConnectorAccountInterface for MockDb {
             .convert(
                 self.get_keymanager_state()
                     .attach_printable("Missing KeyManagerState")?,
-                key_store.key.get_inner(),
+                &key_store.get_key_ring(),
                 common_utils::types::keymanager::Identifier::Merchant(
                     key_store.merchant_id.clone(),
                 ),
@@ -1342,7 +1378,7 @@ impl MerchantConnectorAccountInterface for MockDb {
                     .convert(
                         self.get_keymanager_state()
                             .attach_printable("Missing KeyManagerState")?,
-                        key_store.key.get_inner(),
+                        &key_store.get_key_ring(),
                         key_store.merchant_id.clone().into(),
                     )
                     .await
@@ -1376,7 +1412,7 @@ impl MerchantConnectorAccountInterface for MockDb {
                     .convert(
                         self.get_keymanager_state()
                             .attach_printable("Missing KeyManagerState")?,
-                        key_store.key.get_inner(),
+                        &key_store.get_key_ring(),
                         key_store.merchant_id.clone().into(),
                     )
                     .await
@@ -1410,7 +1446,7 @@ impl MerchantConnectorAccountInterface for MockDb {
                     .convert(
                         self.get_keymanager_state()
                             .attach_printable("Missing KeyManagerState")?,
-                        key_store.key.get_inner(),
+                        &key_store.get_key_ring(),
                         key_store.merchant_id.clone().into(),
                     )
                     .await
@@ -1453,7 +1489,7 @@ impl MerchantConnectorAccountInterface for MockDb {
                     .convert(
                         self.get_keymanager_state()
                             .attach_printable("Missing KeyManagerState")?,
-                        key_store.key.get_inner(),
+                        &key_store.get_key_ring(),
                         common_utils::types::keymanager::Identifier::Merchant(
                             key_store.merchant_id.clone(),
                         ),
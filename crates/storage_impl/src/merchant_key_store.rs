@@ -43,6 +43,17 @@ impl<T: DatabaseStore> MerchantKeyStoreInterface for kv_router_store::KVRouterSt
             .await
     }
 
+    #[instrument(skip_all)]
+    async fn update_merchant_key_store(
+        &self,
+        merchant_key_store: domain::MerchantKeyStore,
+        key: &Secret<Vec<u8>>,
+    ) -> CustomResult<domain::MerchantKeyStore, Self::Error> {
+        self.router_store
+            .update_merchant_key_store(merchant_key_store, key)
+            .await
+    }
+
     #[instrument(skip_all)]
     async fn delete_merchant_key_store_by_merchant_id(
         &self,
@@ -73,6 +84,17 @@ impl<T: DatabaseStore> MerchantKeyStoreInterface for kv_router_store::KVRouterSt
     ) -> CustomResult<Vec<domain::MerchantKeyStore>, Self::Error> {
         self.router_store.get_all_key_stores(key, from, to).await
     }
+
+    async fn list_key_stores_after_merchant_id(
+        &self,
+        key: &Secret<Vec<u8>>,
+        cursor: String,
+        limit: u32,
+    ) -> CustomResult<Vec<domain::MerchantKeyStore>, Self::Error> {
+        self.router_store
+            .list_key_stores_after_merchant_id(key, cursor, limit)
+            .await
+    }
 }
 
 #[async_trait::async_trait]
@@ -96,7 +118,7 @@ impl<T: DatabaseStore> MerchantKeyStoreInterface for RouterStore<T> {
             .convert(
                 self.get_keymanager_state()
                     .attach_printable("Missing KeyManagerState")?,
-                key,
+                &self.get_master_key_ring(key),
                 merchant_id.into(),
             )
             .await
@@ -127,7 +149,11 @@ impl<T: DatabaseStore> MerchantKeyStoreInterface for RouterStore<T> {
         {
             fetch_func()
                 .await?
-                .convert(state, key, merchant_id.clone().into())
+                .convert(
+                    state,
+                    &self.get_master_key_ring(key),
+                    merchant_id.clone().into(),
+                )
                 .await
                 .change_context(Self::Error::DecryptionError)
         }
@@ -143,12 +169,67 @@ impl<T: DatabaseStore> MerchantKeyStoreInterface for RouterStore<T> {
                 &ACCOUNTS_CACHE,
             )
             .await?
-            .convert(state, key, merchant_id.clone().into())
+            .convert(
+                state,
+                &self.get_master_key_ring(key),
+                merchant_id.clone().into(),
+            )
             .await
             .change_context(Self::Error::DecryptionError)
         }
     }
 
+    #[instrument(skip_all)]
+    async fn update_merchant_key_store(
+        &self,
+        merchant_key_store: domain::MerchantKeyStore,
+        key: &Secret<Vec<u8>>,
+    ) -> CustomResult<domain::MerchantKeyStore, Self::Error> {
+        let merchant_id = merchant_key_store.merchant_id.clone();
+        let merchant_key_store = Conversion::convert(merchant_key_store)
+            .await
+            .change_context(Self::Error::EncryptionError)?;
+        let update_func = || async {
+            let conn = pg_accounts_connection_write(self).await?;
+            diesel_models::merchant_key_store::MerchantKeyStore::update_by_merchant_id(
+                &conn,
+                &merchant_id,
+                diesel_models::merchant_key_store::MerchantKeyStoreUpdateInternal {
+                    key: merchant_key_store.key,
+                    key_version: merchant_key_store.key_version,
+                    previous_keys: merchant_key_store.previous_keys,
+                },
+            )
+            .await
+            .map_err(|error| report!(Self::Error::from(error)))
+        };
+
+        #[cfg(not(feature = "accounts_cache"))]
+        let updated_key_store = update_func().await?;
+
+        #[cfg(feature = "accounts_cache")]
+        let updated_key_store = {
+            let key_store_cache_key =
+                format!("merchant_key_store_{}", merchant_id.get_string_repr());
+            cache::publish_and_redact(
+                self,
+                CacheKind::Accounts(key_store_cache_key.into()),
+                update_func,
+            )
+            .await?
+        };
+
+        updated_key_store
+            .convert(
+                self.get_keymanager_state()
+                    .attach_printable("Missing KeyManagerState")?,
+                &self.get_master_key_ring(key),
+                merchant_id.into(),
+            )
+            .await
+            .change_context(Self::Error::DecryptionError)
+    }
+
     #[instrument(skip_all)]
     async fn delete_merchant_key_store_by_merchant_id(
         &self,
@@ -206,7 +287,7 @@ impl<T: DatabaseStore> MerchantKeyStoreInterface for RouterStore<T> {
                 .convert(
                     self.get_keymanager_state()
                         .attach_printable("Missing KeyManagerState")?,
-                    key,
+                    &self.get_master_key_ring(key),
                     merchant_id.into(),
                 )
                 .await
@@ -234,7 +315,35 @@ impl<T: DatabaseStore> MerchantKeyStoreInterface for RouterStore<T> {
                 .convert(
                     self.get_keymanager_state()
                         .attach_printable("Missing KeyManagerState")?,
-                    key,
+                    &self.get_master_key_ring(key),
+                    merchant_id.into(),
+                )
+                .await
+                .change_context(Self::Error::DecryptionError)
+        }))
+        .await
+    }
+
+    async fn list_key_stores_after_merchant_id(
+        &self,
+        key: &Secret<Vec<u8>>,
+        cursor: String,
+        limit: u32,
+    ) -> CustomResult<Vec<domain::MerchantKeyStore>, Self::Error> {
+        let conn = pg_accounts_connection_write(self).await?;
+        let stores = diesel_models::merchant_key_store::MerchantKeyStore::list_after_merchant_id(
+            &conn, cursor, limit,
+        )
+        .await
+        .map_err(|err| report!(Self::Error::from(err)))?;
+
+        futures::future::try_join_all(stores.into_iter().map(|key_store| async {
+            let merchant_id = key_store.merchant_id.clone();
+            key_store
+                .convert(
+                    self.get_keymanager_state()
+                        .attach_printable("Missing KeyManagerState")?,
+                    &self.get_master_key_ring(key),
                     merchant_id.into(),
                 )
                 .await
@@ -304,6 +413,34 @@ impl MerchantKeyStoreInterface for MockDb {
             .change_context(StorageError::DecryptionError)
     }
 
+    async fn update_merchant_key_store(
+        &self,
+        merchant_key_store: domain::MerchantKeyStore,
+        key: &Secret<Vec<u8>>,
+    ) -> CustomResult<domain::MerchantKeyStore, StorageError> {
+        let mut merchant_key_stores = self.merchant_key_store.lock().await;
+        let updated_key_store = Conversion::convert(merchant_key_store)
+            .await
+            .change_context(StorageError::MockDbError)?;
+        let merchant_key = merchant_key_stores
+            .iter_mut()
+            .find(|merchant_key| merchant_key.merchant_id == updated_key_store.merchant_id)
+            .ok_or(StorageError::ValueNotFound(String::from(
+                "merchant_key_store",
+            )))?;
+        *merchant_key = updated_key_store.clone();
+        let merchant_id = updated_key_store.merchant_id.clone();
+        updated_key_store
+            .convert(
+                self.get_keymanager_state()
+                    .attach_printable("Missing KeyManagerState")?,
+                key,
+                merchant_id.into(),
+            )
+            .await
+            .change_context(StorageError::DecryptionError)
+    }
+
     async fn delete_merchant_key_store_by_merchant_id(
         &self,
         merchant_id: &common_utils::id_type::MerchantId,
@@ -367,4 +504,40 @@ impl MerchantKeyStoreInterface for MockDb {
         }))
         .await
     }
+
+    async fn list_key_stores_after_merchant_id(
+        &self,
+        key: &Secret<Vec<u8>>,
+        cursor: String,
+        limit: u32,
+    ) -> CustomResult<Vec<domain::MerchantKeyStore>, StorageError> {
+        let mut merchant_key_stores: Vec<_> = self
+            .merchant_key_store
+            .lock()
+            .await
+            .iter()
+            .filter(|merchant_key| merchant_key.merchant_id.get_string_repr() > cursor.as_str())
+            .cloned()
+            .collect();
+        merchant_key_stores.sort_by(|a, b| {
+            a.merchant_id
+                .get_string_repr()
+                .cmp(b.merchant_id.get_string_repr())
+        });
+        merchant_key_stores.truncate(usize::try_from(limit).unwrap_or_default());
+
+        futures::future::try_join_all(merchant_key_stores.into_iter().map(|merchant_key| async {
+            let merchant_id = merchant_key.merchant_id.clone();
+            merchant_key
+                .convert(
+                    self.get_keymanager_state()
+                        .attach_printable("Missing KeyManagerState")?,
+                    key,
+                    merchant_id.into(),
+                )
+                .await
+                .change_context(StorageError::DecryptionError)
+        }))
+        .await
+    }
 }
@@ -42,6 +42,7 @@ pub struct MockDb {
     pub events: Arc<Mutex<Vec<store::Event>>>,
     pub disputes: Arc<Mutex<Vec<store::Dispute>>>,
     pub lockers: Arc<Mutex<Vec<store::LockerMockUp>>>,
+    pub forex_rate_snapshots: Arc<Mutex<Vec<store::forex_rate_snapshot::ForexRateSnapshot>>>,
     pub mandates: Arc<Mutex<Vec<store::Mandate>>>,
     pub captures: Arc<Mutex<Vec<store::capture::Capture>>>,
     pub merchant_key_store: Arc<Mutex<Vec<store::merchant_key_store::MerchantKeyStore>>>,
@@ -67,6 +68,24 @@ pub struct MockDb {
     pub themes: Arc<Mutex<Vec<store::user::theme::Theme>>>,
     pub hyperswitch_ai_interactions:
         Arc<Mutex<Vec<store::hyperswitch_ai_interaction::HyperswitchAiInteraction>>>,
+    pub gateway_status_maps: Arc<Mutex<Vec<store::gsm::GatewayStatusMap>>>,
+    pub blocklists: Arc<Mutex<Vec<store::blocklist::Blocklist>>>,
+    pub blocklist_fingerprints: Arc<Mutex<Vec<store::blocklist_fingerprint::BlocklistFingerprint>>>,
+    pub blocklist_lookups: Arc<Mutex<Vec<store::blocklist_lookup::BlocklistLookup>>>,
+    pub routing_algorithms: Arc<Mutex<Vec<store::routing_algorithm::RoutingAlgorithm>>>,
+    pub file_metadata: Arc<Mutex<Vec<store::file::FileMetadata>>>,
+    pub fraud_checks: Arc<Mutex<Vec<store::fraud_check::FraudCheck>>>,
+    pub generic_links: Arc<Mutex<Vec<store::generic_link::GenericLink>>>,
+    pub relays: Arc<Mutex<Vec<store::relay::Relay>>>,
+    pub webhook_signing_keys: Arc<Mutex<Vec<store::webhook_signing_key::WebhookSigningKey>>>,
+    pub callback_mappers: Arc<Mutex<Vec<store::callback_mapper::CallbackMapper>>>,
+    pub unified_translations: Arc<Mutex<Vec<store::unified_translations::UnifiedTranslations>>>,
+    pub dynamic_routing_stats: Arc<Mutex<Vec<store::dynamic_routing_stats::DynamicRoutingStats>>>,
+    #[cfg(feature = "v2")]
+    pub payment_methods_sessions:
+        Arc<Mutex<Vec<store::payment_methods_session::PaymentMethodSession>>>,
+    pub invoices: Arc<Mutex<Vec<store::invoice::Invoice>>>,
+    pub subscriptions: Arc<Mutex<Vec<store::subscription::Subscription>>>,
     pub key_manager_state: Option<KeyManagerState>,
 }
 
@@ -102,6 +121,7 @@ impl MockDb {
             events: Default::default(),
             disputes: Default::default(),
             lockers: Default::default(),
+            forex_rate_snapshots: Default::default(),
             mandates: Default::default(),
             captures: Default::default(),
             merchant_key_store: Default::default(),
@@ -125,6 +145,23 @@ impl MockDb {
             user_authentication_methods: Default::default(),
             themes: Default::default(),
             hyperswitch_ai_interactions: Default::default(),
+            gateway_status_maps: Default::default(),
+            blocklists: Default::default(),
+            blocklist_fingerprints: Default::default(),
+            blocklist_lookups: Default::default(),
+            routing_algorithms: Default::default(),
+            file_metadata: Default::default(),
+            fraud_checks: Default::default(),
+            generic_links: Default::default(),
+            relays: Default::default(),
+            webhook_signing_keys: Default::default(),
+            callback_mappers: Default::default(),
+            unified_translations: Default::default(),
+            dynamic_routing_stats: Default::default(),
+            #[cfg(feature = "v2")]
+            payment_methods_sessions: Default::default(),
+            invoices: Default::default(),
+            subscriptions: Default::default(),
             key_manager_state: Some(key_manager_state),
         })
     }
@@ -146,7 +183,7 @@ impl MockDb {
                 res.convert(
                     self.get_keymanager_state()
                         .attach_printable("Missing KeyManagerState")?,
-                    key_store.key.get_inner(),
+                    &key_store.get_key_ring(),
                     key_store.merchant_id.clone().into(),
                 )
                 .await
@@ -195,7 +232,7 @@ impl MockDb {
                     pm.convert(
                         self.get_keymanager_state()
                             .attach_printable("Missing KeyManagerState")?,
-                        key_store.key.get_inner(),
+                        &key_store.get_key_ring(),
                         key_store.merchant_id.clone().into(),
                     )
                     .await
@@ -227,7 +264,7 @@ impl MockDb {
                 .convert(
                     self.get_keymanager_state()
                         .attach_printable("Missing KeyManagerState")?,
-                    key_store.key.get_inner(),
+                    &key_store.get_key_ring(),
                     key_store.merchant_id.clone().into(),
                 )
                 .await
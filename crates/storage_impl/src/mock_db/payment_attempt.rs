@@ -3,9 +3,8 @@ use common_utils::errors::CustomResult;
 use common_utils::id_type;
 use diesel_models::enums as storage_enums;
 use error_stack::ResultExt;
-#[cfg(feature = "v1")]
-use hyperswitch_domain_models::behaviour::Conversion;
 use hyperswitch_domain_models::{
+    behaviour::Conversion,
     merchant_key_store::MerchantKeyStore,
     payments::payment_attempt::{PaymentAttempt, PaymentAttemptInterface, PaymentAttemptUpdate},
 };
@@ -13,148 +12,371 @@ use hyperswitch_domain_models::{
 use super::MockDb;
 use crate::errors::StorageError;
 
+#[cfg(all(feature = "v1", feature = "olap"))]
+fn collect_distinct<T: PartialEq>(values: impl Iterator<Item = T>) -> Vec<T> {
+    values.fold(Vec::new(), |mut distinct, value| {
+        if !distinct.contains(&value) {
+            distinct.push(value);
+        }
+        distinct
+    })
+}
+
 #[async_trait::async_trait]
 impl PaymentAttemptInterface for MockDb {
     type Error = StorageError;
     #[cfg(feature = "v1")]
     async fn find_payment_attempt_by_payment_id_processor_merchant_id_attempt_id(
         &self,
-        _payment_id: &common_utils::id_type::PaymentId,
-        _processor_merchant_id: &common_utils::id_type::MerchantId,
-        _attempt_id: &str,
+        payment_id: &common_utils::id_type::PaymentId,
+        processor_merchant_id: &common_utils::id_type::MerchantId,
+        attempt_id: &str,
         _storage_scheme: storage_enums::MerchantStorageScheme,
         _merchant_key_store: &MerchantKeyStore,
     ) -> CustomResult<PaymentAttempt, StorageError> {
-        // [#172]: Implement function for `MockDb`
-        Err(StorageError::MockDbError)?
+        let payment_attempts = self.payment_attempts.lock().await;
+
+        Ok(payment_attempts
+            .iter()
+            .find(|payment_attempt| {
+                payment_attempt.payment_id == *payment_id
+                    && payment_attempt.processor_merchant_id == *processor_merchant_id
+                    && payment_attempt.attempt_id == attempt_id
+            })
+            .cloned()
+            .ok_or(StorageError::ValueNotFound(
+                "PaymentAttempt not found".to_string(),
+            ))?)
     }
 
     #[cfg(all(feature = "v1", feature = "olap"))]
     async fn get_filters_for_payments(
         &self,
-        _pi: &[hyperswitch_domain_models::payments::PaymentIntent],
-        _processor_merchant_id: &common_utils::id_type::MerchantId,
+        pi: &[hyperswitch_domain_models::payments::PaymentIntent],
+        processor_merchant_id: &common_utils::id_type::MerchantId,
         _storage_scheme: storage_enums::MerchantStorageScheme,
     ) -> CustomResult<
         hyperswitch_domain_models::payments::payment_attempt::PaymentListFilters,
         StorageError,
     > {
-        Err(StorageError::MockDbError)?
+        let active_attempt_ids = pi
+            .iter()
+            .map(|payment_intent| payment_intent.active_attempt.get_id())
+            .collect::<Vec<_>>();
+        let payment_attempts = self.payment_attempts.lock().await;
+        let attempts = payment_attempts
+            .iter()
+            .filter(|payment_attempt| {
+                payment_attempt.processor_merchant_id == *processor_merchant_id
+                    && active_attempt_ids.contains(&payment_attempt.attempt_id)
+            })
+            .collect::<Vec<_>>();
+
+        Ok(
+            hyperswitch_domain_models::payments::payment_attempt::PaymentListFilters {
+                connector: collect_distinct(
+                    attempts
+                        .iter()
+                        .filter_map(|attempt| attempt.connector.clone()),
+                ),
+                currency: collect_distinct(attempts.iter().filter_map(|attempt| attempt.currency)),
+                status: collect_distinct(pi.iter().map(|payment_intent| payment_intent.status)),
+                payment_method: collect_distinct(
+                    attempts.iter().filter_map(|attempt| attempt.payment_method),
+                ),
+                payment_method_type: collect_distinct(
+                    attempts
+                        .iter()
+                        .filter_map(|attempt| attempt.payment_method_type),
+                ),
+                authentication_type: collect_distinct(
+                    attempts
+                        .iter()
+                        .filter_map(|attempt| attempt.authentication_type),
+                ),
+            },
+        )
     }
 
     #[cfg(all(feature = "v1", feature = "olap"))]
     async fn get_total_count_of_filtered_payment_attempts(
         &self,
-        _processor_merchant_id: &common_utils::id_type::MerchantId,
-        _active_attempt_ids: &[String],
-        _connector: Option<Vec<api_models::enums::Connector>>,
-        _payment_method: Option<Vec<common_enums::PaymentMethod>>,
-        _payment_method_type: Option<Vec<common_enums::PaymentMethodType>>,
-        _authentication_type: Option<Vec<common_enums::AuthenticationType>>,
-        _merchanat_connector_id: Option<Vec<common_utils::id_type::MerchantConnectorAccountId>>,
-        _card_network: Option<Vec<storage_enums::CardNetwork>>,
-        _card_discovery: Option<Vec<storage_enums::CardDiscovery>>,
+        processor_merchant_id: &common_utils::id_type::MerchantId,
+        active_attempt_ids: &[String],
+        connector: Option<Vec<api_models::enums::Connector>>,
+        payment_method: Option<Vec<common_enums::PaymentMethod>>,
+        payment_method_type: Option<Vec<common_enums::PaymentMethodType>>,
+        authentication_type: Option<Vec<common_enums::AuthenticationType>>,
+        merchant_connector_id: Option<Vec<common_utils::id_type::MerchantConnectorAccountId>>,
+        card_network: Option<Vec<storage_enums::CardNetwork>>,
+        card_discovery: Option<Vec<storage_enums::CardDiscovery>>,
         _storage_scheme: storage_enums::MerchantStorageScheme,
     ) -> CustomResult<i64, StorageError> {
-        Err(StorageError::MockDbError)?
+        let connector = connector.map(|connector| {
+            connector
+                .iter()
+                .map(|c| c.to_string())
+                .collect::<Vec<String>>()
+        });
+        let payment_attempts = self.payment_attempts.lock().await;
+        let count = payment_attempts
+            .iter()
+            .filter(|attempt| {
+                attempt.processor_merchant_id == *processor_merchant_id
+                    && active_attempt_ids.contains(&attempt.attempt_id)
+                    && connector.as_ref().is_none_or(|connector| {
+                        attempt
+                            .connector
+                            .as_ref()
+                            .is_some_and(|value| connector.contains(value))
+                    })
+                    && payment_method.as_ref().is_none_or(|payment_method| {
+                        attempt
+                            .payment_method
+                            .is_some_and(|value| payment_method.contains(&value))
+                    })
+                    && payment_method_type
+                        .as_ref()
+                        .is_none_or(|payment_method_type| {
+                            attempt
+                                .payment_method_type
+                                .is_some_and(|value| payment_method_type.contains(&value))
+                        })
+                    && authentication_type
+                        .as_ref()
+                        .is_none_or(|authentication_type| {
+                            attempt
+                                .authentication_type
+                                .is_some_and(|value| authentication_type.contains(&value))
+                        })
+                    && merchant_connector_id
+                        .as_ref()
+                        .is_none_or(|merchant_connector_id| {
+                            attempt
+                                .merchant_connector_id
+                                .as_ref()
+                                .is_some_and(|value| merchant_connector_id.contains(value))
+                        })
+                    && card_network.as_ref().is_none_or(|card_network| {
+                        attempt
+                            .extract_card_network()
+                            .is_some_and(|value| card_network.contains(&value))
+                    })
+                    && card_discovery.as_ref().is_none_or(|card_discovery| {
+                        attempt
+                            .card_discovery
+                            .is_some_and(|value| card_discovery.contains(&value))
+                    })
+            })
+            .count();
+
+        i64::try_from(count).change_context(StorageError::MockDbError)
     }
 
     #[cfg(all(feature = "v2", feature = "olap"))]
     async fn get_total_count_of_filtered_payment_attempts(
         &self,
-        _merchant_id: &id_type::MerchantId,
-        _active_attempt_ids: &[String],
-        _connector: Option<Vec<api_models::enums::Connector>>,
-        _payment_method_type: Option<Vec<common_enums::PaymentMethod>>,
-        _payment_method_subtype: Option<Vec<common_enums::PaymentMethodType>>,
-        _authentication_type: Option<Vec<common_enums::AuthenticationType>>,
-        _merchant_connector_id: Option<Vec<id_type::MerchantConnectorAccountId>>,
+        merchant_id: &id_type::MerchantId,
+        active_attempt_ids: &[String],
+        connector: Option<Vec<api_models::enums::Connector>>,
+        payment_method_type: Option<Vec<common_enums::PaymentMethod>>,
+        payment_method_subtype: Option<Vec<common_enums::PaymentMethodType>>,
+        authentication_type: Option<Vec<common_enums::AuthenticationType>>,
+        merchant_connector_id: Option<Vec<id_type::MerchantConnectorAccountId>>,
         _card_network: Option<Vec<storage_enums::CardNetwork>>,
         _storage_scheme: storage_enums::MerchantStorageScheme,
     ) -> CustomResult<i64, StorageError> {
-        Err(StorageError::MockDbError)?
+        // The card network is not tracked on the v2 domain attempt, so that filter is not applied here
+        let connector = connector.map(|connector| {
+            connector
+                .iter()
+                .map(|c| c.to_string())
+                .collect::<Vec<String>>()
+        });
+        let payment_attempts = self.payment_attempts.lock().await;
+        let count = payment_attempts
+            .iter()
+            .filter(|attempt| {
+                attempt.merchant_id == *merchant_id
+                    && active_attempt_ids
+                        .iter()
+                        .any(|id| id.as_str() == attempt.id.get_string_repr())
+                    && connector.as_ref().is_none_or(|connector| {
+                        attempt
+                            .connector
+                            .as_ref()
+                            .is_some_and(|value| connector.contains(value))
+                    })
+                    && payment_method_type
+                        .as_ref()
+                        .is_none_or(|payment_method_type| {
+                            payment_method_type.contains(&attempt.payment_method_type)
+                        })
+                    && payment_method_subtype
+                        .as_ref()
+                        .is_none_or(|payment_method_subtype| {
+                            payment_method_subtype.contains(&attempt.payment_method_subtype)
+                        })
+                    && authentication_type
+                        .as_ref()
+                        .is_none_or(|authentication_type| {
+                            authentication_type.contains(&attempt.authentication_type)
+                        })
+                    && merchant_connector_id
+                        .as_ref()
+                        .is_none_or(|merchant_connector_id| {
+                            attempt
+                                .merchant_connector_id
+                                .as_ref()
+                                .is_some_and(|value| merchant_connector_id.contains(value))
+                        })
+            })
+            .count();
+
+        i64::try_from(count).change_context(StorageError::MockDbError)
     }
 
     #[cfg(feature = "v1")]
     async fn find_payment_attempt_by_attempt_id_processor_merchant_id(
         &self,
-        _attempt_id: &str,
-        _processor_merchant_id: &common_utils::id_type::MerchantId,
+        attempt_id: &str,
+        processor_merchant_id: &common_utils::id_type::MerchantId,
         _storage_scheme: storage_enums::MerchantStorageScheme,
         _merchant_key_store: &MerchantKeyStore,
     ) -> CustomResult<PaymentAttempt, StorageError> {
-        // [#172]: Implement function for `MockDb`
-        Err(StorageError::MockDbError)?
+        let payment_attempts = self.payment_attempts.lock().await;
+
+        Ok(payment_attempts
+            .iter()
+            .find(|payment_attempt| {
+                payment_attempt.attempt_id == attempt_id
+                    && payment_attempt.processor_merchant_id == *processor_merchant_id
+            })
+            .cloned()
+            .ok_or(StorageError::ValueNotFound(
+                "PaymentAttempt not found".to_string(),
+            ))?)
     }
 
     #[cfg(feature = "v2")]
     async fn find_payment_attempt_by_id(
         &self,
         _merchant_key_store: &MerchantKeyStore,
-        _attempt_id: &id_type::GlobalAttemptId,
+        attempt_id: &id_type::GlobalAttemptId,
         _storage_scheme: storage_enums::MerchantStorageScheme,
     ) -> error_stack::Result<PaymentAttempt, StorageError> {
-        // [#172]: Implement function for `MockDb`
-        Err(StorageError::MockDbError)?
+        let payment_attempts = self.payment_attempts.lock().await;
+
+        Ok(payment_attempts
+            .iter()
+            .find(|payment_attempt| payment_attempt.id == *attempt_id)
+            .cloned()
+            .ok_or(StorageError::ValueNotFound(
+                "PaymentAttempt not found".to_string(),
+            ))?)
     }
 
     #[cfg(feature = "v2")]
     async fn find_payment_attempts_by_payment_intent_id(
         &self,
-        _id: &id_type::GlobalPaymentId,
+        id: &id_type::GlobalPaymentId,
         _merchant_key_store: &MerchantKeyStore,
         _storage_scheme: common_enums::MerchantStorageScheme,
     ) -> error_stack::Result<Vec<PaymentAttempt>, StorageError> {
-        // [#172]: Implement function for `MockDb`
-        Err(StorageError::MockDbError)?
+        let payment_attempts = self.payment_attempts.lock().await;
+
+        Ok(payment_attempts
+            .iter()
+            .filter(|payment_attempt| payment_attempt.payment_id == *id)
+            .cloned()
+            .collect())
     }
 
     #[cfg(feature = "v1")]
     async fn find_payment_attempt_by_preprocessing_id_processor_merchant_id(
         &self,
-        _preprocessing_id: &str,
-        _processor_merchant_id: &common_utils::id_type::MerchantId,
+        preprocessing_id: &str,
+        processor_merchant_id: &common_utils::id_type::MerchantId,
         _storage_scheme: storage_enums::MerchantStorageScheme,
         _merchant_key_store: &MerchantKeyStore,
     ) -> CustomResult<PaymentAttempt, StorageError> {
-        // [#172]: Implement function for `MockDb`
-        Err(StorageError::MockDbError)?
+        let payment_attempts = self.payment_attempts.lock().await;
+
+        Ok(payment_attempts
+            .iter()
+            .find(|payment_attempt| {
+                payment_attempt.preprocessing_step_id.as_deref() == Some(preprocessing_id)
+                    && payment_attempt.processor_merchant_id == *processor_merchant_id
+            })
+            .cloned()
+            .ok_or(StorageError::ValueNotFound(
+                "PaymentAttempt not found".to_string(),
+            ))?)
     }
 
     #[cfg(feature = "v1")]
     async fn find_payment_attempt_by_processor_merchant_id_connector_txn_id(
         &self,
-        _processor_merchant_id: &common_utils::id_type::MerchantId,
-        _connector_txn_id: &str,
+        processor_merchant_id: &common_utils::id_type::MerchantId,
+        connector_txn_id: &str,
         _storage_scheme: storage_enums::MerchantStorageScheme,
         _merchant_key_store: &MerchantKeyStore,
     ) -> CustomResult<PaymentAttempt, StorageError> {
-        // [#172]: Implement function for `MockDb`
-        Err(StorageError::MockDbError)?
+        let payment_attempts = self.payment_attempts.lock().await;
+
+        Ok(payment_attempts
+            .iter()
+            .find(|payment_attempt| {
+                payment_attempt.processor_merchant_id == *processor_merchant_id
+                    && payment_attempt.connector_transaction_id.as_deref() == Some(connector_txn_id)
+            })
+            .cloned()
+            .ok_or(StorageError::ValueNotFound(
+                "PaymentAttempt not found".to_string(),
+            ))?)
     }
 
     #[cfg(feature = "v2")]
     async fn find_payment_attempt_by_profile_id_connector_transaction_id(
         &self,
         _merchant_key_store: &MerchantKeyStore,
-        _profile_id: &id_type::ProfileId,
-        _connector_transaction_id: &str,
+        profile_id: &id_type::ProfileId,
+        connector_transaction_id: &str,
         _storage_scheme: storage_enums::MerchantStorageScheme,
     ) -> CustomResult<PaymentAttempt, StorageError> {
-        // [#172]: Implement function for `MockDb`
-        Err(StorageError::MockDbError)?
+        let payment_attempts = self.payment_attempts.lock().await;
+
+        Ok(payment_attempts
+            .iter()
+            .find(|payment_attempt| {
+                payment_attempt.profile_id == *profile_id
+                    && payment_attempt.connector_payment_id.as_deref()
+                        == Some(connector_transaction_id)
+            })
+            .cloned()
+            .ok_or(StorageError::ValueNotFound(
+                "PaymentAttempt not found".to_string(),
+            ))?)
     }
 
     #[cfg(feature = "v1")]
     async fn find_attempts_by_processor_merchant_id_payment_id(
         &self,
-        _processor_merchant_id: &common_utils::id_type::MerchantId,
-        _payment_id: &common_utils::id_type::PaymentId,
+        processor_merchant_id: &common_utils::id_type::MerchantId,
+        payment_id: &common_utils::id_type::PaymentId,
         _storage_scheme: storage_enums::MerchantStorageScheme,
         _merchant_key_store: &MerchantKeyStore,
     ) -> CustomResult<Vec<PaymentAttempt>, StorageError> {
-        // [#172]: Implement function for `MockDb`
-        Err(StorageError::MockDbError)?
+        let payment_attempts = self.payment_attempts.lock().await;
+
+        Ok(payment_attempts
+            .iter()
+            .filter(|payment_attempt| {
+                payment_attempt.processor_merchant_id == *processor_merchant_id
+                    && payment_attempt.payment_id == *payment_id
+            })
+            .cloned()
+            .collect())
     }
 
     #[cfg(feature = "v1")]
@@ -250,26 +472,25 @@ impl PaymentAttemptInterface for MockDb {
             error_details: payment_attempt.error_details,
             retry_type: payment_attempt.retry_type,
             installment_data: payment_attempt.installment_data,
+            retry_hop: payment_attempt.retry_hop,
         };
         payment_attempts.push(payment_attempt.clone());
         Ok(payment_attempt)
     }
 
     #[cfg(feature = "v2")]
-    #[allow(clippy::panic)]
     async fn insert_payment_attempt(
         &self,
         _merchant_key_store: &MerchantKeyStore,
-        _payment_attempt: PaymentAttempt,
+        payment_attempt: PaymentAttempt,
         _storage_scheme: storage_enums::MerchantStorageScheme,
     ) -> CustomResult<PaymentAttempt, StorageError> {
-        // [#172]: Implement function for `MockDb`
-        Err(StorageError::MockDbError)?
+        let mut payment_attempts = self.payment_attempts.lock().await;
+        payment_attempts.push(payment_attempt.clone());
+        Ok(payment_attempt)
     }
 
     #[cfg(feature = "v1")]
-    // safety: only used for testing
-    #[allow(clippy::unwrap_used)]
     async fn update_payment_attempt_with_attempt_id(
         &self,
         this: PaymentAttempt,
@@ -282,7 +503,9 @@ impl PaymentAttemptInterface for MockDb {
         let item = payment_attempts
             .iter_mut()
             .find(|item| item.attempt_id == this.attempt_id)
-            .unwrap();
+            .ok_or(StorageError::ValueNotFound(
+                "PaymentAttempt not found".to_string(),
+            ))?;
         let diesel_payment_attempt = this
             .convert()
             .await
@@ -296,7 +519,7 @@ impl PaymentAttemptInterface for MockDb {
         *item = PaymentAttempt::convert_back(
             key_manager_state,
             updated_diesel_payment_attempt,
-            merchant_key_store.key.get_inner(),
+            &merchant_key_store.get_key_ring(),
             merchant_key_store.merchant_id.clone().into(),
         )
         .await
@@ -308,31 +531,66 @@ impl PaymentAttemptInterface for MockDb {
     #[cfg(feature = "v2")]
     async fn update_payment_attempt(
         &self,
-        _merchant_key_store: &MerchantKeyStore,
-        _this: PaymentAttempt,
-        _payment_attempt: PaymentAttemptUpdate,
+        merchant_key_store: &MerchantKeyStore,
+        this: PaymentAttempt,
+        payment_attempt: PaymentAttemptUpdate,
         _storage_scheme: storage_enums::MerchantStorageScheme,
     ) -> CustomResult<PaymentAttempt, StorageError> {
-        // [#172]: Implement function for `MockDb`
-        Err(StorageError::MockDbError)?
+        let mut payment_attempts = self.payment_attempts.lock().await;
+
+        let item = payment_attempts
+            .iter_mut()
+            .find(|item| item.id == this.id)
+            .ok_or(StorageError::ValueNotFound(
+                "PaymentAttempt not found".to_string(),
+            ))?;
+        let diesel_payment_attempt = Conversion::convert(this)
+            .await
+            .change_context(StorageError::EncryptionError)?;
+        let updated_diesel_payment_attempt =
+            diesel_models::PaymentAttemptUpdateInternal::from(payment_attempt)
+                .apply_changeset(diesel_payment_attempt);
+        let key_manager_state = self
+            .get_keymanager_state()
+            .attach_printable("Missing KeyManagerState")?;
+        *item = PaymentAttempt::convert_back(
+            key_manager_state,
+            updated_diesel_payment_attempt,
+            &merchant_key_store.get_key_ring(),
+            merchant_key_store.merchant_id.clone().into(),
+        )
+        .await
+        .change_context(StorageError::DecryptionError)?;
+
+        Ok(item.clone())
     }
 
     #[cfg(feature = "v1")]
     async fn find_payment_attempt_by_connector_transaction_id_payment_id_processor_merchant_id(
         &self,
-        _connector_transaction_id: &common_utils::types::ConnectorTransactionId,
-        _payment_id: &common_utils::id_type::PaymentId,
-        _processor_merchant_id: &common_utils::id_type::MerchantId,
+        connector_transaction_id: &common_utils::types::ConnectorTransactionId,
+        payment_id: &common_utils::id_type::PaymentId,
+        processor_merchant_id: &common_utils::id_type::MerchantId,
         _storage_scheme: storage_enums::MerchantStorageScheme,
         _merchant_key_store: &MerchantKeyStore,
     ) -> CustomResult<PaymentAttempt, StorageError> {
-        // [#172]: Implement function for `MockDb`
-        Err(StorageError::MockDbError)?
+        let payment_attempts = self.payment_attempts.lock().await;
+
+        Ok(payment_attempts
+            .iter()
+            .find(|payment_attempt| {
+                payment_attempt.payment_id == *payment_id
+                    && payment_attempt.processor_merchant_id == *processor_merchant_id
+                    && payment_attempt.connector_transaction_id.as_ref()
+                        == Some(connector_transaction_id.get_id())
+            })
+            .cloned()
+            .ok_or(StorageError::ValueNotFound(
+                "PaymentAttempt not found".to_string(),
+            ))?)
     }
 
     #[cfg(feature = "v1")]
-    // safety: only used for testing
-    #[allow(clippy::unwrap_used)]
     async fn find_payment_attempt_last_successful_attempt_by_payment_id_processor_merchant_id(
         &self,
         payment_id: &common_utils::id_type::PaymentId,
@@ -344,18 +602,21 @@ impl PaymentAttemptInterface for MockDb {
 
         Ok(payment_attempts
             .iter()
-            .find(|payment_attempt| {
+            .filter(|payment_attempt| {
                 payment_attempt.payment_id == *payment_id
                     && payment_attempt
                         .processor_merchant_id
                         .eq(processor_merchant_id)
+                    && payment_attempt.status == storage_enums::AttemptStatus::Charged
             })
+            .max_by_key(|payment_attempt| payment_attempt.modified_at)
             .cloned()
-            .unwrap())
+            .ok_or(StorageError::ValueNotFound(
+                "PaymentAttempt not found".to_string(),
+            ))?)
     }
 
     #[cfg(feature = "v1")]
-    #[allow(clippy::unwrap_used)]
     async fn find_payment_attempt_last_successful_or_partially_captured_attempt_by_payment_id_processor_merchant_id(
         &self,
         payment_id: &common_utils::id_type::PaymentId,
@@ -367,7 +628,7 @@ impl PaymentAttemptInterface for MockDb {
 
         Ok(payment_attempts
             .iter()
-            .find(|payment_attempt| {
+            .filter(|payment_attempt| {
                 payment_attempt.payment_id == *payment_id
                     && payment_attempt
                         .processor_merchant_id
@@ -375,12 +636,14 @@ impl PaymentAttemptInterface for MockDb {
                     && (payment_attempt.status == storage_enums::AttemptStatus::PartialCharged
                         || payment_attempt.status == storage_enums::AttemptStatus::Charged)
             })
+            .max_by_key(|payment_attempt| payment_attempt.modified_at)
             .cloned()
-            .unwrap())
+            .ok_or(StorageError::ValueNotFound(
+                "PaymentAttempt not found".to_string(),
+            ))?)
     }
 
     #[cfg(feature = "v2")]
-    #[allow(clippy::unwrap_used)]
     async fn find_payment_attempt_last_successful_or_partially_captured_attempt_by_payment_id(
         &self,
         _merchant_key_store: &MerchantKeyStore,
@@ -391,12 +654,15 @@ impl PaymentAttemptInterface for MockDb {
 
         Ok(payment_attempts
             .iter()
-            .find(|payment_attempt| {
+            .filter(|payment_attempt| {
                 payment_attempt.payment_id == *payment_id
                     && (payment_attempt.status == storage_enums::AttemptStatus::PartialCharged
                         || payment_attempt.status == storage_enums::AttemptStatus::Charged)
             })
+            .max_by_key(|payment_attempt| payment_attempt.modified_at)
             .cloned()
-            .unwrap())
+            .ok_or(StorageError::ValueNotFound(
+                "PaymentAttempt not found".to_string(),
+            ))?)
     }
 }
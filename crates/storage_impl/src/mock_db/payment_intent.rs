@@ -1,9 +1,12 @@
 use common_utils::errors::CustomResult;
 use diesel_models::enums as storage_enums;
-#[cfg(feature = "v1")]
 use error_stack::ResultExt;
-#[cfg(feature = "v1")]
 use hyperswitch_domain_models::behaviour::Conversion;
+#[cfg(all(feature = "v1", feature = "olap"))]
+use hyperswitch_domain_models::payments::{
+    payment_attempt::PaymentAttempt,
+    payment_intent::{PaymentIntentFetchConstraints, PaymentIntentListParams},
+};
 use hyperswitch_domain_models::{
     merchant_key_store::MerchantKeyStore,
     payments::{
@@ -15,19 +18,143 @@ use hyperswitch_domain_models::{
 use super::MockDb;
 use crate::errors::StorageError;
 
+/// Applies the intent level filters of the fetch constraints, resolving the pagination cursors
+/// against the same set of payment intents.
+#[cfg(all(feature = "v1", feature = "olap"))]
+fn filter_payment_intents(
+    payment_intents: &[PaymentIntent],
+    processor_merchant_id: &common_utils::id_type::MerchantId,
+    constraints: &PaymentIntentFetchConstraints,
+) -> CustomResult<Vec<PaymentIntent>, StorageError> {
+    let merchant_intents = payment_intents
+        .iter()
+        .filter(|payment_intent| payment_intent.processor_merchant_id == *processor_merchant_id);
+
+    let params = match constraints {
+        PaymentIntentFetchConstraints::Single { payment_intent_id } => {
+            return Ok(merchant_intents
+                .filter(|payment_intent| payment_intent.get_id() == payment_intent_id)
+                .cloned()
+                .collect());
+        }
+        PaymentIntentFetchConstraints::List(params) => params,
+    };
+
+    let created_at_of = |payment_id: &common_utils::id_type::PaymentId| {
+        payment_intents
+            .iter()
+            .find(|payment_intent| {
+                payment_intent.get_id() == payment_id
+                    && payment_intent.processor_merchant_id == *processor_merchant_id
+            })
+            .map(|payment_intent| payment_intent.created_at)
+            .ok_or(StorageError::ValueNotFound(
+                "PaymentIntent not found".to_string(),
+            ))
+    };
+    let starting_at = match (params.starting_at, &params.starting_after_id) {
+        (Some(starting_at), _) => Some(starting_at),
+        (None, Some(starting_after_id)) => Some(created_at_of(starting_after_id)?),
+        (None, None) => None,
+    };
+    let ending_at = match (params.ending_at, &params.ending_before_id) {
+        (Some(ending_at), _) => Some(ending_at),
+        (None, Some(ending_before_id)) => Some(created_at_of(ending_before_id)?),
+        (None, None) => None,
+    };
+
+    Ok(merchant_intents
+        .filter(|payment_intent| {
+            let amount = payment_intent.amount.get_amount_as_i64();
+            params
+                .customer_id
+                .as_ref()
+                .is_none_or(|customer_id| payment_intent.customer_id.as_ref() == Some(customer_id))
+                && params
+                    .merchant_order_reference_id
+                    .as_ref()
+                    .is_none_or(|reference_id| {
+                        payment_intent.merchant_order_reference_id.as_ref() == Some(reference_id)
+                    })
+                && params.profile_id.as_ref().is_none_or(|profile_ids| {
+                    payment_intent
+                        .profile_id
+                        .as_ref()
+                        .is_some_and(|profile_id| profile_ids.contains(profile_id))
+                })
+                && starting_at.is_none_or(|starting_at| payment_intent.created_at >= starting_at)
+                && ending_at.is_none_or(|ending_at| payment_intent.created_at <= ending_at)
+                && params.amount_filter.as_ref().is_none_or(|amount_filter| {
+                    amount_filter
+                        .start_amount
+                        .is_none_or(|start| amount >= start)
+                        && amount_filter.end_amount.is_none_or(|end| amount <= end)
+                })
+                && params.currency.as_ref().is_none_or(|currencies| {
+                    payment_intent
+                        .currency
+                        .is_some_and(|currency| currencies.contains(&currency))
+                })
+                && params
+                    .status
+                    .as_ref()
+                    .is_none_or(|statuses| statuses.contains(&payment_intent.status))
+        })
+        .cloned()
+        .collect())
+}
+
+#[cfg(all(feature = "v1", feature = "olap"))]
+fn sort_payment_intents<T>(
+    items: &mut [T],
+    order: &api_models::payments::Order,
+    payment_intent: impl Fn(&T) -> &PaymentIntent,
+) {
+    use api_models::payments::{SortBy, SortOn};
+
+    items.sort_by(|a, b| {
+        let (a, b) = (payment_intent(a), payment_intent(b));
+        let ordering = match order.on {
+            SortOn::Amount => a.amount.cmp(&b.amount),
+            SortOn::Created => a.created_at.cmp(&b.created_at),
+            SortOn::Modified => a.modified_at.cmp(&b.modified_at),
+        };
+        match order.by {
+            SortBy::Asc => ordering,
+            SortBy::Desc => ordering.reverse(),
+        }
+    });
+}
+
+#[cfg(all(feature = "v1", feature = "olap"))]
+fn paginate<T>(items: Vec<T>, params: &PaymentIntentListParams) -> Vec<T> {
+    let offset = usize::try_from(params.offset).unwrap_or(usize::MAX);
+    let limit = params.limit.map_or(usize::MAX, |limit| {
+        usize::try_from(limit).unwrap_or(usize::MAX)
+    });
+    items.into_iter().skip(offset).take(limit).collect()
+}
+
 #[async_trait::async_trait]
 impl PaymentIntentInterface for MockDb {
     type Error = StorageError;
     #[cfg(all(feature = "v1", feature = "olap"))]
     async fn filter_payment_intent_by_constraints(
         &self,
-        _processor_merchant_id: &common_utils::id_type::MerchantId,
-        _filters: &hyperswitch_domain_models::payments::payment_intent::PaymentIntentFetchConstraints,
+        processor_merchant_id: &common_utils::id_type::MerchantId,
+        filters: &PaymentIntentFetchConstraints,
         _key_store: &MerchantKeyStore,
         _storage_scheme: storage_enums::MerchantStorageScheme,
     ) -> CustomResult<Vec<PaymentIntent>, StorageError> {
-        // [#172]: Implement function for `MockDb`
-        Err(StorageError::MockDbError)?
+        let payment_intents = self.payment_intents.lock().await;
+        let mut filtered =
+            filter_payment_intents(&payment_intents, processor_merchant_id, filters)?;
+        filtered.sort_by(|a, b| b.created_at.cmp(&a.created_at));
+
+        Ok(match filters {
+            PaymentIntentFetchConstraints::Single { .. } => filtered,
+            PaymentIntentFetchConstraints::List(params) => paginate(filtered, params),
+        })
     }
 
     #[cfg(all(feature = "v2", feature = "olap"))]
@@ -50,35 +177,76 @@ impl PaymentIntentInterface for MockDb {
     #[cfg(all(feature = "v1", feature = "olap"))]
     async fn filter_payment_intents_by_time_range_constraints(
         &self,
-        _processor_merchant_id: &common_utils::id_type::MerchantId,
-        _time_range: &common_utils::types::TimeRange,
-        _key_store: &MerchantKeyStore,
-        _storage_scheme: storage_enums::MerchantStorageScheme,
+        processor_merchant_id: &common_utils::id_type::MerchantId,
+        time_range: &common_utils::types::TimeRange,
+        key_store: &MerchantKeyStore,
+        storage_scheme: storage_enums::MerchantStorageScheme,
     ) -> CustomResult<Vec<PaymentIntent>, StorageError> {
-        // [#172]: Implement function for `MockDb`
-        Err(StorageError::MockDbError)?
+        let payment_filters = (*time_range).into();
+        self.filter_payment_intent_by_constraints(
+            processor_merchant_id,
+            &payment_filters,
+            key_store,
+            storage_scheme,
+        )
+        .await
     }
 
     #[cfg(feature = "olap")]
     async fn get_intent_status_with_count(
         &self,
-        _processor_merchant_id: &common_utils::id_type::MerchantId,
-        _profile_id_list: Option<Vec<common_utils::id_type::ProfileId>>,
-        _time_range: &common_utils::types::TimeRange,
+        processor_merchant_id: &common_utils::id_type::MerchantId,
+        profile_id_list: Option<Vec<common_utils::id_type::ProfileId>>,
+        time_range: &common_utils::types::TimeRange,
     ) -> CustomResult<Vec<(common_enums::IntentStatus, i64)>, StorageError> {
-        // [#172]: Implement function for `MockDb`
-        Err(StorageError::MockDbError)?
+        let payment_intents = self.payment_intents.lock().await;
+
+        Ok(payment_intents
+            .iter()
+            .filter(|payment_intent| {
+                payment_intent.processor_merchant_id == *processor_merchant_id
+                    && profile_id_list.as_ref().is_none_or(|profile_ids| {
+                        payment_intent
+                            .profile_id
+                            .as_ref()
+                            .is_some_and(|profile_id| profile_ids.contains(profile_id))
+                    })
+                    && payment_intent.created_at >= time_range.start_time
+                    && time_range
+                        .end_time
+                        .is_none_or(|end_time| payment_intent.created_at <= end_time)
+            })
+            .fold(
+                Vec::<(common_enums::IntentStatus, i64)>::new(),
+                |mut counts, payment_intent| {
+                    match counts
+                        .iter_mut()
+                        .find(|(status, _)| *status == payment_intent.status)
+                    {
+                        Some((_, count)) => *count += 1,
+                        None => counts.push((payment_intent.status, 1)),
+                    }
+                    counts
+                },
+            ))
     }
 
     #[cfg(all(feature = "v1", feature = "olap"))]
     async fn get_filtered_active_attempt_ids_for_total_count(
         &self,
-        _processor_merchant_id: &common_utils::id_type::MerchantId,
-        _constraints: &hyperswitch_domain_models::payments::payment_intent::PaymentIntentFetchConstraints,
+        processor_merchant_id: &common_utils::id_type::MerchantId,
+        constraints: &PaymentIntentFetchConstraints,
         _storage_scheme: storage_enums::MerchantStorageScheme,
     ) -> error_stack::Result<Vec<String>, StorageError> {
-        // [#172]: Implement function for `MockDb`
-        Err(StorageError::MockDbError)?
+        let payment_intents = self.payment_intents.lock().await;
+        let mut filtered =
+            filter_payment_intents(&payment_intents, processor_merchant_id, constraints)?;
+        filtered.sort_by(|a, b| b.created_at.cmp(&a.created_at));
+
+        Ok(filtered
+            .iter()
+            .map(|payment_intent| payment_intent.active_attempt.get_id())
+            .collect())
     }
 
     #[cfg(all(feature = "v2", feature = "olap"))]
@@ -95,19 +263,101 @@ impl PaymentIntentInterface for MockDb {
     #[cfg(all(feature = "v1", feature = "olap"))]
     async fn get_filtered_payment_intents_attempt(
         &self,
-        _processor_merchant_id: &common_utils::id_type::MerchantId,
-        _constraints: &hyperswitch_domain_models::payments::payment_intent::PaymentIntentFetchConstraints,
+        processor_merchant_id: &common_utils::id_type::MerchantId,
+        constraints: &PaymentIntentFetchConstraints,
         _key_store: &MerchantKeyStore,
         _storage_scheme: storage_enums::MerchantStorageScheme,
-    ) -> error_stack::Result<
-        Vec<(
-            PaymentIntent,
-            hyperswitch_domain_models::payments::payment_attempt::PaymentAttempt,
-        )>,
-        StorageError,
-    > {
-        // [#172]: Implement function for `MockDb`
-        Err(StorageError::MockDbError)?
+    ) -> error_stack::Result<Vec<(PaymentIntent, PaymentAttempt)>, StorageError> {
+        let payment_intents = self.payment_intents.lock().await;
+        let filtered =
+            filter_payment_intents(&payment_intents, processor_merchant_id, constraints)?;
+        let payment_attempts = self.payment_attempts.lock().await;
+
+        let mut joined = filtered
+            .into_iter()
+            .filter_map(|payment_intent| {
+                let active_attempt_id = payment_intent.active_attempt.get_id();
+                payment_attempts
+                    .iter()
+                    .find(|payment_attempt| {
+                        payment_attempt.attempt_id == active_attempt_id
+                            && payment_attempt.processor_merchant_id == *processor_merchant_id
+                    })
+                    .cloned()
+                    .map(|payment_attempt| (payment_intent, payment_attempt))
+            })
+            .collect::<Vec<_>>();
+
+        let PaymentIntentFetchConstraints::List(params) = constraints else {
+            return Ok(joined);
+        };
+
+        let connectors = params
+            .connector
+            .as_ref()
+            .map(|c| c.iter().map(|c| c.to_string()).collect::<Vec<String>>());
+        joined.retain(|(_, attempt)| {
+            connectors.as_ref().is_none_or(|connectors| {
+                attempt
+                    .connector
+                    .as_ref()
+                    .is_some_and(|connector| connectors.contains(connector))
+            }) && params
+                .payment_method
+                .as_ref()
+                .is_none_or(|payment_methods| {
+                    attempt
+                        .payment_method
+                        .is_some_and(|payment_method| payment_methods.contains(&payment_method))
+                })
+                && params
+                    .payment_method_type
+                    .as_ref()
+                    .is_none_or(|payment_method_types| {
+                        attempt
+                            .payment_method_type
+                            .is_some_and(|payment_method_type| {
+                                payment_method_types.contains(&payment_method_type)
+                            })
+                    })
+                && params
+                    .authentication_type
+                    .as_ref()
+                    .is_none_or(|authentication_types| {
+                        attempt
+                            .authentication_type
+                            .is_some_and(|authentication_type| {
+                                authentication_types.contains(&authentication_type)
+                            })
+                    })
+                && params
+                    .merchant_connector_id
+                    .as_ref()
+                    .is_none_or(|merchant_connector_ids| {
+                        attempt
+                            .merchant_connector_id
+                            .as_ref()
+                            .is_some_and(|id| merchant_connector_ids.contains(id))
+                    })
+                && params.card_network.as_ref().is_none_or(|card_networks| {
+                    attempt
+                        .extract_card_network()
+                        .is_some_and(|card_network| card_networks.contains(&card_network))
+                })
+                && params
+                    .card_discovery
+                    .as_ref()
+                    .is_none_or(|card_discoveries| {
+                        attempt.card_discovery.is_some_and(|card_discovery| {
+                            card_discoveries.contains(&card_discovery)
+                        })
+                    })
+        });
+        sort_payment_intents(&mut joined, &params.order, |(payment_intent, _)| {
+            payment_intent
+        });
+
+        Ok(paginate(joined, params))
     }
 
     #[allow(clippy::panic)]
@@ -123,8 +373,6 @@ impl PaymentIntentInterface for MockDb {
     }
 
     #[cfg(feature = "v1")]
-    // safety: only used for testing
-    #[allow(clippy::unwrap_used)]
     async fn update_payment_intent(
         &self,
         this: PaymentIntent,
@@ -139,7 +387,9 @@ impl PaymentIntentInterface for MockDb {
                 item.get_id() == this.get_id()
                     && item.processor_merchant_id == this.processor_merchant_id
             })
-            .unwrap();
+            .ok_or(StorageError::ValueNotFound(
+                "PaymentIntent not found".to_string(),
+            ))?;
 
         let diesel_payment_intent_update = diesel_models::PaymentIntentUpdate::from(update);
         let diesel_payment_intent = payment_intent
@@ -152,7 +402,7 @@ impl PaymentIntentInterface for MockDb {
             self.get_keymanager_state()
                 .attach_printable("Missing KeyManagerState")?,
             diesel_payment_intent_update.apply_changeset(diesel_payment_intent),
-            key_store.key.get_inner(),
+            &key_store.get_key_ring(),
             key_store.merchant_id.clone().into(),
         )
         .await
@@ -162,21 +412,42 @@ impl PaymentIntentInterface for MockDb {
     }
 
     #[cfg(feature = "v2")]
-    // safety: only used for testing
-    #[allow(clippy::unwrap_used)]
     async fn update_payment_intent(
         &self,
-        _this: PaymentIntent,
-        _update: PaymentIntentUpdate,
-        _key_store: &MerchantKeyStore,
+        this: PaymentIntent,
+        update: PaymentIntentUpdate,
+        key_store: &MerchantKeyStore,
         _storage_scheme: storage_enums::MerchantStorageScheme,
     ) -> CustomResult<PaymentIntent, StorageError> {
-        todo!()
+        let mut payment_intents = self.payment_intents.lock().await;
+        let payment_intent = payment_intents
+            .iter_mut()
+            .find(|item| item.get_id() == this.get_id())
+            .ok_or(StorageError::ValueNotFound(
+                "PaymentIntent not found".to_string(),
+            ))?;
+
+        let diesel_payment_intent_update =
+            diesel_models::PaymentIntentUpdateInternal::try_from(update)
+                .change_context(StorageError::DeserializationFailed)?;
+        let diesel_payment_intent = Conversion::convert(this)
+            .await
+            .change_context(StorageError::EncryptionError)?;
+
+        *payment_intent = PaymentIntent::convert_back(
+            self.get_keymanager_state()
+                .attach_printable("Missing KeyManagerState")?,
+            diesel_payment_intent_update.apply_changeset(diesel_payment_intent),
+            &key_store.get_key_ring(),
+            key_store.merchant_id.clone().into(),
+        )
+        .await
+        .change_context(StorageError::DecryptionError)?;
+
+        Ok(payment_intent.clone())
     }
 
     #[cfg(feature = "v1")]
-    // safety: only used for testing
-    #[allow(clippy::unwrap_used)]
     async fn find_payment_intent_by_payment_id_processor_merchant_id(
         &self,
         payment_id: &common_utils::id_type::PaymentId,
@@ -195,7 +466,9 @@ impl PaymentIntentInterface for MockDb {
                         .eq(processor_merchant_id)
             })
             .cloned()
-            .unwrap())
+            .ok_or(StorageError::ValueNotFound(
+                "PaymentIntent not found".to_string(),
+            ))?)
     }
 
     #[cfg(feature = "v2")]
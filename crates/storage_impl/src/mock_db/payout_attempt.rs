@@ -1,3 +1,6 @@
+use std::str::FromStr;
+
+use api_models::enums::PayoutConnectors;
 use common_utils::errors::CustomResult;
 use diesel_models::enums as storage_enums;
 use hyperswitch_domain_models::payouts::{
@@ -8,70 +11,204 @@ use hyperswitch_domain_models::payouts::{
 };
 
 use super::MockDb;
-use crate::errors::StorageError;
+use crate::{errors::StorageError, DataModelExt};
 
 #[async_trait::async_trait]
 impl PayoutAttemptInterface for MockDb {
     type Error = StorageError;
     async fn update_payout_attempt(
         &self,
-        _this: &PayoutAttempt,
-        _payout_attempt_update: PayoutAttemptUpdate,
+        this: &PayoutAttempt,
+        payout_attempt_update: PayoutAttemptUpdate,
         _payouts: &Payouts,
         _storage_scheme: storage_enums::MerchantStorageScheme,
     ) -> CustomResult<PayoutAttempt, StorageError> {
-        // TODO: Implement function for `MockDb`
-        Err(StorageError::MockDbError)?
+        let mut payout_attempts = self.payout_attempt.lock().await;
+        let payout_attempt = payout_attempts
+            .iter_mut()
+            .find(|payout_attempt| {
+                payout_attempt.merchant_id == this.merchant_id
+                    && payout_attempt.payout_attempt_id == this.payout_attempt_id
+            })
+            .ok_or(StorageError::ValueNotFound(
+                "PayoutAttempt not found".to_string(),
+            ))?;
+
+        *payout_attempt = payout_attempt_update
+            .to_storage_model()
+            .apply_changeset(this.clone().to_storage_model());
+
+        Ok(PayoutAttempt::from_storage_model(payout_attempt.clone()))
     }
 
     async fn insert_payout_attempt(
         &self,
-        _payout_attempt: PayoutAttemptNew,
+        new_payout_attempt: PayoutAttemptNew,
         _payouts: &Payouts,
         _storage_scheme: storage_enums::MerchantStorageScheme,
     ) -> CustomResult<PayoutAttempt, StorageError> {
-        // TODO: Implement function for `MockDb`
-        Err(StorageError::MockDbError)?
+        let mut payout_attempts = self.payout_attempt.lock().await;
+        if payout_attempts.iter().any(|payout_attempt| {
+            payout_attempt.merchant_id == new_payout_attempt.merchant_id
+                && payout_attempt.payout_attempt_id == new_payout_attempt.payout_attempt_id
+        }) {
+            Err(StorageError::DuplicateValue {
+                entity: "payout_attempt",
+                key: Some(new_payout_attempt.payout_attempt_id),
+            })?
+        }
+
+        let created_attempt = PayoutAttempt {
+            payout_attempt_id: new_payout_attempt.payout_attempt_id,
+            payout_id: new_payout_attempt.payout_id,
+            additional_payout_method_data: new_payout_attempt.additional_payout_method_data,
+            customer_id: new_payout_attempt.customer_id,
+            merchant_id: new_payout_attempt.merchant_id,
+            address_id: new_payout_attempt.address_id,
+            connector: new_payout_attempt.connector,
+            connector_payout_id: new_payout_attempt.connector_payout_id,
+            payout_token: new_payout_attempt.payout_token,
+            status: new_payout_attempt.status,
+            is_eligible: new_payout_attempt.is_eligible,
+            error_message: new_payout_attempt.error_message,
+            error_code: new_payout_attempt.error_code,
+            business_country: new_payout_attempt.business_country,
+            business_label: new_payout_attempt.business_label,
+            created_at: new_payout_attempt.created_at,
+            last_modified_at: new_payout_attempt.last_modified_at,
+            profile_id: new_payout_attempt.profile_id,
+            merchant_connector_id: new_payout_attempt.merchant_connector_id,
+            routing_info: new_payout_attempt.routing_info,
+            unified_code: new_payout_attempt.unified_code,
+            unified_message: new_payout_attempt.unified_message,
+            merchant_order_reference_id: new_payout_attempt.merchant_order_reference_id,
+            payout_connector_metadata: new_payout_attempt.payout_connector_metadata,
+            processor_merchant_id: new_payout_attempt.processor_merchant_id,
+            created_by: new_payout_attempt.created_by,
+        };
+        payout_attempts.push(created_attempt.clone().to_storage_model());
+
+        Ok(created_attempt)
     }
 
     async fn find_payout_attempt_by_merchant_id_payout_attempt_id(
         &self,
-        _merchant_id: &common_utils::id_type::MerchantId,
-        _payout_attempt_id: &str,
+        merchant_id: &common_utils::id_type::MerchantId,
+        payout_attempt_id: &str,
         _storage_scheme: storage_enums::MerchantStorageScheme,
     ) -> CustomResult<PayoutAttempt, StorageError> {
-        // TODO: Implement function for `MockDb`
-        Err(StorageError::MockDbError)?
+        let payout_attempts = self.payout_attempt.lock().await;
+
+        payout_attempts
+            .iter()
+            .find(|payout_attempt| {
+                payout_attempt.merchant_id == *merchant_id
+                    && payout_attempt.payout_attempt_id == payout_attempt_id
+            })
+            .cloned()
+            .map(PayoutAttempt::from_storage_model)
+            .ok_or(StorageError::ValueNotFound("PayoutAttempt not found".to_string()).into())
     }
 
     async fn find_payout_attempt_by_merchant_id_connector_payout_id(
         &self,
-        _merchant_id: &common_utils::id_type::MerchantId,
-        _connector_payout_id: &str,
+        merchant_id: &common_utils::id_type::MerchantId,
+        connector_payout_id: &str,
         _storage_scheme: storage_enums::MerchantStorageScheme,
     ) -> CustomResult<PayoutAttempt, StorageError> {
-        // TODO: Implement function for `MockDb`
-        Err(StorageError::MockDbError)?
+        let payout_attempts = self.payout_attempt.lock().await;
+
+        payout_attempts
+            .iter()
+            .find(|payout_attempt| {
+                payout_attempt.merchant_id == *merchant_id
+                    && payout_attempt.connector_payout_id.as_deref() == Some(connector_payout_id)
+            })
+            .cloned()
+            .map(PayoutAttempt::from_storage_model)
+            .ok_or(StorageError::ValueNotFound("PayoutAttempt not found".to_string()).into())
     }
 
     async fn get_filters_for_payouts(
         &self,
-        _payouts: &[Payouts],
-        _merchant_id: &common_utils::id_type::MerchantId,
+        payouts: &[Payouts],
+        merchant_id: &common_utils::id_type::MerchantId,
         _storage_scheme: storage_enums::MerchantStorageScheme,
     ) -> CustomResult<
         hyperswitch_domain_models::payouts::payout_attempt::PayoutListFilters,
         StorageError,
     > {
-        Err(StorageError::MockDbError)?
+        let active_attempt_ids = payouts
+            .iter()
+            .map(|payout| {
+                format!(
+                    "{}_{}",
+                    payout.payout_id.get_string_repr(),
+                    payout.attempt_count
+                )
+            })
+            .collect::<Vec<String>>();
+        let merchant_payouts = payouts
+            .iter()
+            .filter(|payout| payout.merchant_id == *merchant_id)
+            .collect::<Vec<_>>();
+        let payout_attempts = self.payout_attempt.lock().await;
+
+        let mut filters = hyperswitch_domain_models::payouts::payout_attempt::PayoutListFilters {
+            connector: Vec::new(),
+            currency: Vec::new(),
+            status: Vec::new(),
+            payout_method: Vec::new(),
+        };
+        payout_attempts
+            .iter()
+            .filter(|payout_attempt| {
+                payout_attempt.merchant_id == *merchant_id
+                    && active_attempt_ids.contains(&payout_attempt.payout_attempt_id)
+            })
+            .filter_map(|payout_attempt| payout_attempt.connector.as_deref())
+            .filter_map(|connector| PayoutConnectors::from_str(connector).ok())
+            .for_each(|connector| {
+                if !filters.connector.contains(&connector) {
+                    filters.connector.push(connector);
+                }
+            });
+        payouts.iter().for_each(|payout| {
+            if !filters.status.contains(&payout.status) {
+                filters.status.push(payout.status);
+            }
+        });
+        merchant_payouts.iter().for_each(|payout| {
+            if !filters.currency.contains(&payout.destination_currency) {
+                filters.currency.push(payout.destination_currency);
+            }
+            if let Some(payout_type) = payout.payout_type {
+                if !filters.payout_method.contains(&payout_type) {
+                    filters.payout_method.push(payout_type);
+                }
+            }
+        });
+
+        Ok(filters)
     }
 
     async fn find_payout_attempt_by_merchant_id_merchant_order_reference_id(
         &self,
-        _merchant_id: &common_utils::id_type::MerchantId,
-        _merchant_order_reference_id: &str,
+        merchant_id: &common_utils::id_type::MerchantId,
+        merchant_order_reference_id: &str,
         _storage_scheme: storage_enums::MerchantStorageScheme,
     ) -> CustomResult<PayoutAttempt, StorageError> {
-        Err(StorageError::MockDbError)?
+        let payout_attempts = self.payout_attempt.lock().await;
+
+        payout_attempts
+            .iter()
+            .find(|payout_attempt| {
+                payout_attempt.merchant_id == *merchant_id
+                    && payout_attempt.merchant_order_reference_id.as_deref()
+                        == Some(merchant_order_reference_id)
+            })
+            .cloned()
+            .map(PayoutAttempt::from_storage_model)
+            .ok_or(StorageError::ValueNotFound("PayoutAttempt not found".to_string()).into())
     }
 }
@@ -1,71 +1,313 @@
 use common_utils::errors::CustomResult;
 use diesel_models::enums as storage_enums;
+#[cfg(feature = "olap")]
+use error_stack::ResultExt;
+#[cfg(feature = "olap")]
+use hyperswitch_domain_models::payouts::PayoutFetchConstraints;
 use hyperswitch_domain_models::payouts::{
     payout_attempt::PayoutAttempt,
     payouts::{Payouts, PayoutsInterface, PayoutsNew, PayoutsUpdate},
 };
 
-use crate::{errors::StorageError, MockDb};
+use crate::{errors::StorageError, DataModelExt, MockDb};
+
+/// Applies the payout level filters of the fetch constraints, optionally resolving the pagination
+/// cursors against the same set of payouts. Results are ordered by creation time, newest first.
+#[cfg(feature = "olap")]
+fn filter_payouts(
+    payouts: &[diesel_models::Payouts],
+    merchant_id: &common_utils::id_type::MerchantId,
+    constraints: &PayoutFetchConstraints,
+    apply_cursors: bool,
+) -> CustomResult<Vec<Payouts>, StorageError> {
+    let mut merchant_payouts = payouts
+        .iter()
+        .filter(|payout| payout.merchant_id == *merchant_id)
+        .cloned()
+        .map(Payouts::from_storage_model)
+        .collect::<Vec<_>>();
+    merchant_payouts.sort_by(|a, b| b.created_at.cmp(&a.created_at));
+
+    let params = match constraints {
+        PayoutFetchConstraints::Single { payout_id } => {
+            merchant_payouts.retain(|payout| payout.payout_id == *payout_id);
+            return Ok(merchant_payouts);
+        }
+        PayoutFetchConstraints::List(params) => params,
+    };
+
+    let created_at_of = |payout_id: &common_utils::id_type::PayoutId| {
+        merchant_payouts
+            .iter()
+            .find(|payout| payout.payout_id == *payout_id)
+            .map(|payout| payout.created_at)
+            .ok_or(StorageError::ValueNotFound("Payout not found".to_string()))
+    };
+    let starting_at = match (params.starting_at, params.starting_after_id.as_ref()) {
+        (Some(starting_at), _) => Some(starting_at),
+        (None, Some(starting_after_id)) if apply_cursors => Some(created_at_of(starting_after_id)?),
+        (None, _) => None,
+    };
+    let ending_at = match (params.ending_at, params.ending_before_id.as_ref()) {
+        (Some(ending_at), _) => Some(ending_at),
+        (None, Some(ending_before_id)) if apply_cursors => Some(created_at_of(ending_before_id)?),
+        (None, _) => None,
+    };
+
+    merchant_payouts.retain(|payout| {
+        params
+            .customer_id
+            .as_ref()
+            .is_none_or(|customer_id| payout.customer_id.as_ref() == Some(customer_id))
+            && params
+                .profile_id
+                .as_ref()
+                .is_none_or(|profile_id| payout.profile_id == *profile_id)
+            && starting_at.is_none_or(|starting_at| payout.created_at >= starting_at)
+            && ending_at.is_none_or(|ending_at| payout.created_at <= ending_at)
+            && params
+                .currency
+                .as_ref()
+                .is_none_or(|currency| currency.contains(&payout.destination_currency))
+            && params
+                .status
+                .as_ref()
+                .is_none_or(|status| status.contains(&payout.status))
+    });
+
+    Ok(merchant_payouts)
+}
+
+#[cfg(feature = "olap")]
+fn paginate<T>(items: Vec<T>, constraints: &PayoutFetchConstraints) -> Vec<T> {
+    match constraints {
+        PayoutFetchConstraints::Single { .. } => items,
+        PayoutFetchConstraints::List(params) => {
+            let offset = usize::try_from(params.offset).unwrap_or(usize::MAX);
+            let limit = params.limit.map_or(usize::MAX, |limit| {
+                usize::try_from(limit).unwrap_or(usize::MAX)
+            });
+            items.into_iter().skip(offset).take(limit).collect()
+        }
+    }
+}
 
 #[async_trait::async_trait]
 impl PayoutsInterface for MockDb {
     type Error = StorageError;
     async fn find_payout_by_merchant_id_payout_id(
         &self,
-        _merchant_id: &common_utils::id_type::MerchantId,
-        _payout_id: &common_utils::id_type::PayoutId,
-        _storage_scheme: storage_enums::MerchantStorageScheme,
+        merchant_id: &common_utils::id_type::MerchantId,
+        payout_id: &common_utils::id_type::PayoutId,
+        storage_scheme: storage_enums::MerchantStorageScheme,
     ) -> CustomResult<Payouts, StorageError> {
-        // TODO: Implement function for `MockDb`
-        Err(StorageError::MockDbError)?
+        self.find_optional_payout_by_merchant_id_payout_id(merchant_id, payout_id, storage_scheme)
+            .await?
+            .ok_or(StorageError::ValueNotFound("Payout not found".to_string()).into())
     }
 
     async fn update_payout(
         &self,
-        _this: &Payouts,
-        _payout_update: PayoutsUpdate,
+        this: &Payouts,
+        payout_update: PayoutsUpdate,
         _payout_attempt: &PayoutAttempt,
         _storage_scheme: storage_enums::MerchantStorageScheme,
     ) -> CustomResult<Payouts, StorageError> {
-        // TODO: Implement function for `MockDb`
-        Err(StorageError::MockDbError)?
+        let mut payouts = self.payouts.lock().await;
+        let payout = payouts
+            .iter_mut()
+            .find(|payout| {
+                payout.merchant_id == this.merchant_id && payout.payout_id == this.payout_id
+            })
+            .ok_or(StorageError::ValueNotFound("Payout not found".to_string()))?;
+
+        *payout = payout_update
+            .to_storage_model()
+            .apply_changeset(this.clone().to_storage_model());
+
+        Ok(Payouts::from_storage_model(payout.clone()))
     }
 
     async fn insert_payout(
         &self,
-        _payout: PayoutsNew,
+        new: PayoutsNew,
         _storage_scheme: storage_enums::MerchantStorageScheme,
     ) -> CustomResult<Payouts, StorageError> {
-        // TODO: Implement function for `MockDb`
-        Err(StorageError::MockDbError)?
+        let mut payouts = self.payouts.lock().await;
+        if payouts.iter().any(|payout| {
+            payout.merchant_id == new.merchant_id && payout.payout_id == new.payout_id
+        }) {
+            Err(StorageError::DuplicateValue {
+                entity: "payouts",
+                key: Some(new.payout_id.get_string_repr().to_owned()),
+            })?
+        }
+
+        let created_payout = Payouts {
+            payout_id: new.payout_id,
+            merchant_id: new.merchant_id,
+            customer_id: new.customer_id,
+            address_id: new.address_id,
+            payout_type: new.payout_type,
+            payout_method_id: new.payout_method_id,
+            amount: new.amount,
+            destination_currency: new.destination_currency,
+            source_currency: new.source_currency,
+            description: new.description,
+            recurring: new.recurring,
+            auto_fulfill: new.auto_fulfill,
+            return_url: new.return_url,
+            entity_type: new.entity_type,
+            metadata: new.metadata,
+            created_at: new.created_at,
+            last_modified_at: new.last_modified_at,
+            profile_id: new.profile_id,
+            status: new.status,
+            attempt_count: new.attempt_count,
+            confirm: new.confirm,
+            payout_link_id: new.payout_link_id,
+            client_secret: new.client_secret,
+            priority: new.priority,
+            organization_id: new.organization_id,
+            processor_merchant_id: new.processor_merchant_id,
+            created_by: new.created_by,
+        };
+        payouts.push(created_payout.clone().to_storage_model());
+
+        Ok(created_payout)
     }
 
     async fn find_optional_payout_by_merchant_id_payout_id(
         &self,
-        _merchant_id: &common_utils::id_type::MerchantId,
-        _payout_id: &common_utils::id_type::PayoutId,
+        merchant_id: &common_utils::id_type::MerchantId,
+        payout_id: &common_utils::id_type::PayoutId,
         _storage_scheme: storage_enums::MerchantStorageScheme,
     ) -> CustomResult<Option<Payouts>, StorageError> {
-        // TODO: Implement function for `MockDb`
-        Err(StorageError::MockDbError)?
+        let payouts = self.payouts.lock().await;
+
+        Ok(payouts
+            .iter()
+            .find(|payout| payout.merchant_id == *merchant_id && payout.payout_id == *payout_id)
+            .cloned()
+            .map(Payouts::from_storage_model))
     }
 
     #[cfg(feature = "olap")]
     async fn filter_payouts_by_constraints(
         &self,
-        _merchant_id: &common_utils::id_type::MerchantId,
-        _filters: &hyperswitch_domain_models::payouts::PayoutFetchConstraints,
+        merchant_id: &common_utils::id_type::MerchantId,
+        filters: &PayoutFetchConstraints,
         _storage_scheme: storage_enums::MerchantStorageScheme,
     ) -> CustomResult<Vec<Payouts>, StorageError> {
-        // TODO: Implement function for `MockDb`
-        Err(StorageError::MockDbError)?
+        let payouts = self.payouts.lock().await;
+
+        Ok(paginate(
+            filter_payouts(&payouts, merchant_id, filters, true)?,
+            filters,
+        ))
     }
 
-    #[cfg(feature = "olap")]
+    #[cfg(all(feature = "v1", feature = "olap"))]
+    async fn filter_payouts_and_attempts(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        filters: &PayoutFetchConstraints,
+        _storage_scheme: storage_enums::MerchantStorageScheme,
+    ) -> CustomResult<
+        Vec<(
+            Payouts,
+            PayoutAttempt,
+            Option<diesel_models::Customer>,
+            Option<diesel_models::Address>,
+        )>,
+        StorageError,
+    > {
+        let filtered_payouts =
+            filter_payouts(&*self.payouts.lock().await, merchant_id, filters, true)?;
+        let payout_attempts = self.payout_attempt.lock().await;
+        let customers = self.customers.lock().await;
+        let addresses = self.addresses.lock().await;
+
+        let connectors = match filters {
+            PayoutFetchConstraints::List(params) => params
+                .connector
+                .as_ref()
+                .filter(|connectors| !connectors.is_empty())
+                .map(|c| c.iter().map(|c| c.to_string()).collect::<Vec<String>>()),
+            PayoutFetchConstraints::Single { .. } => None,
+        };
+
+        let joined = filtered_payouts
+            .into_iter()
+            .filter(|payout| match filters {
+                PayoutFetchConstraints::List(params) => params
+                    .payout_method
+                    .as_ref()
+                    .filter(|payout_method| !payout_method.is_empty())
+                    .is_none_or(|payout_method| {
+                        payout
+                            .payout_type
+                            .is_some_and(|payout_type| payout_method.contains(&payout_type))
+                    }),
+                PayoutFetchConstraints::Single { .. } => true,
+            })
+            .flat_map(|payout| {
+                payout_attempts
+                    .iter()
+                    .filter(|payout_attempt| {
+                        payout_attempt.payout_id == payout.payout_id
+                            && connectors.as_ref().is_none_or(|connectors| {
+                                payout_attempt
+                                    .connector
+                                    .as_ref()
+                                    .is_some_and(|connector| connectors.contains(connector))
+                            })
+                            && match filters {
+                                PayoutFetchConstraints::List(params) => params
+                                    .merchant_order_reference_id
+                                    .as_ref()
+                                    .is_none_or(|reference_id| {
+                                        payout_attempt.merchant_order_reference_id.as_ref()
+                                            == Some(reference_id)
+                                    }),
+                                PayoutFetchConstraints::Single { .. } => true,
+                            }
+                    })
+                    .map(|payout_attempt| {
+                        let customer = payout.customer_id.as_ref().and_then(|customer_id| {
+                            customers
+                                .iter()
+                                .find(|customer| {
+                                    customer.customer_id == *customer_id
+                                        && customer.merchant_id == *merchant_id
+                                })
+                                .cloned()
+                        });
+                        let address = payout.address_id.as_ref().and_then(|address_id| {
+                            addresses
+                                .iter()
+                                .find(|address| address.address_id == *address_id)
+                                .cloned()
+                        });
+                        (
+                            payout.clone(),
+                            PayoutAttempt::from_storage_model(payout_attempt.clone()),
+                            customer,
+                            address,
+                        )
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+
+        Ok(paginate(joined, filters))
+    }
+
+    #[cfg(all(feature = "v2", feature = "olap"))]
     async fn filter_payouts_and_attempts(
         &self,
         _merchant_id: &common_utils::id_type::MerchantId,
-        _filters: &hyperswitch_domain_models::payouts::PayoutFetchConstraints,
+        _filters: &PayoutFetchConstraints,
         _storage_scheme: storage_enums::MerchantStorageScheme,
     ) -> CustomResult<
         Vec<(
@@ -83,33 +325,95 @@ impl PayoutsInterface for MockDb {
     #[cfg(feature = "olap")]
     async fn filter_payouts_by_time_range_constraints(
         &self,
-        _merchant_id: &common_utils::id_type::MerchantId,
-        _time_range: &common_utils::types::TimeRange,
-        _storage_scheme: storage_enums::MerchantStorageScheme,
+        merchant_id: &common_utils::id_type::MerchantId,
+        time_range: &common_utils::types::TimeRange,
+        storage_scheme: storage_enums::MerchantStorageScheme,
     ) -> CustomResult<Vec<Payouts>, StorageError> {
-        // TODO: Implement function for `MockDb`
-        Err(StorageError::MockDbError)?
+        let payout_filters = (*time_range).into();
+        self.filter_payouts_by_constraints(merchant_id, &payout_filters, storage_scheme)
+            .await
     }
 
     #[cfg(feature = "olap")]
     async fn get_total_count_of_filtered_payouts(
         &self,
-        _merchant_id: &common_utils::id_type::MerchantId,
-        _active_payout_ids: &[common_utils::id_type::PayoutId],
-        _connector: Option<Vec<api_models::enums::PayoutConnectors>>,
-        _currency: Option<Vec<storage_enums::Currency>>,
-        _status: Option<Vec<storage_enums::PayoutStatus>>,
-        _payout_method: Option<Vec<storage_enums::PayoutType>>,
+        merchant_id: &common_utils::id_type::MerchantId,
+        active_payout_ids: &[common_utils::id_type::PayoutId],
+        connector: Option<Vec<api_models::enums::PayoutConnectors>>,
+        currency: Option<Vec<storage_enums::Currency>>,
+        status: Option<Vec<storage_enums::PayoutStatus>>,
+        payout_method: Option<Vec<storage_enums::PayoutType>>,
     ) -> CustomResult<i64, StorageError> {
-        // TODO: Implement function for `MockDb`
-        Err(StorageError::MockDbError)?
+        let connector = connector.map(|connectors| {
+            connectors
+                .iter()
+                .map(|c| c.to_string())
+                .collect::<Vec<String>>()
+        });
+        let payouts = self.payouts.lock().await;
+        let payout_attempts = self.payout_attempt.lock().await;
+
+        let count = payouts
+            .iter()
+            .filter(|payout| {
+                payout.merchant_id == *merchant_id
+                    && active_payout_ids.contains(&payout.payout_id)
+                    && currency
+                        .as_ref()
+                        .is_none_or(|currency| currency.contains(&payout.destination_currency))
+                    && status
+                        .as_ref()
+                        .is_none_or(|status| status.contains(&payout.status))
+                    && payout_method.as_ref().is_none_or(|payout_method| {
+                        payout
+                            .payout_type
+                            .is_some_and(|payout_type| payout_method.contains(&payout_type))
+                    })
+            })
+            .flat_map(|payout| {
+                payout_attempts.iter().filter(|payout_attempt| {
+                    payout_attempt.payout_id == payout.payout_id
+                        && connector.as_ref().is_none_or(|connector| {
+                            payout_attempt
+                                .connector
+                                .as_ref()
+                                .is_some_and(|value| connector.contains(value))
+                        })
+                })
+            })
+            .count();
+
+        i64::try_from(count).change_context(StorageError::MockDbError)
     }
 
-    #[cfg(feature = "olap")]
+    #[cfg(all(feature = "v1", feature = "olap"))]
+    async fn filter_active_payout_ids_by_constraints(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        constraints: &PayoutFetchConstraints,
+    ) -> CustomResult<Vec<common_utils::id_type::PayoutId>, StorageError> {
+        let payouts = self.payouts.lock().await;
+        let payout_attempts = self.payout_attempt.lock().await;
+        // The cursors only paginate the listing itself and do not narrow down the count
+        let filtered_payouts = filter_payouts(&payouts, merchant_id, constraints, false)?;
+
+        Ok(filtered_payouts
+            .into_iter()
+            .flat_map(|payout| {
+                payout_attempts
+                    .iter()
+                    .filter(|payout_attempt| payout_attempt.payout_id == payout.payout_id)
+                    .map(|_| payout.payout_id.clone())
+                    .collect::<Vec<_>>()
+            })
+            .collect())
+    }
+
+    #[cfg(all(feature = "v2", feature = "olap"))]
     async fn filter_active_payout_ids_by_constraints(
         &self,
         _merchant_id: &common_utils::id_type::MerchantId,
-        _constraints: &hyperswitch_domain_models::payouts::PayoutFetchConstraints,
+        _constraints: &PayoutFetchConstraints,
     ) -> CustomResult<Vec<common_utils::id_type::PayoutId>, StorageError> {
         // TODO: Implement function for `MockDb`
         Err(StorageError::MockDbError)?
@@ -118,10 +422,36 @@ impl PayoutsInterface for MockDb {
     #[cfg(feature = "olap")]
     async fn get_payout_intent_status_with_count(
         &self,
-        _merchant_id: &common_utils::id_type::MerchantId,
-        _profile_id_list: Option<Vec<common_utils::id_type::ProfileId>>,
-        _time_range: &common_utils::types::TimeRange,
+        merchant_id: &common_utils::id_type::MerchantId,
+        profile_id_list: Option<Vec<common_utils::id_type::ProfileId>>,
+        time_range: &common_utils::types::TimeRange,
     ) -> CustomResult<Vec<(common_enums::PayoutStatus, i64)>, StorageError> {
-        Err(StorageError::MockDbError)?
+        let payouts = self.payouts.lock().await;
+
+        Ok(payouts
+            .iter()
+            .filter(|payout| {
+                payout.merchant_id == *merchant_id
+                    && profile_id_list
+                        .as_ref()
+                        .is_none_or(|profile_ids| profile_ids.contains(&payout.profile_id))
+                    && payout.created_at >= time_range.start_time
+                    && time_range
+                        .end_time
+                        .is_none_or(|end_time| payout.created_at <= end_time)
+            })
+            .fold(
+                Vec::<(common_enums::PayoutStatus, i64)>::new(),
+                |mut counts, payout| {
+                    match counts
+                        .iter_mut()
+                        .find(|(status, _)| *status == payout.status)
+                    {
+                        Some((_, count)) => *count += 1,
+                        None => counts.push((payout.status, 1)),
+                    }
+                    counts
+                },
+            ))
     }
 }
@@ -72,7 +72,7 @@ impl<T: DatabaseStore> PaymentAttemptInterface for RouterStore<T> {
                 PaymentAttempt::convert_back(
                     key_manager_state,
                     diesel_payment_attempt,
-                    merchant_key_store.key.get_inner(),
+                    &merchant_key_store.get_key_ring(),
                     merchant_key_store.merchant_id.clone().into(),
                 )
                 .await
@@ -103,7 +103,7 @@ impl<T: DatabaseStore> PaymentAttemptInterface for RouterStore<T> {
             .convert(
                 self.get_keymanager_state()
                     .attach_printable("Missing KeyManagerState")?,
-                merchant_key_store.key.get_inner(),
+                &merchant_key_store.get_key_ring(),
                 merchant_key_store.merchant_id.clone().into(),
             )
             .await
@@ -136,7 +136,7 @@ impl<T: DatabaseStore> PaymentAttemptInterface for RouterStore<T> {
                 PaymentAttempt::convert_back(
                     key_manager_state,
                     diesel_payment_attempt,
-                    merchant_key_store.key.get_inner(),
+                    &merchant_key_store.get_key_ring(),
                     merchant_key_store.merchant_id.clone().into(),
                 )
                 .await
@@ -171,7 +171,7 @@ impl<T: DatabaseStore> PaymentAttemptInterface for RouterStore<T> {
             .convert(
                 self.get_keymanager_state()
                     .attach_printable("Missing KeyManagerState")?,
-                merchant_key_store.key.get_inner(),
+                &merchant_key_store.get_key_ring(),
                 merchant_key_store.merchant_id.clone().into(),
             )
             .await
@@ -207,7 +207,7 @@ impl<T: DatabaseStore> PaymentAttemptInterface for RouterStore<T> {
             PaymentAttempt::convert_back(
                 key_manager_state,
                 diesel_payment_attempt,
-                merchant_key_store.key.get_inner(),
+                &merchant_key_store.get_key_ring(),
                 merchant_key_store.merchant_id.clone().into(),
             )
             .await
@@ -243,7 +243,7 @@ impl<T: DatabaseStore> PaymentAttemptInterface for RouterStore<T> {
             PaymentAttempt::convert_back(
                 key_manager_state,
                 diesel_payment_attempt,
-                merchant_key_store.key.get_inner(),
+                &merchant_key_store.get_key_ring(),
                 merchant_key_store.merchant_id.clone().into(),
             )
             .await
@@ -279,7 +279,7 @@ impl<T: DatabaseStore> PaymentAttemptInterface for RouterStore<T> {
             PaymentAttempt::convert_back(
                 key_manager_state,
                 diesel_payment_attempt,
-                merchant_key_store.key.get_inner(),
+                &merchant_key_store.get_key_ring(),
                 merchant_key_store.merchant_id.clone().into(),
             )
             .await
@@ -307,7 +307,7 @@ impl<T: DatabaseStore> PaymentAttemptInterface for RouterStore<T> {
         .convert(
             self.get_keymanager_state()
                 .attach_printable("Missing KeyManagerState")?,
-            merchant_key_store.key.get_inner(),
+            &merchant_key_store.get_key_ring(),
             merchant_key_store.merchant_id.clone().into(),
         )
         .await
@@ -341,7 +341,7 @@ impl<T: DatabaseStore> PaymentAttemptInterface for RouterStore<T> {
             PaymentAttempt::convert_back(
                 key_manager_state,
                 diesel_payment_attempt,
-                merchant_key_store.key.get_inner(),
+                &merchant_key_store.get_key_ring(),
                 merchant_key_store.merchant_id.clone().into(),
             )
             .await
@@ -373,7 +373,7 @@ impl<T: DatabaseStore> PaymentAttemptInterface for RouterStore<T> {
         .convert(
             self.get_keymanager_state()
                 .attach_printable("Missing KeyManagerState")?,
-            merchant_key_store.key.get_inner(),
+            &merchant_key_store.get_key_ring(),
             merchant_key_store.merchant_id.clone().into(),
         )
         .await
@@ -410,7 +410,7 @@ impl<T: DatabaseStore> PaymentAttemptInterface for RouterStore<T> {
             PaymentAttempt::convert_back(
                 key_manager_state,
                 diesel_payment_attempt,
-                merchant_key_store.key.get_inner(),
+                &merchant_key_store.get_key_ring(),
                 merchant_key_store.merchant_id.clone().into(),
             )
             .await
@@ -494,7 +494,7 @@ impl<T: DatabaseStore> PaymentAttemptInterface for RouterStore<T> {
             PaymentAttempt::convert_back(
                 key_manager_state,
                 diesel_payment_attempt,
-                merchant_key_store.key.get_inner(),
+                &merchant_key_store.get_key_ring(),
                 merchant_key_store.merchant_id.clone().into(),
             )
             .await
@@ -531,7 +531,7 @@ impl<T: DatabaseStore> PaymentAttemptInterface for RouterStore<T> {
                 PaymentAttempt::convert_back(
                     key_manager_state,
                     diesel_payment_attempt,
-                    merchant_key_store.key.get_inner(),
+                    &merchant_key_store.get_key_ring(),
                     merchant_key_store.merchant_id.clone().into(),
                 )
             }))
@@ -568,7 +568,7 @@ impl<T: DatabaseStore> PaymentAttemptInterface for RouterStore<T> {
             PaymentAttempt::convert_back(
                 key_manager_state,
                 diesel_payment_attempt,
-                merchant_key_store.key.get_inner(),
+                &merchant_key_store.get_key_ring(),
                 merchant_key_store.merchant_id.clone().into(),
             )
             .await
@@ -596,7 +596,7 @@ impl<T: DatabaseStore> PaymentAttemptInterface for RouterStore<T> {
             .convert(
                 self.get_keymanager_state()
                     .attach_printable("Missing KeyManagerState")?,
-                merchant_key_store.key.get_inner(),
+                &merchant_key_store.get_key_ring(),
                 merchant_key_store.merchant_id.clone().into(),
             )
             .await
@@ -628,7 +628,7 @@ impl<T: DatabaseStore> PaymentAttemptInterface for RouterStore<T> {
                             .convert(
                                 self.get_keymanager_state()
                                     .attach_printable("Missing KeyManagerState")?,
-                                merchant_key_store.key.get_inner(),
+                                &merchant_key_store.get_key_ring(),
                                 merchant_key_store.merchant_id.clone().into(),
                             )
                             .await
@@ -848,6 +848,7 @@ impl<T: DatabaseStore> PaymentAttemptInterface for KVRouterStore<T> {
                         .clone(),
                     retry_type: payment_attempt.retry_type,
                     installment_data: payment_attempt.installment_data.clone(),
+                    retry_hop: payment_attempt.retry_hop.clone(),
                 };
 
                 let field = format!("pa_{}", created_attempt.attempt_id);
@@ -1054,7 +1055,7 @@ impl<T: DatabaseStore> PaymentAttemptInterface for KVRouterStore<T> {
                             .await
                             .change_context(errors::StorageError::EncryptionError)?,
                     ),
-                    merchant_key_store.key.get_inner(),
+                    &merchant_key_store.get_key_ring(),
                     merchant_key_store.merchant_id.clone().into(),
                 )
                 .await
@@ -1263,7 +1264,7 @@ impl<T: DatabaseStore> PaymentAttemptInterface for KVRouterStore<T> {
                         PaymentAttempt::convert_back(
                             key_manager_state,
                             diesel_payment_attempt,
-                            merchant_key_store.key.get_inner(),
+                            &merchant_key_store.get_key_ring(),
                             processor_merchant_id.clone().into(),
                         )
                         .await
@@ -1334,7 +1335,7 @@ impl<T: DatabaseStore> PaymentAttemptInterface for KVRouterStore<T> {
                     PaymentAttempt::convert_back(
                         key_manager_state,
                         diesel_payment_attempt,
-                        merchant_key_store.key.get_inner(),
+                        &merchant_key_store.get_key_ring(),
                         processor_merchant_id.clone().into(),
                     )
                     .await
@@ -1410,7 +1411,7 @@ impl<T: DatabaseStore> PaymentAttemptInterface for KVRouterStore<T> {
                     PaymentAttempt::convert_back(
                         key_manager_state,
                         diesel_payment_attempt,
-                        merchant_key_store.key.get_inner(),
+                        &merchant_key_store.get_key_ring(),
                         processor_merchant_id.clone().into(),
                     )
                     .await
@@ -1485,7 +1486,7 @@ impl<T: DatabaseStore> PaymentAttemptInterface for KVRouterStore<T> {
                     PaymentAttempt::convert_back(
                         keymanager_state,
                         payment_attempt,
-                        merchant_key_store.key.get_inner(),
+                        &merchant_key_store.get_key_ring(),
                         merchant_id.into(),
                     )
                     .await
@@ -1588,7 +1589,7 @@ impl<T: DatabaseStore> PaymentAttemptInterface for KVRouterStore<T> {
                         PaymentAttempt::convert_back(
                             key_manager_state,
                             diesel_payment_attempt,
-                            merchant_key_store.key.get_inner(),
+                            &merchant_key_store.get_key_ring(),
                             processor_merchant_id.clone().into(),
                         )
                         .await
@@ -1660,7 +1661,7 @@ impl<T: DatabaseStore> PaymentAttemptInterface for KVRouterStore<T> {
                         PaymentAttempt::convert_back(
                             key_manager_state,
                             diesel_payment_attempt,
-                            merchant_key_store.key.get_inner(),
+                            &merchant_key_store.get_key_ring(),
                             processor_merchant_id.clone().into(),
                         )
                         .await
@@ -1746,7 +1747,7 @@ impl<T: DatabaseStore> PaymentAttemptInterface for KVRouterStore<T> {
                         PaymentAttempt::convert_back(
                             key_manager_state,
                             diesel_payment_attempt,
-                            merchant_key_store.key.get_inner(),
+                            &merchant_key_store.get_key_ring(),
                             processor_merchant_id.clone().into(),
                         )
                         .await
@@ -1872,7 +1873,7 @@ impl<T: DatabaseStore> PaymentAttemptInterface for KVRouterStore<T> {
                         PaymentAttempt::convert_back(
                             key_manager_state,
                             diesel_payment_attempt,
-                            merchant_key_store.key.get_inner(),
+                            &merchant_key_store.get_key_ring(),
                             processor_merchant_id.clone().into(),
                         )
                         .await
@@ -1943,7 +1944,7 @@ impl<T: DatabaseStore> PaymentAttemptInterface for KVRouterStore<T> {
                                 PaymentAttempt::convert_back(
                                     key_manager_state,
                                     diesel_payment_attempt,
-                                    merchant_key_store.key.get_inner(),
+                                    &merchant_key_store.get_key_ring(),
                                     merchant_key_store.merchant_id.clone().into(),
                                 )
                             }))
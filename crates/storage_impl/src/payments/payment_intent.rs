@@ -314,7 +314,7 @@ impl<T: DatabaseStore> PaymentIntentInterface for KVRouterStore<T> {
                     self.get_keymanager_state()
                         .attach_printable("Missing KeyManagerState")?,
                     diesel_intent,
-                    merchant_key_store.key.get_inner(),
+                    &merchant_key_store.get_key_ring(),
                     processor_merchant_id.into(),
                 )
                 .await
@@ -393,7 +393,7 @@ impl<T: DatabaseStore> PaymentIntentInterface for KVRouterStore<T> {
                     self.get_keymanager_state()
                         .attach_printable("Missing KeyManagerState")?,
                     diesel_intent,
-                    merchant_key_store.key.get_inner(),
+                    &merchant_key_store.get_key_ring(),
                     merchant_id.into(),
                 )
                 .await
@@ -461,7 +461,7 @@ impl<T: DatabaseStore> PaymentIntentInterface for KVRouterStore<T> {
             self.get_keymanager_state()
                 .attach_printable("Missing KeyManagerState")?,
             diesel_payment_intent,
-            merchant_key_store.key.get_inner(),
+            &merchant_key_store.get_key_ring(),
             processor_merchant_id.to_owned().into(),
         )
         .await
@@ -525,7 +525,7 @@ impl<T: DatabaseStore> PaymentIntentInterface for KVRouterStore<T> {
             self.get_keymanager_state()
                 .attach_printable("Missing KeyManagerState")?,
             diesel_payment_intent,
-            merchant_key_store.key.get_inner(),
+            &merchant_key_store.get_key_ring(),
             merchant_id.into(),
         )
         .await
@@ -707,7 +707,7 @@ impl<T: DatabaseStore> PaymentIntentInterface for KVRouterStore<T> {
                     self.get_keymanager_state()
                         .attach_printable("Missing KeyManagerState")?,
                     diesel_payment_intent,
-                    merchant_key_store.key.get_inner(),
+                    &merchant_key_store.get_key_ring(),
                     merchant_id.into(),
                 )
                 .await
@@ -743,7 +743,7 @@ impl<T: DatabaseStore> PaymentIntentInterface for crate::RouterStore<T> {
             self.get_keymanager_state()
                 .attach_printable("Missing KeyManagerState")?,
             diesel_payment_intent,
-            merchant_key_store.key.get_inner(),
+            &merchant_key_store.get_key_ring(),
             merchant_key_store.merchant_id.clone().into(),
         )
         .await
@@ -777,7 +777,7 @@ impl<T: DatabaseStore> PaymentIntentInterface for crate::RouterStore<T> {
             self.get_keymanager_state()
                 .attach_printable("Missing KeyManagerState")?,
             diesel_payment_intent,
-            merchant_key_store.key.get_inner(),
+            &merchant_key_store.get_key_ring(),
             merchant_key_store.merchant_id.clone().into(),
         )
         .await
@@ -811,7 +811,7 @@ impl<T: DatabaseStore> PaymentIntentInterface for crate::RouterStore<T> {
             self.get_keymanager_state()
                 .attach_printable("Missing KeyManagerState")?,
             diesel_payment_intent,
-            merchant_key_store.key.get_inner(),
+            &merchant_key_store.get_key_ring(),
             merchant_key_store.merchant_id.clone().into(),
         )
         .await
@@ -844,7 +844,7 @@ impl<T: DatabaseStore> PaymentIntentInterface for crate::RouterStore<T> {
                 self.get_keymanager_state()
                     .attach_printable("Missing KeyManagerState")?,
                 diesel_payment_intent,
-                merchant_key_store.key.get_inner(),
+                &merchant_key_store.get_key_ring(),
                 merchant_key_store.merchant_id.clone().into(),
             )
             .await
@@ -875,7 +875,7 @@ impl<T: DatabaseStore> PaymentIntentInterface for crate::RouterStore<T> {
             self.get_keymanager_state()
                 .attach_printable("Missing KeyManagerState")?,
             diesel_payment_intent,
-            merchant_key_store.key.get_inner(),
+            &merchant_key_store.get_key_ring(),
             merchant_id.to_owned().into(),
         )
         .await
@@ -908,7 +908,7 @@ impl<T: DatabaseStore> PaymentIntentInterface for crate::RouterStore<T> {
             self.get_keymanager_state()
                 .attach_printable("Missing KeyManagerState")?,
             diesel_payment_intent,
-            merchant_key_store.key.get_inner(),
+            &merchant_key_store.get_key_ring(),
             merchant_id.to_owned().into(),
         )
         .await
@@ -1023,7 +1023,7 @@ impl<T: DatabaseStore> PaymentIntentInterface for crate::RouterStore<T> {
                 PaymentIntent::convert_back(
                     keymanager_state,
                     diesel_payment_intent,
-                    merchant_key_store.key.get_inner(),
+                    &merchant_key_store.get_key_ring(),
                     merchant_key_store.merchant_id.clone().into(),
                 )
             }))
@@ -1299,7 +1299,7 @@ impl<T: DatabaseStore> PaymentIntentInterface for crate::RouterStore<T> {
                     let payment_intent = PaymentIntent::convert_back(
                         keymanager_state,
                         pi,
-                        merchant_key_store.key.get_inner(),
+                        &merchant_key_store.get_key_ring(),
                         processor_merchant_id.to_owned().into(),
                     )
                     .await
@@ -1308,7 +1308,7 @@ impl<T: DatabaseStore> PaymentIntentInterface for crate::RouterStore<T> {
                     let payment_attempt = PaymentAttempt::convert_back(
                         keymanager_state,
                         pa,
-                        merchant_key_store.key.get_inner(),
+                        &merchant_key_store.get_key_ring(),
                         merchant_key_store.merchant_id.clone().into(),
                     )
                     .await
@@ -1520,7 +1520,7 @@ impl<T: DatabaseStore> PaymentIntentInterface for crate::RouterStore<T> {
                             self.get_keymanager_state()
                                 .attach_printable("Missing KeyManagerState")?,
                             pi,
-                            merchant_key_store.key.get_inner(),
+                            &merchant_key_store.get_key_ring(),
                             merchant_id.to_owned().into(),
                         );
                         let payment_attempt = pa
@@ -1528,7 +1528,7 @@ impl<T: DatabaseStore> PaymentIntentInterface for crate::RouterStore<T> {
                                 PaymentAttempt::convert_back(
                                     keymanager_state,
                                     val,
-                                    merchant_key_store.key.get_inner(),
+                                    &merchant_key_store.get_key_ring(),
                                     merchant_id.to_owned().into(),
                                 )
                             })
@@ -1,6 +1,7 @@
 use common_utils::errors::CustomResult;
 pub use diesel_models::subscription::Subscription;
 use error_stack::ResultExt;
+use hyperswitch_domain_models::behaviour::ReverseConversion;
 pub use hyperswitch_domain_models::{
     behaviour::Conversion,
     merchant_key_store::MerchantKeyStore,
@@ -153,40 +154,133 @@ impl SubscriptionInterface for MockDb {
     #[instrument(skip_all)]
     async fn insert_subscription_entry(
         &self,
-        _key_store: &MerchantKeyStore,
-        _subscription_new: DomainSubscription,
+        key_store: &MerchantKeyStore,
+        subscription_new: DomainSubscription,
     ) -> CustomResult<DomainSubscription, StorageError> {
-        Err(StorageError::MockDbError)?
+        let subscription = Conversion::convert(subscription_new)
+            .await
+            .change_context(StorageError::DecryptionError)?;
+        let mut subscriptions = self.subscriptions.lock().await;
+        if subscriptions
+            .iter()
+            .any(|existing| existing.id == subscription.id)
+        {
+            Err(StorageError::DuplicateValue {
+                entity: "subscription",
+                key: Some(subscription.id.get_string_repr().to_owned()),
+            })?
+        }
+        let subscription_id = subscription.id.clone();
+        subscriptions.push(subscription);
+        self.get_resource(
+            key_store,
+            subscriptions,
+            |subscription| subscription.id == subscription_id,
+            "cannot find subscription".to_string(),
+        )
+        .await
     }
 
     async fn find_by_merchant_id_subscription_id(
         &self,
-        _key_store: &MerchantKeyStore,
-        _merchant_id: &common_utils::id_type::MerchantId,
-        _subscription_id: String,
+        key_store: &MerchantKeyStore,
+        merchant_id: &common_utils::id_type::MerchantId,
+        subscription_id: String,
     ) -> CustomResult<DomainSubscription, StorageError> {
-        Err(StorageError::MockDbError)?
+        self.get_resource(
+            key_store,
+            self.subscriptions.lock().await,
+            |subscription| {
+                subscription.merchant_id == *merchant_id
+                    && subscription.id.get_string_repr() == subscription_id
+            },
+            format!("Subscription not found for subscription_id: {subscription_id}"),
+        )
+        .await
     }
 
     async fn update_subscription_entry(
         &self,
-        _key_store: &MerchantKeyStore,
-        _merchant_id: &common_utils::id_type::MerchantId,
-        _subscription_id: String,
-        _data: DomainSubscriptionUpdate,
+        key_store: &MerchantKeyStore,
+        merchant_id: &common_utils::id_type::MerchantId,
+        subscription_id: String,
+        data: DomainSubscriptionUpdate,
     ) -> CustomResult<DomainSubscription, StorageError> {
-        Err(StorageError::MockDbError)?
+        let subscription_update = data
+            .construct_new()
+            .await
+            .change_context(StorageError::DecryptionError)?;
+        let subscriptions = self.subscriptions.lock().await;
+        let subscription = subscriptions
+            .iter()
+            .find(|subscription| {
+                subscription.merchant_id == *merchant_id
+                    && subscription.id.get_string_repr() == subscription_id
+            })
+            .cloned()
+            .ok_or(StorageError::ValueNotFound(format!(
+                "Subscription not found for subscription_id: {subscription_id}"
+            )))?;
+        self.update_resource(
+            key_store,
+            subscriptions,
+            subscription_update.apply_changeset(subscription),
+            |subscription| {
+                subscription.merchant_id == *merchant_id
+                    && subscription.id.get_string_repr() == subscription_id
+            },
+            format!("Subscription not found for subscription_id: {subscription_id}"),
+        )
+        .await
     }
 
     #[instrument(skip_all)]
     async fn list_by_merchant_id_profile_id(
         &self,
-        _key_store: &MerchantKeyStore,
-        _merchant_id: &common_utils::id_type::MerchantId,
-        _profile_id: &common_utils::id_type::ProfileId,
-        _limit: Option<i64>,
-        _offset: Option<i64>,
+        key_store: &MerchantKeyStore,
+        merchant_id: &common_utils::id_type::MerchantId,
+        profile_id: &common_utils::id_type::ProfileId,
+        limit: Option<i64>,
+        offset: Option<i64>,
     ) -> CustomResult<Vec<DomainSubscription>, StorageError> {
-        Err(StorageError::MockDbError)?
+        let mut subscriptions = self
+            .subscriptions
+            .lock()
+            .await
+            .iter()
+            .filter(|subscription| {
+                subscription.merchant_id == *merchant_id && subscription.profile_id == *profile_id
+            })
+            .cloned()
+            .collect::<Vec<_>>();
+        subscriptions.sort_by(|a, b| b.created_at.cmp(&a.created_at));
+
+        let key_manager_state = self
+            .get_keymanager_state()
+            .attach_printable("Missing KeyManagerState")?;
+        let subscription_futures = subscriptions
+            .into_iter()
+            .skip(
+                offset
+                    .and_then(|offset| usize::try_from(offset).ok())
+                    .unwrap_or(0),
+            )
+            .take(
+                limit
+                    .and_then(|limit| usize::try_from(limit).ok())
+                    .unwrap_or(usize::MAX),
+            )
+            .map(|subscription| async {
+                subscription
+                    .convert(
+                        key_manager_state,
+                        &key_store.get_key_ring(),
+                        key_store.merchant_id.clone().into(),
+                    )
+                    .await
+                    .change_context(StorageError::DecryptionError)
+            });
+
+        futures::future::try_join_all(subscription_futures).await
     }
 }
@@ -62,7 +62,7 @@ impl<T: DatabaseStore> TokenizationInterface for RouterStore<T> {
             .convert(
                 self.get_keymanager_state()
                     .attach_printable("Missing KeyManagerState")?,
-                merchant_key_store.key.get_inner(),
+                &merchant_key_store.get_key_ring(),
                 merchant_key_store.merchant_id.clone().into(),
             )
             .await
@@ -85,7 +85,7 @@ impl<T: DatabaseStore> TokenizationInterface for RouterStore<T> {
             .convert(
                 self.get_keymanager_state()
                     .attach_printable("Missing KeyManagerState")?,
-                merchant_key_store.key.get_inner(),
+                &merchant_key_store.get_key_ring(),
                 merchant_key_store.merchant_id.clone().into(),
             )
             .await
@@ -164,29 +164,96 @@ impl<T: DatabaseStore> TokenizationInterface for KVRouterStore<T> {
 impl TokenizationInterface for MockDb {
     async fn insert_tokenization(
         &self,
-        _tokenization: hyperswitch_domain_models::tokenization::Tokenization,
-        _merchant_key_store: &MerchantKeyStore,
+        tokenization: hyperswitch_domain_models::tokenization::Tokenization,
+        merchant_key_store: &MerchantKeyStore,
     ) -> CustomResult<hyperswitch_domain_models::tokenization::Tokenization, errors::StorageError>
     {
-        Err(errors::StorageError::MockDbError)?
+        let tokenization = tokenization
+            .construct_new()
+            .await
+            .change_context(errors::StorageError::EncryptionError)?;
+        let mut tokenizations = self.tokenizations.lock().await;
+        if tokenizations
+            .iter()
+            .any(|existing| existing.id == tokenization.id)
+        {
+            Err(errors::StorageError::DuplicateValue {
+                entity: "tokenization",
+                key: Some(tokenization.id.get_string_repr().to_owned()),
+            })?
+        }
+        tokenizations.push(tokenization.clone());
+        drop(tokenizations);
+
+        tokenization
+            .convert(
+                self.get_keymanager_state()
+                    .attach_printable("Missing KeyManagerState")?,
+                &merchant_key_store.get_key_ring(),
+                merchant_key_store.merchant_id.clone().into(),
+            )
+            .await
+            .change_context(errors::StorageError::DecryptionError)
     }
     async fn get_entity_id_vault_id_by_token_id(
         &self,
-        _token: &common_utils::id_type::GlobalTokenId,
-        _merchant_key_store: &MerchantKeyStore,
+        token: &common_utils::id_type::GlobalTokenId,
+        merchant_key_store: &MerchantKeyStore,
     ) -> CustomResult<hyperswitch_domain_models::tokenization::Tokenization, errors::StorageError>
     {
-        Err(errors::StorageError::MockDbError)?
+        let tokenization = self
+            .tokenizations
+            .lock()
+            .await
+            .iter()
+            .find(|tokenization| tokenization.id == *token)
+            .cloned()
+            .ok_or(errors::StorageError::ValueNotFound(format!(
+                "No tokenization available for id = {token:?}"
+            )))?;
+
+        tokenization
+            .convert(
+                self.get_keymanager_state()
+                    .attach_printable("Missing KeyManagerState")?,
+                &merchant_key_store.get_key_ring(),
+                merchant_key_store.merchant_id.clone().into(),
+            )
+            .await
+            .change_context(errors::StorageError::DecryptionError)
     }
 
     async fn update_tokenization_record(
         &self,
-        _tokenization_record: hyperswitch_domain_models::tokenization::Tokenization,
-        _tokenization_update: hyperswitch_domain_models::tokenization::TokenizationUpdate,
-        _merchant_key_store: &MerchantKeyStore,
+        tokenization_record: hyperswitch_domain_models::tokenization::Tokenization,
+        tokenization_update: hyperswitch_domain_models::tokenization::TokenizationUpdate,
+        merchant_key_store: &MerchantKeyStore,
     ) -> CustomResult<hyperswitch_domain_models::tokenization::Tokenization, errors::StorageError>
     {
-        Err(errors::StorageError::MockDbError)?
+        let updated_tokenization = {
+            let mut tokenizations = self.tokenizations.lock().await;
+            let tokenization = tokenizations
+                .iter_mut()
+                .find(|tokenization| tokenization.id == tokenization_record.id)
+                .ok_or(errors::StorageError::ValueNotFound(format!(
+                    "No tokenization available for id = {:?}",
+                    tokenization_record.id
+                )))?;
+            *tokenization =
+                tokenization_diesel::TokenizationUpdateInternal::from(tokenization_update)
+                    .apply_changeset(tokenization.clone());
+            tokenization.clone()
+        };
+
+        updated_tokenization
+            .convert(
+                self.get_keymanager_state()
+                    .attach_printable("Missing KeyManagerState")?,
+                &merchant_key_store.get_key_ring(),
+                merchant_key_store.merchant_id.clone().into(),
+            )
+            .await
+            .change_context(errors::StorageError::DecryptionError)
     }
 }
 
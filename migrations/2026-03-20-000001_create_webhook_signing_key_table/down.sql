@@ -0,0 +1,4 @@
+-- This file should undo anything in `up.sql`
+DROP INDEX IF EXISTS webhook_signing_key_profile_id_version_index;
+
+DROP TABLE IF EXISTS webhook_signing_key;
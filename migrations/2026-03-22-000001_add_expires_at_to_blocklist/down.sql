@@ -0,0 +1,4 @@
+-- This file should undo anything in `up.sql`
+DROP INDEX IF EXISTS blocklist_merchant_id_expires_at_index;
+
+ALTER TABLE blocklist DROP COLUMN IF EXISTS expires_at;
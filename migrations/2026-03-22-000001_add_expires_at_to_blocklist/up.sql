@@ -0,0 +1,4 @@
+-- Your SQL goes here
+ALTER TABLE blocklist ADD COLUMN IF NOT EXISTS expires_at TIMESTAMP;
+
+CREATE INDEX IF NOT EXISTS blocklist_merchant_id_expires_at_index ON blocklist (merchant_id, expires_at) WHERE expires_at IS NOT NULL;
@@ -0,0 +1,12 @@
+-- This file should undo anything in `up.sql`
+DELETE FROM blocklist
+WHERE data_kind IN ('email', 'ip_address', 'customer_id', 'device_fingerprint');
+
+DELETE FROM blocklist_fingerprint
+WHERE data_kind IN ('email', 'ip_address', 'customer_id', 'device_fingerprint');
+
+DELETE FROM pg_enum
+WHERE enumlabel IN ('email', 'ip_address', 'customer_id', 'device_fingerprint')
+AND enumtypid = (
+  SELECT oid FROM pg_type WHERE typname = 'BlocklistDataKind'
+);
@@ -0,0 +1,5 @@
+-- Your SQL goes here
+ALTER TABLE api_keys
+ADD COLUMN IF NOT EXISTS profile_id VARCHAR(64),
+ADD COLUMN IF NOT EXISTS scopes JSONB,
+ADD COLUMN IF NOT EXISTS ip_allowlist JSONB;
@@ -0,0 +1,6 @@
+-- This file should undo anything in `up.sql`
+DROP INDEX IF EXISTS forex_rate_snapshot_created_at_index;
+
+DROP INDEX IF EXISTS forex_rate_snapshot_provider_created_at_index;
+
+DROP TABLE IF EXISTS forex_rate_snapshot;
@@ -0,0 +1,11 @@
+-- Your SQL goes here
+CREATE TABLE IF NOT EXISTS forex_rate_snapshot (
+    snapshot_id VARCHAR(64) PRIMARY KEY,
+    provider VARCHAR(64) NOT NULL,
+    rates JSONB NOT NULL,
+    created_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP
+);
+
+CREATE INDEX IF NOT EXISTS forex_rate_snapshot_provider_created_at_index ON forex_rate_snapshot (provider, created_at);
+
+CREATE INDEX IF NOT EXISTS forex_rate_snapshot_created_at_index ON forex_rate_snapshot (created_at);
@@ -0,0 +1,6 @@
+-- This file should undo anything in `up.sql`
+DROP INDEX IF EXISTS gateway_status_map_pattern_index;
+
+ALTER TABLE gateway_status_map
+DROP COLUMN IF EXISTS code_match_type,
+DROP COLUMN IF EXISTS message_match_type;
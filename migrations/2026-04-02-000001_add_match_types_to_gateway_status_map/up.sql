@@ -0,0 +1,8 @@
+-- Your SQL goes here
+ALTER TABLE gateway_status_map
+ADD COLUMN IF NOT EXISTS code_match_type VARCHAR(64),
+ADD COLUMN IF NOT EXISTS message_match_type VARCHAR(64);
+
+CREATE INDEX IF NOT EXISTS gateway_status_map_pattern_index ON gateway_status_map (connector, flow, sub_flow)
+WHERE code_match_type IS NOT NULL AND code_match_type != 'exact'
+    OR message_match_type IS NOT NULL AND message_match_type != 'exact';
@@ -0,0 +1,2 @@
+-- Remove retry_hop column from payment_attempt table
+ALTER TABLE payment_attempt DROP COLUMN IF EXISTS retry_hop;
@@ -0,0 +1,2 @@
+-- Add retry_hop column to payment_attempt table to record the cascade path of auto retries
+ALTER TABLE payment_attempt ADD COLUMN IF NOT EXISTS retry_hop JSONB;
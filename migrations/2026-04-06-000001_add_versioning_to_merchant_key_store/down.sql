@@ -0,0 +1,3 @@
+-- Remove key versioning columns from merchant_key_store table
+ALTER TABLE merchant_key_store DROP COLUMN IF EXISTS previous_keys;
+ALTER TABLE merchant_key_store DROP COLUMN IF EXISTS key_version;
@@ -0,0 +1,3 @@
+-- Track the version of the merchant data encryption key and the keys it replaced
+ALTER TABLE merchant_key_store ADD COLUMN IF NOT EXISTS key_version INTEGER NOT NULL DEFAULT 1;
+ALTER TABLE merchant_key_store ADD COLUMN IF NOT EXISTS previous_keys BYTEA;